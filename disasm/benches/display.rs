@@ -0,0 +1,50 @@
+//! Compares displaying instructions with `to_string`, which allocates a `String` per instruction, with
+//! `ParsedIns::write_str` into one reused `String`, on the ARM inputs in `inputs/mod.rs`.
+//!
+//! ```sh
+//! cargo bench -p unarm --bench display
+//! cargo bench -p unarm --bench display --features corpus
+//! ```
+
+mod inputs;
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unarm::{v5te::arm::parse_all, Endian, ParseFlags};
+
+fn display(c: &mut Criterion) {
+    let flags = ParseFlags::default();
+    let mut group = c.benchmark_group("display");
+    for (name, code) in inputs::arm() {
+        let mut parsed = vec![];
+        parse_all(&code, 0, Endian::Little, &flags, &mut parsed);
+        group.throughput(Throughput::Elements(parsed.len() as u64));
+        group.bench_with_input(BenchmarkId::new("to_string", name), &parsed, |b, parsed| {
+            b.iter(|| {
+                for (_, ins) in parsed {
+                    black_box(ins.display(Default::default()).to_string());
+                }
+            })
+        });
+        let mut text = String::new();
+        group.bench_with_input(BenchmarkId::new("write_str", name), &parsed, |b, parsed| {
+            b.iter(|| {
+                for (_, ins) in parsed {
+                    text.clear();
+                    ins.write_str(&mut text, Default::default()).unwrap();
+                    black_box(&text);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Each iteration displays up to a million instructions, so a few samples are enough
+    config = Criterion::default().sample_size(10);
+    targets = display
+}
+criterion_main!(benches);
@@ -0,0 +1,101 @@
+//! Compares disassembling code into text one instruction at a time, like `examples/dump.rs`, with the batched loop of
+//! `examples/fast_dump.rs`, on the ARM inputs in `inputs/mod.rs`. Fails if the batched loop isn't faster.
+//!
+//! ```sh
+//! cargo bench -p unarm --bench dump
+//! cargo bench -p unarm --bench dump --features corpus
+//! ```
+
+mod inputs;
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use criterion::{criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
+use unarm::{
+    tuning::ARM_BATCH_SIZE,
+    v5te::arm::{Ins, InsIter},
+    Endian, ParseFlags, ParseMode, ParsedIns,
+};
+
+fn dump(c: &mut Criterion) {
+    let flags = ParseFlags::default();
+    let mut group = c.benchmark_group("dump");
+    for (name, code) in inputs::arm() {
+        group.throughput(Throughput::Elements(code.len() as u64 / 4));
+
+        let naive = best_time(&mut group, BenchmarkId::new("naive", name), || {
+            let mut len = 0;
+            for (address, _, parsed) in InsIter::new(&code, 0, Endian::Little, flags) {
+                let parsed = parsed.display_with_pc(Default::default(), address, ParseMode::Arm);
+                len += black_box(format!("{address:#010x}: {parsed}\n")).len();
+            }
+            black_box(len);
+        });
+
+        let mut decoded = Vec::with_capacity(ARM_BATCH_SIZE);
+        let mut parsed = vec![ParsedIns::default(); ARM_BATCH_SIZE];
+        let mut text = String::new();
+        let batched = best_time(&mut group, BenchmarkId::new("batched", name), || {
+            let mut len = 0;
+            for (index, batch) in code.chunks(ARM_BATCH_SIZE * 4).enumerate() {
+                decoded.clear();
+                decoded.extend(
+                    batch
+                        .chunks_exact(4)
+                        .map(|bytes| Ins::new(u32::from_le_bytes(bytes.try_into().unwrap()), &flags)),
+                );
+                for (ins, parsed) in decoded.iter().zip(parsed.iter_mut()) {
+                    ins.parse_into(parsed, &flags);
+                }
+                text.clear();
+                let base = (index * ARM_BATCH_SIZE * 4) as u32;
+                for (address, parsed) in (base..).step_by(4).zip(&parsed[..decoded.len()]) {
+                    let parsed = parsed.display_with_pc(Default::default(), address, ParseMode::Arm);
+                    writeln!(text, "{address:#010x}: {parsed}").unwrap();
+                }
+                len += black_box(&text).len();
+            }
+            black_box(len);
+        });
+
+        // Only compare when a filter didn't skip either benchmark
+        if let (Some(naive), Some(batched)) = (naive, batched) {
+            println!(
+                "{name}: batched is {:.2}x as fast as naive",
+                naive.as_secs_f64() / batched.as_secs_f64()
+            );
+            assert!(batched < naive, "the batched loop should be faster on {name}");
+        }
+    }
+    group.finish();
+}
+
+/// Benchmarks `run` and returns its best time per iteration, or `None` if it didn't run
+fn best_time(group: &mut BenchmarkGroup<WallTime>, id: BenchmarkId, mut run: impl FnMut()) -> Option<Duration> {
+    let mut best = None;
+    group.bench_function(id, |b| {
+        b.iter_custom(|iters| {
+            let start = Instant::now();
+            for _ in 0..iters {
+                run();
+            }
+            let elapsed = start.elapsed();
+            let time = elapsed.div_f64(iters as f64);
+            best = Some(best.map_or(time, |best: Duration| best.min(time)));
+            elapsed
+        })
+    });
+    best
+}
+
+criterion_group! {
+    name = benches;
+    // Each iteration disassembles up to a million instructions, so a few samples are enough
+    config = Criterion::default().sample_size(10);
+    targets = dump
+}
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+//! Compares the two implementations of `Opcode::find` on the ARM inputs in `inputs/mod.rs`.
+//!
+//! ```sh
+//! cargo bench -p unarm --bench find
+//! cargo bench -p unarm --bench find --features corpus
+//! ```
+
+mod inputs;
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unarm::{v5te::arm::Opcode, ParseFlags};
+
+fn find(c: &mut Criterion) {
+    let flags = ParseFlags::default();
+    let mut group = c.benchmark_group("find");
+    for (name, code) in inputs::arm() {
+        let codes: Vec<u32> = code
+            .chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        group.throughput(Throughput::Elements(codes.len() as u64));
+        for (find_name, find) in [
+            ("tree", Opcode::find_tree as fn(u32, &ParseFlags) -> Opcode),
+            ("table", Opcode::find_table),
+        ] {
+            group.bench_with_input(BenchmarkId::new(find_name, name), &codes, |b, codes| {
+                b.iter(|| {
+                    for code in codes {
+                        black_box(find(black_box(*code), &flags));
+                    }
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Each iteration decodes up to a million instructions, so a few samples are enough
+    config = Criterion::default().sample_size(10);
+    targets = find
+}
+criterion_main!(benches);
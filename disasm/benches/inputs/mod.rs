@@ -0,0 +1,32 @@
+//! Code decoded by the benchmarks
+
+/// Number of pseudorandom words in [`pseudorandom`]
+const RANDOM_COUNT: usize = 1_000_000;
+
+/// Little-endian ARMv5TE code, the fixtures `nds_arm9.bin`, `cfg_arm.bin` and `frame_arm.bin` of the tests in a row. Their
+/// sources are in `disasm/tests/fixtures/`.
+const ARMV5TE: &[u8] = include_bytes!("armv5te.bin");
+
+/// Returns 1M pseudorandom words, the same on every run
+pub fn pseudorandom() -> Vec<u32> {
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    (0..RANDOM_COUNT)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect()
+}
+
+/// Returns the little-endian ARM code of each benchmark input by name: the [`pseudorandom`] words, the checked-in ARMv5TE
+/// code, and with the `corpus` feature 16 MB of realistic code from `unarm::corpus::load`, see `cargo xtask corpus`
+pub fn arm() -> Vec<(&'static str, Vec<u8>)> {
+    let random = pseudorandom().into_iter().flat_map(u32::to_le_bytes).collect();
+    let inputs = vec![("pseudorandom", random), ("armv5te", ARMV5TE.to_vec())];
+    #[cfg(feature = "corpus")]
+    let inputs = [inputs, vec![("corpus", unarm::corpus::load(0x400000))]].concat();
+    inputs
+}
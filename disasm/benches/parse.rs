@@ -0,0 +1,49 @@
+//! Compares parsing instructions one by one with [`parse_all`], which reuses its output buffer, on the ARM inputs in
+//! `inputs/mod.rs`.
+//!
+//! ```sh
+//! cargo bench -p unarm --bench parse
+//! cargo bench -p unarm --bench parse --features corpus
+//! ```
+
+mod inputs;
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unarm::{
+    v5te::arm::{parse_all, InsIter},
+    Endian, ParseFlags,
+};
+
+fn parse(c: &mut Criterion) {
+    let flags = ParseFlags::default();
+    let mut group = c.benchmark_group("parse");
+    for (name, code) in inputs::arm() {
+        group.throughput(Throughput::Elements(code.len() as u64 / 4));
+        group.bench_with_input(BenchmarkId::new("InsIter", name), &code, |b, code| {
+            b.iter(|| {
+                let out: Vec<_> = InsIter::new(code, 0, Endian::Little, flags)
+                    .map(|(address, _, parsed)| (address, parsed))
+                    .collect();
+                black_box(out)
+            })
+        });
+        let mut out = vec![];
+        group.bench_with_input(BenchmarkId::new("parse_all", name), &code, |b, code| {
+            b.iter(|| {
+                parse_all(code, 0, Endian::Little, &flags, &mut out);
+                black_box(&out);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Each iteration parses up to a million instructions, so a few samples are enough
+    config = Criterion::default().sample_size(10);
+    targets = parse
+}
+criterion_main!(benches);
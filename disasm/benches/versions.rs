@@ -0,0 +1,108 @@
+//! Compares the decoders of each version in ARM and Thumb mode, to see whether the larger opcode tables of newer versions
+//! slow down the common path. Measures `Opcode::find`, `Ins::new` with `Ins::parse_into`, and `ParsedIns::write_str`
+//! into a reused `String`, in one group per mode and version. The ARM decoders decode the inputs in `inputs/mod.rs`,
+//! the Thumb decoders the low halves of the pseudorandom words.
+//!
+//! Each group also compares the two implementations of the decoder's `parse` function, `parse_table` and `parse_match`,
+//! on instructions which are already decoded.
+//!
+//! ```sh
+//! cargo bench -p unarm --bench versions
+//! cargo bench -p unarm --bench versions -- thumb/
+//! cargo bench -p unarm --bench versions --features corpus
+//! ```
+
+mod inputs;
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use unarm::{ParseFlags, ParsedIns};
+
+/// Benchmarks one decoder on each of `inputs`, a slice of names and codes, in a group named after the mode and version
+macro_rules! bench_decoder {
+    ($c:expr, $version:ident, $mode:ident, $inputs:expr) => {{
+        use unarm::$version::$mode::{parse_match, parse_table, Ins, Opcode};
+        let flags = ParseFlags::default();
+        let mut group = $c.benchmark_group(concat!(stringify!($mode), "/", stringify!($version)));
+        for (name, codes) in $inputs {
+            let codes: &[u32] = codes;
+            group.throughput(Throughput::Elements(codes.len() as u64));
+
+            group.bench_with_input(BenchmarkId::new("find", name), codes, |b, codes| {
+                b.iter(|| {
+                    for code in codes {
+                        black_box(Opcode::find(black_box(*code), &flags));
+                    }
+                })
+            });
+            let mut out = ParsedIns::default();
+            group.bench_with_input(BenchmarkId::new("parse", name), codes, |b, codes| {
+                b.iter(|| {
+                    for code in codes {
+                        Ins::new(black_box(*code), &flags).parse_into(&mut out, &flags);
+                        black_box(&out);
+                    }
+                })
+            });
+            let parsed: Vec<_> = codes.iter().map(|code| Ins::new(*code, &flags).parse(&flags)).collect();
+            let mut text = String::new();
+            group.bench_with_input(BenchmarkId::new("write_str", name), &parsed, |b, parsed| {
+                b.iter(|| {
+                    for ins in parsed {
+                        text.clear();
+                        ins.write_str(&mut text, Default::default()).unwrap();
+                        black_box(&text);
+                    }
+                })
+            });
+
+            let ins: Vec<_> = codes.iter().map(|code| Ins::new(*code, &flags)).collect();
+            for (parse_name, parse) in [
+                ("parse_table", parse_table as fn(&mut ParsedIns, Ins, &ParseFlags)),
+                ("parse_match", parse_match),
+            ] {
+                group.bench_with_input(BenchmarkId::new(parse_name, name), &ins, |b, ins| {
+                    b.iter(|| {
+                        for ins in ins {
+                            parse(&mut out, black_box(*ins), &flags);
+                            black_box(&out);
+                        }
+                    })
+                });
+            }
+        }
+        group.finish();
+    }};
+}
+
+fn arm(c: &mut Criterion) {
+    let inputs: Vec<(&str, Vec<u32>)> = inputs::arm()
+        .into_iter()
+        .map(|(name, code)| {
+            let codes = code
+                .chunks_exact(4)
+                .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()));
+            (name, codes.collect())
+        })
+        .collect();
+    bench_decoder!(c, v4t, arm, &inputs);
+    bench_decoder!(c, v5te, arm, &inputs);
+    bench_decoder!(c, v6k, arm, &inputs);
+}
+
+fn thumb(c: &mut Criterion) {
+    let random: Vec<u32> = inputs::pseudorandom().iter().map(|code| code & 0xffff).collect();
+    let inputs = [("pseudorandom", random)];
+    bench_decoder!(c, v4t, thumb, &inputs);
+    bench_decoder!(c, v5te, thumb, &inputs);
+    bench_decoder!(c, v6k, thumb, &inputs);
+}
+
+criterion_group! {
+    name = benches;
+    // Each iteration decodes up to a million instructions, so a few samples are enough
+    config = Criterion::default().sample_size(10);
+    targets = arm, thumb
+}
+criterion_main!(benches);
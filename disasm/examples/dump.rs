@@ -0,0 +1,32 @@
+//! Disassembles a file of little-endian ARMv5TE code. Also used to compare the binary size of the decoder profiles.
+//!
+//! Usage: `cargo run --example dump -- <file> [thumb]`
+
+use unarm::{v5te, Endian, ParseFlags, ParseMode};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let Some(path) = args.next() else {
+        eprintln!("Usage: dump <file> [thumb]");
+        std::process::exit(1);
+    };
+    let thumb = args.next().as_deref() == Some("thumb");
+    let code = std::fs::read(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    let flags = ParseFlags::default();
+
+    if thumb {
+        for (address, _, parsed) in v5te::thumb::InsIter::new(&code, 0, Endian::Little, flags) {
+            println!(
+                "{address:#010x}: {}",
+                parsed.display_with_pc(Default::default(), address, ParseMode::Thumb)
+            );
+        }
+    } else {
+        for (address, _, parsed) in v5te::arm::InsIter::new(&code, 0, Endian::Little, flags) {
+            println!(
+                "{address:#010x}: {}",
+                parsed.display_with_pc(Default::default(), address, ParseMode::Arm)
+            );
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Disassembles a file of little-endian ARMv5TE code with the fastest known loop, see the `tuning` module. Each batch
+//! of `ARM_BATCH_SIZE` instructions is decoded, then parsed, then written, and the buffers of each pass are reused for
+//! the next batch. `cargo bench -p unarm --bench dump` compares this loop with the one in `dump.rs`.
+//!
+//! Usage: `cargo run --release --example fast_dump -- <file>`
+
+use std::{fmt::Write as _, io::Write as _};
+
+use unarm::{tuning::ARM_BATCH_SIZE, v5te::arm::Ins, ParseFlags, ParseMode, ParsedIns};
+
+fn main() {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("Usage: fast_dump <file>");
+        std::process::exit(1);
+    };
+    let code = std::fs::read(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    let flags = ParseFlags::default();
+
+    let mut stdout = std::io::stdout().lock();
+    let mut decoded = Vec::with_capacity(ARM_BATCH_SIZE);
+    let mut parsed = vec![ParsedIns::default(); ARM_BATCH_SIZE];
+    let mut text = String::new();
+    for (index, batch) in code.chunks(ARM_BATCH_SIZE * 4).enumerate() {
+        // Decode pass, which only reads the decode table
+        decoded.clear();
+        decoded.extend(
+            batch
+                .chunks_exact(4)
+                .map(|bytes| Ins::new(u32::from_le_bytes(bytes.try_into().unwrap()), &flags)),
+        );
+
+        // Parse pass, which reuses the `ParsedIns` of the previous batch
+        for (ins, parsed) in decoded.iter().zip(parsed.iter_mut()) {
+            ins.parse_into(parsed, &flags);
+        }
+
+        // Display pass, which reuses the text of the previous batch
+        text.clear();
+        let base = (index * ARM_BATCH_SIZE * 4) as u32;
+        for (address, parsed) in (base..).step_by(4).zip(&parsed[..decoded.len()]) {
+            let parsed = parsed.display_with_pc(Default::default(), address, ParseMode::Arm);
+            writeln!(text, "{address:#010x}: {parsed}").unwrap();
+        }
+        stdout.write_all(text.as_bytes()).unwrap();
+    }
+}
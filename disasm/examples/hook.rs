@@ -0,0 +1,163 @@
+//! Redirects an ARM function to a hook. The first instruction of the function is replaced by a branch to the hook, and a
+//! trampoline is written which runs the displaced instruction and then branches back into the function. The hook can
+//! call the trampoline to run the original function.
+//!
+//! Usage: `cargo run --example hook`
+
+use unarm::{
+    patch::{branch_to, relocate_instruction, RelocError},
+    v5te::arm::InsIter,
+    Endian,
+};
+
+/// A code image loaded at `base`
+struct Image {
+    base: u32,
+    bytes: Vec<u8>,
+}
+
+impl Image {
+    fn read(&self, address: u32) -> u32 {
+        let offset = (address - self.base) as usize;
+        u32::from_le_bytes(self.bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn write(&mut self, address: u32, code: u32) {
+        let offset = (address - self.base) as usize;
+        self.bytes[offset..offset + 4].copy_from_slice(&code.to_le_bytes());
+    }
+
+    fn print(&self, start: u32, count: usize) {
+        let offset = (start - self.base) as usize;
+        for (address, _, parsed) in InsIter::new(&self.bytes[offset..], start, Endian::Little, Default::default()).take(count)
+        {
+            println!(
+                "  {address:#010x}: {}",
+                parsed.display_with_pc(Default::default(), address, unarm::ParseMode::Arm)
+            );
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum HookError {
+    Reloc(RelocError),
+    /// A branch between the function, hook and trampoline is out of range
+    BranchOutOfRange,
+}
+
+/// Hooks the function at `function` so that it branches to `hook`, and writes a trampoline at `trampoline` which
+/// continues the original function.
+fn inject(image: &mut Image, function: u32, hook: u32, trampoline: u32) -> Result<(), HookError> {
+    let original = image.read(function);
+    let displaced = relocate_instruction(original, function, trampoline).map_err(HookError::Reloc)?;
+    let back = branch_to(trampoline + 4, function + 4).ok_or(HookError::BranchOutOfRange)?;
+    let detour = branch_to(function, hook).ok_or(HookError::BranchOutOfRange)?;
+
+    image.write(trampoline, displaced);
+    image.write(trampoline + 4, back);
+    image.write(function, detour);
+    Ok(())
+}
+
+const BASE: u32 = 0x02000000;
+const FUNCTION: u32 = 0x02000000;
+const HOOK: u32 = 0x02000100;
+const TRAMPOLINE: u32 = 0x02000200;
+
+fn sample_image() -> Image {
+    let mut image = Image {
+        base: BASE,
+        bytes: vec![0; 0x300],
+    };
+    let function = [
+        0xe59f000c, // ldr r0, [pc, #0xc]
+        0xe5901000, // ldr r1, [r0]
+        0xe2811001, // add r1, r1, #0x1
+        0xe5801000, // str r1, [r0]
+        0xe12fff1e, // bx lr
+        0x02000400, // .word 0x2000400
+    ];
+    for (i, code) in function.into_iter().enumerate() {
+        image.write(FUNCTION + i as u32 * 4, code);
+    }
+    // The hook calls the original function through the trampoline
+    image.write(HOOK, 0xe92d4010); // push {r4, lr}
+    image.write(HOOK + 4, 0xeb000000 | (branch_to(HOOK + 4, TRAMPOLINE).unwrap() & 0xffffff)); // bl TRAMPOLINE
+    image.write(HOOK + 8, 0xe8bd8010); // pop {r4, pc}
+    image
+}
+
+fn main() {
+    let mut image = sample_image();
+    println!("Before:");
+    image.print(FUNCTION, 5);
+
+    inject(&mut image, FUNCTION, HOOK, TRAMPOLINE).unwrap();
+    println!("Function:");
+    image.print(FUNCTION, 5);
+    println!("Hook:");
+    image.print(HOOK, 3);
+    println!("Trampoline:");
+    image.print(TRAMPOLINE, 2);
+}
+
+#[cfg(test)]
+mod tests {
+    use unarm::{ParseFlags, ParseMode, ParsedIns};
+
+    use super::*;
+
+    fn parse(code: u32) -> ParsedIns {
+        let flags = ParseFlags::default();
+        unarm::v5te::arm::Ins::new(code, &flags).parse(&flags)
+    }
+
+    #[test]
+    fn test_inject() {
+        let mut image = sample_image();
+        let original = parse(image.read(FUNCTION));
+        inject(&mut image, FUNCTION, HOOK, TRAMPOLINE).unwrap();
+
+        let detour = parse(image.read(FUNCTION));
+        assert_eq!(detour.branch_destination(FUNCTION, ParseMode::Arm), Some(HOOK));
+
+        // The displaced load still reads the literal pool of the original function
+        let displaced = parse(image.read(TRAMPOLINE));
+        assert_eq!(displaced.mnemonic, original.mnemonic);
+        assert_eq!(displaced.args[0], original.args[0]);
+        assert_eq!(
+            displaced.pc_relative_address(TRAMPOLINE, ParseMode::Arm),
+            original.pc_relative_address(FUNCTION, ParseMode::Arm)
+        );
+        assert_eq!(
+            displaced.pc_relative_address(TRAMPOLINE, ParseMode::Arm),
+            Some(FUNCTION + 0x14)
+        );
+
+        let back = parse(image.read(TRAMPOLINE + 4));
+        assert_eq!(back.branch_destination(TRAMPOLINE + 4, ParseMode::Arm), Some(FUNCTION + 4));
+
+        // The rest of the function is untouched
+        assert_eq!(image.read(FUNCTION + 4), 0xe5901000);
+    }
+
+    #[test]
+    fn test_inject_errors() {
+        let mut image = sample_image();
+        // The literal is more than 4 KB away from the trampoline
+        assert_eq!(
+            inject(&mut image, FUNCTION, HOOK, FUNCTION + 0x2000),
+            Err(HookError::Reloc(RelocError::OutOfRange))
+        );
+        // add r0, pc, r1
+        image.write(FUNCTION, 0xe08f0001);
+        assert_eq!(
+            inject(&mut image, FUNCTION, HOOK, TRAMPOLINE),
+            Err(HookError::Reloc(RelocError::Unsupported))
+        );
+        // Nothing was written
+        assert_eq!(image.read(FUNCTION), 0xe08f0001);
+        assert_eq!(image.read(TRAMPOLINE), 0);
+    }
+}
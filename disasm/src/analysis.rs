@@ -0,0 +1,1486 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(any(feature = "arm", feature = "thumb"))]
+use crate::args::Arguments;
+#[cfg(all(feature = "v4t", any(feature = "arm", feature = "thumb")))]
+use crate::v4t;
+#[cfg(all(feature = "v5te", any(feature = "arm", feature = "thumb")))]
+use crate::v5te;
+#[cfg(all(feature = "v6k", any(feature = "arm", feature = "thumb")))]
+use crate::v6k;
+use crate::{
+    args::{Argument, Reg, RegList, Register, Shift, ShiftImm, StatusReg},
+    ins_match, ins_matches,
+    view::{AddrOffset, DataOp, DataProcessingView, LoadStoreView, Operand2, Width},
+    ArgumentVisitor, ArmVersion, Condition, Endian, ImmKind, MemoryMap, Op, ParseMode, ParsedIns, Parser, RegisterRole,
+};
+#[cfg(feature = "arm")]
+use crate::{
+    args::{OffsetImm, ShiftReg},
+    parse::{LDR_MNEMONICS, STR_MNEMONICS},
+};
+
+/// How an instruction affects the control flow
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Flow {
+    /// Continues to the next instruction
+    Next,
+    /// Calls a function and continues to the next instruction, `target` is `None` for indirect calls
+    Call { target: Option<u32> },
+    /// Branches to a known destination
+    Branch { target: u32, conditional: bool },
+    /// Returns from the current function
+    Return { conditional: bool },
+    /// Branches to a destination which can't be resolved statically
+    Indirect { conditional: bool },
+    /// Illegal instruction, execution can't continue
+    Stop,
+}
+
+impl Flow {
+    /// Classifies an instruction parsed by [`Parser`] at the given address
+    pub fn classify(address: u32, code: u32, op: Op, ins: &ParsedIns) -> Self {
+        let Some(mnemonic) = base_mnemonic(op) else {
+            return if matches!(op, Op::Data) { Self::Next } else { Self::Stop };
+        };
+        let mode = op_mode(op);
+        let conditional = match mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => (code >> 28) < 0xe,
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => (code & 0xf000) == 0xd000,
+            ParseMode::Data => {
+                let _ = code;
+                false
+            }
+        };
+        let args = &ins.args;
+        let writes_pc = matches!(
+            args[0],
+            Argument::Reg(Reg {
+                reg: Register::Pc,
+                deref: false,
+                ..
+            })
+        );
+
+        match mnemonic {
+            "b" => match ins.branch_destination(address, mode) {
+                Some(target) => Self::Branch { target, conditional },
+                None => Self::Next,
+            },
+            "bl" | "blx" => match args[0] {
+                Argument::BranchDest(_) => Self::Call {
+                    target: ins.branch_destination(address, mode),
+                },
+                Argument::Reg(_) => Self::Call { target: None },
+                _ => Self::Next,
+            },
+            "bx" | "bxj" if ins_matches!(ins, _ lr) => Self::Return { conditional },
+            "bx" | "bxj" => Self::Indirect { conditional },
+            "pop" | "ldm" => {
+                let Some(regs) = ins.args_iter().find_map(|arg| match arg {
+                    Argument::RegList(list) => Some(list.regs),
+                    _ => None,
+                }) else {
+                    return Self::Next;
+                };
+                if regs & (1 << 15) == 0 {
+                    Self::Next
+                } else if mnemonic == "pop" || matches!(args[0], Argument::Reg(Reg { reg: Register::Sp, .. })) {
+                    Self::Return { conditional }
+                } else {
+                    Self::Indirect { conditional }
+                }
+            }
+            "ldr" if writes_pc => match args[1] {
+                Argument::Reg(Reg { reg: Register::Sp, .. }) => Self::Return { conditional },
+                _ => Self::Indirect { conditional },
+            },
+            "mov" if ins_matches!(ins, _ pc, lr) => Self::Return { conditional },
+            "mov" if writes_pc => Self::Indirect { conditional },
+            "mvn" | "add" | "adc" | "sub" | "sbc" | "rsb" | "rsc" | "and" | "orr" | "eor" | "bic" | "lsl" | "lsr" | "asr"
+            | "ror" | "rrx"
+                if writes_pc =>
+            {
+                Self::Indirect { conditional }
+            }
+            _ => Self::Next,
+        }
+    }
+
+    /// Returns whether this instruction ends a basic block
+    pub fn is_terminator(self) -> bool {
+        matches!(
+            self,
+            Self::Branch { .. } | Self::Return { .. } | Self::Indirect { .. } | Self::Stop
+        )
+    }
+
+    /// Returns whether execution may continue to the next instruction
+    pub fn falls_through(self) -> bool {
+        match self {
+            Self::Next | Self::Call { .. } => true,
+            Self::Branch { conditional, .. } | Self::Return { conditional } | Self::Indirect { conditional } => conditional,
+            Self::Stop => false,
+        }
+    }
+}
+
+/// Set of general-purpose registers, as a bitfield where bit N is register N
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub struct RegisterSet(pub u16);
+
+impl RegisterSet {
+    pub fn contains(self, reg: Register) -> bool {
+        reg != Register::Illegal && self.0 & (1 << reg as u8) != 0
+    }
+
+    /// Adds a register to the set, [`Register::Illegal`] is ignored
+    pub fn insert(&mut self, reg: Register) {
+        if reg != Register::Illegal {
+            self.0 |= 1 << reg as u8;
+        }
+    }
+
+    pub fn remove(&mut self, reg: Register) {
+        if reg != Register::Illegal {
+            self.0 &= !(1 << reg as u8);
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the registers in this set which are not in `other`
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Iterates over the registers in ascending order
+    pub fn iter(self) -> impl Iterator<Item = Register> {
+        (0..16).filter(move |i| self.0 & (1 << i) != 0).map(Register::parse)
+    }
+}
+
+impl FromIterator<Register> for RegisterSet {
+    fn from_iter<T: IntoIterator<Item = Register>>(iter: T) -> Self {
+        let mut set = Self::default();
+        for reg in iter {
+            set.insert(reg);
+        }
+        set
+    }
+}
+
+impl RegList {
+    pub fn registers(self) -> RegisterSet {
+        RegisterSet(self.regs as u16)
+    }
+
+    /// Returns the number of registers in the list. An empty list is unpredictable, see
+    /// [`ParseFlags::unpredictable_as_illegal`](crate::ParseFlags::unpredictable_as_illegal).
+    pub fn len(self) -> usize {
+        self.registers().len()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.registers().is_empty()
+    }
+
+    /// Returns the register if the list has exactly one
+    pub fn single(self) -> Option<Register> {
+        let set = self.registers();
+        (set.len() == 1).then(|| set.iter().next()).flatten()
+    }
+}
+
+/// Data-processing mnemonics whose destination is also the first source when written with two operands, e.g. the Thumb
+/// `adc r7, r2`
+const TWO_OPERAND_MNEMONICS: &[&str] = &[
+    "adc", "adcs", "add", "adds", "and", "ands", "asr", "asrs", "bic", "bics", "eor", "eors", "lsl", "lsls", "lsr", "lsrs",
+    "mul", "muls", "orr", "orrs", "ror", "rors", "sbc", "sbcs", "sub", "subs",
+];
+
+/// `stmdb` mnemonics by [`Condition`], see [`ParsedIns::aliased_from`]
+#[cfg(feature = "arm")]
+const STMDB_MNEMONICS: [&str; 15] = [
+    "stmdbeq", "stmdbne", "stmdbhs", "stmdblo", "stmdbmi", "stmdbpl", "stmdbvs", "stmdbvc", "stmdbhi", "stmdbls", "stmdbge",
+    "stmdblt", "stmdbgt", "stmdble", "stmdb",
+];
+
+/// `ldm` mnemonics by [`Condition`], see [`ParsedIns::aliased_from`]
+#[cfg(feature = "arm")]
+const LDM_MNEMONICS: [&str; 15] = [
+    "ldmeq", "ldmne", "ldmhs", "ldmlo", "ldmmi", "ldmpl", "ldmvs", "ldmvc", "ldmhi", "ldmls", "ldmge", "ldmlt", "ldmgt",
+    "ldmle", "ldm",
+];
+
+/// `mov` mnemonics by [`Condition`], see [`ParsedIns::aliased_from`]
+#[cfg(feature = "arm")]
+const MOV_MNEMONICS: [&str; 15] = [
+    "moveq", "movne", "movhs", "movlo", "movmi", "movpl", "movvs", "movvc", "movhi", "movls", "movge", "movlt", "movgt",
+    "movle", "mov",
+];
+
+/// `movs` mnemonics by [`Condition`], see [`ParsedIns::aliased_from`]
+#[cfg(feature = "arm")]
+const MOVS_MNEMONICS: [&str; 15] = [
+    "movseq", "movsne", "movshs", "movslo", "movsmi", "movspl", "movsvs", "movsvc", "movshi", "movsls", "movsge", "movslt",
+    "movsgt", "movsle", "movs",
+];
+
+/// Mnemonics which don't write to their first register argument
+const NO_DEST_MNEMONICS: &[&str] = &[
+    "cmp", "cmn", "tst", "teq", "msr", "mcr", "mcr2", "mcrr", "mcrr2", "b", "bl", "blx", "bx", "bxj", "pld",
+];
+
+impl ParsedIns {
+    /// Returns the general-purpose registers written by this instruction, including writeback to base registers and
+    /// branches writing to PC (and LR for calls). `op` is needed to tell loads and stores apart.
+    pub fn defs(&self, op: Op) -> RegisterSet {
+        self.defs_uses(op).0
+    }
+
+    /// Returns the general-purpose registers read by this instruction, including base and offset registers.
+    pub fn uses(&self, op: Op) -> RegisterSet {
+        self.defs_uses(op).1
+    }
+
+    /// Returns the underlying instruction if `op` is a unified syntax (UAL) alias which rewrites the operands of another
+    /// instruction, e.g. `stmdb sp!, {r4, lr}` for `push {r4, lr}` or `mov r1, r2, lsl #0x3` for `lsl r1, r2, #0x3`.
+    /// Display uses the alias, while [`Self::defs`] and [`Self::uses`] analyze the underlying instruction. Aliases which
+    /// only rename the mnemonic, such as `svc` for `swi`, return `None` like instructions which aren't aliases.
+    pub fn aliased_from(&self, op: Op) -> Option<ParsedIns> {
+        self.unalias(op).map(|(_, ins)| ins)
+    }
+
+    /// Returns the opcode mnemonic and the instruction which `op` is an alias of, see [`Self::aliased_from`]
+    #[cfg(any(feature = "arm", feature = "thumb"))]
+    fn unalias(&self, op: Op) -> Option<(&'static str, ParsedIns)> {
+        let variant = alias_variant(op)?;
+        #[cfg(feature = "arm")]
+        let cond = self.condition() as usize;
+        let reg = |reg, deref, writeback| Argument::Reg(Reg { deref, reg, writeback });
+        let mut args = Arguments::default();
+        let (base, mnemonic) = match (op_mode(op), variant) {
+            #[cfg(feature = "arm")]
+            (ParseMode::Arm, "PushM" | "PopM") => {
+                args[0] = reg(Register::Sp, false, true);
+                args[1] = self.args[0];
+                if variant == "PushM" {
+                    ("stm", STMDB_MNEMONICS[cond])
+                } else {
+                    ("ldm", LDM_MNEMONICS[cond])
+                }
+            }
+            #[cfg(feature = "arm")]
+            (ParseMode::Arm, "PushR" | "PopR") => {
+                let Argument::RegList(list) = self.args[0] else {
+                    return None;
+                };
+                let push = variant == "PushR";
+                args[0] = reg(list.single()?, false, false);
+                args[1] = reg(Register::Sp, true, push);
+                args[2] = Argument::OffsetImm(OffsetImm {
+                    post_indexed: !push,
+                    value: if push { -4 } else { 4 },
+                });
+                if push {
+                    ("str", STR_MNEMONICS[cond])
+                } else {
+                    ("ldr", LDR_MNEMONICS[cond])
+                }
+            }
+            #[cfg(feature = "arm")]
+            (ParseMode::Arm, "Lsl" | "Lsr" | "Asr" | "Ror" | "Rrx") => {
+                let op = match variant {
+                    "Lsl" => Shift::Lsl,
+                    "Lsr" => Shift::Lsr,
+                    "Asr" => Shift::Asr,
+                    "Ror" => Shift::Ror,
+                    _ => Shift::Rrx,
+                };
+                args[0] = self.args[0];
+                args[1] = self.args[1];
+                args[2] = match self.args[2] {
+                    Argument::UImm(imm) => Argument::ShiftImm(ShiftImm { imm, op }),
+                    Argument::Reg(Reg { reg, .. }) => Argument::ShiftReg(ShiftReg { op, reg }),
+                    _ => Argument::Shift(op),
+                };
+                if self.mnemonic_base().ends_with('s') {
+                    ("mov", MOVS_MNEMONICS[cond])
+                } else {
+                    ("mov", MOV_MNEMONICS[cond])
+                }
+            }
+            #[cfg(feature = "thumb")]
+            (ParseMode::Thumb, "Adr") => {
+                args[0] = self.args[0];
+                args[1] = reg(Register::Pc, false, false);
+                args[2] = self.args[1];
+                ("add", "add")
+            }
+            #[cfg(feature = "thumb")]
+            (ParseMode::Thumb, "Ldm") => {
+                let Argument::Reg(Reg { reg: rn, .. }) = self.args[0] else {
+                    return None;
+                };
+                args[0] = reg(rn, false, true);
+                args[1] = self.args[1];
+                ("ldmia", "ldmia")
+            }
+            #[cfg(feature = "thumb")]
+            (ParseMode::Thumb, "MovsR") => {
+                args[0] = self.args[0];
+                args[1] = self.args[1];
+                args[2] = Argument::UImm(0);
+                ("lsls", "lsls")
+            }
+            #[cfg(feature = "thumb")]
+            (ParseMode::Thumb, "Rsbs") => {
+                args[0] = self.args[0];
+                args[1] = self.args[1];
+                ("neg", "neg")
+            }
+            _ => return None,
+        };
+        Some((base, ParsedIns { mnemonic, args }))
+    }
+
+    /// Without an instruction set there are no aliases
+    #[cfg(not(any(feature = "arm", feature = "thumb")))]
+    fn unalias(&self, _op: Op) -> Option<(&'static str, ParsedIns)> {
+        None
+    }
+
+    fn defs_uses(&self, op: Op) -> (RegisterSet, RegisterSet) {
+        // Aliases are analyzed in their underlying form, e.g. `adr` reads PC like `add rd, pc, #imm`
+        if let Some((mnemonic, underlying)) = self.unalias(op) {
+            return underlying.defs_uses_of(mnemonic);
+        }
+        match base_mnemonic(op) {
+            Some(mnemonic) => self.defs_uses_of(mnemonic),
+            None => Default::default(),
+        }
+    }
+
+    /// Returns the defs and uses of this instruction, where `mnemonic` is the mnemonic of its opcode
+    fn defs_uses_of(&self, mnemonic: &str) -> (RegisterSet, RegisterSet) {
+        let defs = RegisterSet::default();
+        let uses = RegisterSet::default();
+
+        let is_load = mnemonic.starts_with("ldr") || matches!(mnemonic, "ldm" | "ldmia" | "pop");
+        let is_store = mnemonic.starts_with("str") || matches!(mnemonic, "stm" | "push" | "srs");
+        let has_list = self
+            .args_iter()
+            .any(|arg| matches!(arg, Argument::RegList(_) | Argument::SRegList(_) | Argument::DRegList(_)));
+        // VFP transfers write the general-purpose registers before the first VFP register, e.g. `vmov r0, r1, d0`
+        let is_vfp = |arg: &Argument| {
+            matches!(
+                arg,
+                Argument::SReg(_) | Argument::DReg(_) | Argument::DRegIndex(_) | Argument::VfpSysReg(_)
+            )
+        };
+        let vfp_dests = self
+            .args_iter()
+            .any(is_vfp)
+            .then(|| self.args_iter().take_while(|arg| !is_vfp(arg)).count());
+        // Long multiplies which also read their destination registers
+        let accumulate = matches!(mnemonic, "smlal" | "umlal" | "umaal" | "smlald" | "smlsld");
+        // Number of register arguments which are written to, not counting base registers
+        let dests = vfp_dests.unwrap_or(match mnemonic {
+            _ if is_load => usize::MAX,
+            _ if mnemonic.starts_with("strex") => 1,
+            _ if is_store || NO_DEST_MNEMONICS.contains(&mnemonic) => 0,
+            "smull" | "umull" | "mrrc" | "mrrc2" => 2,
+            _ if accumulate => 2,
+            _ => 1,
+        });
+
+        let mut visitor = DefsUses {
+            defs,
+            uses,
+            is_load,
+            has_list,
+            accumulate,
+            dests,
+            base: None,
+            post_indexed: false,
+            transfers: 0,
+        };
+        self.visit(&mut visitor);
+        let DefsUses {
+            mut defs,
+            mut uses,
+            transfers,
+            ..
+        } = visitor;
+
+        let first_reg = match self.args[0] {
+            Argument::Reg(Reg { reg, deref: false, .. }) => Some(reg),
+            _ => None,
+        };
+        if let Some(first_reg) = first_reg {
+            if self.arg_count() == 2 && TWO_OPERAND_MNEMONICS.contains(&mnemonic) {
+                uses.insert(first_reg);
+            }
+            // The second register of LDRD/STRD is implied in divided syntax
+            if matches!(mnemonic, "ldrd" | "strd") && transfers == 1 {
+                if let Some(second) = Register::try_parse(first_reg as u32 + 1) {
+                    if is_load {
+                        defs.insert(second);
+                    } else {
+                        uses.insert(second);
+                    }
+                }
+            }
+        }
+
+        match mnemonic {
+            "push" | "pop" | "vpush" | "vpop" => {
+                defs.insert(Register::Sp);
+                uses.insert(Register::Sp);
+            }
+            "b" | "bx" | "bxj" | "rfe" => defs.insert(Register::Pc),
+            "bl" | "blx" => {
+                defs.insert(Register::Pc);
+                defs.insert(Register::Lr);
+            }
+            _ => {}
+        }
+        (defs, uses)
+    }
+}
+
+/// Collects the registers for [`ParsedIns::defs`] and [`ParsedIns::uses`]
+struct DefsUses {
+    defs: RegisterSet,
+    uses: RegisterSet,
+    is_load: bool,
+    has_list: bool,
+    accumulate: bool,
+    /// Number of register arguments which are written to, not counting base registers
+    dests: usize,
+    base: Option<Register>,
+    /// True after the closing bracket of a memory operand, so any offset is post-indexed
+    post_indexed: bool,
+    transfers: usize,
+}
+
+impl DefsUses {
+    fn write_back(&mut self) {
+        if let (true, Some(base)) = (self.post_indexed, self.base) {
+            self.defs.insert(base);
+        }
+    }
+}
+
+impl ArgumentVisitor for DefsUses {
+    fn visit_register(&mut self, _index: usize, reg: Register, role: RegisterRole) {
+        match role {
+            RegisterRole::Operand { writeback } if !writeback && !self.has_list => {
+                if self.dests > 0 {
+                    self.defs.insert(reg);
+                    if self.accumulate {
+                        self.uses.insert(reg);
+                    }
+                    self.dests -= 1;
+                } else {
+                    self.uses.insert(reg);
+                }
+                self.transfers += 1;
+            }
+            RegisterRole::Operand { writeback } | RegisterRole::Base { writeback } => {
+                self.uses.insert(reg);
+                if writeback {
+                    self.defs.insert(reg);
+                }
+                self.base = Some(reg);
+            }
+            RegisterRole::List if self.is_load => self.defs.insert(reg),
+            RegisterRole::List | RegisterRole::ShiftAmount => self.uses.insert(reg),
+            RegisterRole::Offset { .. } => {
+                self.uses.insert(reg);
+                self.write_back();
+            }
+        }
+    }
+
+    fn visit_immediate(&mut self, _index: usize, _value: i64, kind: ImmKind) {
+        if kind == ImmKind::Offset {
+            self.write_back();
+        }
+    }
+
+    fn visit_memory_end(&mut self, _index: usize, _writeback: bool) {
+        self.post_indexed = true;
+    }
+}
+
+/// Set of condition flags of the CPSR, as a bitfield in the order of the CPSR bits 31-28
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub struct StatusFlags(pub u8);
+
+impl StatusFlags {
+    pub const N: Self = Self(0b1000);
+    pub const Z: Self = Self(0b0100);
+    pub const C: Self = Self(0b0010);
+    pub const V: Self = Self(0b0001);
+    pub const NZ: Self = Self(0b1100);
+    pub const NZC: Self = Self(0b1110);
+    pub const NZCV: Self = Self(0b1111);
+
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the flags tested by a condition
+    pub fn of_condition(cond: Condition) -> Self {
+        match cond {
+            Condition::Eq | Condition::Ne => Self::Z,
+            Condition::Hs | Condition::Lo => Self::C,
+            Condition::Mi | Condition::Pl => Self::N,
+            Condition::Vs | Condition::Vc => Self::V,
+            Condition::Hi | Condition::Ls => Self(Self::C.0 | Self::Z.0),
+            Condition::Ge | Condition::Lt => Self(Self::N.0 | Self::V.0),
+            Condition::Gt | Condition::Le => Self(Self::N.0 | Self::Z.0 | Self::V.0),
+            Condition::Al | Condition::Nv => Self::default(),
+        }
+    }
+}
+
+/// Mnemonics which read the carry flag, besides their condition
+const CARRY_MNEMONICS: &[&str] = &["adc", "adcs", "sbc", "sbcs", "rsc", "rscs", "rrx", "rrxs"];
+
+/// Mnemonics which set the flags from their result, with the flags they set
+const FLAG_SETTERS: &[(&[&str], StatusFlags)] = &[
+    (
+        &["cmp", "cmn", "adds", "subs", "rsbs", "rscs", "adcs", "sbcs", "negs"],
+        StatusFlags::NZCV,
+    ),
+    (
+        &[
+            "tst", "teq", "ands", "orrs", "eors", "bics", "movs", "mvns", "lsls", "lsrs", "asrs", "rors", "rrxs",
+        ],
+        StatusFlags::NZC,
+    ),
+    (&["muls", "mlas", "smulls", "umulls", "smlals", "umlals"], StatusFlags::NZ),
+];
+
+impl ParsedIns {
+    /// Returns the condition flags read by this instruction, from its condition and from the carry input of e.g.
+    /// `adc` and `rrx` shifts
+    pub fn flags_read(&self) -> StatusFlags {
+        let mnemonic = self.mnemonic_base();
+        let mut flags = StatusFlags::of_condition(self.condition());
+        let rrx = self.args_iter().any(|arg| {
+            matches!(
+                arg,
+                Argument::ShiftImm(ShiftImm { op: Shift::Rrx, .. }) | Argument::Shift(Shift::Rrx)
+            )
+        });
+        if rrx || CARRY_MNEMONICS.contains(&mnemonic) {
+            flags = flags.union(StatusFlags::C);
+        }
+        if mnemonic == "mrs" {
+            flags = StatusFlags::NZCV;
+        }
+        flags
+    }
+
+    /// Returns the condition flags which this instruction may write. Instructions which change the flags in ways that
+    /// can't be followed statically return all flags, see [`ParsedIns::clobbers_flags`].
+    ///
+    /// Thumb instructions must be parsed in unified syntax, which shows the flag-setting ones with an `s` suffix.
+    pub fn flags_written(&self) -> StatusFlags {
+        if self.clobbers_flags() {
+            return StatusFlags::NZCV;
+        }
+        let mnemonic = self.mnemonic_base();
+        if let Some((_, flags)) = FLAG_SETTERS.iter().find(|(mnemonics, _)| mnemonics.contains(&mnemonic)) {
+            return *flags;
+        }
+        // `mrc` to PC writes the top bits of the coprocessor register to the flags
+        let mrc_to_pc = matches!(mnemonic, "mrc" | "mrc2")
+            && self.args_iter().find(|arg| matches!(arg, Argument::Reg(_)))
+                == Some(&Argument::Reg(Reg {
+                    deref: false,
+                    reg: Register::Pc,
+                    writeback: false,
+                }));
+        // `fmstat` copies the flags of a VFP comparison
+        let fmstat = self.args[0] == Argument::StatusReg(StatusReg::ApsrNzcv);
+        if mrc_to_pc || fmstat || mnemonic == "fmstat" {
+            StatusFlags::NZCV
+        } else {
+            StatusFlags::default()
+        }
+    }
+
+    /// Returns true if this instruction may change the flags in ways that can't be followed statically: calls, software
+    /// interrupts, `msr`, and exception returns which restore the CPSR
+    pub fn clobbers_flags(&self) -> bool {
+        let mnemonic = self.mnemonic_base();
+        let writes_pc = matches!(
+            self.args[0],
+            Argument::Reg(Reg {
+                reg: Register::Pc,
+                deref: false,
+                ..
+            })
+        );
+        let user_mode_list = self
+            .args_iter()
+            .any(|arg| matches!(arg, Argument::RegList(RegList { user_mode: true, .. })));
+        match mnemonic {
+            "bl" | "blx" | "swi" | "svc" | "msr" | "rfe" => true,
+            "ldm" | "ldmia" | "ldmib" | "ldmda" | "ldmdb" | "ldmfd" | "ldmed" | "ldmfa" | "ldmea" | "pop" => user_mode_list,
+            _ => writes_pc && FLAG_SETTERS.iter().any(|(mnemonics, _)| mnemonics.contains(&mnemonic)),
+        }
+    }
+}
+
+/// An instruction which reads condition flags, paired with an instruction which may have written them last. Found by
+/// [`pair_flags`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FlagPairing {
+    /// Address of the instruction which writes the flags
+    pub setter: u32,
+    /// Address of the instruction which reads the flags
+    pub user: u32,
+    /// Flags which the user reads from the setter
+    pub flags: StatusFlags,
+}
+
+impl FlagPairing {
+    /// Returns a listing comment for the user, e.g. `; uses flags from 0x8004`
+    pub fn user_comment(&self) -> String {
+        format!("; uses flags from {:#x}", self.setter)
+    }
+
+    /// Returns a listing comment for the setter, e.g. `; flags used at 0x800c`
+    pub fn setter_comment(&self) -> String {
+        format!("; flags used at {:#x}", self.user)
+    }
+}
+
+/// Pairs each instruction in a straight-line block which reads condition flags with the instructions which may have
+/// written them last, in order of the users and then the setters. A user is paired with more than one setter if a
+/// conditional instruction may have overwritten some flags, e.g. `cmp` followed by `cmpne`, or if its flags come from
+/// different instructions.
+///
+/// Flags are unknown at the start of the block and after an instruction which [clobbers](ParsedIns::clobbers_flags)
+/// them, so reads of unknown flags are not paired.
+pub fn pair_flags(block: &[(u32, ParsedIns)]) -> Vec<FlagPairing> {
+    const FLAGS: [StatusFlags; 4] = [StatusFlags::N, StatusFlags::Z, StatusFlags::C, StatusFlags::V];
+    // Instructions which may have written each flag last, or none if it's unknown
+    let mut writers: [Vec<u32>; 4] = Default::default();
+    let mut pairings = vec![];
+    for (address, ins) in block {
+        let read = ins.flags_read();
+        let mut setters: Vec<FlagPairing> = vec![];
+        for (flag, writers) in FLAGS.iter().zip(writers.iter()) {
+            if !read.contains(*flag) {
+                continue;
+            }
+            for setter in writers {
+                match setters.iter_mut().find(|pairing| pairing.setter == *setter) {
+                    Some(pairing) => pairing.flags = pairing.flags.union(*flag),
+                    None => setters.push(FlagPairing {
+                        setter: *setter,
+                        user: *address,
+                        flags: *flag,
+                    }),
+                }
+            }
+        }
+        setters.sort_by_key(|pairing| pairing.setter);
+        pairings.extend(setters);
+
+        if ins.clobbers_flags() {
+            writers.iter_mut().for_each(Vec::clear);
+            continue;
+        }
+        let written = ins.flags_written();
+        let conditional = ins.condition() != Condition::Al;
+        for (flag, writers) in FLAGS.iter().zip(writers.iter_mut()) {
+            if !written.contains(*flag) {
+                continue;
+            }
+            if !conditional {
+                writers.clear();
+                writers.push(*address);
+            } else if !writers.is_empty() {
+                // The previous writers remain if the condition fails
+                writers.push(*address);
+            }
+        }
+    }
+    pairings
+}
+
+fn base_mnemonic(op: Op) -> Option<&'static str> {
+    match op {
+        #[cfg(all(feature = "v4t", feature = "arm"))]
+        Op::ArmV4T(x) => (x != v4t::arm::Opcode::Illegal).then(|| x.mnemonic()),
+        #[cfg(all(feature = "v4t", feature = "thumb"))]
+        Op::ThumbV4T(x) => (x != v4t::thumb::Opcode::Illegal).then(|| x.mnemonic()),
+        #[cfg(all(feature = "v5te", feature = "arm"))]
+        Op::ArmV5Te(x) => (x != v5te::arm::Opcode::Illegal).then(|| x.mnemonic()),
+        #[cfg(all(feature = "v5te", feature = "thumb"))]
+        Op::ThumbV5Te(x) => (x != v5te::thumb::Opcode::Illegal).then(|| x.mnemonic()),
+        #[cfg(all(feature = "v6k", feature = "arm"))]
+        Op::ArmV6K(x) => (x != v6k::arm::Opcode::Illegal).then(|| x.mnemonic()),
+        #[cfg(all(feature = "v6k", feature = "thumb"))]
+        Op::ThumbV6K(x) => (x != v6k::thumb::Opcode::Illegal).then(|| x.mnemonic()),
+        Op::Data => None,
+    }
+}
+
+/// Returns the variant name of `op` if it's a unified syntax (UAL) alias, see `Opcode::is_alias`
+#[cfg(any(feature = "arm", feature = "thumb"))]
+fn alias_variant(op: Op) -> Option<&'static str> {
+    match op {
+        #[cfg(all(feature = "v4t", feature = "arm"))]
+        Op::ArmV4T(x) => x.is_alias().then(|| x.variant_name()),
+        #[cfg(all(feature = "v4t", feature = "thumb"))]
+        Op::ThumbV4T(x) => x.is_alias().then(|| x.variant_name()),
+        #[cfg(all(feature = "v5te", feature = "arm"))]
+        Op::ArmV5Te(x) => x.is_alias().then(|| x.variant_name()),
+        #[cfg(all(feature = "v5te", feature = "thumb"))]
+        Op::ThumbV5Te(x) => x.is_alias().then(|| x.variant_name()),
+        #[cfg(all(feature = "v6k", feature = "arm"))]
+        Op::ArmV6K(x) => x.is_alias().then(|| x.variant_name()),
+        #[cfg(all(feature = "v6k", feature = "thumb"))]
+        Op::ThumbV6K(x) => x.is_alias().then(|| x.variant_name()),
+        Op::Data => None,
+    }
+}
+
+fn op_mode(op: Op) -> ParseMode {
+    match op {
+        #[cfg(all(feature = "v4t", feature = "arm"))]
+        Op::ArmV4T(_) => ParseMode::Arm,
+        #[cfg(all(feature = "v4t", feature = "thumb"))]
+        Op::ThumbV4T(_) => ParseMode::Thumb,
+        #[cfg(all(feature = "v5te", feature = "arm"))]
+        Op::ArmV5Te(_) => ParseMode::Arm,
+        #[cfg(all(feature = "v5te", feature = "thumb"))]
+        Op::ThumbV5Te(_) => ParseMode::Thumb,
+        #[cfg(all(feature = "v6k", feature = "arm"))]
+        Op::ArmV6K(_) => ParseMode::Arm,
+        #[cfg(all(feature = "v6k", feature = "thumb"))]
+        Op::ThumbV6K(_) => ParseMode::Thumb,
+        Op::Data => ParseMode::Data,
+    }
+}
+
+/// A sequence of instructions which is only entered at the start and only left at the end
+#[derive(Debug)]
+pub struct Block {
+    /// Address of the first instruction
+    pub start: u32,
+    /// Address after the last instruction
+    pub end: u32,
+    /// Decoded instructions in this block
+    pub instructions: Vec<(u32, Op, ParsedIns)>,
+    /// Control flow of the last instruction
+    pub flow: Flow,
+    /// Start addresses of the blocks which may execute after this block
+    pub successors: Vec<u32>,
+}
+
+/// Blocks reachable from an entry point without following calls
+#[derive(Debug)]
+pub struct Function {
+    /// Entry point address
+    pub entry: u32,
+    /// Indices into [`Cfg::blocks`], sorted by address
+    pub blocks: Vec<usize>,
+}
+
+/// Control flow graph produced by [`segment`]
+#[derive(Debug, Default)]
+pub struct Cfg {
+    /// All discovered blocks, sorted by address
+    pub blocks: Vec<Block>,
+    /// One function per entry point, in the order the entry points were given
+    pub functions: Vec<Function>,
+    /// Addresses of indirect branches whose destinations are unknown
+    pub unresolved: Vec<u32>,
+    /// Addresses of direct branches and calls whose destinations are outside of the code
+    pub dangling: Vec<u32>,
+}
+
+impl Cfg {
+    /// Returns the index of the block starting at `address`
+    pub fn block_index(&self, address: u32) -> Option<usize> {
+        self.blocks.binary_search_by_key(&address, |block| block.start).ok()
+    }
+
+    /// Returns the block starting at `address`
+    pub fn block(&self, address: u32) -> Option<&Block> {
+        self.block_index(address).map(|index| &self.blocks[index])
+    }
+
+    /// Returns the block containing the instruction at `address`
+    pub fn block_containing(&self, address: u32) -> Option<&Block> {
+        let index = self.blocks.partition_point(|block| block.start <= address).checked_sub(1)?;
+        let block = &self.blocks[index];
+        (address < block.end).then_some(block)
+    }
+}
+
+/// Disassembles `code` by recursive traversal from the entry points, following fall-through and direct branches.
+/// Calls and branches to addresses outside `code` are not followed. The version, mode, endianness and flags are taken
+/// from `parser`, while its address and data are ignored in favor of `base` and `code`.
+pub fn segment(code: &[u8], base: u32, parser: &Parser, entries: &[u32]) -> Cfg {
+    segment_with(parser, entries, |address| {
+        let offset = address.checked_sub(base)? as usize;
+        code.get(offset..).filter(|code| !code.is_empty())
+    })
+}
+
+/// Like [`segment`], but for a file with several segments loaded at different addresses, such as overlays. Branches
+/// between segments are followed, and targets which are not in any segment of `map` are listed in [`Cfg::dangling`].
+pub fn segment_mapped(file: &[u8], map: &MemoryMap, parser: &Parser, entries: &[u32]) -> Cfg {
+    segment_with(parser, entries, |address| {
+        map.bytes_from(file, address).filter(|code| !code.is_empty())
+    })
+}
+
+/// Traverses the code from the entry points, where `bytes_at` returns the bytes from an address to the end of the
+/// contiguous code containing it
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+fn segment_with<'a>(parser: &Parser, entries: &[u32], bytes_at: impl Fn(u32) -> Option<&'a [u8]>) -> Cfg {
+    let alignment = parser.mode.instruction_size(0) as u32;
+    let mut decoded = BTreeMap::new();
+    let mut leaders = BTreeSet::new();
+    let mut dangling = BTreeSet::new();
+    let mut queue = entries.to_vec();
+
+    while let Some(start) = queue.pop() {
+        let Some(code) = bytes_at(start) else {
+            continue;
+        };
+        if start % alignment != 0 {
+            continue;
+        }
+        leaders.insert(start);
+        let mut parser = Parser::new(parser.version, parser.mode, start, parser.endian, parser.flags, code);
+        while !decoded.contains_key(&parser.address) {
+            let Some(code) = parser.peek_code() else {
+                break;
+            };
+            let Some((address, op, ins)) = parser.next() else {
+                break;
+            };
+            let flow = Flow::classify(address, code, op, &ins);
+            decoded.insert(address, (op, ins, flow, parser.address));
+
+            match flow {
+                Flow::Branch { target, .. } | Flow::Call { target: Some(target) } if bytes_at(target).is_none() => {
+                    dangling.insert(address);
+                }
+                Flow::Branch { target, .. } => queue.push(target),
+                _ => {}
+            }
+            if flow.is_terminator() {
+                if flow.falls_through() {
+                    queue.push(parser.address);
+                }
+                break;
+            }
+        }
+    }
+
+    let mut cfg = Cfg {
+        dangling: dangling.into_iter().collect(),
+        ..Default::default()
+    };
+    let mut next_address = None;
+    for (address, (op, ins, flow, end)) in decoded {
+        if let Flow::Indirect { .. } = flow {
+            cfg.unresolved.push(address);
+        }
+        match cfg.blocks.last_mut() {
+            Some(block) if next_address == Some(address) && !leaders.contains(&address) => {
+                block.instructions.push((address, op, ins));
+                block.end = end;
+                block.flow = flow;
+            }
+            _ => cfg.blocks.push(Block {
+                start: address,
+                end,
+                instructions: vec![(address, op, ins)],
+                flow,
+                successors: vec![],
+            }),
+        }
+        next_address = (!flow.is_terminator()).then_some(end);
+    }
+
+    let starts: BTreeSet<u32> = cfg.blocks.iter().map(|block| block.start).collect();
+    for block in &mut cfg.blocks {
+        if let Flow::Branch { target, .. } = block.flow {
+            if starts.contains(&target) {
+                block.successors.push(target);
+            }
+        }
+        if block.flow.falls_through() && starts.contains(&block.end) && !block.successors.contains(&block.end) {
+            block.successors.push(block.end);
+        }
+    }
+
+    for &entry in entries {
+        let mut blocks = BTreeSet::new();
+        let mut queue = vec![entry];
+        while let Some(address) = queue.pop() {
+            let Some(index) = cfg.block_index(address) else {
+                continue;
+            };
+            if blocks.insert(index) {
+                queue.extend(&cfg.blocks[index].successors);
+            }
+        }
+        cfg.functions.push(Function {
+            entry,
+            blocks: blocks.into_iter().collect(),
+        });
+    }
+
+    cfg
+}
+
+/// Without an instruction set and a version there is no code to traverse
+#[cfg(not(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+)))]
+fn segment_with<'a>(_parser: &Parser, _entries: &[u32], _bytes_at: impl Fn(u32) -> Option<&'a [u8]>) -> Cfg {
+    Cfg::default()
+}
+
+/// Registers which a function must preserve according to the AAPCS, `r4` to `r11`
+pub const CALLEE_SAVED: RegisterSet = RegisterSet(0x0ff0);
+
+/// Register saves and stack frame of a function, found by [`frame_summary`]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct FrameSummary {
+    /// Callee-saved registers which are stored on the stack anywhere in the function
+    pub saved: RegisterSet,
+    /// Callee-saved registers which are loaded from the stack on every exit
+    pub restored: RegisterSet,
+    /// True if LR is stored on the stack
+    pub saves_lr: bool,
+    /// Largest number of bytes below the SP of the entry, including both saved registers and local variables
+    pub frame_size: u32,
+    /// Problems found in the frame, sorted by address
+    pub mismatches: Vec<FrameMismatch>,
+}
+
+/// Problem in the frame of a function, see [`FrameSummary::mismatches`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FrameMismatch {
+    /// Callee-saved registers which are saved are not restored before the exit at `exit`
+    NotRestored { exit: u32, regs: RegisterSet },
+    /// SP at the exit at `exit` differs from the SP of the entry by `offset` bytes, or `None` if it's unknown
+    UnbalancedStack { exit: u32, offset: Option<i32> },
+    /// Paths with different SP offsets join at the block starting at `block`
+    StackConflict { block: u32 },
+}
+
+impl FrameMismatch {
+    fn address(&self) -> u32 {
+        match *self {
+            Self::NotRestored { exit, .. } | Self::UnbalancedStack { exit, .. } => exit,
+            Self::StackConflict { block } => block,
+        }
+    }
+}
+
+/// State of the stack at a point in a function, see [`frame_summary`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct FrameState {
+    /// Offset of SP from the SP of the entry, `None` if it's unknown
+    sp: Option<i32>,
+    /// Offsets from the SP of the entry held by other registers, like a frame pointer set up by `add r7, sp, #0`
+    copies: [Option<i32>; 16],
+    /// Registers loaded from the stack which haven't been overwritten since
+    restored: RegisterSet,
+}
+
+impl FrameState {
+    fn entry() -> Self {
+        Self {
+            sp: Some(0),
+            copies: [None; 16],
+            restored: RegisterSet::default(),
+        }
+    }
+
+    /// Merges the state of another path into this one, and returns false if the SP offsets differ
+    fn merge(&mut self, other: &Self) -> bool {
+        let conflict = self.sp != other.sp;
+        if conflict {
+            self.sp = None;
+        }
+        for (copy, other) in self.copies.iter_mut().zip(other.copies) {
+            if *copy != other {
+                *copy = None;
+            }
+        }
+        self.restored = self.restored.intersection(other.restored);
+        !conflict
+    }
+
+    /// Returns the SP offset held by `reg`
+    fn offset(&self, reg: Register) -> Option<i32> {
+        match reg {
+            Register::Sp => self.sp,
+            Register::Illegal => None,
+            reg => self.copies[reg as usize],
+        }
+    }
+
+    /// Applies an instruction to the state, and returns the registers it stores on the stack
+    fn apply(&mut self, op: Op, ins: &ParsedIns) -> RegisterSet {
+        let value = match DataProcessingView::try_from(ins) {
+            Ok(DataProcessingView {
+                op: op @ (DataOp::Add | DataOp::Sub),
+                rn: Some(rn),
+                operand2: Operand2::Imm(imm),
+                ..
+            }) => {
+                let imm = if op == DataOp::Sub {
+                    (imm as i32).wrapping_neg()
+                } else {
+                    imm as i32
+                };
+                self.offset(rn).map(|offset| offset.wrapping_add(imm))
+            }
+            Ok(DataProcessingView {
+                op: DataOp::Mov,
+                operand2: Operand2::Reg(rm),
+                ..
+            }) => self.offset(rm),
+            _ => None,
+        };
+        let transfer = stack_transfer(ins);
+        if let Some((_, _, delta)) = transfer {
+            self.sp = self.sp.map(|sp| sp.wrapping_add(delta));
+        }
+        let loaded = match transfer {
+            Some((true, regs, _)) => regs,
+            _ => RegisterSet::default(),
+        };
+        for reg in ins.defs(op).union(loaded).iter() {
+            match reg {
+                Register::Sp if transfer.is_none() => self.sp = value,
+                Register::Sp | Register::Illegal => {}
+                reg => {
+                    self.copies[reg as usize] = value;
+                    self.restored.remove(reg);
+                }
+            }
+        }
+        self.restored = self.restored.union(loaded);
+        match transfer {
+            Some((false, regs, _)) => regs,
+            _ => RegisterSet::default(),
+        }
+    }
+}
+
+/// Returns whether an instruction is a load, the registers it transfers, and the change to SP, if it transfers registers
+/// to or from the stack and updates SP, e.g. `push {r4, lr}` or `ldr r4, [sp], #0x4`
+fn stack_transfer(ins: &ParsedIns) -> Option<(bool, RegisterSet, i32)> {
+    let mnemonic = ins.mnemonic_base();
+    if let Ok(view) = LoadStoreView::try_from(ins) {
+        let regs = RegisterSet::from_iter([Some(view.rt), view.rt2].into_iter().flatten());
+        return match (view.base, view.writeback, view.width, view.offset) {
+            (Register::Sp, true, Width::Word | Width::Doubleword, AddrOffset::Imm(delta)) => Some((view.load, regs, delta)),
+            _ => None,
+        };
+    }
+    let (load, rest) = match mnemonic {
+        "push" => (false, "db"),
+        "pop" => (true, "ia"),
+        _ => match (mnemonic.strip_prefix("ldm"), mnemonic.strip_prefix("stm")) {
+            (Some(rest), _) => (true, rest),
+            (_, Some(rest)) => (false, rest),
+            _ => return None,
+        },
+    };
+    let (list,) = match mnemonic {
+        "push" | "pop" => ins_match!(ins, _ {list}),
+        _ => ins_match!(ins, _ sp!, {list}),
+    }?;
+    let regs = list.registers();
+    let size = regs.len() as i32 * 4;
+    match rest {
+        "" | "ia" | "ib" | "fd" | "ed" => Some((load, regs, size)),
+        "da" | "db" | "fa" | "ea" => Some((load, regs, -size)),
+        _ => None,
+    }
+}
+
+/// Effect of a block on the stack, see [`run_frame_block`]
+struct BlockFrame {
+    /// State at the end of the block
+    exit: FrameState,
+    /// State passed to the successors, which doesn't include a conditional last instruction like `popeq {r4, pc}`
+    successors: FrameState,
+    /// Registers stored on the stack
+    saved: RegisterSet,
+    /// Largest number of bytes below the SP of the entry
+    depth: i32,
+}
+
+/// Applies the instructions of a block to the state at its start
+fn run_frame_block(block: &Block, mut state: FrameState) -> BlockFrame {
+    let mut saved = RegisterSet::default();
+    let mut depth = state.sp.map_or(0, |sp| -sp);
+    let mut successors = None;
+    for (index, (_, op, ins)) in block.instructions.iter().enumerate() {
+        if index + 1 == block.instructions.len() && ins.condition() != Condition::Al {
+            successors = Some(state.clone());
+        }
+        saved = saved.union(state.apply(*op, ins));
+        depth = depth.max(state.sp.map_or(0, |sp| -sp));
+    }
+    BlockFrame {
+        successors: successors.unwrap_or_else(|| state.clone()),
+        exit: state,
+        saved,
+        depth,
+    }
+}
+
+/// Summarizes the register saves and stack frame of `function`, a function of `cfg`. The SP offset and the restored
+/// registers are tracked through the blocks of the function and merged where paths join, without telling paths apart.
+///
+/// Exits are returns and branches to blocks outside the function, such as tail calls. Indirect branches are not exits,
+/// as they may be jump tables. SP is tracked through pushes, pops, loads and stores with writeback, and additions and
+/// subtractions of immediates, including through registers copied from SP like frame pointers. Any other write to SP,
+/// such as `ldmdb r11, {r4, r11, sp, pc}`, makes its offset unknown.
+pub fn frame_summary(cfg: &Cfg, function: &Function) -> FrameSummary {
+    let mut entries = BTreeMap::new();
+    let mut conflicts = BTreeSet::new();
+    let mut queue = vec![];
+    if let Some(entry) = cfg.block_index(function.entry) {
+        entries.insert(entry, FrameState::entry());
+        queue.push(entry);
+    }
+    while let Some(index) = queue.pop() {
+        let block = &cfg.blocks[index];
+        let state = run_frame_block(block, entries[&index].clone()).successors;
+        for &successor in block.successors.iter() {
+            let Some(successor) = cfg.block_index(successor).filter(|index| function.blocks.contains(index)) else {
+                continue;
+            };
+            match entries.get_mut(&successor) {
+                Some(entry) => {
+                    let before = entry.clone();
+                    if !entry.merge(&state) {
+                        conflicts.insert(cfg.blocks[successor].start);
+                    }
+                    if *entry != before {
+                        queue.push(successor);
+                    }
+                }
+                None => {
+                    entries.insert(successor, state.clone());
+                    queue.push(successor);
+                }
+            }
+        }
+    }
+
+    let mut summary = FrameSummary {
+        restored: CALLEE_SAVED,
+        mismatches: conflicts
+            .into_iter()
+            .map(|block| FrameMismatch::StackConflict { block })
+            .collect(),
+        ..Default::default()
+    };
+    let mut exits = vec![];
+    for (&index, state) in &entries {
+        let block = &cfg.blocks[index];
+        let frame = run_frame_block(block, state.clone());
+        summary.saved = summary.saved.union(frame.saved);
+        summary.frame_size = summary.frame_size.max(frame.depth.max(0) as u32);
+        let is_exit = match block.flow {
+            Flow::Return { .. } => true,
+            Flow::Branch { target, .. } => cfg.block_index(target).is_none_or(|index| !function.blocks.contains(&index)),
+            _ => false,
+        };
+        if let (true, Some((address, _, _))) = (is_exit, block.instructions.last()) {
+            exits.push((*address, frame.exit));
+        }
+    }
+    summary.saves_lr = summary.saved.contains(Register::Lr);
+    summary.saved = summary.saved.intersection(CALLEE_SAVED);
+    for (exit, state) in exits {
+        summary.restored = summary.restored.intersection(state.restored);
+        let missing = summary.saved.difference(state.restored);
+        if !missing.is_empty() {
+            summary.mismatches.push(FrameMismatch::NotRestored { exit, regs: missing });
+        }
+        if state.sp != Some(0) {
+            summary
+                .mismatches
+                .push(FrameMismatch::UnbalancedStack { exit, offset: state.sp });
+        }
+    }
+    summary.restored = summary.restored.intersection(summary.saved);
+    summary.mismatches.sort_by_key(FrameMismatch::address);
+    summary
+}
+
+/// Why the target of a PC-relative load is suspicious, see [`check_literals`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LiteralIssueKind {
+    /// Some of the loaded bytes are not in any segment of the memory map
+    Unmapped,
+    /// The target is in a region which the mode map marks as ARM or Thumb code
+    InCode,
+    /// The target is not aligned to the size of the load
+    Misaligned,
+}
+
+/// A PC-relative load whose target is suspicious, found by [`check_literals`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LiteralIssue {
+    /// Address of the load instruction
+    pub address: u32,
+    /// Address of the loaded literal
+    pub target: u32,
+    /// Number of bytes loaded
+    pub size: u32,
+    pub kind: LiteralIssueKind,
+}
+
+/// Checks the PC-relative loads in each segment of `map`, e.g. after relocating or editing code by hand, and returns the
+/// loads whose literal is out of range, in code or misaligned. A load may have more than one issue. The version,
+/// endianness and flags are taken from `parser`.
+///
+/// `modes` switches the parse mode at the given addresses like in [`crate::window_with_modes`], and `parser.mode` is
+/// used before the first entry. [`ParseMode::Data`] regions are not decoded. Literals are only reported as
+/// [`LiteralIssueKind::InCode`] if an entry of `modes` marks them as code, so literal pools without mapping symbols are
+/// accepted.
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+pub fn check_literals(file: &[u8], map: &MemoryMap, parser: &Parser, modes: &[(u32, ParseMode)]) -> Vec<LiteralIssue> {
+    let mode_entry = |address: u32| {
+        let index = modes.partition_point(|&(start, _)| start <= address);
+        index.checked_sub(1).map(|index| modes[index].1)
+    };
+    let mut issues = vec![];
+    for segment in map.segments.iter() {
+        let addresses = segment.addresses();
+        let Some(bytes) = file.get(segment.file_range.clone()) else {
+            continue;
+        };
+        let mut starts = vec![addresses.start];
+        starts.extend(
+            modes
+                .iter()
+                .map(|&(start, _)| start)
+                .filter(|start| addresses.contains(start) && *start > addresses.start),
+        );
+        let ends = starts.iter().skip(1).copied().chain([addresses.end]);
+        for (start, end) in starts.iter().copied().zip(ends) {
+            let mode = mode_entry(start).unwrap_or(parser.mode);
+            if mode == ParseMode::Data {
+                continue;
+            }
+            let code = &bytes[(start - addresses.start) as usize..(end - addresses.start) as usize];
+            let region = Parser::new(parser.version, mode, start, parser.endian, parser.flags, code);
+            for (address, _, ins) in region {
+                let size = match ins.mnemonic_base() {
+                    "ldrb" | "ldrsb" => 1,
+                    "ldrh" | "ldrsh" => 2,
+                    "ldrd" => 8,
+                    mnemonic if mnemonic.starts_with("ldr") => 4,
+                    _ => continue,
+                };
+                let Some(target) = ins.pc_relative_address(address, mode) else {
+                    continue;
+                };
+                let mut report = |kind| {
+                    issues.push(LiteralIssue {
+                        address,
+                        target,
+                        size,
+                        kind,
+                    })
+                };
+                let last = target.wrapping_add(size - 1);
+                if !map
+                    .segment(target)
+                    .is_some_and(|segment| last >= target && segment.contains(last))
+                {
+                    report(LiteralIssueKind::Unmapped);
+                } else if mode_entry(target).is_some_and(|mode| mode != ParseMode::Data) {
+                    report(LiteralIssueKind::InCode);
+                }
+                if target % size != 0 {
+                    report(LiteralIssueKind::Misaligned);
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// Without an instruction set and a version every region is data, so there are no loads to check
+#[cfg(not(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+)))]
+pub fn check_literals(_file: &[u8], _map: &MemoryMap, _parser: &Parser, _modes: &[(u32, ParseMode)]) -> Vec<LiteralIssue> {
+    vec![]
+}
+
+/// Code likelihood at or above which a sample looks like code
+const CODE_LIKELIHOOD: f32 = 0.6;
+/// Difference in code likelihood between the two byte orders needed for full confidence
+const CONFIDENT_MARGIN: f32 = 0.2;
+
+/// Guesses the byte order of `bytes` by decoding samples spread across the whole buffer in both byte orders, and comparing
+/// how much each looks like code. Returns the most likely byte order and a confidence between 0 and 1. The confidence is
+/// low if both byte orders look alike, e.g. if the buffer contains data rather than code.
+pub fn guess_endianness(bytes: &[u8], version: ArmVersion, mode: ParseMode) -> (Endian, f32) {
+    let little = code_likelihood(bytes, version, mode, Endian::Little);
+    let big = code_likelihood(bytes, version, mode, Endian::Big);
+    let (endian, best, other) = if big > little {
+        (Endian::Big, big, little)
+    } else {
+        (Endian::Little, little, big)
+    };
+    let confidence = ((best - other) / CONFIDENT_MARGIN).min(1.0) * (best / CODE_LIKELIHOOD).min(1.0);
+    (endian, confidence)
+}
+
+/// Returns the average code likelihood of instructions in windows spread evenly across `bytes`
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+fn code_likelihood(bytes: &[u8], version: ArmVersion, mode: ParseMode, endian: Endian) -> f32 {
+    /// Number of windows sampled by [`guess_endianness`]
+    const SAMPLE_WINDOWS: usize = 16;
+    /// Number of instructions decoded in each sample window
+    const SAMPLE_WINDOW_SIZE: usize = 32;
+
+    let ins_size = mode.instruction_size(0);
+    let window_size = SAMPLE_WINDOW_SIZE * ins_size;
+    let windows = (bytes.len() / window_size).clamp(1, SAMPLE_WINDOWS);
+    let stride = bytes.len() / windows / ins_size * ins_size;
+
+    let mut total = 0.0;
+    let mut count = 0;
+    for window in 0..windows {
+        let start = window * stride;
+        let end = (start + window_size).min(bytes.len());
+        let mut parser = Parser::new(version, mode, start as u32, endian, Default::default(), &bytes[start..end]);
+        while let Some(code) = parser.peek_code() {
+            let Some((_, op, ins)) = parser.next() else {
+                break;
+            };
+            total += instruction_likelihood(code, op, &ins);
+            count += 1;
+        }
+    }
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f32
+    }
+}
+
+/// Without an instruction set and a version there are no instructions, so nothing looks like code
+#[cfg(not(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+)))]
+fn code_likelihood(_bytes: &[u8], _version: ArmVersion, _mode: ParseMode, _endian: Endian) -> f32 {
+    0.0
+}
+
+/// Scores how likely an instruction is to appear in compiled code, between 0 and 1
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+fn instruction_likelihood(code: u32, op: Op, ins: &ParsedIns) -> f32 {
+    /// Mnemonics which make up most of compiled code
+    const FREQUENT_MNEMONICS: &[&str] = &[
+        "add", "adds", "b", "bl", "bx", "cmp", "ldr", "mov", "movs", "pop", "push", "str", "sub", "subs",
+    ];
+    /// Mnemonics which are common in compiled code, but also make up a large part of the encoding space
+    const COMMON_MNEMONICS: &[&str] = &[
+        "and", "ands", "ldm", "ldrb", "ldrh", "lsl", "lsls", "lsr", "lsrs", "orr", "orrs", "stm", "strb", "strh", "tst",
+    ];
+
+    let Some(mnemonic) = base_mnemonic(op) else {
+        return 0.0;
+    };
+    if ins.is_illegal() {
+        return 0.0;
+    }
+    let score = if FREQUENT_MNEMONICS.contains(&mnemonic) {
+        1.0
+    } else if COMMON_MNEMONICS.contains(&mnemonic) {
+        0.5
+    } else {
+        0.25
+    };
+    match op_mode(op) {
+        #[cfg(feature = "arm")]
+        ParseMode::Arm => match code >> 28 {
+            0xe => score,
+            0xf => score * 0.5,
+            _ => score * 0.75,
+        },
+        _ => {
+            // Only ARM instructions have a condition field to score
+            #[cfg(not(feature = "arm"))]
+            let _ = code;
+            score
+        }
+    }
+}
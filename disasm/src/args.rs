@@ -1,8 +1,13 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused)]
 // Generated by unarm-generator. Do not edit!
-pub type Arguments = [Argument; 6usize];
-#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+/// Maximum number of arguments of an instruction
+pub const MAX_ARGS: usize = 6usize;
+pub type Arguments = [Argument; MAX_ARGS];
+/// Arguments are ordered by variant, then by value. Like all `Ord` impls in this crate, the order follows the
+/// representation and not any meaning of the values, but it is deterministic and consistent with `Eq`.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Argument {
     #[default]
     None,
@@ -16,6 +21,18 @@ pub enum Argument {
     StatusReg(StatusReg),
     /// Status register mask
     StatusMask(StatusMask),
+    /// Single-precision VFP register
+    SReg(SReg),
+    /// Double-precision VFP register
+    DReg(DReg),
+    /// One 32-bit half of a double-precision VFP register
+    DRegIndex(DRegIndex),
+    /// List of consecutive single-precision VFP registers
+    SRegList(SRegList),
+    /// List of consecutive double-precision VFP registers
+    DRegList(DRegList),
+    /// VFP system register
+    VfpSysReg(VfpSysReg),
     /// Shift operation
     Shift(Shift),
     /// Immediate shift offset
@@ -47,7 +64,126 @@ pub enum Argument {
     /// Endian specifier
     Endian(Endian),
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// The variant of an `Argument`, without its value
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArgumentKind {
+    #[default]
+    None,
+    /// General-purpose register
+    Reg,
+    /// List of general-purpose registers
+    RegList,
+    /// Coprocessor register
+    CoReg,
+    /// Status register
+    StatusReg,
+    /// Status register mask
+    StatusMask,
+    /// Single-precision VFP register
+    SReg,
+    /// Double-precision VFP register
+    DReg,
+    /// One 32-bit half of a double-precision VFP register
+    DRegIndex,
+    /// List of consecutive single-precision VFP registers
+    SRegList,
+    /// List of consecutive double-precision VFP registers
+    DRegList,
+    /// VFP system register
+    VfpSysReg,
+    /// Shift operation
+    Shift,
+    /// Immediate shift offset
+    ShiftImm,
+    /// Register shift offset
+    ShiftReg,
+    /// Unsigned immediate
+    UImm,
+    /// Saturation immediate
+    SatImm,
+    /// Signed immediate
+    SImm,
+    /// Signed immediate offset
+    OffsetImm,
+    /// Register offset
+    OffsetReg,
+    /// Branch destination offset
+    BranchDest,
+    /// Additional inStruction options for coprocessor
+    CoOption,
+    /// Coprocessor operation to perform (user-defined)
+    CoOpcode,
+    /// Coprocessor number
+    CoprocNum,
+    /// CPSR mode
+    CpsrMode,
+    /// CPSR flags
+    CpsrFlags,
+    /// Endian specifier
+    Endian,
+}
+impl Argument {
+    pub fn kind(&self) -> ArgumentKind {
+        match self {
+            Argument::None => ArgumentKind::None,
+            Argument::Reg(_) => ArgumentKind::Reg,
+            Argument::RegList(_) => ArgumentKind::RegList,
+            Argument::CoReg(_) => ArgumentKind::CoReg,
+            Argument::StatusReg(_) => ArgumentKind::StatusReg,
+            Argument::StatusMask(_) => ArgumentKind::StatusMask,
+            Argument::SReg(_) => ArgumentKind::SReg,
+            Argument::DReg(_) => ArgumentKind::DReg,
+            Argument::DRegIndex(_) => ArgumentKind::DRegIndex,
+            Argument::SRegList(_) => ArgumentKind::SRegList,
+            Argument::DRegList(_) => ArgumentKind::DRegList,
+            Argument::VfpSysReg(_) => ArgumentKind::VfpSysReg,
+            Argument::Shift(_) => ArgumentKind::Shift,
+            Argument::ShiftImm(_) => ArgumentKind::ShiftImm,
+            Argument::ShiftReg(_) => ArgumentKind::ShiftReg,
+            Argument::UImm(_) => ArgumentKind::UImm,
+            Argument::SatImm(_) => ArgumentKind::SatImm,
+            Argument::SImm(_) => ArgumentKind::SImm,
+            Argument::OffsetImm(_) => ArgumentKind::OffsetImm,
+            Argument::OffsetReg(_) => ArgumentKind::OffsetReg,
+            Argument::BranchDest(_) => ArgumentKind::BranchDest,
+            Argument::CoOption(_) => ArgumentKind::CoOption,
+            Argument::CoOpcode(_) => ArgumentKind::CoOpcode,
+            Argument::CoprocNum(_) => ArgumentKind::CoprocNum,
+            Argument::CpsrMode(_) => ArgumentKind::CpsrMode,
+            Argument::CpsrFlags(_) => ArgumentKind::CpsrFlags,
+            Argument::Endian(_) => ArgumentKind::Endian,
+        }
+    }
+    /// Returns true if the argument contains an `Illegal` value, such as `Register::Illegal`.
+    pub fn is_illegal(&self) -> bool {
+        match self {
+            Argument::Reg(x) => x.is_illegal(),
+            Argument::RegList(x) => x.is_illegal(),
+            Argument::CoReg(x) => x.is_illegal(),
+            Argument::StatusReg(x) => x.is_illegal(),
+            Argument::StatusMask(x) => x.is_illegal(),
+            Argument::SReg(x) => x.is_illegal(),
+            Argument::DReg(x) => x.is_illegal(),
+            Argument::DRegIndex(x) => x.is_illegal(),
+            Argument::SRegList(x) => x.is_illegal(),
+            Argument::DRegList(x) => x.is_illegal(),
+            Argument::VfpSysReg(x) => x.is_illegal(),
+            Argument::Shift(x) => x.is_illegal(),
+            Argument::ShiftImm(x) => x.is_illegal(),
+            Argument::ShiftReg(x) => x.is_illegal(),
+            Argument::OffsetImm(x) => x.is_illegal(),
+            Argument::OffsetReg(x) => x.is_illegal(),
+            Argument::CpsrMode(x) => x.is_illegal(),
+            Argument::CpsrFlags(x) => x.is_illegal(),
+            Argument::Endian(x) => x.is_illegal(),
+            _ => false,
+        }
+    }
+}
+/// General-purpose register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Register {
     Illegal = u8::MAX,
@@ -85,31 +221,153 @@ pub enum Register {
     Pc = 15,
 }
 impl Register {
-    pub fn parse(value: u32) -> Self {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
         if value <= 15 {
-            unsafe { std::mem::transmute::<u8, Self>(value as u8) }
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
         } else {
-            Self::Illegal
+            None
         }
     }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// Status register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum StatusReg {
     Illegal = u8::MAX,
     Cpsr = 0,
     Spsr = 1,
+    /// NZCV flags of the application status register, only the destination of `vmrs`
+    ApsrNzcv = 2,
 }
 impl StatusReg {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
+        if value <= 2 {
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
+        } else {
+            None
+        }
+    }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
     pub fn parse(value: u32) -> Self {
-        if value <= 1 {
-            unsafe { std::mem::transmute::<u8, Self>(value as u8) }
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
+}
+/// Single-precision VFP register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum SReg {
+    Illegal = u8::MAX,
+    S0 = 0,
+    S1 = 1,
+    S2 = 2,
+    S3 = 3,
+    S4 = 4,
+    S5 = 5,
+    S6 = 6,
+    S7 = 7,
+    S8 = 8,
+    S9 = 9,
+    S10 = 10,
+    S11 = 11,
+    S12 = 12,
+    S13 = 13,
+    S14 = 14,
+    S15 = 15,
+    S16 = 16,
+    S17 = 17,
+    S18 = 18,
+    S19 = 19,
+    S20 = 20,
+    S21 = 21,
+    S22 = 22,
+    S23 = 23,
+    S24 = 24,
+    S25 = 25,
+    S26 = 26,
+    S27 = 27,
+    S28 = 28,
+    S29 = 29,
+    S30 = 30,
+    S31 = 31,
+}
+impl SReg {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
+        if value <= 31 {
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
+        } else {
+            None
+        }
+    }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
+}
+/// Double-precision VFP register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum DReg {
+    Illegal = u8::MAX,
+    D0 = 0,
+    D1 = 1,
+    D2 = 2,
+    D3 = 3,
+    D4 = 4,
+    D5 = 5,
+    D6 = 6,
+    D7 = 7,
+    D8 = 8,
+    D9 = 9,
+    D10 = 10,
+    D11 = 11,
+    D12 = 12,
+    D13 = 13,
+    D14 = 14,
+    D15 = 15,
+}
+impl DReg {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
+        if value <= 15 {
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
         } else {
-            Self::Illegal
+            None
         }
     }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// Shift operation
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Shift {
     Illegal = u8::MAX,
@@ -125,15 +383,26 @@ pub enum Shift {
     Rrx = 4,
 }
 impl Shift {
-    pub fn parse(value: u32) -> Self {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
         if value <= 4 {
-            unsafe { std::mem::transmute::<u8, Self>(value as u8) }
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
         } else {
-            Self::Illegal
+            None
         }
     }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// General-purpose register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Reg {
     /// Use as base register
     pub deref: bool,
@@ -142,14 +411,30 @@ pub struct Reg {
     /// When used as a base register, update this register's value
     pub writeback: bool,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl Reg {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.reg.is_illegal()
+    }
+}
+/// List of general-purpose registers
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegList {
     /// Bitfield of registers
     pub regs: u32,
     /// Access user-mode registers from elevated mode
     pub user_mode: bool,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl RegList {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        false
+    }
+}
+/// Coprocessor register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum CoReg {
     Illegal = u8::MAX,
@@ -171,15 +456,26 @@ pub enum CoReg {
     C15 = 15,
 }
 impl CoReg {
-    pub fn parse(value: u32) -> Self {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
         if value <= 15 {
-            unsafe { std::mem::transmute::<u8, Self>(value as u8) }
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
         } else {
-            Self::Illegal
+            None
         }
     }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// Status register mask
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusMask {
     /// Control field mask (c)
     pub control: bool,
@@ -192,28 +488,140 @@ pub struct StatusMask {
     /// Status field mask (s)
     pub status: bool,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl StatusMask {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.reg.is_illegal()
+    }
+}
+/// One 32-bit half of a double-precision VFP register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DRegIndex {
+    /// 0 for the lower half, 1 for the upper half
+    pub index: u32,
+    /// Double-precision register
+    pub reg: DReg,
+}
+impl DRegIndex {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.reg.is_illegal()
+    }
+}
+/// List of consecutive single-precision VFP registers
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SRegList {
+    /// Number of registers
+    pub count: u32,
+    /// First register
+    pub first: SReg,
+}
+impl SRegList {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.first.is_illegal()
+    }
+}
+/// List of consecutive double-precision VFP registers
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DRegList {
+    /// Number of registers
+    pub count: u32,
+    /// First register
+    pub first: DReg,
+}
+impl DRegList {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.first.is_illegal()
+    }
+}
+/// VFP system register
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum VfpSysReg {
+    Illegal = u8::MAX,
+    /// Floating-point System ID register
+    Fpsid = 0,
+    /// Floating-point Status and Control register
+    Fpscr = 1,
+    /// Floating-point Exception register
+    Fpexc = 8,
+    /// Floating-point Instruction register
+    Fpinst = 9,
+}
+impl VfpSysReg {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
+        match value {
+            0 => Some(Self::Fpsid),
+            1 => Some(Self::Fpscr),
+            8 => Some(Self::Fpexc),
+            9 => Some(Self::Fpinst),
+            _ => None,
+        }
+    }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
+}
+/// Immediate shift offset
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftImm {
     /// Immediate shift offset
     pub imm: u32,
     /// Shift operation
     pub op: Shift,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl ShiftImm {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.op.is_illegal()
+    }
+}
+/// Register shift offset
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShiftReg {
     /// Shift operation
     pub op: Shift,
     /// Register shift offset
     pub reg: Register,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl ShiftReg {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.op.is_illegal() || self.reg.is_illegal()
+    }
+}
+/// Signed immediate offset
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OffsetImm {
     /// If true, add the offset to the base register and write-back AFTER derefencing the base register
     pub post_indexed: bool,
     /// Offset value
     pub value: i32,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl OffsetImm {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        false
+    }
+}
+/// Register offset
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OffsetReg {
     /// If true, add the offset to the base register, otherwise subtract
     pub add: bool,
@@ -222,14 +630,30 @@ pub struct OffsetReg {
     /// Offset value
     pub reg: Register,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl OffsetReg {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        self.reg.is_illegal()
+    }
+}
+/// CPSR mode
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpsrMode {
     /// Mode bits
     pub mode: u32,
     /// Writeback to base register
     pub writeback: bool,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl CpsrMode {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        false
+    }
+}
+/// CPSR flags
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpsrFlags {
     /// Imprecise data abort
     pub a: bool,
@@ -240,7 +664,15 @@ pub struct CpsrFlags {
     /// IRQ interrupt
     pub i: bool,
 }
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl CpsrFlags {
+    /// Returns true if any member is `Illegal`.
+    pub fn is_illegal(self) -> bool {
+        false
+    }
+}
+/// Endian specifier
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Endian {
     Illegal = u8::MAX,
@@ -250,11 +682,20 @@ pub enum Endian {
     Be = 1,
 }
 impl Endian {
-    pub fn parse(value: u32) -> Self {
+    /// Returns `None` if `value` has no variant.
+    pub fn try_parse(value: u32) -> Option<Self> {
         if value <= 1 {
-            unsafe { std::mem::transmute::<u8, Self>(value as u8) }
+            Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
         } else {
-            Self::Illegal
+            None
         }
     }
+    /// Returns `Illegal` if `value` has no variant, see [`Self::try_parse`].
+    pub fn parse(value: u32) -> Self {
+        Self::try_parse(value).unwrap_or(Self::Illegal)
+    }
+    /// Returns true if this is the `Illegal` variant.
+    pub fn is_illegal(self) -> bool {
+        self == Self::Illegal
+    }
 }
@@ -0,0 +1,107 @@
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+#[cfg(feature = "v4t")]
+use crate::v4t;
+#[cfg(feature = "v5te")]
+use crate::v5te;
+#[cfg(feature = "v6k")]
+use crate::v6k;
+use crate::{ArmVersion, Op, ParseMode, ParsedIns, Parser};
+
+/// Diagnostics of [`Parser::parse_catching`]. Owned by the caller and shared by reference, so that there is no global
+/// state, see [`Parser::with_stats`].
+#[derive(Debug, Default)]
+pub struct ParseStats {
+    caught_panics: AtomicU64,
+}
+
+impl ParseStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of panics which were turned into illegal instructions
+    pub fn caught_panics(&self) -> u64 {
+        self.caught_panics.load(Ordering::Relaxed)
+    }
+}
+
+impl<'a> Parser<'a> {
+    /// Counts the panics caught by [`Self::parse_catching`] in `stats`
+    pub fn with_stats(mut self, stats: &'a ParseStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Decodes and parses one instruction code in the mode and version of the parser, and returns an illegal instruction
+    /// if that panics. This is a safety net for untrusted input in long-running processes, decoding should never panic.
+    ///
+    /// Panics are counted in the [`ParseStats`] given to [`Self::with_stats`]. The panic hook still runs, so the message
+    /// is printed unless the hook is replaced. The result is built in a local value and only returned if parsing
+    /// finished, so no partially parsed instruction leaks out. The second half of a Thumb BL pair is not combined, as
+    /// only one code is given.
+    pub fn parse_catching(&self, code: u32) -> ParsedIns {
+        self.catch_parse(code, |parser, code| parser.parse_code(code))
+    }
+
+    /// Implementation of [`Self::parse_catching`] with any parse function. Public to test it with a function which
+    /// panics.
+    #[doc(hidden)]
+    pub fn catch_parse(&self, code: u32, parse: impl FnOnce(&Self, u32) -> ParsedIns) -> ParsedIns {
+        // The parser is only read, and the result is discarded if `parse` panics, so no broken state can be observed
+        match catch_unwind(AssertUnwindSafe(|| parse(self, code))) {
+            Ok(parsed) => parsed,
+            Err(_) => {
+                if let Some(stats) = self.stats {
+                    stats.caught_panics.fetch_add(1, Ordering::Relaxed);
+                }
+                ParsedIns::illegal()
+            }
+        }
+    }
+
+    fn parse_code(&self, code: u32) -> ParsedIns {
+        let flags = &self.flags;
+        let (op, parsed) = match (self.version, self.mode) {
+            #[cfg(all(feature = "v4t", feature = "arm"))]
+            (ArmVersion::V4T | ArmVersion::V4, ParseMode::Arm) => {
+                let ins = v4t::arm::Ins::new(code, flags);
+                (Op::ArmV4T(ins.op), ins.parse(flags))
+            }
+            #[cfg(all(feature = "v4t", feature = "thumb"))]
+            (ArmVersion::V4T | ArmVersion::V4, ParseMode::Thumb) => {
+                let ins = v4t::thumb::Ins::new(code, flags);
+                (Op::ThumbV4T(ins.op), ins.parse(flags))
+            }
+            #[cfg(all(feature = "v5te", feature = "arm"))]
+            (ArmVersion::V5Te | ArmVersion::V5T, ParseMode::Arm) => {
+                let ins = v5te::arm::Ins::new(code, flags);
+                (Op::ArmV5Te(ins.op), ins.parse(flags))
+            }
+            #[cfg(all(feature = "v5te", feature = "thumb"))]
+            (ArmVersion::V5Te | ArmVersion::V5T, ParseMode::Thumb) => {
+                let ins = v5te::thumb::Ins::new(code, flags);
+                (Op::ThumbV5Te(ins.op), ins.parse(flags))
+            }
+            #[cfg(all(feature = "v6k", feature = "arm"))]
+            (ArmVersion::V6K, ParseMode::Arm) => {
+                let ins = v6k::arm::Ins::new(code, flags);
+                (Op::ArmV6K(ins.op), ins.parse(flags))
+            }
+            #[cfg(all(feature = "v6k", feature = "thumb"))]
+            (ArmVersion::V6K, ParseMode::Thumb) => {
+                let ins = v6k::thumb::Ins::new(code, flags);
+                (Op::ThumbV6K(ins.op), ins.parse(flags))
+            }
+            (_, ParseMode::Data) => (Op::Data, ParsedIns::data(code, self.mode.instruction_size(self.address))),
+        };
+        if self.version.supports(op) {
+            parsed
+        } else {
+            ParsedIns::illegal()
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Models of the CP15 registers which a core implements, to find `mcr` and `mrc` instructions which access a register
+//! that would be undefined at runtime. See [`ParsedIns::unimplemented_coproc_access`] and
+//! [`find_unimplemented_accesses`].
+
+use crate::{
+    args::{Argument, CoReg},
+    ParsedIns, Parser,
+};
+
+/// Coprocessor register accessed by an `mcr`, `mrc`, `mcr2` or `mrc2` instruction, see [`ParsedIns::coproc_access`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct CoprocAccess {
+    pub coproc: u32,
+    pub opc1: u32,
+    pub crn: CoReg,
+    pub crm: CoReg,
+    /// Zero if the instruction omits it
+    pub opc2: u32,
+    /// True if the register is read into an ARM register, i.e. `mrc`
+    pub read: bool,
+}
+
+/// Describes which coprocessor registers a core implements
+pub trait CoprocModel {
+    /// Returns true if the core implements the register of `access`. Models which only describe some coprocessors should
+    /// return true for the others, so that accesses to them are not reported.
+    fn implements(&self, access: &CoprocAccess) -> bool;
+}
+
+/// `opc1`, `CRn`, `CRm`, and a bitfield where bit N is set if `opc2` N is implemented
+type Cp15Entry = (u32, CoReg, CoReg, u8);
+
+fn implements_cp15(entries: &[Cp15Entry], access: &CoprocAccess) -> bool {
+    if access.coproc != 15 {
+        return true;
+    }
+    entries.iter().any(|&(opc1, crn, crm, opc2)| {
+        (opc1, crn, crm) == (access.opc1, access.crn, access.crm) && access.opc2 < 8 && opc2 & (1 << access.opc2) != 0
+    })
+}
+
+/// CP15 of the ARM946E-S, which has a protection unit instead of an MMU, from its Technical Reference Manual. Used by
+/// the ARM9 of the Nintendo DS. Other coprocessors are not checked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Arm946es;
+
+const ARM946ES_CP15: &[Cp15Entry] = &[
+    // ID, cache type and TCM size
+    (0, CoReg::C0, CoReg::C0, 0b111),
+    // Control
+    (0, CoReg::C1, CoReg::C0, 0b1),
+    // Cacheable and write-bufferable bits of the protection regions
+    (0, CoReg::C2, CoReg::C0, 0b11),
+    (0, CoReg::C3, CoReg::C0, 0b1),
+    // Access permissions
+    (0, CoReg::C5, CoReg::C0, 0b1111),
+    // Protection regions
+    (0, CoReg::C6, CoReg::C0, 0b1),
+    (0, CoReg::C6, CoReg::C1, 0b1),
+    (0, CoReg::C6, CoReg::C2, 0b1),
+    (0, CoReg::C6, CoReg::C3, 0b1),
+    (0, CoReg::C6, CoReg::C4, 0b1),
+    (0, CoReg::C6, CoReg::C5, 0b1),
+    (0, CoReg::C6, CoReg::C6, 0b1),
+    (0, CoReg::C6, CoReg::C7, 0b1),
+    // Wait for interrupt and cache operations
+    (0, CoReg::C7, CoReg::C0, 0b1_0000),
+    (0, CoReg::C7, CoReg::C5, 0b11),
+    (0, CoReg::C7, CoReg::C6, 0b11),
+    (0, CoReg::C7, CoReg::C8, 0b100),
+    (0, CoReg::C7, CoReg::C10, 0b1_0110),
+    (0, CoReg::C7, CoReg::C13, 0b10),
+    (0, CoReg::C7, CoReg::C14, 0b110),
+    // Cache lockdown and TCM regions
+    (0, CoReg::C9, CoReg::C0, 0b11),
+    (0, CoReg::C9, CoReg::C1, 0b11),
+    // Trace process ID
+    (0, CoReg::C13, CoReg::C0, 0b10),
+    (0, CoReg::C13, CoReg::C1, 0b10),
+];
+
+impl CoprocModel for Arm946es {
+    fn implements(&self, access: &CoprocAccess) -> bool {
+        implements_cp15(ARM946ES_CP15, access)
+    }
+}
+
+/// CP15 of the ARM1176JZF-S, which has an MMU and the security extensions, from its Technical Reference Manual. Used by
+/// the Raspberry Pi 1. Other coprocessors are not checked.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Arm1176jzfs;
+
+const ARM1176JZFS_CP15: &[Cp15Entry] = &[
+    // Identification and features
+    (0, CoReg::C0, CoReg::C0, 0b1111),
+    (0, CoReg::C0, CoReg::C1, 0b1111_1111),
+    (0, CoReg::C0, CoReg::C2, 0b11_1111),
+    // System control and security
+    (0, CoReg::C1, CoReg::C0, 0b111),
+    (0, CoReg::C1, CoReg::C1, 0b111),
+    // Translation tables and domains
+    (0, CoReg::C2, CoReg::C0, 0b111),
+    (0, CoReg::C3, CoReg::C0, 0b1),
+    // Faults
+    (0, CoReg::C5, CoReg::C0, 0b11),
+    (0, CoReg::C6, CoReg::C0, 0b111),
+    // Cache operations, address translation and barriers
+    (0, CoReg::C7, CoReg::C0, 0b1_0000),
+    (0, CoReg::C7, CoReg::C4, 0b1),
+    (0, CoReg::C7, CoReg::C5, 0b1101_0111),
+    (0, CoReg::C7, CoReg::C6, 0b111),
+    (0, CoReg::C7, CoReg::C7, 0b1),
+    (0, CoReg::C7, CoReg::C8, 0b1111_1111),
+    (0, CoReg::C7, CoReg::C10, 0b111_1111),
+    (0, CoReg::C7, CoReg::C11, 0b111),
+    (0, CoReg::C7, CoReg::C12, 0b11_0000),
+    (0, CoReg::C7, CoReg::C13, 0b10),
+    (0, CoReg::C7, CoReg::C14, 0b111),
+    // TLB operations
+    (0, CoReg::C8, CoReg::C5, 0b111),
+    (0, CoReg::C8, CoReg::C6, 0b111),
+    (0, CoReg::C8, CoReg::C7, 0b111),
+    // Cache lockdown and TCM
+    (0, CoReg::C9, CoReg::C0, 0b11),
+    (0, CoReg::C9, CoReg::C1, 0b11),
+    (0, CoReg::C9, CoReg::C2, 0b1),
+    (0, CoReg::C9, CoReg::C8, 0b1),
+    // TLB lockdown and memory region remap
+    (0, CoReg::C10, CoReg::C0, 0b11),
+    (0, CoReg::C10, CoReg::C2, 0b11),
+    // DMA
+    (0, CoReg::C11, CoReg::C0, 0b1111),
+    (0, CoReg::C11, CoReg::C1, 0b1),
+    (0, CoReg::C11, CoReg::C2, 0b1),
+    (0, CoReg::C11, CoReg::C3, 0b111),
+    (0, CoReg::C11, CoReg::C4, 0b1),
+    (0, CoReg::C11, CoReg::C5, 0b1),
+    (0, CoReg::C11, CoReg::C6, 0b1),
+    (0, CoReg::C11, CoReg::C7, 0b1),
+    (0, CoReg::C11, CoReg::C8, 0b1),
+    (0, CoReg::C11, CoReg::C15, 0b1),
+    // Vector base addresses
+    (0, CoReg::C12, CoReg::C0, 0b11),
+    (0, CoReg::C12, CoReg::C1, 0b1),
+    // Process and thread IDs
+    (0, CoReg::C13, CoReg::C0, 0b1_1111),
+    // Peripheral port remap and performance monitor
+    (0, CoReg::C15, CoReg::C2, 0b1_0000),
+    (0, CoReg::C15, CoReg::C12, 0b1111),
+];
+
+impl CoprocModel for Arm1176jzfs {
+    fn implements(&self, access: &CoprocAccess) -> bool {
+        implements_cp15(ARM1176JZFS_CP15, access)
+    }
+}
+
+impl ParsedIns {
+    /// Returns the coprocessor register accessed by an `mcr`, `mrc`, `mcr2` or `mrc2` instruction
+    pub fn coproc_access(&self) -> Option<CoprocAccess> {
+        let read = match self.mnemonic_base() {
+            "mcr" | "mcr2" => false,
+            "mrc" | "mrc2" => true,
+            _ => return None,
+        };
+        match self.args {
+            [Argument::CoprocNum(coproc), Argument::CoOpcode(opc1), _, Argument::CoReg(crn), Argument::CoReg(crm), opc2] => {
+                let opc2 = match opc2 {
+                    Argument::CoOpcode(opc2) => opc2,
+                    _ => 0,
+                };
+                Some(CoprocAccess {
+                    coproc,
+                    opc1,
+                    crn,
+                    crm,
+                    opc2,
+                    read,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the coprocessor register accessed by this instruction if `model` doesn't implement it, so that the
+    /// instruction would be undefined at runtime
+    pub fn unimplemented_coproc_access(&self, model: &dyn CoprocModel) -> Option<CoprocAccess> {
+        self.coproc_access().filter(|access| !model.implements(access))
+    }
+}
+
+/// Returns the address and register of each instruction parsed by `parser` which accesses a coprocessor register that
+/// `model` doesn't implement, see [`ParsedIns::unimplemented_coproc_access`]
+pub fn find_unimplemented_accesses(parser: Parser, model: &dyn CoprocModel) -> Vec<(u32, CoprocAccess)> {
+    parser
+        .filter_map(|(address, _, ins)| Some((address, ins.unimplemented_coproc_access(model)?)))
+        .collect()
+}
@@ -0,0 +1,223 @@
+//! Reproducible ARMv5TE ARM code for benchmarks and fuzzing, see `cargo xtask corpus`.
+//!
+//! Real ROMs can't be checked in, so a [`FrequencyModel`] stores how often each opcode and each value of each field
+//! appears in some real code instead. [`FrequencyModel::generate`] then samples any amount of code with the same mix of
+//! instructions. The model in [`MODEL`] and how it was produced are described in `disasm/corpus/README.md`.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    enumerate::FieldFilter,
+    v5te::arm::{Ins, Opcode},
+    Endian, ParseFlags,
+};
+
+/// The checked-in frequency model, see [`FrequencyModel::builtin`]
+pub const MODEL: &str = include_str!("../corpus/model.txt");
+
+/// Seed of the corpora generated by [`load`] and `cargo xtask corpus`
+pub const SEED: u32 = 0x2545f491;
+
+/// Number of times [`FrequencyModel::generate`] samples the fields of an opcode before drawing another opcode. Some
+/// combinations of fields decode to another opcode, e.g. `mov` with a register-specified shift of PC.
+const ATTEMPTS: usize = 8;
+
+/// Frequencies of the opcodes and field values of a set of ARM code
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct FrequencyModel {
+    /// Number of instructions of each opcode, in the order of first appearance
+    opcodes: Vec<(Opcode, u32)>,
+    /// Number of times each raw value of a field appears, by the field name of [`Opcode::field_descs`]
+    fields: Vec<(String, Vec<(u32, u32)>)>,
+}
+
+impl FrequencyModel {
+    /// Parses the checked-in [`MODEL`]
+    pub fn builtin() -> Self {
+        // Covered by the tests
+        Self::parse(MODEL).unwrap()
+    }
+
+    /// Counts the opcodes and field values of the little- or big-endian ARM code in `code`. Illegal instructions, such
+    /// as literal pools, are skipped.
+    pub fn observe(code: &[u8], endian: Endian) -> Self {
+        let mut model = Self::default();
+        model.add(code, endian);
+        model
+    }
+
+    /// Adds the opcodes and field values of more code to this model, see [`Self::observe`]
+    pub fn add(&mut self, code: &[u8], endian: Endian) {
+        let flags = ParseFlags::default();
+        for bytes in code.chunks_exact(4) {
+            let Some(ins) = Ins::from_bytes(bytes, endian, &flags) else {
+                continue;
+            };
+            if ins.op == Opcode::Illegal {
+                continue;
+            }
+            increment(&mut self.opcodes, ins.op, 1);
+            for field in ins.op.field_descs() {
+                let value = extract(ins.code, field.bitmask);
+                match self.fields.iter_mut().find(|(name, _)| name == field.name) {
+                    Some((_, values)) => increment(values, value, 1),
+                    None => self.fields.push((field.name.to_string(), vec![(value, 1)])),
+                }
+            }
+        }
+    }
+
+    /// Returns the total number of instructions in this model
+    pub fn len(&self) -> u64 {
+        self.opcodes.iter().map(|(_, count)| *count as u64).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.opcodes.is_empty()
+    }
+
+    /// Returns the number of instructions of `op` in this model
+    pub fn count(&self, op: Opcode) -> u32 {
+        self.opcodes.iter().find(|(o, _)| *o == op).map_or(0, |(_, count)| *count)
+    }
+
+    /// Parses a model written by the [`Display`] impl:
+    ///
+    /// ```text
+    /// # Comments and empty lines are ignored
+    /// op <Opcode variant name> <count>
+    /// field <field name> <raw value in hex> <count>
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut model = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: &str| format!("line {}: {message}: '{line}'", index + 1);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let parse_count = |word: &str| word.parse::<u32>().map_err(|_| error("invalid count"));
+            match words.as_slice() {
+                ["op", name, count] => {
+                    let op = Opcode::from_variant_name(name).ok_or_else(|| error("unknown opcode"))?;
+                    increment(&mut model.opcodes, op, parse_count(count)?);
+                }
+                ["field", name, value, count] => {
+                    let value = value
+                        .strip_prefix("0x")
+                        .and_then(|value| u32::from_str_radix(value, 16).ok())
+                        .ok_or_else(|| error("invalid value"))?;
+                    let count = parse_count(count)?;
+                    match model.fields.iter_mut().find(|(n, _)| n == name) {
+                        Some((_, values)) => increment(values, value, count),
+                        None => model.fields.push((name.to_string(), vec![(value, count)])),
+                    }
+                }
+                _ => return Err(error("expected `op` or `field`")),
+            }
+        }
+        Ok(model)
+    }
+
+    /// Generates `count` little-endian instructions with the mix of this model. The same seed always generates the same
+    /// code. Fields which the model has no values of are uniformly random.
+    pub fn generate(&self, seed: u32, count: usize) -> Vec<u8> {
+        let mut rng = XorShift32(seed.max(1));
+        let flags = ParseFlags::default();
+        let total = self.len();
+        let mut code = Vec::with_capacity(count * 4);
+        while code.len() < count * 4 && total > 0 {
+            let op = *pick(&self.opcodes, total, &mut rng);
+            for _ in 0..ATTEMPTS {
+                let filter = op.field_descs().iter().fold(FieldFilter::new(), |filter, field| {
+                    let value = match self.fields.iter().find(|(name, _)| name == field.name) {
+                        Some((_, values)) => *pick(values, values.iter().map(|(_, n)| *n as u64).sum(), &mut rng),
+                        None => rng.next(),
+                    };
+                    filter.pin(field.name, value)
+                });
+                if let Some(ins) = op.enumerate(&filter, &flags).next() {
+                    code.extend(ins.to_le_bytes());
+                    break;
+                }
+            }
+        }
+        code
+    }
+}
+
+impl Display for FrequencyModel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut opcodes = self.opcodes.clone();
+        opcodes.sort_by_key(|(op, count)| (u32::MAX - count, op.variant_name()));
+        for (op, count) in opcodes {
+            writeln!(f, "op {} {count}", op.variant_name())?;
+        }
+        let mut fields: Vec<_> = self.fields.iter().collect();
+        fields.sort_by_key(|(name, _)| name);
+        for (name, values) in fields {
+            let mut values = values.clone();
+            values.sort();
+            for (value, count) in values {
+                writeln!(f, "field {name} {value:#x} {count}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the code in the file at `UNARM_BENCH_CORPUS` if set, otherwise `count` instructions generated from
+/// [`MODEL`] with [`SEED`]
+pub fn load(count: usize) -> Vec<u8> {
+    match std::env::var("UNARM_BENCH_CORPUS") {
+        Ok(path) => std::fs::read(&path).unwrap_or_else(|e| panic!("{path}: {e}")),
+        Err(_) => FrequencyModel::builtin().generate(SEED, count),
+    }
+}
+
+fn increment<T: PartialEq>(counts: &mut Vec<(T, u32)>, key: T, count: u32) {
+    match counts.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, n)) => *n += count,
+        None => counts.push((key, count)),
+    }
+}
+
+/// Picks a key with a probability proportional to its count, where `total` is the sum of the counts
+fn pick<'a, T>(counts: &'a [(T, u32)], total: u64, rng: &mut XorShift32) -> &'a T {
+    let mut target = (rng.next() as u64 * total) >> 32;
+    for (key, count) in counts {
+        if target < *count as u64 {
+            return key;
+        }
+        target -= *count as u64;
+    }
+    &counts[counts.len() - 1].0
+}
+
+/// Gathers the bits of `code` in the set bits of `mask` into the lowest bits, the inverse of
+/// [`FieldFilter::pin`]
+fn extract(code: u32, mut mask: u32) -> u32 {
+    let mut result = 0;
+    let mut out = 1;
+    while mask != 0 {
+        let bit = mask & mask.wrapping_neg();
+        if code & bit != 0 {
+            result |= out;
+        }
+        out <<= 1;
+        mask &= !bit;
+    }
+    result
+}
+
+struct XorShift32(u32);
+
+impl XorShift32 {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+}
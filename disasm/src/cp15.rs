@@ -0,0 +1,77 @@
+use crate::args::CoReg;
+
+/// `opc1`, `CRn`, `CRm` and `opc2` of an `mcr` or `mrc` instruction
+type Cp15Key = (u32, CoReg, CoReg, u32);
+
+/// Names of the CP15 system registers and operations of ARMv5 and ARMv6, by `opc1`, `CRn`, `CRm` and `opc2`. Cores with
+/// a protection unit instead of an MMU, such as the ARM946E-S, use `c2`, `c3`, `c5` and `c6` for other registers.
+const CP15_NAMES: &[(Cp15Key, &str)] = &[
+    // Identification
+    ((0, CoReg::C0, CoReg::C0, 0), "MIDR"),
+    ((0, CoReg::C0, CoReg::C0, 1), "CTR"),
+    ((0, CoReg::C0, CoReg::C0, 2), "TCMTR"),
+    ((0, CoReg::C0, CoReg::C0, 3), "TLBTR"),
+    ((0, CoReg::C0, CoReg::C0, 5), "MPIDR"),
+    // System control
+    ((0, CoReg::C1, CoReg::C0, 0), "SCTLR"),
+    ((0, CoReg::C1, CoReg::C0, 1), "ACTLR"),
+    ((0, CoReg::C1, CoReg::C0, 2), "CPACR"),
+    // Memory management
+    ((0, CoReg::C2, CoReg::C0, 0), "TTBR0"),
+    ((0, CoReg::C2, CoReg::C0, 1), "TTBR1"),
+    ((0, CoReg::C2, CoReg::C0, 2), "TTBCR"),
+    ((0, CoReg::C3, CoReg::C0, 0), "DACR"),
+    ((0, CoReg::C5, CoReg::C0, 0), "DFSR"),
+    ((0, CoReg::C5, CoReg::C0, 1), "IFSR"),
+    ((0, CoReg::C6, CoReg::C0, 0), "FAR"),
+    ((0, CoReg::C6, CoReg::C0, 1), "WFAR"),
+    // Cache maintenance
+    ((0, CoReg::C7, CoReg::C0, 4), "WFI"),
+    ((0, CoReg::C7, CoReg::C5, 0), "ICIALLU"),
+    ((0, CoReg::C7, CoReg::C5, 1), "ICIMVAU"),
+    ((0, CoReg::C7, CoReg::C5, 2), "ICISW"),
+    ((0, CoReg::C7, CoReg::C5, 4), "CP15ISB"),
+    ((0, CoReg::C7, CoReg::C5, 6), "BPIALL"),
+    ((0, CoReg::C7, CoReg::C5, 7), "BPIMVA"),
+    ((0, CoReg::C7, CoReg::C6, 0), "DCIALL"),
+    ((0, CoReg::C7, CoReg::C6, 1), "DCIMVAC"),
+    ((0, CoReg::C7, CoReg::C6, 2), "DCISW"),
+    ((0, CoReg::C7, CoReg::C7, 0), "IDCIALL"),
+    ((0, CoReg::C7, CoReg::C10, 0), "DCCALL"),
+    ((0, CoReg::C7, CoReg::C10, 1), "DCCMVAC"),
+    ((0, CoReg::C7, CoReg::C10, 2), "DCCSW"),
+    ((0, CoReg::C7, CoReg::C10, 4), "CP15DSB"),
+    ((0, CoReg::C7, CoReg::C10, 5), "CP15DMB"),
+    ((0, CoReg::C7, CoReg::C13, 1), "ICPFMVA"),
+    ((0, CoReg::C7, CoReg::C14, 0), "DCCIALL"),
+    ((0, CoReg::C7, CoReg::C14, 1), "DCCIMVAC"),
+    ((0, CoReg::C7, CoReg::C14, 2), "DCCISW"),
+    // TLB maintenance
+    ((0, CoReg::C8, CoReg::C5, 0), "ITLBIALL"),
+    ((0, CoReg::C8, CoReg::C5, 1), "ITLBIMVA"),
+    ((0, CoReg::C8, CoReg::C6, 0), "DTLBIALL"),
+    ((0, CoReg::C8, CoReg::C6, 1), "DTLBIMVA"),
+    ((0, CoReg::C8, CoReg::C7, 0), "TLBIALL"),
+    ((0, CoReg::C8, CoReg::C7, 1), "TLBIMVA"),
+    ((0, CoReg::C8, CoReg::C7, 2), "TLBIASID"),
+    // Lockdown and tightly coupled memory
+    ((0, CoReg::C9, CoReg::C0, 0), "DCLR"),
+    ((0, CoReg::C9, CoReg::C0, 1), "ICLR"),
+    ((0, CoReg::C9, CoReg::C1, 0), "DTCMRR"),
+    ((0, CoReg::C9, CoReg::C1, 1), "ITCMRR"),
+    ((0, CoReg::C10, CoReg::C0, 0), "TLBLR"),
+    // Process and thread IDs
+    ((0, CoReg::C13, CoReg::C0, 0), "FCSEIDR"),
+    ((0, CoReg::C13, CoReg::C0, 1), "CONTEXTIDR"),
+    ((0, CoReg::C13, CoReg::C0, 2), "TPIDRURW"),
+    ((0, CoReg::C13, CoReg::C0, 3), "TPIDRURO"),
+    ((0, CoReg::C13, CoReg::C0, 4), "TPIDRPRW"),
+];
+
+/// Returns the name of a CP15 system register or operation, see [`crate::DisplayOptions::cp15_names`]
+pub(crate) fn cp15_name(opc1: u32, crn: CoReg, crm: CoReg, opc2: u32) -> Option<&'static str> {
+    CP15_NAMES
+        .iter()
+        .find(|(key, _)| *key == (opc1, crn, crm, opc2))
+        .map(|(_, name)| *name)
+}
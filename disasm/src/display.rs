@@ -1,158 +1,581 @@
-use std::fmt::{self, Display, Formatter};
+use std::{
+    borrow::Cow,
+    fmt::{self, Debug, Display, Formatter, Write},
+    ops::Range,
+};
 
 use crate::{
     args::{
-        Argument, CoReg, CpsrFlags, CpsrMode, Endian, OffsetImm, OffsetReg, Reg, Register, Shift, ShiftImm, ShiftReg,
-        StatusMask, StatusReg,
+        Argument, CoReg, CpsrFlags, CpsrMode, DReg, DRegIndex, DRegList, Endian, OffsetReg, Register, SReg, SRegList, Shift,
+        ShiftImm, ShiftReg, StatusMask, StatusReg, VfpSysReg,
     },
-    parse::ParsedIns,
+    cp15::cp15_name,
+    format::{format_argument, Comment, FormatVisitor},
+    parse::{ParseMode, ParsedIns},
+    ImmKind, RegisterRole,
 };
 
 impl ParsedIns {
-    pub fn display(&self, options: DisplayOptions) -> ParsedInsDisplay<'_> {
-        ParsedInsDisplay { ins: self, options }
+    /// Displays the instruction with the given options. Displaying never allocates, unless the
+    /// [`DisplayOptions::mnemonic_hook`] does.
+    pub fn display<'a>(&'a self, options: DisplayOptions<'a>) -> ParsedInsDisplay<'a> {
+        ParsedInsDisplay {
+            ins: self,
+            options,
+            pc: None,
+            code: None,
+        }
+    }
+
+    /// Writes the instruction into `w` without allocating, e.g. to reuse one `String` for many instructions instead of
+    /// creating one with `to_string` for each. See [`Self::display`].
+    pub fn write_str<W: Write>(&self, w: &mut W, options: DisplayOptions) -> fmt::Result {
+        write!(w, "{}", self.display(options))
+    }
+
+    /// Same as [`Self::display`], but branch destinations are displayed as absolute addresses instead of offsets. See
+    /// [`Self::branch_destination`] and [`DisplayOptions::pc_relative_comment`].
+    pub fn display_with_pc<'a>(&'a self, options: DisplayOptions<'a>, address: u32, mode: ParseMode) -> ParsedInsDisplay<'a> {
+        ParsedInsDisplay {
+            ins: self,
+            options,
+            pc: Some((address, mode)),
+            code: None,
+        }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
-pub struct DisplayOptions {
+/// Renames mnemonics when displaying instructions, see [`DisplayOptions::mnemonic_hook`]
+pub type MnemonicHook<'a> = &'a (dyn Fn(&ParsedIns) -> Option<Cow<'static, str>> + Sync);
+
+#[derive(Clone, Copy, Default)]
+pub struct DisplayOptions<'a> {
     pub reg_names: RegNames,
+    /// Called before displaying the mnemonic. If it returns a string, that string is displayed instead of the mnemonic.
+    /// [`ParsedIns::mnemonic_base`] and [`ParsedIns::condition`] can be used to keep the condition suffix of a renamed
+    /// mnemonic. A hook which names SWI calls can key its table on [`ParsedIns::swi_comment`], which doesn't depend on
+    /// how the number is displayed.
+    pub mnemonic_hook: Option<MnemonicHook<'a>>,
+    /// If true, runs of three or more consecutive registers in register lists display as a range, e.g. `{r4-r7, lr}`.
+    /// Registers with a special name such as `fp` are never part of a range.
+    pub reg_list_ranges: bool,
+    /// How immediates, offsets and shift amounts are displayed. Absolute branch destinations are always hexadecimal.
+    pub immediate_style: ImmediateStyle,
+    /// If set, the comment field of `swi`/`svc` and the immediate of `bkpt` are displayed in this style instead of
+    /// [`Self::immediate_style`], e.g. [`ImmediateStyle::Decimal`] for platforms which document SWI numbers in decimal.
+    /// See [`ParsedIns::swi_comment`] and [`ParsedIns::bkpt_immediate`].
+    pub comment_field_style: Option<ImmediateStyle>,
+    /// If true, instructions displayed with [`ParsedIns::display_with_pc`] are followed by the address of PC-relative
+    /// loads and address calculations as a comment, e.g. `ldr r0, [pc, #0x1c]  ; 0x2004a8`. See
+    /// [`ParsedIns::pc_relative_address`]. The comment is not counted in `MAX_RENDERED_LEN`.
+    pub pc_relative_comment: bool,
+    /// If true, `add` and `sub` of PC and an immediate display as `adr` with the offset from PC, e.g. `adr r0, #-0x4`
+    /// instead of `sub r0, pc, #0x4`. With [`ParsedIns::display_with_pc`], these and Thumb `adr` display the address
+    /// instead, e.g. `adr r0, #0x200004`, without the [`Self::pc_relative_comment`]. The parsed instruction is not
+    /// changed, so the raw form is still displayed when this is false.
+    pub adr: bool,
+    /// If true, `mcr` and `mrc` instructions of coprocessor 15 are followed by the name of the system register as a
+    /// comment, e.g. `mcr p15, #0, r0, c7, c10, #4  ; CP15DSB`. Unknown registers are displayed unchanged. The comment
+    /// is not counted in `MAX_RENDERED_LEN`.
+    pub cp15_names: bool,
+    /// If true, mnemonics, register names, shifts and other keywords display in uppercase, e.g. `LDRNE R0, [R1, #0x1c]`
+    /// for the listings of older toolchains. Hexadecimal literals keep their lowercase `0x` prefix and digits.
+    pub uppercase: bool,
+    /// If set, mnemonics are padded with spaces so that the operands start at this column and line up in a listing, e.g.
+    /// `ldr     r0, [r1]` with a width of 8. The padding comes before the [`Self::operand_separator`], which is always
+    /// written. Instructions without operands are never padded. The padding is not counted in `MAX_RENDERED_LEN`.
+    pub mnemonic_width: Option<usize>,
+    /// Separates the mnemonic from the first operand, after the padding of [`Self::mnemonic_width`]
+    pub operand_separator: OperandSeparator,
+    /// How illegal instructions are displayed, see [`ParsedIns::is_illegal`]
+    pub illegal_style: IllegalStyle,
+}
+
+/// Separator between the mnemonic and the operands, see [`DisplayOptions::operand_separator`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OperandSeparator {
+    /// One space, e.g. `ldr r0, [r1]`
+    #[default]
+    Space,
+    /// One tab like objdump, e.g. `ldr\tr0, [r1]`
+    Tab,
+}
+
+impl OperandSeparator {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Space => " ",
+            Self::Tab => "\t",
+        }
+    }
+}
+
+/// Text of illegal instructions, see [`DisplayOptions::illegal_style`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum IllegalStyle {
+    /// `<illegal>`
+    #[default]
+    Placeholder,
+    /// The code as a `.word` or `.hword` directive in hex, e.g. `.word 0xe7f000f0`. Unlike the data lines of
+    /// [`ParseMode::Data`], the value has no `#`, so the GNU assembler accepts the line. The code must be passed to the
+    /// display with `with_code`, e.g. `parsed.display(options).with_code(ins.code, ins.size())`, otherwise the placeholder
+    /// is displayed.
+    RawWord,
+    /// A custom mnemonic without arguments, e.g. `udf` or `???`
+    Custom(&'static str),
+}
+
+/// Radix of displayed immediates, see [`DisplayOptions::immediate_style`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ImmediateStyle {
+    /// Hexadecimal, e.g. `#0x1`
+    #[default]
+    Hex,
+    /// Decimal, e.g. `#1`
+    Decimal,
+    /// Decimal if the absolute value is at most the threshold, otherwise hexadecimal
+    HexAboveThreshold(u32),
+}
+
+impl PartialEq for DisplayOptions<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_hook = match (self.mnemonic_hook, other.mnemonic_hook) {
+            (Some(a), Some(b)) => std::ptr::addr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        self.reg_names == other.reg_names
+            && same_hook
+            && self.reg_list_ranges == other.reg_list_ranges
+            && self.immediate_style == other.immediate_style
+            && self.comment_field_style == other.comment_field_style
+            && self.pc_relative_comment == other.pc_relative_comment
+            && self.adr == other.adr
+            && self.cp15_names == other.cp15_names
+            && self.uppercase == other.uppercase
+            && self.mnemonic_width == other.mnemonic_width
+            && self.operand_separator == other.operand_separator
+            && self.illegal_style == other.illegal_style
+    }
+}
+
+impl Eq for DisplayOptions<'_> {}
+
+impl Debug for DisplayOptions<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DisplayOptions")
+            .field("reg_names", &self.reg_names)
+            .field("mnemonic_hook", &self.mnemonic_hook.map(|_| ".."))
+            .field("reg_list_ranges", &self.reg_list_ranges)
+            .field("immediate_style", &self.immediate_style)
+            .field("comment_field_style", &self.comment_field_style)
+            .field("pc_relative_comment", &self.pc_relative_comment)
+            .field("adr", &self.adr)
+            .field("cp15_names", &self.cp15_names)
+            .field("uppercase", &self.uppercase)
+            .field("mnemonic_width", &self.mnemonic_width)
+            .field("operand_separator", &self.operand_separator)
+            .field("illegal_style", &self.illegal_style)
+            .finish()
+    }
 }
 
 pub struct ParsedInsDisplay<'a> {
     ins: &'a ParsedIns,
-    options: DisplayOptions,
+    options: DisplayOptions<'a>,
+    /// Address and mode of the instruction, for resolving branch destinations
+    pc: Option<(u32, ParseMode)>,
+    /// Code and size of the instruction, for [`IllegalStyle::RawWord`]
+    code: Option<(u32, usize)>,
 }
 
 impl<'a> Display for ParsedInsDisplay<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.ins.mnemonic)?;
-        if self.ins.args[0] != Argument::None {
-            write!(f, " ")?;
-        }
-        let mut comma = false;
-        let mut deref = false;
-        let mut writeback = false;
-        for arg in self.ins.args_iter() {
-            if deref {
-                match arg {
-                    Argument::OffsetImm(OffsetImm {
-                        post_indexed: true,
-                        value: _,
-                    })
-                    | Argument::OffsetReg(OffsetReg {
-                        add: _,
-                        post_indexed: true,
-                        reg: _,
-                    })
-                    | Argument::CoOption(_) => {
-                        deref = false;
-                        write!(f, "]")?;
-                        if writeback {
-                            write!(f, "!")?;
-                            writeback = false;
-                        }
-                    }
-                    _ => {}
-                }
-            }
-            if comma {
-                write!(f, ", ")?;
-            }
-            if let Argument::Reg(Reg {
-                deref: true,
-                reg,
-                writeback: wb,
-            }) = arg
-            {
-                deref = true;
-                writeback = *wb;
-                write!(f, "[{}", reg.display(self.options.reg_names))?;
-            } else {
-                write!(f, "{}", arg.display(self.options))?;
+        if self.options.uppercase {
+            self.visit(&mut TextVisitor::new(UppercaseTokens(f), self.options.reg_names))
+        } else {
+            self.visit(&mut TextVisitor::new(f, self.options.reg_names))
+        }
+    }
+}
+
+impl<'a> ParsedInsDisplay<'a> {
+    /// Renders the instruction and splits the text into tokens, see [`Tokens`]
+    pub fn tokens(&self) -> Tokens {
+        let mut tokens = Tokens::default();
+        // Writing to a String can't fail
+        if self.options.uppercase {
+            self.visit(&mut TextVisitor::new(UppercaseTokens(&mut tokens), self.options.reg_names))
+                .unwrap();
+        } else {
+            self.visit(&mut TextVisitor::new(&mut tokens, self.options.reg_names))
+                .unwrap();
+        }
+        tokens
+    }
+
+    /// Sets the code of the instruction and its size in bytes, e.g. `Ins::code` and `Ins::size`, which is displayed if
+    /// the instruction is illegal and [`DisplayOptions::illegal_style`] is [`IllegalStyle::RawWord`]
+    pub fn with_code(self, code: u32, size: usize) -> Self {
+        Self {
+            code: Some((code, size)),
+            ..self
+        }
+    }
+
+    pub(crate) fn ins(&self) -> &'a ParsedIns {
+        self.ins
+    }
+
+    pub(crate) fn options(&self) -> DisplayOptions<'a> {
+        self.options
+    }
+
+    pub(crate) fn pc(&self) -> Option<(u32, ParseMode)> {
+        self.pc
+    }
+
+    pub(crate) fn code(&self) -> Option<(u32, usize)> {
+        self.code
+    }
+
+    /// Returns the name of the CP15 system register accessed by an `mcr` or `mrc` instruction
+    pub(crate) fn cp15_name(&self) -> Option<&'static str> {
+        if !matches!(self.ins.mnemonic_base(), "mcr" | "mrc") {
+            return None;
+        }
+        match self.ins.args {
+            [Argument::CoprocNum(15), Argument::CoOpcode(opc1), _, Argument::CoReg(crn), Argument::CoReg(crm), Argument::CoOpcode(opc2)] => {
+                cp15_name(opc1, crn, crm, opc2)
             }
-            comma = true;
+            _ => None,
+        }
+    }
+}
+
+/// Writes the events of a [`FormatVisitor`] as tokens of text. This is the only place which decides how the events
+/// look as text, so that displaying and visiting can't drift apart.
+struct TextVisitor<W> {
+    writer: W,
+    reg_names: RegNames,
+    /// Padding to write before the next separator, see [`FormatVisitor::padding`]
+    padding: usize,
+    /// Inside the brackets of a memory operand, where the writeback of the base register comes after the brackets
+    deref: bool,
+}
+
+impl<W: TokenWriter> TextVisitor<W> {
+    fn new(writer: W, reg_names: RegNames) -> Self {
+        Self {
+            writer,
+            reg_names,
+            padding: 0,
+            deref: false,
+        }
+    }
+}
+
+impl<W: TokenWriter> FormatVisitor for TextVisitor<W> {
+    fn mnemonic(&mut self, mnemonic: &str) -> fmt::Result {
+        self.writer.token(TokenKind::Mnemonic, None, format_args!("{mnemonic}"))
+    }
+
+    fn separator(&mut self, index: Option<usize>, text: &str) -> fmt::Result {
+        let padding = std::mem::take(&mut self.padding);
+        // Separators inside an argument belong to the argument
+        let kind = if index.is_some() {
+            TokenKind::Argument
+        } else {
+            TokenKind::Separator
+        };
+        self.writer.token(kind, index, format_args!("{:padding$}{text}", ""))
+    }
+
+    fn padding(&mut self, width: usize) -> fmt::Result {
+        self.padding = width;
+        Ok(())
+    }
+
+    fn register(&mut self, index: usize, reg: Register, role: RegisterRole) -> fmt::Result {
+        let reg = reg.display(self.reg_names);
+        let (prefix, suffix) = match role {
+            RegisterRole::Operand { writeback: true } => ("", "!"),
+            RegisterRole::Base { writeback: true } if !self.deref => ("", "!"),
+            RegisterRole::Offset { add: false } => ("-", ""),
+            _ => ("", ""),
+        };
+        self.writer
+            .token(TokenKind::Argument, Some(index), format_args!("{prefix}{reg}{suffix}"))
+    }
+
+    fn register_range(&mut self, index: usize, first: Register, last: Register) -> fmt::Result {
+        let (first, last) = (first.display(self.reg_names), last.display(self.reg_names));
+        self.writer
+            .token(TokenKind::Argument, Some(index), format_args!("{first}-{last}"))
+    }
+
+    fn immediate(&mut self, index: usize, imm: Immediate, kind: ImmKind) -> fmt::Result {
+        match kind {
+            ImmKind::RawWord => self
+                .writer
+                .token(TokenKind::Argument, Some(index), format_args!("{:#x}", imm.value())),
+            _ => self.writer.token(TokenKind::Argument, Some(index), format_args!("{imm}")),
+        }
+    }
+
+    fn shift(&mut self, index: usize, shift: Shift) -> fmt::Result {
+        self.writer.token(TokenKind::Argument, Some(index), format_args!("{shift}"))
+    }
+
+    fn open_deref(&mut self, _index: usize) -> fmt::Result {
+        self.deref = true;
+        self.writer.token(TokenKind::Separator, None, format_args!("["))
+    }
+
+    fn close_deref(&mut self, index: usize, writeback: bool) -> fmt::Result {
+        self.deref = false;
+        self.writer.token(TokenKind::Separator, None, format_args!("]"))?;
+        if writeback {
+            self.writer.token(TokenKind::Argument, Some(index), format_args!("!"))?;
+        }
+        Ok(())
+    }
+
+    fn open_reg_list(&mut self, index: usize) -> fmt::Result {
+        self.writer.token(TokenKind::Argument, Some(index), format_args!("{{"))
+    }
+
+    fn close_reg_list(&mut self, index: usize, user_mode: bool) -> fmt::Result {
+        let suffix = if user_mode { "^" } else { "" };
+        self.writer
+            .token(TokenKind::Argument, Some(index), format_args!("}}{suffix}"))
+    }
+
+    fn other(&mut self, index: usize, arg: &Argument) -> fmt::Result {
+        let mut write = |text: fmt::Arguments| self.writer.token(TokenKind::Argument, Some(index), text);
+        match arg {
+            Argument::CoReg(x) => write(format_args!("{x}")),
+            Argument::StatusReg(x) => write(format_args!("{x}")),
+            Argument::StatusMask(x) => write(format_args!("{x}")),
+            Argument::CoOption(x) => write(format_args!("{{0x{x:x}}}")),
+            Argument::CoprocNum(x) => write(format_args!("p{x}")),
+            Argument::CpsrMode(x) => write(format_args!("{x}")),
+            Argument::CpsrFlags(x) => write(format_args!("{x}")),
+            Argument::Endian(x) => write(format_args!("{x}")),
+            Argument::SReg(x) => write(format_args!("{x}")),
+            Argument::DReg(x) => write(format_args!("{x}")),
+            Argument::DRegIndex(x) => write(format_args!("{x}")),
+            Argument::SRegList(x) => write(format_args!("{x}")),
+            Argument::DRegList(x) => write(format_args!("{x}")),
+            Argument::VfpSysReg(x) => write(format_args!("{x}")),
+            // The other arguments have their own events
+            _ => Ok(()),
+        }
+    }
+
+    fn comment(&mut self, comment: Comment) -> fmt::Result {
+        match comment {
+            Comment::PcRelative(target) => self.writer.token(TokenKind::Comment, None, format_args!("; 0x{target:x}")),
+            Comment::Cp15(name) => self.writer.token(TokenKind::Comment, None, format_args!("; {name}")),
+        }
+    }
+}
+
+trait TokenWriter {
+    fn token(&mut self, kind: TokenKind, arg_index: Option<usize>, text: fmt::Arguments) -> fmt::Result;
+}
+
+impl TokenWriter for Formatter<'_> {
+    fn token(&mut self, _kind: TokenKind, _arg_index: Option<usize>, text: fmt::Arguments) -> fmt::Result {
+        self.write_fmt(text)
+    }
+}
+
+impl<W: TokenWriter> TokenWriter for &mut W {
+    fn token(&mut self, kind: TokenKind, arg_index: Option<usize>, text: fmt::Arguments) -> fmt::Result {
+        (**self).token(kind, arg_index, text)
+    }
+}
+
+/// Converts the text of each token to uppercase, see [`DisplayOptions::uppercase`]
+struct UppercaseTokens<W>(W);
+
+impl<W: TokenWriter> TokenWriter for UppercaseTokens<W> {
+    fn token(&mut self, kind: TokenKind, arg_index: Option<usize>, text: fmt::Arguments) -> fmt::Result {
+        self.0.token(kind, arg_index, format_args!("{}", Uppercase(text)))
+    }
+}
+
+/// Displays text in uppercase apart from hexadecimal literals, without allocating
+struct Uppercase<'a>(fmt::Arguments<'a>);
+
+impl Display for Uppercase<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut writer = UppercaseWriter {
+            f,
+            prev: None,
+            literal_start: false,
+            hex: false,
+        };
+        writer.write_fmt(self.0)
+    }
+}
+
+struct UppercaseWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    prev: Option<char>,
+    /// The previous character is a `0` which starts a number
+    literal_start: bool,
+    /// Inside the digits of a hexadecimal literal
+    hex: bool,
+}
+
+impl Write for UppercaseWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.hex &= c.is_ascii_hexdigit();
+            let keep = self.hex || (self.literal_start && c == 'x');
+            self.hex |= self.literal_start && c == 'x';
+            self.literal_start = c == '0' && !self.prev.is_some_and(|prev| prev.is_ascii_alphanumeric());
+            self.prev = Some(c);
+            self.f.write_char(if keep { c } else { c.to_ascii_uppercase() })?;
         }
-        if deref {
-            write!(f, "]")?;
-            if writeback {
-                write!(f, "!")?;
+        Ok(())
+    }
+}
+
+impl TokenWriter for Tokens {
+    fn token(&mut self, kind: TokenKind, arg_index: Option<usize>, text: fmt::Arguments) -> fmt::Result {
+        let start = self.text.len();
+        self.text.write_fmt(text)?;
+        let span = start..self.text.len();
+        if span.is_empty() {
+            return Ok(());
+        }
+        // Parts of the same argument form one token, e.g. `lsl #0x3`
+        match self.tokens.last_mut() {
+            Some(last) if kind == TokenKind::Argument && last.kind == kind && last.arg_index == arg_index => {
+                last.span.end = span.end
             }
+            _ => self.tokens.push(Token { kind, span, arg_index }),
         }
         Ok(())
     }
 }
 
-pub struct SignedHex(i32);
+/// A rendered instruction split into tokens, for mapping parts of the text back to the mnemonic or arguments
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Tokens {
+    /// The rendered instruction, same as the [`Display`] output
+    pub text: String,
+    /// Tokens covering all of `text`, in order
+    pub tokens: Vec<Token>,
+}
+
+impl Tokens {
+    /// Returns the token containing the byte at `offset` in the text
+    pub fn token_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.iter().find(|token| token.span.contains(&offset))
+    }
+
+    /// Returns the index of the argument rendered at byte `offset` in the text
+    pub fn arg_at(&self, offset: usize) -> Option<usize> {
+        self.token_at(offset).and_then(|token| token.arg_index)
+    }
+
+    /// Returns the text of a token
+    pub fn text(&self, token: &Token) -> &str {
+        &self.text[token.span.clone()]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TokenKind {
+    Mnemonic,
+    /// Spaces, commas and brackets
+    Separator,
+    /// Text which belongs to an argument
+    Argument,
+    /// Trailing comment, see [`DisplayOptions::pc_relative_comment`]
+    Comment,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Byte range of this token in [`Tokens::text`]
+    pub span: Range<usize>,
+    /// Index into [`ParsedIns::args`], if this token belongs to an argument. The `!` after a memory operand belongs to its
+    /// base register, while the brackets don't belong to any argument.
+    pub arg_index: Option<usize>,
+}
+
+/// An immediate with a `#` prefix, in the given style
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Immediate(i64, ImmediateStyle);
+
+impl Immediate {
+    pub fn signed(value: i32, style: ImmediateStyle) -> Self {
+        Self(value.into(), style)
+    }
+
+    pub fn unsigned(value: u32, style: ImmediateStyle) -> Self {
+        Self(value.into(), style)
+    }
+
+    /// Returns the value, sign-extended if it's signed and zero-extended otherwise
+    pub fn value(self) -> i64 {
+        self.0
+    }
 
-impl Display for SignedHex {
+    pub fn style(self) -> ImmediateStyle {
+        self.1
+    }
+}
+
+impl Display for Immediate {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "#")?;
         if self.0.is_negative() {
             write!(f, "-")?;
         }
-        write!(f, "0x{:x}", self.0.abs())
+        let abs = self.0.unsigned_abs();
+        let decimal = match self.1 {
+            ImmediateStyle::Hex => false,
+            ImmediateStyle::Decimal => true,
+            ImmediateStyle::HexAboveThreshold(threshold) => abs <= threshold.into(),
+        };
+        if decimal {
+            write!(f, "{}", abs)
+        } else {
+            write!(f, "0x{:x}", abs)
+        }
     }
 }
 
 impl Argument {
-    pub fn display(&self, options: DisplayOptions) -> DisplayArgument<'_> {
+    pub fn display<'a>(&'a self, options: DisplayOptions<'a>) -> DisplayArgument<'a> {
         DisplayArgument { arg: self, options }
     }
+
+    /// Writes the argument into `w` without allocating, e.g. for custom formatters which write their own text between
+    /// the arguments of an instruction. See [`ParsedIns::write_str`].
+    pub fn write_str<W: Write>(&self, w: &mut W, options: DisplayOptions) -> fmt::Result {
+        write!(w, "{}", self.display(options))
+    }
 }
 
 pub struct DisplayArgument<'a> {
     arg: &'a Argument,
-    options: DisplayOptions,
+    options: DisplayOptions<'a>,
 }
 
 impl<'a> Display for DisplayArgument<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self.arg {
-            Argument::None => Ok(()),
-            Argument::Reg(reg) => {
-                write!(f, "{}", reg.reg.display(self.options.reg_names))?;
-                if reg.writeback {
-                    write!(f, "!")?;
-                }
-                Ok(())
-            }
-            Argument::RegList(list) => {
-                write!(f, "{{")?;
-                let mut first = true;
-                for i in 0..16 {
-                    if (list.regs & (1 << i)) != 0 {
-                        if !first {
-                            write!(f, ", ")?;
-                        }
-                        write!(f, "{}", Register::parse(i).display(self.options.reg_names))?;
-                        first = false;
-                    }
-                }
-                write!(f, "}}")?;
-                if list.user_mode {
-                    write!(f, "^")?;
-                }
-                Ok(())
-            }
-            Argument::CoReg(x) => write!(f, "{}", x),
-            Argument::StatusReg(x) => write!(f, "{}", x),
-            Argument::UImm(x) => write!(f, "#0x{:x}", x),
-            Argument::SImm(x) => write!(f, "{}", SignedHex(*x)),
-            Argument::OffsetImm(x) => write!(f, "{}", SignedHex(x.value)),
-            Argument::CoOption(x) => write!(f, "{{0x{:x}}}", x),
-            Argument::CoOpcode(x) => write!(f, "#{}", x),
-            Argument::CoprocNum(x) => write!(f, "p{}", x),
-            Argument::ShiftImm(x) => write!(f, "{}", x),
-            Argument::ShiftReg(x) => write!(f, "{}", x.display(self.options.reg_names)),
-            Argument::OffsetReg(x) => write!(f, "{}", x.display(self.options.reg_names)),
-            Argument::BranchDest(x) => write!(f, "{}", SignedHex(*x)),
-            Argument::StatusMask(x) => write!(f, "{}", x),
-            Argument::Shift(x) => write!(f, "{}", x),
-            Argument::SatImm(x) => write!(f, "#0x{:x}", x),
-            Argument::CpsrMode(x) => write!(f, "{}", x),
-            Argument::CpsrFlags(x) => write!(f, "{}", x),
-            Argument::Endian(x) => write!(f, "{}", x),
+        let names = self.options.reg_names;
+        if self.options.uppercase {
+            format_argument(&mut TextVisitor::new(UppercaseTokens(f), names), 0, self.arg, self.options)
+        } else {
+            format_argument(&mut TextVisitor::new(f, names), 0, self.arg, self.options)
         }
     }
 }
@@ -192,11 +615,26 @@ impl Register {
 
 pub struct RegDisplay(Register, RegNames);
 
-impl Display for RegDisplay {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+impl RegDisplay {
+    /// Returns true if `reg` is displayed with the same prefix and `offset` added to the number of this register, e.g.
+    /// `r4` continues to `r7` with offset 3, but not to `fp`.
+    pub(crate) fn continues_to(&self, offset: u32, reg: Register) -> bool {
+        fn split(name: &str) -> (&str, Option<u32>) {
+            let digits = name.trim_start_matches(|c: char| !c.is_ascii_digit());
+            (&name[..name.len() - digits.len()], digits.parse().ok())
+        }
+        let (prefix, number) = split(self.name());
+        let (next_prefix, next_number) = split(reg.display(self.1).name());
+        match (number, next_number) {
+            (Some(number), Some(next_number)) => prefix == next_prefix && number + offset == next_number,
+            _ => false,
+        }
+    }
+
+    fn name(&self) -> &'static str {
         #[rustfmt::skip]
         let s = match self.0 {
-            Register::Illegal => todo!(),
+            Register::Illegal => "<illegal>",
             Register::R0 => if self.1.av_registers { "a1" } else { "r0" },
             Register::R1 => if self.1.av_registers { "a2" } else { "r1" },
             Register::R2 => if self.1.av_registers { "a3" } else { "r2" },
@@ -218,7 +656,13 @@ impl Display for RegDisplay {
             Register::Lr => "lr",
             Register::Pc => "pc",
         };
-        write!(f, "{}", s)
+        s
+    }
+}
+
+impl Display for RegDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
     }
 }
 
@@ -252,6 +696,71 @@ impl Display for StatusReg {
             StatusReg::Illegal => write!(f, "<illegal>"),
             StatusReg::Cpsr => write!(f, "cpsr"),
             StatusReg::Spsr => write!(f, "spsr"),
+            StatusReg::ApsrNzcv => write!(f, "apsr_nzcv"),
+        }
+    }
+}
+
+impl Display for SReg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SReg::Illegal => write!(f, "<illegal>"),
+            reg => write!(f, "s{}", *reg as u8),
+        }
+    }
+}
+
+impl Display for DReg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DReg::Illegal => write!(f, "<illegal>"),
+            reg => write!(f, "d{}", *reg as u8),
+        }
+    }
+}
+
+impl Display for DRegIndex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.reg, self.index)
+    }
+}
+
+/// Writes a list of `count` consecutive VFP registers as a range, with a numeric last register even if it's past the
+/// last register of the VFP
+fn write_fp_list(f: &mut Formatter<'_>, prefix: char, first: u8, count: u32) -> fmt::Result {
+    match count {
+        0 => write!(f, "{{}}"),
+        1 => write!(f, "{{{prefix}{first}}}"),
+        _ => write!(f, "{{{prefix}{first}-{prefix}{}}}", first as u32 + count - 1),
+    }
+}
+
+impl Display for SRegList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.first {
+            SReg::Illegal => write!(f, "<illegal>"),
+            first => write_fp_list(f, 's', first as u8, self.count),
+        }
+    }
+}
+
+impl Display for DRegList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.first {
+            DReg::Illegal => write!(f, "<illegal>"),
+            first => write_fp_list(f, 'd', first as u8, self.count),
+        }
+    }
+}
+
+impl Display for VfpSysReg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            VfpSysReg::Illegal => write!(f, "<illegal>"),
+            VfpSysReg::Fpsid => write!(f, "fpsid"),
+            VfpSysReg::Fpscr => write!(f, "fpscr"),
+            VfpSysReg::Fpexc => write!(f, "fpexc"),
+            VfpSysReg::Fpinst => write!(f, "fpinst"),
         }
     }
 }
@@ -293,7 +802,21 @@ impl Display for Shift {
 
 impl Display for ShiftImm {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{} #0x{:x}", self.op, self.imm)
+        write!(f, "{}", self.display(ImmediateStyle::Hex))
+    }
+}
+
+impl ShiftImm {
+    pub fn display(self, style: ImmediateStyle) -> DisplayShiftImm {
+        DisplayShiftImm(self, style)
+    }
+}
+
+pub struct DisplayShiftImm(ShiftImm, ImmediateStyle);
+
+impl Display for DisplayShiftImm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.0.op, Immediate::unsigned(self.0.imm, self.1))
     }
 }
 
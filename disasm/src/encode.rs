@@ -0,0 +1,246 @@
+//! Encoding of parsed instructions back into machine code. Each instruction set module has an `encode` function and an
+//! `Opcode::encode` method, which are generated from the same ISA definitions as the disassembler.
+
+use std::fmt::Display;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EncodeError {
+    /// No opcode has this mnemonic
+    UnknownMnemonic,
+    /// The argument at `index` has the wrong kind, conflicts with another argument or shouldn't be there at all
+    InvalidArgument { index: usize },
+    /// The argument at `index` has a value which can't be encoded, e.g. an immediate which is too large
+    OutOfRange { index: usize },
+    /// The instruction can be encoded, but the result decodes as something else. For example, `stmdb sp!, {r4, lr}` is
+    /// decoded as `push {r4, lr}` in unified syntax.
+    Shadowed,
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownMnemonic => write!(f, "unknown mnemonic"),
+            Self::InvalidArgument { index } => write!(f, "invalid argument {index}"),
+            Self::OutOfRange { index } => write!(f, "argument {index} is out of range"),
+            Self::Shadowed => write!(f, "instruction decodes as another instruction"),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+pub(crate) use forms::*;
+
+/// Forms and field encoders of the generated `encode` functions, which only exist with an instruction set and a version
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+mod forms {
+    use super::EncodeError;
+    use crate::{args::Argument, ParsedIns};
+
+    impl EncodeError {
+        /// How far encoding got before failing, to pick the most relevant error when trying several opcodes
+        fn progress(self) -> (u8, usize) {
+            match self {
+                Self::UnknownMnemonic => (0, 0),
+                Self::InvalidArgument { index } => (1, index),
+                Self::OutOfRange { index } => (2, index),
+                Self::Shadowed => (3, 0),
+            }
+        }
+
+        pub(crate) fn most_relevant(self, other: Self) -> Self {
+            if other.progress() > self.progress() {
+                other
+            } else {
+                self
+            }
+        }
+    }
+
+    /// Error of a single field encoder, which doesn't know the index of its argument
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub(crate) enum FieldError {
+        Invalid,
+        OutOfRange,
+    }
+
+    impl FieldError {
+        fn at(self, index: usize) -> EncodeError {
+            match self {
+                Self::Invalid => EncodeError::InvalidArgument { index },
+                Self::OutOfRange => EncodeError::OutOfRange { index },
+            }
+        }
+    }
+
+    /// Encodes one argument into the bits of `bitmask`
+    pub(crate) struct EncodeField {
+        pub encode: fn(Argument) -> Result<u32, FieldError>,
+        pub bitmask: u32,
+    }
+
+    pub(crate) struct EncodeCase {
+        pub suffix: &'static str,
+        pub bitmask: u32,
+        pub pattern: u32,
+        pub fields: &'static [EncodeField],
+    }
+
+    /// One syntax of an opcode
+    pub(crate) struct EncodeForm {
+        pub name: &'static str,
+        pub opcode_suffix: &'static str,
+        /// Whether the mnemonic is in unified syntax, where modifier suffixes come after the opcode suffix
+        pub ual: bool,
+        /// The value of [`crate::ParseFlags::ual`] which this form is parsed with, or `None` for both
+        pub flag: Option<bool>,
+        pub bitmask: u32,
+        pub pattern: u32,
+        pub fields: &'static [EncodeField],
+        /// Cases of each modifier, in the order their suffixes appear in the mnemonic
+        pub modifiers: &'static [&'static [EncodeCase]],
+    }
+
+    /// Encodes `ins` with the first form and modifier cases which match its mnemonic and arguments, and whose result is
+    /// accepted by `decodes_to`.
+    pub(crate) fn encode_forms(
+        forms: &[EncodeForm],
+        ins: &ParsedIns,
+        ual: bool,
+        decodes_to: impl Fn(u32) -> bool,
+    ) -> Result<u32, EncodeError> {
+        let mut error = EncodeError::UnknownMnemonic;
+        for form in forms.iter().filter(|form| form.flag.is_none_or(|flag| flag == ual)) {
+            let Some(suffixes) = strip_mnemonic(form, ins.mnemonic) else {
+                continue;
+            };
+            let result = search_cases(form.modifiers, suffixes, &mut vec![], &mut |cases| {
+                let code = pack(form, cases, ins)?;
+                if decodes_to(code) {
+                    Ok(code)
+                } else {
+                    Err(EncodeError::Shadowed)
+                }
+            });
+            match result {
+                Ok(code) => return Ok(code),
+                Err(e) => error = error.most_relevant(e),
+            }
+        }
+        Err(error)
+    }
+
+    /// Removes the opcode name and suffix from `mnemonic`, leaving the modifier suffixes
+    fn strip_mnemonic<'a>(form: &EncodeForm, mnemonic: &'a str) -> Option<&'a str> {
+        let rest = mnemonic.strip_prefix(form.name)?;
+        if form.ual {
+            rest.strip_prefix(form.opcode_suffix)
+        } else {
+            rest.strip_suffix(form.opcode_suffix)
+        }
+    }
+
+    /// Tries every combination of modifier cases whose suffixes spell out `suffixes`. Several cases may have the same
+    /// suffix, e.g. the addressing modes of a load.
+    fn search_cases<'a>(
+        modifiers: &[&'a [EncodeCase]],
+        suffixes: &str,
+        cases: &mut Vec<&'a EncodeCase>,
+        pack: &mut impl FnMut(&[&EncodeCase]) -> Result<u32, EncodeError>,
+    ) -> Result<u32, EncodeError> {
+        let Some((modifier, rest)) = modifiers.split_first() else {
+            return if suffixes.is_empty() {
+                pack(cases)
+            } else {
+                Err(EncodeError::UnknownMnemonic)
+            };
+        };
+        let mut error = EncodeError::UnknownMnemonic;
+        for case in modifier.iter() {
+            let Some(suffixes) = suffixes.strip_prefix(case.suffix) else {
+                continue;
+            };
+            cases.push(case);
+            let result = search_cases(rest, suffixes, cases, pack);
+            cases.pop();
+            match result {
+                Ok(code) => return Ok(code),
+                Err(e) => error = error.most_relevant(e),
+            }
+        }
+        Err(error)
+    }
+
+    fn pack(form: &EncodeForm, cases: &[&EncodeCase], ins: &ParsedIns) -> Result<u32, EncodeError> {
+        let mut code = form.pattern;
+        let mut bitmask = form.bitmask;
+        for case in cases {
+            code |= case.pattern;
+            bitmask |= case.bitmask;
+        }
+        let fields = form.fields.iter().chain(cases.iter().flat_map(|case| case.fields));
+        let mut count = 0;
+        for (index, (field, arg)) in fields.zip(ins.args).enumerate() {
+            let bits = (field.encode)(arg).map_err(|e| e.at(index))?;
+            debug_assert_eq!(bits & !field.bitmask, 0);
+            // Fields which share bits with another field, e.g. the second register of `ldrd`, must agree with it
+            if (code ^ bits) & bitmask & field.bitmask != 0 {
+                return Err(EncodeError::InvalidArgument { index });
+            }
+            code |= bits;
+            bitmask |= field.bitmask;
+            count += 1;
+        }
+        if let Some(extra) = ins.args[count..].iter().position(|arg| *arg != Argument::None) {
+            return Err(EncodeError::InvalidArgument { index: count + extra });
+        }
+        Ok(code)
+    }
+
+    /// Places `value` in the bits `start..end`, or returns `None` if it doesn't fit
+    pub(crate) fn bits(value: u32, start: u32, end: u32) -> Option<u32> {
+        (value.checked_shr(end - start).unwrap_or(0) == 0).then_some(value << start)
+    }
+
+    /// Encodes a signed value whose lowest `shift` bits must be zero as a `width`-bit two's complement number, e.g. a branch
+    /// offset
+    pub(crate) fn signed_bits(value: i64, shift: u32, width: u32) -> Option<u32> {
+        if value & ((1 << shift) - 1) != 0 {
+            return None;
+        }
+        let value = value >> shift;
+        let half = 1 << (width - 1);
+        (-half..half).contains(&value).then_some(value as u32 & ((1 << width) - 1))
+    }
+
+    /// Encodes the magnitude of an ARM load/store offset in the lowest `width` bits, and its sign in the U bit (bit 23)
+    #[cfg(feature = "arm")]
+    pub(crate) fn offset(value: i32, width: u32) -> Option<u32> {
+        let up = if value >= 0 { 1 << 23 } else { 0 };
+        bits(value.unsigned_abs(), 0, width).map(|magnitude| magnitude | up)
+    }
+
+    /// Encodes `value` as an 8-bit immediate rotated right by an even amount, with the rotation in bits 8..12
+    #[cfg(feature = "arm")]
+    pub(crate) fn rotated_imm(value: u32) -> Option<u32> {
+        (0..16).find_map(|rotate| {
+            let imm = value.rotate_left(rotate * 2);
+            (imm <= 0xff).then_some((rotate << 8) | imm)
+        })
+    }
+
+    /// Encodes the 5-bit amount of an immediate shift, where 32 is encoded as 0 for `lsr` and `asr`
+    pub(crate) fn arm_shift(op: u32, imm: u32) -> Option<u32> {
+        match op {
+            1 | 2 => (1..=32).contains(&imm).then_some(imm & 0x1f),
+            _ => (imm < 32).then_some(imm),
+        }
+    }
+}
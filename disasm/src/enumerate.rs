@@ -0,0 +1,142 @@
+//! Enumeration of the encodings of an opcode, e.g. to test an emulator against every `ldr` with a given base register.
+//! Each instruction set module has an `Opcode::enumerate` method, which sweeps the fields described by
+//! `Opcode::field_descs`.
+
+/// Describes a field or modifier of an opcode, see `Opcode::field_descs`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FieldDesc {
+    /// Name of the field or modifier in the ISA specification, e.g. `Rd` or `cond`
+    pub name: &'static str,
+    /// Bits which the field reads, excluding bits which are fixed by the opcode. Fields may share bits, e.g. the two
+    /// transfer registers of `ldrd`.
+    pub bitmask: u32,
+}
+
+impl FieldDesc {
+    /// Returns the value of this field in `code`, with its bits packed from lowest to highest like the value of
+    /// [`FieldFilter::pin`]
+    pub fn value(&self, code: u32) -> u32 {
+        extract(code, self.bitmask)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Selection {
+    Sweep,
+    Pin(u32),
+}
+
+/// Selects the encodings which `Opcode::enumerate` yields. Swept fields take every value and pinned fields take one
+/// value. All other bits which are not fixed by the opcode are zero.
+///
+/// Fields are selected by their name in [`FieldDesc`]. Fields which an opcode doesn't have are ignored, so one filter
+/// can be used for several opcodes. If a field is selected more than once, the last selection wins for the bits it
+/// reads.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct FieldFilter {
+    fields: Vec<(String, Selection)>,
+}
+
+impl FieldFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sweeps the field `name` over all of its values
+    pub fn sweep(mut self, name: &str) -> Self {
+        self.fields.push((name.to_string(), Selection::Sweep));
+        self
+    }
+
+    /// Pins the field `name` to the raw `value` of its bits. The bits of `value` are placed in the bits of the field
+    /// from lowest to highest, so for example `pin("Rn", 1)` selects `r1`. Bits of `value` which don't fit are
+    /// ignored.
+    pub fn pin(mut self, name: &str, value: u32) -> Self {
+        self.fields.push((name.to_string(), Selection::Pin(value)));
+        self
+    }
+
+    /// Returns the swept bits and the pinned bits of an opcode with the given fields
+    #[cfg(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    ))]
+    fn select(&self, fields: &[FieldDesc]) -> (u32, u32) {
+        let mut sweep = 0;
+        let mut pinned = 0;
+        for (name, selection) in self.fields.iter() {
+            for field in fields.iter().filter(|field| field.name == name) {
+                match selection {
+                    Selection::Sweep => {
+                        sweep |= field.bitmask;
+                        pinned &= !field.bitmask;
+                    }
+                    Selection::Pin(value) => {
+                        sweep &= !field.bitmask;
+                        pinned = (pinned & !field.bitmask) | deposit(*value, field.bitmask);
+                    }
+                }
+            }
+        }
+        (sweep, pinned)
+    }
+}
+
+/// Places the lowest bits of `value` in the set bits of `mask`, from lowest to highest
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+fn deposit(mut value: u32, mut mask: u32) -> u32 {
+    let mut result = 0;
+    while mask != 0 {
+        let bit = mask & mask.wrapping_neg();
+        if value & 1 != 0 {
+            result |= bit;
+        }
+        value >>= 1;
+        mask &= !bit;
+    }
+    result
+}
+
+/// Inverse of [`deposit`], gathers the bits of `value` selected by `mask` into the lowest bits
+fn extract(value: u32, mut mask: u32) -> u32 {
+    let mut result = 0;
+    let mut out = 1;
+    while mask != 0 {
+        let bit = mask & mask.wrapping_neg();
+        if value & bit != 0 {
+            result |= out;
+        }
+        out <<= 1;
+        mask &= !bit;
+    }
+    result
+}
+
+/// Yields the encodings of an opcode selected by `filter` in increasing order, skipping those which `decodes_to`
+/// rejects. Yields nothing if `fields` is `None`, i.e. for illegal opcodes.
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+pub(crate) fn enumerate(
+    bitmask: u32,
+    pattern: u32,
+    fields: Option<&[FieldDesc]>,
+    filter: &FieldFilter,
+    decodes_to: impl Fn(u32) -> bool,
+) -> impl Iterator<Item = u32> {
+    let first = fields.map(|_| 0u32);
+    let (sweep, pinned) = filter.select(fields.unwrap_or_default());
+    let sweep = sweep & !bitmask;
+    let base = (pattern & bitmask) | (pinned & !bitmask);
+    // Counts through the subsets of the swept bits
+    std::iter::successors(first, move |bits| {
+        let next = bits.wrapping_sub(sweep) & sweep;
+        (next != 0).then_some(next)
+    })
+    .map(move |bits| base | bits)
+    .filter(move |code| decodes_to(*code))
+}
@@ -0,0 +1,374 @@
+use std::fmt;
+
+use crate::{
+    args::{Argument, OffsetImm, OffsetReg, Reg, RegList, Register, Shift, ShiftImm, ShiftReg},
+    display::{IllegalStyle, Immediate, ImmediateStyle, ParsedInsDisplay},
+    ArgumentVisitor, DisplayOptions, ImmKind, ParsedIns, RegisterRole,
+};
+
+/// Receives a displayed instruction as a sequence of events instead of text, e.g. to color registers or make branch
+/// destinations clickable. The events come in the same order as the text of [`ParsedIns::display`], which is itself
+/// written by a `FormatVisitor`. See [`ParsedIns::visit_format`].
+///
+/// Every method does nothing by default. Events which belong to an argument take the index of the argument in
+/// [`ParsedIns::args`]. The visitor is responsible for applying [`DisplayOptions::reg_names`] and
+/// [`DisplayOptions::uppercase`], everything else is already applied to the events.
+pub trait FormatVisitor {
+    /// The mnemonic, or the string returned by [`DisplayOptions::mnemonic_hook`]
+    fn mnemonic(&mut self, mnemonic: &str) -> fmt::Result {
+        let _ = mnemonic;
+        Ok(())
+    }
+
+    /// Text between the parts of an instruction, e.g. `, ` between arguments. `index` is set for separators inside an
+    /// argument, such as the space between a shift and its amount or the commas of a register list.
+    fn separator(&mut self, index: Option<usize>, text: &str) -> fmt::Result {
+        let _ = (index, text);
+        Ok(())
+    }
+
+    /// Spaces which line up the operands, see [`DisplayOptions::mnemonic_width`]. Called right before the separator
+    /// between the mnemonic and the operands.
+    fn padding(&mut self, width: usize) -> fmt::Result {
+        let _ = width;
+        Ok(())
+    }
+
+    /// A register. The display text adds `!` after [`RegisterRole::Operand`] with writeback and `-` before a subtracted
+    /// [`RegisterRole::Offset`]. The writeback of a [`RegisterRole::Base`] is passed to [`Self::close_deref`] instead.
+    fn register(&mut self, index: usize, reg: Register, role: RegisterRole) -> fmt::Result {
+        let _ = (index, reg, role);
+        Ok(())
+    }
+
+    /// Three or more consecutive registers of a register list, see [`DisplayOptions::reg_list_ranges`]
+    fn register_range(&mut self, index: usize, first: Register, last: Register) -> fmt::Result {
+        let _ = (index, first, last);
+        Ok(())
+    }
+
+    /// An immediate with a `#` prefix, in the style chosen by [`DisplayOptions::immediate_style`] or
+    /// [`DisplayOptions::comment_field_style`]
+    fn immediate(&mut self, index: usize, imm: Immediate, kind: ImmKind) -> fmt::Result {
+        let _ = (index, imm, kind);
+        Ok(())
+    }
+
+    fn shift(&mut self, index: usize, shift: Shift) -> fmt::Result {
+        let _ = (index, shift);
+        Ok(())
+    }
+
+    /// The opening bracket of a memory operand, before its base register
+    fn open_deref(&mut self, index: usize) -> fmt::Result {
+        let _ = index;
+        Ok(())
+    }
+
+    /// The closing bracket of a memory operand, followed by `!` if `writeback` is set. `index` is the index of the base
+    /// register.
+    fn close_deref(&mut self, index: usize, writeback: bool) -> fmt::Result {
+        let _ = (index, writeback);
+        Ok(())
+    }
+
+    /// The opening brace of a register list
+    fn open_reg_list(&mut self, index: usize) -> fmt::Result {
+        let _ = index;
+        Ok(())
+    }
+
+    /// The closing brace of a register list, followed by `^` if `user_mode` is set
+    fn close_reg_list(&mut self, index: usize, user_mode: bool) -> fmt::Result {
+        let _ = (index, user_mode);
+        Ok(())
+    }
+
+    /// Arguments without any of the events above, e.g. [`Argument::StatusReg`] or [`Argument::CoprocNum`]. They're
+    /// displayed with their [`Display`](fmt::Display) implementation.
+    fn other(&mut self, index: usize, arg: &Argument) -> fmt::Result {
+        let _ = (index, arg);
+        Ok(())
+    }
+
+    /// A trailing comment, after a separator
+    fn comment(&mut self, comment: Comment) -> fmt::Result {
+        let _ = comment;
+        Ok(())
+    }
+}
+
+/// Trailing comment of an instruction, see [`FormatVisitor::comment`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Comment {
+    /// Address of a PC-relative load or address calculation, see [`DisplayOptions::pc_relative_comment`]
+    PcRelative(u32),
+    /// Name of a CP15 system register, see [`DisplayOptions::cp15_names`]
+    Cp15(&'static str),
+}
+
+impl ParsedIns {
+    /// Sends the instruction to `visitor` as it would be displayed with `options`. To include the branch destinations
+    /// and comments of [`Self::display_with_pc`], call `visit` on the value it returns instead.
+    pub fn visit_format<V: FormatVisitor + ?Sized>(&self, visitor: &mut V, options: DisplayOptions) -> fmt::Result {
+        self.display(options).visit(visitor)
+    }
+}
+
+impl ParsedInsDisplay<'_> {
+    /// Sends the instruction to `visitor` in the same order as its text, see [`FormatVisitor`]
+    pub fn visit<V: FormatVisitor + ?Sized>(&self, visitor: &mut V) -> fmt::Result {
+        let ins = self.ins();
+        let options = self.options();
+        if ins.is_illegal() {
+            match (options.illegal_style, self.code()) {
+                (IllegalStyle::Placeholder, _) | (IllegalStyle::RawWord, None) => {}
+                (IllegalStyle::RawWord, Some((code, size))) => {
+                    let mnemonic = ParsedIns::data(code, size).mnemonic;
+                    visitor.mnemonic(mnemonic)?;
+                    separate_operands(visitor, options, mnemonic.len())?;
+                    let code = Immediate::unsigned(code, ImmediateStyle::Hex);
+                    return visitor.immediate(0, code, ImmKind::RawWord);
+                }
+                (IllegalStyle::Custom(mnemonic), _) => return visitor.mnemonic(mnemonic),
+            }
+        }
+        let adr = options.adr.then(|| ins.adr_operands()).flatten();
+        let len = match options.mnemonic_hook.and_then(|hook| hook(ins)) {
+            Some(mnemonic) => {
+                visitor.mnemonic(&mnemonic)?;
+                mnemonic.chars().count()
+            }
+            None => {
+                let mnemonic = if adr.is_some() { ins.adr_mnemonic() } else { ins.mnemonic };
+                visitor.mnemonic(mnemonic)?;
+                mnemonic.len()
+            }
+        };
+        if ins.args[0] != Argument::None {
+            separate_operands(visitor, options, len)?;
+        }
+        let adr_address = match adr {
+            Some((rd, offset)) => self.visit_adr(visitor, rd, offset)?,
+            None => {
+                let mut driver = Driver {
+                    display: self,
+                    visitor,
+                    result: Ok(()),
+                    last_index: None,
+                };
+                ins.visit(&mut driver);
+                driver.result?;
+                None
+            }
+        };
+        if let (true, Some((address, mode)), None) = (options.pc_relative_comment, self.pc(), adr_address) {
+            if let Some(target) = ins.pc_relative_address(address, mode) {
+                visitor.separator(None, "  ")?;
+                visitor.comment(Comment::PcRelative(target))?;
+            }
+        }
+        if let Some(name) = options.cp15_names.then(|| self.cp15_name()).flatten() {
+            visitor.separator(None, "  ")?;
+            visitor.comment(Comment::Cp15(name))?;
+        }
+        Ok(())
+    }
+
+    /// Sends the arguments of `adr`, see [`DisplayOptions::adr`]. The offset or address takes the index of the
+    /// immediate it comes from. Returns the address if it was displayed.
+    fn visit_adr<V: FormatVisitor + ?Sized>(
+        &self,
+        visitor: &mut V,
+        rd: Register,
+        offset: i32,
+    ) -> Result<Option<u32>, fmt::Error> {
+        let ins = self.ins();
+        let index = ins.arg_count() - 1;
+        visitor.register(0, rd, RegisterRole::Operand { writeback: false })?;
+        visitor.separator(None, ", ")?;
+        let address = self.pc().and_then(|(address, mode)| ins.pc_relative_address(address, mode));
+        match address {
+            Some(address) => {
+                let imm = Immediate::unsigned(address, ImmediateStyle::Hex);
+                visitor.immediate(index, imm, ImmKind::PcRelativeAddress)?
+            }
+            None => {
+                let imm = Immediate::signed(offset, self.options().immediate_style);
+                visitor.immediate(index, imm, ImmKind::Offset)?
+            }
+        }
+        Ok(address)
+    }
+}
+
+/// Sends the arguments of an instruction to a [`FormatVisitor`]. Only the first error is kept, after which nothing is
+/// sent.
+struct Driver<'a, 'v, V: ?Sized> {
+    display: &'a ParsedInsDisplay<'a>,
+    visitor: &'v mut V,
+    result: fmt::Result,
+    last_index: Option<usize>,
+}
+
+impl<V: FormatVisitor + ?Sized> Driver<'_, '_, V> {
+    fn send(&mut self, event: impl FnOnce(&mut V) -> fmt::Result) {
+        if self.result.is_ok() {
+            self.result = event(self.visitor);
+        }
+    }
+
+    /// Sends a comma if `index` is not the same argument as the last event
+    fn separate(&mut self, index: usize) {
+        if self.last_index.is_some_and(|last| last != index) {
+            self.send(|v| v.separator(None, ", "));
+        }
+        self.last_index = Some(index);
+    }
+}
+
+impl<V: FormatVisitor + ?Sized> ArgumentVisitor for Driver<'_, '_, V> {
+    fn visit_argument(&mut self, index: usize, arg: &Argument) {
+        self.separate(index);
+        let ins = self.display.ins();
+        let mut options = self.display.options();
+        if let Some(style) = options.comment_field_style {
+            if index == 0 && (ins.swi_comment().is_some() || ins.bkpt_immediate().is_some()) {
+                options.immediate_style = style;
+            }
+        }
+        match (arg, self.display.pc()) {
+            (
+                &Argument::Reg(Reg {
+                    deref: true,
+                    reg,
+                    writeback,
+                }),
+                _,
+            ) => self.send(|v| v.register(index, reg, RegisterRole::Base { writeback })),
+            (Argument::BranchDest(_), Some((address, mode))) => {
+                // The argument is a branch destination, so this always succeeds
+                let dest = ins.branch_destination(address, mode).unwrap_or_default();
+                let imm = Immediate::unsigned(dest, ImmediateStyle::Hex);
+                self.send(|v| v.immediate(index, imm, ImmKind::BranchAddress))
+            }
+            _ => self.send(|v| format_argument(v, index, arg, options)),
+        }
+    }
+
+    fn visit_memory_begin(&mut self, index: usize) {
+        self.separate(index);
+        self.send(|v| v.open_deref(index));
+    }
+
+    fn visit_memory_end(&mut self, index: usize, writeback: bool) {
+        self.send(|v| v.close_deref(index, writeback));
+    }
+}
+
+/// Sends one argument to `visitor`, which is also how [`Argument::display`] writes a single argument
+/// Sends the padding after a mnemonic of `len` characters, see [`DisplayOptions::mnemonic_width`], and the separator
+/// before the operands
+fn separate_operands<V: FormatVisitor + ?Sized>(visitor: &mut V, options: DisplayOptions, len: usize) -> fmt::Result {
+    let padding = options.mnemonic_width.unwrap_or_default().saturating_sub(len + 1);
+    if padding > 0 {
+        visitor.padding(padding)?;
+    }
+    visitor.separator(None, options.operand_separator.as_str())
+}
+
+pub(crate) fn format_argument<V: FormatVisitor + ?Sized>(
+    visitor: &mut V,
+    index: usize,
+    arg: &Argument,
+    options: DisplayOptions,
+) -> fmt::Result {
+    let style = options.immediate_style;
+    match *arg {
+        Argument::None => Ok(()),
+        Argument::Reg(Reg { deref, reg, writeback }) => {
+            let role = if deref {
+                RegisterRole::Base { writeback }
+            } else {
+                RegisterRole::Operand { writeback }
+            };
+            visitor.register(index, reg, role)
+        }
+        Argument::RegList(list) => format_reg_list(visitor, index, list, options),
+        Argument::Shift(shift) => visitor.shift(index, shift),
+        Argument::ShiftImm(ShiftImm { imm, op }) => {
+            visitor.shift(index, op)?;
+            visitor.separator(Some(index), " ")?;
+            visitor.immediate(index, Immediate::unsigned(imm, style), ImmKind::ShiftAmount)
+        }
+        Argument::ShiftReg(ShiftReg { op, reg }) => {
+            visitor.shift(index, op)?;
+            visitor.separator(Some(index), " ")?;
+            visitor.register(index, reg, RegisterRole::ShiftAmount)
+        }
+        Argument::UImm(x) => visitor.immediate(index, Immediate::unsigned(x, style), ImmKind::Unsigned),
+        Argument::SatImm(x) => visitor.immediate(index, Immediate::unsigned(x, style), ImmKind::Saturation),
+        Argument::SImm(x) => visitor.immediate(index, Immediate::signed(x, style), ImmKind::Signed),
+        Argument::OffsetImm(OffsetImm { value, .. }) => {
+            visitor.immediate(index, Immediate::signed(value, style), ImmKind::Offset)
+        }
+        Argument::OffsetReg(OffsetReg { add, reg, .. }) => visitor.register(index, reg, RegisterRole::Offset { add }),
+        Argument::BranchDest(x) => visitor.immediate(index, Immediate::signed(x, style), ImmKind::BranchOffset),
+        // Coprocessor opcodes are always decimal
+        Argument::CoOpcode(x) => visitor.immediate(index, Immediate::unsigned(x, ImmediateStyle::Decimal), ImmKind::CoOpcode),
+        Argument::CoReg(_)
+        | Argument::StatusReg(_)
+        | Argument::StatusMask(_)
+        | Argument::CoOption(_)
+        | Argument::CoprocNum(_)
+        | Argument::CpsrMode(_)
+        | Argument::CpsrFlags(_)
+        | Argument::Endian(_)
+        | Argument::SReg(_)
+        | Argument::DReg(_)
+        | Argument::DRegIndex(_)
+        | Argument::SRegList(_)
+        | Argument::DRegList(_)
+        | Argument::VfpSysReg(_) => visitor.other(index, arg),
+    }
+}
+
+fn format_reg_list<V: FormatVisitor + ?Sized>(
+    visitor: &mut V,
+    index: usize,
+    list: RegList,
+    options: DisplayOptions,
+) -> fmt::Result {
+    visitor.open_reg_list(index)?;
+    let mut first = true;
+    let mut i = 0;
+    while i < 16 {
+        if (list.regs & (1 << i)) == 0 {
+            i += 1;
+            continue;
+        }
+        if !first {
+            visitor.separator(Some(index), ", ")?;
+        }
+        first = false;
+        let start = Register::parse(i);
+        let mut end = i;
+        if options.reg_list_ranges {
+            let names = options.reg_names;
+            while end < 15
+                && (list.regs & (1 << (end + 1))) != 0
+                && start.display(names).continues_to(end + 1 - i, Register::parse(end + 1))
+            {
+                end += 1;
+            }
+        }
+        if end - i >= 2 {
+            visitor.register_range(index, start, Register::parse(end))?;
+            i = end + 1;
+        } else {
+            visitor.register(index, start, RegisterRole::List)?;
+            i += 1;
+        }
+    }
+    visitor.close_reg_list(index, list.user_mode)
+}
@@ -1,12 +1,57 @@
+#[cfg(all(feature = "compact", feature = "fast"))]
+compile_error!("The compact and fast features are mutually exclusive");
+
+pub mod analysis;
 pub mod args;
+#[cfg(feature = "catch-panic")]
+mod catch;
+pub mod coproc;
+#[cfg(feature = "corpus")]
+pub mod corpus;
+mod cp15;
 mod display;
+pub mod encode;
+pub mod enumerate;
+mod format;
+mod listing;
+mod memory_map;
+mod newer;
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+mod no_panic;
 pub mod parse;
+#[cfg(all(feature = "arm", any(feature = "v4t", feature = "v5te", feature = "v6k")))]
+pub mod patch;
+pub mod pattern;
+mod search;
+mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod text;
+pub mod tuning;
 #[cfg(feature = "v4t")]
 pub mod v4t;
 #[cfg(feature = "v5te")]
 pub mod v5te;
 #[cfg(feature = "v6k")]
 pub mod v6k;
+pub mod view;
+mod visit;
 
-pub use display::{DisplayOptions, R9Use, RegNames};
+#[cfg(feature = "catch-panic")]
+pub use catch::ParseStats;
+pub use display::{
+    DisplayOptions, IllegalStyle, Immediate, ImmediateStyle, MnemonicHook, OperandSeparator, R9Use, RegNames, Token,
+    TokenKind, Tokens,
+};
+pub use format::{Comment, FormatVisitor};
+pub use listing::{Listing, OutputDialect};
+pub use memory_map::*;
+pub use newer::IllegalKind;
 pub use parse::*;
+pub use search::fuzzy_score;
+pub use stream::*;
+pub use text::TextError;
+pub use visit::*;
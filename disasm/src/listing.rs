@@ -0,0 +1,450 @@
+use std::{collections::BTreeMap, fmt};
+
+#[cfg(feature = "thumb")]
+use crate::args::OffsetImm;
+use crate::{
+    args::Argument, encode::EncodeError, window_with_modes, ArmVersion, DisplayOptions, Line, Op, ParseFlags, ParseMode,
+    ParsedIns, Parser, Provenance,
+};
+
+/// Column of the `@` comments in [`OutputDialect::GasSource`], after the indented instruction
+const COMMENT_COLUMN: usize = 36;
+
+/// How a [`Listing`] is written
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OutputDialect {
+    /// One line per instruction with its address, encoding and text, e.g. `02000000: e3a00001  mov r0, #0x1`. Branch
+    /// destinations are absolute addresses, and labels are on their own lines.
+    #[default]
+    Plain,
+    /// GNU assembler source which `arm-none-eabi-as` assembles back into the original bytes. Starts with `.syntax`,
+    /// `.arch` and `.fpu` directives for the parser, switches modes with `.arm` and `.thumb`, branches to labels and writes
+    /// the address of each line as an `@` comment. Data is written as `.word`, `.hword` and `.byte`, while illegal,
+    /// UNPREDICTABLE and other instructions which wouldn't assemble into the same encoding are written as `.inst` with
+    /// their disassembly in the comment. Big-endian code must be assembled with `-EB`.
+    GasSource,
+}
+
+/// A label in a [`Listing`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Label {
+    name: String,
+    /// Symbols given to [`Listing::with_symbol`] are global, generated labels are local
+    global: bool,
+}
+
+/// Disassembles a whole image into a listing, which is written with [`Display`](fmt::Display). Branch destinations in the
+/// image get a label, which is `func_` followed by the address for the destinations of `bl` and `blx`, and `lbl_`
+/// followed by the address otherwise.
+///
+/// ```
+/// use unarm::{ArmVersion, Endian, Listing, OutputDialect, ParseFlags, ParseMode, Parser};
+///
+/// let code = [0x01, 0x00, 0xa0, 0xe3, 0xfd, 0xff, 0xff, 0xea];
+/// let parser = Parser::new(ArmVersion::V5Te, ParseMode::Arm, 0, Endian::Little, ParseFlags::default(), &[]);
+/// let listing = Listing::new(&code, 0x2000000, &parser)
+///     .with_symbol(0x2000000, "Entry")
+///     .with_dialect(OutputDialect::GasSource);
+/// assert!(listing.to_string().contains("    b Entry"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Listing<'a> {
+    bytes: &'a [u8],
+    base: u32,
+    parser: Parser<'a>,
+    modes: &'a [(u32, ParseMode)],
+    symbols: BTreeMap<u32, String>,
+    dialect: OutputDialect,
+}
+
+impl<'a> Listing<'a> {
+    /// Creates a listing of `bytes` loaded at `base`. The version, mode, endianness and flags are taken from `parser`,
+    /// while its address and data are ignored, like in [`window`](crate::window).
+    pub fn new(bytes: &'a [u8], base: u32, parser: &Parser<'a>) -> Self {
+        Self {
+            bytes,
+            base,
+            parser: *parser,
+            modes: &[],
+            symbols: BTreeMap::new(),
+            dialect: OutputDialect::default(),
+        }
+    }
+
+    /// Switches the parse mode at the given addresses, see [`window_with_modes`]
+    pub fn with_modes(mut self, modes: &'a [(u32, ParseMode)]) -> Self {
+        self.modes = modes;
+        self
+    }
+
+    /// Names the line at `address`, instead of a generated label. In [`OutputDialect::GasSource`], the symbol is declared
+    /// with `.global`, and with `.thumb_func` in Thumb code. Symbols which aren't at the start of a line are left out.
+    pub fn with_symbol(mut self, address: u32, name: impl Into<String>) -> Self {
+        self.symbols.insert(address, name.into());
+        self
+    }
+
+    pub fn with_dialect(mut self, dialect: OutputDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    fn lines(&self) -> Vec<Line> {
+        window_with_modes(
+            self.bytes,
+            self.base,
+            self.base,
+            0,
+            self.bytes.len(),
+            &self.parser,
+            self.modes,
+        )
+    }
+
+    fn mode_at(&self, address: u32) -> ParseMode {
+        let index = self.modes.partition_point(|&(start, _)| start <= address);
+        index.checked_sub(1).map_or(self.parser.mode, |index| self.modes[index].1)
+    }
+
+    /// Returns the encoding of `size` bytes at `address`
+    #[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+    fn code(&self, address: u32, size: usize) -> u32 {
+        let bytes = &self.bytes[(address - self.base) as usize..];
+        Parser::new(
+            self.parser.version,
+            ParseMode::Data,
+            address,
+            self.parser.endian,
+            self.parser.flags,
+            bytes,
+        )
+        .peek_sized(size)
+        .unwrap_or_default()
+    }
+
+    /// Without a version there is no parser, so there are no listings
+    #[cfg(not(any(feature = "v4t", feature = "v5te", feature = "v6k")))]
+    fn code(&self, _address: u32, _size: usize) -> u32 {
+        0
+    }
+
+    /// Returns the labels of the symbols and branch destinations which are at the start of a line
+    fn labels(&self, lines: &[Line]) -> BTreeMap<u32, Label> {
+        let is_line_start = |address: u32| lines.binary_search_by_key(&address, |line| line.address).is_ok();
+        let mut labels: BTreeMap<u32, Label> = self
+            .symbols
+            .iter()
+            .filter(|(&address, _)| is_line_start(address))
+            .map(|(&address, name)| {
+                let label = Label {
+                    name: name.clone(),
+                    global: true,
+                };
+                (address, label)
+            })
+            .collect();
+        for line in lines {
+            let Some(dest) = line.ins.branch_destination(line.address, self.mode_at(line.address)) else {
+                continue;
+            };
+            if !is_line_start(dest) {
+                continue;
+            }
+            let prefix = if is_call(&line.ins) { "func" } else { "lbl" };
+            let label = Label {
+                name: format!("{prefix}_{dest:08x}"),
+                global: false,
+            };
+            labels.entry(dest).or_insert(label);
+        }
+        labels
+    }
+
+    /// Returns the label of the destination of a branch, or its offset from the branch if it's not in the listing
+    fn branch_target(&self, labels: &BTreeMap<u32, Label>, line: &Line, dest: u32) -> String {
+        match labels.get(&dest) {
+            Some(label) => label.name.clone(),
+            None => {
+                let offset = dest.wrapping_sub(line.address) as i32;
+                if offset < 0 {
+                    format!(". - {:#x}", offset.unsigned_abs())
+                } else {
+                    format!(". + {offset:#x}")
+                }
+            }
+        }
+    }
+
+    fn fmt_plain(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self.lines();
+        let labels = self.labels(&lines);
+        for line in &lines {
+            if let Some(label) = labels.get(&line.address) {
+                writeln!(f, "{}:", label.name)?;
+            }
+            let encoding = match line.provenance {
+                Provenance::CombinedThumbBl { first, second } => format!("{first:04x} {second:04x}"),
+                _ => format!(
+                    "{:0width$x}",
+                    self.code(line.address, line.size as usize),
+                    width = line.size as usize * 2
+                ),
+            };
+            let mode = self.mode_at(line.address);
+            let ins = line.ins.display_with_pc(DisplayOptions::default(), line.address, mode);
+            writeln!(f, "{:08x}: {encoding:<9}  {ins}", line.address)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_gas(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = &self.parser.flags;
+        writeln!(f, "    .syntax {}", if flags.ual { "unified" } else { "divided" })?;
+        writeln!(f, "    .arch {}", arch_name(self.parser.version))?;
+        if flags.vfp {
+            writeln!(f, "    .fpu vfpv2")?;
+        }
+
+        let lines = self.lines();
+        let labels = self.labels(&lines);
+        let mut current_mode = None;
+        for line in &lines {
+            let mode = self.mode_at(line.address);
+            if mode != ParseMode::Data && current_mode != Some(mode) {
+                writeln!(f, "    {}", mode_directive(mode))?;
+                current_mode = Some(mode);
+            }
+            if let Some(label) = labels.get(&line.address) {
+                if label.global {
+                    writeln!(f, "    .global {}", label.name)?;
+                    #[cfg(feature = "thumb")]
+                    if mode == ParseMode::Thumb {
+                        writeln!(f, "    .thumb_func")?;
+                    }
+                }
+                writeln!(f, "{}:", label.name)?;
+            }
+            self.fmt_gas_line(f, &labels, line, mode)?;
+        }
+        Ok(())
+    }
+
+    fn fmt_gas_line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        labels: &BTreeMap<u32, Label>,
+        line: &Line,
+        mode: ParseMode,
+    ) -> fmt::Result {
+        let disasm = line.ins.display(DisplayOptions::default()).to_string();
+        let (text, comment) = match line.provenance {
+            Provenance::DataFallback { .. } => {
+                let value = match line.ins.args[0] {
+                    Argument::UImm(value) => value,
+                    _ => 0,
+                };
+                (format!("{} {value:#x}", line.ins.mnemonic), String::new())
+            }
+            Provenance::CombinedThumbBl { first, second } => {
+                let dest = line.ins.branch_destination(line.address, mode).unwrap_or_default();
+                let blx = line.ins.mnemonic == "blx";
+                if thumb_bl_pair(line.address, dest, blx) == (first, second) {
+                    let target = self.branch_target(labels, line, dest);
+                    (format!("{} {target}", line.ins.mnemonic), String::new())
+                } else {
+                    let comment = format!(": {disasm}");
+                    (format!(".inst.n {first:#06x}, {second:#06x}"), comment)
+                }
+            }
+            Provenance::Plain => {
+                let code = self.code(line.address, line.size as usize);
+                match self.gas_instruction(labels, line, mode, code) {
+                    Some(text) => (text, String::new()),
+                    None if line.size == 2 => (format!(".inst.n {code:#06x}"), format!(": {disasm}")),
+                    None => (format!(".inst {code:#010x}"), format!(": {disasm}")),
+                }
+            }
+        };
+        let address = line.address;
+        writeln!(f, "    {text:<width$} @ {address:08x}{comment}", width = COMMENT_COLUMN - 4)
+    }
+
+    /// Returns whether the instruction of `line` is UNPREDICTABLE, which the assembler rejects, see
+    /// [`ParseFlags::unpredictable_as_illegal`](crate::ParseFlags::unpredictable_as_illegal)
+    #[cfg(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    ))]
+    fn is_unpredictable(&self, line: &Line, mode: ParseMode) -> bool {
+        let flags = ParseFlags {
+            unpredictable_as_illegal: true,
+            ..self.parser.flags
+        };
+        let bytes = &self.bytes[(line.address - self.base) as usize..];
+        let mut parser = Parser::new(self.parser.version, mode, line.address, self.parser.endian, flags, bytes);
+        parser.next().is_none_or(|(_, _, ins)| ins.is_illegal())
+    }
+
+    /// Without an instruction set and a version there are no instructions
+    #[cfg(not(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    )))]
+    fn is_unpredictable(&self, _line: &Line, _mode: ParseMode) -> bool {
+        false
+    }
+
+    /// Returns the source of an instruction, or `None` if it wouldn't assemble back into `code`
+    fn gas_instruction(&self, labels: &BTreeMap<u32, Label>, line: &Line, mode: ParseMode, code: u32) -> Option<String> {
+        let ins = &line.ins;
+        if ins.is_illegal()
+            || encode(line.op, ins, &self.parser.flags) != Ok(code)
+            || self.is_unpredictable(line, mode)
+            || assembles_differently(line, mode, code)
+        {
+            return None;
+        }
+        if let Some(dest) = ins.branch_destination(line.address, mode) {
+            // The destination is the only argument of a branch
+            let mnemonic = ParsedIns {
+                mnemonic: ins.mnemonic,
+                args: Default::default(),
+            };
+            let target = self.branch_target(labels, line, dest);
+            return Some(format!("{} {target}", mnemonic.display(DisplayOptions::default())));
+        }
+        let text = match gas_form(line, mode) {
+            Some(ins) => ins.display(DisplayOptions::default()).to_string(),
+            None => ins.display(DisplayOptions::default()).to_string(),
+        };
+        Some(text)
+    }
+}
+
+impl fmt::Display for Listing<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.dialect {
+            OutputDialect::Plain => self.fmt_plain(f),
+            OutputDialect::GasSource => self.fmt_gas(f),
+        }
+    }
+}
+
+/// Returns whether the assembler picks another encoding for the source of `line`, even though it means the same
+fn assembles_differently(line: &Line, mode: ParseMode, code: u32) -> bool {
+    // The assembler only writes both halves of a Thumb BL/BLX pair together
+    if is_call(&line.ins) && matches!(line.ins.args[0], Argument::SImm(_) | Argument::UImm(_)) {
+        return true;
+    }
+    match mode {
+        // The high register forms of `add`, `cmp` and `mov` with two low registers are assembled into the low register
+        // forms, or rejected as UNPREDICTABLE
+        #[cfg(feature = "thumb")]
+        ParseMode::Thumb => (0x4400..0x4700).contains(&(code & 0xff00)) && code & 0xc0 == 0,
+        _ => {
+            let _ = code;
+            false
+        }
+    }
+}
+
+/// Returns the instruction which the GNU assembler accepts in place of `line`, if it doesn't accept the displayed form
+/// or assembles it into another encoding
+fn gas_form(line: &Line, mode: ParseMode) -> Option<ParsedIns> {
+    match mode {
+        // `push` and `pop` of one register are assembled into `str` and `ldr`, so the `stm` and `ldm` encodings are
+        // written as such. `str` and `ldr` are written too, as their encodings depend on the assembler.
+        #[cfg(feature = "arm")]
+        ParseMode::Arm if matches!(line.ins.mnemonic_base(), "push" | "pop") => match line.ins.args[0] {
+            Argument::RegList(list) if list.len() > 1 => None,
+            _ => line.ins.aliased_from(line.op),
+        },
+        // Thumb `adr` only takes a label, so it's written as the `add` it's an alias of
+        #[cfg(feature = "thumb")]
+        ParseMode::Thumb if line.ins.mnemonic == "adr" => line.ins.aliased_from(line.op),
+        #[cfg(feature = "thumb")]
+        ParseMode::Thumb => {
+            // The 5-bit immediate offsets of byte and halfword transfers are displayed scaled by 4 like those of word
+            // transfers, while the assembler takes the byte offset
+            let scale = match line.ins.mnemonic {
+                "ldrb" | "strb" => 4,
+                "ldrh" | "strh" => 2,
+                _ => return None,
+            };
+            let Argument::OffsetImm(offset) = line.ins.args[2] else {
+                return None;
+            };
+            let mut ins = line.ins.clone();
+            ins.args[2] = Argument::OffsetImm(OffsetImm {
+                value: offset.value / scale,
+                ..offset
+            });
+            Some(ins)
+        }
+        _ => {
+            let _ = line;
+            None
+        }
+    }
+}
+
+/// Encodes `ins` as the opcode `op`
+fn encode(op: Op, ins: &ParsedIns, flags: &ParseFlags) -> Result<u32, EncodeError> {
+    match op {
+        #[cfg(all(feature = "v4t", feature = "arm"))]
+        Op::ArmV4T(op) => op.encode(ins, flags),
+        #[cfg(all(feature = "v4t", feature = "thumb"))]
+        Op::ThumbV4T(op) => op.encode(ins, flags),
+        #[cfg(all(feature = "v5te", feature = "arm"))]
+        Op::ArmV5Te(op) => op.encode(ins, flags),
+        #[cfg(all(feature = "v5te", feature = "thumb"))]
+        Op::ThumbV5Te(op) => op.encode(ins, flags),
+        #[cfg(all(feature = "v6k", feature = "arm"))]
+        Op::ArmV6K(op) => op.encode(ins, flags),
+        #[cfg(all(feature = "v6k", feature = "thumb"))]
+        Op::ThumbV6K(op) => op.encode(ins, flags),
+        Op::Data => {
+            let _ = (ins, flags);
+            Err(EncodeError::UnknownMnemonic)
+        }
+    }
+}
+
+fn is_call(ins: &ParsedIns) -> bool {
+    matches!(ins.mnemonic_base(), "bl" | "blx")
+}
+
+/// Returns the two halves which the assembler emits for a Thumb `bl` or `blx` at `address` to `dest`
+fn thumb_bl_pair(address: u32, dest: u32, blx: bool) -> (u16, u16) {
+    let pc = address.wrapping_add(4);
+    let pc = if blx { pc & !3 } else { pc };
+    let offset = dest.wrapping_sub(pc);
+    let first = 0xf000 | ((offset >> 12) & 0x7ff) as u16;
+    let second = if blx { 0xe800 } else { 0xf800 } | ((offset >> 1) & 0x7ff) as u16;
+    (first, second)
+}
+
+fn arch_name(version: ArmVersion) -> &'static str {
+    match version {
+        #[cfg(feature = "v4t")]
+        ArmVersion::V4T => "armv4t",
+        #[cfg(feature = "v4t")]
+        ArmVersion::V4 => "armv4",
+        #[cfg(feature = "v5te")]
+        ArmVersion::V5Te => "armv5te",
+        #[cfg(feature = "v5te")]
+        ArmVersion::V5T => "armv5t",
+        #[cfg(feature = "v6k")]
+        ArmVersion::V6K => "armv6k",
+    }
+}
+
+fn mode_directive(mode: ParseMode) -> &'static str {
+    match mode {
+        #[cfg(feature = "arm")]
+        ParseMode::Arm => ".arm",
+        #[cfg(feature = "thumb")]
+        ParseMode::Thumb => ".thumb",
+        ParseMode::Data => "",
+    }
+}
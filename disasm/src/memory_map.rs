@@ -0,0 +1,106 @@
+use std::ops::Range;
+
+use crate::{Op, ParsedIns, Parser, StreamOptions};
+
+/// A part of a file which is loaded at a given address, such as an overlay or a decompressed payload
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Segment {
+    /// Byte range of the segment in the file
+    pub file_range: Range<usize>,
+    /// Address of the first byte of the segment at runtime
+    pub load_addr: u32,
+}
+
+impl Segment {
+    /// Returns the runtime address range of the segment
+    pub fn addresses(&self) -> Range<u32> {
+        let size = self.file_range.len() as u32;
+        self.load_addr..self.load_addr.saturating_add(size)
+    }
+
+    pub fn contains(&self, address: u32) -> bool {
+        self.addresses().contains(&address)
+    }
+}
+
+/// Maps runtime addresses to file offsets, for files where the code doesn't run at the address of its file offset.
+/// Segments should not overlap in address space, if they do then the first matching segment is used.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct MemoryMap {
+    pub segments: Vec<Segment>,
+}
+
+impl MemoryMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `file_range` to start at `load_addr`
+    pub fn push(&mut self, file_range: Range<usize>, load_addr: u32) {
+        self.segments.push(Segment { file_range, load_addr });
+    }
+
+    /// Returns the segment containing `address`
+    pub fn segment(&self, address: u32) -> Option<&Segment> {
+        self.segments.iter().find(|segment| segment.contains(address))
+    }
+
+    pub fn is_mapped(&self, address: u32) -> bool {
+        self.segment(address).is_some()
+    }
+
+    /// Returns the file offset of `address`, or `None` if it's not in any segment
+    pub fn file_offset(&self, address: u32) -> Option<usize> {
+        let segment = self.segment(address)?;
+        Some(segment.file_range.start + (address - segment.load_addr) as usize)
+    }
+
+    /// Returns the bytes of `file` from `address` to the end of its segment. Returns `None` if `address` is not in any
+    /// segment, or if the segment is outside of `file`.
+    pub fn bytes_from<'a>(&self, file: &'a [u8], address: u32) -> Option<&'a [u8]> {
+        let segment = self.segment(address)?;
+        let start = segment.file_range.start + (address - segment.load_addr) as usize;
+        file.get(start..segment.file_range.end)
+    }
+
+    /// Disassembles each segment of `file` in order, at its load address. The version, mode, endianness and flags are
+    /// taken from `parser`, while its address and data are ignored. To resolve words in [`SkippedStyle::WordTable`] ranges
+    /// against all segments, parse a segment with [`Stream::with_map`] and use [`Stream::pointer_target`].
+    ///
+    /// [`SkippedStyle::WordTable`]: crate::SkippedStyle::WordTable
+    /// [`Stream::with_map`]: crate::Stream::with_map
+    /// [`Stream::pointer_target`]: crate::Stream::pointer_target
+    #[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+    pub fn stream<'a>(
+        &'a self,
+        file: &'a [u8],
+        parser: &Parser,
+        options: &StreamOptions,
+    ) -> impl Iterator<Item = (u32, Op, ParsedIns)> + 'a {
+        let parser = *parser;
+        let options = options.clone();
+        self.segments.iter().flat_map(move |segment| {
+            let code = file.get(segment.file_range.clone()).unwrap_or_default();
+            Parser::new(
+                parser.version,
+                parser.mode,
+                segment.load_addr,
+                parser.endian,
+                parser.flags,
+                code,
+            )
+            .with_options(options.clone())
+        })
+    }
+
+    /// Without a version there is no parser, so there is nothing to disassemble
+    #[cfg(not(any(feature = "v4t", feature = "v5te", feature = "v6k")))]
+    pub fn stream<'a>(
+        &'a self,
+        _file: &'a [u8],
+        _parser: &Parser,
+        _options: &StreamOptions,
+    ) -> impl Iterator<Item = (u32, Op, ParsedIns)> + 'a {
+        std::iter::empty()
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Why an instruction was decoded as illegal, see `Ins::illegal_kind`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IllegalKind {
+    /// Not an instruction of this version, or rejected by [`ParseFlags`](crate::ParseFlags)
+    Undefined,
+    /// A well-known encoding which a newer version of the architecture allocates, such as `movw` of ARMv6T2 or `dmb` of
+    /// ARMv7. It's most likely code for a newer core, not data or a bug.
+    NewerArchitecture {
+        /// Version which allocated the encoding, e.g. `ARMv7`
+        arch: &'static str,
+        /// Mnemonic of the encoding in that version, e.g. `dmb`
+        mnemonic: &'static str,
+    },
+}
+
+/// Displays `<illegal>`, or e.g. `<unknown: likely ARMv7 dmb>` for [`IllegalKind::NewerArchitecture`]
+impl Display for IllegalKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Undefined => write!(f, "<illegal>"),
+            Self::NewerArchitecture { arch, mnemonic } => write!(f, "<unknown: likely {arch} {mnemonic}>"),
+        }
+    }
+}
+
+/// ARM encodings of newer versions which ARMv5TE and ARMv6K leave unallocated, by bitmask and pattern. Only encodings
+/// which are common in code for newer cores are listed, as a hint for users who disassemble such code by mistake.
+#[cfg(all(feature = "arm", any(feature = "v5te", feature = "v6k")))]
+const NEWER_ARM_ENCODINGS: &[(u32, u32, &str, &str)] = &[
+    (0x0ff00000, 0x03000000, "ARMv6T2", "movw"),
+    (0x0ff00000, 0x03400000, "ARMv6T2", "movt"),
+    (0xfffffff0, 0xf57ff040, "ARMv7", "dsb"),
+    (0xfffffff0, 0xf57ff050, "ARMv7", "dmb"),
+    (0xfffffff0, 0xf57ff060, "ARMv7", "isb"),
+    // Immediate and register offset
+    (0xff70f000, 0xf450f000, "ARMv7", "pli"),
+    (0xff70f010, 0xf650f000, "ARMv7", "pli"),
+];
+
+/// Returns the newer encoding which `code` matches, see [`IllegalKind::NewerArchitecture`]. The condition of `movw` and
+/// `movt` must not be `0b1111`, as that's the unconditional instruction space.
+#[cfg(all(feature = "arm", any(feature = "v5te", feature = "v6k")))]
+pub(crate) const fn newer_arm_encoding(code: u32) -> Option<IllegalKind> {
+    // A loop instead of iterators, so that `Ins::const_new` can call this
+    let mut i = 0;
+    while i < NEWER_ARM_ENCODINGS.len() {
+        let (bitmask, pattern, arch, mnemonic) = NEWER_ARM_ENCODINGS[i];
+        if code & bitmask == pattern && (bitmask >> 28 == 0xf || code >> 28 != 0xf) {
+            return Some(IllegalKind::NewerArchitecture { arch, mnemonic });
+        }
+        i += 1;
+    }
+    None
+}
@@ -0,0 +1,34 @@
+//! Compile-time proof that `Opcode::find` and the generated parse functions can't panic, with the `no-panic` feature.
+//!
+//! Each of those functions holds a [`NoPanicGuard`] until it returns. With the feature, dropping the guard calls a
+//! function which doesn't exist, and the guard is only dropped if the function unwinds. An optimized build only keeps
+//! that call if the optimizer can't rule out a panic, in which case linking fails with an error naming this module.
+//! Debug builds can't rule out anything, so the guard does nothing unless debug assertions are off.
+//!
+//! `cargo xtask no-panic` links `tests/test_no_panic.rs` in release mode with overflow checks, so that arithmetic
+//! which would panic in a debug build is flagged as well.
+
+/// Fails to link if it's dropped by unwinding, see the module docs
+pub(crate) struct NoPanicGuard;
+
+impl NoPanicGuard {
+    /// Forgets the guard at the end of a function which returned normally
+    #[inline(always)]
+    // The guard only implements `Drop` in optimized builds with the feature
+    #[allow(clippy::forget_non_drop)]
+    pub(crate) const fn disarm(self) {
+        std::mem::forget(self);
+    }
+}
+
+#[cfg(all(feature = "no-panic", not(debug_assertions)))]
+impl Drop for NoPanicGuard {
+    #[inline(always)]
+    fn drop(&mut self) {
+        extern "C" {
+            #[link_name = "\n\nERROR: the unarm decode path may panic, see disasm/src/no_panic.rs\n\n"]
+            fn decode_path_may_panic() -> !;
+        }
+        unsafe { decode_path_may_panic() }
+    }
+}
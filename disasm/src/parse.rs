@@ -1,10 +1,11 @@
-use crate::args::{Argument, Arguments};
+use crate::args::{Argument, ArgumentKind, Arguments, OffsetImm, Reg, Register, MAX_ARGS};
 #[cfg(feature = "v4t")]
 use crate::v4t;
 #[cfg(feature = "v5te")]
 use crate::v5te;
 #[cfg(feature = "v6k")]
 use crate::v6k;
+use crate::view::{CoAddrMode, CoLoadStoreView};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Parser<'a> {
@@ -14,6 +15,9 @@ pub struct Parser<'a> {
     pub endian: Endian,
     pub flags: ParseFlags,
     data: &'a [u8],
+    /// Counters updated by [`Parser::parse_catching`], see [`Parser::with_stats`]
+    #[cfg(feature = "catch-panic")]
+    pub(crate) stats: Option<&'a crate::ParseStats>,
 }
 
 impl<'a> Parser<'a> {
@@ -25,21 +29,94 @@ impl<'a> Parser<'a> {
             endian,
             flags,
             data,
+            #[cfg(feature = "catch-panic")]
+            stats: None,
         }
     }
 
-    fn read_code(&mut self) -> Option<(u32, u32)> {
-        let ins_size = self.mode.instruction_size(self.address);
-        if self.data.len() < ins_size {
+    /// Returns the next instruction code without advancing the parser
+    #[cfg(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    ))]
+    pub(crate) fn peek_code(&self) -> Option<u32> {
+        self.peek_sized(self.mode.instruction_size(self.address))
+    }
+
+    /// Returns the next 1, 2 or 4 bytes as an integer without advancing the parser
+    pub(crate) fn peek_sized(&self, size: usize) -> Option<u32> {
+        if self.data.len() < size {
             return None;
         }
-        let code = match (self.endian, ins_size) {
+        let code = match (self.endian, size) {
+            (_, 1) => self.data[0] as u32,
             (Endian::Little, 2) => u16::from_le_bytes([self.data[0], self.data[1]]) as u32,
             (Endian::Little, 4) => u32::from_le_bytes([self.data[0], self.data[1], self.data[2], self.data[3]]),
             (Endian::Big, 2) => u16::from_be_bytes([self.data[0], self.data[1]]) as u32,
             (Endian::Big, 4) => u32::from_be_bytes([self.data[0], self.data[1], self.data[2], self.data[3]]),
             _ => return None,
         };
+        Some(code)
+    }
+
+    /// Advances the parser by `size` bytes, or to the end of the data
+    pub(crate) fn advance(&mut self, size: usize) {
+        let size = size.min(self.data.len());
+        self.data = &self.data[size..];
+        self.address += size as u32;
+    }
+
+    /// Returns the number of bytes left to parse
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns a copy of this parser which stops after `size` bytes
+    #[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+    pub(crate) fn limit(&self, size: usize) -> Self {
+        Self {
+            data: &self.data[..size.min(self.data.len())],
+            ..*self
+        }
+    }
+
+    /// Switches the mode of the lines after the current address, like a `$a`, `$t` or `$d` mapping symbol at this address.
+    /// In [`ParseMode::Data`], the bytes are emitted as `.word`, `.hword` and `.byte` lines until the mode is switched
+    /// back, e.g. over a literal pool. Switch back to ARM or Thumb at an instruction boundary of that mode, as the parser
+    /// doesn't realign by itself.
+    pub fn set_mode(&mut self, mode: ParseMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the number of instructions to decode and parse per batch in the current mode, see
+    /// [`tuning::recommended_batch_size`](crate::tuning::recommended_batch_size)
+    pub fn recommended_batch_size(&self) -> usize {
+        crate::tuning::recommended_batch_size(self.mode.instruction_size(self.address))
+    }
+
+    /// Reads one data line of at most `max_size` bytes, which is a word, halfword or byte depending on the alignment of
+    /// the address and the number of bytes left. Returns the value and its size in bytes.
+    pub(crate) fn read_data(&mut self, max_size: usize) -> Option<(u32, usize)> {
+        let mut size = if self.address & 1 != 0 {
+            1
+        } else {
+            ParseMode::Data.instruction_size(self.address)
+        };
+        while size > max_size.min(self.remaining()) && size > 1 {
+            size /= 2;
+        }
+        let value = self.peek_sized(size)?;
+        self.advance(size);
+        Some((value, size))
+    }
+
+    #[cfg(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    ))]
+    fn read_code(&mut self) -> Option<(u32, u32)> {
+        let ins_size = self.mode.instruction_size(self.address);
+        let code = self.peek_code()?;
         self.data = &self.data[ins_size..];
         self.address += ins_size as u32;
         Some((ins_size as u32, code))
@@ -48,25 +125,56 @@ impl<'a> Parser<'a> {
 
 #[derive(Clone, Copy, Debug)]
 pub struct ParseFlags {
+    /// Parses into the Unified Assembler Language, e.g. `svc`, `qasx` and `ldmiblt`, instead of the divided syntax of ARM
+    /// and Thumb, e.g. `swi`, `qaddsubx` and `ldmltib`.
     pub ual: bool,
+    /// Parses encodings which are UNPREDICTABLE as illegal instructions, see `Ins::is_unpredictable`.
+    pub unpredictable_as_illegal: bool,
+    /// Parses instructions with an `Illegal` argument value as illegal instructions, see
+    /// [`ParsedIns::has_illegal_operand`].
+    pub illegal_operand_as_illegal: bool,
+    /// Decodes coprocessor 10 and 11 instructions as VFP instructions, such as `vadd.f32` and `vpush`, instead of
+    /// generic coprocessor instructions. Requires the `ext-vfp` feature.
+    pub vfp: bool,
+    /// Parses instructions as a preferred equivalent instruction where one exists. A single-register `ldm` or `stm` with
+    /// increment-after addressing and no writeback is parsed as `ldr` or `str`, e.g. `ldm r0, {r3}` as
+    /// `ldr r3, [r0, #0x0]`. See [`RegList::len`](crate::args::RegList::len) to implement other policies.
+    pub aliases: bool,
+    /// Parses the unallocated hints of ARMv6K as illegal instructions instead of `hint #n`. They execute as `nop`, but
+    /// are unlikely to appear in real code.
+    pub unallocated_hint_as_illegal: bool,
+}
+
+impl ParseFlags {
+    /// Same as [`ParseFlags::default`], but usable in constants, e.g. with `Ins::const_new`.
+    pub const DEFAULT: Self = Self {
+        ual: true,
+        unpredictable_as_illegal: false,
+        illegal_operand_as_illegal: false,
+        vfp: false,
+        aliases: false,
+        unallocated_hint_as_illegal: false,
+    };
 }
 
 impl Default for ParseFlags {
     fn default() -> Self {
-        Self { ual: true }
+        Self::DEFAULT
     }
 }
 
 macro_rules! parse_arm {
-    ($self:expr, $module:ident, $op:ident, $code:expr) => {{
-        let ins = $module::arm::Ins::new($code, &$self.flags);
+    ($self:expr, $module:ident, $op:ident) => {{
+        let (_, code) = $self.read_code()?;
+        let ins = $module::arm::Ins::new(code, &$self.flags);
         (Op::$op(ins.op), ins.parse(&$self.flags))
     }};
 }
 
 macro_rules! parse_thumb {
-    ($self:expr, $module:ident, $op:ident, $code:expr) => {{
-        let ins = $module::thumb::Ins::new($code, &$self.flags);
+    ($self:expr, $module:ident, $op:ident) => {{
+        let (_, code) = $self.read_code()?;
+        let ins = $module::thumb::Ins::new(code, &$self.flags);
         let op = Op::$op(ins.op);
         let parsed = ins.parse(&$self.flags);
         if ins.is_half_bl() {
@@ -86,34 +194,41 @@ impl<'a> Iterator for Parser<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let address = self.address;
-        let (ins_size, code) = self.read_code()?;
-
         let (op, ins) = match (self.version, self.mode) {
             #[cfg(all(feature = "v4t", feature = "arm"))]
-            (ArmVersion::V4T, ParseMode::Arm) => parse_arm!(self, v4t, ArmV4T, code),
+            (ArmVersion::V4T, ParseMode::Arm) => parse_arm!(self, v4t, ArmV4T),
+            #[cfg(all(feature = "v4t", feature = "thumb"))]
+            (ArmVersion::V4T, ParseMode::Thumb) => parse_thumb!(self, v4t, ThumbV4T),
+            #[cfg(all(feature = "v4t", feature = "arm"))]
+            (ArmVersion::V4, ParseMode::Arm) => parse_arm!(self, v4t, ArmV4T),
+            // Without Thumb, the second half of a BL pair must not be consumed
             #[cfg(all(feature = "v4t", feature = "thumb"))]
-            (ArmVersion::V4T, ParseMode::Thumb) => parse_thumb!(self, v4t, ThumbV4T, code),
+            (ArmVersion::V4, ParseMode::Thumb) => {
+                self.read_code()?;
+                (Op::ThumbV4T(v4t::thumb::Opcode::Illegal), ParsedIns::illegal())
+            }
             #[cfg(all(feature = "v5te", feature = "arm"))]
-            (ArmVersion::V5Te, ParseMode::Arm) => parse_arm!(self, v5te, ArmV5Te, code),
+            (ArmVersion::V5Te | ArmVersion::V5T, ParseMode::Arm) => parse_arm!(self, v5te, ArmV5Te),
             #[cfg(all(feature = "v5te", feature = "thumb"))]
-            (ArmVersion::V5Te, ParseMode::Thumb) => parse_thumb!(self, v5te, ThumbV5Te, code),
+            (ArmVersion::V5Te | ArmVersion::V5T, ParseMode::Thumb) => parse_thumb!(self, v5te, ThumbV5Te),
             #[cfg(all(feature = "v6k", feature = "arm"))]
-            (ArmVersion::V6K, ParseMode::Arm) => parse_arm!(self, v6k, ArmV6K, code),
+            (ArmVersion::V6K, ParseMode::Arm) => parse_arm!(self, v6k, ArmV6K),
             #[cfg(all(feature = "v6k", feature = "thumb"))]
-            (ArmVersion::V6K, ParseMode::Thumb) => parse_thumb!(self, v6k, ThumbV6K, code),
+            (ArmVersion::V6K, ParseMode::Thumb) => parse_thumb!(self, v6k, ThumbV6K),
             (_, ParseMode::Data) => {
-                let mut args = Arguments::default();
-                args[0] = Argument::UImm(code);
-                let mnemonic = if ins_size == 4 { ".word" } else { ".hword" };
-                (Op::Data, ParsedIns { mnemonic, args })
+                let (value, size) = self.read_data(usize::MAX)?;
+                (Op::Data, ParsedIns::data(value, size))
             }
         };
+        if !self.version.supports(op) {
+            return Some((address, op.illegal(), ParsedIns::illegal()));
+        }
 
         Some((address, op, ins))
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum ArmVersion {
     #[cfg(feature = "v4t")]
     V4T,
@@ -121,9 +236,32 @@ pub enum ArmVersion {
     V5Te,
     #[cfg(feature = "v6k")]
     V6K,
+    /// ARMv4 without Thumb, decoded like [`ArmVersion::V4T`] but without `bx` and Thumb instructions
+    #[cfg(feature = "v4t")]
+    V4,
+    /// ARMv5T without the enhanced DSP extension, decoded like [`ArmVersion::V5Te`] but without the `dsp` extension
+    /// opcodes such as `qadd`, `smulbb`, `ldrd`/`strd` and `pld`
+    #[cfg(feature = "v5te")]
+    V5T,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl ArmVersion {
+    /// Returns whether this version has the instruction `op`. Only [`ArmVersion::V4`] and [`ArmVersion::V5T`] reject
+    /// instructions of the decoder they share, which [`Parser`] then parses as illegal.
+    pub fn supports(self, op: Op) -> bool {
+        match (self, op) {
+            #[cfg(all(feature = "v4t", feature = "arm"))]
+            (Self::V4, Op::ArmV4T(op)) => op != v4t::arm::Opcode::Bx,
+            #[cfg(all(feature = "v4t", feature = "thumb"))]
+            (Self::V4, Op::ThumbV4T(_)) => false,
+            #[cfg(all(feature = "v5te", feature = "arm"))]
+            (Self::V5T, Op::ArmV5Te(op)) => op.extension().is_none(),
+            _ => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum ParseMode {
     #[cfg(feature = "arm")]
     Arm,
@@ -155,13 +293,14 @@ impl ParseMode {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Endian {
     Little,
     Big,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Op {
     #[cfg(all(feature = "v4t", feature = "arm"))]
     ArmV4T(v4t::arm::Opcode),
@@ -179,6 +318,25 @@ pub enum Op {
 }
 
 impl Op {
+    /// Returns the illegal opcode of the same decoder
+    pub fn illegal(self) -> Self {
+        match self {
+            #[cfg(all(feature = "v4t", feature = "arm"))]
+            Self::ArmV4T(_) => Self::ArmV4T(v4t::arm::Opcode::Illegal),
+            #[cfg(all(feature = "v4t", feature = "thumb"))]
+            Self::ThumbV4T(_) => Self::ThumbV4T(v4t::thumb::Opcode::Illegal),
+            #[cfg(all(feature = "v5te", feature = "arm"))]
+            Self::ArmV5Te(_) => Self::ArmV5Te(v5te::arm::Opcode::Illegal),
+            #[cfg(all(feature = "v5te", feature = "thumb"))]
+            Self::ThumbV5Te(_) => Self::ThumbV5Te(v5te::thumb::Opcode::Illegal),
+            #[cfg(all(feature = "v6k", feature = "arm"))]
+            Self::ArmV6K(_) => Self::ArmV6K(v6k::arm::Opcode::Illegal),
+            #[cfg(all(feature = "v6k", feature = "thumb"))]
+            Self::ThumbV6K(_) => Self::ThumbV6K(v6k::thumb::Opcode::Illegal),
+            Self::Data => Self::Data,
+        }
+    }
+
     pub fn id(self) -> u16 {
         match self {
             #[cfg(all(feature = "v4t", feature = "arm"))]
@@ -198,15 +356,350 @@ impl Op {
     }
 }
 
-#[derive(Default, Debug)]
+/// Condition code of an instruction, see `Ins::condition`. The discriminants match the encoding.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[repr(u8)]
+pub enum Condition {
+    /// Equal
+    Eq = 0x0,
+    /// Not equal
+    Ne = 0x1,
+    /// Unsigned higher or same
+    Hs = 0x2,
+    /// Unsigned lower
+    Lo = 0x3,
+    /// Minus/negative
+    Mi = 0x4,
+    /// Plus/positive or zero
+    Pl = 0x5,
+    /// Overflow
+    Vs = 0x6,
+    /// No overflow
+    Vc = 0x7,
+    /// Unsigned higher
+    Hi = 0x8,
+    /// Unsigned lower or same
+    Ls = 0x9,
+    /// Signed greater than or equal
+    Ge = 0xa,
+    /// Signed less than
+    Lt = 0xb,
+    /// Signed greater than
+    Gt = 0xc,
+    /// Signed less than or equal
+    Le = 0xd,
+    /// Always
+    Al = 0xe,
+    /// Unconditional instruction space, which has no condition
+    Nv = 0xf,
+}
+
+impl Condition {
+    /// Parses the low 4 bits of `value`
+    pub fn parse(value: u32) -> Self {
+        match value & 0xf {
+            0x0 => Self::Eq,
+            0x1 => Self::Ne,
+            0x2 => Self::Hs,
+            0x3 => Self::Lo,
+            0x4 => Self::Mi,
+            0x5 => Self::Pl,
+            0x6 => Self::Vs,
+            0x7 => Self::Vc,
+            0x8 => Self::Hi,
+            0x9 => Self::Ls,
+            0xa => Self::Ge,
+            0xb => Self::Lt,
+            0xc => Self::Gt,
+            0xd => Self::Le,
+            0xe => Self::Al,
+            _ => Self::Nv,
+        }
+    }
+
+    /// Parses a mnemonic suffix, see [`Condition::suffix`]. Returns `None` for the empty suffix, as it's ambiguous.
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        (0x0..0xe).map(Self::parse).find(|cond| cond.suffix() == suffix)
+    }
+
+    /// Returns the mnemonic suffix, which is empty for [`Condition::Al`] and [`Condition::Nv`]
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Hs => "hs",
+            Self::Lo => "lo",
+            Self::Mi => "mi",
+            Self::Pl => "pl",
+            Self::Vs => "vs",
+            Self::Vc => "vc",
+            Self::Hi => "hi",
+            Self::Ls => "ls",
+            Self::Ge => "ge",
+            Self::Lt => "lt",
+            Self::Gt => "gt",
+            Self::Le => "le",
+            Self::Al | Self::Nv => "",
+        }
+    }
+}
+
+/// Describes an argument which an opcode can produce, see `Opcode::arg_meta`
+///
+/// Non-optional arguments always come first, so their index is also their index in [`ParsedIns::args`]. The generator
+/// enforces these positions for every opcode:
+/// - Load/store multiple: the base register comes first, followed by the register list
+/// - Data processing, loads, stores and `strex`: the destination or transfer register `Rd` comes first
+/// - Multiplies: the destination `RdHi` comes first, long multiplies start with `RdLo, RdHi`
+/// - Coprocessor transfers follow the assembler syntax, e.g. `mcr p15, 0, Rd, ...`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ArgMeta {
+    /// Name of the field in the ISA specification, e.g. `Rd`
+    pub name: &'static str,
+    /// If true, the argument only appears for some modifier cases
+    pub optional: bool,
+    pub kind: ArgumentKind,
+}
+
+/// Parsed instructions are ordered lexicographically by mnemonic, then by arguments, like the tuple `(mnemonic, args)`.
+/// The order is based on the representation, not on what the instruction does, but it is consistent with `Eq` so a
+/// corpus can be sorted and deduplicated directly.
+#[derive(Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ParsedIns {
     pub mnemonic: &'static str,
     pub args: Arguments,
 }
 
+/// Mnemonic and argument kinds of an instruction, see [`ParsedIns::signature`]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct InsSignature {
+    /// Mnemonic without its condition suffix
+    pub mnemonic: &'static str,
+    pub kinds: [ArgumentKind; MAX_ARGS],
+}
+
 impl ParsedIns {
+    /// Iterates over the arguments up to the first [`Argument::None`], see [`Self::args`]
     pub fn args_iter(&self) -> impl Iterator<Item = &Argument> {
-        self.args.iter().take_while(|a| **a != Argument::None)
+        self.args().iter()
+    }
+
+    /// Returns the arguments up to the first [`Argument::None`], which pads the rest of [`ParsedIns::args`]. Empty for
+    /// instructions without arguments, like `nop` and `clrex`. See [`Self::arg_count`].
+    pub fn args(&self) -> &[Argument] {
+        &self.args[..self.arg_count()]
+    }
+
+    /// Returns the number of arguments before the first [`Argument::None`]. This counts argument slots, not operands: a
+    /// memory operand takes one slot for its base register and one for each offset and shift, e.g. `ldr r0, [r1, #0x4]`
+    /// has three arguments.
+    pub fn arg_count(&self) -> usize {
+        self.args
+            .iter()
+            .position(|arg| *arg == Argument::None)
+            .unwrap_or(self.args.len())
+    }
+
+    /// Returns true if this is an illegal instruction, i.e. the code didn't decode into any opcode or was rejected by the
+    /// [`ParseFlags`]. See [`DisplayOptions::illegal_style`](crate::DisplayOptions::illegal_style).
+    pub fn is_illegal(&self) -> bool {
+        self.mnemonic == "<illegal>"
+    }
+
+    /// Returns true if any argument contains an `Illegal` value, e.g. a register field which is out of range. Such
+    /// arguments are displayed as `<illegal>`.
+    pub fn has_illegal_operand(&self) -> bool {
+        self.args_iter().any(|arg| arg.is_illegal())
+    }
+
+    /// Returns the mnemonic without its condition suffix, e.g. `adc` for `adcne` and `ldrb` for `ldrneb`. Other
+    /// suffixes such as `s` are kept.
+    pub fn mnemonic_base(&self) -> &'static str {
+        self.split_condition().0
+    }
+
+    /// Returns the condition from the mnemonic suffix. Mnemonics without a condition suffix return [`Condition::Al`],
+    /// use `Ins::condition` to also detect the unconditional instruction space.
+    pub fn condition(&self) -> Condition {
+        self.split_condition().1
+    }
+
+    /// Returns true if both instructions are the same apart from their condition, e.g. `addne r0, r1, r2` and
+    /// `add r0, r1, r2`
+    pub fn eq_ignoring_condition(&self, other: &ParsedIns) -> bool {
+        self.args == other.args && self.mnemonic_base() == other.mnemonic_base()
+    }
+
+    /// Returns the mnemonic without its condition suffix and the kinds of the arguments, which erases registers and
+    /// immediate values. Instructions with the same signature have the same shape, e.g. `addne r0, r1, r2` and
+    /// `addeq r3, r4, r5`.
+    pub fn signature(&self) -> InsSignature {
+        InsSignature {
+            mnemonic: self.mnemonic_base(),
+            kinds: self.args.map(|arg| arg.kind()),
+        }
+    }
+
+    /// Returns the comment field of a `swi` or `svc` instruction, which is 24 bits in ARM mode and 8 bits in Thumb mode.
+    /// The value is the same regardless of [`DisplayOptions::comment_field_style`], so tables of SWI names can be keyed
+    /// on it.
+    ///
+    /// [`DisplayOptions::comment_field_style`]: crate::DisplayOptions::comment_field_style
+    pub fn swi_comment(&self) -> Option<u32> {
+        match (self.mnemonic_base(), self.args[0]) {
+            ("swi" | "svc", Argument::UImm(comment)) => Some(comment),
+            _ => None,
+        }
+    }
+
+    /// Returns the immediate of a `bkpt` instruction, which is 16 bits in ARM mode and 8 bits in Thumb mode
+    pub fn bkpt_immediate(&self) -> Option<u32> {
+        match (self.mnemonic, self.args[0]) {
+            ("bkpt", Argument::UImm(imm)) => Some(imm),
+            _ => None,
+        }
+    }
+
+    /// Splits the mnemonic into the mnemonic without a condition suffix, and the condition
+    fn split_condition(&self) -> (&'static str, Condition) {
+        if let Some(mnemonic) = unconditional_mnemonic(self.mnemonic) {
+            return (mnemonic, Condition::Al);
+        }
+        // A buffer on the stack, as displaying instructions must not allocate
+        let mut buffer = [0u8; 16];
+        let len = self.mnemonic.len();
+        let end = if len.saturating_sub(2) <= buffer.len() {
+            len.saturating_sub(1)
+        } else {
+            1
+        };
+        for index in 1..end {
+            let Some(cond) = self.mnemonic.get(index..index + 2).and_then(Condition::from_suffix) else {
+                continue;
+            };
+            let bytes = self.mnemonic.as_bytes();
+            buffer[..index].copy_from_slice(&bytes[..index]);
+            buffer[index..len - 2].copy_from_slice(&bytes[index + 2..]);
+            let Ok(stripped) = std::str::from_utf8(&buffer[..len - 2]) else {
+                continue;
+            };
+            if let Some(mnemonic) = unconditional_mnemonic(stripped) {
+                return (mnemonic, cond);
+            }
+        }
+        (self.mnemonic, Condition::Al)
+    }
+
+    /// Finds the bits of `code` which affect the argument at `index`, by flipping each bit and checking which arguments
+    /// change. Bits which change the mnemonic are not attributed to any argument.
+    #[cfg(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    ))]
+    pub(crate) fn arg_bitmask(&self, code: u32, size: u32, index: usize, parse: impl Fn(u32) -> ParsedIns) -> u32 {
+        if index >= self.arg_count() {
+            return 0;
+        }
+        let mut mask = 0;
+        for bit in 0..size {
+            let flipped = parse(code ^ (1 << bit));
+            if flipped.mnemonic == self.mnemonic && flipped.args[index] != self.args[index] {
+                mask |= 1 << bit;
+            }
+        }
+        mask
+    }
+
+    /// Returns the absolute address this instruction branches to, if it has a [`Argument::BranchDest`]. The branch offset
+    /// already includes the PC offset of the pipeline, so only the `address` of the instruction is needed. In Thumb mode,
+    /// BLX switches to ARM mode, so its destination is aligned to 4 bytes.
+    pub fn branch_destination(&self, address: u32, mode: ParseMode) -> Option<u32> {
+        let dest = self.args_iter().find_map(|arg| match arg {
+            Argument::BranchDest(dest) => Some(*dest),
+            _ => None,
+        })?;
+        let target = address.wrapping_add(dest as u32);
+        match mode {
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb if self.mnemonic == "blx" => Some(target & !3),
+            _ => Some(target),
+        }
+    }
+
+    /// Returns the address which a PC-relative load or address calculation refers to, e.g. the literal pool entry of
+    /// `ldr r0, [pc, #0x1c]` or the result of `adr`. Pre-indexed loads without writeback, `add`/`sub` with PC and an
+    /// immediate, Thumb `adr`, and coprocessor loads and stores with offset addressing are detected. PC reads as
+    /// `address + 8` in ARM mode, and as `address + 4` aligned down to 4 bytes in Thumb mode.
+    pub fn pc_relative_address(&self, address: u32, mode: ParseMode) -> Option<u32> {
+        if mode == ParseMode::Data {
+            return None;
+        }
+        let pc = pc_read_value(address, mode, true);
+        let pc_operand = |deref: bool| {
+            Argument::Reg(Reg {
+                deref,
+                reg: Register::Pc,
+                writeback: false,
+            })
+        };
+        let offset = match self.adr_operands() {
+            Some((_, offset)) => offset,
+            None if self.mnemonic_base().starts_with("ldr") => {
+                let base = self.args_iter().position(|arg| *arg == pc_operand(true))?;
+                match self.args.get(base + 1).copied().unwrap_or_default() {
+                    Argument::None => 0,
+                    Argument::OffsetImm(OffsetImm {
+                        post_indexed: false,
+                        value,
+                    }) => value,
+                    // Thumb encodes the offset as an unsigned immediate
+                    Argument::UImm(imm) => imm as i32,
+                    _ => return None,
+                }
+            }
+            None => match CoLoadStoreView::try_from(self) {
+                Ok(CoLoadStoreView {
+                    base: Register::Pc,
+                    mode: CoAddrMode::Offset,
+                    offset,
+                    ..
+                }) => offset,
+                _ => return None,
+            },
+        };
+        Some(pc.wrapping_add(offset as u32))
+    }
+
+    /// Returns the destination register and PC offset of `adr`, or of an `add` or `sub` of PC and an immediate which is
+    /// equivalent to it. See [`DisplayOptions::adr`](crate::DisplayOptions::adr).
+    pub(crate) fn adr_operands(&self) -> Option<(Register, i32)> {
+        let Argument::Reg(Reg {
+            deref: false,
+            reg: rd,
+            writeback: false,
+        }) = self.args[0]
+        else {
+            return None;
+        };
+        let pc = Argument::Reg(Reg {
+            deref: false,
+            reg: Register::Pc,
+            writeback: false,
+        });
+        let mnemonic = self.mnemonic_base();
+        let offset = match (mnemonic, self.args[1], self.args[2], self.args[3]) {
+            ("adr", Argument::UImm(imm), Argument::None, _) => imm as i32,
+            ("add", arg, Argument::UImm(imm), Argument::None) if arg == pc => imm as i32,
+            ("sub", arg, Argument::UImm(imm), Argument::None) if arg == pc => (imm as i32).wrapping_neg(),
+            _ => return None,
+        };
+        Some((rd, offset))
+    }
+
+    /// Returns the `adr` mnemonic with the same condition as this instruction, see [`Self::adr_operands`]
+    pub(crate) fn adr_mnemonic(&self) -> &'static str {
+        ADR_MNEMONICS[self.condition() as usize]
     }
 
     /// Combines a pair of Thumb BL/BL or BL/BLX half-instructions into a full 32-bit instruction
@@ -221,10 +714,172 @@ impl ParsedIns {
                     args,
                 }
             }
-            _ => Self {
-                mnemonic: "<illegal>",
-                args: Arguments::default(),
+            _ => Self::illegal(),
+        }
+    }
+
+    /// Rewrites a single-register `ldm`/`stm` with increment-after addressing and no writeback into the equivalent `ldr`
+    /// or `str`, see [`ParseFlags::aliases`]. Other instructions are left as they are.
+    #[cfg(all(
+        any(feature = "arm", feature = "thumb"),
+        any(feature = "v4t", feature = "v5te", feature = "v6k")
+    ))]
+    pub(crate) fn alias_single_transfer(&mut self) {
+        if !matches!(self.args[1], Argument::RegList(_)) {
+            return;
+        }
+        let mnemonics = match self.mnemonic_base() {
+            "ldm" | "ldmia" => &LDR_MNEMONICS,
+            "stm" | "stmia" => &STR_MNEMONICS,
+            _ => return,
+        };
+        let (base, rt) = match (self.args[0], self.args[1], self.args[2]) {
+            (
+                Argument::Reg(Reg {
+                    reg: base,
+                    deref: false,
+                    writeback: false,
+                }),
+                Argument::RegList(list),
+                Argument::None,
+            ) if !list.user_mode => match list.single() {
+                Some(rt) => (base, rt),
+                None => return,
             },
+            _ => return,
+        };
+        self.mnemonic = mnemonics[self.condition() as usize];
+        self.args[0] = Argument::Reg(Reg {
+            reg: rt,
+            deref: false,
+            writeback: false,
+        });
+        self.args[1] = Argument::Reg(Reg {
+            reg: base,
+            deref: true,
+            writeback: false,
+        });
+        self.args[2] = Argument::OffsetImm(OffsetImm {
+            post_indexed: false,
+            value: 0,
+        });
+    }
+
+    /// Returns a `.word`, `.hword` or `.byte` line of `value`, depending on `size`
+    pub(crate) fn data(value: u32, size: usize) -> Self {
+        let mut args = Arguments::default();
+        args[0] = Argument::UImm(value);
+        let mnemonic = match size {
+            4 => ".word",
+            2 => ".hword",
+            _ => ".byte",
+        };
+        Self { mnemonic, args }
+    }
+
+    /// Returns an illegal instruction, which has no arguments
+    pub(crate) fn illegal() -> Self {
+        Self {
+            mnemonic: "<illegal>",
+            args: Arguments::default(),
+        }
+    }
+}
+
+/// `adr` mnemonics by [`Condition`], see [`ParsedIns::adr_mnemonic`]
+const ADR_MNEMONICS: [&str; 15] = [
+    "adreq", "adrne", "adrhs", "adrlo", "adrmi", "adrpl", "adrvs", "adrvc", "adrhi", "adrls", "adrge", "adrlt", "adrgt",
+    "adrle", "adr",
+];
+
+/// `ldr` mnemonics by [`Condition`], see [`ParsedIns::alias_single_transfer`]
+#[cfg(any(
+    feature = "arm",
+    all(feature = "thumb", any(feature = "v4t", feature = "v5te", feature = "v6k"))
+))]
+pub(crate) const LDR_MNEMONICS: [&str; 15] = [
+    "ldreq", "ldrne", "ldrhs", "ldrlo", "ldrmi", "ldrpl", "ldrvs", "ldrvc", "ldrhi", "ldrls", "ldrge", "ldrlt", "ldrgt",
+    "ldrle", "ldr",
+];
+
+/// `str` mnemonics by [`Condition`], see [`ParsedIns::alias_single_transfer`]
+#[cfg(any(
+    feature = "arm",
+    all(feature = "thumb", any(feature = "v4t", feature = "v5te", feature = "v6k"))
+))]
+pub(crate) const STR_MNEMONICS: [&str; 15] = [
+    "streq", "strne", "strhs", "strlo", "strmi", "strpl", "strvs", "strvc", "strhi", "strls", "strge", "strlt", "strgt",
+    "strle", "str",
+];
+
+/// Returns the value which PC reads as in an instruction at `address`, which is 8 bytes ahead in ARM mode and 4 bytes
+/// ahead in Thumb mode. If `word_aligned` is true, the Thumb value is aligned down to 4 bytes like in PC-relative loads.
+pub(crate) fn pc_read_value(address: u32, mode: ParseMode, word_aligned: bool) -> u32 {
+    match (mode, word_aligned) {
+        #[cfg(feature = "thumb")]
+        (ParseMode::Thumb, true) => address.wrapping_add(4) & !3,
+        #[cfg(feature = "thumb")]
+        (ParseMode::Thumb, false) => address.wrapping_add(4),
+        _ => address.wrapping_add(8),
+    }
+}
+
+/// Finds `mnemonic` among the mnemonics without a condition suffix in every instruction set
+fn unconditional_mnemonic(mnemonic: &str) -> Option<&'static str> {
+    let lists: &[&[&'static str]] = &[
+        #[cfg(all(feature = "v4t", feature = "arm"))]
+        &v4t::arm::UNCONDITIONAL_MNEMONICS,
+        #[cfg(all(feature = "v4t", feature = "thumb"))]
+        &v4t::thumb::UNCONDITIONAL_MNEMONICS,
+        #[cfg(all(feature = "v5te", feature = "arm"))]
+        &v5te::arm::UNCONDITIONAL_MNEMONICS,
+        #[cfg(all(feature = "v5te", feature = "thumb"))]
+        &v5te::thumb::UNCONDITIONAL_MNEMONICS,
+        #[cfg(all(feature = "v6k", feature = "arm"))]
+        &v6k::arm::UNCONDITIONAL_MNEMONICS,
+        #[cfg(all(feature = "v6k", feature = "thumb"))]
+        &v6k::thumb::UNCONDITIONAL_MNEMONICS,
+    ];
+    lists
+        .iter()
+        .find_map(|list| list.binary_search(&mnemonic).ok().map(|index| list[index]))
+}
+
+/// Finds `mnemonic` among every mnemonic in every instruction set, including data directives
+pub(crate) fn static_mnemonic(mnemonic: &str) -> Option<&'static str> {
+    let lists: &[&[&'static str]] = &[
+        &[".byte", ".hword", ".word"],
+        #[cfg(all(feature = "v4t", feature = "arm"))]
+        &v4t::arm::MNEMONICS,
+        #[cfg(all(feature = "v4t", feature = "thumb"))]
+        &v4t::thumb::MNEMONICS,
+        #[cfg(all(feature = "v5te", feature = "arm"))]
+        &v5te::arm::MNEMONICS,
+        #[cfg(all(feature = "v5te", feature = "thumb"))]
+        &v5te::thumb::MNEMONICS,
+        #[cfg(all(feature = "v6k", feature = "arm"))]
+        &v6k::arm::MNEMONICS,
+        #[cfg(all(feature = "v6k", feature = "thumb"))]
+        &v6k::thumb::MNEMONICS,
+    ];
+    lists
+        .iter()
+        .find_map(|list| list.binary_search(&mnemonic).ok().map(|index| list[index]))
+}
+
+/// Looks up the mnemonic in every instruction set, as it must be `'static`. Fails for unknown mnemonics.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ParsedIns {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "ParsedIns")]
+        struct Owned {
+            mnemonic: String,
+            args: Arguments,
         }
+        let Owned { mnemonic, args } = Owned::deserialize(deserializer)?;
+        let mnemonic = static_mnemonic(&mnemonic)
+            .ok_or_else(|| serde::de::Error::custom(format_args!("unknown mnemonic `{}`", mnemonic)))?;
+        Ok(Self { mnemonic, args })
     }
 }
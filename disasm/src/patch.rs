@@ -0,0 +1,115 @@
+//! Helpers for patching ARM code, e.g. to redirect a function to a hook. Moving an instruction to another address changes
+//! the value of PC it reads, so PC-relative instructions must be re-encoded to keep referring to the same address.
+
+use std::fmt::Display;
+
+use crate::{encode, ParseFlags};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RelocError {
+    /// The original target can't be reached from the new address, or its offset can't be encoded
+    OutOfRange,
+    /// The instruction reads PC in a way that can't be re-encoded, e.g. as a register operand
+    Unsupported,
+}
+
+impl Display for RelocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfRange => write!(f, "target is out of range from the new address"),
+            Self::Unsupported => write!(f, "instruction reads PC and can't be relocated"),
+        }
+    }
+}
+
+impl std::error::Error for RelocError {}
+
+/// Value of PC in ARM mode, which is 8 bytes ahead of the instruction
+const PC_OFFSET: i64 = 8;
+
+/// Re-encodes the ARM instruction `code` so that it can be moved from `old_address` to `new_address` and still refer to
+/// the same addresses. These forms are relocated:
+/// - `b`, `bl` and `blx` with an immediate destination
+/// - `ldr` and `ldrb` from `[pc, #imm]`
+/// - `add` and `sub` with PC and an immediate (`adr`), which may turn into the other one
+///
+/// Instructions which don't read PC are returned as-is. Any other instruction which reads PC, such as `ldrh` from PC or
+/// `add r0, pc, r1`, fails with [`RelocError::Unsupported`].
+pub fn relocate_instruction(code: u32, old_address: u32, new_address: u32) -> Result<u32, RelocError> {
+    let old_pc = old_address as i64 + PC_OFFSET;
+    let new_pc = new_address as i64 + PC_OFFSET;
+    if code & 0xfe000000 == 0xfa000000 {
+        // BLX with an immediate, where the H bit is bit 1 of the offset
+        let offset = sign_extend_24(code) * 4 + ((code >> 23) & 2) as i64;
+        let offset = old_pc + offset - new_pc;
+        let imm = branch_imm24(offset, 2).ok_or(RelocError::OutOfRange)?;
+        return Ok((code & 0xfe000000) | ((offset as u32 & 2) << 23) | imm);
+    }
+    if code & 0xf0000000 != 0xf0000000 && code & 0x0e000000 == 0x0a000000 {
+        // B or BL
+        let offset = old_pc + sign_extend_24(code) * 4 - new_pc;
+        let imm = branch_imm24(offset, 4).ok_or(RelocError::OutOfRange)?;
+        return Ok((code & 0xff000000) | imm);
+    }
+    if code & 0x0f3f0000 == 0x051f0000 {
+        // LDR or LDRB, pre-indexed from PC without writeback
+        let imm = (code & 0xfff) as i64;
+        let offset = if code & (1 << 23) != 0 { imm } else { -imm };
+        let offset = old_pc + offset - new_pc;
+        if offset.unsigned_abs() > 0xfff {
+            return Err(RelocError::OutOfRange);
+        }
+        let up = if offset >= 0 { 1 << 23 } else { 0 };
+        return Ok((code & !0x00800fff) | up | offset.unsigned_abs() as u32);
+    }
+    if matches!(code & 0x0fff0000, 0x028f0000 | 0x024f0000) {
+        // ADD or SUB with PC and an immediate
+        let imm = (code & 0xff).rotate_right((code >> 8 & 0xf) * 2) as i64;
+        let offset = if code & 0x00800000 != 0 { imm } else { -imm };
+        let offset = old_pc + offset - new_pc;
+        let imm = u32::try_from(offset.unsigned_abs())
+            .ok()
+            .and_then(encode::rotated_imm)
+            .ok_or(RelocError::OutOfRange)?;
+        let opcode = if offset >= 0 { 0x00800000 } else { 0x00400000 };
+        return Ok((code & !0x01e00fff) | opcode | imm);
+    }
+    if reads_pc(code, old_address) {
+        return Err(RelocError::Unsupported);
+    }
+    Ok(code)
+}
+
+/// Encodes an unconditional ARM `b` from `from` to `to`. Returns `None` if `to` is not word-aligned or is out of range,
+/// which is about 32 MB in either direction.
+pub fn branch_to(from: u32, to: u32) -> Option<u32> {
+    let offset = to as i64 - (from as i64 + PC_OFFSET);
+    Some(0xea000000 | branch_imm24(offset, 4)?)
+}
+
+fn sign_extend_24(code: u32) -> i64 {
+    (((code & 0xffffff) << 8) as i32 >> 8) as i64
+}
+
+/// Encodes a branch offset in words, or returns `None` if it is not a multiple of `align` or doesn't fit in 24 bits
+fn branch_imm24(offset: i64, align: i64) -> Option<u32> {
+    if offset % align != 0 {
+        return None;
+    }
+    let words = offset >> 2;
+    if !(-(1 << 23)..(1 << 23)).contains(&words) {
+        return None;
+    }
+    Some(words as u32 & 0xffffff)
+}
+
+fn reads_pc(code: u32, address: u32) -> bool {
+    let flags = ParseFlags::default();
+    #[cfg(feature = "v6k")]
+    let ins = crate::v6k::arm::Ins::new(code, &flags);
+    #[cfg(all(feature = "v5te", not(feature = "v6k")))]
+    let ins = crate::v5te::arm::Ins::new(code, &flags);
+    #[cfg(not(any(feature = "v5te", feature = "v6k")))]
+    let ins = crate::v4t::arm::Ins::new(code, &flags);
+    ins.pc_read_value(address).is_some()
+}
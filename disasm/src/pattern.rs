@@ -0,0 +1,400 @@
+//! Matching parsed instructions against assembly-like patterns with [`ins_match!`](crate::ins_match) and
+//! [`ins_matches!`](crate::ins_matches), e.g. `ins_match!(ins, sub sp, sp, #imm)`.
+//!
+//! A pattern is a mnemonic without its condition suffix or `_` for any mnemonic, optionally followed by `.cond` to
+//! require a condition such as `.ls`, and then the operands in the order they are displayed:
+//! - `r4`, `sp`, `lr` etc. match that register. The names are `r0` to `r15`, `sb`, `sl`, `fp`, `ip`, `sp`, `lr` and
+//!   `pc`, so these can't be used as variable names.
+//! - Any other name, like `rd`, matches any register and binds it as a [`Register`].
+//! - `rd!` matches a register with writeback, like the base of `stmdb sp!, {r4, lr}`.
+//! - `#0x10` matches an immediate of that value, and `#imm` binds any immediate as a `u32`.
+//! - `lsl #2` and `lsl #amount` match an immediate shift, binding the amount as a `u32`.
+//! - `{r4, lr}` matches exactly these registers, and `{regs}` binds any register list as a [`RegList`].
+//! - `[rn, #off]`, `[rn, rm]`, `[rn, -rm, lsl #2]`, `[rn, #off]!` and `[rn], #off` match memory operands. Offsets
+//!   inside and after the brackets are bound as an `i32`.
+//! - `_` matches any operand.
+//!
+//! All operands must be given, so `bx lr` doesn't match `bx lr, r0`. [`ins_match!`](crate::ins_match) returns
+//! `Some` with a tuple of the bound values in the order of the pattern, or `None` if the instruction doesn't match.
+//!
+//! ```
+//! use unarm::{args::Register, ins_match, ins_matches, v5te::arm::Ins};
+//!
+//! let flags = Default::default();
+//! // sub sp, sp, #0x10
+//! let ins = Ins::new(0xe24dd010, &flags).parse(&flags);
+//! assert_eq!(ins_match!(ins, sub sp, sp, #imm), Some((0x10,)));
+//! assert_eq!(ins_match!(ins, sub rd, rn, #0x10), Some((Register::Sp, Register::Sp)));
+//! assert!(!ins_matches!(ins, add sp, sp, #imm));
+//!
+//! // addls pc, pc, r0, lsl #0x2
+//! let ins = Ins::new(0x908ff100, &flags).parse(&flags);
+//! assert_eq!(ins_match!(ins, add.ls pc, pc, index, lsl #2), Some((Register::R0,)));
+//! assert!(!ins_matches!(ins, add.hi pc, pc, _, _));
+//! ```
+
+use crate::{
+    args::{Argument, OffsetImm, OffsetReg, RegList, Register, Shift, ShiftImm},
+    Condition, ParsedIns,
+};
+
+#[doc(hidden)]
+pub fn __mnemonic_is(ins: &ParsedIns, mnemonic: &str) -> bool {
+    mnemonic == "_" || ins.mnemonic_base() == mnemonic
+}
+
+#[doc(hidden)]
+pub fn __condition_is(ins: &ParsedIns, suffix: &str) -> bool {
+    let cond = match suffix {
+        "al" => Some(Condition::Al),
+        "cs" => Some(Condition::Hs),
+        "cc" => Some(Condition::Lo),
+        suffix => Condition::from_suffix(suffix),
+    };
+    cond == Some(ins.condition())
+}
+
+#[doc(hidden)]
+pub fn __reg(arg: &Argument, deref: bool, writeback: bool) -> Option<Register> {
+    match *arg {
+        Argument::Reg(reg) if reg.deref == deref && reg.writeback == writeback => Some(reg.reg),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub fn __offset_reg(arg: &Argument, add: bool) -> Option<Register> {
+    match *arg {
+        Argument::OffsetReg(OffsetReg { add: a, reg, .. }) if a == add => Some(reg),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub fn __imm(arg: &Argument) -> Option<u32> {
+    match *arg {
+        Argument::UImm(imm) | Argument::SatImm(imm) => Some(imm),
+        _ => None,
+    }
+}
+
+/// Thumb loads and stores encode their offset as an unsigned immediate
+#[doc(hidden)]
+pub fn __offset(arg: &Argument) -> Option<i32> {
+    match *arg {
+        Argument::OffsetImm(OffsetImm { value, .. }) => Some(value),
+        Argument::UImm(imm) => Some(imm as i32),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub fn __shift(arg: &Argument, op: Shift) -> Option<u32> {
+    match *arg {
+        Argument::ShiftImm(ShiftImm { op: o, imm }) if o == op => Some(imm),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub fn __reg_list(arg: &Argument) -> Option<RegList> {
+    match *arg {
+        Argument::RegList(list) if !list.user_mode => Some(list),
+        _ => None,
+    }
+}
+
+#[doc(hidden)]
+pub fn __any(arg: &Argument) -> Option<()> {
+    (*arg != Argument::None).then_some(())
+}
+
+/// Matches a [`ParsedIns`] against a pattern, and returns `Some` with a tuple of the values bound by the pattern, or
+/// `None` if it doesn't match. See the [`pattern`](crate::pattern) module for the syntax.
+#[macro_export]
+macro_rules! ins_match {
+    ($ins:expr, $mnemonic:tt . $cond:ident $($operands:tt)*) => {
+        (|| {
+            let ins: &$crate::ParsedIns = &$ins;
+            if !$crate::pattern::__condition_is(ins, stringify!($cond)) {
+                return None;
+            }
+            $crate::ins_match!(@ ins $mnemonic $($operands)*)
+        })()
+    };
+    ($ins:expr, $mnemonic:tt $($operands:tt)*) => {
+        (|| {
+            let ins: &$crate::ParsedIns = &$ins;
+            $crate::ins_match!(@ ins $mnemonic $($operands)*)
+        })()
+    };
+    (@ $ins:ident $mnemonic:tt $($operands:tt)*) => {{
+        if !$crate::pattern::__mnemonic_is($ins, stringify!($mnemonic)) {
+            return None;
+        }
+        let args = &$ins.args;
+        let mut index = 0usize;
+        let mut next = || {
+            let arg = args.get(index).copied().unwrap_or_default();
+            index += 1;
+            arg
+        };
+        $crate::__ins_operands!(top next [] $($operands)*)
+    }};
+}
+
+/// Returns true if a [`ParsedIns`] matches a pattern, see [`ins_match!`](crate::ins_match)
+#[macro_export]
+macro_rules! ins_matches {
+    ($($pattern:tt)*) => {
+        $crate::ins_match!($($pattern)*).is_some()
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ins_operands {
+    // End of the pattern, which must also be the end of the arguments
+    (top $next:ident [$($bind:ident)*]) => {{
+        if $next() != $crate::args::Argument::None {
+            return None;
+        }
+        Some(($($bind,)*))
+    }};
+    (mem $next:ident [$($bind:ident)*]) => {
+        $crate::__ins_operands!(top $next [$($bind)*])
+    };
+    ($mode:ident $next:ident [$($bind:ident)*] , $($rest:tt)*) => {
+        $crate::__ins_operands!($mode $next [$($bind)*] $($rest)*)
+    };
+    ($mode:ident $next:ident [$($bind:ident)*] _ $($rest:tt)*) => {{
+        $crate::pattern::__any(&$next())?;
+        $crate::__ins_operands!($mode $next [$($bind)*] $($rest)*)
+    }};
+
+    // Immediates
+    (top $next:ident [$($bind:ident)*] # $value:literal $($rest:tt)*) => {{
+        if $crate::pattern::__imm(&$next())? != $value {
+            return None;
+        }
+        $crate::__ins_operands!(top $next [$($bind)*] $($rest)*)
+    }};
+    (top $next:ident [$($bind:ident)*] # $var:ident $($rest:tt)*) => {{
+        let $var = $crate::pattern::__imm(&$next())?;
+        $crate::__ins_operands!(top $next [$($bind)* $var] $($rest)*)
+    }};
+    (mem $next:ident [$($bind:ident)*] # - $value:literal $($rest:tt)*) => {{
+        if $crate::pattern::__offset(&$next())? != -$value {
+            return None;
+        }
+        $crate::__ins_operands!(mem $next [$($bind)*] $($rest)*)
+    }};
+    (mem $next:ident [$($bind:ident)*] # $value:literal $($rest:tt)*) => {{
+        if $crate::pattern::__offset(&$next())? != $value {
+            return None;
+        }
+        $crate::__ins_operands!(mem $next [$($bind)*] $($rest)*)
+    }};
+    (mem $next:ident [$($bind:ident)*] # $var:ident $($rest:tt)*) => {{
+        let $var = $crate::pattern::__offset(&$next())?;
+        $crate::__ins_operands!(mem $next [$($bind)* $var] $($rest)*)
+    }};
+
+    // Shifts
+    ($mode:ident $next:ident [$($bind:ident)*] $op:ident # $amount:literal $($rest:tt)*) => {{
+        if $crate::pattern::__shift(&$next(), $crate::__ins_shift!($op))? != $amount {
+            return None;
+        }
+        $crate::__ins_operands!($mode $next [$($bind)*] $($rest)*)
+    }};
+    ($mode:ident $next:ident [$($bind:ident)*] $op:ident # $var:ident $($rest:tt)*) => {{
+        let $var = $crate::pattern::__shift(&$next(), $crate::__ins_shift!($op))?;
+        $crate::__ins_operands!($mode $next [$($bind)* $var] $($rest)*)
+    }};
+
+    // Register lists
+    (top $next:ident [$($bind:ident)*] { _ } $($rest:tt)*) => {{
+        $crate::pattern::__reg_list(&$next())?;
+        $crate::__ins_operands!(top $next [$($bind)*] $($rest)*)
+    }};
+    (top $next:ident [$($bind:ident)*] { $reg:ident } $($rest:tt)*) => {
+        $crate::__ins_register!(list $reg top $next [$($bind)*] $($rest)*)
+    };
+    (top $next:ident [$($bind:ident)*] { $($reg:ident),+ } $($rest:tt)*) => {{
+        let regs = 0 $(| 1 << $crate::__ins_reg!($reg) as u32)+;
+        if $crate::pattern::__reg_list(&$next())?.regs != regs {
+            return None;
+        }
+        $crate::__ins_operands!(top $next [$($bind)*] $($rest)*)
+    }};
+
+    // Memory operands. Everything after a post-indexed memory operand is an offset.
+    (top $next:ident [$($bind:ident)*] [$base:ident $($offset:tt)*] ! $($rest:tt)*) => {
+        $crate::__ins_register!(base_wb $base mem $next [$($bind)*] $($offset)* , $($rest)*)
+    };
+    (top $next:ident [$($bind:ident)*] [$base:ident $($offset:tt)*] $($rest:tt)*) => {
+        $crate::__ins_register!(base $base mem $next [$($bind)*] $($offset)* , $($rest)*)
+    };
+
+    // Registers
+    (mem $next:ident [$($bind:ident)*] - $reg:ident $($rest:tt)*) => {
+        $crate::__ins_register!(sub $reg mem $next [$($bind)*] $($rest)*)
+    };
+    (mem $next:ident [$($bind:ident)*] $reg:ident $($rest:tt)*) => {
+        $crate::__ins_register!(add $reg mem $next [$($bind)*] $($rest)*)
+    };
+    (top $next:ident [$($bind:ident)*] $reg:ident ! $($rest:tt)*) => {
+        $crate::__ins_register!(reg_wb $reg top $next [$($bind)*] $($rest)*)
+    };
+    (top $next:ident [$($bind:ident)*] $reg:ident $($rest:tt)*) => {
+        $crate::__ins_register!(reg $reg top $next [$($bind)*] $($rest)*)
+    };
+
+    // A register name from `__ins_register`, or a variable to bind the register to
+    (@ list $reg:ident lit $mode:ident $next:ident [$($bind:ident)*] $($rest:tt)*) => {{
+        if $crate::pattern::__reg_list(&$next())?.regs != 1 << $crate::__ins_reg!($reg) as u32 {
+            return None;
+        }
+        $crate::__ins_operands!($mode $next [$($bind)*] $($rest)*)
+    }};
+    (@ $kind:ident $reg:ident lit $mode:ident $next:ident [$($bind:ident)*] $($rest:tt)*) => {{
+        if $crate::__ins_operands!(@ $kind $next)? != $crate::__ins_reg!($reg) {
+            return None;
+        }
+        $crate::__ins_operands!($mode $next [$($bind)*] $($rest)*)
+    }};
+    (@ $kind:ident $var:ident bind $mode:ident $next:ident [$($bind:ident)*] $($rest:tt)*) => {{
+        let $var = $crate::__ins_operands!(@ $kind $next)?;
+        $crate::__ins_operands!($mode $next [$($bind)* $var] $($rest)*)
+    }};
+    (@ reg $next:ident) => { $crate::pattern::__reg(&$next(), false, false) };
+    (@ reg_wb $next:ident) => { $crate::pattern::__reg(&$next(), false, true) };
+    (@ base $next:ident) => { $crate::pattern::__reg(&$next(), true, false) };
+    (@ base_wb $next:ident) => { $crate::pattern::__reg(&$next(), true, true) };
+    (@ add $next:ident) => { $crate::pattern::__offset_reg(&$next(), true) };
+    (@ sub $next:ident) => { $crate::pattern::__offset_reg(&$next(), false) };
+    (@ list $next:ident) => { $crate::pattern::__reg_list(&$next()) };
+}
+
+/// Continues [`__ins_operands`] with `lit` if `$reg` is a register name, otherwise with `bind`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ins_register {
+    ($kind:ident r0 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r0 lit $($rest)*) };
+    ($kind:ident r1 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r1 lit $($rest)*) };
+    ($kind:ident r2 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r2 lit $($rest)*) };
+    ($kind:ident r3 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r3 lit $($rest)*) };
+    ($kind:ident r4 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r4 lit $($rest)*) };
+    ($kind:ident r5 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r5 lit $($rest)*) };
+    ($kind:ident r6 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r6 lit $($rest)*) };
+    ($kind:ident r7 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r7 lit $($rest)*) };
+    ($kind:ident r8 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r8 lit $($rest)*) };
+    ($kind:ident r9 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r9 lit $($rest)*) };
+    ($kind:ident r10 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r10 lit $($rest)*) };
+    ($kind:ident r11 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r11 lit $($rest)*) };
+    ($kind:ident r12 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r12 lit $($rest)*) };
+    ($kind:ident r13 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r13 lit $($rest)*) };
+    ($kind:ident r14 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r14 lit $($rest)*) };
+    ($kind:ident r15 $($rest:tt)*) => { $crate::__ins_operands!(@ $kind r15 lit $($rest)*) };
+    ($kind:ident sb $($rest:tt)*) => { $crate::__ins_operands!(@ $kind sb lit $($rest)*) };
+    ($kind:ident sl $($rest:tt)*) => { $crate::__ins_operands!(@ $kind sl lit $($rest)*) };
+    ($kind:ident fp $($rest:tt)*) => { $crate::__ins_operands!(@ $kind fp lit $($rest)*) };
+    ($kind:ident ip $($rest:tt)*) => { $crate::__ins_operands!(@ $kind ip lit $($rest)*) };
+    ($kind:ident sp $($rest:tt)*) => { $crate::__ins_operands!(@ $kind sp lit $($rest)*) };
+    ($kind:ident lr $($rest:tt)*) => { $crate::__ins_operands!(@ $kind lr lit $($rest)*) };
+    ($kind:ident pc $($rest:tt)*) => { $crate::__ins_operands!(@ $kind pc lit $($rest)*) };
+    ($kind:ident $var:ident $($rest:tt)*) => { $crate::__ins_operands!(@ $kind $var bind $($rest)*) };
+}
+
+/// Returns the [`Register`] of a register name
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ins_reg {
+    (r0) => {
+        $crate::args::Register::R0
+    };
+    (r1) => {
+        $crate::args::Register::R1
+    };
+    (r2) => {
+        $crate::args::Register::R2
+    };
+    (r3) => {
+        $crate::args::Register::R3
+    };
+    (r4) => {
+        $crate::args::Register::R4
+    };
+    (r5) => {
+        $crate::args::Register::R5
+    };
+    (r6) => {
+        $crate::args::Register::R6
+    };
+    (r7) => {
+        $crate::args::Register::R7
+    };
+    (r8) => {
+        $crate::args::Register::R8
+    };
+    (r9) => {
+        $crate::args::Register::R9
+    };
+    (r10) => {
+        $crate::args::Register::R10
+    };
+    (r11) => {
+        $crate::args::Register::R11
+    };
+    (r12) => {
+        $crate::args::Register::R12
+    };
+    (r13) => {
+        $crate::args::Register::Sp
+    };
+    (r14) => {
+        $crate::args::Register::Lr
+    };
+    (r15) => {
+        $crate::args::Register::Pc
+    };
+    (sb) => {
+        $crate::args::Register::R9
+    };
+    (sl) => {
+        $crate::args::Register::R10
+    };
+    (fp) => {
+        $crate::args::Register::R11
+    };
+    (ip) => {
+        $crate::args::Register::R12
+    };
+    (sp) => {
+        $crate::args::Register::Sp
+    };
+    (lr) => {
+        $crate::args::Register::Lr
+    };
+    (pc) => {
+        $crate::args::Register::Pc
+    };
+}
+
+/// Returns the [`Shift`] of a shift name
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ins_shift {
+    (lsl) => {
+        $crate::args::Shift::Lsl
+    };
+    (lsr) => {
+        $crate::args::Shift::Lsr
+    };
+    (asr) => {
+        $crate::args::Shift::Asr
+    };
+    (ror) => {
+        $crate::args::Shift::Ror
+    };
+}
@@ -0,0 +1,82 @@
+/// Scores how well `mnemonic` matches `query`, or returns `None` if it doesn't match at all. From best to worst:
+/// - 1000 for an exact match
+/// - 900 minus the number of extra characters if `query` is a prefix of `mnemonic`
+/// - 600 minus the number of extra characters if `query` is a subsequence of `mnemonic`
+/// - 400 minus 100 per edit if the edit distance is at most a third of the query length, but at least 1
+pub fn fuzzy_score(query: &str, mnemonic: &str) -> Option<u32> {
+    if query.is_empty() {
+        return None;
+    }
+    let extra = mnemonic.len().saturating_sub(query.len()) as u32;
+    if mnemonic == query {
+        Some(1000)
+    } else if mnemonic.starts_with(query) {
+        Some(900u32.saturating_sub(extra).max(601))
+    } else if is_subsequence(query, mnemonic) {
+        Some(600u32.saturating_sub(extra).max(401))
+    } else {
+        let max_distance = (query.len() / 3).max(1);
+        let distance = edit_distance(query, mnemonic);
+        (distance <= max_distance).then(|| 400u32.saturating_sub(100 * distance as u32).max(1))
+    }
+}
+
+fn is_subsequence(query: &str, text: &str) -> bool {
+    let mut text = text.chars();
+    query.chars().all(|c| text.any(|t| t == c))
+}
+
+/// Levenshtein distance
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + (ca != cb) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Scores every opcode by its best matching mnemonic, see [`fuzzy_score`]. Equal scores keep the order of `opcodes`.
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+pub(crate) fn fuzzy_search<O>(
+    query: &str,
+    limit: usize,
+    opcodes: impl Iterator<Item = (O, &'static [&'static str])>,
+) -> Vec<(O, u32)> {
+    use std::cmp::Reverse;
+
+    /// Stack addressing mode aliases of load/store multiple mnemonics
+    const STACK_ALIASES: [(&str, &str); 8] = [
+        ("ldmda", "ldmfa"),
+        ("ldmdb", "ldmea"),
+        ("ldmia", "ldmfd"),
+        ("ldmib", "ldmed"),
+        ("stmda", "stmed"),
+        ("stmdb", "stmfd"),
+        ("stmia", "stmea"),
+        ("stmib", "stmfa"),
+    ];
+
+    let query = query.trim().to_ascii_lowercase();
+    let mut hits: Vec<(O, u32)> = opcodes
+        .filter_map(|(opcode, mnemonics)| {
+            let aliases = mnemonics
+                .iter()
+                .filter_map(|m| STACK_ALIASES.iter().find(|(name, _)| name == m).map(|(_, alias)| alias));
+            let score = mnemonics.iter().chain(aliases).filter_map(|m| fuzzy_score(&query, m)).max()?;
+            Some((opcode, score))
+        })
+        .collect();
+    hits.sort_by_key(|&(_, score)| Reverse(score));
+    hits.truncate(limit);
+    hits
+}
@@ -0,0 +1,396 @@
+use std::ops::Range;
+
+#[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+use crate::Endian;
+use crate::{args::Argument, MemoryMap, Op, ParseMode, ParsedIns, Parser};
+
+/// How to render the bytes in a skipped range
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SkippedStyle {
+    /// Leave the range out of the output entirely
+    Elide,
+    /// Emit `.word`/`.hword`/`.byte` data lines, like [`ParseMode::Data`]
+    #[default]
+    Data,
+    /// Emit a `.word` line for each aligned word. Words which point into the parsed image (ignoring the Thumb bit), or
+    /// into any segment given to [`Stream::with_map`], can be symbolized with [`Stream::pointer_target`].
+    WordTable,
+}
+
+/// Options for [`Stream`]
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct StreamOptions {
+    /// Address ranges which will not be parsed as instructions, such as vector tables or literal pools. Ranges should
+    /// start and end on instruction boundaries, so that the instructions on either side are parsed at the right
+    /// addresses.
+    pub skip_ranges: Vec<Range<u32>>,
+    /// How to render the skipped ranges
+    pub render_skipped: SkippedStyle,
+}
+
+/// Parser which doesn't parse instructions in the skipped ranges of [`StreamOptions`]. Created by
+/// [`Parser::with_options`].
+///
+/// Instructions are never parsed across the edge of a skipped range. If there are too few bytes left before the start
+/// of a range to parse an instruction, or a range ends in the middle of an instruction, the bytes up to the next
+/// instruction boundary are emitted as data instead. Since the address of every line is kept,
+/// labels at the edges of a range can be placed like any other label.
+#[derive(Clone, Debug)]
+pub struct Stream<'a> {
+    parser: Parser<'a>,
+    options: StreamOptions,
+    /// Address ranges which words in a [`SkippedStyle::WordTable`] may point into
+    images: Vec<Range<u32>>,
+    /// Whether the parser left a skipped range in the middle of an instruction
+    misaligned: bool,
+}
+
+impl<'a> Parser<'a> {
+    pub fn with_options(self, options: StreamOptions) -> Stream<'a> {
+        let image = self.address..self.address.saturating_add(self.remaining() as u32);
+        Stream {
+            parser: self,
+            options,
+            images: vec![image],
+            misaligned: false,
+        }
+    }
+}
+
+impl<'a> Stream<'a> {
+    pub fn parser(&self) -> &Parser<'a> {
+        &self.parser
+    }
+
+    pub fn options(&self) -> &StreamOptions {
+        &self.options
+    }
+
+    /// Resolves words in [`SkippedStyle::WordTable`] ranges against every segment of `map` instead of only the parsed
+    /// image
+    pub fn with_map(mut self, map: &MemoryMap) -> Self {
+        self.images = map.segments.iter().map(|segment| segment.addresses()).collect();
+        self
+    }
+
+    fn skip_range(&self, address: u32) -> Option<&Range<u32>> {
+        self.options.skip_ranges.iter().find(|range| range.contains(&address))
+    }
+
+    /// Returns the number of bytes until the next skipped range, if there is one
+    fn next_gap(&self, address: u32) -> Option<usize> {
+        self.options
+            .skip_ranges
+            .iter()
+            .filter(|range| range.start > address && !range.is_empty())
+            .map(|range| (range.start - address) as usize)
+            .min()
+    }
+
+    /// Returns the address which the `.word` line `ins` at `address` points to, with the Thumb bit cleared. Returns `None`
+    /// if the line is not in a [`SkippedStyle::WordTable`] range, or if the word doesn't point into the parsed image or
+    /// the segments given to [`Self::with_map`].
+    pub fn pointer_target(&self, address: u32, ins: &ParsedIns) -> Option<u32> {
+        if self.options.render_skipped != SkippedStyle::WordTable || self.skip_range(address).is_none() {
+            return None;
+        }
+        let (".word", Argument::UImm(value)) = (ins.mnemonic, ins.args[0]) else {
+            return None;
+        };
+        let target = value & !1;
+        self.images.iter().any(|image| image.contains(&target)).then_some(target)
+    }
+
+    /// Emits one data line of at most `max_size` bytes
+    fn next_data(&mut self, max_size: usize) -> Option<(u32, Op, ParsedIns)> {
+        let address = self.parser.address;
+        let (value, size) = self.parser.read_data(max_size)?;
+        Some((address, Op::Data, ParsedIns::data(value, size)))
+    }
+}
+
+impl<'a> Iterator for Stream<'a> {
+    type Item = (u32, Op, ParsedIns);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let address = self.parser.address;
+            if self.parser.remaining() == 0 {
+                return None;
+            }
+
+            let ins_size = self.parser.mode.instruction_size(address);
+            if let Some(range) = self.skip_range(address) {
+                let size = (range.end - address) as usize;
+                self.misaligned = !(range.end as usize).is_multiple_of(ins_size);
+                match self.options.render_skipped {
+                    SkippedStyle::Elide => {
+                        self.parser.advance(size);
+                        continue;
+                    }
+                    SkippedStyle::Data | SkippedStyle::WordTable => return self.next_data(size),
+                }
+            }
+
+            // Realign after a skipped range which ended in the middle of an instruction
+            let misalignment = address as usize % ins_size;
+            if self.misaligned && misalignment != 0 {
+                let size = ins_size - misalignment;
+                return self.next_data(size.min(self.next_gap(address).unwrap_or(size)));
+            }
+
+            self.misaligned = false;
+
+            let Some(gap) = self.next_gap(address) else {
+                return self.parser.next();
+            };
+            #[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+            {
+                let mut limited = self.parser.limit(gap);
+                return match limited.next() {
+                    Some(item) => {
+                        self.parser.advance((limited.address - address) as usize);
+                        Some(item)
+                    }
+                    // Not enough bytes for an instruction before the skipped range
+                    None => self.next_data(gap),
+                };
+            }
+            // Without a version there is no parser to limit
+            #[cfg(not(any(feature = "v4t", feature = "v5te", feature = "v6k")))]
+            return self.next_data(gap);
+        }
+    }
+}
+
+/// An instruction or data line in a disassembly [`window`]
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+    pub address: u32,
+    /// Number of bytes, which is 4 for a combined Thumb BL/BLX pair
+    pub size: u32,
+    pub op: Op,
+    pub ins: ParsedIns,
+    /// How the line was made from its bytes, for tools which map lines back to their encodings
+    pub provenance: Provenance,
+}
+
+/// How a [`Line`] was made from its bytes
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Provenance {
+    /// One instruction decoded from its encoding
+    #[default]
+    Plain,
+    /// The two halfwords of a Thumb BL/BLX pair, combined into one `bl` or `blx`, see [`ParsedIns::combine_thumb_bl`]
+    CombinedThumbBl { first: u16, second: u16 },
+    /// Bytes displayed as `.word`, `.hword` or `.byte` data instead of an instruction
+    DataFallback { reason: DataReason },
+}
+
+/// Why bytes were displayed as data, see [`Provenance::DataFallback`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataReason {
+    /// The bytes are in a [`ParseMode::Data`] region, such as after a `$d` mapping symbol
+    DataMode,
+    /// Too few bytes were left for an instruction at the end of the code or before a mode change
+    Truncated,
+}
+
+impl Line {
+    pub fn contains(&self, address: u32) -> bool {
+        address.wrapping_sub(self.address) < self.size
+    }
+}
+
+/// Number of extra instructions to decode before a window, so that a linear decode has synchronized with the real
+/// instruction boundaries by the time it reaches the window
+#[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+const WINDOW_LOOKBACK: usize = 16;
+
+/// Disassembles `before` lines before the instruction containing `around`, that instruction, and `after` lines after it,
+/// for debugger views which show the code around the PC. The version, mode, endianness and flags are taken from
+/// `parser`, while its address and data are ignored in favor of `base` and `bytes`.
+///
+/// The lines before `around` are found by decoding forward from a point further back, see [`window_with_modes`]. If
+/// `around` is in the middle of a Thumb BL/BLX pair, the window is centered on the pair, so it's the same window as for
+/// the address of the pair. Returns an empty window if `around` is outside of `bytes`.
+pub fn window(bytes: &[u8], base: u32, around: u32, before: usize, after: usize, parser: &Parser) -> Vec<Line> {
+    window_with_modes(bytes, base, around, before, after, parser, &[])
+}
+
+/// Like [`window`], but `modes` switches the parse mode at the given addresses, like the `$a`, `$t` and `$d` mapping
+/// symbols, see [`ParseMode::from_mapping_symbol`]. `modes` must be sorted by address, and `parser.mode` is used before
+/// the first entry.
+///
+/// Mode changes are known instruction boundaries, so lines are never decoded across them. Within a mode, decoding starts
+/// at the previous mode change, or a few instructions before the window if the mode change is further back. A Thumb
+/// decode which would start at the second half of a BL/BLX pair starts after it instead.
+#[cfg(any(feature = "v4t", feature = "v5te", feature = "v6k"))]
+pub fn window_with_modes(
+    bytes: &[u8],
+    base: u32,
+    around: u32,
+    before: usize,
+    after: usize,
+    parser: &Parser,
+    modes: &[(u32, ParseMode)],
+) -> Vec<Line> {
+    let end = base.saturating_add(bytes.len() as u32);
+    if !(base..end).contains(&around) {
+        return vec![];
+    }
+    let mode_at = |address: u32| {
+        let index = modes.partition_point(|&(start, _)| start <= address);
+        index.checked_sub(1).map_or(parser.mode, |index| modes[index].1)
+    };
+    // Mode regions as (start, end), in address order
+    let mut starts: Vec<u32> = vec![base];
+    starts.extend(
+        modes
+            .iter()
+            .map(|&(start, _)| start)
+            .filter(|&start| start > base && start < end),
+    );
+    starts.dedup();
+    let regions: Vec<(u32, u32)> = starts
+        .iter()
+        .zip(starts.iter().skip(1).chain([&end]))
+        .map(|(&a, &b)| (a, b))
+        .collect();
+
+    let bytes_at = |address: u32, end: u32| &bytes[(address - base) as usize..(end - base) as usize];
+    let halfword = |address: u32| {
+        let bytes = bytes_at(address, address + 2);
+        let bytes = [bytes[0], bytes[1]];
+        match parser.endian {
+            Endian::Little => u16::from_le_bytes(bytes),
+            Endian::Big => u16::from_be_bytes(bytes),
+        }
+    };
+    let decoder = |mode: ParseMode, from: u32, end: u32| {
+        Parser::new(parser.version, mode, from, parser.endian, parser.flags, bytes_at(from, end))
+    };
+    let decode = |(start, end): (u32, u32), from: u32| -> Vec<Line> {
+        let mut parser = decoder(mode_at(start), from, end);
+        let mut lines = vec![];
+        let mut address = parser.address;
+        loop {
+            let Some((_, op, ins)) = parser.next() else {
+                // Bytes which are too few for an instruction, including the first half of a BL/BLX pair without its
+                // second half, are still part of the listing
+                if parser.mode == ParseMode::Data || address == end {
+                    break;
+                }
+                parser = decoder(ParseMode::Data, address, end);
+                continue;
+            };
+            let size = parser.address - address;
+            let provenance = if op == Op::Data {
+                let reason = if mode_at(start) == ParseMode::Data {
+                    DataReason::DataMode
+                } else {
+                    DataReason::Truncated
+                };
+                Provenance::DataFallback { reason }
+            } else if size == 4 && parser.mode.instruction_size(address) == 2 {
+                Provenance::CombinedThumbBl {
+                    first: halfword(address),
+                    second: halfword(address + 2),
+                }
+            } else {
+                Provenance::Plain
+            };
+            lines.push(Line {
+                address,
+                size,
+                op,
+                ins,
+                provenance,
+            });
+            address = parser.address;
+        }
+        lines
+    };
+    // Where to start decoding so that `count` lines before `until` are synchronized
+    let decode_start = |region: (u32, u32), until: u32, count: usize| -> u32 {
+        let start = region.0;
+        let lookback = ((count + WINDOW_LOOKBACK) * 4) as u32;
+        let from = start + (until.saturating_sub(start).saturating_sub(lookback) & !3);
+        #[cfg(feature = "thumb")]
+        if mode_at(start) == ParseMode::Thumb && from > start {
+            let halfword = |address: u32| {
+                Parser::new(
+                    parser.version,
+                    ParseMode::Thumb,
+                    address,
+                    parser.endian,
+                    parser.flags,
+                    bytes_at(address, end),
+                )
+                .peek_code()
+            };
+            let is_first_half = |code: u32| code & 0xf800 == 0xf000;
+            let is_second_half = |code: u32| matches!(code & 0xf800, 0xf800 | 0xe800);
+            if halfword(from).is_some_and(is_second_half) && halfword(from - 2).is_some_and(is_first_half) {
+                return from + 2;
+            }
+        }
+        from
+    };
+
+    // The region containing `around`, split at the center line
+    let Some(center_region) = regions.iter().position(|&(start, end)| (start..end).contains(&around)) else {
+        return vec![];
+    };
+    let region = regions[center_region];
+    let mut preceding = decode(region, decode_start(region, around, before));
+    let Some(center) = preceding.iter().position(|line| line.contains(around)) else {
+        return vec![];
+    };
+    let mut following = preceding.split_off(center);
+
+    // Lines before the center, from earlier regions if needed
+    let mut window: Vec<Line> = vec![];
+    let mut index = center_region;
+    loop {
+        let skip = preceding.len().saturating_sub(before - window.len());
+        window.splice(0..0, preceding.drain(skip..));
+        if window.len() == before || index == 0 {
+            break;
+        }
+        index -= 1;
+        let region = regions[index];
+        preceding = decode(region, decode_start(region, region.1, before - window.len()));
+    }
+
+    // The center line and the lines after it, from later regions if needed
+    let target = window.len() + 1 + after;
+    let mut index = center_region;
+    loop {
+        let take = following.len().min(target - window.len());
+        window.extend(following.drain(..take));
+        if window.len() == target || index + 1 == regions.len() {
+            break;
+        }
+        index += 1;
+        following = decode(regions[index], regions[index].0);
+    }
+    window
+}
+
+/// Without a version there is no parser, so there are no windows
+#[cfg(not(any(feature = "v4t", feature = "v5te", feature = "v6k")))]
+pub fn window_with_modes(
+    _bytes: &[u8],
+    _base: u32,
+    _around: u32,
+    _before: usize,
+    _after: usize,
+    _parser: &Parser,
+    _modes: &[(u32, ParseMode)],
+) -> Vec<Line> {
+    vec![]
+}
@@ -0,0 +1,136 @@
+//! Helpers for the tests of crates which use or extend unarm, with the `testing` feature. unarm's own tests use them
+//! too.
+//!
+//! - [`assert_asm!`](crate::assert_asm) checks how an instruction is displayed, and
+//!   [`assert_roundtrip!`](crate::assert_roundtrip) checks that its text assembles back into the same code.
+//! - [`fixture`] returns the small ARMv5TE binaries in `disasm/tests/fixtures`, and [`random_code`] generates
+//!   reproducible pseudorandom code.
+
+use crate::ParseMode;
+pub use crate::{assert_asm, assert_roundtrip};
+
+/// Asserts that the instruction `code` is displayed as `disasm`. The instruction is decoded by the `Ins` type of the
+/// module given first, such as `v5te::arm` for `unarm::v5te::arm::Ins`, with the default
+/// [`ParseFlags`](crate::ParseFlags) and [`DisplayOptions`](crate::DisplayOptions) unless `flags:` or `options:` are
+/// given.
+///
+/// ```
+/// use unarm::{testing::assert_asm, DisplayOptions, ImmediateStyle, ParseFlags};
+///
+/// assert_asm!(v5te::arm, 0xef000001, "svc #0x1");
+/// assert_asm!(v5te::arm, 0xef000001, "swi #0x1", flags: ParseFlags { ual: false, ..Default::default() });
+/// assert_asm!(
+///     v5te::arm,
+///     0xef000001,
+///     "svc #1",
+///     options: DisplayOptions { immediate_style: ImmediateStyle::Decimal, ..Default::default() }
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_asm {
+    ($module:ident::$mode:ident, $code:expr, $disasm:expr $(, flags: $flags:expr)? $(, options: $options:expr)? $(,)?) => {{
+        let flags: $crate::ParseFlags = $crate::__or_default!($($flags)?);
+        let options: $crate::DisplayOptions = $crate::__or_default!($($options)?);
+        let code: u32 = $code;
+        let ins = $crate::$module::$mode::Ins::new(code, &flags);
+        assert_eq!(ins.parse(&flags).display(options).to_string(), $disasm, "{code:#x}")
+    }};
+}
+
+/// Asserts that the text of the instruction `code` parses back into the same [`ParsedIns`](crate::ParsedIns), and
+/// that it encodes back into `code`. Decoded by the `Ins` type of the module given first like
+/// [`assert_asm!`](crate::assert_asm), with the default [`ParseFlags`](crate::ParseFlags) unless `flags:` is given. The
+/// halves of a Thumb BL/BLX pair can't be checked on their own.
+///
+/// ```
+/// use unarm::testing::assert_roundtrip;
+///
+/// assert_roundtrip!(v5te::thumb, 0x1c48);
+/// ```
+#[macro_export]
+macro_rules! assert_roundtrip {
+    ($module:ident::$mode:ident, $code:expr $(, flags: $flags:expr)? $(,)?) => {{
+        let flags: $crate::ParseFlags = $crate::__or_default!($($flags)?);
+        let code: u32 = $code;
+        let ins = $crate::$module::$mode::Ins::new(code, &flags);
+        let parsed = ins.parse(&flags);
+        let text = parsed.display(::core::default::Default::default()).to_string();
+        let mode = $crate::testing::mode_of_size(ins.size());
+        let reparsed = $crate::ParsedIns::from_text(&text, mode).unwrap_or_else(|e| panic!("{text}: {e}"));
+        assert_eq!(reparsed, parsed, "{text}");
+        assert_eq!(ins.op.encode(&reparsed, &flags), Ok(code), "{text}");
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __or_default {
+    () => {
+        ::core::default::Default::default()
+    };
+    ($value:expr) => {
+        $value
+    };
+}
+
+/// Returns the mode of instructions of `size` bytes, for [`assert_roundtrip!`](crate::assert_roundtrip)
+#[doc(hidden)]
+pub fn mode_of_size(size: usize) -> ParseMode {
+    match size {
+        #[cfg(feature = "thumb")]
+        2 => ParseMode::Thumb,
+        #[cfg(feature = "arm")]
+        4 => ParseMode::Arm,
+        _ => ParseMode::Data,
+    }
+}
+
+/// Little-endian ARMv5TE code in `disasm/tests/fixtures`, see [`fixture`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Fixture {
+    pub name: &'static str,
+    pub mode: ParseMode,
+    pub code: &'static [u8],
+}
+
+macro_rules! fixtures {
+    ($($feature:literal $mode:ident $name:literal),* $(,)?) => {
+        /// Every fixture whose mode is enabled
+        pub const FIXTURES: &[Fixture] = &[$(
+            #[cfg(feature = $feature)]
+            Fixture {
+                name: $name,
+                mode: ParseMode::$mode,
+                code: include_bytes!(concat!("../tests/fixtures/", $name, ".bin")),
+            },
+        )*];
+    };
+}
+
+fixtures! {
+    "arm" Arm "cfg_arm",
+    "thumb" Thumb "cfg_thumb",
+    "arm" Arm "frame_arm",
+    "arm" Arm "nds_arm9",
+    "thumb" Thumb "nds_thumb",
+}
+
+/// Returns the fixture `name`, such as `"nds_arm9"`. Each one is assembled from the `.s` file of the same name.
+pub fn fixture(name: &str) -> Option<&'static Fixture> {
+    FIXTURES.iter().find(|fixture| fixture.name == name)
+}
+
+/// Returns `size` bytes of pseudorandom code, the same for the same `seed`. `seed` must not be zero.
+pub fn random_code(seed: u32, size: usize) -> Vec<u8> {
+    // xorshift32
+    let mut state = seed;
+    (0..size.div_ceil(4))
+        .flat_map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()
+        })
+        .take(size)
+        .collect()
+}
@@ -0,0 +1,798 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(all(feature = "v4t", any(feature = "arm", feature = "thumb")))]
+use crate::v4t;
+#[cfg(all(feature = "v5te", any(feature = "arm", feature = "thumb")))]
+use crate::v5te;
+#[cfg(all(feature = "v6k", any(feature = "arm", feature = "thumb")))]
+use crate::v6k;
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+use crate::ParseFlags;
+use crate::{
+    args::{
+        Argument, ArgumentKind, Arguments, CoReg, CpsrFlags, CpsrMode, DReg, DRegIndex, DRegList, Endian, OffsetImm,
+        OffsetReg, Reg, RegList, Register, SReg, SRegList, Shift, ShiftImm, ShiftReg, StatusMask, StatusReg, VfpSysReg,
+    },
+    parse::static_mnemonic,
+    ArgMeta, ParseMode, ParsedIns,
+};
+
+/// Error of parsing assembly text into a [`ParsedIns`]. Each variant except `MissingOperand` holds the token which
+/// failed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TextError {
+    /// No instruction set has this mnemonic
+    UnknownMnemonic(String),
+    /// The token can't be parsed, e.g. an unknown register name or a malformed immediate
+    InvalidToken(String),
+    /// No form of the mnemonic accepts this operand, because it has the wrong kind or there are too many operands
+    UnexpectedOperand(String),
+    /// The operands are valid, but the mnemonic needs more of them
+    MissingOperand,
+}
+
+impl Display for TextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownMnemonic(token) => write!(f, "unknown mnemonic `{token}`"),
+            Self::InvalidToken(token) => write!(f, "invalid token `{token}`"),
+            Self::UnexpectedOperand(token) => write!(f, "unexpected operand `{token}`"),
+            Self::MissingOperand => write!(f, "missing operand"),
+        }
+    }
+}
+
+impl std::error::Error for TextError {}
+
+/// Parses assembly text in the syntax of [`ParsedIns::display`], e.g. `ldrne r0, [r1, #0x4]!`, with the forms of both ARM
+/// and Thumb instructions. ARM forms are tried first, see [`ParsedIns::from_text`].
+impl FromStr for ParsedIns {
+    type Err = TextError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_text(
+            s,
+            &[
+                #[cfg(feature = "arm")]
+                ParseMode::Arm,
+                #[cfg(feature = "thumb")]
+                ParseMode::Thumb,
+            ],
+        )
+    }
+}
+
+impl ParsedIns {
+    /// Parses assembly text in the syntax of [`ParsedIns::display`], e.g. `ldrne r0, [r1, #0x4]!`. Registers may have
+    /// any of the names in [`crate::RegNames`], immediates may be hexadecimal or decimal and register lists may contain
+    /// ranges. The text is not case sensitive.
+    ///
+    /// The kind of each argument is chosen by the first opcode of `mode` which has the mnemonic and accepts the
+    /// operands, see `Opcode::arg_meta`. This matters where ARM and Thumb use different kinds for the same text, such as
+    /// the offset of `ldr r0, [pc, #0x8]`. Branch destinations are offsets, like in [`ParsedIns::display`].
+    pub fn from_text(text: &str, mode: ParseMode) -> Result<Self, TextError> {
+        parse_text(text, &[mode])
+    }
+}
+
+fn parse_text(text: &str, modes: &[ParseMode]) -> Result<ParsedIns, TextError> {
+    let text = text.trim().to_ascii_lowercase();
+    let (mnemonic, operands) = text.split_once(char::is_whitespace).unwrap_or((&text, ""));
+    let mnemonic = static_mnemonic(mnemonic).ok_or_else(|| TextError::UnknownMnemonic(mnemonic.to_string()))?;
+    let operands = parse_operands(operands)?;
+
+    let ins = ParsedIns {
+        mnemonic,
+        args: Arguments::default(),
+    };
+    let base = ins.mnemonic_base();
+    let forms = modes.iter().flat_map(|mode| forms(base, *mode)).collect::<Vec<_>>();
+    if forms.is_empty() {
+        return Err(TextError::UnknownMnemonic(mnemonic.to_string()));
+    }
+    let context = Context {
+        // The `enable` field is decoded from bit 18, which is set for `cpsid`
+        cps_enable: base == "cpsid",
+    };
+    // Prefer arguments which an opcode can encode, as opcodes with the same mnemonic may use different argument kinds for
+    // the same text, e.g. the offset of Thumb `ldr r0, [pc, #0x8]` and `ldr r0, [r1, #0x8]`
+    let mut fallback = None;
+    let mut furthest = 0;
+    for form in forms {
+        let mut args = vec![];
+        if !assign(&operands, form.meta, context, &mut args, &mut furthest) {
+            continue;
+        }
+        let mut candidate = ins.clone();
+        for (slot, arg) in candidate.args.iter_mut().zip(args) {
+            *slot = arg;
+        }
+        if (form.encodes)(&candidate) {
+            return Ok(candidate);
+        }
+        fallback.get_or_insert(candidate);
+    }
+    if let Some(ins) = fallback {
+        return Ok(ins);
+    }
+    match operands.get(furthest) {
+        Some(operand) => Err(TextError::UnexpectedOperand(operand.text.to_string())),
+        None => Err(TextError::MissingOperand),
+    }
+}
+
+/// Arguments of an opcode
+struct Form {
+    meta: &'static [ArgMeta],
+    /// Returns true if the opcode can encode an instruction
+    encodes: Box<dyn Fn(&ParsedIns) -> bool>,
+}
+
+const DATA_META: &[ArgMeta] = &[ArgMeta {
+    name: "value",
+    optional: false,
+    kind: ArgumentKind::UImm,
+}];
+
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+macro_rules! extend_forms {
+    ($forms:ident, $module:path, $base:ident, $flags:ident) => {{
+        use $module as module;
+        $forms.extend(module::Opcode::from_mnemonic($base).map(|op| Form {
+            meta: op.arg_meta(&$flags),
+            encodes: Box::new(move |ins| op.encode(ins, &$flags).is_ok()),
+        }));
+    }};
+}
+
+/// Returns the forms of every opcode in `mode` with the mnemonic `base`, from the latest version to the earliest and in
+/// unified syntax before divided syntax
+fn forms(base: &str, mode: ParseMode) -> Vec<Form> {
+    // Illegal instructions and data directives are the same in every mode
+    if base == "<illegal>" || base.starts_with('.') {
+        let meta = if base == "<illegal>" { &[] } else { DATA_META };
+        return vec![Form {
+            meta,
+            encodes: Box::new(|_| true),
+        }];
+    }
+    opcode_forms(base, mode)
+}
+
+/// Returns the forms of the opcodes in `mode` with the mnemonic `base`, see [`forms`]
+#[cfg(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+))]
+fn opcode_forms(base: &str, mode: ParseMode) -> Vec<Form> {
+    let mut forms = vec![];
+    for ual in [true, false] {
+        // VFP opcodes have their own mnemonics, so they don't shadow the coprocessor instructions of other modes
+        let flags = ParseFlags {
+            ual,
+            vfp: true,
+            aliases: false,
+            ..Default::default()
+        };
+        match mode {
+            #[cfg(feature = "arm")]
+            ParseMode::Arm => {
+                #[cfg(feature = "v6k")]
+                extend_forms!(forms, v6k::arm, base, flags);
+                #[cfg(feature = "v5te")]
+                extend_forms!(forms, v5te::arm, base, flags);
+                #[cfg(feature = "v4t")]
+                extend_forms!(forms, v4t::arm, base, flags);
+            }
+            #[cfg(feature = "thumb")]
+            ParseMode::Thumb => {
+                #[cfg(feature = "v6k")]
+                extend_forms!(forms, v6k::thumb, base, flags);
+                #[cfg(feature = "v5te")]
+                extend_forms!(forms, v5te::thumb, base, flags);
+                #[cfg(feature = "v4t")]
+                extend_forms!(forms, v4t::thumb, base, flags);
+            }
+            ParseMode::Data => {}
+        }
+    }
+    forms
+}
+
+/// Without an instruction set and a version there are no opcodes
+#[cfg(not(all(
+    any(feature = "arm", feature = "thumb"),
+    any(feature = "v4t", feature = "v5te", feature = "v6k")
+)))]
+fn opcode_forms(_base: &str, _mode: ParseMode) -> Vec<Form> {
+    vec![]
+}
+
+/// Information from the mnemonic which some arguments need
+#[derive(Clone, Copy)]
+struct Context {
+    /// Whether `cps` enables or disables interrupts, which is part of [`CpsrFlags`] but not of its text
+    cps_enable: bool,
+}
+
+/// Assigns each operand to an argument of `meta`, skipping optional arguments as needed. `furthest` is the index of the
+/// furthest operand which was reached, for error reporting.
+fn assign(operands: &[Operand], meta: &[ArgMeta], context: Context, args: &mut Vec<Argument>, furthest: &mut usize) -> bool {
+    *furthest = (*furthest).max(args.len());
+    let Some((operand, rest)) = operands.split_first() else {
+        return meta.iter().all(|meta| meta.optional);
+    };
+    let Some((first, meta_rest)) = meta.split_first() else {
+        return false;
+    };
+    if let Some(arg) = operand.to_argument(first.kind, context) {
+        args.push(arg);
+        if assign(rest, meta_rest, context, args, furthest) {
+            return true;
+        }
+        args.pop();
+    }
+    first.optional && assign(operands, meta_rest, context, args, furthest)
+}
+
+/// Where an operand appears relative to a memory operand
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Place {
+    Plain,
+    /// Base register in brackets
+    Base {
+        writeback: bool,
+    },
+    /// In brackets after the base register
+    Offset,
+    /// After the closing bracket
+    PostOffset,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Value {
+    Reg {
+        reg: Register,
+        negative: bool,
+        bang: bool,
+    },
+    Imm {
+        value: i64,
+        bang: bool,
+    },
+    RegList(RegList),
+    CoOption(u32),
+    Shift(Shift),
+    ShiftImm(ShiftImm),
+    ShiftReg(ShiftReg),
+    CoReg(CoReg),
+    CoprocNum(u32),
+    /// A status register with or without a field mask
+    Status {
+        mask: StatusMask,
+        has_mask: bool,
+    },
+    CpsrFlags {
+        a: bool,
+        i: bool,
+        f: bool,
+    },
+    Endian(Endian),
+    SReg(SReg),
+    DReg(DReg),
+    DRegIndex(DRegIndex),
+    SRegList(SRegList),
+    DRegList(DRegList),
+    VfpSysReg(VfpSysReg),
+}
+
+#[derive(Clone, Debug)]
+struct Operand<'a> {
+    value: Value,
+    place: Place,
+    text: &'a str,
+}
+
+impl Operand<'_> {
+    fn to_argument(&self, kind: ArgumentKind, context: Context) -> Option<Argument> {
+        let post_indexed = self.place == Place::PostOffset;
+        let in_memory = matches!(self.place, Place::Offset | Place::PostOffset);
+        Some(match (kind, self.value) {
+            (
+                ArgumentKind::Reg,
+                Value::Reg {
+                    reg,
+                    negative: false,
+                    bang,
+                },
+            ) => match self.place {
+                Place::Plain => Argument::Reg(Reg {
+                    deref: false,
+                    reg,
+                    writeback: bang,
+                }),
+                Place::Base { writeback } => Argument::Reg(Reg {
+                    deref: true,
+                    reg,
+                    writeback,
+                }),
+                Place::Offset | Place::PostOffset => return None,
+            },
+            (
+                ArgumentKind::OffsetReg,
+                Value::Reg {
+                    reg,
+                    negative,
+                    bang: false,
+                },
+            ) if in_memory => Argument::OffsetReg(OffsetReg {
+                add: !negative,
+                post_indexed,
+                reg,
+            }),
+            (ArgumentKind::OffsetImm, Value::Imm { value, bang: false }) if in_memory => Argument::OffsetImm(OffsetImm {
+                post_indexed,
+                value: value.try_into().ok()?,
+            }),
+            (ArgumentKind::UImm, Value::Imm { value, bang: false }) => Argument::UImm(value.try_into().ok()?),
+            (ArgumentKind::SatImm, Value::Imm { value, bang: false }) => Argument::SatImm(value.try_into().ok()?),
+            (ArgumentKind::SImm, Value::Imm { value, bang: false }) => Argument::SImm(value.try_into().ok()?),
+            (ArgumentKind::BranchDest, Value::Imm { value, bang: false }) => Argument::BranchDest(value.try_into().ok()?),
+            (ArgumentKind::CoOpcode, Value::Imm { value, bang: false }) => Argument::CoOpcode(value.try_into().ok()?),
+            (ArgumentKind::CpsrMode, Value::Imm { value, bang }) => Argument::CpsrMode(CpsrMode {
+                mode: value.try_into().ok()?,
+                writeback: bang,
+            }),
+            (ArgumentKind::RegList, Value::RegList(list)) => Argument::RegList(list),
+            (ArgumentKind::CoOption, Value::CoOption(x)) => Argument::CoOption(x),
+            (ArgumentKind::Shift, Value::Shift(x)) => Argument::Shift(x),
+            (ArgumentKind::ShiftImm, Value::ShiftImm(x)) => Argument::ShiftImm(x),
+            (ArgumentKind::ShiftReg, Value::ShiftReg(x)) => Argument::ShiftReg(x),
+            (ArgumentKind::CoReg, Value::CoReg(x)) => Argument::CoReg(x),
+            (ArgumentKind::CoprocNum, Value::CoprocNum(x)) => Argument::CoprocNum(x),
+            (ArgumentKind::StatusReg, Value::Status { mask, has_mask: false }) => Argument::StatusReg(mask.reg),
+            (ArgumentKind::StatusMask, Value::Status { mask, .. }) => Argument::StatusMask(mask),
+            (ArgumentKind::CpsrFlags, Value::CpsrFlags { a, i, f }) => Argument::CpsrFlags(CpsrFlags {
+                a,
+                enable: context.cps_enable,
+                f,
+                i,
+            }),
+            (ArgumentKind::Endian, Value::Endian(x)) => Argument::Endian(x),
+            (ArgumentKind::SReg, Value::SReg(x)) => Argument::SReg(x),
+            (ArgumentKind::DReg, Value::DReg(x)) => Argument::DReg(x),
+            (ArgumentKind::DRegIndex, Value::DRegIndex(x)) => Argument::DRegIndex(x),
+            (ArgumentKind::SRegList, Value::SRegList(x)) => Argument::SRegList(x),
+            (ArgumentKind::DRegList, Value::DRegList(x)) => Argument::DRegList(x),
+            (ArgumentKind::VfpSysReg, Value::VfpSysReg(x)) => Argument::VfpSysReg(x),
+            _ => return None,
+        })
+    }
+}
+
+/// Splits text into words, numbers and single punctuation characters
+struct Lexer<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = &self.text[self.pos..];
+        let first = rest.chars().next()?;
+        let len = if first.is_ascii_alphanumeric() || first == '_' {
+            rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len())
+        } else {
+            first.len_utf8()
+        };
+        Some(&rest[..len])
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.peek()?;
+        self.pos += token.len();
+        Some(token)
+    }
+
+    /// Consumes the next token if it is `token`
+    fn eat(&mut self, token: &str) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), TextError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.invalid())
+        }
+    }
+
+    /// Returns an error for the next token, or for the end of the text
+    fn invalid(&mut self) -> TextError {
+        TextError::InvalidToken(self.peek().unwrap_or_default().to_string())
+    }
+}
+
+fn parse_operands(text: &str) -> Result<Vec<Operand<'_>>, TextError> {
+    let mut lexer = Lexer { text, pos: 0 };
+    let mut operands = vec![];
+    if lexer.peek().is_none() {
+        return Ok(operands);
+    }
+    let mut after_memory = false;
+    loop {
+        if lexer.eat("[") {
+            parse_memory(&mut lexer, &mut operands)?;
+            after_memory = true;
+        } else {
+            let place = if after_memory { Place::PostOffset } else { Place::Plain };
+            operands.push(parse_operand(&mut lexer, place)?);
+        }
+        if lexer.peek().is_none() {
+            return Ok(operands);
+        }
+        lexer.expect(",")?;
+    }
+}
+
+/// Parses a memory operand after its opening bracket
+fn parse_memory<'a>(lexer: &mut Lexer<'a>, operands: &mut Vec<Operand<'a>>) -> Result<(), TextError> {
+    let base_index = operands.len();
+    let base = parse_operand(lexer, Place::Base { writeback: false })?;
+    if !matches!(
+        base.value,
+        Value::Reg {
+            negative: false,
+            bang: false,
+            ..
+        }
+    ) {
+        return Err(TextError::InvalidToken(base.text.to_string()));
+    }
+    operands.push(base);
+    while lexer.eat(",") {
+        operands.push(parse_operand(lexer, Place::Offset)?);
+    }
+    lexer.expect("]")?;
+    if lexer.eat("!") {
+        operands[base_index].place = Place::Base { writeback: true };
+    }
+    Ok(())
+}
+
+fn parse_operand<'a>(lexer: &mut Lexer<'a>, place: Place) -> Result<Operand<'a>, TextError> {
+    lexer.skip_whitespace();
+    let start = lexer.pos;
+    let value = parse_value(lexer)?;
+    Ok(Operand {
+        value,
+        place,
+        text: &lexer.text[start..lexer.pos],
+    })
+}
+
+fn parse_value(lexer: &mut Lexer) -> Result<Value, TextError> {
+    let Some(token) = lexer.peek() else {
+        return Err(lexer.invalid());
+    };
+    match token {
+        "#" => {
+            lexer.next();
+            let value = parse_imm(lexer)?;
+            let bang = lexer.eat("!");
+            Ok(Value::Imm { value, bang })
+        }
+        "-" => {
+            lexer.next();
+            let reg = parse_register_token(lexer)?;
+            Ok(Value::Reg {
+                reg,
+                negative: true,
+                bang: false,
+            })
+        }
+        "{" => {
+            lexer.next();
+            if lexer
+                .peek()
+                .is_some_and(|token| token.starts_with(|c: char| c.is_ascii_digit()))
+            {
+                let value = parse_number(lexer)?;
+                lexer.expect("}")?;
+                return Ok(Value::CoOption(value.try_into().map_err(|_| lexer.invalid())?));
+            }
+            if lexer.peek().and_then(parse_fp_register).is_some() {
+                return parse_fp_list(lexer);
+            }
+            let regs = parse_reg_list(lexer)?;
+            let user_mode = lexer.eat("^");
+            Ok(Value::RegList(RegList { regs, user_mode }))
+        }
+        _ => {
+            lexer.next();
+            parse_word(lexer, token)
+        }
+    }
+}
+
+/// Parses an operand which starts with the word `token`
+fn parse_word(lexer: &mut Lexer, token: &str) -> Result<Value, TextError> {
+    if let Some(reg) = parse_register(token) {
+        let bang = lexer.eat("!");
+        return Ok(Value::Reg {
+            reg,
+            negative: false,
+            bang,
+        });
+    }
+    if let Some(op) = parse_shift(token) {
+        if lexer.eat("#") {
+            let imm = parse_imm(lexer)?
+                .try_into()
+                .map_err(|_| TextError::InvalidToken(token.to_string()))?;
+            return Ok(Value::ShiftImm(ShiftImm { imm, op }));
+        }
+        if let Some(reg) = lexer.peek().and_then(parse_register) {
+            lexer.next();
+            return Ok(Value::ShiftReg(ShiftReg { op, reg }));
+        }
+        return Ok(Value::Shift(op));
+    }
+    if let Some(value) = parse_status(token) {
+        return Ok(value);
+    }
+    match parse_fp_register(token) {
+        Some(Value::DReg(reg)) if lexer.eat("[") => {
+            let index = parse_number(lexer)?.try_into().map_err(|_| lexer.invalid())?;
+            lexer.expect("]")?;
+            return Ok(Value::DRegIndex(DRegIndex { reg, index }));
+        }
+        Some(value) => return Ok(value),
+        None => {}
+    }
+    let number = |prefix| {
+        token
+            .strip_prefix(prefix)
+            .and_then(|n: &str| n.parse::<u32>().ok())
+            .filter(|n| *n < 16)
+    };
+    if let Some(number) = number('c') {
+        return Ok(Value::CoReg(CoReg::parse(number)));
+    }
+    if let Some(number) = number('p') {
+        return Ok(Value::CoprocNum(number));
+    }
+    match token {
+        "fpsid" => return Ok(Value::VfpSysReg(VfpSysReg::Fpsid)),
+        "fpscr" => return Ok(Value::VfpSysReg(VfpSysReg::Fpscr)),
+        "fpexc" => return Ok(Value::VfpSysReg(VfpSysReg::Fpexc)),
+        "fpinst" => return Ok(Value::VfpSysReg(VfpSysReg::Fpinst)),
+        "le" => return Ok(Value::Endian(Endian::Le)),
+        "be" => return Ok(Value::Endian(Endian::Be)),
+        "none" => {
+            return Ok(Value::CpsrFlags {
+                a: false,
+                i: false,
+                f: false,
+            })
+        }
+        _ => {}
+    }
+    let unique_flags = "aif".chars().filter(|c| token.contains(*c)).count();
+    if token.chars().all(|c| "aif".contains(c)) && token.len() == unique_flags {
+        return Ok(Value::CpsrFlags {
+            a: token.contains('a'),
+            i: token.contains('i'),
+            f: token.contains('f'),
+        });
+    }
+    Err(TextError::InvalidToken(token.to_string()))
+}
+
+/// Parses an immediate after its `#`
+fn parse_imm(lexer: &mut Lexer) -> Result<i64, TextError> {
+    let negative = lexer.eat("-");
+    let value = parse_number(lexer)?;
+    Ok(if negative { -value } else { value })
+}
+
+/// Parses a hexadecimal number with a `0x` prefix or a decimal number
+fn parse_number(lexer: &mut Lexer) -> Result<i64, TextError> {
+    let token = lexer.next().unwrap_or_default();
+    let value = match token.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => token.parse::<u32>(),
+    };
+    value.map(i64::from).map_err(|_| TextError::InvalidToken(token.to_string()))
+}
+
+/// Parses a register list after its opening brace, including the closing brace
+fn parse_reg_list(lexer: &mut Lexer) -> Result<u32, TextError> {
+    let mut regs = 0;
+    if lexer.eat("}") {
+        return Ok(regs);
+    }
+    loop {
+        lexer.skip_whitespace();
+        let range_start = lexer.pos;
+        let start = parse_register_token(lexer)?;
+        let end = if lexer.eat("-") { parse_register_token(lexer)? } else { start };
+        if end < start {
+            return Err(TextError::InvalidToken(lexer.text[range_start..lexer.pos].to_string()));
+        }
+        for reg in start as u32..=end as u32 {
+            regs |= 1 << reg;
+        }
+        if lexer.eat("}") {
+            return Ok(regs);
+        }
+        lexer.expect(",")?;
+    }
+}
+
+fn parse_register_token(lexer: &mut Lexer) -> Result<Register, TextError> {
+    let token = lexer.next().unwrap_or_default();
+    parse_register(token).ok_or_else(|| TextError::InvalidToken(token.to_string()))
+}
+
+/// Parses a register name, including the alternative names in [`crate::RegNames`]
+fn parse_register(name: &str) -> Option<Register> {
+    let number = |prefix| name.strip_prefix(prefix).and_then(|n: &str| n.parse::<u32>().ok());
+    let reg = match name {
+        "sb" | "tr" => Register::R9,
+        "sl" => Register::R10,
+        "fp" => Register::R11,
+        "ip" => Register::R12,
+        "sp" => Register::Sp,
+        "lr" => Register::Lr,
+        "pc" => Register::Pc,
+        _ => {
+            if let Some(n) = number('r') {
+                Register::try_parse(n)?
+            } else if let Some(n) = number('a').filter(|n| (1..=4).contains(n)) {
+                Register::parse(n - 1)
+            } else if let Some(n) = number('v').filter(|n| (1..=8).contains(n)) {
+                Register::parse(n + 3)
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(reg)
+}
+
+/// Parses a single-precision (`s0`-`s31`) or double-precision (`d0`-`d15`) VFP register name
+fn parse_fp_register(name: &str) -> Option<Value> {
+    let number = |prefix| name.strip_prefix(prefix).and_then(|n: &str| n.parse::<u32>().ok());
+    if let Some(n) = number('s') {
+        Some(Value::SReg(SReg::try_parse(n)?))
+    } else if let Some(n) = number('d') {
+        Some(Value::DReg(DReg::try_parse(n)?))
+    } else {
+        None
+    }
+}
+
+/// Parses a list of consecutive VFP registers after its opening brace, including the closing brace, e.g. `{d8-d11}` or
+/// `{s0, s1}`
+fn parse_fp_list(lexer: &mut Lexer) -> Result<Value, TextError> {
+    let mut first = None;
+    let mut count = 0;
+    loop {
+        lexer.skip_whitespace();
+        let range_start = lexer.pos;
+        let start = parse_fp_register_token(lexer)?;
+        let end = if lexer.eat("-") {
+            parse_fp_register_token(lexer)?
+        } else {
+            start
+        };
+        let invalid = || TextError::InvalidToken(lexer.text[range_start..lexer.pos].to_string());
+        let (start_number, end_number) = match (start, end, first) {
+            (Value::SReg(start), Value::SReg(end), None | Some(Value::SReg(_))) => (start as u32, end as u32),
+            (Value::DReg(start), Value::DReg(end), None | Some(Value::DReg(_))) => (start as u32, end as u32),
+            _ => return Err(invalid()),
+        };
+        // The registers must continue where the previous ones ended
+        let expected = match first {
+            Some(Value::SReg(reg)) => reg as u32 + count,
+            Some(Value::DReg(reg)) => reg as u32 + count,
+            _ => start_number,
+        };
+        if end_number < start_number || start_number != expected {
+            return Err(invalid());
+        }
+        first.get_or_insert(start);
+        count += end_number - start_number + 1;
+        if lexer.eat("}") {
+            break;
+        }
+        lexer.expect(",")?;
+    }
+    Ok(match first {
+        Some(Value::SReg(first)) => Value::SRegList(SRegList { first, count }),
+        Some(Value::DReg(first)) => Value::DRegList(DRegList { first, count }),
+        _ => unreachable!(),
+    })
+}
+
+fn parse_fp_register_token(lexer: &mut Lexer) -> Result<Value, TextError> {
+    let token = lexer.next().unwrap_or_default();
+    parse_fp_register(token).ok_or_else(|| TextError::InvalidToken(token.to_string()))
+}
+
+fn parse_shift(name: &str) -> Option<Shift> {
+    match name {
+        "lsl" => Some(Shift::Lsl),
+        "lsr" => Some(Shift::Lsr),
+        "asr" => Some(Shift::Asr),
+        "ror" => Some(Shift::Ror),
+        "rrx" => Some(Shift::Rrx),
+        _ => None,
+    }
+}
+
+/// Parses `cpsr` or `spsr`, optionally followed by a field mask such as `_fc`, or `apsr_nzcv`
+fn parse_status(name: &str) -> Option<Value> {
+    if name == "apsr_nzcv" {
+        let mask = StatusMask {
+            control: false,
+            extension: false,
+            flags: false,
+            reg: StatusReg::ApsrNzcv,
+            status: false,
+        };
+        return Some(Value::Status { mask, has_mask: false });
+    }
+    let (reg, fields) = name.split_once('_').map_or((name, None), |(reg, fields)| (reg, Some(fields)));
+    let reg = match reg {
+        "cpsr" => StatusReg::Cpsr,
+        "spsr" => StatusReg::Spsr,
+        _ => return None,
+    };
+    let mut mask = StatusMask {
+        control: false,
+        extension: false,
+        flags: false,
+        reg,
+        status: false,
+    };
+    let Some(fields) = fields else {
+        return Some(Value::Status { mask, has_mask: false });
+    };
+    if fields.is_empty() {
+        return None;
+    }
+    for c in fields.chars() {
+        let field = match c {
+            'f' => &mut mask.flags,
+            's' => &mut mask.status,
+            'x' => &mut mask.extension,
+            'c' => &mut mask.control,
+            _ => return None,
+        };
+        if *field {
+            return None;
+        }
+        *field = true;
+    }
+    Some(Value::Status { mask, has_mask: true })
+}
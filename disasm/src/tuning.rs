@@ -0,0 +1,36 @@
+//! Constants for structuring fast disassembly loops. The fastest known loop over a whole image is in
+//! `examples/fast_dump.rs`: it decodes a batch of [`recommended_batch_size`] instructions, then parses the batch into a
+//! reused buffer, then writes the batch into a reused `String`. `cargo bench -p unarm --bench dump` compares it with
+//! parsing and displaying one instruction at a time.
+
+use std::mem::size_of;
+
+use crate::ParsedIns;
+
+/// Size of the L1 data cache which [`recommended_batch_size`] assumes. Most ARM, x86 and Apple CPUs have at least
+/// 32 KB.
+pub const L1_DATA_CACHE_SIZE: usize = 0x8000;
+
+/// Size of a decoded instruction, which is its code and its opcode like `v5te::arm::Ins`
+pub const DECODED_INS_SIZE: usize = size_of::<(u32, u16)>();
+
+/// Size of an entry of the output buffer of `parse_all`, which is an address and a [`ParsedIns`]
+pub const PARSED_ENTRY_SIZE: usize = size_of::<(u32, ParsedIns)>();
+
+/// Returns the number of instructions of `ins_size` bytes to process per batch. The code, the decoded instructions
+/// and the parsed instructions of a batch take up at most half of [`L1_DATA_CACHE_SIZE`], leaving the other half for
+/// the decode table and the output text. Rounded down to a power of two, and at least 1.
+pub const fn recommended_batch_size(ins_size: usize) -> usize {
+    let count = (L1_DATA_CACHE_SIZE / 2) / (ins_size + DECODED_INS_SIZE + PARSED_ENTRY_SIZE);
+    if count == 0 {
+        1
+    } else {
+        1 << (usize::BITS - 1 - count.leading_zeros())
+    }
+}
+
+/// [`recommended_batch_size`] of ARM instructions
+pub const ARM_BATCH_SIZE: usize = recommended_batch_size(4);
+
+/// [`recommended_batch_size`] of Thumb instructions
+pub const THUMB_BATCH_SIZE: usize = recommended_batch_size(2);
@@ -1,8 +1,18 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused)]
-#![allow(clippy::double_parens, clippy::unnecessary_cast)]
+#![allow(
+    clippy::double_parens,
+    clippy::unnecessary_cast,
+    clippy::match_like_matches_macro,
+    clippy::collapsible_match
+)]
 // Generated by unarm-generator. Do not edit!
-use crate::{ParseFlags, args::*, parse::ParsedIns};
+use crate::{
+    ParseFlags, args::*,
+    encode::{self, EncodeCase, EncodeError, EncodeField, EncodeForm, FieldError},
+    enumerate::{self, FieldDesc, FieldFilter},
+    parse::{ArgMeta, Condition, ParsedIns},
+};
 use super::Ins;
 /// These are the mnemonics of each opcode. Some mnemonics are duplicated due to them having multiple formats.
 static OPCODE_MNEMONICS: [&str; 68] = [
@@ -75,7 +85,9639 @@ static OPCODE_MNEMONICS: [&str; 68] = [
     "umlal",
     "umull",
 ];
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// These are the names of each opcode variant, see [`Opcode::variant_name`].
+static OPCODE_VARIANT_NAMES: [&str; 68] = [
+    "Adc",
+    "Add",
+    "And",
+    "Asr",
+    "B",
+    "Bl",
+    "Bic",
+    "Bx",
+    "Cdp",
+    "Cmn",
+    "Cmp",
+    "Eor",
+    "Ldc",
+    "LdmW",
+    "Ldm",
+    "LdmP",
+    "LdmPcW",
+    "LdmPc",
+    "Ldr",
+    "LdrB",
+    "LdrBt",
+    "LdrH",
+    "LdrSb",
+    "LdrSh",
+    "LdrT",
+    "Lsl",
+    "Lsr",
+    "Mcr",
+    "Mla",
+    "Mov",
+    "MovImm",
+    "MovReg",
+    "Mrc",
+    "Mrs",
+    "MsrI",
+    "Msr",
+    "Mul",
+    "Mvn",
+    "Orr",
+    "PopM",
+    "PopR",
+    "PushM",
+    "PushR",
+    "Ror",
+    "Rrx",
+    "Rsb",
+    "Rsc",
+    "Sbc",
+    "Smlal",
+    "Smull",
+    "Stc",
+    "Stm",
+    "StmW",
+    "StmP",
+    "Str",
+    "StrB",
+    "StrBt",
+    "StrH",
+    "StrT",
+    "Sub",
+    "Svc",
+    "Swi",
+    "Swp",
+    "Swpb",
+    "Teq",
+    "Tst",
+    "Umlal",
+    "Umull",
+];
+/// These are the ids of each opcode, see [`Opcode::id`].
+static OPCODE_IDS: [&str; 68] = [
+    "adc",
+    "add",
+    "and",
+    "asr",
+    "b",
+    "bl",
+    "bic",
+    "bx",
+    "cdp",
+    "cmn",
+    "cmp",
+    "eor",
+    "ldc",
+    "ldm_w",
+    "ldm",
+    "ldm_p",
+    "ldm_pc_w",
+    "ldm_pc",
+    "ldr",
+    "ldr_b",
+    "ldr_bt",
+    "ldr_h",
+    "ldr_sb",
+    "ldr_sh",
+    "ldr_t",
+    "lsl",
+    "lsr",
+    "mcr",
+    "mla",
+    "mov",
+    "mov_imm",
+    "mov_reg",
+    "mrc",
+    "mrs",
+    "msr_i",
+    "msr",
+    "mul",
+    "mvn",
+    "orr",
+    "pop_m",
+    "pop_r",
+    "push_m",
+    "push_r",
+    "ror",
+    "rrx",
+    "rsb",
+    "rsc",
+    "sbc",
+    "smlal",
+    "smull",
+    "stc",
+    "stm",
+    "stm_w",
+    "stm_p",
+    "str",
+    "str_b",
+    "str_bt",
+    "str_h",
+    "str_t",
+    "sub",
+    "svc",
+    "swi",
+    "swp",
+    "swpb",
+    "teq",
+    "tst",
+    "umlal",
+    "umull",
+];
+/// Opcodes sorted by id, see [`Opcode::from_id`].
+static OPCODES_BY_ID: &[(&str, Opcode)] = &[
+    ("adc", Opcode::Adc),
+    ("add", Opcode::Add),
+    ("and", Opcode::And),
+    ("asr", Opcode::Asr),
+    ("b", Opcode::B),
+    ("bic", Opcode::Bic),
+    ("bl", Opcode::Bl),
+    ("bx", Opcode::Bx),
+    ("cdp", Opcode::Cdp),
+    ("cmn", Opcode::Cmn),
+    ("cmp", Opcode::Cmp),
+    ("eor", Opcode::Eor),
+    ("illegal", Opcode::Illegal),
+    ("ldc", Opcode::Ldc),
+    ("ldm", Opcode::Ldm),
+    ("ldm_p", Opcode::LdmP),
+    ("ldm_pc", Opcode::LdmPc),
+    ("ldm_pc_w", Opcode::LdmPcW),
+    ("ldm_w", Opcode::LdmW),
+    ("ldr", Opcode::Ldr),
+    ("ldr_b", Opcode::LdrB),
+    ("ldr_bt", Opcode::LdrBt),
+    ("ldr_h", Opcode::LdrH),
+    ("ldr_sb", Opcode::LdrSb),
+    ("ldr_sh", Opcode::LdrSh),
+    ("ldr_t", Opcode::LdrT),
+    ("lsl", Opcode::Lsl),
+    ("lsr", Opcode::Lsr),
+    ("mcr", Opcode::Mcr),
+    ("mla", Opcode::Mla),
+    ("mov", Opcode::Mov),
+    ("mov_imm", Opcode::MovImm),
+    ("mov_reg", Opcode::MovReg),
+    ("mrc", Opcode::Mrc),
+    ("mrs", Opcode::Mrs),
+    ("msr", Opcode::Msr),
+    ("msr_i", Opcode::MsrI),
+    ("mul", Opcode::Mul),
+    ("mvn", Opcode::Mvn),
+    ("orr", Opcode::Orr),
+    ("pop_m", Opcode::PopM),
+    ("pop_r", Opcode::PopR),
+    ("push_m", Opcode::PushM),
+    ("push_r", Opcode::PushR),
+    ("ror", Opcode::Ror),
+    ("rrx", Opcode::Rrx),
+    ("rsb", Opcode::Rsb),
+    ("rsc", Opcode::Rsc),
+    ("sbc", Opcode::Sbc),
+    ("smlal", Opcode::Smlal),
+    ("smull", Opcode::Smull),
+    ("stc", Opcode::Stc),
+    ("stm", Opcode::Stm),
+    ("stm_p", Opcode::StmP),
+    ("stm_w", Opcode::StmW),
+    ("str", Opcode::Str),
+    ("str_b", Opcode::StrB),
+    ("str_bt", Opcode::StrBt),
+    ("str_h", Opcode::StrH),
+    ("str_t", Opcode::StrT),
+    ("sub", Opcode::Sub),
+    ("svc", Opcode::Svc),
+    ("swi", Opcode::Swi),
+    ("swp", Opcode::Swp),
+    ("swpb", Opcode::Swpb),
+    ("teq", Opcode::Teq),
+    ("tst", Opcode::Tst),
+    ("umlal", Opcode::Umlal),
+    ("umull", Opcode::Umull),
+];
+const ARG_BRANCH_OFFSET: ArgMeta = ArgMeta {
+    name: "branch_offset",
+    optional: false,
+    kind: ArgumentKind::BranchDest,
+};
+const ARG_CODAT_OPCODE_1: ArgMeta = ArgMeta {
+    name: "codat_opcode_1",
+    optional: false,
+    kind: ArgumentKind::CoOpcode,
+};
+const ARG_COMOV_OPCODE_1: ArgMeta = ArgMeta {
+    name: "comov_opcode_1",
+    optional: false,
+    kind: ArgumentKind::CoOpcode,
+};
+const ARG_COPROC: ArgMeta = ArgMeta {
+    name: "coproc",
+    optional: false,
+    kind: ArgumentKind::CoprocNum,
+};
+const ARG_CRD: ArgMeta = ArgMeta {
+    name: "CRd",
+    optional: false,
+    kind: ArgumentKind::CoReg,
+};
+const ARG_CRM: ArgMeta = ArgMeta {
+    name: "CRm",
+    optional: false,
+    kind: ArgumentKind::CoReg,
+};
+const ARG_CRN: ArgMeta = ArgMeta {
+    name: "CRn",
+    optional: false,
+    kind: ArgumentKind::CoReg,
+};
+const ARG_FIELD_MASK: ArgMeta = ArgMeta {
+    name: "field_mask",
+    optional: false,
+    kind: ArgumentKind::StatusMask,
+};
+const ARG_IMMED_24: ArgMeta = ArgMeta {
+    name: "immed_24",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_OPCODE_2: ArgMeta = ArgMeta {
+    name: "opcode_2",
+    optional: false,
+    kind: ArgumentKind::CoOpcode,
+};
+const ARG_R: ArgMeta = ArgMeta {
+    name: "R",
+    optional: false,
+    kind: ArgumentKind::StatusReg,
+};
+const ARG_RD: ArgMeta = ArgMeta {
+    name: "Rd",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RDHI: ArgMeta = ArgMeta {
+    name: "RdHi",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RDLO: ArgMeta = ArgMeta {
+    name: "RdLo",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_REGISTERS: ArgMeta = ArgMeta {
+    name: "registers",
+    optional: false,
+    kind: ArgumentKind::RegList,
+};
+const ARG_REGISTERS_C: ArgMeta = ArgMeta {
+    name: "registers_c",
+    optional: false,
+    kind: ArgumentKind::RegList,
+};
+const ARG_RM: ArgMeta = ArgMeta {
+    name: "Rm",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN: ArgMeta = ArgMeta {
+    name: "Rn",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_12: ArgMeta = ArgMeta {
+    name: "Rn_12",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_DEREF: ArgMeta = ArgMeta {
+    name: "Rn_deref",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_WB: ArgMeta = ArgMeta {
+    name: "Rn_wb",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_ROTATED_IMMED_8: ArgMeta = ArgMeta {
+    name: "rotated_immed_8",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_RS: ArgMeta = ArgMeta {
+    name: "Rs",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RT_LIST: ArgMeta = ArgMeta {
+    name: "Rt_list",
+    optional: false,
+    kind: ArgumentKind::RegList,
+};
+const OPT_COPROC_OFFSET: ArgMeta = ArgMeta {
+    name: "coproc_offset",
+    optional: true,
+    kind: ArgumentKind::OffsetImm,
+};
+const OPT_COPROC_POST_OFFSET: ArgMeta = ArgMeta {
+    name: "coproc_post_offset",
+    optional: true,
+    kind: ArgumentKind::OffsetImm,
+};
+const OPT_IMMED_5: ArgMeta = ArgMeta {
+    name: "immed_5",
+    optional: true,
+    kind: ArgumentKind::UImm,
+};
+const OPT_OFFSET_12: ArgMeta = ArgMeta {
+    name: "offset_12",
+    optional: true,
+    kind: ArgumentKind::OffsetImm,
+};
+const OPT_OFFSET_8: ArgMeta = ArgMeta {
+    name: "offset_8",
+    optional: true,
+    kind: ArgumentKind::OffsetImm,
+};
+const OPT_OPTION: ArgMeta = ArgMeta {
+    name: "option",
+    optional: true,
+    kind: ArgumentKind::CoOption,
+};
+const OPT_POST_OFFSET_12: ArgMeta = ArgMeta {
+    name: "post_offset_12",
+    optional: true,
+    kind: ArgumentKind::OffsetImm,
+};
+const OPT_POST_OFFSET_8: ArgMeta = ArgMeta {
+    name: "post_offset_8",
+    optional: true,
+    kind: ArgumentKind::OffsetImm,
+};
+const OPT_REG_OFFSET: ArgMeta = ArgMeta {
+    name: "reg_offset",
+    optional: true,
+    kind: ArgumentKind::OffsetReg,
+};
+const OPT_REG_POST_OFFSET: ArgMeta = ArgMeta {
+    name: "reg_post_offset",
+    optional: true,
+    kind: ArgumentKind::OffsetReg,
+};
+const OPT_RM: ArgMeta = ArgMeta {
+    name: "Rm",
+    optional: true,
+    kind: ArgumentKind::Reg,
+};
+const OPT_RN_DEREF: ArgMeta = ArgMeta {
+    name: "Rn_deref",
+    optional: true,
+    kind: ArgumentKind::Reg,
+};
+const OPT_RN_DEREF_WB: ArgMeta = ArgMeta {
+    name: "Rn_deref_wb",
+    optional: true,
+    kind: ArgumentKind::Reg,
+};
+const OPT_ROTATED_IMMED_8: ArgMeta = ArgMeta {
+    name: "rotated_immed_8",
+    optional: true,
+    kind: ArgumentKind::UImm,
+};
+const OPT_RRX: ArgMeta = ArgMeta {
+    name: "rrx",
+    optional: true,
+    kind: ArgumentKind::Shift,
+};
+const OPT_RS: ArgMeta = ArgMeta {
+    name: "Rs",
+    optional: true,
+    kind: ArgumentKind::Reg,
+};
+const OPT_SHIFT_IMM: ArgMeta = ArgMeta {
+    name: "shift_imm",
+    optional: true,
+    kind: ArgumentKind::ShiftImm,
+};
+const OPT_SHIFT_REG: ArgMeta = ArgMeta {
+    name: "shift_reg",
+    optional: true,
+    kind: ArgumentKind::ShiftReg,
+};
+/// Minimum number of arguments, maximum number of arguments and argument metadata of each opcode,
+/// in divided (pre-UAL) and unified (UAL) syntax.
+static OPCODE_ARGS: [[(u8, u8, &[ArgMeta]); 2]; 68] = [
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+    ],
+    [(1, 1, &[ARG_BRANCH_OFFSET]), (1, 1, &[ARG_BRANCH_OFFSET])],
+    [(1, 1, &[ARG_BRANCH_OFFSET]), (1, 1, &[ARG_BRANCH_OFFSET])],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [(1, 1, &[ARG_RM]), (1, 1, &[ARG_RM])],
+    [
+        (
+            6,
+            6,
+            &[ARG_COPROC, ARG_CODAT_OPCODE_1, ARG_CRD, ARG_CRN, ARG_CRM, ARG_OPCODE_2],
+        ),
+        (
+            6,
+            6,
+            &[ARG_COPROC, ARG_CODAT_OPCODE_1, ARG_CRD, ARG_CRN, ARG_CRM, ARG_OPCODE_2],
+        ),
+    ],
+    [
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+    ],
+    [
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (
+            4,
+            4,
+            &[
+                ARG_COPROC,
+                ARG_CRD,
+                OPT_RN_DEREF,
+                OPT_COPROC_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_COPROC_POST_OFFSET,
+                OPT_OPTION,
+            ],
+        ),
+        (
+            4,
+            4,
+            &[
+                ARG_COPROC,
+                ARG_CRD,
+                OPT_RN_DEREF,
+                OPT_COPROC_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_COPROC_POST_OFFSET,
+                OPT_OPTION,
+            ],
+        ),
+    ],
+    [(2, 2, &[ARG_RN_WB, ARG_REGISTERS]), (2, 2, &[ARG_RN_WB, ARG_REGISTERS])],
+    [(2, 2, &[ARG_RN, ARG_REGISTERS]), (2, 2, &[ARG_RN, ARG_REGISTERS])],
+    [(2, 2, &[ARG_RN, ARG_REGISTERS_C]), (2, 2, &[ARG_RN, ARG_REGISTERS_C])],
+    [(2, 2, &[ARG_RN_WB, ARG_REGISTERS_C]), (2, 2, &[ARG_RN_WB, ARG_REGISTERS_C])],
+    [(2, 2, &[ARG_RN, ARG_REGISTERS_C]), (2, 2, &[ARG_RN, ARG_REGISTERS_C])],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+    ],
+    [
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+    ],
+    [
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+    ],
+    [
+        (
+            6,
+            6,
+            &[ARG_COPROC, ARG_COMOV_OPCODE_1, ARG_RD, ARG_CRN, ARG_CRM, ARG_OPCODE_2],
+        ),
+        (6, 6, &[ARG_COPROC, ARG_COMOV_OPCODE_1, ARG_RD, ARG_CRN, ARG_CRM, ARG_OPCODE_2]),
+    ],
+    [
+        (4, 4, &[ARG_RDHI, ARG_RM, ARG_RS, ARG_RN_12]),
+        (4, 4, &[ARG_RDHI, ARG_RM, ARG_RS, ARG_RN_12]),
+    ],
+    [
+        (
+            2,
+            3,
+            &[ARG_RD, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+        (
+            2,
+            3,
+            &[ARG_RD, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+    ],
+    [(2, 2, &[ARG_RD, ARG_ROTATED_IMMED_8]), (2, 2, &[ARG_RD, ARG_ROTATED_IMMED_8])],
+    [(2, 2, &[ARG_RD, ARG_RM]), (2, 2, &[ARG_RD, ARG_RM])],
+    [
+        (
+            6,
+            6,
+            &[ARG_COPROC, ARG_COMOV_OPCODE_1, ARG_RD, ARG_CRN, ARG_CRM, ARG_OPCODE_2],
+        ),
+        (6, 6, &[ARG_COPROC, ARG_COMOV_OPCODE_1, ARG_RD, ARG_CRN, ARG_CRM, ARG_OPCODE_2]),
+    ],
+    [(2, 2, &[ARG_RD, ARG_R]), (2, 2, &[ARG_RD, ARG_R])],
+    [
+        (2, 2, &[ARG_FIELD_MASK, ARG_ROTATED_IMMED_8]),
+        (2, 2, &[ARG_FIELD_MASK, ARG_ROTATED_IMMED_8]),
+    ],
+    [(2, 2, &[ARG_FIELD_MASK, ARG_RM]), (2, 2, &[ARG_FIELD_MASK, ARG_RM])],
+    [(3, 3, &[ARG_RDHI, ARG_RM, ARG_RS]), (3, 3, &[ARG_RDHI, ARG_RM, ARG_RS])],
+    [
+        (
+            2,
+            3,
+            &[ARG_RD, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+        (
+            2,
+            3,
+            &[ARG_RD, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [(1, 1, &[ARG_REGISTERS]), (1, 1, &[ARG_REGISTERS])],
+    [(1, 1, &[ARG_RT_LIST]), (1, 1, &[ARG_RT_LIST])],
+    [(1, 1, &[ARG_REGISTERS]), (1, 1, &[ARG_REGISTERS])],
+    [(1, 1, &[ARG_RT_LIST]), (1, 1, &[ARG_RT_LIST])],
+    [
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+        (3, 3, &[ARG_RD, ARG_RM, OPT_IMMED_5, OPT_RS]),
+    ],
+    [(2, 2, &[ARG_RD, ARG_RM]), (2, 2, &[ARG_RD, ARG_RM])],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+    ],
+    [
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+    ],
+    [
+        (
+            4,
+            4,
+            &[
+                ARG_COPROC,
+                ARG_CRD,
+                OPT_RN_DEREF,
+                OPT_COPROC_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_COPROC_POST_OFFSET,
+                OPT_OPTION,
+            ],
+        ),
+        (
+            4,
+            4,
+            &[
+                ARG_COPROC,
+                ARG_CRD,
+                OPT_RN_DEREF,
+                OPT_COPROC_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_COPROC_POST_OFFSET,
+                OPT_OPTION,
+            ],
+        ),
+    ],
+    [(2, 2, &[ARG_RN, ARG_REGISTERS]), (2, 2, &[ARG_RN, ARG_REGISTERS])],
+    [(2, 2, &[ARG_RN_WB, ARG_REGISTERS]), (2, 2, &[ARG_RN_WB, ARG_REGISTERS])],
+    [(2, 2, &[ARG_RN, ARG_REGISTERS_C]), (2, 2, &[ARG_RN, ARG_REGISTERS_C])],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_12,
+                OPT_REG_OFFSET,
+                OPT_SHIFT_IMM,
+                OPT_RRX,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+        (
+            3,
+            3,
+            &[
+                ARG_RD,
+                OPT_RN_DEREF,
+                OPT_OFFSET_8,
+                OPT_REG_OFFSET,
+                OPT_RN_DEREF_WB,
+                OPT_POST_OFFSET_8,
+                OPT_REG_POST_OFFSET,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN_DEREF,
+                OPT_POST_OFFSET_12,
+                OPT_REG_POST_OFFSET,
+                OPT_SHIFT_IMM,
+            ],
+        ),
+    ],
+    [
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+        (
+            3,
+            4,
+            &[
+                ARG_RD,
+                ARG_RN,
+                OPT_ROTATED_IMMED_8,
+                OPT_RM,
+                OPT_SHIFT_IMM,
+                OPT_SHIFT_REG,
+                OPT_RRX,
+            ],
+        ),
+    ],
+    [(1, 1, &[ARG_IMMED_24]), (1, 1, &[ARG_IMMED_24])],
+    [(1, 1, &[ARG_IMMED_24]), (1, 1, &[ARG_IMMED_24])],
+    [(3, 3, &[ARG_RD, ARG_RM, ARG_RN_DEREF]), (3, 3, &[ARG_RD, ARG_RM, ARG_RN_DEREF])],
+    [(3, 3, &[ARG_RD, ARG_RM, ARG_RN_DEREF]), (3, 3, &[ARG_RD, ARG_RM, ARG_RN_DEREF])],
+    [
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+    ],
+    [
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+        (
+            2,
+            3,
+            &[ARG_RN, OPT_ROTATED_IMMED_8, OPT_RM, OPT_SHIFT_IMM, OPT_SHIFT_REG, OPT_RRX],
+        ),
+    ],
+    [
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+    ],
+    [
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+        (4, 4, &[ARG_RDLO, ARG_RDHI, ARG_RM, ARG_RS]),
+    ],
+];
+/// Bitmask, pattern and fields of each opcode.
+static OPCODE_FIELDS: [(u32, u32, &[FieldDesc]); 68] = [
+    (
+        0x0de00000,
+        0x00a00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00800000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00000000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0fef0060,
+        0x01a00040,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "shift_arg",
+                bitmask: 0x00000090,
+            },
+            FieldDesc {
+                name: "immed_5",
+                bitmask: 0x00000f80,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+        ],
+    ),
+    (
+        0x0f000000,
+        0x0a000000,
+        &[
+            FieldDesc {
+                name: "branch_offset",
+                bitmask: 0x00ffffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0f000000,
+        0x0b000000,
+        &[
+            FieldDesc {
+                name: "branch_offset",
+                bitmask: 0x00ffffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x01c00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0ffffff0,
+        0x012fff10,
+        &[
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0f000010,
+        0x0e000000,
+        &[
+            FieldDesc {
+                name: "coproc",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "codat_opcode_1",
+                bitmask: 0x00f00000,
+            },
+            FieldDesc {
+                name: "CRd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "CRn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "CRm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "opcode_2",
+                bitmask: 0x000000e0,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0df0f000,
+        0x01700000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0df0f000,
+        0x01500000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00200000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0e100000,
+        0x0c100000,
+        &[
+            FieldDesc {
+                name: "coproc",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "CRd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "L",
+                bitmask: 0x00400000,
+            },
+            FieldDesc {
+                name: "addr_coproc",
+                bitmask: 0x01a00000,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "coproc_offset",
+                bitmask: 0x008000ff,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "coproc_post_offset",
+                bitmask: 0x008000ff,
+            },
+            FieldDesc {
+                name: "option",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0e700000,
+        0x08300000,
+        &[
+            FieldDesc {
+                name: "Rn_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0e700000,
+        0x08100000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0e708000,
+        0x08500000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers_c",
+                bitmask: 0x00007fff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0e708000,
+        0x08708000,
+        &[
+            FieldDesc {
+                name: "Rn_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers_c",
+                bitmask: 0x00007fff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0e708000,
+        0x08508000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers_c",
+                bitmask: 0x00007fff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0c500000,
+        0x04100000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldr_str",
+                bitmask: 0x03200ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0c500000,
+        0x04500000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldr_str",
+                bitmask: 0x03200ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0d700000,
+        0x04700000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldrt_strt",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+        ],
+    ),
+    (
+        0x0e1000f0,
+        0x001000b0,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_misc_ldr_str",
+                bitmask: 0x01600f00,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0e1000f0,
+        0x001000d0,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_misc_ldr_str",
+                bitmask: 0x01600f00,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0e1000f0,
+        0x001000f0,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_misc_ldr_str",
+                bitmask: 0x01600f00,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0d700000,
+        0x04300000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldrt_strt",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+        ],
+    ),
+    (
+        0x0fef0060,
+        0x01a00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "shift_arg",
+                bitmask: 0x00000090,
+            },
+            FieldDesc {
+                name: "immed_5",
+                bitmask: 0x00000f80,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+        ],
+    ),
+    (
+        0x0fef0060,
+        0x01a00020,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "shift_arg",
+                bitmask: 0x00000090,
+            },
+            FieldDesc {
+                name: "immed_5",
+                bitmask: 0x00000f80,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+        ],
+    ),
+    (
+        0x0f100010,
+        0x0e000010,
+        &[
+            FieldDesc {
+                name: "coproc",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "comov_opcode_1",
+                bitmask: 0x00e00000,
+            },
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "CRn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "CRm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "opcode_2",
+                bitmask: 0x000000e0,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fe000f0,
+        0x00200090,
+        &[
+            FieldDesc {
+                name: "RdHi",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "Rn_12",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0def0000,
+        0x01a00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0fef0000,
+        0x03a00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0fef0ff0,
+        0x01a00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0f100010,
+        0x0e100010,
+        &[
+            FieldDesc {
+                name: "coproc",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "comov_opcode_1",
+                bitmask: 0x00e00000,
+            },
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "CRn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "CRm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "opcode_2",
+                bitmask: 0x000000e0,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fbf0fff,
+        0x010f0000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "R",
+                bitmask: 0x00400000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fb0f000,
+        0x0320f000,
+        &[
+            FieldDesc {
+                name: "field_mask",
+                bitmask: 0x004f0000,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fb0fff0,
+        0x0120f000,
+        &[
+            FieldDesc {
+                name: "field_mask",
+                bitmask: 0x004f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fe0f0f0,
+        0x00000090,
+        &[
+            FieldDesc {
+                name: "RdHi",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0def0000,
+        0x01e00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x01800000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0fff0000,
+        0x08bd0000,
+        &[
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fff0fff,
+        0x049d0004,
+        &[
+            FieldDesc {
+                name: "Rt_list",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fff0000,
+        0x092d0000,
+        &[
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fff0fff,
+        0x052d0004,
+        &[
+            FieldDesc {
+                name: "Rt_list",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0fef0060,
+        0x01a00060,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "shift_arg",
+                bitmask: 0x00000090,
+            },
+            FieldDesc {
+                name: "immed_5",
+                bitmask: 0x00000f80,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+        ],
+    ),
+    (
+        0x0fef0ff0,
+        0x01a00060,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00600000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00e00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00c00000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0fe000f0,
+        0x00e00090,
+        &[
+            FieldDesc {
+                name: "RdLo",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "RdHi",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0fe000f0,
+        0x00c00090,
+        &[
+            FieldDesc {
+                name: "RdLo",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "RdHi",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0e100000,
+        0x0c000000,
+        &[
+            FieldDesc {
+                name: "coproc",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "CRd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "L",
+                bitmask: 0x00400000,
+            },
+            FieldDesc {
+                name: "addr_coproc",
+                bitmask: 0x01a00000,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "coproc_offset",
+                bitmask: 0x008000ff,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "coproc_post_offset",
+                bitmask: 0x008000ff,
+            },
+            FieldDesc {
+                name: "option",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0e700000,
+        0x08000000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0e700000,
+        0x08200000,
+        &[
+            FieldDesc {
+                name: "Rn_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0e700000,
+        0x08400000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "registers_c",
+                bitmask: 0x0000ffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldm_stm",
+                bitmask: 0x01800000,
+            },
+        ],
+    ),
+    (
+        0x0c500000,
+        0x04000000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldr_str",
+                bitmask: 0x03200ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0c500000,
+        0x04400000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldr_str",
+                bitmask: 0x03200ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0d700000,
+        0x04600000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldrt_strt",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+        ],
+    ),
+    (
+        0x0e1000f0,
+        0x000000b0,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_misc_ldr_str",
+                bitmask: 0x01600f00,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "Rn_deref_wb",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_8",
+                bitmask: 0x00800f0f,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+        ],
+    ),
+    (
+        0x0d700000,
+        0x04200000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_ldrt_strt",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "post_offset_12",
+                bitmask: 0x00800fff,
+            },
+            FieldDesc {
+                name: "reg_post_offset",
+                bitmask: 0x0080000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+        ],
+    ),
+    (
+        0x0de00000,
+        0x00400000,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0f000000,
+        0x0f000000,
+        &[
+            FieldDesc {
+                name: "immed_24",
+                bitmask: 0x00ffffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0f000000,
+        0x0f000000,
+        &[
+            FieldDesc {
+                name: "immed_24",
+                bitmask: 0x00ffffff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0ff00ff0,
+        0x01000090,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0ff00ff0,
+        0x01400090,
+        &[
+            FieldDesc {
+                name: "Rd",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rn_deref",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+        ],
+    ),
+    (
+        0x0df0f000,
+        0x01300000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0df0f000,
+        0x01100000,
+        &[
+            FieldDesc {
+                name: "Rn",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "addr_data",
+                bitmask: 0x02000ff0,
+            },
+            FieldDesc {
+                name: "rotated_immed_8",
+                bitmask: 0x00000fff,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "shift_imm",
+                bitmask: 0x00000fe0,
+            },
+            FieldDesc {
+                name: "shift_reg",
+                bitmask: 0x00000f60,
+            },
+        ],
+    ),
+    (
+        0x0fe000f0,
+        0x00a00090,
+        &[
+            FieldDesc {
+                name: "RdLo",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "RdHi",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+    (
+        0x0fe000f0,
+        0x00800090,
+        &[
+            FieldDesc {
+                name: "RdLo",
+                bitmask: 0x0000f000,
+            },
+            FieldDesc {
+                name: "RdHi",
+                bitmask: 0x000f0000,
+            },
+            FieldDesc {
+                name: "Rm",
+                bitmask: 0x0000000f,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000f00,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0xf0000000,
+            },
+            FieldDesc {
+                name: "S",
+                bitmask: 0x00100000,
+            },
+        ],
+    ),
+];
+/// Every opcode enabled by the cargo features, in order of discriminant.
+static OPCODES: &[Opcode] = &[
+    Opcode::Adc,
+    Opcode::Add,
+    Opcode::And,
+    Opcode::Asr,
+    Opcode::B,
+    Opcode::Bl,
+    Opcode::Bic,
+    Opcode::Bx,
+    Opcode::Cdp,
+    Opcode::Cmn,
+    Opcode::Cmp,
+    Opcode::Eor,
+    Opcode::Ldc,
+    Opcode::LdmW,
+    Opcode::Ldm,
+    Opcode::LdmP,
+    Opcode::LdmPcW,
+    Opcode::LdmPc,
+    Opcode::Ldr,
+    Opcode::LdrB,
+    Opcode::LdrBt,
+    Opcode::LdrH,
+    Opcode::LdrSb,
+    Opcode::LdrSh,
+    Opcode::LdrT,
+    Opcode::Lsl,
+    Opcode::Lsr,
+    Opcode::Mcr,
+    Opcode::Mla,
+    Opcode::Mov,
+    Opcode::MovImm,
+    Opcode::MovReg,
+    Opcode::Mrc,
+    Opcode::Mrs,
+    Opcode::MsrI,
+    Opcode::Msr,
+    Opcode::Mul,
+    Opcode::Mvn,
+    Opcode::Orr,
+    Opcode::PopM,
+    Opcode::PopR,
+    Opcode::PushM,
+    Opcode::PushR,
+    Opcode::Ror,
+    Opcode::Rrx,
+    Opcode::Rsb,
+    Opcode::Rsc,
+    Opcode::Sbc,
+    Opcode::Smlal,
+    Opcode::Smull,
+    Opcode::Stc,
+    Opcode::Stm,
+    Opcode::StmW,
+    Opcode::StmP,
+    Opcode::Str,
+    Opcode::StrB,
+    Opcode::StrBt,
+    Opcode::StrH,
+    Opcode::StrT,
+    Opcode::Sub,
+    Opcode::Svc,
+    Opcode::Swi,
+    Opcode::Swp,
+    Opcode::Swpb,
+    Opcode::Teq,
+    Opcode::Tst,
+    Opcode::Umlal,
+    Opcode::Umull,
+];
+/// Maps the bits 0x0ff000f0 of an instruction to its opcode, unless the residual group checks another
+/// opcode first. See [`Opcode::find_table`].
+static FIND_TABLE: [(Opcode, u8); 4096] = [
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 1),
+    (Opcode::And, 0),
+    (Opcode::StrH, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 1),
+    (Opcode::And, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::And, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::And, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Mla, 0),
+    (Opcode::Eor, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Mla, 0),
+    (Opcode::Eor, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Eor, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Eor, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Sub, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Sub, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Umull, 0),
+    (Opcode::Add, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Umull, 0),
+    (Opcode::Add, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Add, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Add, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Umlal, 0),
+    (Opcode::Adc, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Umlal, 0),
+    (Opcode::Adc, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Adc, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Adc, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Smull, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Smull, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Smlal, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Smlal, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Illegal, 2),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 3),
+    (Opcode::Illegal, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::LdrH, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::LdrSb, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::LdrSh, 4),
+    (Opcode::Illegal, 5),
+    (Opcode::Illegal, 6),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::LdrH, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::LdrSb, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::LdrSh, 7),
+    (Opcode::Illegal, 2),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::LdrH, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::LdrSb, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::LdrSh, 9),
+    (Opcode::Illegal, 5),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::LdrH, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::LdrSb, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::LdrSh, 10),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Orr, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Orr, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Illegal, 11),
+    (Opcode::Illegal, 12),
+    (Opcode::Illegal, 13),
+    (Opcode::Illegal, 13),
+    (Opcode::Illegal, 14),
+    (Opcode::Illegal, 14),
+    (Opcode::Illegal, 15),
+    (Opcode::Illegal, 16),
+    (Opcode::Illegal, 12),
+    (Opcode::Illegal, 12),
+    (Opcode::Illegal, 13),
+    (Opcode::StrH, 13),
+    (Opcode::Illegal, 14),
+    (Opcode::Illegal, 14),
+    (Opcode::Illegal, 16),
+    (Opcode::Illegal, 16),
+    (Opcode::Illegal, 11),
+    (Opcode::Illegal, 12),
+    (Opcode::Illegal, 13),
+    (Opcode::Illegal, 13),
+    (Opcode::Illegal, 14),
+    (Opcode::Illegal, 14),
+    (Opcode::Illegal, 15),
+    (Opcode::Illegal, 16),
+    (Opcode::Illegal, 12),
+    (Opcode::Illegal, 12),
+    (Opcode::Illegal, 13),
+    (Opcode::LdrH, 13),
+    (Opcode::Illegal, 14),
+    (Opcode::LdrSb, 14),
+    (Opcode::Illegal, 16),
+    (Opcode::LdrSh, 16),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::StrH, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::LdrH, 0),
+    (Opcode::Bic, 0),
+    (Opcode::LdrSb, 0),
+    (Opcode::Bic, 0),
+    (Opcode::LdrSh, 0),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::StrH, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::LdrH, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::LdrSb, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::LdrSh, 17),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Sub, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Rsb, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Add, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Rsc, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 4),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 18),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Illegal, 19),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Illegal, 17),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 20),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Str, 21),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::StrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::LdrT, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::StrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::LdrBt, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Str, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::Ldr, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::StrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::LdrB, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::LdmW, 24),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::StmW, 25),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::Ldm, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::StmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::LdmW, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::StmP, 0),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 22),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::Illegal, 23),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Stc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Ldc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mcr, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Cdp, 0),
+    (Opcode::Mrc, 0),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+    (Opcode::Illegal, 26),
+];
+/// Length of the longest mnemonic, including `<illegal>`.
+pub const MAX_MNEMONIC_LEN: usize = 9;
+/// Upper bound of the length of a rendered instruction. This holds for any [`crate::DisplayOptions`] without a
+/// mnemonic hook, as no register name is longer than the default ones.
+pub const MAX_RENDERED_LEN: usize = 82;
+/// Every mnemonic without a condition suffix, sorted.
+pub(crate) static UNCONDITIONAL_MNEMONICS: [&str; 90] = [
+    "adc",
+    "adcs",
+    "add",
+    "adds",
+    "and",
+    "ands",
+    "asr",
+    "asrs",
+    "b",
+    "bic",
+    "bics",
+    "bl",
+    "bx",
+    "cdp",
+    "cmn",
+    "cmp",
+    "eor",
+    "eors",
+    "ldc",
+    "ldcl",
+    "ldm",
+    "ldmda",
+    "ldmdb",
+    "ldmia",
+    "ldmib",
+    "ldr",
+    "ldrb",
+    "ldrbt",
+    "ldrh",
+    "ldrsb",
+    "ldrsh",
+    "ldrt",
+    "lsl",
+    "lsls",
+    "lsr",
+    "lsrs",
+    "mcr",
+    "mla",
+    "mlas",
+    "mov",
+    "movs",
+    "mrc",
+    "mrs",
+    "msr",
+    "mul",
+    "muls",
+    "mvn",
+    "mvns",
+    "orr",
+    "orrs",
+    "pop",
+    "push",
+    "ror",
+    "rors",
+    "rrx",
+    "rrxs",
+    "rsb",
+    "rsbs",
+    "rsc",
+    "rscs",
+    "sbc",
+    "sbcs",
+    "smlal",
+    "smlals",
+    "smull",
+    "smulls",
+    "stc",
+    "stcl",
+    "stm",
+    "stmda",
+    "stmdb",
+    "stmia",
+    "stmib",
+    "str",
+    "strb",
+    "strbt",
+    "strh",
+    "strt",
+    "sub",
+    "subs",
+    "svc",
+    "swi",
+    "swp",
+    "swpb",
+    "teq",
+    "tst",
+    "umlal",
+    "umlals",
+    "umull",
+    "umulls",
+];
+/// Every mnemonic in both syntaxes, sorted. Used to parse and deserialize [`ParsedIns::mnemonic`].
+pub(crate) static MNEMONICS: [&str; 1855] = [
+    "<illegal>",
+    "adc",
+    "adceq",
+    "adceqs",
+    "adcge",
+    "adcges",
+    "adcgt",
+    "adcgts",
+    "adchi",
+    "adchis",
+    "adchs",
+    "adchss",
+    "adcle",
+    "adcles",
+    "adclo",
+    "adclos",
+    "adcls",
+    "adclss",
+    "adclt",
+    "adclts",
+    "adcmi",
+    "adcmis",
+    "adcne",
+    "adcnes",
+    "adcpl",
+    "adcpls",
+    "adcs",
+    "adcseq",
+    "adcsge",
+    "adcsgt",
+    "adcshi",
+    "adcshs",
+    "adcsle",
+    "adcslo",
+    "adcsls",
+    "adcslt",
+    "adcsmi",
+    "adcsne",
+    "adcspl",
+    "adcsvc",
+    "adcsvs",
+    "adcvc",
+    "adcvcs",
+    "adcvs",
+    "adcvss",
+    "add",
+    "addeq",
+    "addeqs",
+    "addge",
+    "addges",
+    "addgt",
+    "addgts",
+    "addhi",
+    "addhis",
+    "addhs",
+    "addhss",
+    "addle",
+    "addles",
+    "addlo",
+    "addlos",
+    "addls",
+    "addlss",
+    "addlt",
+    "addlts",
+    "addmi",
+    "addmis",
+    "addne",
+    "addnes",
+    "addpl",
+    "addpls",
+    "adds",
+    "addseq",
+    "addsge",
+    "addsgt",
+    "addshi",
+    "addshs",
+    "addsle",
+    "addslo",
+    "addsls",
+    "addslt",
+    "addsmi",
+    "addsne",
+    "addspl",
+    "addsvc",
+    "addsvs",
+    "addvc",
+    "addvcs",
+    "addvs",
+    "addvss",
+    "and",
+    "andeq",
+    "andeqs",
+    "andge",
+    "andges",
+    "andgt",
+    "andgts",
+    "andhi",
+    "andhis",
+    "andhs",
+    "andhss",
+    "andle",
+    "andles",
+    "andlo",
+    "andlos",
+    "andls",
+    "andlss",
+    "andlt",
+    "andlts",
+    "andmi",
+    "andmis",
+    "andne",
+    "andnes",
+    "andpl",
+    "andpls",
+    "ands",
+    "andseq",
+    "andsge",
+    "andsgt",
+    "andshi",
+    "andshs",
+    "andsle",
+    "andslo",
+    "andsls",
+    "andslt",
+    "andsmi",
+    "andsne",
+    "andspl",
+    "andsvc",
+    "andsvs",
+    "andvc",
+    "andvcs",
+    "andvs",
+    "andvss",
+    "asr",
+    "asreq",
+    "asrge",
+    "asrgt",
+    "asrhi",
+    "asrhs",
+    "asrle",
+    "asrlo",
+    "asrls",
+    "asrlt",
+    "asrmi",
+    "asrne",
+    "asrpl",
+    "asrs",
+    "asrseq",
+    "asrsge",
+    "asrsgt",
+    "asrshi",
+    "asrshs",
+    "asrsle",
+    "asrslo",
+    "asrsls",
+    "asrslt",
+    "asrsmi",
+    "asrsne",
+    "asrspl",
+    "asrsvc",
+    "asrsvs",
+    "asrvc",
+    "asrvs",
+    "b",
+    "beq",
+    "bge",
+    "bgt",
+    "bhi",
+    "bhs",
+    "bic",
+    "biceq",
+    "biceqs",
+    "bicge",
+    "bicges",
+    "bicgt",
+    "bicgts",
+    "bichi",
+    "bichis",
+    "bichs",
+    "bichss",
+    "bicle",
+    "bicles",
+    "biclo",
+    "biclos",
+    "bicls",
+    "biclss",
+    "biclt",
+    "biclts",
+    "bicmi",
+    "bicmis",
+    "bicne",
+    "bicnes",
+    "bicpl",
+    "bicpls",
+    "bics",
+    "bicseq",
+    "bicsge",
+    "bicsgt",
+    "bicshi",
+    "bicshs",
+    "bicsle",
+    "bicslo",
+    "bicsls",
+    "bicslt",
+    "bicsmi",
+    "bicsne",
+    "bicspl",
+    "bicsvc",
+    "bicsvs",
+    "bicvc",
+    "bicvcs",
+    "bicvs",
+    "bicvss",
+    "bl",
+    "ble",
+    "bleq",
+    "blge",
+    "blgt",
+    "blhi",
+    "blhs",
+    "blle",
+    "bllo",
+    "blls",
+    "bllt",
+    "blmi",
+    "blne",
+    "blo",
+    "blpl",
+    "bls",
+    "blt",
+    "blvc",
+    "blvs",
+    "bmi",
+    "bne",
+    "bpl",
+    "bvc",
+    "bvs",
+    "bx",
+    "bxeq",
+    "bxge",
+    "bxgt",
+    "bxhi",
+    "bxhs",
+    "bxle",
+    "bxlo",
+    "bxls",
+    "bxlt",
+    "bxmi",
+    "bxne",
+    "bxpl",
+    "bxvc",
+    "bxvs",
+    "cdp",
+    "cdpeq",
+    "cdpge",
+    "cdpgt",
+    "cdphi",
+    "cdphs",
+    "cdple",
+    "cdplo",
+    "cdpls",
+    "cdplt",
+    "cdpmi",
+    "cdpne",
+    "cdppl",
+    "cdpvc",
+    "cdpvs",
+    "cmn",
+    "cmneq",
+    "cmnge",
+    "cmngt",
+    "cmnhi",
+    "cmnhs",
+    "cmnle",
+    "cmnlo",
+    "cmnls",
+    "cmnlt",
+    "cmnmi",
+    "cmnne",
+    "cmnpl",
+    "cmnvc",
+    "cmnvs",
+    "cmp",
+    "cmpeq",
+    "cmpge",
+    "cmpgt",
+    "cmphi",
+    "cmphs",
+    "cmple",
+    "cmplo",
+    "cmpls",
+    "cmplt",
+    "cmpmi",
+    "cmpne",
+    "cmppl",
+    "cmpvc",
+    "cmpvs",
+    "eor",
+    "eoreq",
+    "eoreqs",
+    "eorge",
+    "eorges",
+    "eorgt",
+    "eorgts",
+    "eorhi",
+    "eorhis",
+    "eorhs",
+    "eorhss",
+    "eorle",
+    "eorles",
+    "eorlo",
+    "eorlos",
+    "eorls",
+    "eorlss",
+    "eorlt",
+    "eorlts",
+    "eormi",
+    "eormis",
+    "eorne",
+    "eornes",
+    "eorpl",
+    "eorpls",
+    "eors",
+    "eorseq",
+    "eorsge",
+    "eorsgt",
+    "eorshi",
+    "eorshs",
+    "eorsle",
+    "eorslo",
+    "eorsls",
+    "eorslt",
+    "eorsmi",
+    "eorsne",
+    "eorspl",
+    "eorsvc",
+    "eorsvs",
+    "eorvc",
+    "eorvcs",
+    "eorvs",
+    "eorvss",
+    "ldc",
+    "ldceq",
+    "ldceql",
+    "ldcge",
+    "ldcgel",
+    "ldcgt",
+    "ldcgtl",
+    "ldchi",
+    "ldchil",
+    "ldchs",
+    "ldchsl",
+    "ldcl",
+    "ldcle",
+    "ldclel",
+    "ldcleq",
+    "ldclge",
+    "ldclgt",
+    "ldclhi",
+    "ldclhs",
+    "ldclle",
+    "ldcllo",
+    "ldclls",
+    "ldcllt",
+    "ldclmi",
+    "ldclne",
+    "ldclo",
+    "ldclol",
+    "ldclpl",
+    "ldcls",
+    "ldclsl",
+    "ldclt",
+    "ldcltl",
+    "ldclvc",
+    "ldclvs",
+    "ldcmi",
+    "ldcmil",
+    "ldcne",
+    "ldcnel",
+    "ldcpl",
+    "ldcpll",
+    "ldcvc",
+    "ldcvcl",
+    "ldcvs",
+    "ldcvsl",
+    "ldm",
+    "ldmda",
+    "ldmdaeq",
+    "ldmdage",
+    "ldmdagt",
+    "ldmdahi",
+    "ldmdahs",
+    "ldmdale",
+    "ldmdalo",
+    "ldmdals",
+    "ldmdalt",
+    "ldmdami",
+    "ldmdane",
+    "ldmdapl",
+    "ldmdavc",
+    "ldmdavs",
+    "ldmdb",
+    "ldmdbeq",
+    "ldmdbge",
+    "ldmdbgt",
+    "ldmdbhi",
+    "ldmdbhs",
+    "ldmdble",
+    "ldmdblo",
+    "ldmdbls",
+    "ldmdblt",
+    "ldmdbmi",
+    "ldmdbne",
+    "ldmdbpl",
+    "ldmdbvc",
+    "ldmdbvs",
+    "ldmeq",
+    "ldmeqda",
+    "ldmeqdb",
+    "ldmeqia",
+    "ldmeqib",
+    "ldmge",
+    "ldmgeda",
+    "ldmgedb",
+    "ldmgeia",
+    "ldmgeib",
+    "ldmgt",
+    "ldmgtda",
+    "ldmgtdb",
+    "ldmgtia",
+    "ldmgtib",
+    "ldmhi",
+    "ldmhida",
+    "ldmhidb",
+    "ldmhiia",
+    "ldmhiib",
+    "ldmhs",
+    "ldmhsda",
+    "ldmhsdb",
+    "ldmhsia",
+    "ldmhsib",
+    "ldmia",
+    "ldmib",
+    "ldmibeq",
+    "ldmibge",
+    "ldmibgt",
+    "ldmibhi",
+    "ldmibhs",
+    "ldmible",
+    "ldmiblo",
+    "ldmibls",
+    "ldmiblt",
+    "ldmibmi",
+    "ldmibne",
+    "ldmibpl",
+    "ldmibvc",
+    "ldmibvs",
+    "ldmle",
+    "ldmleda",
+    "ldmledb",
+    "ldmleia",
+    "ldmleib",
+    "ldmlo",
+    "ldmloda",
+    "ldmlodb",
+    "ldmloia",
+    "ldmloib",
+    "ldmls",
+    "ldmlsda",
+    "ldmlsdb",
+    "ldmlsia",
+    "ldmlsib",
+    "ldmlt",
+    "ldmltda",
+    "ldmltdb",
+    "ldmltia",
+    "ldmltib",
+    "ldmmi",
+    "ldmmida",
+    "ldmmidb",
+    "ldmmiia",
+    "ldmmiib",
+    "ldmne",
+    "ldmneda",
+    "ldmnedb",
+    "ldmneia",
+    "ldmneib",
+    "ldmpl",
+    "ldmplda",
+    "ldmpldb",
+    "ldmplia",
+    "ldmplib",
+    "ldmvc",
+    "ldmvcda",
+    "ldmvcdb",
+    "ldmvcia",
+    "ldmvcib",
+    "ldmvs",
+    "ldmvsda",
+    "ldmvsdb",
+    "ldmvsia",
+    "ldmvsib",
+    "ldr",
+    "ldrb",
+    "ldrbeq",
+    "ldrbge",
+    "ldrbgt",
+    "ldrbhi",
+    "ldrbhs",
+    "ldrble",
+    "ldrblo",
+    "ldrbls",
+    "ldrblt",
+    "ldrbmi",
+    "ldrbne",
+    "ldrbpl",
+    "ldrbt",
+    "ldrbteq",
+    "ldrbtge",
+    "ldrbtgt",
+    "ldrbthi",
+    "ldrbths",
+    "ldrbtle",
+    "ldrbtlo",
+    "ldrbtls",
+    "ldrbtlt",
+    "ldrbtmi",
+    "ldrbtne",
+    "ldrbtpl",
+    "ldrbtvc",
+    "ldrbtvs",
+    "ldrbvc",
+    "ldrbvs",
+    "ldreq",
+    "ldreqb",
+    "ldreqbt",
+    "ldreqh",
+    "ldreqsb",
+    "ldreqsh",
+    "ldreqt",
+    "ldrge",
+    "ldrgeb",
+    "ldrgebt",
+    "ldrgeh",
+    "ldrgesb",
+    "ldrgesh",
+    "ldrget",
+    "ldrgt",
+    "ldrgtb",
+    "ldrgtbt",
+    "ldrgth",
+    "ldrgtsb",
+    "ldrgtsh",
+    "ldrgtt",
+    "ldrh",
+    "ldrheq",
+    "ldrhge",
+    "ldrhgt",
+    "ldrhhi",
+    "ldrhhs",
+    "ldrhi",
+    "ldrhib",
+    "ldrhibt",
+    "ldrhih",
+    "ldrhisb",
+    "ldrhish",
+    "ldrhit",
+    "ldrhle",
+    "ldrhlo",
+    "ldrhls",
+    "ldrhlt",
+    "ldrhmi",
+    "ldrhne",
+    "ldrhpl",
+    "ldrhs",
+    "ldrhsb",
+    "ldrhsbt",
+    "ldrhsh",
+    "ldrhssb",
+    "ldrhssh",
+    "ldrhst",
+    "ldrhvc",
+    "ldrhvs",
+    "ldrle",
+    "ldrleb",
+    "ldrlebt",
+    "ldrleh",
+    "ldrlesb",
+    "ldrlesh",
+    "ldrlet",
+    "ldrlo",
+    "ldrlob",
+    "ldrlobt",
+    "ldrloh",
+    "ldrlosb",
+    "ldrlosh",
+    "ldrlot",
+    "ldrls",
+    "ldrlsb",
+    "ldrlsbt",
+    "ldrlsh",
+    "ldrlssb",
+    "ldrlssh",
+    "ldrlst",
+    "ldrlt",
+    "ldrltb",
+    "ldrltbt",
+    "ldrlth",
+    "ldrltsb",
+    "ldrltsh",
+    "ldrltt",
+    "ldrmi",
+    "ldrmib",
+    "ldrmibt",
+    "ldrmih",
+    "ldrmisb",
+    "ldrmish",
+    "ldrmit",
+    "ldrne",
+    "ldrneb",
+    "ldrnebt",
+    "ldrneh",
+    "ldrnesb",
+    "ldrnesh",
+    "ldrnet",
+    "ldrpl",
+    "ldrplb",
+    "ldrplbt",
+    "ldrplh",
+    "ldrplsb",
+    "ldrplsh",
+    "ldrplt",
+    "ldrsb",
+    "ldrsbeq",
+    "ldrsbge",
+    "ldrsbgt",
+    "ldrsbhi",
+    "ldrsbhs",
+    "ldrsble",
+    "ldrsblo",
+    "ldrsbls",
+    "ldrsblt",
+    "ldrsbmi",
+    "ldrsbne",
+    "ldrsbpl",
+    "ldrsbvc",
+    "ldrsbvs",
+    "ldrsh",
+    "ldrsheq",
+    "ldrshge",
+    "ldrshgt",
+    "ldrshhi",
+    "ldrshhs",
+    "ldrshle",
+    "ldrshlo",
+    "ldrshls",
+    "ldrshlt",
+    "ldrshmi",
+    "ldrshne",
+    "ldrshpl",
+    "ldrshvc",
+    "ldrshvs",
+    "ldrt",
+    "ldrteq",
+    "ldrtge",
+    "ldrtgt",
+    "ldrthi",
+    "ldrths",
+    "ldrtle",
+    "ldrtlo",
+    "ldrtls",
+    "ldrtlt",
+    "ldrtmi",
+    "ldrtne",
+    "ldrtpl",
+    "ldrtvc",
+    "ldrtvs",
+    "ldrvc",
+    "ldrvcb",
+    "ldrvcbt",
+    "ldrvch",
+    "ldrvcsb",
+    "ldrvcsh",
+    "ldrvct",
+    "ldrvs",
+    "ldrvsb",
+    "ldrvsbt",
+    "ldrvsh",
+    "ldrvssb",
+    "ldrvssh",
+    "ldrvst",
+    "lsl",
+    "lsleq",
+    "lslge",
+    "lslgt",
+    "lslhi",
+    "lslhs",
+    "lslle",
+    "lsllo",
+    "lslls",
+    "lsllt",
+    "lslmi",
+    "lslne",
+    "lslpl",
+    "lsls",
+    "lslseq",
+    "lslsge",
+    "lslsgt",
+    "lslshi",
+    "lslshs",
+    "lslsle",
+    "lslslo",
+    "lslsls",
+    "lslslt",
+    "lslsmi",
+    "lslsne",
+    "lslspl",
+    "lslsvc",
+    "lslsvs",
+    "lslvc",
+    "lslvs",
+    "lsr",
+    "lsreq",
+    "lsrge",
+    "lsrgt",
+    "lsrhi",
+    "lsrhs",
+    "lsrle",
+    "lsrlo",
+    "lsrls",
+    "lsrlt",
+    "lsrmi",
+    "lsrne",
+    "lsrpl",
+    "lsrs",
+    "lsrseq",
+    "lsrsge",
+    "lsrsgt",
+    "lsrshi",
+    "lsrshs",
+    "lsrsle",
+    "lsrslo",
+    "lsrsls",
+    "lsrslt",
+    "lsrsmi",
+    "lsrsne",
+    "lsrspl",
+    "lsrsvc",
+    "lsrsvs",
+    "lsrvc",
+    "lsrvs",
+    "mcr",
+    "mcreq",
+    "mcrge",
+    "mcrgt",
+    "mcrhi",
+    "mcrhs",
+    "mcrle",
+    "mcrlo",
+    "mcrls",
+    "mcrlt",
+    "mcrmi",
+    "mcrne",
+    "mcrpl",
+    "mcrvc",
+    "mcrvs",
+    "mla",
+    "mlaeq",
+    "mlaeqs",
+    "mlage",
+    "mlages",
+    "mlagt",
+    "mlagts",
+    "mlahi",
+    "mlahis",
+    "mlahs",
+    "mlahss",
+    "mlale",
+    "mlales",
+    "mlalo",
+    "mlalos",
+    "mlals",
+    "mlalss",
+    "mlalt",
+    "mlalts",
+    "mlami",
+    "mlamis",
+    "mlane",
+    "mlanes",
+    "mlapl",
+    "mlapls",
+    "mlas",
+    "mlaseq",
+    "mlasge",
+    "mlasgt",
+    "mlashi",
+    "mlashs",
+    "mlasle",
+    "mlaslo",
+    "mlasls",
+    "mlaslt",
+    "mlasmi",
+    "mlasne",
+    "mlaspl",
+    "mlasvc",
+    "mlasvs",
+    "mlavc",
+    "mlavcs",
+    "mlavs",
+    "mlavss",
+    "mov",
+    "moveq",
+    "moveqs",
+    "movge",
+    "movges",
+    "movgt",
+    "movgts",
+    "movhi",
+    "movhis",
+    "movhs",
+    "movhss",
+    "movle",
+    "movles",
+    "movlo",
+    "movlos",
+    "movls",
+    "movlss",
+    "movlt",
+    "movlts",
+    "movmi",
+    "movmis",
+    "movne",
+    "movnes",
+    "movpl",
+    "movpls",
+    "movs",
+    "movseq",
+    "movsge",
+    "movsgt",
+    "movshi",
+    "movshs",
+    "movsle",
+    "movslo",
+    "movsls",
+    "movslt",
+    "movsmi",
+    "movsne",
+    "movspl",
+    "movsvc",
+    "movsvs",
+    "movvc",
+    "movvcs",
+    "movvs",
+    "movvss",
+    "mrc",
+    "mrceq",
+    "mrcge",
+    "mrcgt",
+    "mrchi",
+    "mrchs",
+    "mrcle",
+    "mrclo",
+    "mrcls",
+    "mrclt",
+    "mrcmi",
+    "mrcne",
+    "mrcpl",
+    "mrcvc",
+    "mrcvs",
+    "mrs",
+    "mrseq",
+    "mrsge",
+    "mrsgt",
+    "mrshi",
+    "mrshs",
+    "mrsle",
+    "mrslo",
+    "mrsls",
+    "mrslt",
+    "mrsmi",
+    "mrsne",
+    "mrspl",
+    "mrsvc",
+    "mrsvs",
+    "msr",
+    "msreq",
+    "msrge",
+    "msrgt",
+    "msrhi",
+    "msrhs",
+    "msrle",
+    "msrlo",
+    "msrls",
+    "msrlt",
+    "msrmi",
+    "msrne",
+    "msrpl",
+    "msrvc",
+    "msrvs",
+    "mul",
+    "muleq",
+    "muleqs",
+    "mulge",
+    "mulges",
+    "mulgt",
+    "mulgts",
+    "mulhi",
+    "mulhis",
+    "mulhs",
+    "mulhss",
+    "mulle",
+    "mulles",
+    "mullo",
+    "mullos",
+    "mulls",
+    "mullss",
+    "mullt",
+    "mullts",
+    "mulmi",
+    "mulmis",
+    "mulne",
+    "mulnes",
+    "mulpl",
+    "mulpls",
+    "muls",
+    "mulseq",
+    "mulsge",
+    "mulsgt",
+    "mulshi",
+    "mulshs",
+    "mulsle",
+    "mulslo",
+    "mulsls",
+    "mulslt",
+    "mulsmi",
+    "mulsne",
+    "mulspl",
+    "mulsvc",
+    "mulsvs",
+    "mulvc",
+    "mulvcs",
+    "mulvs",
+    "mulvss",
+    "mvn",
+    "mvneq",
+    "mvneqs",
+    "mvnge",
+    "mvnges",
+    "mvngt",
+    "mvngts",
+    "mvnhi",
+    "mvnhis",
+    "mvnhs",
+    "mvnhss",
+    "mvnle",
+    "mvnles",
+    "mvnlo",
+    "mvnlos",
+    "mvnls",
+    "mvnlss",
+    "mvnlt",
+    "mvnlts",
+    "mvnmi",
+    "mvnmis",
+    "mvnne",
+    "mvnnes",
+    "mvnpl",
+    "mvnpls",
+    "mvns",
+    "mvnseq",
+    "mvnsge",
+    "mvnsgt",
+    "mvnshi",
+    "mvnshs",
+    "mvnsle",
+    "mvnslo",
+    "mvnsls",
+    "mvnslt",
+    "mvnsmi",
+    "mvnsne",
+    "mvnspl",
+    "mvnsvc",
+    "mvnsvs",
+    "mvnvc",
+    "mvnvcs",
+    "mvnvs",
+    "mvnvss",
+    "orr",
+    "orreq",
+    "orreqs",
+    "orrge",
+    "orrges",
+    "orrgt",
+    "orrgts",
+    "orrhi",
+    "orrhis",
+    "orrhs",
+    "orrhss",
+    "orrle",
+    "orrles",
+    "orrlo",
+    "orrlos",
+    "orrls",
+    "orrlss",
+    "orrlt",
+    "orrlts",
+    "orrmi",
+    "orrmis",
+    "orrne",
+    "orrnes",
+    "orrpl",
+    "orrpls",
+    "orrs",
+    "orrseq",
+    "orrsge",
+    "orrsgt",
+    "orrshi",
+    "orrshs",
+    "orrsle",
+    "orrslo",
+    "orrsls",
+    "orrslt",
+    "orrsmi",
+    "orrsne",
+    "orrspl",
+    "orrsvc",
+    "orrsvs",
+    "orrvc",
+    "orrvcs",
+    "orrvs",
+    "orrvss",
+    "pop",
+    "popeq",
+    "popge",
+    "popgt",
+    "pophi",
+    "pophs",
+    "pople",
+    "poplo",
+    "popls",
+    "poplt",
+    "popmi",
+    "popne",
+    "poppl",
+    "popvc",
+    "popvs",
+    "push",
+    "pusheq",
+    "pushge",
+    "pushgt",
+    "pushhi",
+    "pushhs",
+    "pushle",
+    "pushlo",
+    "pushls",
+    "pushlt",
+    "pushmi",
+    "pushne",
+    "pushpl",
+    "pushvc",
+    "pushvs",
+    "ror",
+    "roreq",
+    "rorge",
+    "rorgt",
+    "rorhi",
+    "rorhs",
+    "rorle",
+    "rorlo",
+    "rorls",
+    "rorlt",
+    "rormi",
+    "rorne",
+    "rorpl",
+    "rors",
+    "rorseq",
+    "rorsge",
+    "rorsgt",
+    "rorshi",
+    "rorshs",
+    "rorsle",
+    "rorslo",
+    "rorsls",
+    "rorslt",
+    "rorsmi",
+    "rorsne",
+    "rorspl",
+    "rorsvc",
+    "rorsvs",
+    "rorvc",
+    "rorvs",
+    "rrx",
+    "rrxeq",
+    "rrxge",
+    "rrxgt",
+    "rrxhi",
+    "rrxhs",
+    "rrxle",
+    "rrxlo",
+    "rrxls",
+    "rrxlt",
+    "rrxmi",
+    "rrxne",
+    "rrxpl",
+    "rrxs",
+    "rrxseq",
+    "rrxsge",
+    "rrxsgt",
+    "rrxshi",
+    "rrxshs",
+    "rrxsle",
+    "rrxslo",
+    "rrxsls",
+    "rrxslt",
+    "rrxsmi",
+    "rrxsne",
+    "rrxspl",
+    "rrxsvc",
+    "rrxsvs",
+    "rrxvc",
+    "rrxvs",
+    "rsb",
+    "rsbeq",
+    "rsbeqs",
+    "rsbge",
+    "rsbges",
+    "rsbgt",
+    "rsbgts",
+    "rsbhi",
+    "rsbhis",
+    "rsbhs",
+    "rsbhss",
+    "rsble",
+    "rsbles",
+    "rsblo",
+    "rsblos",
+    "rsbls",
+    "rsblss",
+    "rsblt",
+    "rsblts",
+    "rsbmi",
+    "rsbmis",
+    "rsbne",
+    "rsbnes",
+    "rsbpl",
+    "rsbpls",
+    "rsbs",
+    "rsbseq",
+    "rsbsge",
+    "rsbsgt",
+    "rsbshi",
+    "rsbshs",
+    "rsbsle",
+    "rsbslo",
+    "rsbsls",
+    "rsbslt",
+    "rsbsmi",
+    "rsbsne",
+    "rsbspl",
+    "rsbsvc",
+    "rsbsvs",
+    "rsbvc",
+    "rsbvcs",
+    "rsbvs",
+    "rsbvss",
+    "rsc",
+    "rsceq",
+    "rsceqs",
+    "rscge",
+    "rscges",
+    "rscgt",
+    "rscgts",
+    "rschi",
+    "rschis",
+    "rschs",
+    "rschss",
+    "rscle",
+    "rscles",
+    "rsclo",
+    "rsclos",
+    "rscls",
+    "rsclss",
+    "rsclt",
+    "rsclts",
+    "rscmi",
+    "rscmis",
+    "rscne",
+    "rscnes",
+    "rscpl",
+    "rscpls",
+    "rscs",
+    "rscseq",
+    "rscsge",
+    "rscsgt",
+    "rscshi",
+    "rscshs",
+    "rscsle",
+    "rscslo",
+    "rscsls",
+    "rscslt",
+    "rscsmi",
+    "rscsne",
+    "rscspl",
+    "rscsvc",
+    "rscsvs",
+    "rscvc",
+    "rscvcs",
+    "rscvs",
+    "rscvss",
+    "sbc",
+    "sbceq",
+    "sbceqs",
+    "sbcge",
+    "sbcges",
+    "sbcgt",
+    "sbcgts",
+    "sbchi",
+    "sbchis",
+    "sbchs",
+    "sbchss",
+    "sbcle",
+    "sbcles",
+    "sbclo",
+    "sbclos",
+    "sbcls",
+    "sbclss",
+    "sbclt",
+    "sbclts",
+    "sbcmi",
+    "sbcmis",
+    "sbcne",
+    "sbcnes",
+    "sbcpl",
+    "sbcpls",
+    "sbcs",
+    "sbcseq",
+    "sbcsge",
+    "sbcsgt",
+    "sbcshi",
+    "sbcshs",
+    "sbcsle",
+    "sbcslo",
+    "sbcsls",
+    "sbcslt",
+    "sbcsmi",
+    "sbcsne",
+    "sbcspl",
+    "sbcsvc",
+    "sbcsvs",
+    "sbcvc",
+    "sbcvcs",
+    "sbcvs",
+    "sbcvss",
+    "smlal",
+    "smlaleq",
+    "smlaleqs",
+    "smlalge",
+    "smlalges",
+    "smlalgt",
+    "smlalgts",
+    "smlalhi",
+    "smlalhis",
+    "smlalhs",
+    "smlalhss",
+    "smlalle",
+    "smlalles",
+    "smlallo",
+    "smlallos",
+    "smlalls",
+    "smlallss",
+    "smlallt",
+    "smlallts",
+    "smlalmi",
+    "smlalmis",
+    "smlalne",
+    "smlalnes",
+    "smlalpl",
+    "smlalpls",
+    "smlals",
+    "smlalseq",
+    "smlalsge",
+    "smlalsgt",
+    "smlalshi",
+    "smlalshs",
+    "smlalsle",
+    "smlalslo",
+    "smlalsls",
+    "smlalslt",
+    "smlalsmi",
+    "smlalsne",
+    "smlalspl",
+    "smlalsvc",
+    "smlalsvs",
+    "smlalvc",
+    "smlalvcs",
+    "smlalvs",
+    "smlalvss",
+    "smull",
+    "smulleq",
+    "smulleqs",
+    "smullge",
+    "smullges",
+    "smullgt",
+    "smullgts",
+    "smullhi",
+    "smullhis",
+    "smullhs",
+    "smullhss",
+    "smullle",
+    "smullles",
+    "smulllo",
+    "smulllos",
+    "smullls",
+    "smulllss",
+    "smulllt",
+    "smulllts",
+    "smullmi",
+    "smullmis",
+    "smullne",
+    "smullnes",
+    "smullpl",
+    "smullpls",
+    "smulls",
+    "smullseq",
+    "smullsge",
+    "smullsgt",
+    "smullshi",
+    "smullshs",
+    "smullsle",
+    "smullslo",
+    "smullsls",
+    "smullslt",
+    "smullsmi",
+    "smullsne",
+    "smullspl",
+    "smullsvc",
+    "smullsvs",
+    "smullvc",
+    "smullvcs",
+    "smullvs",
+    "smullvss",
+    "stc",
+    "stceq",
+    "stceql",
+    "stcge",
+    "stcgel",
+    "stcgt",
+    "stcgtl",
+    "stchi",
+    "stchil",
+    "stchs",
+    "stchsl",
+    "stcl",
+    "stcle",
+    "stclel",
+    "stcleq",
+    "stclge",
+    "stclgt",
+    "stclhi",
+    "stclhs",
+    "stclle",
+    "stcllo",
+    "stclls",
+    "stcllt",
+    "stclmi",
+    "stclne",
+    "stclo",
+    "stclol",
+    "stclpl",
+    "stcls",
+    "stclsl",
+    "stclt",
+    "stcltl",
+    "stclvc",
+    "stclvs",
+    "stcmi",
+    "stcmil",
+    "stcne",
+    "stcnel",
+    "stcpl",
+    "stcpll",
+    "stcvc",
+    "stcvcl",
+    "stcvs",
+    "stcvsl",
+    "stm",
+    "stmda",
+    "stmdaeq",
+    "stmdage",
+    "stmdagt",
+    "stmdahi",
+    "stmdahs",
+    "stmdale",
+    "stmdalo",
+    "stmdals",
+    "stmdalt",
+    "stmdami",
+    "stmdane",
+    "stmdapl",
+    "stmdavc",
+    "stmdavs",
+    "stmdb",
+    "stmdbeq",
+    "stmdbge",
+    "stmdbgt",
+    "stmdbhi",
+    "stmdbhs",
+    "stmdble",
+    "stmdblo",
+    "stmdbls",
+    "stmdblt",
+    "stmdbmi",
+    "stmdbne",
+    "stmdbpl",
+    "stmdbvc",
+    "stmdbvs",
+    "stmeq",
+    "stmeqda",
+    "stmeqdb",
+    "stmeqia",
+    "stmeqib",
+    "stmge",
+    "stmgeda",
+    "stmgedb",
+    "stmgeia",
+    "stmgeib",
+    "stmgt",
+    "stmgtda",
+    "stmgtdb",
+    "stmgtia",
+    "stmgtib",
+    "stmhi",
+    "stmhida",
+    "stmhidb",
+    "stmhiia",
+    "stmhiib",
+    "stmhs",
+    "stmhsda",
+    "stmhsdb",
+    "stmhsia",
+    "stmhsib",
+    "stmia",
+    "stmib",
+    "stmibeq",
+    "stmibge",
+    "stmibgt",
+    "stmibhi",
+    "stmibhs",
+    "stmible",
+    "stmiblo",
+    "stmibls",
+    "stmiblt",
+    "stmibmi",
+    "stmibne",
+    "stmibpl",
+    "stmibvc",
+    "stmibvs",
+    "stmle",
+    "stmleda",
+    "stmledb",
+    "stmleia",
+    "stmleib",
+    "stmlo",
+    "stmloda",
+    "stmlodb",
+    "stmloia",
+    "stmloib",
+    "stmls",
+    "stmlsda",
+    "stmlsdb",
+    "stmlsia",
+    "stmlsib",
+    "stmlt",
+    "stmltda",
+    "stmltdb",
+    "stmltia",
+    "stmltib",
+    "stmmi",
+    "stmmida",
+    "stmmidb",
+    "stmmiia",
+    "stmmiib",
+    "stmne",
+    "stmneda",
+    "stmnedb",
+    "stmneia",
+    "stmneib",
+    "stmpl",
+    "stmplda",
+    "stmpldb",
+    "stmplia",
+    "stmplib",
+    "stmvc",
+    "stmvcda",
+    "stmvcdb",
+    "stmvcia",
+    "stmvcib",
+    "stmvs",
+    "stmvsda",
+    "stmvsdb",
+    "stmvsia",
+    "stmvsib",
+    "str",
+    "strb",
+    "strbeq",
+    "strbge",
+    "strbgt",
+    "strbhi",
+    "strbhs",
+    "strble",
+    "strblo",
+    "strbls",
+    "strblt",
+    "strbmi",
+    "strbne",
+    "strbpl",
+    "strbt",
+    "strbteq",
+    "strbtge",
+    "strbtgt",
+    "strbthi",
+    "strbths",
+    "strbtle",
+    "strbtlo",
+    "strbtls",
+    "strbtlt",
+    "strbtmi",
+    "strbtne",
+    "strbtpl",
+    "strbtvc",
+    "strbtvs",
+    "strbvc",
+    "strbvs",
+    "streq",
+    "streqb",
+    "streqbt",
+    "streqh",
+    "streqt",
+    "strge",
+    "strgeb",
+    "strgebt",
+    "strgeh",
+    "strget",
+    "strgt",
+    "strgtb",
+    "strgtbt",
+    "strgth",
+    "strgtt",
+    "strh",
+    "strheq",
+    "strhge",
+    "strhgt",
+    "strhhi",
+    "strhhs",
+    "strhi",
+    "strhib",
+    "strhibt",
+    "strhih",
+    "strhit",
+    "strhle",
+    "strhlo",
+    "strhls",
+    "strhlt",
+    "strhmi",
+    "strhne",
+    "strhpl",
+    "strhs",
+    "strhsb",
+    "strhsbt",
+    "strhsh",
+    "strhst",
+    "strhvc",
+    "strhvs",
+    "strle",
+    "strleb",
+    "strlebt",
+    "strleh",
+    "strlet",
+    "strlo",
+    "strlob",
+    "strlobt",
+    "strloh",
+    "strlot",
+    "strls",
+    "strlsb",
+    "strlsbt",
+    "strlsh",
+    "strlst",
+    "strlt",
+    "strltb",
+    "strltbt",
+    "strlth",
+    "strltt",
+    "strmi",
+    "strmib",
+    "strmibt",
+    "strmih",
+    "strmit",
+    "strne",
+    "strneb",
+    "strnebt",
+    "strneh",
+    "strnet",
+    "strpl",
+    "strplb",
+    "strplbt",
+    "strplh",
+    "strplt",
+    "strt",
+    "strteq",
+    "strtge",
+    "strtgt",
+    "strthi",
+    "strths",
+    "strtle",
+    "strtlo",
+    "strtls",
+    "strtlt",
+    "strtmi",
+    "strtne",
+    "strtpl",
+    "strtvc",
+    "strtvs",
+    "strvc",
+    "strvcb",
+    "strvcbt",
+    "strvch",
+    "strvct",
+    "strvs",
+    "strvsb",
+    "strvsbt",
+    "strvsh",
+    "strvst",
+    "sub",
+    "subeq",
+    "subeqs",
+    "subge",
+    "subges",
+    "subgt",
+    "subgts",
+    "subhi",
+    "subhis",
+    "subhs",
+    "subhss",
+    "suble",
+    "subles",
+    "sublo",
+    "sublos",
+    "subls",
+    "sublss",
+    "sublt",
+    "sublts",
+    "submi",
+    "submis",
+    "subne",
+    "subnes",
+    "subpl",
+    "subpls",
+    "subs",
+    "subseq",
+    "subsge",
+    "subsgt",
+    "subshi",
+    "subshs",
+    "subsle",
+    "subslo",
+    "subsls",
+    "subslt",
+    "subsmi",
+    "subsne",
+    "subspl",
+    "subsvc",
+    "subsvs",
+    "subvc",
+    "subvcs",
+    "subvs",
+    "subvss",
+    "svc",
+    "svceq",
+    "svcge",
+    "svcgt",
+    "svchi",
+    "svchs",
+    "svcle",
+    "svclo",
+    "svcls",
+    "svclt",
+    "svcmi",
+    "svcne",
+    "svcpl",
+    "svcvc",
+    "svcvs",
+    "swi",
+    "swieq",
+    "swige",
+    "swigt",
+    "swihi",
+    "swihs",
+    "swile",
+    "swilo",
+    "swils",
+    "swilt",
+    "swimi",
+    "swine",
+    "swipl",
+    "swivc",
+    "swivs",
+    "swp",
+    "swpb",
+    "swpbeq",
+    "swpbge",
+    "swpbgt",
+    "swpbhi",
+    "swpbhs",
+    "swpble",
+    "swpblo",
+    "swpbls",
+    "swpblt",
+    "swpbmi",
+    "swpbne",
+    "swpbpl",
+    "swpbvc",
+    "swpbvs",
+    "swpeq",
+    "swpge",
+    "swpgt",
+    "swphi",
+    "swphs",
+    "swple",
+    "swplo",
+    "swpls",
+    "swplt",
+    "swpmi",
+    "swpne",
+    "swppl",
+    "swpvc",
+    "swpvs",
+    "teq",
+    "teqeq",
+    "teqge",
+    "teqgt",
+    "teqhi",
+    "teqhs",
+    "teqle",
+    "teqlo",
+    "teqls",
+    "teqlt",
+    "teqmi",
+    "teqne",
+    "teqpl",
+    "teqvc",
+    "teqvs",
+    "tst",
+    "tsteq",
+    "tstge",
+    "tstgt",
+    "tsthi",
+    "tsths",
+    "tstle",
+    "tstlo",
+    "tstls",
+    "tstlt",
+    "tstmi",
+    "tstne",
+    "tstpl",
+    "tstvc",
+    "tstvs",
+    "umlal",
+    "umlaleq",
+    "umlaleqs",
+    "umlalge",
+    "umlalges",
+    "umlalgt",
+    "umlalgts",
+    "umlalhi",
+    "umlalhis",
+    "umlalhs",
+    "umlalhss",
+    "umlalle",
+    "umlalles",
+    "umlallo",
+    "umlallos",
+    "umlalls",
+    "umlallss",
+    "umlallt",
+    "umlallts",
+    "umlalmi",
+    "umlalmis",
+    "umlalne",
+    "umlalnes",
+    "umlalpl",
+    "umlalpls",
+    "umlals",
+    "umlalseq",
+    "umlalsge",
+    "umlalsgt",
+    "umlalshi",
+    "umlalshs",
+    "umlalsle",
+    "umlalslo",
+    "umlalsls",
+    "umlalslt",
+    "umlalsmi",
+    "umlalsne",
+    "umlalspl",
+    "umlalsvc",
+    "umlalsvs",
+    "umlalvc",
+    "umlalvcs",
+    "umlalvs",
+    "umlalvss",
+    "umull",
+    "umulleq",
+    "umulleqs",
+    "umullge",
+    "umullges",
+    "umullgt",
+    "umullgts",
+    "umullhi",
+    "umullhis",
+    "umullhs",
+    "umullhss",
+    "umullle",
+    "umullles",
+    "umulllo",
+    "umulllos",
+    "umullls",
+    "umulllss",
+    "umulllt",
+    "umulllts",
+    "umullmi",
+    "umullmis",
+    "umullne",
+    "umullnes",
+    "umullpl",
+    "umullpls",
+    "umulls",
+    "umullseq",
+    "umullsge",
+    "umullsgt",
+    "umullshi",
+    "umullshs",
+    "umullsle",
+    "umullslo",
+    "umullsls",
+    "umullslt",
+    "umullsmi",
+    "umullsne",
+    "umullspl",
+    "umullsvc",
+    "umullsvs",
+    "umullvc",
+    "umullvcs",
+    "umullvs",
+    "umullvss",
+];
+/// One-line description of each opcode.
+static OPCODE_DESCRIPTIONS: [&str; 68] = [
+    "Add with Carry",
+    "Add",
+    "Bitwise AND",
+    "Arithmetic Right Shift",
+    "Branch",
+    "Branch and Link",
+    "Bit Clear",
+    "Branch and Exchange",
+    "Coprocessor Data Processing",
+    "Compare Negative",
+    "Compare",
+    "Bitwise Exclusive OR",
+    "Load Coprocessor",
+    "Load Multiple (writeback)",
+    "Load Multiple",
+    "Load Multiple (privileged)",
+    "Load Multiple (including PC, writeback)",
+    "Load Multiple (including PC)",
+    "Load Register",
+    "Load Register Byte",
+    "Load Register Byte with Translation",
+    "Load Register Halfword",
+    "Load Register Signed Byte",
+    "Load Register Signed Halfword",
+    "Load Register with Translation",
+    "Logical Shift Left",
+    "Logical Shift Right",
+    "Move to Coprocessor from ARM Register",
+    "Multiply Accumulate",
+    "Move",
+    "Move immediate",
+    "Move register",
+    "Move to ARM Register from Coprocessor",
+    "Move to ARM Register from Status Register",
+    "Move to Status Register from ARM Register",
+    "Move to Status Register from ARM Register",
+    "Multiply",
+    "Move Not",
+    "Logical OR",
+    "Pop multiple registers",
+    "Pop register",
+    "Push multiple registers",
+    "Push register",
+    "Rotate Right",
+    "Rotate Right with Extend",
+    "Reverse Subtract",
+    "Reverse Subtract with Carry",
+    "Subtract with Carry",
+    "Signed Multiply Accumulate Long",
+    "Signed Multiply Long",
+    "Store Coprocessor",
+    "Store Multiple",
+    "Store Multiple (writeback)",
+    "Store Multiple (privileged)",
+    "Store Register",
+    "Store Register Byte",
+    "Store Register Byte with Translation",
+    "Store Register Halfword",
+    "Store Register with Translation",
+    "Subtract",
+    "Supervisor Call",
+    "Software Interrupt",
+    "Swap",
+    "Swap Byte",
+    "Test Equivalence",
+    "Test",
+    "Unsigned Multiply Accumulate Long",
+    "Unsigned Multiply Long",
+];
+/// Every mnemonic of each opcode without a condition suffix, in both syntaxes.
+static OPCODE_SPELLINGS: &[(Opcode, &[&str])] = &[
+    (Opcode::Adc, &["adc", "adcs"]),
+    (Opcode::Add, &["add", "adds"]),
+    (Opcode::And, &["and", "ands"]),
+    (Opcode::Asr, &["asr", "asrs"]),
+    (Opcode::B, &["b"]),
+    (Opcode::Bl, &["bl"]),
+    (Opcode::Bic, &["bic", "bics"]),
+    (Opcode::Bx, &["bx"]),
+    (Opcode::Cdp, &["cdp"]),
+    (Opcode::Cmn, &["cmn"]),
+    (Opcode::Cmp, &["cmp"]),
+    (Opcode::Eor, &["eor", "eors"]),
+    (Opcode::Ldc, &["ldc", "ldcl"]),
+    (Opcode::LdmW, &["ldm", "ldmda", "ldmdb", "ldmia", "ldmib"]),
+    (Opcode::Ldm, &["ldm", "ldmda", "ldmdb", "ldmia", "ldmib"]),
+    (Opcode::LdmP, &["ldm", "ldmda", "ldmdb", "ldmia", "ldmib"]),
+    (Opcode::LdmPcW, &["ldm", "ldmda", "ldmdb", "ldmia", "ldmib"]),
+    (Opcode::LdmPc, &["ldm", "ldmda", "ldmdb", "ldmia", "ldmib"]),
+    (Opcode::Ldr, &["ldr"]),
+    (Opcode::LdrB, &["ldrb"]),
+    (Opcode::LdrBt, &["ldrbt"]),
+    (Opcode::LdrH, &["ldrh"]),
+    (Opcode::LdrSb, &["ldrsb"]),
+    (Opcode::LdrSh, &["ldrsh"]),
+    (Opcode::LdrT, &["ldrt"]),
+    (Opcode::Lsl, &["lsl", "lsls"]),
+    (Opcode::Lsr, &["lsr", "lsrs"]),
+    (Opcode::Mcr, &["mcr"]),
+    (Opcode::Mla, &["mla", "mlas"]),
+    (Opcode::Mov, &["mov", "movs"]),
+    (Opcode::MovImm, &["mov", "movs"]),
+    (Opcode::MovReg, &["mov", "movs"]),
+    (Opcode::Mrc, &["mrc"]),
+    (Opcode::Mrs, &["mrs"]),
+    (Opcode::MsrI, &["msr"]),
+    (Opcode::Msr, &["msr"]),
+    (Opcode::Mul, &["mul", "muls"]),
+    (Opcode::Mvn, &["mvn", "mvns"]),
+    (Opcode::Orr, &["orr", "orrs"]),
+    (Opcode::PopM, &["pop"]),
+    (Opcode::PopR, &["pop"]),
+    (Opcode::PushM, &["push"]),
+    (Opcode::PushR, &["push"]),
+    (Opcode::Ror, &["ror", "rors"]),
+    (Opcode::Rrx, &["rrx", "rrxs"]),
+    (Opcode::Rsb, &["rsb", "rsbs"]),
+    (Opcode::Rsc, &["rsc", "rscs"]),
+    (Opcode::Sbc, &["sbc", "sbcs"]),
+    (Opcode::Smlal, &["smlal", "smlals"]),
+    (Opcode::Smull, &["smull", "smulls"]),
+    (Opcode::Stc, &["stc", "stcl"]),
+    (Opcode::Stm, &["stm", "stmda", "stmdb", "stmia", "stmib"]),
+    (Opcode::StmW, &["stm", "stmda", "stmdb", "stmia", "stmib"]),
+    (Opcode::StmP, &["stm", "stmda", "stmdb", "stmia", "stmib"]),
+    (Opcode::Str, &["str"]),
+    (Opcode::StrB, &["strb"]),
+    (Opcode::StrBt, &["strbt"]),
+    (Opcode::StrH, &["strh"]),
+    (Opcode::StrT, &["strt"]),
+    (Opcode::Sub, &["sub", "subs"]),
+    (Opcode::Svc, &["svc"]),
+    (Opcode::Swi, &["swi"]),
+    (Opcode::Swp, &["swp"]),
+    (Opcode::Swpb, &["swpb"]),
+    (Opcode::Teq, &["teq"]),
+    (Opcode::Tst, &["tst"]),
+    (Opcode::Umlal, &["umlal", "umlals"]),
+    (Opcode::Umull, &["umull", "umulls"]),
+];
+/// Opcode of an instruction. The discriminants, such as `op as u8`, change whenever opcodes are added or
+/// reordered, so use [`Opcode::id`] or [`Opcode::variant_name`] to store an opcode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum Opcode {
@@ -83,145 +9725,506 @@ pub enum Opcode {
     #[default]
     Illegal = u8::MAX,
     /// ADC: Add with Carry
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0101  | S  | Rn    | Rd    | addr_data
+    /// ```
     Adc = 0,
     /// ADD: Add
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0100  | S  | Rn    | Rd    | addr_data
+    /// ```
     Add = 1,
     /// AND: Bitwise AND
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0000  | S  | Rn    | Rd    | addr_data
+    /// ```
     And = 2,
     /// ASR: Arithmetic Right Shift
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:7      | 6:5 | 4         | 3:0
+    /// cond  | 0001101 | S  | 0000  | Rd    | shift_arg | 10  | shift_arg | Rm
+    /// ```
     Asr = 3,
     /// B: Branch
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:0
+    /// cond  | 1010  | branch_offset
+    /// ```
     B = 4,
     /// BL: Branch and Link
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:0
+    /// cond  | 1011  | branch_offset
+    /// ```
     Bl = 5,
     /// BIC: Bit Clear
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 1110  | S  | Rn    | Rd    | addr_data
+    /// ```
     Bic = 6,
     /// BX: Branch and Exchange
+    ///
+    /// ```text
+    /// 31:28 | 27:4                     | 3:0
+    /// cond  | 000100101111111111110001 | Rm
+    /// ```
     Bx = 7,
     /// CDP: Coprocessor Data Processing
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:20          | 19:16 | 15:12 | 11:8   | 7:5      | 4 | 3:0
+    /// cond  | 1110  | codat_opcode_1 | CRn   | CRd   | coproc | opcode_2 | 0 | CRm
+    /// ```
     Cdp = 8,
     /// CMN: Compare Negative
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 10111 | Rn    | 0000  | addr_data
+    /// ```
     Cmn = 9,
     /// CMP: Compare
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 10101 | Rn    | 0000  | addr_data
+    /// ```
     Cmp = 10,
     /// EOR: Bitwise Exclusive OR
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0001  | S  | Rn    | Rd    | addr_data
+    /// ```
     Eor = 11,
     /// LDC: Load Coprocessor
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23       | 22 | 21          | 20 | 19:16       | 15:12 | 11:8   | 7:0
+    /// cond  | 110   | addr_coproc | L  | addr_coproc | 1  | addr_coproc | CRd   | coproc | addr_coproc
+    /// ```
     Ldc = 12,
     /// LDM: Load Multiple (writeback)
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15:0
+    /// cond  | 100   | addr_ldm_stm | 011   | Rn_wb | registers
+    /// ```
     LdmW = 13,
     /// LDM: Load Multiple
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15:0
+    /// cond  | 100   | addr_ldm_stm | 001   | Rn    | registers
+    /// ```
     Ldm = 14,
     /// LDM: Load Multiple (privileged)
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15 | 14:0
+    /// cond  | 100   | addr_ldm_stm | 101   | Rn    | 0  | registers_c
+    /// ```
     LdmP = 15,
     /// LDM: Load Multiple (including PC, writeback)
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15 | 14:0
+    /// cond  | 100   | addr_ldm_stm | 111   | Rn_wb | 1  | registers_c
+    /// ```
     LdmPcW = 16,
     /// LDM: Load Multiple (including PC)
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15 | 14:0
+    /// cond  | 100   | addr_ldm_stm | 101   | Rn    | 1  | registers_c
+    /// ```
     LdmPc = 17,
     /// LDR: Load Register
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25:23        | 22 | 21           | 20 | 19:16        | 15:12 | 11:0
+    /// cond  | 01    | addr_ldr_str | 0  | addr_ldr_str | 1  | addr_ldr_str | Rd    | addr_ldr_str
+    /// ```
     Ldr = 18,
     /// LDRB: Load Register Byte
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25:23        | 22 | 21           | 20 | 19:16        | 15:12 | 11:0
+    /// cond  | 01    | addr_ldr_str | 1  | addr_ldr_str | 1  | addr_ldr_str | Rd    | addr_ldr_str
+    /// ```
     LdrB = 19,
     /// LDRBT: Load Register Byte with Translation
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25             | 24 | 23             | 22:20 | 19:16          | 15:12 | 11:0
+    /// cond  | 01    | addr_ldrt_strt | 0  | addr_ldrt_strt | 111   | addr_ldrt_strt | Rd    | addr_ldrt_strt
+    /// ```
     LdrBt = 20,
     /// LDRH: Load Register Halfword
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:21             | 20 | 19:16             | 15:12 | 11:8              | 7:4  | 3:0
+    /// cond  | 000   | addr_misc_ldr_str | 1  | addr_misc_ldr_str | Rd    | addr_misc_ldr_str | 1011 | addr_misc_ldr_str
+    /// ```
     LdrH = 21,
     /// LDRSB: Load Register Signed Byte
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:21             | 20 | 19:16             | 15:12 | 11:8              | 7:4  | 3:0
+    /// cond  | 000   | addr_misc_ldr_str | 1  | addr_misc_ldr_str | Rd    | addr_misc_ldr_str | 1101 | addr_misc_ldr_str
+    /// ```
     LdrSb = 22,
     /// LDRSH: Load Register Signed Halfword
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:21             | 20 | 19:16             | 15:12 | 11:8              | 7:4  | 3:0
+    /// cond  | 000   | addr_misc_ldr_str | 1  | addr_misc_ldr_str | Rd    | addr_misc_ldr_str | 1111 | addr_misc_ldr_str
+    /// ```
     LdrSh = 23,
     /// LDRT: Load Register with Translation
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25             | 24 | 23             | 22:20 | 19:16          | 15:12 | 11:0
+    /// cond  | 01    | addr_ldrt_strt | 0  | addr_ldrt_strt | 011   | addr_ldrt_strt | Rd    | addr_ldrt_strt
+    /// ```
     LdrT = 24,
     /// LSL: Logical Shift Left
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:7      | 6:5 | 4         | 3:0
+    /// cond  | 0001101 | S  | 0000  | Rd    | shift_arg | 00  | shift_arg | Rm
+    /// ```
     Lsl = 25,
     /// LSR: Logical Shift Right
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:7      | 6:5 | 4         | 3:0
+    /// cond  | 0001101 | S  | 0000  | Rd    | shift_arg | 01  | shift_arg | Rm
+    /// ```
     Lsr = 26,
     /// MCR: Move to Coprocessor from ARM Register
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:21          | 20 | 19:16 | 15:12 | 11:8   | 7:5      | 4 | 3:0
+    /// cond  | 1110  | comov_opcode_1 | 0  | CRn   | Rd    | coproc | opcode_2 | 1 | CRm
+    /// ```
     Mcr = 27,
     /// MLA: Multiply Accumulate
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:8 | 7:4  | 3:0
+    /// cond  | 0000001 | S  | RdHi  | Rn_12 | Rs   | 1001 | Rm
+    /// ```
     Mla = 28,
     /// MOV: Move
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 1101  | S  | 0000  | Rd    | addr_data
+    /// ```
     Mov = 29,
     /// MOV: Move immediate
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 0011101 | S  | 0000  | Rd    | rotated_immed_8
+    /// ```
     MovImm = 30,
     /// MOV: Move register
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:4     | 3:0
+    /// cond  | 0001101 | S  | 0000  | Rd    | 00000000 | Rm
+    /// ```
     MovReg = 31,
     /// MRC: Move to ARM Register from Coprocessor
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:21          | 20 | 19:16 | 15:12 | 11:8   | 7:5      | 4 | 3:0
+    /// cond  | 1110  | comov_opcode_1 | 1  | CRn   | Rd    | coproc | opcode_2 | 1 | CRm
+    /// ```
     Mrc = 32,
     /// MRS: Move to ARM Register from Status Register
+    ///
+    /// ```text
+    /// 31:28 | 27:23 | 22 | 21:16  | 15:12 | 11:0
+    /// cond  | 00010 | R  | 001111 | Rd    | 000000000000
+    /// ```
     Mrs = 33,
     /// MSR: Move to Status Register from ARM Register
+    ///
+    /// ```text
+    /// 31:28 | 27:23 | 22         | 21:20 | 19:16      | 15:12 | 11:0
+    /// cond  | 00110 | field_mask | 10    | field_mask | 1111  | rotated_immed_8
+    /// ```
     MsrI = 34,
     /// MSR: Move to Status Register from ARM Register
+    ///
+    /// ```text
+    /// 31:28 | 27:23 | 22         | 21:20 | 19:16      | 15:4         | 3:0
+    /// cond  | 00010 | field_mask | 10    | field_mask | 111100000000 | Rm
+    /// ```
     Msr = 35,
     /// MUL: Multiply
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:8 | 7:4  | 3:0
+    /// cond  | 0000000 | S  | RdHi  | 0000  | Rs   | 1001 | Rm
+    /// ```
     Mul = 36,
     /// MVN: Move Not
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 1111  | S  | 0000  | Rd    | addr_data
+    /// ```
     Mvn = 37,
     /// ORR: Logical OR
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 1100  | S  | Rn    | Rd    | addr_data
+    /// ```
     Orr = 38,
     /// POP: Pop multiple registers
+    ///
+    /// ```text
+    /// 31:28 | 27:16        | 15:0
+    /// cond  | 100010111101 | registers
+    /// ```
     PopM = 39,
     /// POP: Pop register
+    ///
+    /// ```text
+    /// 31:28 | 27:16        | 15:12   | 11:0
+    /// cond  | 010010011101 | Rt_list | 000000000100
+    /// ```
     PopR = 40,
     /// PUSH: Push multiple registers
+    ///
+    /// ```text
+    /// 31:28 | 27:16        | 15:0
+    /// cond  | 100100101101 | registers
+    /// ```
     PushM = 41,
     /// PUSH: Push register
+    ///
+    /// ```text
+    /// 31:28 | 27:16        | 15:12   | 11:0
+    /// cond  | 010100101101 | Rt_list | 000000000100
+    /// ```
     PushR = 42,
     /// ROR: Rotate Right
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:7      | 6:5 | 4         | 3:0
+    /// cond  | 0001101 | S  | 0000  | Rd    | shift_arg | 11  | shift_arg | Rm
+    /// ```
     Ror = 43,
     /// RRX: Rotate Right with Extend
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:4     | 3:0
+    /// cond  | 0001101 | S  | 0000  | Rd    | 00000110 | Rm
+    /// ```
     Rrx = 44,
     /// RSB: Reverse Subtract
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0011  | S  | Rn    | Rd    | addr_data
+    /// ```
     Rsb = 45,
     /// RSC: Reverse Subtract with Carry
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0111  | S  | Rn    | Rd    | addr_data
+    /// ```
     Rsc = 46,
     /// SBC: Subtract with Carry
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0110  | S  | Rn    | Rd    | addr_data
+    /// ```
     Sbc = 47,
     /// SMLAL: Signed Multiply Accumulate Long
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:8 | 7:4  | 3:0
+    /// cond  | 0000111 | S  | RdHi  | RdLo  | Rs   | 1001 | Rm
+    /// ```
     Smlal = 48,
     /// SMULL: Signed Multiply Long
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:8 | 7:4  | 3:0
+    /// cond  | 0000110 | S  | RdHi  | RdLo  | Rs   | 1001 | Rm
+    /// ```
     Smull = 49,
     /// STC: Store Coprocessor
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23       | 22 | 21          | 20 | 19:16       | 15:12 | 11:8   | 7:0
+    /// cond  | 110   | addr_coproc | L  | addr_coproc | 0  | addr_coproc | CRd   | coproc | addr_coproc
+    /// ```
     Stc = 50,
     /// STM: Store Multiple
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15:0
+    /// cond  | 100   | addr_ldm_stm | 000   | Rn    | registers
+    /// ```
     Stm = 51,
     /// STM: Store Multiple (writeback)
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15:0
+    /// cond  | 100   | addr_ldm_stm | 010   | Rn_wb | registers
+    /// ```
     StmW = 52,
     /// STM: Store Multiple (privileged)
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:23        | 22:20 | 19:16 | 15:0
+    /// cond  | 100   | addr_ldm_stm | 100   | Rn    | registers_c
+    /// ```
     StmP = 53,
     /// STR: Store Register
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25:23        | 22 | 21           | 20 | 19:16        | 15:12 | 11:0
+    /// cond  | 01    | addr_ldr_str | 0  | addr_ldr_str | 0  | addr_ldr_str | Rd    | addr_ldr_str
+    /// ```
     Str = 54,
     /// STRB: Store Register Byte
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25:23        | 22 | 21           | 20 | 19:16        | 15:12 | 11:0
+    /// cond  | 01    | addr_ldr_str | 1  | addr_ldr_str | 0  | addr_ldr_str | Rd    | addr_ldr_str
+    /// ```
     StrB = 55,
     /// STRBT: Store Register Byte with Translation
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25             | 24 | 23             | 22:20 | 19:16          | 15:12 | 11:0
+    /// cond  | 01    | addr_ldrt_strt | 0  | addr_ldrt_strt | 110   | addr_ldrt_strt | Rd    | addr_ldrt_strt
+    /// ```
     StrBt = 56,
     /// STRH: Store Register Halfword
+    ///
+    /// ```text
+    /// 31:28 | 27:25 | 24:21             | 20 | 19:16             | 15:12 | 11:8              | 7:4  | 3:0
+    /// cond  | 000   | addr_misc_ldr_str | 0  | addr_misc_ldr_str | Rd    | addr_misc_ldr_str | 1011 | addr_misc_ldr_str
+    /// ```
     StrH = 57,
     /// STRT: Store Register with Translation
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25             | 24 | 23             | 22:20 | 19:16          | 15:12 | 11:0
+    /// cond  | 01    | addr_ldrt_strt | 0  | addr_ldrt_strt | 010   | addr_ldrt_strt | Rd    | addr_ldrt_strt
+    /// ```
     StrT = 58,
     /// SUB: Subtract
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:21 | 20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 0010  | S  | Rn    | Rd    | addr_data
+    /// ```
     Sub = 59,
     /// SVC: Supervisor Call
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:0
+    /// cond  | 1111  | immed_24
+    /// ```
     Svc = 60,
     /// SWI: Software Interrupt
+    ///
+    /// ```text
+    /// 31:28 | 27:24 | 23:0
+    /// cond  | 1111  | immed_24
+    /// ```
     Swi = 61,
     /// SWP: Swap
+    ///
+    /// ```text
+    /// 31:28 | 27:20    | 19:16    | 15:12 | 11:4     | 3:0
+    /// cond  | 00010000 | Rn_deref | Rd    | 00001001 | Rm
+    /// ```
     Swp = 62,
     /// SWPB: Swap Byte
+    ///
+    /// ```text
+    /// 31:28 | 27:20    | 19:16    | 15:12 | 11:4     | 3:0
+    /// cond  | 00010100 | Rn_deref | Rd    | 00001001 | Rm
+    /// ```
     Swpb = 63,
     /// TEQ: Test Equivalence
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 10011 | Rn    | 0000  | addr_data
+    /// ```
     Teq = 64,
     /// TST: Test
+    ///
+    /// ```text
+    /// 31:28 | 27:26 | 25        | 24:20 | 19:16 | 15:12 | 11:0
+    /// cond  | 00    | addr_data | 10001 | Rn    | 0000  | addr_data
+    /// ```
     Tst = 65,
     /// UMLAL: Unsigned Multiply Accumulate Long
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:8 | 7:4  | 3:0
+    /// cond  | 0000101 | S  | RdHi  | RdLo  | Rs   | 1001 | Rm
+    /// ```
     Umlal = 66,
     /// UMULL: Unsigned Multiply Long
+    ///
+    /// ```text
+    /// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:8 | 7:4  | 3:0
+    /// cond  | 0000100 | S  | RdHi  | RdLo  | Rs   | 1001 | Rm
+    /// ```
     Umull = 67,
 }
 impl Opcode {
+    /// Decodes the opcode with a tree of bit tests.
+    #[cfg(not(feature = "fast"))]
+    #[inline]
+    pub const fn find(code: u32, flags: &ParseFlags) -> Self {
+        let guard = crate::no_panic::NoPanicGuard;
+        let op = Self::find_tree(code, flags);
+        guard.disarm();
+        op
+    }
+    /// Decodes the opcode with a lookup table.
+    #[cfg(feature = "fast")]
     #[inline]
-    pub fn find(code: u32, flags: &ParseFlags) -> Self {
+    pub const fn find(code: u32, flags: &ParseFlags) -> Self {
+        let guard = crate::no_panic::NoPanicGuard;
+        let op = Self::find_table(code, flags);
+        guard.disarm();
+        op
+    }
+    /// Implementation of [`Self::find`] as a tree of bit tests. Public to test that it's equivalent to
+    /// [`Self::find_table`], whichever one the profile uses.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn find_tree(code: u32, flags: &ParseFlags) -> Self {
         if (code & 0x00400000) == 0x00400000 {
             if (code & 0x02000000) == 0x02000000 {
                 if (code & 0x01000000) == 0x01000000 {
@@ -1921,15 +11924,741 @@ impl Opcode {
         }
         Opcode::Illegal
     }
+    /// Implementation of [`Self::find`] as a lookup table. Public to test that it's equivalent to
+    /// [`Self::find_tree`], whichever one the profile uses.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn find_table(code: u32, flags: &ParseFlags) -> Self {
+        let key = ((code >> 4) & 0x0000000f) | ((code >> 16) & 0x00000ff0);
+        let (opcode, group) = FIND_TABLE[key as usize];
+        match group {
+            1 => {
+                if (code & 0x0000f000) == 0x00000000 {
+                    return Opcode::Mul;
+                }
+            }
+            2 => {
+                if (code & 0x000f0f0f) == 0x000f0000 {
+                    return Opcode::Mrs;
+                }
+            }
+            3 => {
+                if (code & 0x00000f00) == 0x00000000 {
+                    return Opcode::Swp;
+                }
+            }
+            4 => {
+                if (code & 0x0000f000) == 0x00000000 {
+                    return Opcode::Tst;
+                }
+            }
+            5 => {
+                if (code & 0x0000ff00) == 0x0000f000 {
+                    return Opcode::Msr;
+                }
+            }
+            6 => {
+                if (code & 0x000fff00) == 0x000fff00 {
+                    return Opcode::Bx;
+                }
+            }
+            7 => {
+                if (code & 0x0000f000) == 0x00000000 {
+                    return Opcode::Teq;
+                }
+            }
+            8 => {
+                if (code & 0x00000f00) == 0x00000000 {
+                    return Opcode::Swpb;
+                }
+            }
+            9 => {
+                if (code & 0x0000f000) == 0x00000000 {
+                    return Opcode::Cmp;
+                }
+            }
+            10 => {
+                if (code & 0x0000f000) == 0x00000000 {
+                    return Opcode::Cmn;
+                }
+            }
+            11 => {
+                if flags.ual && (code & 0x000f0f00) == 0x00000000 {
+                    return Opcode::MovReg;
+                }
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Lsl;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            12 => {
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Lsl;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            13 => {
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Lsr;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            14 => {
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Asr;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            15 => {
+                if flags.ual && (code & 0x000f0f00) == 0x00000000 {
+                    return Opcode::Rrx;
+                }
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Ror;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            16 => {
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Ror;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            17 => {
+                if (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mvn;
+                }
+            }
+            18 => {
+                if (code & 0x0000f000) == 0x0000f000 {
+                    return Opcode::MsrI;
+                }
+            }
+            19 => {
+                if flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::MovImm;
+                }
+                if !flags.ual && (code & 0x000f0000) == 0x00000000 {
+                    return Opcode::Mov;
+                }
+            }
+            20 => {
+                if flags.ual && (code & 0x000f0f0f) == 0x000d0004 {
+                    return Opcode::PopR;
+                }
+            }
+            21 => {
+                if flags.ual && (code & 0x000f0f0f) == 0x000d0004 {
+                    return Opcode::PushR;
+                }
+            }
+            22 => {
+                if (code & 0x00008000) == 0x00000000 {
+                    return Opcode::LdmP;
+                }
+                if (code & 0x00008000) == 0x00008000 {
+                    return Opcode::LdmPc;
+                }
+            }
+            23 => {
+                if (code & 0x00008000) == 0x00008000 {
+                    return Opcode::LdmPcW;
+                }
+            }
+            24 => {
+                if flags.ual && (code & 0x000f0000) == 0x000d0000 {
+                    return Opcode::PopM;
+                }
+            }
+            25 => {
+                if flags.ual && (code & 0x000f0000) == 0x000d0000 {
+                    return Opcode::PushM;
+                }
+            }
+            26 => {
+                if flags.ual {
+                    return Opcode::Svc;
+                }
+                if !flags.ual {
+                    return Opcode::Swi;
+                }
+            }
+            _ => {}
+        }
+        opcode
+    }
+    /// Returns every opcode whose bitmask and pattern match the code, starting with the result of [`Self::find`].
+    /// The rest are ordered from most to least specific bitmask. This is meant for debugging the ISA
+    /// definitions, as it checks every opcode one by one.
+    pub fn find_all(code: u32, flags: &ParseFlags) -> impl Iterator<Item = Self> {
+        let found = Self::find(code, flags);
+        let mut all = vec![];
+        if found != Opcode::Illegal {
+            all.push(found);
+        }
+        if (code & 0x0ffffff0) == 0x012fff10 && found != Opcode::Bx {
+            all.push(Opcode::Bx);
+        }
+        if flags.ual && (code & 0x0fff0fff) == 0x049d0004 && found != Opcode::PopR {
+            all.push(Opcode::PopR);
+        }
+        if flags.ual && (code & 0x0fff0fff) == 0x052d0004 && found != Opcode::PushR {
+            all.push(Opcode::PushR);
+        }
+        if (code & 0x0fbf0fff) == 0x010f0000 && found != Opcode::Mrs {
+            all.push(Opcode::Mrs);
+        }
+        if flags.ual && (code & 0x0fef0ff0) == 0x01a00000 && found != Opcode::MovReg {
+            all.push(Opcode::MovReg);
+        }
+        if (code & 0x0fb0fff0) == 0x0120f000 && found != Opcode::Msr {
+            all.push(Opcode::Msr);
+        }
+        if flags.ual && (code & 0x0fef0ff0) == 0x01a00060 && found != Opcode::Rrx {
+            all.push(Opcode::Rrx);
+        }
+        if (code & 0x0ff00ff0) == 0x01000090 && found != Opcode::Swp {
+            all.push(Opcode::Swp);
+        }
+        if (code & 0x0ff00ff0) == 0x01400090 && found != Opcode::Swpb {
+            all.push(Opcode::Swpb);
+        }
+        if (code & 0x0fe0f0f0) == 0x00000090 && found != Opcode::Mul {
+            all.push(Opcode::Mul);
+        }
+        if flags.ual && (code & 0x0fef0060) == 0x01a00040 && found != Opcode::Asr {
+            all.push(Opcode::Asr);
+        }
+        if flags.ual && (code & 0x0fef0060) == 0x01a00000 && found != Opcode::Lsl {
+            all.push(Opcode::Lsl);
+        }
+        if flags.ual && (code & 0x0fef0060) == 0x01a00020 && found != Opcode::Lsr {
+            all.push(Opcode::Lsr);
+        }
+        if flags.ual && (code & 0x0fef0060) == 0x01a00060 && found != Opcode::Ror {
+            all.push(Opcode::Ror);
+        }
+        if flags.ual && (code & 0x0fff0000) == 0x08bd0000 && found != Opcode::PopM {
+            all.push(Opcode::PopM);
+        }
+        if flags.ual && (code & 0x0fff0000) == 0x092d0000 && found != Opcode::PushM {
+            all.push(Opcode::PushM);
+        }
+        if (code & 0x0df0f000) == 0x01700000 && found != Opcode::Cmn {
+            all.push(Opcode::Cmn);
+        }
+        if (code & 0x0df0f000) == 0x01500000 && found != Opcode::Cmp {
+            all.push(Opcode::Cmp);
+        }
+        if (code & 0x0fe000f0) == 0x00200090 && found != Opcode::Mla {
+            all.push(Opcode::Mla);
+        }
+        if flags.ual && (code & 0x0fef0000) == 0x03a00000 && found != Opcode::MovImm {
+            all.push(Opcode::MovImm);
+        }
+        if (code & 0x0fb0f000) == 0x0320f000 && found != Opcode::MsrI {
+            all.push(Opcode::MsrI);
+        }
+        if (code & 0x0fe000f0) == 0x00e00090 && found != Opcode::Smlal {
+            all.push(Opcode::Smlal);
+        }
+        if (code & 0x0fe000f0) == 0x00c00090 && found != Opcode::Smull {
+            all.push(Opcode::Smull);
+        }
+        if (code & 0x0df0f000) == 0x01300000 && found != Opcode::Teq {
+            all.push(Opcode::Teq);
+        }
+        if (code & 0x0df0f000) == 0x01100000 && found != Opcode::Tst {
+            all.push(Opcode::Tst);
+        }
+        if (code & 0x0fe000f0) == 0x00a00090 && found != Opcode::Umlal {
+            all.push(Opcode::Umlal);
+        }
+        if (code & 0x0fe000f0) == 0x00800090 && found != Opcode::Umull {
+            all.push(Opcode::Umull);
+        }
+        if !flags.ual && (code & 0x0def0000) == 0x01a00000 && found != Opcode::Mov {
+            all.push(Opcode::Mov);
+        }
+        if (code & 0x0def0000) == 0x01e00000 && found != Opcode::Mvn {
+            all.push(Opcode::Mvn);
+        }
+        if (code & 0x0e1000f0) == 0x001000b0 && found != Opcode::LdrH {
+            all.push(Opcode::LdrH);
+        }
+        if (code & 0x0e1000f0) == 0x001000d0 && found != Opcode::LdrSb {
+            all.push(Opcode::LdrSb);
+        }
+        if (code & 0x0e1000f0) == 0x001000f0 && found != Opcode::LdrSh {
+            all.push(Opcode::LdrSh);
+        }
+        if (code & 0x0e1000f0) == 0x000000b0 && found != Opcode::StrH {
+            all.push(Opcode::StrH);
+        }
+        if (code & 0x0e708000) == 0x08500000 && found != Opcode::LdmP {
+            all.push(Opcode::LdmP);
+        }
+        if (code & 0x0e708000) == 0x08708000 && found != Opcode::LdmPcW {
+            all.push(Opcode::LdmPcW);
+        }
+        if (code & 0x0e708000) == 0x08508000 && found != Opcode::LdmPc {
+            all.push(Opcode::LdmPc);
+        }
+        if (code & 0x0de00000) == 0x00a00000 && found != Opcode::Adc {
+            all.push(Opcode::Adc);
+        }
+        if (code & 0x0de00000) == 0x00800000 && found != Opcode::Add {
+            all.push(Opcode::Add);
+        }
+        if (code & 0x0de00000) == 0x00000000 && found != Opcode::And {
+            all.push(Opcode::And);
+        }
+        if (code & 0x0de00000) == 0x01c00000 && found != Opcode::Bic {
+            all.push(Opcode::Bic);
+        }
+        if (code & 0x0de00000) == 0x00200000 && found != Opcode::Eor {
+            all.push(Opcode::Eor);
+        }
+        if (code & 0x0e700000) == 0x08300000 && found != Opcode::LdmW {
+            all.push(Opcode::LdmW);
+        }
+        if (code & 0x0e700000) == 0x08100000 && found != Opcode::Ldm {
+            all.push(Opcode::Ldm);
+        }
+        if (code & 0x0d700000) == 0x04700000 && found != Opcode::LdrBt {
+            all.push(Opcode::LdrBt);
+        }
+        if (code & 0x0d700000) == 0x04300000 && found != Opcode::LdrT {
+            all.push(Opcode::LdrT);
+        }
+        if (code & 0x0f100010) == 0x0e000010 && found != Opcode::Mcr {
+            all.push(Opcode::Mcr);
+        }
+        if (code & 0x0f100010) == 0x0e100010 && found != Opcode::Mrc {
+            all.push(Opcode::Mrc);
+        }
+        if (code & 0x0de00000) == 0x01800000 && found != Opcode::Orr {
+            all.push(Opcode::Orr);
+        }
+        if (code & 0x0de00000) == 0x00600000 && found != Opcode::Rsb {
+            all.push(Opcode::Rsb);
+        }
+        if (code & 0x0de00000) == 0x00e00000 && found != Opcode::Rsc {
+            all.push(Opcode::Rsc);
+        }
+        if (code & 0x0de00000) == 0x00c00000 && found != Opcode::Sbc {
+            all.push(Opcode::Sbc);
+        }
+        if (code & 0x0e700000) == 0x08000000 && found != Opcode::Stm {
+            all.push(Opcode::Stm);
+        }
+        if (code & 0x0e700000) == 0x08200000 && found != Opcode::StmW {
+            all.push(Opcode::StmW);
+        }
+        if (code & 0x0e700000) == 0x08400000 && found != Opcode::StmP {
+            all.push(Opcode::StmP);
+        }
+        if (code & 0x0d700000) == 0x04600000 && found != Opcode::StrBt {
+            all.push(Opcode::StrBt);
+        }
+        if (code & 0x0d700000) == 0x04200000 && found != Opcode::StrT {
+            all.push(Opcode::StrT);
+        }
+        if (code & 0x0de00000) == 0x00400000 && found != Opcode::Sub {
+            all.push(Opcode::Sub);
+        }
+        if (code & 0x0f000010) == 0x0e000000 && found != Opcode::Cdp {
+            all.push(Opcode::Cdp);
+        }
+        if (code & 0x0f000000) == 0x0a000000 && found != Opcode::B {
+            all.push(Opcode::B);
+        }
+        if (code & 0x0f000000) == 0x0b000000 && found != Opcode::Bl {
+            all.push(Opcode::Bl);
+        }
+        if (code & 0x0e100000) == 0x0c100000 && found != Opcode::Ldc {
+            all.push(Opcode::Ldc);
+        }
+        if (code & 0x0c500000) == 0x04100000 && found != Opcode::Ldr {
+            all.push(Opcode::Ldr);
+        }
+        if (code & 0x0c500000) == 0x04500000 && found != Opcode::LdrB {
+            all.push(Opcode::LdrB);
+        }
+        if (code & 0x0e100000) == 0x0c000000 && found != Opcode::Stc {
+            all.push(Opcode::Stc);
+        }
+        if (code & 0x0c500000) == 0x04000000 && found != Opcode::Str {
+            all.push(Opcode::Str);
+        }
+        if (code & 0x0c500000) == 0x04400000 && found != Opcode::StrB {
+            all.push(Opcode::StrB);
+        }
+        if flags.ual && (code & 0x0f000000) == 0x0f000000 && found != Opcode::Svc {
+            all.push(Opcode::Svc);
+        }
+        if !flags.ual && (code & 0x0f000000) == 0x0f000000 && found != Opcode::Swi {
+            all.push(Opcode::Swi);
+        }
+        all.into_iter()
+    }
     pub fn mnemonic(self) -> &'static str {
         OPCODE_MNEMONICS[self as usize]
     }
     pub fn count() -> usize {
         68
     }
+    /// Returns whether this opcode is a unified syntax (UAL) alias of another opcode, such as PUSH for STMDB.
+    /// See `Ins::aliased_from` to find the underlying opcode.
+    pub const fn is_alias(self) -> bool {
+        match self {
+            Opcode::Asr
+            | Opcode::Lsl
+            | Opcode::Lsr
+            | Opcode::MovImm
+            | Opcode::MovReg
+            | Opcode::PopM
+            | Opcode::PopR
+            | Opcode::PushM
+            | Opcode::PushR
+            | Opcode::Ror
+            | Opcode::Rrx
+            | Opcode::Svc => true,
+            _ => false,
+        }
+    }
+    /// Returns the name of this variant, e.g. `LdrB`. Unlike the mnemonic, it tells apart opcodes with the same
+    /// mnemonic. Variant names are kept stable, see `renames` in the ISA files.
+    pub fn variant_name(self) -> &'static str {
+        if self == Opcode::Illegal {
+            "Illegal"
+        } else {
+            OPCODE_VARIANT_NAMES[self as usize]
+        }
+    }
+    /// Returns the opcode with the given variant name. Old names of renamed variants are also accepted, so that
+    /// names logged by earlier versions can be read back.
+    pub fn from_variant_name(name: &str) -> Option<Self> {
+        match name {
+            "Illegal" => Some(Opcode::Illegal),
+            "Adc" => Some(Opcode::Adc),
+            "Add" => Some(Opcode::Add),
+            "And" => Some(Opcode::And),
+            "Asr" => Some(Opcode::Asr),
+            "B" => Some(Opcode::B),
+            "Bl" => Some(Opcode::Bl),
+            "Bic" => Some(Opcode::Bic),
+            "Bx" => Some(Opcode::Bx),
+            "Cdp" => Some(Opcode::Cdp),
+            "Cmn" => Some(Opcode::Cmn),
+            "Cmp" => Some(Opcode::Cmp),
+            "Eor" => Some(Opcode::Eor),
+            "Ldc" => Some(Opcode::Ldc),
+            "LdmW" => Some(Opcode::LdmW),
+            "Ldm" => Some(Opcode::Ldm),
+            "LdmP" => Some(Opcode::LdmP),
+            "LdmPcW" => Some(Opcode::LdmPcW),
+            "LdmPc" => Some(Opcode::LdmPc),
+            "Ldr" => Some(Opcode::Ldr),
+            "LdrB" => Some(Opcode::LdrB),
+            "LdrBt" => Some(Opcode::LdrBt),
+            "LdrH" => Some(Opcode::LdrH),
+            "LdrSb" => Some(Opcode::LdrSb),
+            "LdrSh" => Some(Opcode::LdrSh),
+            "LdrT" => Some(Opcode::LdrT),
+            "Lsl" => Some(Opcode::Lsl),
+            "Lsr" => Some(Opcode::Lsr),
+            "Mcr" => Some(Opcode::Mcr),
+            "Mla" => Some(Opcode::Mla),
+            "Mov" => Some(Opcode::Mov),
+            "MovImm" => Some(Opcode::MovImm),
+            "MovReg" => Some(Opcode::MovReg),
+            "Mrc" => Some(Opcode::Mrc),
+            "Mrs" => Some(Opcode::Mrs),
+            "MsrI" => Some(Opcode::MsrI),
+            "Msr" => Some(Opcode::Msr),
+            "Mul" => Some(Opcode::Mul),
+            "Mvn" => Some(Opcode::Mvn),
+            "Orr" => Some(Opcode::Orr),
+            "PopM" => Some(Opcode::PopM),
+            "PopR" => Some(Opcode::PopR),
+            "PushM" => Some(Opcode::PushM),
+            "PushR" => Some(Opcode::PushR),
+            "Ror" => Some(Opcode::Ror),
+            "Rrx" => Some(Opcode::Rrx),
+            "Rsb" => Some(Opcode::Rsb),
+            "Rsc" => Some(Opcode::Rsc),
+            "Sbc" => Some(Opcode::Sbc),
+            "Smlal" => Some(Opcode::Smlal),
+            "Smull" => Some(Opcode::Smull),
+            "Stc" => Some(Opcode::Stc),
+            "Stm" => Some(Opcode::Stm),
+            "StmW" => Some(Opcode::StmW),
+            "StmP" => Some(Opcode::StmP),
+            "Str" => Some(Opcode::Str),
+            "StrB" => Some(Opcode::StrB),
+            "StrBt" => Some(Opcode::StrBt),
+            "StrH" => Some(Opcode::StrH),
+            "StrT" => Some(Opcode::StrT),
+            "Sub" => Some(Opcode::Sub),
+            "Svc" => Some(Opcode::Svc),
+            "Swi" => Some(Opcode::Swi),
+            "Swp" => Some(Opcode::Swp),
+            "Swpb" => Some(Opcode::Swpb),
+            "Teq" => Some(Opcode::Teq),
+            "Tst" => Some(Opcode::Tst),
+            "Umlal" => Some(Opcode::Umlal),
+            "Umull" => Some(Opcode::Umull),
+            _ => None,
+        }
+    }
+    /// Returns the stable id of this opcode, e.g. `ldr_b`, which is the name of its entry in the ISA file. Unlike
+    /// the discriminant, which changes when opcodes are added or reordered, ids are kept stable like variant
+    /// names, so they can key on-disk caches.
+    pub fn id(self) -> &'static str {
+        if self == Opcode::Illegal { "illegal" } else { OPCODE_IDS[self as usize] }
+    }
+    /// Returns the opcode with the given [`id`](Self::id). Ids of renamed variants are also accepted.
+    pub fn from_id(id: &str) -> Option<Self> {
+        OPCODES_BY_ID
+            .binary_search_by_key(&id, |&(id, _)| id)
+            .ok()
+            .map(|index| OPCODES_BY_ID[index].1)
+    }
+    /// Returns true for branch instructions. Other instructions which can write to PC, such as `pop {pc}`, are
+    /// not included.
+    pub const fn is_branch(self) -> bool {
+        match self {
+            Opcode::B | Opcode::Bl | Opcode::Bx => true,
+            _ => false,
+        }
+    }
+    /// Returns true for instructions which load from memory.
+    pub const fn is_load(self) -> bool {
+        match self {
+            Opcode::Ldc
+            | Opcode::LdmW
+            | Opcode::Ldm
+            | Opcode::LdmP
+            | Opcode::LdmPcW
+            | Opcode::LdmPc
+            | Opcode::Ldr
+            | Opcode::LdrB
+            | Opcode::LdrBt
+            | Opcode::LdrH
+            | Opcode::LdrSb
+            | Opcode::LdrSh
+            | Opcode::LdrT
+            | Opcode::PopM
+            | Opcode::PopR
+            | Opcode::Swp
+            | Opcode::Swpb => true,
+            _ => false,
+        }
+    }
+    /// Returns true for instructions which store to memory.
+    pub const fn is_store(self) -> bool {
+        match self {
+            Opcode::PushM
+            | Opcode::PushR
+            | Opcode::Stc
+            | Opcode::Stm
+            | Opcode::StmW
+            | Opcode::StmP
+            | Opcode::Str
+            | Opcode::StrB
+            | Opcode::StrBt
+            | Opcode::StrH
+            | Opcode::StrT
+            | Opcode::Swp
+            | Opcode::Swpb => true,
+            _ => false,
+        }
+    }
+    /// Returns true for coprocessor instructions.
+    pub const fn is_coprocessor(self) -> bool {
+        match self {
+            Opcode::Cdp | Opcode::Ldc | Opcode::Mcr | Opcode::Mrc | Opcode::Stc => true,
+            _ => false,
+        }
+    }
+    /// Returns true for arithmetic, logical, move and compare instructions, including shifts. Multiplies and
+    /// media instructions are not included.
+    pub const fn is_data_processing(self) -> bool {
+        match self {
+            Opcode::Adc
+            | Opcode::Add
+            | Opcode::And
+            | Opcode::Asr
+            | Opcode::Bic
+            | Opcode::Cmn
+            | Opcode::Cmp
+            | Opcode::Eor
+            | Opcode::Lsl
+            | Opcode::Lsr
+            | Opcode::Mov
+            | Opcode::MovImm
+            | Opcode::MovReg
+            | Opcode::Mvn
+            | Opcode::Orr
+            | Opcode::Ror
+            | Opcode::Rrx
+            | Opcode::Rsb
+            | Opcode::Rsc
+            | Opcode::Sbc
+            | Opcode::Sub
+            | Opcode::Teq
+            | Opcode::Tst => true,
+            _ => false,
+        }
+    }
+    /// Returns true for hint instructions such as `nop` and `wfi`, which execute as NOP if not implemented.
+    pub const fn is_hint(self) -> bool {
+        false
+    }
+    /// Returns true for branch instructions which have a condition code.
+    pub const fn is_conditional_branch_capable(self) -> bool {
+        match self {
+            Opcode::B | Opcode::Bl | Opcode::Bx => true,
+            _ => false,
+        }
+    }
+    /// Returns the architecture extension of the opcode, e.g. `dsp`, or `None` if it's in the base instruction set.
+    /// Each extension has an `ext-<name>` cargo feature.
+    pub const fn extension(self) -> Option<&'static str> {
+        None
+    }
+    /// Returns the minimum number of arguments this opcode produces when parsed.
+    pub fn min_args(self, flags: &ParseFlags) -> usize {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_ARGS[self as usize][flags.ual as usize].0 as usize
+    }
+    /// Returns the maximum number of arguments this opcode produces when parsed.
+    pub fn max_args(self, flags: &ParseFlags) -> usize {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_ARGS[self as usize][flags.ual as usize].1 as usize
+    }
+    /// Returns every argument this opcode can produce, in order. Optional arguments only appear for some
+    /// modifier cases, so the parsed arguments are always a subsequence of this slice. See [`ArgMeta`] for
+    /// the positional contract of each opcode family.
+    pub fn arg_meta(self, flags: &ParseFlags) -> &'static [ArgMeta] {
+        if self == Opcode::Illegal {
+            return &[];
+        }
+        OPCODE_ARGS[self as usize][flags.ual as usize].2
+    }
+    /// Returns every opcode enabled by the cargo features except [`Opcode::Illegal`], in order of discriminant.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        OPCODES.iter().copied()
+    }
+    /// Returns the bits which are fixed by this opcode. A code with these bits equal to [`Self::pattern`] may
+    /// decode to this opcode, unless a more specific opcode also matches it. Returns 0 for [`Opcode::Illegal`].
+    pub fn bitmask(self) -> u32 {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_FIELDS[self as usize].0
+    }
+    /// Returns the values of the bits in [`Self::bitmask`], which is also the lowest code of this opcode. Returns
+    /// 0 for [`Opcode::Illegal`].
+    pub fn pattern(self) -> u32 {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_FIELDS[self as usize].1
+    }
+    /// Returns the fields and modifiers of this opcode which are not fixed by its pattern, in both syntaxes.
+    pub fn field_descs(self) -> &'static [FieldDesc] {
+        if self == Opcode::Illegal {
+            return &[];
+        }
+        OPCODE_FIELDS[self as usize].2
+    }
+    /// Returns the encodings of this opcode selected by `filter`, in increasing order. Encodings which decode to
+    /// another opcode with the given flags, e.g. a more specific opcode with an overlapping pattern, are skipped.
+    pub fn enumerate(
+        self,
+        filter: &FieldFilter,
+        flags: &ParseFlags,
+    ) -> impl Iterator<Item = u32> {
+        let (bitmask, pattern, fields) = match self {
+            Opcode::Illegal => (0, 0, None),
+            _ => {
+                let (bitmask, pattern, fields) = OPCODE_FIELDS[self as usize];
+                (bitmask, pattern, Some(fields))
+            }
+        };
+        let flags = *flags;
+        enumerate::enumerate(
+            bitmask,
+            pattern,
+            fields,
+            filter,
+            move |code| Ins::new(code, &flags).op == self,
+        )
+    }
+    /// Returns a one-line description of this opcode, e.g. "Add with Carry".
+    pub fn describe(self) -> &'static str {
+        if self == Opcode::Illegal {
+            return "Illegal or unknown";
+        }
+        OPCODE_DESCRIPTIONS[self as usize]
+    }
+    /// Finds the opcodes whose mnemonics best match `query`, with the best match first. Each mnemonic is matched with
+    /// all of its suffixes except conditions, e.g. `ldmdb` and its stack alias `ldmea`. See [`crate::fuzzy_score`] for
+    /// how matches are scored.
+    pub fn fuzzy_search(query: &str, limit: usize) -> Vec<(Self, u32)> {
+        crate::search::fuzzy_search(query, limit, OPCODE_SPELLINGS.iter().copied())
+    }
+    /// Returns the opcodes which have `mnemonic` as one of their mnemonics without a condition suffix, in either
+    /// syntax.
+    pub fn from_mnemonic(mnemonic: &str) -> impl Iterator<Item = Self> + '_ {
+        OPCODE_SPELLINGS
+            .iter()
+            .filter(move |(_, spellings)| spellings.contains(&mnemonic))
+            .map(|(opcode, _)| *opcode)
+    }
+    /// Encodes a parsed instruction as this opcode. The result decodes to this opcode and the same [`ParsedIns`]
+    /// with the given flags.
+    pub fn encode(
+        self,
+        ins: &ParsedIns,
+        flags: &ParseFlags,
+    ) -> Result<u32, EncodeError> {
+        if self == Opcode::Illegal {
+            return Err(EncodeError::UnknownMnemonic);
+        }
+        encode::encode_forms(
+            ENCODE_FORMS[self as usize],
+            ins,
+            flags.ual,
+            |code| {
+                let decoded = Ins::new(code, flags);
+                decoded.op == self && decoded.parse(flags) == *ins
+            },
+        )
+    }
 }
 impl Ins {
     /// Rn: First source operand register
+    ///
+    /// Reads bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_rn(&self) -> Reg {
         Reg {
@@ -1939,6 +12668,8 @@ impl Ins {
         }
     }
     /// Rn_wb: Source operand register with writeback
+    ///
+    /// Reads bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_rn_wb(&self) -> Reg {
         Reg {
@@ -1948,6 +12679,8 @@ impl Ins {
         }
     }
     /// Rn_deref: Base register
+    ///
+    /// Reads bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_rn_deref(&self) -> Reg {
         Reg {
@@ -1957,6 +12690,8 @@ impl Ins {
         }
     }
     /// Rn_deref_wb: Base register with writeback
+    ///
+    /// Reads bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_rn_deref_wb(&self) -> Reg {
         Reg {
@@ -1965,7 +12700,20 @@ impl Ins {
             writeback: true,
         }
     }
+    /// Rn_12: First source operand register
+    ///
+    /// Reads bits[15:12] of the instruction.
+    #[inline(always)]
+    pub fn field_rn_12(&self) -> Reg {
+        Reg {
+            deref: false,
+            reg: Register::parse(((self.code >> 12) & 0x0000000f)),
+            writeback: false,
+        }
+    }
     /// Rm: Second source operand register
+    ///
+    /// Reads bits[3:0] of the instruction.
     #[inline(always)]
     pub fn field_rm(&self) -> Reg {
         Reg {
@@ -1975,6 +12723,8 @@ impl Ins {
         }
     }
     /// Rd: Destination register
+    ///
+    /// Reads bits[15:12] of the instruction.
     #[inline(always)]
     pub fn field_rd(&self) -> Reg {
         Reg {
@@ -1984,6 +12734,8 @@ impl Ins {
         }
     }
     /// Rs: Register containing shift offset
+    ///
+    /// Reads bits[11:8] of the instruction.
     #[inline(always)]
     pub fn field_rs(&self) -> Reg {
         Reg {
@@ -1993,6 +12745,8 @@ impl Ins {
         }
     }
     /// Rt: Transferred register
+    ///
+    /// Reads bits[15:12] of the instruction.
     #[inline(always)]
     pub fn field_rt(&self) -> Reg {
         Reg {
@@ -2002,6 +12756,8 @@ impl Ins {
         }
     }
     /// RdHi: Upper 32-bit long destination register
+    ///
+    /// Reads bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_rdhi(&self) -> Reg {
         Reg {
@@ -2011,6 +12767,8 @@ impl Ins {
         }
     }
     /// RdLo: Lower 32-bit long destination register
+    ///
+    /// Reads bits[15:12] of the instruction.
     #[inline(always)]
     pub fn field_rdlo(&self) -> Reg {
         Reg {
@@ -2020,6 +12778,8 @@ impl Ins {
         }
     }
     /// registers: List of registers
+    ///
+    /// Reads bits[15:0] of the instruction.
     #[inline(always)]
     pub fn field_registers(&self) -> RegList {
         RegList {
@@ -2028,6 +12788,8 @@ impl Ins {
         }
     }
     /// registers_c: List of registers (with ^ suffix)
+    ///
+    /// Reads bits[15:0] of the instruction.
     #[inline(always)]
     pub fn field_registers_c(&self) -> RegList {
         RegList {
@@ -2036,6 +12798,8 @@ impl Ins {
         }
     }
     /// Rt_list: List of one register
+    ///
+    /// Reads bits[15:12] of the instruction.
     #[inline(always)]
     pub fn field_rt_list(&self) -> RegList {
         RegList {
@@ -2044,36 +12808,50 @@ impl Ins {
         }
     }
     /// CRn: First source coprocessor register
+    ///
+    /// Reads bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_crn(&self) -> CoReg {
         CoReg::parse(((self.code >> 16) & 0x0000000f))
     }
     /// CRm: Second source coprocessor register
+    ///
+    /// Reads bits[3:0] of the instruction.
     #[inline(always)]
     pub fn field_crm(&self) -> CoReg {
         CoReg::parse((self.code & 0x0000000f))
     }
     /// CRd: Destination coprocessor register
+    ///
+    /// Reads bits[15:12] of the instruction.
     #[inline(always)]
     pub fn field_crd(&self) -> CoReg {
         CoReg::parse(((self.code >> 12) & 0x0000000f))
     }
     /// immed_5: 5-bit immediate
+    ///
+    /// Reads bits[11:7] of the instruction.
     #[inline(always)]
     pub fn field_immed_5(&self) -> u32 {
         ((self.code >> 7) & 0x0000001f)
     }
-    /// rotated_immed_8: 8-bit immediate
+    /// rotated_immed_8: 8-bit immediate rotated right by twice the value of bits 8..12
+    ///
+    /// Reads bits[11:0] of the instruction.
     #[inline(always)]
     pub fn field_rotated_immed_8(&self) -> u32 {
         (self.code & 0x000000ff).rotate_right(((self.code >> 8) & 0x0000000f) << 1)
     }
     /// immed_24: 24-bit immediate
+    ///
+    /// Reads bits[23:0] of the instruction.
     #[inline(always)]
     pub fn field_immed_24(&self) -> u32 {
         (self.code & 0x00ffffff)
     }
     /// offset_8: 8-bit immediate offset
+    ///
+    /// Reads bits[23], bits[11:8], bits[3:0] of the instruction.
     #[inline(always)]
     pub fn field_offset_8(&self) -> OffsetImm {
         OffsetImm {
@@ -2086,6 +12864,8 @@ impl Ins {
         }
     }
     /// post_offset_8: 8-bit immediate post-indexed offset
+    ///
+    /// Reads bits[23], bits[11:8], bits[3:0] of the instruction.
     #[inline(always)]
     pub fn field_post_offset_8(&self) -> OffsetImm {
         OffsetImm {
@@ -2098,6 +12878,8 @@ impl Ins {
         }
     }
     /// offset_12: 12-bit immediate offset
+    ///
+    /// Reads bits[23], bits[11:0] of the instruction.
     #[inline(always)]
     pub fn field_offset_12(&self) -> OffsetImm {
         OffsetImm {
@@ -2109,6 +12891,8 @@ impl Ins {
         }
     }
     /// post_offset_12: 12-bit immediate post-indexed offset
+    ///
+    /// Reads bits[23], bits[11:0] of the instruction.
     #[inline(always)]
     pub fn field_post_offset_12(&self) -> OffsetImm {
         OffsetImm {
@@ -2120,6 +12904,8 @@ impl Ins {
         }
     }
     /// shift_imm: Immediate shift offset
+    ///
+    /// Reads bits[11:5] of the instruction.
     #[inline(always)]
     pub fn field_shift_imm(&self) -> ShiftImm {
         ShiftImm {
@@ -2134,6 +12920,8 @@ impl Ins {
         }
     }
     /// shift_reg: Register shift offset
+    ///
+    /// Reads bits[11:8], bits[6:5] of the instruction.
     #[inline(always)]
     pub fn field_shift_reg(&self) -> ShiftReg {
         ShiftReg {
@@ -2142,11 +12930,15 @@ impl Ins {
         }
     }
     /// rrx: Rotate right with extend
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_rrx(&self) -> Shift {
         Shift::parse(4)
     }
     /// reg_offset: Register offset
+    ///
+    /// Reads bits[23], bits[3:0] of the instruction.
     #[inline(always)]
     pub fn field_reg_offset(&self) -> OffsetReg {
         OffsetReg {
@@ -2156,6 +12948,8 @@ impl Ins {
         }
     }
     /// reg_post_offset: Register post-indexed offset
+    ///
+    /// Reads bits[23], bits[3:0] of the instruction.
     #[inline(always)]
     pub fn field_reg_post_offset(&self) -> OffsetReg {
         OffsetReg {
@@ -2165,11 +12959,15 @@ impl Ins {
         }
     }
     /// R: Move SPSR (1) or CPSR (0)
+    ///
+    /// Reads bits[22] of the instruction.
     #[inline(always)]
     pub fn field_r(&self) -> StatusReg {
         StatusReg::parse(((self.code >> 22) & 0x00000001))
     }
     /// coproc_offset: 8-bit immediate coprocessor offset
+    ///
+    /// Reads bits[23], bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_coproc_offset(&self) -> OffsetImm {
         OffsetImm {
@@ -2181,6 +12979,8 @@ impl Ins {
         }
     }
     /// coproc_post_offset: 8-bit immediate coprocessor offset
+    ///
+    /// Reads bits[23], bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_coproc_post_offset(&self) -> OffsetImm {
         OffsetImm {
@@ -2192,16 +12992,22 @@ impl Ins {
         }
     }
     /// option: Additional instruction options for coprocessor
+    ///
+    /// Reads bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_option(&self) -> u32 {
         (self.code & 0x000000ff)
     }
     /// branch_offset: 24-bit signed B/BL target offset
+    ///
+    /// Reads bits[23:0] of the instruction.
     #[inline(always)]
     pub fn field_branch_offset(&self) -> i32 {
-        (((((self.code & 0x00ffffff) << 2) + 8) as i32) << 8 >> 8) as i32
+        (((((self.code & 0x00ffffff) << 2) as i32) << 6 >> 6) + 8) as i32
     }
     /// field_mask: Status fields to set
+    ///
+    /// Reads bits[22], bits[19:16] of the instruction.
     #[inline(always)]
     pub fn field_field_mask(&self) -> StatusMask {
         StatusMask {
@@ -2213,36 +13019,50 @@ impl Ins {
         }
     }
     /// codat_opcode_1: Coprocessor operation to perform (user-defined, used by CDP instruction)
+    ///
+    /// Reads bits[23:20] of the instruction.
     #[inline(always)]
     pub fn field_codat_opcode_1(&self) -> u32 {
         ((self.code >> 20) & 0x0000000f)
     }
     /// comov_opcode_1: Coprocessor operation to perform (user-defined, used by MCR/MRC instructions)
+    ///
+    /// Reads bits[23:21] of the instruction.
     #[inline(always)]
     pub fn field_comov_opcode_1(&self) -> u32 {
         ((self.code >> 21) & 0x00000007)
     }
     /// opcode_2: Coprocessor operation to perform (user-defined)
+    ///
+    /// Reads bits[7:5] of the instruction.
     #[inline(always)]
     pub fn field_opcode_2(&self) -> u32 {
         ((self.code >> 5) & 0x00000007)
     }
     /// coproc: Coprocessor number
+    ///
+    /// Reads bits[11:8] of the instruction.
     #[inline(always)]
     pub fn field_coproc(&self) -> u32 {
         ((self.code >> 8) & 0x0000000f)
     }
     /// S: Update condition status flags
+    ///
+    /// Reads bits[20] of the instruction.
     #[inline(always)]
     pub const fn modifier_s(&self) -> bool {
         (self.code & 0x00100000) == 0x00100000
     }
     /// L: Long coprocessor load (e.g. double instead of float)
+    ///
+    /// Reads bits[22] of the instruction.
     #[inline(always)]
     pub const fn modifier_l(&self) -> bool {
         (self.code & 0x00400000) == 0x00400000
     }
     /// shift_arg: Second operand for shift instructions
+    ///
+    /// Reads bits[7], bits[4] of the instruction.
     #[inline(always)]
     pub const fn modifier_shift_arg(&self) -> ShiftArg {
         if (self.code & 0x00000090) == 0x00000010 {
@@ -2254,6 +13074,8 @@ impl Ins {
         }
     }
     /// cond: Condition code
+    ///
+    /// Reads bits[31:28] of the instruction.
     #[inline(always)]
     pub const fn modifier_cond(&self) -> Cond {
         match self.code & 0xf0000000 {
@@ -2276,6 +13098,8 @@ impl Ins {
         }
     }
     /// addr_data: Data-processing operands
+    ///
+    /// Reads bits[27:25], bits[11:4] of the instruction.
     #[inline(always)]
     pub const fn modifier_addr_data(&self) -> AddrData {
         if (self.code & 0x0e000ff0) == 0x00000000 {
@@ -2293,6 +13117,8 @@ impl Ins {
         }
     }
     /// addr_ldr_str: Load and Store Word or Unsigned Byte
+    ///
+    /// Reads bits[27:24], bits[21], bits[11:4] of the instruction.
     #[inline(always)]
     pub const fn modifier_addr_ldr_str(&self) -> AddrLdrStr {
         if (self.code & 0x0f200ff0) == 0x07000000 {
@@ -2324,6 +13150,8 @@ impl Ins {
         }
     }
     /// addr_ldrt_strt: Load and Store Word or Unsigned Byte with Translation
+    ///
+    /// Reads bits[27:24], bits[21], bits[11:4] of the instruction.
     #[inline(always)]
     pub const fn modifier_addr_ldrt_strt(&self) -> AddrLdrtStrt {
         if (self.code & 0x0f200ff0) == 0x06200000 {
@@ -2337,6 +13165,8 @@ impl Ins {
         }
     }
     /// addr_misc_ldr_str: Miscellaneous Loads and Stores
+    ///
+    /// Reads bits[27:24], bits[22:21], bits[11:7], bits[4] of the instruction.
     #[inline(always)]
     pub const fn modifier_addr_misc_ldr_str(&self) -> AddrMiscLdrStr {
         if (self.code & 0x0f600f90) == 0x01000090 {
@@ -2356,6 +13186,8 @@ impl Ins {
         }
     }
     /// addr_ldm_stm: Load and Store Multiple
+    ///
+    /// Reads bits[24:23] of the instruction.
     #[inline(always)]
     pub const fn modifier_addr_ldm_stm(&self) -> AddrLdmStm {
         match self.code & 0x01800000 {
@@ -2367,6 +13199,8 @@ impl Ins {
         }
     }
     /// addr_coproc: Load and Store Coprocessor
+    ///
+    /// Reads bits[24:23], bits[21] of the instruction.
     #[inline(always)]
     pub const fn modifier_addr_coproc(&self) -> AddrCoproc {
         if (self.code & 0x01a00000) == 0x00800000 {
@@ -2381,9 +13215,5885 @@ impl Ins {
             AddrCoproc::Illegal
         }
     }
+    /// Returns the fields and modifiers of this instruction which are not fixed by its opcode, in both syntaxes. Unlike
+    /// [`Opcode::field_descs`], fields which only belong to other modifier cases are left out, e.g. the offset
+    /// register of an `ldr` with an immediate offset.
+    pub fn field_descs(&self) -> &'static [FieldDesc] {
+        match self.op {
+            Opcode::Adc => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Add => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::And => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Asr => {
+                match self.modifier_shift_arg() {
+                    ShiftArg::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "immed_5",
+                                bitmask: 0x00000f80,
+                            },
+                        ]
+                    }
+                    ShiftArg::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "Rs",
+                                bitmask: 0x00000f00,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::B => {
+                &[
+                    FieldDesc {
+                        name: "branch_offset",
+                        bitmask: 0x00ffffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Bl => {
+                &[
+                    FieldDesc {
+                        name: "branch_offset",
+                        bitmask: 0x00ffffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Bic => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Bx => {
+                &[
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Cdp => {
+                &[
+                    FieldDesc {
+                        name: "coproc",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "codat_opcode_1",
+                        bitmask: 0x00f00000,
+                    },
+                    FieldDesc {
+                        name: "CRd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "CRn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "CRm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "opcode_2",
+                        bitmask: 0x000000e0,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Cmn => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Cmp => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Eor => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Ldc => {
+                match self.modifier_addr_coproc() {
+                    AddrCoproc::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "coproc_offset",
+                                bitmask: 0x008000ff,
+                            },
+                        ]
+                    }
+                    AddrCoproc::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "coproc_offset",
+                                bitmask: 0x008000ff,
+                            },
+                        ]
+                    }
+                    AddrCoproc::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "coproc_post_offset",
+                                bitmask: 0x008000ff,
+                            },
+                        ]
+                    }
+                    AddrCoproc::Unidx => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "option",
+                                bitmask: 0x000000ff,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdmW => {
+                &[
+                    FieldDesc {
+                        name: "Rn_wb",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::Ldm => {
+                &[
+                    FieldDesc {
+                        name: "Rn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::LdmP => {
+                &[
+                    FieldDesc {
+                        name: "Rn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers_c",
+                        bitmask: 0x00007fff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::LdmPcW => {
+                &[
+                    FieldDesc {
+                        name: "Rn_wb",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers_c",
+                        bitmask: 0x00007fff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::LdmPc => {
+                &[
+                    FieldDesc {
+                        name: "Rn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers_c",
+                        bitmask: 0x00007fff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::Ldr => {
+                match self.modifier_addr_ldr_str() {
+                    AddrLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Scl => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPpost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdrB => {
+                match self.modifier_addr_ldr_str() {
+                    AddrLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Scl => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPpost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdrBt => {
+                match self.modifier_addr_ldrt_strt() {
+                    AddrLdrtStrt::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdrH => {
+                match self.modifier_addr_misc_ldr_str() {
+                    AddrMiscLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdrSb => {
+                match self.modifier_addr_misc_ldr_str() {
+                    AddrMiscLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdrSh => {
+                match self.modifier_addr_misc_ldr_str() {
+                    AddrMiscLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::LdrT => {
+                match self.modifier_addr_ldrt_strt() {
+                    AddrLdrtStrt::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Lsl => {
+                match self.modifier_shift_arg() {
+                    ShiftArg::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "immed_5",
+                                bitmask: 0x00000f80,
+                            },
+                        ]
+                    }
+                    ShiftArg::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "Rs",
+                                bitmask: 0x00000f00,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Lsr => {
+                match self.modifier_shift_arg() {
+                    ShiftArg::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "immed_5",
+                                bitmask: 0x00000f80,
+                            },
+                        ]
+                    }
+                    ShiftArg::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "Rs",
+                                bitmask: 0x00000f00,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Mcr => {
+                &[
+                    FieldDesc {
+                        name: "coproc",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "comov_opcode_1",
+                        bitmask: 0x00e00000,
+                    },
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "CRn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "CRm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "opcode_2",
+                        bitmask: 0x000000e0,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Mla => {
+                &[
+                    FieldDesc {
+                        name: "RdHi",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "Rn_12",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Mov => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::MovImm => {
+                &[
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "rotated_immed_8",
+                        bitmask: 0x00000fff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::MovReg => {
+                &[
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Mrc => {
+                &[
+                    FieldDesc {
+                        name: "coproc",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "comov_opcode_1",
+                        bitmask: 0x00e00000,
+                    },
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "CRn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "CRm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "opcode_2",
+                        bitmask: 0x000000e0,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Mrs => {
+                &[
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "R",
+                        bitmask: 0x00400000,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::MsrI => {
+                &[
+                    FieldDesc {
+                        name: "field_mask",
+                        bitmask: 0x004f0000,
+                    },
+                    FieldDesc {
+                        name: "rotated_immed_8",
+                        bitmask: 0x00000fff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Msr => {
+                &[
+                    FieldDesc {
+                        name: "field_mask",
+                        bitmask: 0x004f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Mul => {
+                &[
+                    FieldDesc {
+                        name: "RdHi",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Mvn => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Orr => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::PopM => {
+                &[
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::PopR => {
+                &[
+                    FieldDesc {
+                        name: "Rt_list",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::PushM => {
+                &[
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::PushR => {
+                &[
+                    FieldDesc {
+                        name: "Rt_list",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Ror => {
+                match self.modifier_shift_arg() {
+                    ShiftArg::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "immed_5",
+                                bitmask: 0x00000f80,
+                            },
+                        ]
+                    }
+                    ShiftArg::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "shift_arg",
+                                bitmask: 0x00000090,
+                            },
+                            FieldDesc {
+                                name: "Rs",
+                                bitmask: 0x00000f00,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Rrx => {
+                &[
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Rsb => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Rsc => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Sbc => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Smlal => {
+                &[
+                    FieldDesc {
+                        name: "RdLo",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "RdHi",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Smull => {
+                &[
+                    FieldDesc {
+                        name: "RdLo",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "RdHi",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Stc => {
+                match self.modifier_addr_coproc() {
+                    AddrCoproc::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "coproc_offset",
+                                bitmask: 0x008000ff,
+                            },
+                        ]
+                    }
+                    AddrCoproc::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "coproc_offset",
+                                bitmask: 0x008000ff,
+                            },
+                        ]
+                    }
+                    AddrCoproc::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "coproc_post_offset",
+                                bitmask: 0x008000ff,
+                            },
+                        ]
+                    }
+                    AddrCoproc::Unidx => {
+                        &[
+                            FieldDesc {
+                                name: "coproc",
+                                bitmask: 0x00000f00,
+                            },
+                            FieldDesc {
+                                name: "CRd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "L",
+                                bitmask: 0x00400000,
+                            },
+                            FieldDesc {
+                                name: "addr_coproc",
+                                bitmask: 0x01a00000,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "option",
+                                bitmask: 0x000000ff,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Stm => {
+                &[
+                    FieldDesc {
+                        name: "Rn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::StmW => {
+                &[
+                    FieldDesc {
+                        name: "Rn_wb",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::StmP => {
+                &[
+                    FieldDesc {
+                        name: "Rn",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "registers_c",
+                        bitmask: 0x0000ffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "addr_ldm_stm",
+                        bitmask: 0x01800000,
+                    },
+                ]
+            }
+            Opcode::Str => {
+                match self.modifier_addr_ldr_str() {
+                    AddrLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Scl => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPpost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::StrB => {
+                match self.modifier_addr_ldr_str() {
+                    AddrLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Scl => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrLdrStr::RrxPpost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldr_str",
+                                bitmask: 0x03200ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::StrBt => {
+                match self.modifier_addr_ldrt_strt() {
+                    AddrLdrtStrt::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::StrH => {
+                match self.modifier_addr_misc_ldr_str() {
+                    AddrMiscLdrStr::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPre => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref_wb",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_8",
+                                bitmask: 0x00800f0f,
+                            },
+                        ]
+                    }
+                    AddrMiscLdrStr::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_misc_ldr_str",
+                                bitmask: 0x01600f00,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::StrT => {
+                match self.modifier_addr_ldrt_strt() {
+                    AddrLdrtStrt::ImmPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "post_offset_12",
+                                bitmask: 0x00800fff,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::RegPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                        ]
+                    }
+                    AddrLdrtStrt::SclPost => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_ldrt_strt",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rn_deref",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "reg_post_offset",
+                                bitmask: 0x0080000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Sub => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rd",
+                                bitmask: 0x0000f000,
+                            },
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "S",
+                                bitmask: 0x00100000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Svc => {
+                &[
+                    FieldDesc {
+                        name: "immed_24",
+                        bitmask: 0x00ffffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Swi => {
+                &[
+                    FieldDesc {
+                        name: "immed_24",
+                        bitmask: 0x00ffffff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Swp => {
+                &[
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rn_deref",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Swpb => {
+                &[
+                    FieldDesc {
+                        name: "Rd",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rn_deref",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                ]
+            }
+            Opcode::Teq => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Tst => {
+                match self.modifier_addr_data() {
+                    AddrData::Imm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "rotated_immed_8",
+                                bitmask: 0x00000fff,
+                            },
+                        ]
+                    }
+                    AddrData::Reg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftImm => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_imm",
+                                bitmask: 0x00000fe0,
+                            },
+                        ]
+                    }
+                    AddrData::ShiftReg => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                            FieldDesc {
+                                name: "shift_reg",
+                                bitmask: 0x00000f60,
+                            },
+                        ]
+                    }
+                    AddrData::Rrx => {
+                        &[
+                            FieldDesc {
+                                name: "Rn",
+                                bitmask: 0x000f0000,
+                            },
+                            FieldDesc {
+                                name: "cond",
+                                bitmask: 0xf0000000,
+                            },
+                            FieldDesc {
+                                name: "addr_data",
+                                bitmask: 0x02000ff0,
+                            },
+                            FieldDesc {
+                                name: "Rm",
+                                bitmask: 0x0000000f,
+                            },
+                        ]
+                    }
+                    _ => &[],
+                }
+            }
+            Opcode::Umlal => {
+                &[
+                    FieldDesc {
+                        name: "RdLo",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "RdHi",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            Opcode::Umull => {
+                &[
+                    FieldDesc {
+                        name: "RdLo",
+                        bitmask: 0x0000f000,
+                    },
+                    FieldDesc {
+                        name: "RdHi",
+                        bitmask: 0x000f0000,
+                    },
+                    FieldDesc {
+                        name: "Rm",
+                        bitmask: 0x0000000f,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000f00,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0xf0000000,
+                    },
+                    FieldDesc {
+                        name: "S",
+                        bitmask: 0x00100000,
+                    },
+                ]
+            }
+            _ => &[],
+        }
+    }
+    /// Returns the name and raw value of each field of [`Self::field_descs`], see [`FieldDesc::value`].
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, u32)> {
+        let code = self.code;
+        self.field_descs().iter().map(move |field| (field.name, field.value(code)))
+    }
+    #[inline(always)]
+    fn modifier_cond_condition(&self) -> Condition {
+        match self.modifier_cond() {
+            Cond::Eq => Condition::Eq,
+            Cond::Ne => Condition::Ne,
+            Cond::Hs => Condition::Hs,
+            Cond::Lo => Condition::Lo,
+            Cond::Mi => Condition::Mi,
+            Cond::Pl => Condition::Pl,
+            Cond::Vs => Condition::Vs,
+            Cond::Vc => Condition::Vc,
+            Cond::Hi => Condition::Hi,
+            Cond::Ls => Condition::Ls,
+            Cond::Ge => Condition::Ge,
+            Cond::Lt => Condition::Lt,
+            Cond::Gt => Condition::Gt,
+            Cond::Le => Condition::Le,
+            Cond::Al => Condition::Al,
+            Cond::Illegal => Condition::Nv,
+        }
+    }
+    /// Returns the condition under which the instruction is executed. Instructions without a condition code return
+    /// [`Condition::Al`], except for those in the unconditional instruction space which return [`Condition::Nv`].
+    pub fn condition(&self) -> Condition {
+        match self.op {
+            Opcode::Adc => self.modifier_cond_condition(),
+            Opcode::Add => self.modifier_cond_condition(),
+            Opcode::And => self.modifier_cond_condition(),
+            Opcode::Asr => self.modifier_cond_condition(),
+            Opcode::B => self.modifier_cond_condition(),
+            Opcode::Bl => self.modifier_cond_condition(),
+            Opcode::Bic => self.modifier_cond_condition(),
+            Opcode::Bx => self.modifier_cond_condition(),
+            Opcode::Cdp => self.modifier_cond_condition(),
+            Opcode::Cmn => self.modifier_cond_condition(),
+            Opcode::Cmp => self.modifier_cond_condition(),
+            Opcode::Eor => self.modifier_cond_condition(),
+            Opcode::Ldc => self.modifier_cond_condition(),
+            Opcode::LdmW => self.modifier_cond_condition(),
+            Opcode::Ldm => self.modifier_cond_condition(),
+            Opcode::LdmP => self.modifier_cond_condition(),
+            Opcode::LdmPcW => self.modifier_cond_condition(),
+            Opcode::LdmPc => self.modifier_cond_condition(),
+            Opcode::Ldr => self.modifier_cond_condition(),
+            Opcode::LdrB => self.modifier_cond_condition(),
+            Opcode::LdrBt => self.modifier_cond_condition(),
+            Opcode::LdrH => self.modifier_cond_condition(),
+            Opcode::LdrSb => self.modifier_cond_condition(),
+            Opcode::LdrSh => self.modifier_cond_condition(),
+            Opcode::LdrT => self.modifier_cond_condition(),
+            Opcode::Lsl => self.modifier_cond_condition(),
+            Opcode::Lsr => self.modifier_cond_condition(),
+            Opcode::Mcr => self.modifier_cond_condition(),
+            Opcode::Mla => self.modifier_cond_condition(),
+            Opcode::Mov => self.modifier_cond_condition(),
+            Opcode::MovImm => self.modifier_cond_condition(),
+            Opcode::MovReg => self.modifier_cond_condition(),
+            Opcode::Mrc => self.modifier_cond_condition(),
+            Opcode::Mrs => self.modifier_cond_condition(),
+            Opcode::MsrI => self.modifier_cond_condition(),
+            Opcode::Msr => self.modifier_cond_condition(),
+            Opcode::Mul => self.modifier_cond_condition(),
+            Opcode::Mvn => self.modifier_cond_condition(),
+            Opcode::Orr => self.modifier_cond_condition(),
+            Opcode::PopM => self.modifier_cond_condition(),
+            Opcode::PopR => self.modifier_cond_condition(),
+            Opcode::PushM => self.modifier_cond_condition(),
+            Opcode::PushR => self.modifier_cond_condition(),
+            Opcode::Ror => self.modifier_cond_condition(),
+            Opcode::Rrx => self.modifier_cond_condition(),
+            Opcode::Rsb => self.modifier_cond_condition(),
+            Opcode::Rsc => self.modifier_cond_condition(),
+            Opcode::Sbc => self.modifier_cond_condition(),
+            Opcode::Smlal => self.modifier_cond_condition(),
+            Opcode::Smull => self.modifier_cond_condition(),
+            Opcode::Stc => self.modifier_cond_condition(),
+            Opcode::Stm => self.modifier_cond_condition(),
+            Opcode::StmW => self.modifier_cond_condition(),
+            Opcode::StmP => self.modifier_cond_condition(),
+            Opcode::Str => self.modifier_cond_condition(),
+            Opcode::StrB => self.modifier_cond_condition(),
+            Opcode::StrBt => self.modifier_cond_condition(),
+            Opcode::StrH => self.modifier_cond_condition(),
+            Opcode::StrT => self.modifier_cond_condition(),
+            Opcode::Sub => self.modifier_cond_condition(),
+            Opcode::Svc => self.modifier_cond_condition(),
+            Opcode::Swi => self.modifier_cond_condition(),
+            Opcode::Swp => self.modifier_cond_condition(),
+            Opcode::Swpb => self.modifier_cond_condition(),
+            Opcode::Teq => self.modifier_cond_condition(),
+            Opcode::Tst => self.modifier_cond_condition(),
+            Opcode::Umlal => self.modifier_cond_condition(),
+            Opcode::Umull => self.modifier_cond_condition(),
+            _ => Condition::Al,
+        }
+    }
+    /// Returns a description of why the encoding is UNPREDICTABLE, or `None` if it's not. Only the constraints in the
+    /// ISA specification are checked, so this is not exhaustive.
+    pub const fn unpredictable_reason(&self) -> Option<&'static str> {
+        match self.op {
+            Opcode::LdmW => {
+                if (self.code >> ((self.code >> 16) & 0x0000000f)) & 1 == 1 {
+                    return Some("Writeback to a loaded register");
+                }
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::Ldm => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::LdmP => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::LdmPcW => {
+                if (self.code >> ((self.code >> 16) & 0x0000000f)) & 1 == 1 {
+                    return Some("Writeback to a loaded register");
+                }
+            }
+            Opcode::Ldr => {
+                if (((self.code >> 24) & 0x00000001) == 0
+                    || ((self.code >> 21) & 0x00000001) == 1)
+                    && ((self.code >> 16) & 0x0000000f)
+                        == ((self.code >> 12) & 0x0000000f)
+                {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::LdrB => {
+                if (((self.code >> 24) & 0x00000001) == 0
+                    || ((self.code >> 21) & 0x00000001) == 1)
+                    && ((self.code >> 16) & 0x0000000f)
+                        == ((self.code >> 12) & 0x0000000f)
+                {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::LdrBt => {
+                if ((self.code >> 16) & 0x0000000f) == ((self.code >> 12) & 0x0000000f) {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::LdrH => {
+                if ((self.code >> 24) & 0x00000001) == 0
+                    && ((self.code >> 21) & 0x00000001) == 1
+                {
+                    return Some("Post-indexed with writeback");
+                }
+                if (((self.code >> 24) & 0x00000001) == 0
+                    || ((self.code >> 21) & 0x00000001) == 1)
+                    && ((self.code >> 16) & 0x0000000f)
+                        == ((self.code >> 12) & 0x0000000f)
+                {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::LdrSb => {
+                if ((self.code >> 24) & 0x00000001) == 0
+                    && ((self.code >> 21) & 0x00000001) == 1
+                {
+                    return Some("Post-indexed with writeback");
+                }
+                if (((self.code >> 24) & 0x00000001) == 0
+                    || ((self.code >> 21) & 0x00000001) == 1)
+                    && ((self.code >> 16) & 0x0000000f)
+                        == ((self.code >> 12) & 0x0000000f)
+                {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::LdrSh => {
+                if ((self.code >> 24) & 0x00000001) == 0
+                    && ((self.code >> 21) & 0x00000001) == 1
+                {
+                    return Some("Post-indexed with writeback");
+                }
+                if (((self.code >> 24) & 0x00000001) == 0
+                    || ((self.code >> 21) & 0x00000001) == 1)
+                    && ((self.code >> 16) & 0x0000000f)
+                        == ((self.code >> 12) & 0x0000000f)
+                {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::LdrT => {
+                if ((self.code >> 16) & 0x0000000f) == ((self.code >> 12) & 0x0000000f) {
+                    return Some("Writeback to the loaded register");
+                }
+            }
+            Opcode::Mla => {
+                if ((self.code >> 16) & 0x0000000f) == 15 {
+                    return Some("PC as destination register");
+                }
+                if ((self.code >> 16) & 0x0000000f) == (self.code & 0x0000000f) {
+                    return Some("Destination register same as first operand");
+                }
+            }
+            Opcode::Mul => {
+                if ((self.code >> 16) & 0x0000000f) == 15 {
+                    return Some("PC as destination register");
+                }
+                if ((self.code >> 16) & 0x0000000f) == (self.code & 0x0000000f) {
+                    return Some("Destination register same as first operand");
+                }
+            }
+            Opcode::PopM => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::PushM => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::Smlal => {
+                if ((self.code >> 12) & 0x0000000f) == 15
+                    || ((self.code >> 16) & 0x0000000f) == 15
+                {
+                    return Some("PC as destination register");
+                }
+                if ((self.code >> 12) & 0x0000000f) == ((self.code >> 16) & 0x0000000f) {
+                    return Some("Same destination register for both halves");
+                }
+            }
+            Opcode::Smull => {
+                if ((self.code >> 12) & 0x0000000f) == 15
+                    || ((self.code >> 16) & 0x0000000f) == 15
+                {
+                    return Some("PC as destination register");
+                }
+                if ((self.code >> 12) & 0x0000000f) == ((self.code >> 16) & 0x0000000f) {
+                    return Some("Same destination register for both halves");
+                }
+            }
+            Opcode::Stm => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::StmW => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::StmP => {
+                if (self.code & 0x0000ffff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::StrH => {
+                if ((self.code >> 24) & 0x00000001) == 0
+                    && ((self.code >> 21) & 0x00000001) == 1
+                {
+                    return Some("Post-indexed with writeback");
+                }
+            }
+            Opcode::Umlal => {
+                if ((self.code >> 12) & 0x0000000f) == 15
+                    || ((self.code >> 16) & 0x0000000f) == 15
+                {
+                    return Some("PC as destination register");
+                }
+                if ((self.code >> 12) & 0x0000000f) == ((self.code >> 16) & 0x0000000f) {
+                    return Some("Same destination register for both halves");
+                }
+            }
+            Opcode::Umull => {
+                if ((self.code >> 12) & 0x0000000f) == 15
+                    || ((self.code >> 16) & 0x0000000f) == 15
+                {
+                    return Some("PC as destination register");
+                }
+                if ((self.code >> 12) & 0x0000000f) == ((self.code >> 16) & 0x0000000f) {
+                    return Some("Same destination register for both halves");
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+    /// Returns true if the encoding is UNPREDICTABLE, see [`Self::unpredictable_reason`].
+    pub const fn is_unpredictable(&self) -> bool {
+        self.unpredictable_reason().is_some()
+    }
 }
 /// shift_arg: Second operand for shift instructions
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ShiftArg {
     Illegal,
     /// imm: Immediate
@@ -2392,7 +19102,7 @@ pub enum ShiftArg {
     Reg,
 }
 /// cond: Condition code
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Cond {
     Illegal,
     /// eq: Equal
@@ -2427,7 +19137,7 @@ pub enum Cond {
     Al,
 }
 /// addr_data: Data-processing operands
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AddrData {
     Illegal,
     /// imm: Immediate
@@ -2442,7 +19152,7 @@ pub enum AddrData {
     Rrx,
 }
 /// addr_ldr_str: Load and Store Word or Unsigned Byte
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AddrLdrStr {
     Illegal,
     /// imm: Immediate offset
@@ -2471,7 +19181,7 @@ pub enum AddrLdrStr {
     RrxPpost,
 }
 /// addr_ldrt_strt: Load and Store Word or Unsigned Byte with Translation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AddrLdrtStrt {
     Illegal,
     /// imm_post: Immediate post-indexed
@@ -2482,7 +19192,7 @@ pub enum AddrLdrtStrt {
     SclPost,
 }
 /// addr_misc_ldr_str: Miscellaneous Loads and Stores
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AddrMiscLdrStr {
     Illegal,
     /// imm: Immediate offset
@@ -2499,7 +19209,7 @@ pub enum AddrMiscLdrStr {
     RegPost,
 }
 /// addr_ldm_stm: Load and Store Multiple
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AddrLdmStm {
     Illegal,
     /// ia: Increment After
@@ -2512,7 +19222,7 @@ pub enum AddrLdmStm {
     Db,
 }
 /// addr_coproc: Load and Store Coprocessor
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AddrCoproc {
     Illegal,
     /// imm: Immediate offset
@@ -2524,7 +19234,9 @@ pub enum AddrCoproc {
     /// unidx: Unindexed
     Unidx,
 }
+#[inline]
 fn parse_adc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -6457,9 +23169,12 @@ fn parse_adc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -10392,9 +27107,12 @@ fn parse_add(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_and(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -14327,9 +31045,12 @@ fn parse_and(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_asr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_shift_arg()) {
         (true, Cond::Eq, ShiftArg::Imm) => {
             ParsedIns {
@@ -15125,8 +31846,11 @@ fn parse_asr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -15337,8 +32061,11 @@ fn parse_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_bl(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -15549,8 +32276,11 @@ fn parse_bl(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_bic(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -19483,9 +36213,12 @@ fn parse_bic(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_bx(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -19696,8 +36429,11 @@ fn parse_bx(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cdp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -19908,8 +36644,11 @@ fn parse_cdp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cmn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_addr_data()) {
         (Cond::Eq, AddrData::Imm) => {
             ParsedIns {
@@ -20900,8 +37639,11 @@ fn parse_cmn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cmp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_addr_data()) {
         (Cond::Eq, AddrData::Imm) => {
             ParsedIns {
@@ -21892,8 +38634,11 @@ fn parse_cmp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_eor(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -25826,9 +42571,12 @@ fn parse_eor(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (
             ins.modifier_l(),
@@ -28989,9 +45737,12 @@ fn parse_ldc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldm_w(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -30584,9 +47335,12 @@ fn parse_ldm_w(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -32179,9 +48933,12 @@ fn parse_ldm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldm_p(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -33774,9 +50531,12 @@ fn parse_ldm_p(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldm_pc_w(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -35369,9 +52129,12 @@ fn parse_ldm_pc_w(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldm_pc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -36964,9 +53727,12 @@ fn parse_ldm_pc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_addr_ldr_str()) {
         (Cond::Eq, AddrLdrStr::Imm) => {
             ParsedIns {
@@ -39322,8 +56088,11 @@ fn parse_ldr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_ldr_str()) {
             (Cond::Eq, AddrLdrStr::Imm) => {
@@ -44036,9 +60805,12 @@ fn parse_ldr_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_bt(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_ldrt_strt()) {
             (Cond::Eq, AddrLdrtStrt::ImmPost) => {
@@ -45241,9 +62013,12 @@ fn parse_ldr_bt(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_h(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_misc_ldr_str()) {
             (Cond::Eq, AddrMiscLdrStr::Imm) => {
@@ -47616,9 +64391,12 @@ fn parse_ldr_h(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_sb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_misc_ldr_str()) {
             (Cond::Eq, AddrMiscLdrStr::Imm) => {
@@ -49991,9 +66769,12 @@ fn parse_ldr_sb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_sh(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_misc_ldr_str()) {
             (Cond::Eq, AddrMiscLdrStr::Imm) => {
@@ -52366,9 +69147,12 @@ fn parse_ldr_sh(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_t(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_ldrt_strt()) {
             (Cond::Eq, AddrLdrtStrt::ImmPost) => {
@@ -53571,9 +70355,12 @@ fn parse_ldr_t(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_lsl(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_shift_arg()) {
         (true, Cond::Eq, ShiftArg::Imm) => {
             ParsedIns {
@@ -54369,8 +71156,11 @@ fn parse_lsl(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_lsr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_shift_arg()) {
         (true, Cond::Eq, ShiftArg::Imm) => {
             ParsedIns {
@@ -55166,8 +71956,11 @@ fn parse_lsr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mcr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -55378,18 +72171,21 @@ fn parse_mcr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond()) {
             (true, Cond::Eq) => {
                 ParsedIns {
                     mnemonic: "mlaseq",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55399,10 +72195,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlaeq",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55412,10 +72208,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasne",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55425,10 +72221,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlane",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55438,10 +72234,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlashs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55451,10 +72247,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlahs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55464,10 +72260,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlaslo",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55477,10 +72273,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalo",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55490,10 +72286,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasmi",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55503,10 +72299,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlami",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55516,10 +72312,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlaspl",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55529,10 +72325,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlapl",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55542,10 +72338,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasvs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55555,10 +72351,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlavs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55568,10 +72364,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasvc",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55581,10 +72377,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlavc",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55594,10 +72390,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlashi",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55607,10 +72403,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlahi",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55620,10 +72416,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasls",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55633,10 +72429,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlals",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55646,10 +72442,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasge",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55659,10 +72455,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlage",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55672,10 +72468,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlaslt",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55685,10 +72481,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalt",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55698,10 +72494,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasgt",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55711,10 +72507,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlagt",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55724,10 +72520,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlasle",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55737,10 +72533,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlale",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55750,10 +72546,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlas",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55763,10 +72559,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mla",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55792,10 +72588,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlaeqs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55805,10 +72601,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlanes",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55818,10 +72614,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlahss",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55831,10 +72627,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalos",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55844,10 +72640,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlamis",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55857,10 +72653,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlapls",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55870,10 +72666,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlavss",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55883,10 +72679,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlavcs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55896,10 +72692,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlahis",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55909,10 +72705,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalss",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55922,10 +72718,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlages",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55935,10 +72731,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalts",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55948,10 +72744,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlagts",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55961,10 +72757,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlales",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55974,10 +72770,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlas",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -55987,10 +72783,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlaeq",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56000,10 +72796,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlane",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56013,10 +72809,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlahs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56026,10 +72822,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalo",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56039,10 +72835,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlami",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56052,10 +72848,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlapl",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56065,10 +72861,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlavs",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56078,10 +72874,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlavc",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56091,10 +72887,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlahi",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56104,10 +72900,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlals",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56117,10 +72913,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlage",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56130,10 +72926,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlalt",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56143,10 +72939,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlagt",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56156,10 +72952,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mlale",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56169,10 +72965,10 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ParsedIns {
                     mnemonic: "mla",
                     args: [
-                        Argument::Reg(ins.field_rd()),
+                        Argument::Reg(ins.field_rdhi()),
                         Argument::Reg(ins.field_rm()),
                         Argument::Reg(ins.field_rs()),
-                        Argument::Reg(ins.field_rn()),
+                        Argument::Reg(ins.field_rn_12()),
                         Argument::None,
                         Argument::None,
                     ],
@@ -56192,9 +72988,12 @@ fn parse_mla(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_mov(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_s(), ins.modifier_addr_data()) {
         (Cond::Eq, true, AddrData::Imm) => {
             ParsedIns {
@@ -58160,8 +74959,11 @@ fn parse_mov(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mov_imm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond()) {
         (true, Cond::Eq) => {
             ParsedIns {
@@ -58567,8 +75369,11 @@ fn parse_mov_imm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mov_reg(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond()) {
         (true, Cond::Eq) => {
             ParsedIns {
@@ -58974,8 +75779,11 @@ fn parse_mov_reg(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mrc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -59186,8 +75994,11 @@ fn parse_mrc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mrs(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -59398,8 +76209,11 @@ fn parse_mrs(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_msr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -59610,8 +76424,11 @@ fn parse_msr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_msr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -59822,8 +76639,11 @@ fn parse_msr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mul(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond()) {
             (true, Cond::Eq) => {
@@ -60636,9 +77456,12 @@ fn parse_mul(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_mvn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -64571,9 +81394,12 @@ fn parse_mvn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_orr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -68506,9 +85332,12 @@ fn parse_orr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_pop_m(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -68719,8 +85548,11 @@ fn parse_pop_m(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_pop_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -68931,8 +85763,11 @@ fn parse_pop_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_push_m(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -69143,8 +85978,11 @@ fn parse_push_m(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_push_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -69355,8 +86193,11 @@ fn parse_push_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ror(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_shift_arg()) {
         (true, Cond::Eq, ShiftArg::Imm) => {
             ParsedIns {
@@ -70152,8 +86993,11 @@ fn parse_ror(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_rrx(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_s(), ins.modifier_cond()) {
         (true, Cond::Eq) => {
             ParsedIns {
@@ -70559,8 +87403,11 @@ fn parse_rrx(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_rsb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -74493,9 +91340,12 @@ fn parse_rsb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_rsc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -78428,9 +95278,12 @@ fn parse_rsc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_sbc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -82363,9 +99216,12 @@ fn parse_sbc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_smlal(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond()) {
             (true, Cond::Eq) => {
@@ -83178,9 +100034,12 @@ fn parse_smlal(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_smull(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond()) {
             (true, Cond::Eq) => {
@@ -83993,9 +100852,12 @@ fn parse_smull(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_stc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (
             ins.modifier_l(),
@@ -87156,9 +104018,12 @@ fn parse_stc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_stm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -88751,9 +105616,12 @@ fn parse_stm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_stm_w(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -90346,9 +107214,12 @@ fn parse_stm_w(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_stm_p(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_addr_ldm_stm(), ins.modifier_cond()) {
             (AddrLdmStm::Ia, Cond::Eq) => {
@@ -91941,9 +108812,12 @@ fn parse_stm_p(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_str(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_addr_ldr_str()) {
         (Cond::Eq, AddrLdrStr::Imm) => {
             ParsedIns {
@@ -94299,8 +111173,11 @@ fn parse_str(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_ldr_str()) {
             (Cond::Eq, AddrLdrStr::Imm) => {
@@ -99013,9 +115890,12 @@ fn parse_str_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_bt(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_ldrt_strt()) {
             (Cond::Eq, AddrLdrtStrt::ImmPost) => {
@@ -100218,9 +117098,12 @@ fn parse_str_bt(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_h(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_misc_ldr_str()) {
             (Cond::Eq, AddrMiscLdrStr::Imm) => {
@@ -102593,9 +119476,12 @@ fn parse_str_h(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_t(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_cond(), ins.modifier_addr_ldrt_strt()) {
             (Cond::Eq, AddrLdrtStrt::ImmPost) => {
@@ -103798,9 +120684,12 @@ fn parse_str_t(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_sub(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond(), ins.modifier_addr_data()) {
             (true, Cond::Eq, AddrData::Imm) => {
@@ -107733,9 +124622,12 @@ fn parse_sub(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_svc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -107946,8 +124838,11 @@ fn parse_svc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_swi(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -108158,8 +125053,11 @@ fn parse_swi(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_swp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -108370,8 +125268,11 @@ fn parse_swp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_swpb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -108582,8 +125483,11 @@ fn parse_swpb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_teq(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_addr_data()) {
         (Cond::Eq, AddrData::Imm) => {
             ParsedIns {
@@ -109574,8 +126478,11 @@ fn parse_teq(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_tst(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match (ins.modifier_cond(), ins.modifier_addr_data()) {
         (Cond::Eq, AddrData::Imm) => {
             ParsedIns {
@@ -110566,8 +127473,11 @@ fn parse_tst(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_umlal(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond()) {
             (true, Cond::Eq) => {
@@ -111380,9 +128290,12 @@ fn parse_umlal(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_umull(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = match (ins.modifier_s(), ins.modifier_cond()) {
             (true, Cond::Eq) => {
@@ -112195,7 +129108,20 @@ fn parse_umull(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 }
             }
         }
-    }
+    };
+    guard.disarm();
+}
+/// Parses the instruction with a table of parse functions.
+#[cfg(not(feature = "fast"))]
+#[inline]
+pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    parse_table(out, ins, flags)
+}
+/// Parses the instruction with a table of parse functions.
+#[cfg(feature = "fast")]
+#[inline]
+pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    parse_table(out, ins, flags)
 }
 type MnemonicParser = fn(&mut ParsedIns, Ins, &ParseFlags);
 static MNEMONIC_PARSERS: [MnemonicParser; 68] = [
@@ -112268,21 +129194,3422 @@ static MNEMONIC_PARSERS: [MnemonicParser; 68] = [
     parse_umlal,
     parse_umull,
 ];
+/// Implementation of [`parse`] as a call through a table of parse functions, indexed by the opcode. Public
+/// to test that it's equivalent to [`parse_match`], whichever one the profile uses.
+#[doc(hidden)]
 #[inline]
-pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
-    if ins.op != Opcode::Illegal {
-        MNEMONIC_PARSERS[ins.op as usize](out, ins, flags);
-    } else {
-        *out = ParsedIns {
-            mnemonic: "<illegal>",
-            args: [
-                Argument::None,
-                Argument::None,
-                Argument::None,
-                Argument::None,
-                Argument::None,
-                Argument::None,
+pub fn parse_table(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    match MNEMONIC_PARSERS.get(ins.op as usize) {
+        Some(parser) => parser(out, ins, flags),
+        None => {
+            *out = ParsedIns {
+                mnemonic: "<illegal>",
+                args: [
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                ],
+            };
+        }
+    }
+}
+/// Implementation of [`parse`] as a match on the opcode, which inlines the parse function of each opcode.
+/// Public to test that it's equivalent to [`parse_table`], whichever one the profile uses.
+#[doc(hidden)]
+pub fn parse_match(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    match ins.op {
+        Opcode::Adc => parse_adc(out, ins, flags),
+        Opcode::Add => parse_add(out, ins, flags),
+        Opcode::And => parse_and(out, ins, flags),
+        Opcode::Asr => parse_asr(out, ins, flags),
+        Opcode::B => parse_b(out, ins, flags),
+        Opcode::Bl => parse_bl(out, ins, flags),
+        Opcode::Bic => parse_bic(out, ins, flags),
+        Opcode::Bx => parse_bx(out, ins, flags),
+        Opcode::Cdp => parse_cdp(out, ins, flags),
+        Opcode::Cmn => parse_cmn(out, ins, flags),
+        Opcode::Cmp => parse_cmp(out, ins, flags),
+        Opcode::Eor => parse_eor(out, ins, flags),
+        Opcode::Ldc => parse_ldc(out, ins, flags),
+        Opcode::LdmW => parse_ldm_w(out, ins, flags),
+        Opcode::Ldm => parse_ldm(out, ins, flags),
+        Opcode::LdmP => parse_ldm_p(out, ins, flags),
+        Opcode::LdmPcW => parse_ldm_pc_w(out, ins, flags),
+        Opcode::LdmPc => parse_ldm_pc(out, ins, flags),
+        Opcode::Ldr => parse_ldr(out, ins, flags),
+        Opcode::LdrB => parse_ldr_b(out, ins, flags),
+        Opcode::LdrBt => parse_ldr_bt(out, ins, flags),
+        Opcode::LdrH => parse_ldr_h(out, ins, flags),
+        Opcode::LdrSb => parse_ldr_sb(out, ins, flags),
+        Opcode::LdrSh => parse_ldr_sh(out, ins, flags),
+        Opcode::LdrT => parse_ldr_t(out, ins, flags),
+        Opcode::Lsl => parse_lsl(out, ins, flags),
+        Opcode::Lsr => parse_lsr(out, ins, flags),
+        Opcode::Mcr => parse_mcr(out, ins, flags),
+        Opcode::Mla => parse_mla(out, ins, flags),
+        Opcode::Mov => parse_mov(out, ins, flags),
+        Opcode::MovImm => parse_mov_imm(out, ins, flags),
+        Opcode::MovReg => parse_mov_reg(out, ins, flags),
+        Opcode::Mrc => parse_mrc(out, ins, flags),
+        Opcode::Mrs => parse_mrs(out, ins, flags),
+        Opcode::MsrI => parse_msr_i(out, ins, flags),
+        Opcode::Msr => parse_msr(out, ins, flags),
+        Opcode::Mul => parse_mul(out, ins, flags),
+        Opcode::Mvn => parse_mvn(out, ins, flags),
+        Opcode::Orr => parse_orr(out, ins, flags),
+        Opcode::PopM => parse_pop_m(out, ins, flags),
+        Opcode::PopR => parse_pop_r(out, ins, flags),
+        Opcode::PushM => parse_push_m(out, ins, flags),
+        Opcode::PushR => parse_push_r(out, ins, flags),
+        Opcode::Ror => parse_ror(out, ins, flags),
+        Opcode::Rrx => parse_rrx(out, ins, flags),
+        Opcode::Rsb => parse_rsb(out, ins, flags),
+        Opcode::Rsc => parse_rsc(out, ins, flags),
+        Opcode::Sbc => parse_sbc(out, ins, flags),
+        Opcode::Smlal => parse_smlal(out, ins, flags),
+        Opcode::Smull => parse_smull(out, ins, flags),
+        Opcode::Stc => parse_stc(out, ins, flags),
+        Opcode::Stm => parse_stm(out, ins, flags),
+        Opcode::StmW => parse_stm_w(out, ins, flags),
+        Opcode::StmP => parse_stm_p(out, ins, flags),
+        Opcode::Str => parse_str(out, ins, flags),
+        Opcode::StrB => parse_str_b(out, ins, flags),
+        Opcode::StrBt => parse_str_bt(out, ins, flags),
+        Opcode::StrH => parse_str_h(out, ins, flags),
+        Opcode::StrT => parse_str_t(out, ins, flags),
+        Opcode::Sub => parse_sub(out, ins, flags),
+        Opcode::Svc => parse_svc(out, ins, flags),
+        Opcode::Swi => parse_swi(out, ins, flags),
+        Opcode::Swp => parse_swp(out, ins, flags),
+        Opcode::Swpb => parse_swpb(out, ins, flags),
+        Opcode::Teq => parse_teq(out, ins, flags),
+        Opcode::Tst => parse_tst(out, ins, flags),
+        Opcode::Umlal => parse_umlal(out, ins, flags),
+        Opcode::Umull => parse_umull(out, ins, flags),
+        _ => {
+            *out = ParsedIns {
+                mnemonic: "<illegal>",
+                args: [
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                ],
+            };
+        }
+    }
+}
+/// Rn: First source operand register
+fn encode_rn(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 16, 20).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_wb: Source operand register with writeback
+fn encode_rn_wb(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 16, 20).ok_or(FieldError::OutOfRange)?;
+    if !value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_deref: Base register
+fn encode_rn_deref(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 16, 20).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_deref_wb: Base register with writeback
+fn encode_rn_deref_wb(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 16, 20).ok_or(FieldError::OutOfRange)?;
+    if !value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_12: First source operand register
+fn encode_rn_12(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 12, 16).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rm: Second source operand register
+fn encode_rm(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 0, 4).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rd: Destination register
+fn encode_rd(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 12, 16).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rs: Register containing shift offset
+fn encode_rs(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 8, 12).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rt: Transferred register
+fn encode_rt(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 12, 16).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// RdHi: Upper 32-bit long destination register
+fn encode_rdhi(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 16, 20).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// RdLo: Lower 32-bit long destination register
+fn encode_rdlo(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 12, 16).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// registers: List of registers
+fn encode_registers(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::RegList(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.regs, 0, 16).ok_or(FieldError::OutOfRange)?;
+    if value.user_mode {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// registers_c: List of registers (with ^ suffix)
+fn encode_registers_c(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::RegList(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.regs, 0, 16).ok_or(FieldError::OutOfRange)?;
+    if !value.user_mode {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rt_list: List of one register
+fn encode_rt_list(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::RegList(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.user_mode {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (value.regs.is_power_of_two().then_some(value.regs.trailing_zeros() << 12))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// CRn: First source coprocessor register
+fn encode_crn(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value as u32, 16, 20).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// CRm: Second source coprocessor register
+fn encode_crm(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value as u32, 0, 4).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// CRd: Destination coprocessor register
+fn encode_crd(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value as u32, 12, 16).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// immed_5: 5-bit immediate
+fn encode_immed_5(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 7, 12).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// rotated_immed_8: 8-bit immediate rotated right by twice the value of bits 8..12
+fn encode_rotated_immed_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= (encode::rotated_imm(value)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// immed_24: 24-bit immediate
+fn encode_immed_24(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 0, 24).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// offset_8: 8-bit immediate offset
+fn encode_offset_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (encode::offset(value.value, 8)
+            .map(|bits| (bits & !0xf0) | (bits & 0xf0) << 4))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// post_offset_8: 8-bit immediate post-indexed offset
+fn encode_post_offset_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (encode::offset(value.value, 8)
+            .map(|bits| (bits & !0xf0) | (bits & 0xf0) << 4))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// offset_12: 12-bit immediate offset
+fn encode_offset_12(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code |= (encode::offset(value.value, 12)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// post_offset_12: 12-bit immediate post-indexed offset
+fn encode_post_offset_12(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code |= (encode::offset(value.value, 12)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// shift_imm: Immediate shift offset
+fn encode_shift_imm(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::ShiftImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.op as u32, 5, 7).ok_or(FieldError::OutOfRange)?;
+    code
+        |= (encode::arm_shift(value.op as u32, value.imm).map(|imm| imm << 7))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// shift_reg: Register shift offset
+fn encode_shift_reg(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::ShiftReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.op as u32, 5, 7).ok_or(FieldError::OutOfRange)?;
+    code |= encode::bits(value.reg as u32, 8, 12).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// rrx: Rotate right with extend
+fn encode_rrx(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Shift(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value as u32 != 4 {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// reg_offset: Register offset
+fn encode_reg_offset(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.add as u32, 23, 24).ok_or(FieldError::OutOfRange)?;
+    if value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 0, 4).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// reg_post_offset: Register post-indexed offset
+fn encode_reg_post_offset(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.add as u32, 23, 24).ok_or(FieldError::OutOfRange)?;
+    if !value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 0, 4).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// R: Move SPSR (1) or CPSR (0)
+fn encode_r(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::StatusReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value as u32, 22, 23).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// coproc_offset: 8-bit immediate coprocessor offset
+fn encode_coproc_offset(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= ((value.value % 4 == 0)
+            .then_some(value.value / 4)
+            .and_then(|offset| encode::offset(offset, 8)))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// coproc_post_offset: 8-bit immediate coprocessor offset
+fn encode_coproc_post_offset(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= ((value.value % 4 == 0)
+            .then_some(value.value / 4)
+            .and_then(|offset| encode::offset(offset, 8)))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// option: Additional instruction options for coprocessor
+fn encode_option(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoOption(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 0, 8).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// branch_offset: 24-bit signed B/BL target offset
+fn encode_branch_offset(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::BranchDest(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::signed_bits(value as i64 - 8, 2, 24)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// field_mask: Status fields to set
+fn encode_field_mask(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::StatusMask(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.control as u32, 16, 17).ok_or(FieldError::OutOfRange)?;
+    code |= encode::bits(value.extension as u32, 17, 18).ok_or(FieldError::OutOfRange)?;
+    code |= encode::bits(value.flags as u32, 19, 20).ok_or(FieldError::OutOfRange)?;
+    code |= encode::bits(value.reg as u32, 22, 23).ok_or(FieldError::OutOfRange)?;
+    code |= encode::bits(value.status as u32, 18, 19).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// codat_opcode_1: Coprocessor operation to perform (user-defined, used by CDP instruction)
+fn encode_codat_opcode_1(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoOpcode(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 20, 24).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// comov_opcode_1: Coprocessor operation to perform (user-defined, used by MCR/MRC instructions)
+fn encode_comov_opcode_1(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoOpcode(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 21, 24).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// opcode_2: Coprocessor operation to perform (user-defined)
+fn encode_opcode_2(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoOpcode(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 5, 8).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// coproc: Coprocessor number
+fn encode_coproc(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::CoprocNum(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 8, 12).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// addr_coproc: Load and Store Coprocessor
+static ENCODE_CASES_ADDR_COPROC: [EncodeCase; 4] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x01200000,
+        pattern: 0x01000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_coproc_offset,
+                bitmask: 0x008000ff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x01200000,
+        pattern: 0x01200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_coproc_offset,
+                bitmask: 0x008000ff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x01200000,
+        pattern: 0x00200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_coproc_post_offset,
+                bitmask: 0x008000ff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x01a00000,
+        pattern: 0x00800000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_option,
+                bitmask: 0x000000ff,
+            },
+        ],
+    },
+];
+/// addr_data: Data-processing operands
+static ENCODE_CASES_ADDR_DATA: [EncodeCase; 5] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0e000000,
+        pattern: 0x02000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rotated_immed_8,
+                bitmask: 0x00000fff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0e000ff0,
+        pattern: 0x00000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rm,
+                bitmask: 0x0000000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0e000010,
+        pattern: 0x00000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rm,
+                bitmask: 0x0000000f,
+            },
+            EncodeField {
+                encode: encode_shift_imm,
+                bitmask: 0x00000fe0,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0e000090,
+        pattern: 0x00000010,
+        fields: &[
+            EncodeField {
+                encode: encode_rm,
+                bitmask: 0x0000000f,
+            },
+            EncodeField {
+                encode: encode_shift_reg,
+                bitmask: 0x00000f60,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0e000ff0,
+        pattern: 0x00000060,
+        fields: &[
+            EncodeField {
+                encode: encode_rm,
+                bitmask: 0x0000000f,
+            },
+            EncodeField {
+                encode: encode_rrx,
+                bitmask: 0x00000000,
+            },
+        ],
+    },
+];
+/// addr_ldm_stm: Load and Store Multiple
+static ENCODE_CASES_ADDR_LDM_STM: [EncodeCase; 4] = [
+    EncodeCase {
+        suffix: "ia",
+        bitmask: 0x01800000,
+        pattern: 0x00800000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ib",
+        bitmask: 0x01800000,
+        pattern: 0x01800000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "da",
+        bitmask: 0x01800000,
+        pattern: 0x00000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "db",
+        bitmask: 0x01800000,
+        pattern: 0x01000000,
+        fields: &[],
+    },
+];
+/// addr_ldm_stm: Load and Store Multiple
+static ENCODE_CASES_ADDR_LDM_STM_UAL: [EncodeCase; 4] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x01800000,
+        pattern: 0x00800000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ib",
+        bitmask: 0x01800000,
+        pattern: 0x01800000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "da",
+        bitmask: 0x01800000,
+        pattern: 0x00000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "db",
+        bitmask: 0x01800000,
+        pattern: 0x01000000,
+        fields: &[],
+    },
+];
+/// addr_ldrt_strt: Load and Store Word or Unsigned Byte with Translation
+static ENCODE_CASES_ADDR_LDRT_STRT: [EncodeCase; 3] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200000,
+        pattern: 0x04200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_post_offset_12,
+                bitmask: 0x00800fff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x06200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_post_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200010,
+        pattern: 0x06200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_post_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_shift_imm,
+                bitmask: 0x00000fe0,
+            },
+        ],
+    },
+];
+/// addr_ldr_str: Load and Store Word or Unsigned Byte
+static ENCODE_CASES_ADDR_LDR_STR: [EncodeCase; 12] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200000,
+        pattern: 0x05000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_offset_12,
+                bitmask: 0x00800fff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x07000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200010,
+        pattern: 0x07000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_shift_imm,
+                bitmask: 0x00000fe0,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x07000060,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_rrx,
+                bitmask: 0x00000000,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200000,
+        pattern: 0x05200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_offset_12,
+                bitmask: 0x00800fff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x07200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200010,
+        pattern: 0x07200000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_shift_imm,
+                bitmask: 0x00000fe0,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x07200060,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_rrx,
+                bitmask: 0x00000000,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200000,
+        pattern: 0x04000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_post_offset_12,
+                bitmask: 0x00800fff,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x06000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_post_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200010,
+        pattern: 0x06000000,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_post_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_shift_imm,
+                bitmask: 0x00000fe0,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f200ff0,
+        pattern: 0x06000060,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_post_offset,
+                bitmask: 0x0080000f,
+            },
+            EncodeField {
+                encode: encode_rrx,
+                bitmask: 0x00000000,
+            },
+        ],
+    },
+];
+/// addr_misc_ldr_str: Miscellaneous Loads and Stores
+static ENCODE_CASES_ADDR_MISC_LDR_STR: [EncodeCase; 6] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f600090,
+        pattern: 0x01400090,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_offset_8,
+                bitmask: 0x00800f0f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f600f90,
+        pattern: 0x01000090,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f600090,
+        pattern: 0x01600090,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_offset_8,
+                bitmask: 0x00800f0f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f600f90,
+        pattern: 0x01200090,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref_wb,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f600090,
+        pattern: 0x00400090,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_post_offset_8,
+                bitmask: 0x00800f0f,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x0f600f90,
+        pattern: 0x00000090,
+        fields: &[
+            EncodeField {
+                encode: encode_rn_deref,
+                bitmask: 0x000f0000,
+            },
+            EncodeField {
+                encode: encode_reg_post_offset,
+                bitmask: 0x0080000f,
+            },
+        ],
+    },
+];
+/// cond: Condition code
+static ENCODE_CASES_COND: [EncodeCase; 15] = [
+    EncodeCase {
+        suffix: "eq",
+        bitmask: 0xf0000000,
+        pattern: 0x00000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ne",
+        bitmask: 0xf0000000,
+        pattern: 0x10000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "hs",
+        bitmask: 0xf0000000,
+        pattern: 0x20000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "lo",
+        bitmask: 0xf0000000,
+        pattern: 0x30000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "mi",
+        bitmask: 0xf0000000,
+        pattern: 0x40000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "pl",
+        bitmask: 0xf0000000,
+        pattern: 0x50000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "vs",
+        bitmask: 0xf0000000,
+        pattern: 0x60000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "vc",
+        bitmask: 0xf0000000,
+        pattern: 0x70000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "hi",
+        bitmask: 0xf0000000,
+        pattern: 0x80000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ls",
+        bitmask: 0xf0000000,
+        pattern: 0x90000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ge",
+        bitmask: 0xf0000000,
+        pattern: 0xa0000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "lt",
+        bitmask: 0xf0000000,
+        pattern: 0xb0000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "gt",
+        bitmask: 0xf0000000,
+        pattern: 0xc0000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "le",
+        bitmask: 0xf0000000,
+        pattern: 0xd0000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0xf0000000,
+        pattern: 0xe0000000,
+        fields: &[],
+    },
+];
+/// L: Long coprocessor load (e.g. double instead of float)
+static ENCODE_CASES_L: [EncodeCase; 2] = [
+    EncodeCase {
+        suffix: "l",
+        bitmask: 0x00400000,
+        pattern: 0x00400000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x00400000,
+        pattern: 0x00000000,
+        fields: &[],
+    },
+];
+/// S: Update condition status flags
+static ENCODE_CASES_S: [EncodeCase; 2] = [
+    EncodeCase {
+        suffix: "s",
+        bitmask: 0x00100000,
+        pattern: 0x00100000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x00100000,
+        pattern: 0x00000000,
+        fields: &[],
+    },
+];
+/// shift_arg: Second operand for shift instructions
+static ENCODE_CASES_SHIFT_ARG: [EncodeCase; 2] = [
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x00000010,
+        pattern: 0x00000000,
+        fields: &[
+            EncodeField {
+                encode: encode_immed_5,
+                bitmask: 0x00000f80,
+            },
+        ],
+    },
+    EncodeCase {
+        suffix: "",
+        bitmask: 0x00000090,
+        pattern: 0x00000010,
+        fields: &[
+            EncodeField {
+                encode: encode_rs,
+                bitmask: 0x00000f00,
+            },
+        ],
+    },
+];
+static ENCODE_FORMS: [&[EncodeForm]; 68] = [
+    &[
+        EncodeForm {
+            name: "adc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00a00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
             ],
-        };
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "adc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00a00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00800000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00800000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "and",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "and",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "asr",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0060,
+            pattern: 0x01a00040,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_SHIFT_ARG],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "b",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0f000000,
+            pattern: 0x0a000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_branch_offset,
+                    bitmask: 0x00ffffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bl",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0f000000,
+            pattern: 0x0b000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_branch_offset,
+                    bitmask: 0x00ffffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bic",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x01c00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "bic",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x01c00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bx",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0ffffff0,
+            pattern: 0x012fff10,
+            fields: &[
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cdp",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0f000010,
+            pattern: 0x0e000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_codat_opcode_1,
+                    bitmask: 0x00f00000,
+                },
+                EncodeField {
+                    encode: encode_crd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_crn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_crm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_opcode_2,
+                    bitmask: 0x000000e0,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cmn",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0df0f000,
+            pattern: 0x01700000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cmp",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0df0f000,
+            pattern: 0x01500000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "eor",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00200000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "eor",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00200000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e100000,
+            pattern: 0x0c100000,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_crd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_L, &ENCODE_CASES_ADDR_COPROC],
+        },
+        EncodeForm {
+            name: "ldc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e100000,
+            pattern: 0x0c100000,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_crd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_L, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_COPROC],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e700000,
+            pattern: 0x08300000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_wb,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e700000,
+            pattern: 0x08300000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_wb,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e700000,
+            pattern: 0x08100000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e700000,
+            pattern: 0x08100000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e708000,
+            pattern: 0x08500000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e708000,
+            pattern: 0x08500000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e708000,
+            pattern: 0x08708000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_wb,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e708000,
+            pattern: 0x08708000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_wb,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e708000,
+            pattern: 0x08508000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e708000,
+            pattern: 0x08508000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0c500000,
+            pattern: 0x04100000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "b",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0c500000,
+            pattern: 0x04500000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDR_STR],
+        },
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "b",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0c500000,
+            pattern: 0x04500000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "bt",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0d700000,
+            pattern: 0x04700000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "bt",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0d700000,
+            pattern: 0x04700000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "h",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e1000f0,
+            pattern: 0x001000b0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "h",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e1000f0,
+            pattern: 0x001000b0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "sb",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e1000f0,
+            pattern: 0x001000d0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "sb",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e1000f0,
+            pattern: 0x001000d0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "sh",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e1000f0,
+            pattern: 0x001000f0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "sh",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e1000f0,
+            pattern: 0x001000f0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "t",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0d700000,
+            pattern: 0x04300000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "t",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0d700000,
+            pattern: 0x04300000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "lsl",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0060,
+            pattern: 0x01a00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_SHIFT_ARG],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "lsr",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0060,
+            pattern: 0x01a00020,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_SHIFT_ARG],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mcr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0f100010,
+            pattern: 0x0e000010,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_comov_opcode_1,
+                    bitmask: 0x00e00000,
+                },
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_crn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_crm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_opcode_2,
+                    bitmask: 0x000000e0,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mla",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00200090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_rn_12,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S],
+        },
+        EncodeForm {
+            name: "mla",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00200090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_rn_12,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0def0000,
+            pattern: 0x01a00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0000,
+            pattern: 0x03a00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rotated_immed_8,
+                    bitmask: 0x00000fff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0ff0,
+            pattern: 0x01a00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mrc",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0f100010,
+            pattern: 0x0e100010,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_comov_opcode_1,
+                    bitmask: 0x00e00000,
+                },
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_crn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_crm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_opcode_2,
+                    bitmask: 0x000000e0,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mrs",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0fbf0fff,
+            pattern: 0x010f0000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_r,
+                    bitmask: 0x00400000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "msr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0fb0f000,
+            pattern: 0x0320f000,
+            fields: &[
+                EncodeField {
+                    encode: encode_field_mask,
+                    bitmask: 0x004f0000,
+                },
+                EncodeField {
+                    encode: encode_rotated_immed_8,
+                    bitmask: 0x00000fff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "msr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0fb0fff0,
+            pattern: 0x0120f000,
+            fields: &[
+                EncodeField {
+                    encode: encode_field_mask,
+                    bitmask: 0x004f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mul",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0fe0f0f0,
+            pattern: 0x00000090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S],
+        },
+        EncodeForm {
+            name: "mul",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fe0f0f0,
+            pattern: 0x00000090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mvn",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0def0000,
+            pattern: 0x01e00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "mvn",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0def0000,
+            pattern: 0x01e00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "orr",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x01800000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "orr",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x01800000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "pop",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fff0000,
+            pattern: 0x08bd0000,
+            fields: &[
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "pop",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fff0fff,
+            pattern: 0x049d0004,
+            fields: &[
+                EncodeField {
+                    encode: encode_rt_list,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "push",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fff0000,
+            pattern: 0x092d0000,
+            fields: &[
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "push",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fff0fff,
+            pattern: 0x052d0004,
+            fields: &[
+                EncodeField {
+                    encode: encode_rt_list,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ror",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0060,
+            pattern: 0x01a00060,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_SHIFT_ARG],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "rrx",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fef0ff0,
+            pattern: 0x01a00060,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "rsb",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00600000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "rsb",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00600000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "rsc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00e00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "rsc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00e00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "sbc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00c00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "sbc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00c00000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "smlal",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00e00090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S],
+        },
+        EncodeForm {
+            name: "smlal",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00e00090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "smull",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00c00090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S],
+        },
+        EncodeForm {
+            name: "smull",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00c00090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "stc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e100000,
+            pattern: 0x0c000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_crd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_L, &ENCODE_CASES_ADDR_COPROC],
+        },
+        EncodeForm {
+            name: "stc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e100000,
+            pattern: 0x0c000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_coproc,
+                    bitmask: 0x00000f00,
+                },
+                EncodeField {
+                    encode: encode_crd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_L, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_COPROC],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e700000,
+            pattern: 0x08000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e700000,
+            pattern: 0x08000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e700000,
+            pattern: 0x08200000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_wb,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e700000,
+            pattern: 0x08200000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_wb,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e700000,
+            pattern: 0x08400000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDM_STM],
+        },
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e700000,
+            pattern: 0x08400000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_registers_c,
+                    bitmask: 0x0000ffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_ADDR_LDM_STM_UAL, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0c500000,
+            pattern: 0x04000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "b",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0c500000,
+            pattern: 0x04400000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDR_STR],
+        },
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "b",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0c500000,
+            pattern: 0x04400000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "bt",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0d700000,
+            pattern: 0x04600000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "bt",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0d700000,
+            pattern: 0x04600000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "h",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0e1000f0,
+            pattern: 0x000000b0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "h",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0e1000f0,
+            pattern: 0x000000b0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_MISC_LDR_STR],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "t",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0d700000,
+            pattern: 0x04200000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "t",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0d700000,
+            pattern: 0x04200000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_LDRT_STRT],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0de00000,
+            pattern: 0x00400000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S, &ENCODE_CASES_ADDR_DATA],
+        },
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0de00000,
+            pattern: 0x00400000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "svc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0f000000,
+            pattern: 0x0f000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_immed_24,
+                    bitmask: 0x00ffffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "swi",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0f000000,
+            pattern: 0x0f000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_immed_24,
+                    bitmask: 0x00ffffff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "swp",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0ff00ff0,
+            pattern: 0x01000090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rn_deref,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "swpb",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0ff00ff0,
+            pattern: 0x01400090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rn_deref,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "teq",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0df0f000,
+            pattern: 0x01300000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "tst",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0df0f000,
+            pattern: 0x01100000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn,
+                    bitmask: 0x000f0000,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_ADDR_DATA],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "umlal",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00a00090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S],
+        },
+        EncodeForm {
+            name: "umlal",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00a00090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "umull",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00800090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND, &ENCODE_CASES_S],
+        },
+        EncodeForm {
+            name: "umull",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0fe000f0,
+            pattern: 0x00800090,
+            fields: &[
+                EncodeField {
+                    encode: encode_rdlo,
+                    bitmask: 0x0000f000,
+                },
+                EncodeField {
+                    encode: encode_rdhi,
+                    bitmask: 0x000f0000,
+                },
+                EncodeField {
+                    encode: encode_rm,
+                    bitmask: 0x0000000f,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000f00,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_S, &ENCODE_CASES_COND],
+        },
+    ],
+];
+/// Encodes a parsed instruction with any opcode. The result decodes to the same [`ParsedIns`] with the given
+/// flags.
+pub fn encode(ins: &ParsedIns, flags: &ParseFlags) -> Result<u32, EncodeError> {
+    let mut error = EncodeError::UnknownMnemonic;
+    for forms in ENCODE_FORMS {
+        let result = encode::encode_forms(
+            forms,
+            ins,
+            flags.ual,
+            |code| Ins::new(code, flags).parse(flags) == *ins,
+        );
+        match result {
+            Ok(code) => return Ok(code),
+            Err(e) => error = error.most_relevant(e),
+        }
     }
+    Err(error)
 }
@@ -2,3 +2,8 @@
 pub mod arm;
 #[cfg(feature = "thumb")]
 pub mod thumb;
+#[cfg(all(feature = "arm", feature = "thumb"))]
+mod unified;
+
+#[cfg(all(feature = "arm", feature = "thumb"))]
+pub use unified::*;
@@ -1,17 +1,53 @@
-use crate::{v5te::thumb::generated::Opcode, ParseFlags, ParsedIns};
+use crate::{
+    args::Register, parse::pc_read_value, v5te::thumb::generated::Opcode, Endian, Op, ParseFlags, ParseMode, ParsedIns,
+};
 
 use super::parse;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 pub struct Ins {
     pub code: u32,
     pub op: Opcode,
 }
 
 impl Ins {
+    /// Decodes `code`. The flags pick between opcodes which share an encoding, such as `swi` and `svc` with
+    /// [`ParseFlags::ual`], and can decode some encodings as [`Opcode::Illegal`]. Pass the same flags to [`Self::parse`].
     pub fn new(code: u32, flags: &ParseFlags) -> Self {
+        let ins = Self::const_new(code, flags);
+        if flags.illegal_operand_as_illegal && ins.parse(flags).has_illegal_operand() {
+            return Self {
+                code,
+                op: Opcode::Illegal,
+            };
+        }
+        ins
+    }
+
+    /// Same as [`Self::new`], but usable in constants, e.g. to build a table of decoded instructions at compile time.
+    /// [`ParseFlags::illegal_operand_as_illegal`] is ignored, as the operands can only be checked by parsing.
+    pub const fn const_new(code: u32, flags: &ParseFlags) -> Self {
         let op = Opcode::find(code, flags);
-        Self { code, op }
+        let ins = Self { code, op };
+        if flags.unpredictable_as_illegal && ins.is_unpredictable() {
+            return Self {
+                code,
+                op: Opcode::Illegal,
+            };
+        }
+        ins
+    }
+
+    /// Decodes the first 2 bytes of `bytes` in the given byte order. Returns `None` if there are fewer than 2 bytes.
+    ///
+    /// A BL instruction takes 4 bytes, so if [`Self::is_half_bl`] is true, decode the second half from `bytes[2..]`.
+    pub fn from_bytes(bytes: &[u8], endian: Endian, flags: &ParseFlags) -> Option<Self> {
+        let bytes = bytes.get(..2)?.try_into().ok()?;
+        let code = match endian {
+            Endian::Little => u16::from_le_bytes(bytes),
+            Endian::Big => u16::from_be_bytes(bytes),
+        };
+        Some(Self::new(code.into(), flags))
     }
 
     /// Returns whether this is a BL half-instruction and should be combined with the upcoming instruction
@@ -19,9 +55,144 @@ impl Ins {
         self.op == Opcode::BlH
     }
 
+    /// Returns the number of bytes this instruction takes, which is 4 for the first half of a BL pair and 2 otherwise
+    pub fn size(&self) -> usize {
+        if self.is_half_bl() {
+            4
+        } else {
+            2
+        }
+    }
+
+    /// Parses the mnemonic and arguments, with the same flags which were passed to [`Self::new`]
     pub fn parse(self, flags: &ParseFlags) -> ParsedIns {
         let mut out = ParsedIns::default();
-        parse(&mut out, self, flags);
+        self.parse_into(&mut out, flags);
         out
     }
+
+    /// Parses into `out` instead of returning a new [`ParsedIns`], to avoid copying it when parsing many instructions.
+    /// Every argument of `out` is overwritten, so the arguments after the first [`Argument::None`] are `None` as well
+    /// and nothing is left over from the instruction which was parsed into it before.
+    ///
+    /// [`Argument::None`]: crate::args::Argument::None
+    pub fn parse_into(self, out: &mut ParsedIns, flags: &ParseFlags) {
+        parse(out, self, flags);
+        if flags.aliases {
+            out.alias_single_transfer();
+        }
+    }
+
+    /// Returns a bitmask of the encoding bits which back the argument at `index` in [`ParsedIns::args`]. Returns 0 if there
+    /// is no such argument or if its value is implied by the opcode.
+    pub fn arg_bitmask(self, index: usize, flags: &ParseFlags) -> u32 {
+        self.parse(flags)
+            .arg_bitmask(self.code, 16, index, |code| Self { code, op: self.op }.parse(flags))
+    }
+
+    /// Returns the underlying opcode if this instruction was decoded as a unified syntax (UAL) alias, otherwise `None`.
+    /// See [`ParsedIns::aliased_from`] for the underlying instruction with its operands.
+    /// Pass the flags which it was decoded with.
+    pub fn aliased_from(&self, flags: &ParseFlags) -> Option<Opcode> {
+        if self.op.is_alias() {
+            Some(Opcode::find(self.code, &ParseFlags { ual: false, ..*flags }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value which PC reads as when this instruction at `address` uses it as an operand, or `None` if it
+    /// doesn't read PC. PC reads as `address + 4`, aligned down to 4 bytes in PC-relative loads and address calculations.
+    pub fn pc_read_value(&self, address: u32) -> Option<u32> {
+        let aligned = matches!(self.op, Opcode::AddPc | Opcode::Adr | Opcode::LdrPc);
+        let parsed = self.parse(&ParseFlags::default());
+        if !aligned && !parsed.uses(Op::ThumbV5Te(self.op)).contains(Register::Pc) {
+            return None;
+        }
+        Some(pc_read_value(address, ParseMode::Thumb, aligned))
+    }
+
+    /// Parses the underlying instruction if this instruction was decoded as an alias, otherwise the same as [`Self::parse`].
+    pub fn parse_unaliased(self, flags: &ParseFlags) -> ParsedIns {
+        let flags = &ParseFlags {
+            aliases: false,
+            ..*flags
+        };
+        match self.aliased_from(flags) {
+            Some(op) => Self { code: self.code, op }.parse(flags),
+            None => self.parse(flags),
+        }
+    }
+}
+
+/// Iterator over the instructions in a code buffer. BL instructions take 4 bytes and are returned as one instruction, with
+/// the first half as the [`Ins`] and the [`ParsedIns`] combined from both halves. Stops when there are too few bytes left
+/// for the next instruction.
+#[derive(Clone)]
+pub struct InsIter<'a> {
+    pub address: u32,
+    pub endian: Endian,
+    pub flags: ParseFlags,
+    data: &'a [u8],
+}
+
+impl<'a> InsIter<'a> {
+    pub fn new(data: &'a [u8], address: u32, endian: Endian, flags: ParseFlags) -> Self {
+        Self {
+            address,
+            endian,
+            flags,
+            data,
+        }
+    }
+}
+
+impl Iterator for InsIter<'_> {
+    type Item = (u32, Ins, ParsedIns);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ins = Ins::from_bytes(self.data, self.endian, &self.flags)?;
+        let address = self.address;
+        let size = ins.size();
+        let parsed = if ins.is_half_bl() {
+            let second = Ins::from_bytes(&self.data[2..], self.endian, &self.flags)?;
+            ins.parse(&self.flags).combine_thumb_bl(&second.parse(&self.flags))
+        } else {
+            ins.parse(&self.flags)
+        };
+        self.data = &self.data[size..];
+        self.address = address.wrapping_add(size as u32);
+        Some((address, ins, parsed))
+    }
+}
+
+/// Parses the instructions in `code` into `out` along with their addresses, starting at `base`. BL pairs are combined
+/// like in [`InsIter`], and parsing stops when there are too few bytes left for the next instruction. The entries
+/// which are already in `out` are reused with [`Ins::parse_into`], so parsing one buffer after another into the same
+/// `Vec` doesn't allocate once it's large enough.
+pub fn parse_all(code: &[u8], base: u32, endian: Endian, flags: &ParseFlags, out: &mut Vec<(u32, ParsedIns)>) {
+    let mut len = 0;
+    let mut offset = 0;
+    while let Some(ins) = Ins::from_bytes(&code[offset..], endian, flags) {
+        let second = if ins.is_half_bl() {
+            let Some(second) = Ins::from_bytes(&code[offset + 2..], endian, flags) else {
+                break;
+            };
+            Some(second)
+        } else {
+            None
+        };
+        if len == out.len() {
+            out.push(Default::default());
+        }
+        let (address, parsed) = &mut out[len];
+        *address = base.wrapping_add(offset as u32);
+        match second {
+            Some(second) => *parsed = ins.parse(flags).combine_thumb_bl(&second.parse(flags)),
+            None => ins.parse_into(parsed, flags),
+        }
+        offset += ins.size();
+        len += 1;
+    }
+    out.truncate(len);
 }
@@ -1,11 +1,21 @@
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused)]
-#![allow(clippy::double_parens, clippy::unnecessary_cast)]
+#![allow(
+    clippy::double_parens,
+    clippy::unnecessary_cast,
+    clippy::match_like_matches_macro,
+    clippy::collapsible_match
+)]
 // Generated by unarm-generator. Do not edit!
-use crate::{ParseFlags, args::*, parse::ParsedIns};
+use crate::{
+    ParseFlags, args::*,
+    encode::{self, EncodeCase, EncodeError, EncodeField, EncodeForm, FieldError},
+    enumerate::{self, FieldDesc, FieldFilter},
+    parse::{ArgMeta, Condition, ParsedIns},
+};
 use super::Ins;
 /// These are the mnemonics of each opcode. Some mnemonics are duplicated due to them having multiple formats.
-static OPCODE_MNEMONICS: [&str; 72] = [
+static OPCODE_MNEMONICS: [&str; 73] = [
     "adcs",
     "adds",
     "adds",
@@ -78,8 +88,6143 @@ static OPCODE_MNEMONICS: [&str; 72] = [
     "svc",
     "swi",
     "tst",
+    "udf",
 ];
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// These are the names of each opcode variant, see [`Opcode::variant_name`].
+static OPCODE_VARIANT_NAMES: [&str; 73] = [
+    "Adc",
+    "Add3",
+    "Add8",
+    "AddR",
+    "AddHr",
+    "AddSp",
+    "AddSp7",
+    "AddRegSp",
+    "AddSpReg",
+    "AddPc",
+    "Adr",
+    "And",
+    "AsrI",
+    "AsrR",
+    "B",
+    "BLong",
+    "Bic",
+    "Bkpt",
+    "BlH",
+    "Bl",
+    "BlxI",
+    "BlxR",
+    "BxR",
+    "Cmn",
+    "CmpI",
+    "CmpR",
+    "CmpHr",
+    "Eor",
+    "Ldm",
+    "Ldmia",
+    "LdrI",
+    "LdrR",
+    "LdrPc",
+    "LdrSp",
+    "LdrbI",
+    "LdrbR",
+    "LdrhI",
+    "LdrhR",
+    "Ldrsb",
+    "Ldrsh",
+    "LslI",
+    "LslR",
+    "LsrI",
+    "LsrR",
+    "MovI",
+    "MovR",
+    "MovsR",
+    "MovHr",
+    "Mul",
+    "Mvn",
+    "Neg",
+    "Rsbs",
+    "Orr",
+    "Pop",
+    "Push",
+    "Ror",
+    "Sbc",
+    "Stm",
+    "StrI",
+    "StrR",
+    "StrSp",
+    "StrbI",
+    "StrbR",
+    "StrhI",
+    "StrhR",
+    "Subs3",
+    "Sub8",
+    "SubR",
+    "SubSp7",
+    "Svc",
+    "Swi",
+    "Tst",
+    "Udf",
+];
+/// These are the ids of each opcode, see [`Opcode::id`].
+static OPCODE_IDS: [&str; 73] = [
+    "adc",
+    "add_3",
+    "add_8",
+    "add_r",
+    "add_hr",
+    "add_sp",
+    "add_sp7",
+    "add_reg_sp",
+    "add_sp_reg",
+    "add_pc",
+    "adr",
+    "and",
+    "asr_i",
+    "asr_r",
+    "b",
+    "b_long",
+    "bic",
+    "bkpt",
+    "bl_h",
+    "bl",
+    "blx_i",
+    "blx_r",
+    "bx_r",
+    "cmn",
+    "cmp_i",
+    "cmp_r",
+    "cmp_hr",
+    "eor",
+    "ldm",
+    "ldmia",
+    "ldr_i",
+    "ldr_r",
+    "ldr_pc",
+    "ldr_sp",
+    "ldrb_i",
+    "ldrb_r",
+    "ldrh_i",
+    "ldrh_r",
+    "ldrsb",
+    "ldrsh",
+    "lsl_i",
+    "lsl_r",
+    "lsr_i",
+    "lsr_r",
+    "mov_i",
+    "mov_r",
+    "movs_r",
+    "mov_hr",
+    "mul",
+    "mvn",
+    "neg",
+    "rsbs",
+    "orr",
+    "pop",
+    "push",
+    "ror",
+    "sbc",
+    "stm",
+    "str_i",
+    "str_r",
+    "str_sp",
+    "strb_i",
+    "strb_r",
+    "strh_i",
+    "strh_r",
+    "subs_3",
+    "sub_8",
+    "sub_r",
+    "sub_sp7",
+    "svc",
+    "swi",
+    "tst",
+    "udf",
+];
+/// Opcodes sorted by id, see [`Opcode::from_id`].
+static OPCODES_BY_ID: &[(&str, Opcode)] = &[
+    ("adc", Opcode::Adc),
+    ("add_3", Opcode::Add3),
+    ("add_8", Opcode::Add8),
+    ("add_hr", Opcode::AddHr),
+    ("add_pc", Opcode::AddPc),
+    ("add_r", Opcode::AddR),
+    ("add_reg_sp", Opcode::AddRegSp),
+    ("add_sp", Opcode::AddSp),
+    ("add_sp7", Opcode::AddSp7),
+    ("add_sp_reg", Opcode::AddSpReg),
+    ("adr", Opcode::Adr),
+    ("and", Opcode::And),
+    ("asr_i", Opcode::AsrI),
+    ("asr_r", Opcode::AsrR),
+    ("b", Opcode::B),
+    ("b_long", Opcode::BLong),
+    ("bic", Opcode::Bic),
+    ("bkpt", Opcode::Bkpt),
+    ("bl", Opcode::Bl),
+    ("bl_h", Opcode::BlH),
+    ("blx_i", Opcode::BlxI),
+    ("blx_r", Opcode::BlxR),
+    ("bx_r", Opcode::BxR),
+    ("cmn", Opcode::Cmn),
+    ("cmp_hr", Opcode::CmpHr),
+    ("cmp_i", Opcode::CmpI),
+    ("cmp_r", Opcode::CmpR),
+    ("eor", Opcode::Eor),
+    ("illegal", Opcode::Illegal),
+    ("ldm", Opcode::Ldm),
+    ("ldmia", Opcode::Ldmia),
+    ("ldr_i", Opcode::LdrI),
+    ("ldr_pc", Opcode::LdrPc),
+    ("ldr_r", Opcode::LdrR),
+    ("ldr_sp", Opcode::LdrSp),
+    ("ldrb_i", Opcode::LdrbI),
+    ("ldrb_r", Opcode::LdrbR),
+    ("ldrh_i", Opcode::LdrhI),
+    ("ldrh_r", Opcode::LdrhR),
+    ("ldrsb", Opcode::Ldrsb),
+    ("ldrsh", Opcode::Ldrsh),
+    ("lsl_i", Opcode::LslI),
+    ("lsl_r", Opcode::LslR),
+    ("lsr_i", Opcode::LsrI),
+    ("lsr_r", Opcode::LsrR),
+    ("mov_hr", Opcode::MovHr),
+    ("mov_i", Opcode::MovI),
+    ("mov_r", Opcode::MovR),
+    ("movs_r", Opcode::MovsR),
+    ("mul", Opcode::Mul),
+    ("mvn", Opcode::Mvn),
+    ("neg", Opcode::Neg),
+    ("orr", Opcode::Orr),
+    ("pop", Opcode::Pop),
+    ("push", Opcode::Push),
+    ("ror", Opcode::Ror),
+    ("rsbs", Opcode::Rsbs),
+    ("sbc", Opcode::Sbc),
+    ("stm", Opcode::Stm),
+    ("str_i", Opcode::StrI),
+    ("str_r", Opcode::StrR),
+    ("str_sp", Opcode::StrSp),
+    ("strb_i", Opcode::StrbI),
+    ("strb_r", Opcode::StrbR),
+    ("strh_i", Opcode::StrhI),
+    ("strh_r", Opcode::StrhR),
+    ("sub_8", Opcode::Sub8),
+    ("sub_r", Opcode::SubR),
+    ("sub_sp7", Opcode::SubSp7),
+    ("subs_3", Opcode::Subs3),
+    ("svc", Opcode::Svc),
+    ("swi", Opcode::Swi),
+    ("tst", Opcode::Tst),
+    ("udf", Opcode::Udf),
+];
+const ARG_BRANCH_OFFSET_11: ArgMeta = ArgMeta {
+    name: "branch_offset_11",
+    optional: false,
+    kind: ArgumentKind::BranchDest,
+};
+const ARG_BRANCH_OFFSET_8: ArgMeta = ArgMeta {
+    name: "branch_offset_8",
+    optional: false,
+    kind: ArgumentKind::BranchDest,
+};
+const ARG_HIGH_BRANCH_OFFSET_11: ArgMeta = ArgMeta {
+    name: "high_branch_offset_11",
+    optional: false,
+    kind: ArgumentKind::SImm,
+};
+const ARG_IMMED_3: ArgMeta = ArgMeta {
+    name: "immed_3",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_IMMED_8: ArgMeta = ArgMeta {
+    name: "immed_8",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_LEFT_SHIFT_IMM: ArgMeta = ArgMeta {
+    name: "left_shift_imm",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_LOW_BLX_OFFSET_11: ArgMeta = ArgMeta {
+    name: "low_blx_offset_11",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_LOW_BRANCH_OFFSET_11: ArgMeta = ArgMeta {
+    name: "low_branch_offset_11",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_OFFSET_5: ArgMeta = ArgMeta {
+    name: "offset_5",
+    optional: false,
+    kind: ArgumentKind::OffsetImm,
+};
+const ARG_PC: ArgMeta = ArgMeta {
+    name: "pc",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_PC_DEREF: ArgMeta = ArgMeta {
+    name: "pc_deref",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RD_0: ArgMeta = ArgMeta {
+    name: "Rd_0",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RD_0_UAL: ArgMeta = ArgMeta {
+    name: "Rd_0_ual",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RD_8: ArgMeta = ArgMeta {
+    name: "Rd_8",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RD_H1: ArgMeta = ArgMeta {
+    name: "Rd_H1",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RD_H1_UAL: ArgMeta = ArgMeta {
+    name: "Rd_H1_ual",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_REGISTERS: ArgMeta = ArgMeta {
+    name: "registers",
+    optional: false,
+    kind: ArgumentKind::RegList,
+};
+const ARG_REGISTERS_LR: ArgMeta = ArgMeta {
+    name: "registers_lr",
+    optional: false,
+    kind: ArgumentKind::RegList,
+};
+const ARG_REGISTERS_PC: ArgMeta = ArgMeta {
+    name: "registers_pc",
+    optional: false,
+    kind: ArgumentKind::RegList,
+};
+const ARG_REL_IMMED_7: ArgMeta = ArgMeta {
+    name: "rel_immed_7",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_REL_IMMED_8: ArgMeta = ArgMeta {
+    name: "rel_immed_8",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_RIGHT_SHIFT_IMM: ArgMeta = ArgMeta {
+    name: "right_shift_imm",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+const ARG_RM_3: ArgMeta = ArgMeta {
+    name: "Rm_3",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RM_6: ArgMeta = ArgMeta {
+    name: "Rm_6",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RM_6_OFFSET: ArgMeta = ArgMeta {
+    name: "Rm_6_offset",
+    optional: false,
+    kind: ArgumentKind::OffsetReg,
+};
+const ARG_RM_H2: ArgMeta = ArgMeta {
+    name: "Rm_H2",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_0: ArgMeta = ArgMeta {
+    name: "Rn_0",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_3: ArgMeta = ArgMeta {
+    name: "Rn_3",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_3_DEREF: ArgMeta = ArgMeta {
+    name: "Rn_3_deref",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_8: ArgMeta = ArgMeta {
+    name: "Rn_8",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_8_LDM: ArgMeta = ArgMeta {
+    name: "Rn_8_ldm",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_8_WB: ArgMeta = ArgMeta {
+    name: "Rn_8_wb",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RN_H1: ArgMeta = ArgMeta {
+    name: "Rn_H1",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_RS: ArgMeta = ArgMeta {
+    name: "Rs",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_SP: ArgMeta = ArgMeta {
+    name: "sp",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_SP_DEREF: ArgMeta = ArgMeta {
+    name: "sp_deref",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_SP_UAL: ArgMeta = ArgMeta {
+    name: "sp_ual",
+    optional: false,
+    kind: ArgumentKind::Reg,
+};
+const ARG_ZERO: ArgMeta = ArgMeta {
+    name: "zero",
+    optional: false,
+    kind: ArgumentKind::UImm,
+};
+/// Minimum number of arguments, maximum number of arguments and argument metadata of each opcode,
+/// in divided (pre-UAL) and unified (UAL) syntax.
+static OPCODE_ARGS: [[(u8, u8, &[ArgMeta]); 2]; 73] = [
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3, ARG_IMMED_3]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3, ARG_IMMED_3]),
+    ],
+    [(2, 2, &[ARG_RD_8, ARG_IMMED_8]), (2, 2, &[ARG_RD_8, ARG_IMMED_8])],
+    [(3, 3, &[ARG_RD_0, ARG_RN_3, ARG_RM_6]), (3, 3, &[ARG_RD_0, ARG_RN_3, ARG_RM_6])],
+    [(2, 2, &[ARG_RD_H1, ARG_RM_H2]), (3, 3, &[ARG_RD_H1, ARG_RD_H1_UAL, ARG_RM_H2])],
+    [
+        (3, 3, &[ARG_RD_8, ARG_SP, ARG_REL_IMMED_8]),
+        (3, 3, &[ARG_RD_8, ARG_SP, ARG_REL_IMMED_8]),
+    ],
+    [(2, 2, &[ARG_SP, ARG_REL_IMMED_7]), (3, 3, &[ARG_SP, ARG_SP_UAL, ARG_REL_IMMED_7])],
+    [(3, 3, &[ARG_RD_H1, ARG_SP, ARG_RD_H1]), (3, 3, &[ARG_RD_H1, ARG_SP, ARG_RD_H1])],
+    [(2, 2, &[ARG_SP, ARG_RM_H2]), (3, 3, &[ARG_SP, ARG_SP_UAL, ARG_RM_H2])],
+    [
+        (3, 3, &[ARG_RD_8, ARG_PC, ARG_REL_IMMED_8]),
+        (3, 3, &[ARG_RD_8, ARG_PC, ARG_REL_IMMED_8]),
+    ],
+    [(2, 2, &[ARG_RD_8, ARG_REL_IMMED_8]), (2, 2, &[ARG_RD_8, ARG_REL_IMMED_8])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_RIGHT_SHIFT_IMM]),
+        (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_RIGHT_SHIFT_IMM]),
+    ],
+    [(2, 2, &[ARG_RD_0, ARG_RS]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RS])],
+    [(1, 1, &[ARG_BRANCH_OFFSET_8]), (1, 1, &[ARG_BRANCH_OFFSET_8])],
+    [(1, 1, &[ARG_BRANCH_OFFSET_11]), (1, 1, &[ARG_BRANCH_OFFSET_11])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [(1, 1, &[ARG_IMMED_8]), (1, 1, &[ARG_IMMED_8])],
+    [(1, 1, &[ARG_HIGH_BRANCH_OFFSET_11]), (1, 1, &[ARG_HIGH_BRANCH_OFFSET_11])],
+    [(1, 1, &[ARG_LOW_BRANCH_OFFSET_11]), (1, 1, &[ARG_LOW_BRANCH_OFFSET_11])],
+    [(1, 1, &[ARG_LOW_BLX_OFFSET_11]), (1, 1, &[ARG_LOW_BLX_OFFSET_11])],
+    [(1, 1, &[ARG_RM_H2]), (1, 1, &[ARG_RM_H2])],
+    [(1, 1, &[ARG_RM_H2]), (1, 1, &[ARG_RM_H2])],
+    [(2, 2, &[ARG_RN_0, ARG_RM_3]), (2, 2, &[ARG_RN_0, ARG_RM_3])],
+    [(2, 2, &[ARG_RN_8, ARG_IMMED_8]), (2, 2, &[ARG_RN_8, ARG_IMMED_8])],
+    [(2, 2, &[ARG_RN_0, ARG_RM_3]), (2, 2, &[ARG_RN_0, ARG_RM_3])],
+    [(2, 2, &[ARG_RN_H1, ARG_RM_H2]), (2, 2, &[ARG_RN_H1, ARG_RM_H2])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [(2, 2, &[ARG_RN_8_LDM, ARG_REGISTERS]), (2, 2, &[ARG_RN_8_LDM, ARG_REGISTERS])],
+    [(2, 2, &[ARG_RN_8_WB, ARG_REGISTERS]), (2, 2, &[ARG_RN_8_WB, ARG_REGISTERS])],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_8, ARG_PC_DEREF, ARG_REL_IMMED_8]),
+        (3, 3, &[ARG_RD_8, ARG_PC_DEREF, ARG_REL_IMMED_8]),
+    ],
+    [
+        (3, 3, &[ARG_RD_8, ARG_SP_DEREF, ARG_REL_IMMED_8]),
+        (3, 3, &[ARG_RD_8, ARG_SP_DEREF, ARG_REL_IMMED_8]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_LEFT_SHIFT_IMM]),
+        (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_LEFT_SHIFT_IMM]),
+    ],
+    [(2, 2, &[ARG_RD_0, ARG_RS]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RS])],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_RIGHT_SHIFT_IMM]),
+        (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_RIGHT_SHIFT_IMM]),
+    ],
+    [(2, 2, &[ARG_RD_0, ARG_RS]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RS])],
+    [(2, 2, &[ARG_RD_8, ARG_IMMED_8]), (2, 2, &[ARG_RD_8, ARG_IMMED_8])],
+    [(2, 2, &[ARG_RD_0, ARG_RN_3]), (2, 2, &[ARG_RD_0, ARG_RN_3])],
+    [(2, 2, &[ARG_RD_0, ARG_RN_3]), (2, 2, &[ARG_RD_0, ARG_RN_3])],
+    [(2, 2, &[ARG_RD_H1, ARG_RM_H2]), (2, 2, &[ARG_RD_H1, ARG_RM_H2])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (2, 2, &[ARG_RD_0, ARG_RM_3])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (2, 2, &[ARG_RD_0, ARG_RM_3])],
+    [(3, 3, &[ARG_RD_0, ARG_RM_3, ARG_ZERO]), (3, 3, &[ARG_RD_0, ARG_RM_3, ARG_ZERO])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [(1, 1, &[ARG_REGISTERS_PC]), (1, 1, &[ARG_REGISTERS_PC])],
+    [(1, 1, &[ARG_REGISTERS_LR]), (1, 1, &[ARG_REGISTERS_LR])],
+    [(2, 2, &[ARG_RD_0, ARG_RS]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RS])],
+    [(2, 2, &[ARG_RD_0, ARG_RM_3]), (3, 3, &[ARG_RD_0, ARG_RD_0_UAL, ARG_RM_3])],
+    [(2, 2, &[ARG_RN_8_WB, ARG_REGISTERS]), (2, 2, &[ARG_RN_8_WB, ARG_REGISTERS])],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_8, ARG_SP_DEREF, ARG_REL_IMMED_8]),
+        (3, 3, &[ARG_RD_8, ARG_SP_DEREF, ARG_REL_IMMED_8]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_OFFSET_5]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3_DEREF, ARG_RM_6_OFFSET]),
+    ],
+    [
+        (3, 3, &[ARG_RD_0, ARG_RN_3, ARG_IMMED_3]),
+        (3, 3, &[ARG_RD_0, ARG_RN_3, ARG_IMMED_3]),
+    ],
+    [(2, 2, &[ARG_RD_8, ARG_IMMED_8]), (2, 2, &[ARG_RD_8, ARG_IMMED_8])],
+    [(3, 3, &[ARG_RD_0, ARG_RN_3, ARG_RM_6]), (3, 3, &[ARG_RD_0, ARG_RN_3, ARG_RM_6])],
+    [(2, 2, &[ARG_SP, ARG_REL_IMMED_7]), (3, 3, &[ARG_SP, ARG_SP_UAL, ARG_REL_IMMED_7])],
+    [(1, 1, &[ARG_IMMED_8]), (1, 1, &[ARG_IMMED_8])],
+    [(1, 1, &[ARG_IMMED_8]), (1, 1, &[ARG_IMMED_8])],
+    [(2, 2, &[ARG_RN_0, ARG_RM_3]), (2, 2, &[ARG_RN_0, ARG_RM_3])],
+    [(1, 1, &[ARG_IMMED_8]), (1, 1, &[ARG_IMMED_8])],
+];
+/// Bitmask, pattern and fields of each opcode.
+static OPCODE_FIELDS: [(u32, u32, &[FieldDesc]); 73] = [
+    (
+        0x0000ffc0,
+        0x00004140,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00001c00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "immed_3",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00003000,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00001800,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x00004400,
+        &[
+            FieldDesc {
+                name: "Rd_H1",
+                bitmask: 0x00000087,
+            },
+            FieldDesc {
+                name: "Rm_H2",
+                bitmask: 0x00000078,
+            },
+            FieldDesc {
+                name: "Rd_H1_ual",
+                bitmask: 0x00000087,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000a800,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "rel_immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000ff80,
+        0x0000b000,
+        &[
+            FieldDesc {
+                name: "rel_immed_7",
+                bitmask: 0x0000007f,
+            },
+        ],
+    ),
+    (
+        0x0000ff78,
+        0x00004468,
+        &[
+            FieldDesc {
+                name: "Rd_H1",
+                bitmask: 0x00000087,
+            },
+        ],
+    ),
+    (
+        0x0000ff87,
+        0x00004485,
+        &[
+            FieldDesc {
+                name: "Rm_H2",
+                bitmask: 0x00000078,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000a000,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "rel_immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000a000,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "rel_immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00001000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "right_shift_imm",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004100,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000f000,
+        0x0000d000,
+        &[
+            FieldDesc {
+                name: "branch_offset_8",
+                bitmask: 0x000000ff,
+            },
+            FieldDesc {
+                name: "cond",
+                bitmask: 0x00000f00,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000e000,
+        &[
+            FieldDesc {
+                name: "branch_offset_11",
+                bitmask: 0x000007ff,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004380,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x0000be00,
+        &[
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000f000,
+        &[
+            FieldDesc {
+                name: "high_branch_offset_11",
+                bitmask: 0x000007ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000f800,
+        &[
+            FieldDesc {
+                name: "low_branch_offset_11",
+                bitmask: 0x000007ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000e800,
+        &[
+            FieldDesc {
+                name: "low_blx_offset_11",
+                bitmask: 0x000007ff,
+            },
+        ],
+    ),
+    (
+        0x0000ff87,
+        0x00004780,
+        &[
+            FieldDesc {
+                name: "Rm_H2",
+                bitmask: 0x00000078,
+            },
+        ],
+    ),
+    (
+        0x0000ff87,
+        0x00004700,
+        &[
+            FieldDesc {
+                name: "Rm_H2",
+                bitmask: 0x00000078,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x000042c0,
+        &[
+            FieldDesc {
+                name: "Rn_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00002800,
+        &[
+            FieldDesc {
+                name: "Rn_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004280,
+        &[
+            FieldDesc {
+                name: "Rn_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x00004500,
+        &[
+            FieldDesc {
+                name: "Rn_H1",
+                bitmask: 0x00000087,
+            },
+            FieldDesc {
+                name: "Rm_H2",
+                bitmask: 0x00000078,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004040,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000c800,
+        &[
+            FieldDesc {
+                name: "Rn_8_ldm",
+                bitmask: 0x000007ff,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000c800,
+        &[
+            FieldDesc {
+                name: "Rn_8_wb",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00006800,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "offset_5",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005800,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00004800,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "rel_immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00009800,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "rel_immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00007800,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "offset_5",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005c00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00008800,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "offset_5",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005a00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005600,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005e00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00000000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "left_shift_imm",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004080,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00000800,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "right_shift_imm",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x000040c0,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00002000,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00001c00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00000000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x00004600,
+        &[
+            FieldDesc {
+                name: "Rd_H1",
+                bitmask: 0x00000087,
+            },
+            FieldDesc {
+                name: "Rm_H2",
+                bitmask: 0x00000078,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004340,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x000043c0,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004240,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004240,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004300,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x0000bc00,
+        &[
+            FieldDesc {
+                name: "registers_pc",
+                bitmask: 0x000001ff,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x0000b400,
+        &[
+            FieldDesc {
+                name: "registers_lr",
+                bitmask: 0x000001ff,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x000041c0,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rs",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004180,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rd_0_ual",
+                bitmask: 0x00000007,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x0000c000,
+        &[
+            FieldDesc {
+                name: "Rn_8_wb",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "registers",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00006000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "offset_5",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00009000,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "rel_immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00007000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "offset_5",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005400,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00008000,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "offset_5",
+                bitmask: 0x000007c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00005200,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3_deref",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6_offset",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00001e00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "immed_3",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000f800,
+        0x00003800,
+        &[
+            FieldDesc {
+                name: "Rd_8",
+                bitmask: 0x00000700,
+            },
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000fe00,
+        0x00001a00,
+        &[
+            FieldDesc {
+                name: "Rd_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rn_3",
+                bitmask: 0x00000038,
+            },
+            FieldDesc {
+                name: "Rm_6",
+                bitmask: 0x000001c0,
+            },
+        ],
+    ),
+    (
+        0x0000ff80,
+        0x0000b080,
+        &[
+            FieldDesc {
+                name: "rel_immed_7",
+                bitmask: 0x0000007f,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x0000df00,
+        &[
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x0000df00,
+        &[
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+    (
+        0x0000ffc0,
+        0x00004200,
+        &[
+            FieldDesc {
+                name: "Rn_0",
+                bitmask: 0x00000007,
+            },
+            FieldDesc {
+                name: "Rm_3",
+                bitmask: 0x00000038,
+            },
+        ],
+    ),
+    (
+        0x0000ff00,
+        0x0000de00,
+        &[
+            FieldDesc {
+                name: "immed_8",
+                bitmask: 0x000000ff,
+            },
+        ],
+    ),
+];
+/// Every opcode enabled by the cargo features, in order of discriminant.
+static OPCODES: &[Opcode] = &[
+    Opcode::Adc,
+    Opcode::Add3,
+    Opcode::Add8,
+    Opcode::AddR,
+    Opcode::AddHr,
+    Opcode::AddSp,
+    Opcode::AddSp7,
+    Opcode::AddRegSp,
+    Opcode::AddSpReg,
+    Opcode::AddPc,
+    Opcode::Adr,
+    Opcode::And,
+    Opcode::AsrI,
+    Opcode::AsrR,
+    Opcode::B,
+    Opcode::BLong,
+    Opcode::Bic,
+    Opcode::Bkpt,
+    Opcode::BlH,
+    Opcode::Bl,
+    Opcode::BlxI,
+    Opcode::BlxR,
+    Opcode::BxR,
+    Opcode::Cmn,
+    Opcode::CmpI,
+    Opcode::CmpR,
+    Opcode::CmpHr,
+    Opcode::Eor,
+    Opcode::Ldm,
+    Opcode::Ldmia,
+    Opcode::LdrI,
+    Opcode::LdrR,
+    Opcode::LdrPc,
+    Opcode::LdrSp,
+    Opcode::LdrbI,
+    Opcode::LdrbR,
+    Opcode::LdrhI,
+    Opcode::LdrhR,
+    Opcode::Ldrsb,
+    Opcode::Ldrsh,
+    Opcode::LslI,
+    Opcode::LslR,
+    Opcode::LsrI,
+    Opcode::LsrR,
+    Opcode::MovI,
+    Opcode::MovR,
+    Opcode::MovsR,
+    Opcode::MovHr,
+    Opcode::Mul,
+    Opcode::Mvn,
+    Opcode::Neg,
+    Opcode::Rsbs,
+    Opcode::Orr,
+    Opcode::Pop,
+    Opcode::Push,
+    Opcode::Ror,
+    Opcode::Sbc,
+    Opcode::Stm,
+    Opcode::StrI,
+    Opcode::StrR,
+    Opcode::StrSp,
+    Opcode::StrbI,
+    Opcode::StrbR,
+    Opcode::StrhI,
+    Opcode::StrhR,
+    Opcode::Subs3,
+    Opcode::Sub8,
+    Opcode::SubR,
+    Opcode::SubSp7,
+    Opcode::Svc,
+    Opcode::Swi,
+    Opcode::Tst,
+    Opcode::Udf,
+];
+/// Maps the bits 0x0000fff0 of an instruction to its opcode, unless the residual group checks another
+/// opcode first. See [`Opcode::find_table`].
+static FIND_TABLE: [(Opcode, u8); 4096] = [
+    (Opcode::LslI, 1),
+    (Opcode::LslI, 1),
+    (Opcode::LslI, 1),
+    (Opcode::LslI, 1),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LslI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::LsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AsrI, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::AddR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::SubR, 0),
+    (Opcode::Add3, 2),
+    (Opcode::Add3, 2),
+    (Opcode::Add3, 2),
+    (Opcode::Add3, 2),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Add3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::Subs3, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::MovI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::CmpI, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Add8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::Sub8, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::And, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::Eor, 0),
+    (Opcode::LslR, 0),
+    (Opcode::LslR, 0),
+    (Opcode::LslR, 0),
+    (Opcode::LslR, 0),
+    (Opcode::LsrR, 0),
+    (Opcode::LsrR, 0),
+    (Opcode::LsrR, 0),
+    (Opcode::LsrR, 0),
+    (Opcode::AsrR, 0),
+    (Opcode::AsrR, 0),
+    (Opcode::AsrR, 0),
+    (Opcode::AsrR, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Adc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Sbc, 0),
+    (Opcode::Ror, 0),
+    (Opcode::Ror, 0),
+    (Opcode::Ror, 0),
+    (Opcode::Ror, 0),
+    (Opcode::Tst, 0),
+    (Opcode::Tst, 0),
+    (Opcode::Tst, 0),
+    (Opcode::Tst, 0),
+    (Opcode::Illegal, 3),
+    (Opcode::Illegal, 3),
+    (Opcode::Illegal, 3),
+    (Opcode::Illegal, 3),
+    (Opcode::CmpR, 0),
+    (Opcode::CmpR, 0),
+    (Opcode::CmpR, 0),
+    (Opcode::CmpR, 0),
+    (Opcode::Cmn, 0),
+    (Opcode::Cmn, 0),
+    (Opcode::Cmn, 0),
+    (Opcode::Cmn, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Orr, 0),
+    (Opcode::Mul, 0),
+    (Opcode::Mul, 0),
+    (Opcode::Mul, 0),
+    (Opcode::Mul, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Bic, 0),
+    (Opcode::Mvn, 0),
+    (Opcode::Mvn, 0),
+    (Opcode::Mvn, 0),
+    (Opcode::Mvn, 0),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 4),
+    (Opcode::AddHr, 0),
+    (Opcode::AddHr, 5),
+    (Opcode::AddHr, 5),
+    (Opcode::AddHr, 5),
+    (Opcode::AddHr, 5),
+    (Opcode::AddHr, 5),
+    (Opcode::AddHr, 5),
+    (Opcode::AddHr, 6),
+    (Opcode::AddHr, 5),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::CmpHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::MovHr, 0),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 7),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::Illegal, 8),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::LdrPc, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrhR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::StrbR, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::Ldrsb, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrhR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::LdrbR, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::Ldrsh, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::StrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::LdrI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::StrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::LdrbI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::StrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::LdrhI, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::StrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::LdrSp, 0),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::Illegal, 9),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::AddSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::SubSp7, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Push, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Pop, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Bkpt, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Illegal, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Stm, 0),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::Illegal, 10),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::B, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::Udf, 0),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::B, 11),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BLong, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlxI, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::BlH, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+    (Opcode::Bl, 0),
+];
+/// Length of the longest mnemonic, including `<illegal>`.
+pub const MAX_MNEMONIC_LEN: usize = 9;
+/// Upper bound of the length of a rendered instruction. This holds for any [`crate::DisplayOptions`] without a
+/// mnemonic hook, as no register name is longer than the default ones.
+pub const MAX_RENDERED_LEN: usize = 80;
+/// Every mnemonic without a condition suffix, sorted.
+pub(crate) static UNCONDITIONAL_MNEMONICS: [&str; 58] = [
+    "adc",
+    "adcs",
+    "add",
+    "adds",
+    "adr",
+    "and",
+    "ands",
+    "asr",
+    "asrs",
+    "b",
+    "bic",
+    "bics",
+    "bkpt",
+    "bl",
+    "blx",
+    "bx",
+    "cmn",
+    "cmp",
+    "eor",
+    "eors",
+    "ldm",
+    "ldmia",
+    "ldr",
+    "ldrb",
+    "ldrh",
+    "ldrsb",
+    "ldrsh",
+    "lsl",
+    "lsls",
+    "lsr",
+    "lsrs",
+    "mov",
+    "movs",
+    "mul",
+    "muls",
+    "mvn",
+    "mvns",
+    "neg",
+    "orr",
+    "orrs",
+    "pop",
+    "push",
+    "ror",
+    "rors",
+    "rsbs",
+    "sbc",
+    "sbcs",
+    "stm",
+    "stmia",
+    "str",
+    "strb",
+    "strh",
+    "sub",
+    "subs",
+    "svc",
+    "swi",
+    "tst",
+    "udf",
+];
+/// Every mnemonic in both syntaxes, sorted. Used to parse and deserialize [`ParsedIns::mnemonic`].
+pub(crate) static MNEMONICS: [&str; 73] = [
+    "<illegal>",
+    "adc",
+    "adcs",
+    "add",
+    "adds",
+    "adr",
+    "and",
+    "ands",
+    "asr",
+    "asrs",
+    "b",
+    "beq",
+    "bge",
+    "bgt",
+    "bhi",
+    "bhs",
+    "bic",
+    "bics",
+    "bkpt",
+    "bl",
+    "ble",
+    "blo",
+    "bls",
+    "blt",
+    "blx",
+    "bmi",
+    "bne",
+    "bpl",
+    "bvc",
+    "bvs",
+    "bx",
+    "cmn",
+    "cmp",
+    "eor",
+    "eors",
+    "ldm",
+    "ldmia",
+    "ldr",
+    "ldrb",
+    "ldrh",
+    "ldrsb",
+    "ldrsh",
+    "lsl",
+    "lsls",
+    "lsr",
+    "lsrs",
+    "mov",
+    "movs",
+    "mul",
+    "muls",
+    "mvn",
+    "mvns",
+    "neg",
+    "orr",
+    "orrs",
+    "pop",
+    "push",
+    "ror",
+    "rors",
+    "rsbs",
+    "sbc",
+    "sbcs",
+    "stm",
+    "stmia",
+    "str",
+    "strb",
+    "strh",
+    "sub",
+    "subs",
+    "svc",
+    "swi",
+    "tst",
+    "udf",
+];
+/// One-line description of each opcode.
+static OPCODE_DESCRIPTIONS: [&str; 73] = [
+    "Add with Carry",
+    "Add 3-bit immediate",
+    "Add 8-bit immediate",
+    "Add register",
+    "Add high register",
+    "Add SP-relative address",
+    "Add 7-bit immediate multiple of 4 to SP",
+    "Add SP to register",
+    "Add register to SP",
+    "Add 8-bit immediate multiple of 4 to PC",
+    "Add PC-relative address",
+    "Bitwise AND",
+    "Arithmetic Shift Right by 5-bit immediate",
+    "Arithmetic Shift Right by register",
+    "Branch",
+    "Branch (unconditional, long)",
+    "Bit Clear",
+    "Breakpoint",
+    "Branch and Link (high part)",
+    "Branch and Link (low part)",
+    "Branch and Link and Exchange to ARM (low part, immediate target)",
+    "Branch and Link and Exchange to ARM (register target)",
+    "Branch and Exchange",
+    "Compare Negative",
+    "Compare with immediate",
+    "Compare with register",
+    "Compare with high register",
+    "Exclusive OR",
+    "Load Multiple",
+    "Load Multiple",
+    "Load Register with immediate offset",
+    "Load Register with register offset",
+    "Load Register with PC-relative address",
+    "Load Register with SP-relative address",
+    "Load Register Byte with immediate offset",
+    "Load Register Byte with register offset",
+    "Load Register Halfword with immediate offset",
+    "Load Register Halfword with register offset",
+    "Load Register Signed Byte",
+    "Load Register Signed Halfword",
+    "Logical Shift Left by 5-bit immediate",
+    "Logical Shift Left by register",
+    "Logical Shift Right by 5-bit immediate",
+    "Logical Shift Right by register",
+    "Move immediate",
+    "Move register",
+    "Move register",
+    "Move high register",
+    "Multiply",
+    "Move Negative",
+    "Negate",
+    "Negate",
+    "Bitwise OR",
+    "Pop multiple registers",
+    "Push multiple registers",
+    "Rotate Right",
+    "Subtract with Carry",
+    "Store Multiple",
+    "Store Register with immediate offset",
+    "Store Register with register offset",
+    "Store Register with SP-relative address",
+    "Store Register Byte with immediate offset",
+    "Store Register Byte with register offset",
+    "Store Register Halfword with immediate offset",
+    "Store Register Halfword with register offset",
+    "Subtract 3-bit immediate",
+    "Subtract 8-bit immediate",
+    "Subtract register",
+    "Subtract 7-bit immediate multiple of 4 from SP",
+    "Supervisor Call",
+    "Software Interrupt",
+    "Test",
+    "Permanently Undefined",
+];
+/// Every mnemonic of each opcode without a condition suffix, in both syntaxes.
+static OPCODE_SPELLINGS: &[(Opcode, &[&str])] = &[
+    (Opcode::Adc, &["adc", "adcs"]),
+    (Opcode::Add3, &["add", "adds"]),
+    (Opcode::Add8, &["add", "adds"]),
+    (Opcode::AddR, &["add", "adds"]),
+    (Opcode::AddHr, &["add"]),
+    (Opcode::AddSp, &["add"]),
+    (Opcode::AddSp7, &["add"]),
+    (Opcode::AddRegSp, &["add"]),
+    (Opcode::AddSpReg, &["add"]),
+    (Opcode::AddPc, &["add"]),
+    (Opcode::Adr, &["adr"]),
+    (Opcode::And, &["and", "ands"]),
+    (Opcode::AsrI, &["asr", "asrs"]),
+    (Opcode::AsrR, &["asr", "asrs"]),
+    (Opcode::B, &[]),
+    (Opcode::BLong, &["b"]),
+    (Opcode::Bic, &["bic", "bics"]),
+    (Opcode::Bkpt, &["bkpt"]),
+    (Opcode::BlH, &["bl"]),
+    (Opcode::Bl, &["bl"]),
+    (Opcode::BlxI, &["blx"]),
+    (Opcode::BlxR, &["blx"]),
+    (Opcode::BxR, &["bx"]),
+    (Opcode::Cmn, &["cmn"]),
+    (Opcode::CmpI, &["cmp"]),
+    (Opcode::CmpR, &["cmp"]),
+    (Opcode::CmpHr, &["cmp"]),
+    (Opcode::Eor, &["eor", "eors"]),
+    (Opcode::Ldm, &["ldm"]),
+    (Opcode::Ldmia, &["ldmia"]),
+    (Opcode::LdrI, &["ldr"]),
+    (Opcode::LdrR, &["ldr"]),
+    (Opcode::LdrPc, &["ldr"]),
+    (Opcode::LdrSp, &["ldr"]),
+    (Opcode::LdrbI, &["ldrb"]),
+    (Opcode::LdrbR, &["ldrb"]),
+    (Opcode::LdrhI, &["ldrh"]),
+    (Opcode::LdrhR, &["ldrh"]),
+    (Opcode::Ldrsb, &["ldrsb"]),
+    (Opcode::Ldrsh, &["ldrsh"]),
+    (Opcode::LslI, &["lsl", "lsls"]),
+    (Opcode::LslR, &["lsl", "lsls"]),
+    (Opcode::LsrI, &["lsr", "lsrs"]),
+    (Opcode::LsrR, &["lsr", "lsrs"]),
+    (Opcode::MovI, &["mov", "movs"]),
+    (Opcode::MovR, &["mov"]),
+    (Opcode::MovsR, &["movs"]),
+    (Opcode::MovHr, &["mov"]),
+    (Opcode::Mul, &["mul", "muls"]),
+    (Opcode::Mvn, &["mvn", "mvns"]),
+    (Opcode::Neg, &["neg"]),
+    (Opcode::Rsbs, &["rsbs"]),
+    (Opcode::Orr, &["orr", "orrs"]),
+    (Opcode::Pop, &["pop"]),
+    (Opcode::Push, &["push"]),
+    (Opcode::Ror, &["ror", "rors"]),
+    (Opcode::Sbc, &["sbc", "sbcs"]),
+    (Opcode::Stm, &["stm", "stmia"]),
+    (Opcode::StrI, &["str"]),
+    (Opcode::StrR, &["str"]),
+    (Opcode::StrSp, &["str"]),
+    (Opcode::StrbI, &["strb"]),
+    (Opcode::StrbR, &["strb"]),
+    (Opcode::StrhI, &["strh"]),
+    (Opcode::StrhR, &["strh"]),
+    (Opcode::Subs3, &["subs"]),
+    (Opcode::Sub8, &["sub", "subs"]),
+    (Opcode::SubR, &["sub", "subs"]),
+    (Opcode::SubSp7, &["sub"]),
+    (Opcode::Svc, &["svc"]),
+    (Opcode::Swi, &["swi"]),
+    (Opcode::Tst, &["tst"]),
+    (Opcode::Udf, &["udf"]),
+];
+/// Opcode of an instruction. The discriminants, such as `op as u8`, change whenever opcodes are added or
+/// reordered, so use [`Opcode::id`] or [`Opcode::variant_name`] to store an opcode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum Opcode {
@@ -87,153 +6232,541 @@ pub enum Opcode {
     #[default]
     Illegal = u8::MAX,
     /// ADCS: Add with Carry
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100000101 | Rm_3 | Rd_0
+    /// ```
     Adc = 0,
     /// ADDS: Add 3-bit immediate
+    ///
+    /// ```text
+    /// 15:9    | 8:6     | 5:3  | 2:0
+    /// 0001110 | immed_3 | Rn_3 | Rd_0
+    /// ```
     Add3 = 1,
     /// ADDS: Add 8-bit immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 00110 | Rd_8 | immed_8
+    /// ```
     Add8 = 2,
     /// ADDS: Add register
+    ///
+    /// ```text
+    /// 15:9    | 8:6  | 5:3  | 2:0
+    /// 0001100 | Rm_6 | Rn_3 | Rd_0
+    /// ```
     AddR = 3,
     /// ADD: Add high register
+    ///
+    /// ```text
+    /// 15:8     | 7     | 6:3   | 2:0
+    /// 01000100 | Rd_H1 | Rm_H2 | Rd_H1
+    /// ```
     AddHr = 4,
     /// ADD: Add SP-relative address
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 10101 | Rd_8 | rel_immed_8
+    /// ```
     AddSp = 5,
     /// ADD: Add 7-bit immediate multiple of 4 to SP
+    ///
+    /// ```text
+    /// 15:7      | 6:0
+    /// 101100000 | rel_immed_7
+    /// ```
     AddSp7 = 6,
     /// ADD: Add SP to register
+    ///
+    /// ```text
+    /// 15:8     | 7     | 6:3  | 2:0
+    /// 01000100 | Rd_H1 | 1101 | Rd_H1
+    /// ```
     AddRegSp = 7,
     /// ADD: Add register to SP
+    ///
+    /// ```text
+    /// 15:7      | 6:3   | 2:0
+    /// 010001001 | Rm_H2 | 101
+    /// ```
     AddSpReg = 8,
     /// ADD: Add 8-bit immediate multiple of 4 to PC
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 10100 | Rd_8 | rel_immed_8
+    /// ```
     AddPc = 9,
     /// ADR: Add PC-relative address
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 10100 | Rd_8 | rel_immed_8
+    /// ```
     Adr = 10,
     /// ANDS: Bitwise AND
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100000000 | Rm_3 | Rd_0
+    /// ```
     And = 11,
     /// ASRS: Arithmetic Shift Right by 5-bit immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:6            | 5:3  | 2:0
+    /// 00010 | right_shift_imm | Rm_3 | Rd_0
+    /// ```
     AsrI = 12,
     /// ASRS: Arithmetic Shift Right by register
+    ///
+    /// ```text
+    /// 15:6       | 5:3 | 2:0
+    /// 0100000100 | Rs  | Rd_0
+    /// ```
     AsrR = 13,
     /// B: Branch
+    ///
+    /// ```text
+    /// 15:12 | 11:8 | 7:0
+    /// 1101  | cond | branch_offset_8
+    /// ```
     B = 14,
     /// B: Branch (unconditional, long)
+    ///
+    /// ```text
+    /// 15:11 | 10:0
+    /// 11100 | branch_offset_11
+    /// ```
     BLong = 15,
     /// BICS: Bit Clear
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001110 | Rm_3 | Rd_0
+    /// ```
     Bic = 16,
     /// BKPT: Breakpoint
+    ///
+    /// ```text
+    /// 15:8     | 7:0
+    /// 10111110 | immed_8
+    /// ```
     Bkpt = 17,
     /// BL: Branch and Link (high part)
+    ///
+    /// ```text
+    /// 15:11 | 10:0
+    /// 11110 | high_branch_offset_11
+    /// ```
     BlH = 18,
     /// BL: Branch and Link (low part)
+    ///
+    /// ```text
+    /// 15:11 | 10:0
+    /// 11111 | low_branch_offset_11
+    /// ```
     Bl = 19,
     /// BLX: Branch and Link and Exchange to ARM (low part, immediate target)
+    ///
+    /// ```text
+    /// 15:11 | 10:0
+    /// 11101 | low_blx_offset_11
+    /// ```
     BlxI = 20,
     /// BLX: Branch and Link and Exchange to ARM (register target)
+    ///
+    /// ```text
+    /// 15:7      | 6:3   | 2:0
+    /// 010001111 | Rm_H2 | 000
+    /// ```
     BlxR = 21,
     /// BX: Branch and Exchange
+    ///
+    /// ```text
+    /// 15:7      | 6:3   | 2:0
+    /// 010001110 | Rm_H2 | 000
+    /// ```
     BxR = 22,
     /// CMN: Compare Negative
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001011 | Rm_3 | Rn_0
+    /// ```
     Cmn = 23,
     /// CMP: Compare with immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 00101 | Rn_8 | immed_8
+    /// ```
     CmpI = 24,
     /// CMP: Compare with register
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001010 | Rm_3 | Rn_0
+    /// ```
     CmpR = 25,
     /// CMP: Compare with high register
+    ///
+    /// ```text
+    /// 15:8     | 7     | 6:3   | 2:0
+    /// 01000101 | Rn_H1 | Rm_H2 | Rn_H1
+    /// ```
     CmpHr = 26,
     /// EORS: Exclusive OR
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100000001 | Rm_3 | Rd_0
+    /// ```
     Eor = 27,
     /// LDM: Load Multiple
+    ///
+    /// ```text
+    /// 15:11 | 10:0
+    /// 11001 | Rn_8_ldm
+    /// ```
     Ldm = 28,
     /// LDMIA: Load Multiple
+    ///
+    /// ```text
+    /// 15:11 | 10:8    | 7:0
+    /// 11001 | Rn_8_wb | registers
+    /// ```
     Ldmia = 29,
     /// LDR: Load Register with immediate offset
+    ///
+    /// ```text
+    /// 15:11 | 10:6     | 5:3        | 2:0
+    /// 01101 | offset_5 | Rn_3_deref | Rd_0
+    /// ```
     LdrI = 30,
     /// LDR: Load Register with register offset
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101100 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     LdrR = 31,
     /// LDR: Load Register with PC-relative address
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 01001 | Rd_8 | rel_immed_8
+    /// ```
     LdrPc = 32,
     /// LDR: Load Register with SP-relative address
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 10011 | Rd_8 | rel_immed_8
+    /// ```
     LdrSp = 33,
     /// LDRB: Load Register Byte with immediate offset
+    ///
+    /// ```text
+    /// 15:11 | 10:6     | 5:3        | 2:0
+    /// 01111 | offset_5 | Rn_3_deref | Rd_0
+    /// ```
     LdrbI = 34,
     /// LDRB: Load Register Byte with register offset
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101110 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     LdrbR = 35,
     /// LDRH: Load Register Halfword with immediate offset
+    ///
+    /// ```text
+    /// 15:11 | 10:6     | 5:3        | 2:0
+    /// 10001 | offset_5 | Rn_3_deref | Rd_0
+    /// ```
     LdrhI = 36,
     /// LDRH: Load Register Halfword with register offset
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101101 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     LdrhR = 37,
     /// LDRSB: Load Register Signed Byte
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101011 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     Ldrsb = 38,
     /// LDRSH: Load Register Signed Halfword
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101111 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     Ldrsh = 39,
     /// LSLS: Logical Shift Left by 5-bit immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:6           | 5:3  | 2:0
+    /// 00000 | left_shift_imm | Rm_3 | Rd_0
+    /// ```
     LslI = 40,
     /// LSLS: Logical Shift Left by register
+    ///
+    /// ```text
+    /// 15:6       | 5:3 | 2:0
+    /// 0100000010 | Rs  | Rd_0
+    /// ```
     LslR = 41,
     /// LSRS: Logical Shift Right by 5-bit immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:6            | 5:3  | 2:0
+    /// 00001 | right_shift_imm | Rm_3 | Rd_0
+    /// ```
     LsrI = 42,
     /// LSRS: Logical Shift Right by register
+    ///
+    /// ```text
+    /// 15:6       | 5:3 | 2:0
+    /// 0100000011 | Rs  | Rd_0
+    /// ```
     LsrR = 43,
     /// MOVS: Move immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 00100 | Rd_8 | immed_8
+    /// ```
     MovI = 44,
     /// MOV: Move register
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0001110000 | Rn_3 | Rd_0
+    /// ```
     MovR = 45,
     /// MOVS: Move register
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0000000000 | Rn_3 | Rd_0
+    /// ```
     MovsR = 46,
     /// MOV: Move high register
+    ///
+    /// ```text
+    /// 15:8     | 7     | 6:3   | 2:0
+    /// 01000110 | Rd_H1 | Rm_H2 | Rd_H1
+    /// ```
     MovHr = 47,
     /// MULS: Multiply
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001101 | Rm_3 | Rd_0
+    /// ```
     Mul = 48,
     /// MVNS: Move Negative
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001111 | Rm_3 | Rd_0
+    /// ```
     Mvn = 49,
     /// NEG: Negate
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001001 | Rm_3 | Rd_0
+    /// ```
     Neg = 50,
     /// RSBS: Negate
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001001 | Rm_3 | Rd_0
+    /// ```
     Rsbs = 51,
     /// ORRS: Bitwise OR
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001100 | Rm_3 | Rd_0
+    /// ```
     Orr = 52,
     /// POP: Pop multiple registers
+    ///
+    /// ```text
+    /// 15:9    | 8:0
+    /// 1011110 | registers_pc
+    /// ```
     Pop = 53,
     /// PUSH: Push multiple registers
+    ///
+    /// ```text
+    /// 15:9    | 8:0
+    /// 1011010 | registers_lr
+    /// ```
     Push = 54,
     /// RORS: Rotate Right
+    ///
+    /// ```text
+    /// 15:6       | 5:3 | 2:0
+    /// 0100000111 | Rs  | Rd_0
+    /// ```
     Ror = 55,
     /// SBCS: Subtract with Carry
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100000110 | Rm_3 | Rd_0
+    /// ```
     Sbc = 56,
     /// STM: Store Multiple
+    ///
+    /// ```text
+    /// 15:11 | 10:8    | 7:0
+    /// 11000 | Rn_8_wb | registers
+    /// ```
     Stm = 57,
     /// STR: Store Register with immediate offset
+    ///
+    /// ```text
+    /// 15:11 | 10:6     | 5:3        | 2:0
+    /// 01100 | offset_5 | Rn_3_deref | Rd_0
+    /// ```
     StrI = 58,
     /// STR: Store Register with register offset
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101000 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     StrR = 59,
     /// STR: Store Register with SP-relative address
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 10010 | Rd_8 | rel_immed_8
+    /// ```
     StrSp = 60,
     /// STRB: Store Register Byte with immediate offset
+    ///
+    /// ```text
+    /// 15:11 | 10:6     | 5:3        | 2:0
+    /// 01110 | offset_5 | Rn_3_deref | Rd_0
+    /// ```
     StrbI = 61,
     /// STRB: Store Register Byte with register offset
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101010 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     StrbR = 62,
     /// STRH: Store Register Halfword with immediate offset
+    ///
+    /// ```text
+    /// 15:11 | 10:6     | 5:3        | 2:0
+    /// 10000 | offset_5 | Rn_3_deref | Rd_0
+    /// ```
     StrhI = 63,
     /// STRH: Store Register Halfword with register offset
+    ///
+    /// ```text
+    /// 15:9    | 8:6         | 5:3        | 2:0
+    /// 0101001 | Rm_6_offset | Rn_3_deref | Rd_0
+    /// ```
     StrhR = 64,
     /// SUBS: Subtract 3-bit immediate
+    ///
+    /// ```text
+    /// 15:9    | 8:6     | 5:3  | 2:0
+    /// 0001111 | immed_3 | Rn_3 | Rd_0
+    /// ```
     Subs3 = 65,
     /// SUBS: Subtract 8-bit immediate
+    ///
+    /// ```text
+    /// 15:11 | 10:8 | 7:0
+    /// 00111 | Rd_8 | immed_8
+    /// ```
     Sub8 = 66,
     /// SUBS: Subtract register
+    ///
+    /// ```text
+    /// 15:9    | 8:6  | 5:3  | 2:0
+    /// 0001101 | Rm_6 | Rn_3 | Rd_0
+    /// ```
     SubR = 67,
     /// SUB: Subtract 7-bit immediate multiple of 4 from SP
+    ///
+    /// ```text
+    /// 15:7      | 6:0
+    /// 101100001 | rel_immed_7
+    /// ```
     SubSp7 = 68,
     /// SVC: Supervisor Call
+    ///
+    /// ```text
+    /// 15:8     | 7:0
+    /// 11011111 | immed_8
+    /// ```
     Svc = 69,
     /// SWI: Software Interrupt
+    ///
+    /// ```text
+    /// 15:8     | 7:0
+    /// 11011111 | immed_8
+    /// ```
     Swi = 70,
     /// TST: Test
+    ///
+    /// ```text
+    /// 15:6       | 5:3  | 2:0
+    /// 0100001000 | Rm_3 | Rn_0
+    /// ```
     Tst = 71,
+    /// UDF: Permanently Undefined
+    ///
+    /// ```text
+    /// 15:8     | 7:0
+    /// 11011110 | immed_8
+    /// ```
+    Udf = 72,
 }
 impl Opcode {
+    /// Decodes the opcode with a tree of bit tests.
+    #[cfg(not(feature = "fast"))]
+    #[inline]
+    pub const fn find(code: u32, flags: &ParseFlags) -> Self {
+        let guard = crate::no_panic::NoPanicGuard;
+        let op = Self::find_tree(code, flags);
+        guard.disarm();
+        op
+    }
+    /// Decodes the opcode with a lookup table.
+    #[cfg(feature = "fast")]
+    #[inline]
+    pub const fn find(code: u32, flags: &ParseFlags) -> Self {
+        let guard = crate::no_panic::NoPanicGuard;
+        let op = Self::find_table(code, flags);
+        guard.disarm();
+        op
+    }
+    /// Implementation of [`Self::find`] as a tree of bit tests. Public to test that it's equivalent to
+    /// [`Self::find_table`], whichever one the profile uses.
+    #[doc(hidden)]
     #[inline]
-    pub fn find(code: u32, flags: &ParseFlags) -> Self {
+    pub const fn find_tree(code: u32, flags: &ParseFlags) -> Self {
         if (code & 0x00001000) == 0x00000000 {
             if (code & 0x00000400) == 0x00000400 {
                 if (code & 0x00008000) == 0x00000000 {
@@ -688,220 +7221,871 @@ impl Opcode {
             } else if flags.ual && (code & 0x0000f800) == 0x0000a000 {
                 return Opcode::Adr;
             }
-        } else if (code & 0x00004000) == 0x00004000 {
-            if (code & 0x00000200) == 0x00000000 {
-                if (code & 0x00002000) == 0x00002000 {
+        } else if (code & 0x00004000) == 0x00000000 {
+            if (code & 0x00002000) == 0x00002000 {
+                if (code & 0x00000200) == 0x00000200 {
                     if (code & 0x00000800) == 0x00000000 {
-                        if (code & 0x00008000) == 0x00000000 {
-                            if (code & 0x0000f800) == 0x00007000 {
-                                return Opcode::StrbI;
-                            }
-                        } else if (code & 0x0000f800) == 0x0000f000 {
-                            return Opcode::BlH;
+                        if (code & 0x0000f800) == 0x00003000 {
+                            return Opcode::Add8;
                         }
                     } else if (code & 0x00008000) == 0x00000000 {
-                        if (code & 0x0000f800) == 0x00007800 {
-                            return Opcode::LdrbI;
+                        if (code & 0x0000f800) == 0x00003800 {
+                            return Opcode::Sub8;
                         }
-                    } else if (code & 0x0000f800) == 0x0000f800 {
-                        return Opcode::Bl;
+                    } else if (code & 0x0000ff00) == 0x0000be00 {
+                        return Opcode::Bkpt;
                     }
                 } else if (code & 0x00000400) == 0x00000000 {
-                    if (code & 0x00008000) == 0x00008000 {
-                        if (code & 0x0000f000) == 0x0000d000 {
-                            return Opcode::B;
+                    if (code & 0x00008000) == 0x00000000 {
+                        if (code & 0x00000800) == 0x00000000 {
+                            if (code & 0x0000f800) == 0x00003000 {
+                                return Opcode::Add8;
+                            }
+                        } else if (code & 0x0000f800) == 0x00003800 {
+                            return Opcode::Sub8;
                         }
-                    } else if (code & 0x00000800) == 0x00000000 {
-                        if (code & 0x0000fe00) == 0x00005000 {
-                            return Opcode::StrR;
+                    } else if (code & 0x00000080) == 0x00000000 {
+                        if (code & 0x0000ff80) == 0x0000b000 {
+                            return Opcode::AddSp7;
                         }
-                    } else if (code & 0x0000fe00) == 0x00005800 {
-                        return Opcode::LdrR;
-                    }
-                } else if (code & 0x00008000) == 0x00008000 {
-                    if (code & 0x0000f000) == 0x0000d000 {
-                        return Opcode::B;
+                    } else if (code & 0x0000ff80) == 0x0000b080 {
+                        return Opcode::SubSp7;
                     }
                 } else if (code & 0x00000800) == 0x00000000 {
-                    if (code & 0x0000fe00) == 0x00005400 {
-                        return Opcode::StrbR;
+                    if (code & 0x00008000) == 0x00000000 {
+                        if (code & 0x0000f800) == 0x00003000 {
+                            return Opcode::Add8;
+                        }
+                    } else if (code & 0x0000fe00) == 0x0000b400 {
+                        return Opcode::Push;
+                    }
+                } else if (code & 0x00008000) == 0x00000000 {
+                    if (code & 0x0000f800) == 0x00003800 {
+                        return Opcode::Sub8;
                     }
-                } else if (code & 0x0000fe00) == 0x00005c00 {
-                    return Opcode::LdrbR;
+                } else if (code & 0x0000fe00) == 0x0000bc00 {
+                    return Opcode::Pop;
                 }
-            } else if (code & 0x00008000) == 0x00000000 {
+            } else if (code & 0x00000200) == 0x00000200 {
                 if (code & 0x00000800) == 0x00000000 {
-                    if (code & 0x00002000) == 0x00002000 {
-                        if (code & 0x0000f800) == 0x00007000 {
-                            return Opcode::StrbI;
-                        }
-                    } else if (code & 0x00000400) == 0x00000000 {
-                        if (code & 0x0000fe00) == 0x00005200 {
-                            return Opcode::StrhR;
+                    if (code & 0x00008000) == 0x00000000 {
+                        if (code & 0x0000f800) == 0x00001000 {
+                            return Opcode::AsrI;
                         }
-                    } else if (code & 0x0000fe00) == 0x00005600 {
-                        return Opcode::Ldrsb;
+                    } else if (code & 0x0000f800) == 0x00009000 {
+                        return Opcode::StrSp;
                     }
-                } else if (code & 0x00002000) == 0x00002000 {
-                    if (code & 0x0000f800) == 0x00007800 {
-                        return Opcode::LdrbI;
+                } else if (code & 0x00008000) == 0x00008000 {
+                    if (code & 0x0000f800) == 0x00009800 {
+                        return Opcode::LdrSp;
                     }
                 } else if (code & 0x00000400) == 0x00000000 {
-                    if (code & 0x0000fe00) == 0x00005a00 {
-                        return Opcode::LdrhR;
+                    if (code & 0x0000fe00) == 0x00001a00 {
+                        return Opcode::SubR;
                     }
-                } else if (code & 0x0000fe00) == 0x00005e00 {
-                    return Opcode::Ldrsh;
+                } else if (code & 0x0000fe00) == 0x00001e00 {
+                    return Opcode::Subs3;
                 }
-            } else if (code & 0x00000100) == 0x00000000 {
+            } else if (code & 0x00000400) == 0x00000000 {
                 if (code & 0x00000800) == 0x00000000 {
-                    if (code & 0x00002000) == 0x00000000 {
-                        if (code & 0x0000f000) == 0x0000d000 {
-                            return Opcode::B;
+                    if (code & 0x00008000) == 0x00000000 {
+                        if (code & 0x0000f800) == 0x00001000 {
+                            return Opcode::AsrI;
                         }
-                    } else if (code & 0x0000f800) == 0x0000f000 {
-                        return Opcode::BlH;
+                    } else if (code & 0x0000f800) == 0x00009000 {
+                        return Opcode::StrSp;
                     }
-                } else if (code & 0x00002000) == 0x00002000 {
-                    if (code & 0x0000f800) == 0x0000f800 {
-                        return Opcode::Bl;
+                } else if (code & 0x00008000) == 0x00000000 {
+                    if (code & 0x0000fe00) == 0x00001800 {
+                        return Opcode::AddR;
                     }
-                } else if (code & 0x0000ff00) == 0x0000de00 {
-                    return Opcode::Bkpt;
-                } else if (code & 0x0000f000) == 0x0000d000 {
-                    return Opcode::B;
+                } else if (code & 0x0000f800) == 0x00009800 {
+                    return Opcode::LdrSp;
                 }
             } else if (code & 0x00000800) == 0x00000000 {
-                if (code & 0x00002000) == 0x00000000 {
-                    if (code & 0x0000f000) == 0x0000d000 {
-                        return Opcode::B;
+                if (code & 0x00008000) == 0x00000000 {
+                    if (code & 0x0000f800) == 0x00001000 {
+                        return Opcode::AsrI;
                     }
-                } else if (code & 0x0000f800) == 0x0000f000 {
-                    return Opcode::BlH;
+                } else if (code & 0x0000f800) == 0x00009000 {
+                    return Opcode::StrSp;
                 }
-            } else if (code & 0x00002000) == 0x00000000 {
-                if flags.ual && (code & 0x0000ff00) == 0x0000df00 {
-                    return Opcode::Svc;
-                } else if !flags.ual && (code & 0x0000ff00) == 0x0000df00 {
-                    return Opcode::Swi;
-                } else if (code & 0x0000f000) == 0x0000d000 {
-                    return Opcode::B;
+            } else if (code & 0x00008000) == 0x00008000 {
+                if (code & 0x0000f800) == 0x00009800 {
+                    return Opcode::LdrSp;
                 }
-            } else if (code & 0x0000f800) == 0x0000f800 {
-                return Opcode::Bl;
+            } else if !flags.ual && (code & 0x0000ffc0) == 0x00001c00 {
+                return Opcode::MovR;
+            } else if (code & 0x0000fe00) == 0x00001c00 {
+                return Opcode::Add3;
             }
-        } else if (code & 0x00000200) == 0x00000200 {
-            if (code & 0x00000800) == 0x00000000 {
-                if (code & 0x00002000) == 0x00002000 {
-                    if (code & 0x0000f800) == 0x00003000 {
-                        return Opcode::Add8;
+        } else if (code & 0x00000200) == 0x00000000 {
+            if (code & 0x00002000) == 0x00002000 {
+                if (code & 0x00000800) == 0x00000000 {
+                    if (code & 0x00008000) == 0x00000000 {
+                        if (code & 0x0000f800) == 0x00007000 {
+                            return Opcode::StrbI;
+                        }
+                    } else if (code & 0x0000f800) == 0x0000f000 {
+                        return Opcode::BlH;
                     }
                 } else if (code & 0x00008000) == 0x00000000 {
-                    if (code & 0x0000f800) == 0x00001000 {
-                        return Opcode::AsrI;
+                    if (code & 0x0000f800) == 0x00007800 {
+                        return Opcode::LdrbI;
                     }
-                } else if (code & 0x0000f800) == 0x00009000 {
-                    return Opcode::StrSp;
+                } else if (code & 0x0000f800) == 0x0000f800 {
+                    return Opcode::Bl;
                 }
             } else if (code & 0x00000400) == 0x00000000 {
+                if (code & 0x00008000) == 0x00008000 {
+                    if (code & 0x0000f000) == 0x0000d000 {
+                        return Opcode::B;
+                    }
+                } else if (code & 0x00000800) == 0x00000000 {
+                    if (code & 0x0000fe00) == 0x00005000 {
+                        return Opcode::StrR;
+                    }
+                } else if (code & 0x0000fe00) == 0x00005800 {
+                    return Opcode::LdrR;
+                }
+            } else if (code & 0x00008000) == 0x00008000 {
+                if (code & 0x0000f000) == 0x0000d000 {
+                    return Opcode::B;
+                }
+            } else if (code & 0x00000800) == 0x00000000 {
+                if (code & 0x0000fe00) == 0x00005400 {
+                    return Opcode::StrbR;
+                }
+            } else if (code & 0x0000fe00) == 0x00005c00 {
+                return Opcode::LdrbR;
+            }
+        } else if (code & 0x00008000) == 0x00000000 {
+            if (code & 0x00000800) == 0x00000000 {
                 if (code & 0x00002000) == 0x00002000 {
-                    if (code & 0x0000f800) == 0x00003800 {
-                        return Opcode::Sub8;
+                    if (code & 0x0000f800) == 0x00007000 {
+                        return Opcode::StrbI;
                     }
-                } else if (code & 0x00008000) == 0x00000000 {
-                    if (code & 0x0000fe00) == 0x00001a00 {
-                        return Opcode::SubR;
+                } else if (code & 0x00000400) == 0x00000000 {
+                    if (code & 0x0000fe00) == 0x00005200 {
+                        return Opcode::StrhR;
                     }
-                } else if (code & 0x0000f800) == 0x00009800 {
-                    return Opcode::LdrSp;
+                } else if (code & 0x0000fe00) == 0x00005600 {
+                    return Opcode::Ldrsb;
                 }
             } else if (code & 0x00002000) == 0x00002000 {
-                if (code & 0x0000f800) == 0x00003800 {
-                    return Opcode::Sub8;
+                if (code & 0x0000f800) == 0x00007800 {
+                    return Opcode::LdrbI;
                 }
-            } else if (code & 0x00008000) == 0x00000000 {
-                if (code & 0x0000fe00) == 0x00001e00 {
-                    return Opcode::Subs3;
+            } else if (code & 0x00000400) == 0x00000000 {
+                if (code & 0x0000fe00) == 0x00005a00 {
+                    return Opcode::LdrhR;
                 }
-            } else if (code & 0x0000f800) == 0x00009800 {
-                return Opcode::LdrSp;
+            } else if (code & 0x0000fe00) == 0x00005e00 {
+                return Opcode::Ldrsh;
             }
-        } else if (code & 0x00000800) == 0x00000000 {
-            if (code & 0x00000400) == 0x00000400 {
+        } else if (code & 0x00000100) == 0x00000000 {
+            if (code & 0x00000800) == 0x00000000 {
                 if (code & 0x00002000) == 0x00000000 {
-                    if (code & 0x00008000) == 0x00000000 {
-                        if (code & 0x0000f800) == 0x00001000 {
-                            return Opcode::AsrI;
-                        }
-                    } else if (code & 0x0000f800) == 0x00009000 {
-                        return Opcode::StrSp;
-                    }
-                } else if (code & 0x00008000) == 0x00000000 {
-                    if (code & 0x0000f800) == 0x00003000 {
-                        return Opcode::Add8;
+                    if (code & 0x0000f000) == 0x0000d000 {
+                        return Opcode::B;
                     }
-                } else if (code & 0x0000fe00) == 0x0000b400 {
-                    return Opcode::Push;
+                } else if (code & 0x0000f800) == 0x0000f000 {
+                    return Opcode::BlH;
                 }
-            } else if (code & 0x00002000) == 0x00000000 {
-                if (code & 0x00008000) == 0x00000000 {
-                    if (code & 0x0000f800) == 0x00001000 {
-                        return Opcode::AsrI;
-                    }
-                } else if (code & 0x0000f800) == 0x00009000 {
-                    return Opcode::StrSp;
+            } else if (code & 0x00002000) == 0x00002000 {
+                if (code & 0x0000f800) == 0x0000f800 {
+                    return Opcode::Bl;
                 }
-            } else if (code & 0x00008000) == 0x00000000 {
-                if (code & 0x0000f800) == 0x00003000 {
-                    return Opcode::Add8;
+            } else if (code & 0x0000ff00) == 0x0000de00 {
+                return Opcode::Udf;
+            } else if (code & 0x0000f000) == 0x0000d000 {
+                return Opcode::B;
+            }
+        } else if (code & 0x00000800) == 0x00000000 {
+            if (code & 0x00002000) == 0x00000000 {
+                if (code & 0x0000f000) == 0x0000d000 {
+                    return Opcode::B;
+                }
+            } else if (code & 0x0000f800) == 0x0000f000 {
+                return Opcode::BlH;
+            }
+        } else if (code & 0x00002000) == 0x00000000 {
+            if flags.ual && (code & 0x0000ff00) == 0x0000df00 {
+                return Opcode::Svc;
+            } else if !flags.ual && (code & 0x0000ff00) == 0x0000df00 {
+                return Opcode::Swi;
+            } else if (code & 0x0000f000) == 0x0000d000 {
+                return Opcode::B;
+            }
+        } else if (code & 0x0000f800) == 0x0000f800 {
+            return Opcode::Bl;
+        }
+        Opcode::Illegal
+    }
+    /// Implementation of [`Self::find`] as a lookup table. Public to test that it's equivalent to
+    /// [`Self::find_tree`], whichever one the profile uses.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn find_table(code: u32, flags: &ParseFlags) -> Self {
+        let key = ((code >> 4) & 0x00000fff);
+        let (opcode, group) = FIND_TABLE[key as usize];
+        match group {
+            1 => {
+                if flags.ual {
+                    return Opcode::MovsR;
                 }
-            } else if (code & 0x00000080) == 0x00000000 {
-                if (code & 0x0000ff80) == 0x0000b000 {
-                    return Opcode::AddSp7;
+            }
+            2 => {
+                if !flags.ual {
+                    return Opcode::MovR;
                 }
-            } else if (code & 0x0000ff80) == 0x0000b080 {
-                return Opcode::SubSp7;
             }
-        } else if (code & 0x00000400) == 0x00000000 {
-            if (code & 0x00002000) == 0x00002000 {
-                if (code & 0x0000f800) == 0x00003800 {
-                    return Opcode::Sub8;
+            3 => {
+                if !flags.ual {
+                    return Opcode::Neg;
                 }
-            } else if (code & 0x00008000) == 0x00000000 {
-                if (code & 0x0000fe00) == 0x00001800 {
-                    return Opcode::AddR;
+                if flags.ual {
+                    return Opcode::Rsbs;
+                }
+            }
+            4 => {
+                if (code & 0x00000008) == 0x00000008 {
+                    return Opcode::AddRegSp;
+                }
+            }
+            5 => {
+                if (code & 0x00000007) == 0x00000005 {
+                    return Opcode::AddSpReg;
+                }
+            }
+            6 => {
+                if (code & 0x00000008) == 0x00000008 {
+                    return Opcode::AddRegSp;
+                }
+                if (code & 0x00000007) == 0x00000005 {
+                    return Opcode::AddSpReg;
+                }
+            }
+            7 => {
+                if (code & 0x00000007) == 0x00000000 {
+                    return Opcode::BxR;
+                }
+            }
+            8 => {
+                if (code & 0x00000007) == 0x00000000 {
+                    return Opcode::BlxR;
                 }
-            } else if (code & 0x0000f800) == 0x00009800 {
-                return Opcode::LdrSp;
             }
-        } else if (code & 0x00002000) == 0x00002000 {
-            if (code & 0x00008000) == 0x00000000 {
-                if (code & 0x0000f800) == 0x00003800 {
-                    return Opcode::Sub8;
+            9 => {
+                if !flags.ual {
+                    return Opcode::AddPc;
+                }
+                if flags.ual {
+                    return Opcode::Adr;
+                }
+            }
+            10 => {
+                if flags.ual {
+                    return Opcode::Ldm;
+                }
+                if !flags.ual {
+                    return Opcode::Ldmia;
                 }
-            } else if (code & 0x0000fe00) == 0x0000bc00 {
-                return Opcode::Pop;
             }
-        } else if (code & 0x00008000) == 0x00008000 {
-            if (code & 0x0000f800) == 0x00009800 {
-                return Opcode::LdrSp;
+            11 => {
+                if flags.ual {
+                    return Opcode::Svc;
+                }
+                if !flags.ual {
+                    return Opcode::Swi;
+                }
             }
-        } else if !flags.ual && (code & 0x0000ffc0) == 0x00001c00 {
-            return Opcode::MovR;
-        } else if (code & 0x0000fe00) == 0x00001c00 {
-            return Opcode::Add3;
+            _ => {}
         }
-        Opcode::Illegal
+        opcode
+    }
+    /// Returns every opcode whose bitmask and pattern match the code, starting with the result of [`Self::find`].
+    /// The rest are ordered from most to least specific bitmask. This is meant for debugging the ISA
+    /// definitions, as it checks every opcode one by one.
+    pub fn find_all(code: u32, flags: &ParseFlags) -> impl Iterator<Item = Self> {
+        let found = Self::find(code, flags);
+        let mut all = vec![];
+        if found != Opcode::Illegal {
+            all.push(found);
+        }
+        if (code & 0x0000ff78) == 0x00004468 && found != Opcode::AddRegSp {
+            all.push(Opcode::AddRegSp);
+        }
+        if (code & 0x0000ff87) == 0x00004485 && found != Opcode::AddSpReg {
+            all.push(Opcode::AddSpReg);
+        }
+        if (code & 0x0000ff87) == 0x00004780 && found != Opcode::BlxR {
+            all.push(Opcode::BlxR);
+        }
+        if (code & 0x0000ff87) == 0x00004700 && found != Opcode::BxR {
+            all.push(Opcode::BxR);
+        }
+        if (code & 0x0000ffc0) == 0x00004140 && found != Opcode::Adc {
+            all.push(Opcode::Adc);
+        }
+        if (code & 0x0000ffc0) == 0x00004000 && found != Opcode::And {
+            all.push(Opcode::And);
+        }
+        if (code & 0x0000ffc0) == 0x00004100 && found != Opcode::AsrR {
+            all.push(Opcode::AsrR);
+        }
+        if (code & 0x0000ffc0) == 0x00004380 && found != Opcode::Bic {
+            all.push(Opcode::Bic);
+        }
+        if (code & 0x0000ffc0) == 0x000042c0 && found != Opcode::Cmn {
+            all.push(Opcode::Cmn);
+        }
+        if (code & 0x0000ffc0) == 0x00004280 && found != Opcode::CmpR {
+            all.push(Opcode::CmpR);
+        }
+        if (code & 0x0000ffc0) == 0x00004040 && found != Opcode::Eor {
+            all.push(Opcode::Eor);
+        }
+        if (code & 0x0000ffc0) == 0x00004080 && found != Opcode::LslR {
+            all.push(Opcode::LslR);
+        }
+        if (code & 0x0000ffc0) == 0x000040c0 && found != Opcode::LsrR {
+            all.push(Opcode::LsrR);
+        }
+        if !flags.ual && (code & 0x0000ffc0) == 0x00001c00 && found != Opcode::MovR {
+            all.push(Opcode::MovR);
+        }
+        if flags.ual && (code & 0x0000ffc0) == 0x00000000 && found != Opcode::MovsR {
+            all.push(Opcode::MovsR);
+        }
+        if (code & 0x0000ffc0) == 0x00004340 && found != Opcode::Mul {
+            all.push(Opcode::Mul);
+        }
+        if (code & 0x0000ffc0) == 0x000043c0 && found != Opcode::Mvn {
+            all.push(Opcode::Mvn);
+        }
+        if !flags.ual && (code & 0x0000ffc0) == 0x00004240 && found != Opcode::Neg {
+            all.push(Opcode::Neg);
+        }
+        if flags.ual && (code & 0x0000ffc0) == 0x00004240 && found != Opcode::Rsbs {
+            all.push(Opcode::Rsbs);
+        }
+        if (code & 0x0000ffc0) == 0x00004300 && found != Opcode::Orr {
+            all.push(Opcode::Orr);
+        }
+        if (code & 0x0000ffc0) == 0x000041c0 && found != Opcode::Ror {
+            all.push(Opcode::Ror);
+        }
+        if (code & 0x0000ffc0) == 0x00004180 && found != Opcode::Sbc {
+            all.push(Opcode::Sbc);
+        }
+        if (code & 0x0000ffc0) == 0x00004200 && found != Opcode::Tst {
+            all.push(Opcode::Tst);
+        }
+        if (code & 0x0000ff80) == 0x0000b000 && found != Opcode::AddSp7 {
+            all.push(Opcode::AddSp7);
+        }
+        if (code & 0x0000ff80) == 0x0000b080 && found != Opcode::SubSp7 {
+            all.push(Opcode::SubSp7);
+        }
+        if (code & 0x0000ff00) == 0x00004400 && found != Opcode::AddHr {
+            all.push(Opcode::AddHr);
+        }
+        if (code & 0x0000ff00) == 0x0000be00 && found != Opcode::Bkpt {
+            all.push(Opcode::Bkpt);
+        }
+        if (code & 0x0000ff00) == 0x00004500 && found != Opcode::CmpHr {
+            all.push(Opcode::CmpHr);
+        }
+        if (code & 0x0000ff00) == 0x00004600 && found != Opcode::MovHr {
+            all.push(Opcode::MovHr);
+        }
+        if flags.ual && (code & 0x0000ff00) == 0x0000df00 && found != Opcode::Svc {
+            all.push(Opcode::Svc);
+        }
+        if !flags.ual && (code & 0x0000ff00) == 0x0000df00 && found != Opcode::Swi {
+            all.push(Opcode::Swi);
+        }
+        if (code & 0x0000ff00) == 0x0000de00 && found != Opcode::Udf {
+            all.push(Opcode::Udf);
+        }
+        if (code & 0x0000fe00) == 0x00001c00 && found != Opcode::Add3 {
+            all.push(Opcode::Add3);
+        }
+        if (code & 0x0000fe00) == 0x00001800 && found != Opcode::AddR {
+            all.push(Opcode::AddR);
+        }
+        if (code & 0x0000fe00) == 0x00005800 && found != Opcode::LdrR {
+            all.push(Opcode::LdrR);
+        }
+        if (code & 0x0000fe00) == 0x00005c00 && found != Opcode::LdrbR {
+            all.push(Opcode::LdrbR);
+        }
+        if (code & 0x0000fe00) == 0x00005a00 && found != Opcode::LdrhR {
+            all.push(Opcode::LdrhR);
+        }
+        if (code & 0x0000fe00) == 0x00005600 && found != Opcode::Ldrsb {
+            all.push(Opcode::Ldrsb);
+        }
+        if (code & 0x0000fe00) == 0x00005e00 && found != Opcode::Ldrsh {
+            all.push(Opcode::Ldrsh);
+        }
+        if (code & 0x0000fe00) == 0x0000bc00 && found != Opcode::Pop {
+            all.push(Opcode::Pop);
+        }
+        if (code & 0x0000fe00) == 0x0000b400 && found != Opcode::Push {
+            all.push(Opcode::Push);
+        }
+        if (code & 0x0000fe00) == 0x00005000 && found != Opcode::StrR {
+            all.push(Opcode::StrR);
+        }
+        if (code & 0x0000fe00) == 0x00005400 && found != Opcode::StrbR {
+            all.push(Opcode::StrbR);
+        }
+        if (code & 0x0000fe00) == 0x00005200 && found != Opcode::StrhR {
+            all.push(Opcode::StrhR);
+        }
+        if (code & 0x0000fe00) == 0x00001e00 && found != Opcode::Subs3 {
+            all.push(Opcode::Subs3);
+        }
+        if (code & 0x0000fe00) == 0x00001a00 && found != Opcode::SubR {
+            all.push(Opcode::SubR);
+        }
+        if (code & 0x0000f800) == 0x00003000 && found != Opcode::Add8 {
+            all.push(Opcode::Add8);
+        }
+        if (code & 0x0000f800) == 0x0000a800 && found != Opcode::AddSp {
+            all.push(Opcode::AddSp);
+        }
+        if !flags.ual && (code & 0x0000f800) == 0x0000a000 && found != Opcode::AddPc {
+            all.push(Opcode::AddPc);
+        }
+        if flags.ual && (code & 0x0000f800) == 0x0000a000 && found != Opcode::Adr {
+            all.push(Opcode::Adr);
+        }
+        if (code & 0x0000f800) == 0x00001000 && found != Opcode::AsrI {
+            all.push(Opcode::AsrI);
+        }
+        if (code & 0x0000f800) == 0x0000e000 && found != Opcode::BLong {
+            all.push(Opcode::BLong);
+        }
+        if (code & 0x0000f800) == 0x0000f000 && found != Opcode::BlH {
+            all.push(Opcode::BlH);
+        }
+        if (code & 0x0000f800) == 0x0000f800 && found != Opcode::Bl {
+            all.push(Opcode::Bl);
+        }
+        if (code & 0x0000f800) == 0x0000e800 && found != Opcode::BlxI {
+            all.push(Opcode::BlxI);
+        }
+        if (code & 0x0000f800) == 0x00002800 && found != Opcode::CmpI {
+            all.push(Opcode::CmpI);
+        }
+        if flags.ual && (code & 0x0000f800) == 0x0000c800 && found != Opcode::Ldm {
+            all.push(Opcode::Ldm);
+        }
+        if !flags.ual && (code & 0x0000f800) == 0x0000c800 && found != Opcode::Ldmia {
+            all.push(Opcode::Ldmia);
+        }
+        if (code & 0x0000f800) == 0x00006800 && found != Opcode::LdrI {
+            all.push(Opcode::LdrI);
+        }
+        if (code & 0x0000f800) == 0x00004800 && found != Opcode::LdrPc {
+            all.push(Opcode::LdrPc);
+        }
+        if (code & 0x0000f800) == 0x00009800 && found != Opcode::LdrSp {
+            all.push(Opcode::LdrSp);
+        }
+        if (code & 0x0000f800) == 0x00007800 && found != Opcode::LdrbI {
+            all.push(Opcode::LdrbI);
+        }
+        if (code & 0x0000f800) == 0x00008800 && found != Opcode::LdrhI {
+            all.push(Opcode::LdrhI);
+        }
+        if (code & 0x0000f800) == 0x00000000 && found != Opcode::LslI {
+            all.push(Opcode::LslI);
+        }
+        if (code & 0x0000f800) == 0x00000800 && found != Opcode::LsrI {
+            all.push(Opcode::LsrI);
+        }
+        if (code & 0x0000f800) == 0x00002000 && found != Opcode::MovI {
+            all.push(Opcode::MovI);
+        }
+        if (code & 0x0000f800) == 0x0000c000 && found != Opcode::Stm {
+            all.push(Opcode::Stm);
+        }
+        if (code & 0x0000f800) == 0x00006000 && found != Opcode::StrI {
+            all.push(Opcode::StrI);
+        }
+        if (code & 0x0000f800) == 0x00009000 && found != Opcode::StrSp {
+            all.push(Opcode::StrSp);
+        }
+        if (code & 0x0000f800) == 0x00007000 && found != Opcode::StrbI {
+            all.push(Opcode::StrbI);
+        }
+        if (code & 0x0000f800) == 0x00008000 && found != Opcode::StrhI {
+            all.push(Opcode::StrhI);
+        }
+        if (code & 0x0000f800) == 0x00003800 && found != Opcode::Sub8 {
+            all.push(Opcode::Sub8);
+        }
+        if (code & 0x0000f000) == 0x0000d000 && found != Opcode::B {
+            all.push(Opcode::B);
+        }
+        all.into_iter()
     }
     pub fn mnemonic(self) -> &'static str {
         OPCODE_MNEMONICS[self as usize]
     }
     pub fn count() -> usize {
-        72
+        73
+    }
+    /// Returns whether this opcode is a unified syntax (UAL) alias of another opcode, such as PUSH for STMDB.
+    /// See `Ins::aliased_from` to find the underlying opcode.
+    pub const fn is_alias(self) -> bool {
+        match self {
+            Opcode::Adr | Opcode::Ldm | Opcode::MovsR | Opcode::Rsbs | Opcode::Svc => {
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Returns the name of this variant, e.g. `LdrB`. Unlike the mnemonic, it tells apart opcodes with the same
+    /// mnemonic. Variant names are kept stable, see `renames` in the ISA files.
+    pub fn variant_name(self) -> &'static str {
+        if self == Opcode::Illegal {
+            "Illegal"
+        } else {
+            OPCODE_VARIANT_NAMES[self as usize]
+        }
+    }
+    /// Returns the opcode with the given variant name. Old names of renamed variants are also accepted, so that
+    /// names logged by earlier versions can be read back.
+    pub fn from_variant_name(name: &str) -> Option<Self> {
+        match name {
+            "Illegal" => Some(Opcode::Illegal),
+            "Adc" => Some(Opcode::Adc),
+            "Add3" => Some(Opcode::Add3),
+            "Add8" => Some(Opcode::Add8),
+            "AddR" => Some(Opcode::AddR),
+            "AddHr" => Some(Opcode::AddHr),
+            "AddSp" => Some(Opcode::AddSp),
+            "AddSp7" => Some(Opcode::AddSp7),
+            "AddRegSp" => Some(Opcode::AddRegSp),
+            "AddSpReg" => Some(Opcode::AddSpReg),
+            "AddPc" => Some(Opcode::AddPc),
+            "Adr" => Some(Opcode::Adr),
+            "And" => Some(Opcode::And),
+            "AsrI" => Some(Opcode::AsrI),
+            "AsrR" => Some(Opcode::AsrR),
+            "B" => Some(Opcode::B),
+            "BLong" => Some(Opcode::BLong),
+            "Bic" => Some(Opcode::Bic),
+            "Bkpt" => Some(Opcode::Bkpt),
+            "BlH" => Some(Opcode::BlH),
+            "Bl" => Some(Opcode::Bl),
+            "BlxI" => Some(Opcode::BlxI),
+            "BlxR" => Some(Opcode::BlxR),
+            "BxR" => Some(Opcode::BxR),
+            "Cmn" => Some(Opcode::Cmn),
+            "CmpI" => Some(Opcode::CmpI),
+            "CmpR" => Some(Opcode::CmpR),
+            "CmpHr" => Some(Opcode::CmpHr),
+            "Eor" => Some(Opcode::Eor),
+            "Ldm" => Some(Opcode::Ldm),
+            "Ldmia" => Some(Opcode::Ldmia),
+            "LdrI" => Some(Opcode::LdrI),
+            "LdrR" => Some(Opcode::LdrR),
+            "LdrPc" => Some(Opcode::LdrPc),
+            "LdrSp" => Some(Opcode::LdrSp),
+            "LdrbI" => Some(Opcode::LdrbI),
+            "LdrbR" => Some(Opcode::LdrbR),
+            "LdrhI" => Some(Opcode::LdrhI),
+            "LdrhR" => Some(Opcode::LdrhR),
+            "Ldrsb" => Some(Opcode::Ldrsb),
+            "Ldrsh" => Some(Opcode::Ldrsh),
+            "LslI" => Some(Opcode::LslI),
+            "LslR" => Some(Opcode::LslR),
+            "LsrI" => Some(Opcode::LsrI),
+            "LsrR" => Some(Opcode::LsrR),
+            "MovI" => Some(Opcode::MovI),
+            "MovR" => Some(Opcode::MovR),
+            "MovsR" => Some(Opcode::MovsR),
+            "MovHr" => Some(Opcode::MovHr),
+            "Mul" => Some(Opcode::Mul),
+            "Mvn" => Some(Opcode::Mvn),
+            "Neg" => Some(Opcode::Neg),
+            "Rsbs" => Some(Opcode::Rsbs),
+            "Orr" => Some(Opcode::Orr),
+            "Pop" => Some(Opcode::Pop),
+            "Push" => Some(Opcode::Push),
+            "Ror" => Some(Opcode::Ror),
+            "Sbc" => Some(Opcode::Sbc),
+            "Stm" => Some(Opcode::Stm),
+            "StrI" => Some(Opcode::StrI),
+            "StrR" => Some(Opcode::StrR),
+            "StrSp" => Some(Opcode::StrSp),
+            "StrbI" => Some(Opcode::StrbI),
+            "StrbR" => Some(Opcode::StrbR),
+            "StrhI" => Some(Opcode::StrhI),
+            "StrhR" => Some(Opcode::StrhR),
+            "Subs3" => Some(Opcode::Subs3),
+            "Sub8" => Some(Opcode::Sub8),
+            "SubR" => Some(Opcode::SubR),
+            "SubSp7" => Some(Opcode::SubSp7),
+            "Svc" => Some(Opcode::Svc),
+            "Swi" => Some(Opcode::Swi),
+            "Tst" => Some(Opcode::Tst),
+            "Udf" => Some(Opcode::Udf),
+            _ => None,
+        }
+    }
+    /// Returns the stable id of this opcode, e.g. `ldr_b`, which is the name of its entry in the ISA file. Unlike
+    /// the discriminant, which changes when opcodes are added or reordered, ids are kept stable like variant
+    /// names, so they can key on-disk caches.
+    pub fn id(self) -> &'static str {
+        if self == Opcode::Illegal { "illegal" } else { OPCODE_IDS[self as usize] }
+    }
+    /// Returns the opcode with the given [`id`](Self::id). Ids of renamed variants are also accepted.
+    pub fn from_id(id: &str) -> Option<Self> {
+        OPCODES_BY_ID
+            .binary_search_by_key(&id, |&(id, _)| id)
+            .ok()
+            .map(|index| OPCODES_BY_ID[index].1)
+    }
+    /// Returns true for branch instructions. Other instructions which can write to PC, such as `pop {pc}`, are
+    /// not included.
+    pub const fn is_branch(self) -> bool {
+        match self {
+            Opcode::B
+            | Opcode::BLong
+            | Opcode::BlH
+            | Opcode::Bl
+            | Opcode::BlxI
+            | Opcode::BlxR
+            | Opcode::BxR => true,
+            _ => false,
+        }
+    }
+    /// Returns true for instructions which load from memory.
+    pub const fn is_load(self) -> bool {
+        match self {
+            Opcode::Ldm
+            | Opcode::Ldmia
+            | Opcode::LdrI
+            | Opcode::LdrR
+            | Opcode::LdrPc
+            | Opcode::LdrSp
+            | Opcode::LdrbI
+            | Opcode::LdrbR
+            | Opcode::LdrhI
+            | Opcode::LdrhR
+            | Opcode::Ldrsb
+            | Opcode::Ldrsh
+            | Opcode::Pop => true,
+            _ => false,
+        }
+    }
+    /// Returns true for instructions which store to memory.
+    pub const fn is_store(self) -> bool {
+        match self {
+            Opcode::Push
+            | Opcode::Stm
+            | Opcode::StrI
+            | Opcode::StrR
+            | Opcode::StrSp
+            | Opcode::StrbI
+            | Opcode::StrbR
+            | Opcode::StrhI
+            | Opcode::StrhR => true,
+            _ => false,
+        }
+    }
+    /// Returns true for coprocessor instructions.
+    pub const fn is_coprocessor(self) -> bool {
+        false
+    }
+    /// Returns true for arithmetic, logical, move and compare instructions, including shifts. Multiplies and
+    /// media instructions are not included.
+    pub const fn is_data_processing(self) -> bool {
+        match self {
+            Opcode::Adc
+            | Opcode::Add3
+            | Opcode::Add8
+            | Opcode::AddR
+            | Opcode::AddHr
+            | Opcode::AddSp
+            | Opcode::AddSp7
+            | Opcode::AddRegSp
+            | Opcode::AddSpReg
+            | Opcode::AddPc
+            | Opcode::Adr
+            | Opcode::And
+            | Opcode::AsrI
+            | Opcode::AsrR
+            | Opcode::Bic
+            | Opcode::Cmn
+            | Opcode::CmpI
+            | Opcode::CmpR
+            | Opcode::CmpHr
+            | Opcode::Eor
+            | Opcode::LslI
+            | Opcode::LslR
+            | Opcode::LsrI
+            | Opcode::LsrR
+            | Opcode::MovI
+            | Opcode::MovR
+            | Opcode::MovsR
+            | Opcode::MovHr
+            | Opcode::Mvn
+            | Opcode::Neg
+            | Opcode::Rsbs
+            | Opcode::Orr
+            | Opcode::Ror
+            | Opcode::Sbc
+            | Opcode::Subs3
+            | Opcode::Sub8
+            | Opcode::SubR
+            | Opcode::SubSp7
+            | Opcode::Tst => true,
+            _ => false,
+        }
+    }
+    /// Returns true for hint instructions such as `nop` and `wfi`, which execute as NOP if not implemented.
+    pub const fn is_hint(self) -> bool {
+        false
+    }
+    /// Returns true for branch instructions which have a condition code.
+    pub const fn is_conditional_branch_capable(self) -> bool {
+        match self {
+            Opcode::B => true,
+            _ => false,
+        }
+    }
+    /// Returns the architecture extension of the opcode, e.g. `dsp`, or `None` if it's in the base instruction set.
+    /// Each extension has an `ext-<name>` cargo feature.
+    pub const fn extension(self) -> Option<&'static str> {
+        None
+    }
+    /// Returns the minimum number of arguments this opcode produces when parsed.
+    pub fn min_args(self, flags: &ParseFlags) -> usize {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_ARGS[self as usize][flags.ual as usize].0 as usize
+    }
+    /// Returns the maximum number of arguments this opcode produces when parsed.
+    pub fn max_args(self, flags: &ParseFlags) -> usize {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_ARGS[self as usize][flags.ual as usize].1 as usize
+    }
+    /// Returns every argument this opcode can produce, in order. Optional arguments only appear for some
+    /// modifier cases, so the parsed arguments are always a subsequence of this slice. See [`ArgMeta`] for
+    /// the positional contract of each opcode family.
+    pub fn arg_meta(self, flags: &ParseFlags) -> &'static [ArgMeta] {
+        if self == Opcode::Illegal {
+            return &[];
+        }
+        OPCODE_ARGS[self as usize][flags.ual as usize].2
+    }
+    /// Returns every opcode enabled by the cargo features except [`Opcode::Illegal`], in order of discriminant.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        OPCODES.iter().copied()
+    }
+    /// Returns the bits which are fixed by this opcode. A code with these bits equal to [`Self::pattern`] may
+    /// decode to this opcode, unless a more specific opcode also matches it. Returns 0 for [`Opcode::Illegal`].
+    pub fn bitmask(self) -> u32 {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_FIELDS[self as usize].0
+    }
+    /// Returns the values of the bits in [`Self::bitmask`], which is also the lowest code of this opcode. Returns
+    /// 0 for [`Opcode::Illegal`].
+    pub fn pattern(self) -> u32 {
+        if self == Opcode::Illegal {
+            return 0;
+        }
+        OPCODE_FIELDS[self as usize].1
+    }
+    /// Returns the fields and modifiers of this opcode which are not fixed by its pattern, in both syntaxes.
+    pub fn field_descs(self) -> &'static [FieldDesc] {
+        if self == Opcode::Illegal {
+            return &[];
+        }
+        OPCODE_FIELDS[self as usize].2
+    }
+    /// Returns the encodings of this opcode selected by `filter`, in increasing order. Encodings which decode to
+    /// another opcode with the given flags, e.g. a more specific opcode with an overlapping pattern, are skipped.
+    pub fn enumerate(
+        self,
+        filter: &FieldFilter,
+        flags: &ParseFlags,
+    ) -> impl Iterator<Item = u32> {
+        let (bitmask, pattern, fields) = match self {
+            Opcode::Illegal => (0, 0, None),
+            _ => {
+                let (bitmask, pattern, fields) = OPCODE_FIELDS[self as usize];
+                (bitmask, pattern, Some(fields))
+            }
+        };
+        let flags = *flags;
+        enumerate::enumerate(
+            bitmask,
+            pattern,
+            fields,
+            filter,
+            move |code| Ins::new(code, &flags).op == self,
+        )
+    }
+    /// Returns a one-line description of this opcode, e.g. "Add with Carry".
+    pub fn describe(self) -> &'static str {
+        if self == Opcode::Illegal {
+            return "Illegal or unknown";
+        }
+        OPCODE_DESCRIPTIONS[self as usize]
+    }
+    /// Finds the opcodes whose mnemonics best match `query`, with the best match first. Each mnemonic is matched with
+    /// all of its suffixes except conditions, e.g. `ldmdb` and its stack alias `ldmea`. See [`crate::fuzzy_score`] for
+    /// how matches are scored.
+    pub fn fuzzy_search(query: &str, limit: usize) -> Vec<(Self, u32)> {
+        crate::search::fuzzy_search(query, limit, OPCODE_SPELLINGS.iter().copied())
+    }
+    /// Returns the opcodes which have `mnemonic` as one of their mnemonics without a condition suffix, in either
+    /// syntax.
+    pub fn from_mnemonic(mnemonic: &str) -> impl Iterator<Item = Self> + '_ {
+        OPCODE_SPELLINGS
+            .iter()
+            .filter(move |(_, spellings)| spellings.contains(&mnemonic))
+            .map(|(opcode, _)| *opcode)
+    }
+    /// Encodes a parsed instruction as this opcode. The result decodes to this opcode and the same [`ParsedIns`]
+    /// with the given flags.
+    pub fn encode(
+        self,
+        ins: &ParsedIns,
+        flags: &ParseFlags,
+    ) -> Result<u32, EncodeError> {
+        if self == Opcode::Illegal {
+            return Err(EncodeError::UnknownMnemonic);
+        }
+        encode::encode_forms(
+            ENCODE_FORMS[self as usize],
+            ins,
+            flags.ual,
+            |code| {
+                let decoded = Ins::new(code, flags);
+                decoded.op == self && decoded.parse(flags) == *ins
+            },
+        )
     }
 }
 impl Ins {
     /// Rd_0: Destination register
+    ///
+    /// Reads bits[2:0] of the instruction.
     #[inline(always)]
     pub fn field_rd_0(&self) -> Reg {
         Reg {
@@ -911,6 +8095,8 @@ impl Ins {
         }
     }
     /// Rd_0_ual: Destination register
+    ///
+    /// Reads bits[2:0] of the instruction.
     #[inline(always)]
     pub fn field_rd_0_ual(&self) -> Reg {
         Reg {
@@ -920,6 +8106,8 @@ impl Ins {
         }
     }
     /// Rd_8: Destination register
+    ///
+    /// Reads bits[10:8] of the instruction.
     #[inline(always)]
     pub fn field_rd_8(&self) -> Reg {
         Reg {
@@ -929,28 +8117,40 @@ impl Ins {
         }
     }
     /// Rd_H1: Destination register
+    ///
+    /// Reads bits[7], bits[2:0] of the instruction.
     #[inline(always)]
     pub fn field_rd_h1(&self) -> Reg {
         Reg {
             deref: false,
-            reg: Register::parse(
-                (self.code & 0x00000007) | ((self.code >> 7) & 0x00000001) << 3,
-            ),
+            reg: {
+                let value = (self.code & 0x00000007)
+                    | ((self.code >> 7) & 0x00000001) << 3;
+                debug_assert!(Register::try_parse(value).is_some());
+                Register::parse(value)
+            },
             writeback: false,
         }
     }
     /// Rd_H1_ual: Destination register
+    ///
+    /// Reads bits[7], bits[2:0] of the instruction.
     #[inline(always)]
     pub fn field_rd_h1_ual(&self) -> Reg {
         Reg {
             deref: false,
-            reg: Register::parse(
-                (self.code & 0x00000007) | ((self.code >> 7) & 0x00000001) << 3,
-            ),
+            reg: {
+                let value = (self.code & 0x00000007)
+                    | ((self.code >> 7) & 0x00000001) << 3;
+                debug_assert!(Register::try_parse(value).is_some());
+                Register::parse(value)
+            },
             writeback: false,
         }
     }
     /// Rn_0: First source operand register
+    ///
+    /// Reads bits[2:0] of the instruction.
     #[inline(always)]
     pub fn field_rn_0(&self) -> Reg {
         Reg {
@@ -960,6 +8160,8 @@ impl Ins {
         }
     }
     /// Rn_3: First source operand register
+    ///
+    /// Reads bits[5:3] of the instruction.
     #[inline(always)]
     pub fn field_rn_3(&self) -> Reg {
         Reg {
@@ -969,6 +8171,8 @@ impl Ins {
         }
     }
     /// Rn_3_deref: Base register
+    ///
+    /// Reads bits[5:3] of the instruction.
     #[inline(always)]
     pub fn field_rn_3_deref(&self) -> Reg {
         Reg {
@@ -978,6 +8182,8 @@ impl Ins {
         }
     }
     /// Rn_8: First source operand register
+    ///
+    /// Reads bits[10:8] of the instruction.
     #[inline(always)]
     pub fn field_rn_8(&self) -> Reg {
         Reg {
@@ -987,6 +8193,8 @@ impl Ins {
         }
     }
     /// Rn_8_wb: First source operand register
+    ///
+    /// Reads bits[10:8] of the instruction.
     #[inline(always)]
     pub fn field_rn_8_wb(&self) -> Reg {
         Reg {
@@ -996,6 +8204,8 @@ impl Ins {
         }
     }
     /// Rn_8_ldm: First source operand register
+    ///
+    /// Reads bits[10:0] of the instruction.
     #[inline(always)]
     pub fn field_rn_8_ldm(&self) -> Reg {
         Reg {
@@ -1006,17 +8216,24 @@ impl Ins {
         }
     }
     /// Rn_H1: First source operand register
+    ///
+    /// Reads bits[7], bits[2:0] of the instruction.
     #[inline(always)]
     pub fn field_rn_h1(&self) -> Reg {
         Reg {
             deref: false,
-            reg: Register::parse(
-                (self.code & 0x00000007) | ((self.code >> 7) & 0x00000001) << 3,
-            ),
+            reg: {
+                let value = (self.code & 0x00000007)
+                    | ((self.code >> 7) & 0x00000001) << 3;
+                debug_assert!(Register::try_parse(value).is_some());
+                Register::parse(value)
+            },
             writeback: false,
         }
     }
     /// Rm_3: Second source operand register
+    ///
+    /// Reads bits[5:3] of the instruction.
     #[inline(always)]
     pub fn field_rm_3(&self) -> Reg {
         Reg {
@@ -1026,6 +8243,8 @@ impl Ins {
         }
     }
     /// Rm_6: Second source operand register
+    ///
+    /// Reads bits[8:6] of the instruction.
     #[inline(always)]
     pub fn field_rm_6(&self) -> Reg {
         Reg {
@@ -1035,6 +8254,8 @@ impl Ins {
         }
     }
     /// Rm_6_offset: Offset register
+    ///
+    /// Reads bits[8:6] of the instruction.
     #[inline(always)]
     pub fn field_rm_6_offset(&self) -> OffsetReg {
         OffsetReg {
@@ -1044,6 +8265,8 @@ impl Ins {
         }
     }
     /// Rm_H2: Second source operand register
+    ///
+    /// Reads bits[6:3] of the instruction.
     #[inline(always)]
     pub fn field_rm_h2(&self) -> Reg {
         Reg {
@@ -1053,6 +8276,8 @@ impl Ins {
         }
     }
     /// Rs: Register containing shift offset
+    ///
+    /// Reads bits[5:3] of the instruction.
     #[inline(always)]
     pub fn field_rs(&self) -> Reg {
         Reg {
@@ -1062,6 +8287,8 @@ impl Ins {
         }
     }
     /// pc: Program counter
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_pc(&self) -> Reg {
         Reg {
@@ -1071,6 +8298,8 @@ impl Ins {
         }
     }
     /// pc_deref: Program counter as base register
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_pc_deref(&self) -> Reg {
         Reg {
@@ -1080,6 +8309,8 @@ impl Ins {
         }
     }
     /// sp: Stack pointer
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_sp(&self) -> Reg {
         Reg {
@@ -1089,6 +8320,8 @@ impl Ins {
         }
     }
     /// sp_ual: Stack pointer
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_sp_ual(&self) -> Reg {
         Reg {
@@ -1098,6 +8331,8 @@ impl Ins {
         }
     }
     /// sp_deref: Stack pointer as base register
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_sp_deref(&self) -> Reg {
         Reg {
@@ -1107,6 +8342,8 @@ impl Ins {
         }
     }
     /// registers: List of general-purpose registers
+    ///
+    /// Reads bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_registers(&self) -> RegList {
         RegList {
@@ -1115,6 +8352,8 @@ impl Ins {
         }
     }
     /// registers_pc: List of general-purpose registers, including PC
+    ///
+    /// Reads bits[8:0] of the instruction.
     #[inline(always)]
     pub fn field_registers_pc(&self) -> RegList {
         RegList {
@@ -1123,6 +8362,8 @@ impl Ins {
         }
     }
     /// registers_lr: List of general-purpose registers, including LR
+    ///
+    /// Reads bits[8:0] of the instruction.
     #[inline(always)]
     pub fn field_registers_lr(&self) -> RegList {
         RegList {
@@ -1131,36 +8372,50 @@ impl Ins {
         }
     }
     /// zero: Zero
+    ///
+    /// Reads no bits of the instruction.
     #[inline(always)]
     pub fn field_zero(&self) -> u32 {
         0
     }
     /// immed_3: 3-bit immediate
+    ///
+    /// Reads bits[8:6] of the instruction.
     #[inline(always)]
     pub fn field_immed_3(&self) -> u32 {
         ((self.code >> 6) & 0x00000007)
     }
     /// immed_8: 8-bit immediate
+    ///
+    /// Reads bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_immed_8(&self) -> u32 {
         (self.code & 0x000000ff)
     }
     /// rel_immed_7: Address-relative 7-bit immediate
+    ///
+    /// Reads bits[6:0] of the instruction.
     #[inline(always)]
     pub fn field_rel_immed_7(&self) -> u32 {
         (self.code & 0x0000007f) << 2
     }
     /// rel_immed_8: Address-relative 8-bit immediate
+    ///
+    /// Reads bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_rel_immed_8(&self) -> u32 {
         (self.code & 0x000000ff) << 2
     }
     /// left_shift_imm: 5-bit left shift offset
+    ///
+    /// Reads bits[10:6] of the instruction.
     #[inline(always)]
     pub fn field_left_shift_imm(&self) -> u32 {
         ((self.code >> 6) & 0x0000001f)
     }
     /// right_shift_imm: 5-bit right shift offset
+    ///
+    /// Reads bits[10:6] of the instruction.
     #[inline(always)]
     pub fn field_right_shift_imm(&self) -> u32 {
         {
@@ -1172,31 +8427,43 @@ impl Ins {
         }
     }
     /// branch_offset_8: 9-bit signed B target offset
+    ///
+    /// Reads bits[7:0] of the instruction.
     #[inline(always)]
     pub fn field_branch_offset_8(&self) -> i32 {
-        (((self.code & 0x000000ff) << 1) + 4) as i32
+        (((((self.code & 0x000000ff) << 1) as i32) << 23 >> 23) + 4) as i32
     }
     /// branch_offset_11: 12-bit signed B target offset
+    ///
+    /// Reads bits[10:0] of the instruction.
     #[inline(always)]
     pub fn field_branch_offset_11(&self) -> i32 {
-        (((self.code & 0x000007ff) << 1) + 4) as i32
+        (((((self.code & 0x000007ff) << 1) as i32) << 20 >> 20) + 4) as i32
     }
     /// high_branch_offset_11: 23-bit signed BL/BLX target offset (high part)
+    ///
+    /// Reads bits[10:0] of the instruction.
     #[inline(always)]
     pub fn field_high_branch_offset_11(&self) -> i32 {
         (((self.code & 0x000007ff) << 12) + 4) as i32
     }
     /// low_branch_offset_11: 23-bit signed BL target offset (low part)
+    ///
+    /// Reads bits[10:0] of the instruction.
     #[inline(always)]
     pub fn field_low_branch_offset_11(&self) -> u32 {
         (self.code & 0x000007ff) << 1
     }
     /// low_blx_offset_11: 23-bit signed BLX target offset (low part)
+    ///
+    /// Reads bits[10:0] of the instruction.
     #[inline(always)]
     pub fn field_low_blx_offset_11(&self) -> u32 {
         (self.code & 0x000007ff) << 1 & !3
     }
     /// offset_5: 7-bit immediate offset
+    ///
+    /// Reads bits[10:6] of the instruction.
     #[inline(always)]
     pub fn field_offset_5(&self) -> OffsetImm {
         OffsetImm {
@@ -1205,6 +8472,8 @@ impl Ins {
         }
     }
     /// cond: Condition code
+    ///
+    /// Reads bits[11:8] of the instruction.
     #[inline(always)]
     pub const fn modifier_cond(&self) -> Cond {
         match self.code & 0x00000f00 {
@@ -1222,51 +8491,1072 @@ impl Ins {
             0x00000b00 => Cond::Lt,
             0x00000c00 => Cond::Gt,
             0x00000d00 => Cond::Le,
-            0x00000e00 => Cond::Al,
             _ => Cond::Illegal,
         }
     }
-}
-/// cond: Condition code
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Cond {
-    Illegal,
-    /// eq: Equal
-    Eq,
-    /// ne: Not equal
-    Ne,
-    /// hs: Unsigned higher or same
-    Hs,
-    /// lo: Unsigned lower
-    Lo,
-    /// mi: Minus/negative
-    Mi,
-    /// pl: Plus/positive or zero
-    Pl,
-    /// vs: Overflow
-    Vs,
-    /// vc: No overflow
-    Vc,
-    /// hi: Unsigned higher
-    Hi,
-    /// ls: Unsigned lower or same
-    Ls,
-    /// ge: Signed greater than or equal
-    Ge,
-    /// lt: Signed less than
-    Lt,
-    /// gt: Signed greater than
-    Gt,
-    /// le: Signed less than or equal
-    Le,
-    /// al: Always
-    Al,
-}
-fn parse_adc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
-    if flags.ual {
-        *out = ParsedIns {
-            mnemonic: "adcs",
-            args: [
+    /// Returns the fields and modifiers of this instruction which are not fixed by its opcode, in both syntaxes. Unlike
+    /// [`Opcode::field_descs`], fields which only belong to other modifier cases are left out, e.g. the offset
+    /// register of an `ldr` with an immediate offset.
+    pub fn field_descs(&self) -> &'static [FieldDesc] {
+        match self.op {
+            Opcode::Adc => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Add3 => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "immed_3",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::Add8 => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::AddR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::AddHr => {
+                &[
+                    FieldDesc {
+                        name: "Rd_H1",
+                        bitmask: 0x00000087,
+                    },
+                    FieldDesc {
+                        name: "Rm_H2",
+                        bitmask: 0x00000078,
+                    },
+                    FieldDesc {
+                        name: "Rd_H1_ual",
+                        bitmask: 0x00000087,
+                    },
+                ]
+            }
+            Opcode::AddSp => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "rel_immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::AddSp7 => {
+                &[
+                    FieldDesc {
+                        name: "rel_immed_7",
+                        bitmask: 0x0000007f,
+                    },
+                ]
+            }
+            Opcode::AddRegSp => {
+                &[
+                    FieldDesc {
+                        name: "Rd_H1",
+                        bitmask: 0x00000087,
+                    },
+                ]
+            }
+            Opcode::AddSpReg => {
+                &[
+                    FieldDesc {
+                        name: "Rm_H2",
+                        bitmask: 0x00000078,
+                    },
+                ]
+            }
+            Opcode::AddPc => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "rel_immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::Adr => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "rel_immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::And => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::AsrI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "right_shift_imm",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::AsrR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::B => {
+                &[
+                    FieldDesc {
+                        name: "branch_offset_8",
+                        bitmask: 0x000000ff,
+                    },
+                    FieldDesc {
+                        name: "cond",
+                        bitmask: 0x00000f00,
+                    },
+                ]
+            }
+            Opcode::BLong => {
+                &[
+                    FieldDesc {
+                        name: "branch_offset_11",
+                        bitmask: 0x000007ff,
+                    },
+                ]
+            }
+            Opcode::Bic => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Bkpt => {
+                &[
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::BlH => {
+                &[
+                    FieldDesc {
+                        name: "high_branch_offset_11",
+                        bitmask: 0x000007ff,
+                    },
+                ]
+            }
+            Opcode::Bl => {
+                &[
+                    FieldDesc {
+                        name: "low_branch_offset_11",
+                        bitmask: 0x000007ff,
+                    },
+                ]
+            }
+            Opcode::BlxI => {
+                &[
+                    FieldDesc {
+                        name: "low_blx_offset_11",
+                        bitmask: 0x000007ff,
+                    },
+                ]
+            }
+            Opcode::BlxR => {
+                &[
+                    FieldDesc {
+                        name: "Rm_H2",
+                        bitmask: 0x00000078,
+                    },
+                ]
+            }
+            Opcode::BxR => {
+                &[
+                    FieldDesc {
+                        name: "Rm_H2",
+                        bitmask: 0x00000078,
+                    },
+                ]
+            }
+            Opcode::Cmn => {
+                &[
+                    FieldDesc {
+                        name: "Rn_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::CmpI => {
+                &[
+                    FieldDesc {
+                        name: "Rn_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::CmpR => {
+                &[
+                    FieldDesc {
+                        name: "Rn_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::CmpHr => {
+                &[
+                    FieldDesc {
+                        name: "Rn_H1",
+                        bitmask: 0x00000087,
+                    },
+                    FieldDesc {
+                        name: "Rm_H2",
+                        bitmask: 0x00000078,
+                    },
+                ]
+            }
+            Opcode::Eor => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Ldm => {
+                &[
+                    FieldDesc {
+                        name: "Rn_8_ldm",
+                        bitmask: 0x000007ff,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::Ldmia => {
+                &[
+                    FieldDesc {
+                        name: "Rn_8_wb",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::LdrI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "offset_5",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::LdrR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::LdrPc => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "rel_immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::LdrSp => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "rel_immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::LdrbI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "offset_5",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::LdrbR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::LdrhI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "offset_5",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::LdrhR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::Ldrsb => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::Ldrsh => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::LslI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "left_shift_imm",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::LslR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::LsrI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "right_shift_imm",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::LsrR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::MovI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::MovR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::MovsR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::MovHr => {
+                &[
+                    FieldDesc {
+                        name: "Rd_H1",
+                        bitmask: 0x00000087,
+                    },
+                    FieldDesc {
+                        name: "Rm_H2",
+                        bitmask: 0x00000078,
+                    },
+                ]
+            }
+            Opcode::Mul => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Mvn => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::Neg => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::Rsbs => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::Orr => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Pop => {
+                &[
+                    FieldDesc {
+                        name: "registers_pc",
+                        bitmask: 0x000001ff,
+                    },
+                ]
+            }
+            Opcode::Push => {
+                &[
+                    FieldDesc {
+                        name: "registers_lr",
+                        bitmask: 0x000001ff,
+                    },
+                ]
+            }
+            Opcode::Ror => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rs",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Sbc => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rd_0_ual",
+                        bitmask: 0x00000007,
+                    },
+                ]
+            }
+            Opcode::Stm => {
+                &[
+                    FieldDesc {
+                        name: "Rn_8_wb",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "registers",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::StrI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "offset_5",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::StrR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::StrSp => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "rel_immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::StrbI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "offset_5",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::StrbR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::StrhI => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "offset_5",
+                        bitmask: 0x000007c0,
+                    },
+                ]
+            }
+            Opcode::StrhR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3_deref",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6_offset",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::Subs3 => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "immed_3",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::Sub8 => {
+                &[
+                    FieldDesc {
+                        name: "Rd_8",
+                        bitmask: 0x00000700,
+                    },
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::SubR => {
+                &[
+                    FieldDesc {
+                        name: "Rd_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rn_3",
+                        bitmask: 0x00000038,
+                    },
+                    FieldDesc {
+                        name: "Rm_6",
+                        bitmask: 0x000001c0,
+                    },
+                ]
+            }
+            Opcode::SubSp7 => {
+                &[
+                    FieldDesc {
+                        name: "rel_immed_7",
+                        bitmask: 0x0000007f,
+                    },
+                ]
+            }
+            Opcode::Svc => {
+                &[
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::Swi => {
+                &[
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            Opcode::Tst => {
+                &[
+                    FieldDesc {
+                        name: "Rn_0",
+                        bitmask: 0x00000007,
+                    },
+                    FieldDesc {
+                        name: "Rm_3",
+                        bitmask: 0x00000038,
+                    },
+                ]
+            }
+            Opcode::Udf => {
+                &[
+                    FieldDesc {
+                        name: "immed_8",
+                        bitmask: 0x000000ff,
+                    },
+                ]
+            }
+            _ => &[],
+        }
+    }
+    /// Returns the name and raw value of each field of [`Self::field_descs`], see [`FieldDesc::value`].
+    pub fn fields(&self) -> impl Iterator<Item = (&'static str, u32)> {
+        let code = self.code;
+        self.field_descs().iter().map(move |field| (field.name, field.value(code)))
+    }
+    #[inline(always)]
+    fn modifier_cond_condition(&self) -> Condition {
+        match self.modifier_cond() {
+            Cond::Eq => Condition::Eq,
+            Cond::Ne => Condition::Ne,
+            Cond::Hs => Condition::Hs,
+            Cond::Lo => Condition::Lo,
+            Cond::Mi => Condition::Mi,
+            Cond::Pl => Condition::Pl,
+            Cond::Vs => Condition::Vs,
+            Cond::Vc => Condition::Vc,
+            Cond::Hi => Condition::Hi,
+            Cond::Ls => Condition::Ls,
+            Cond::Ge => Condition::Ge,
+            Cond::Lt => Condition::Lt,
+            Cond::Gt => Condition::Gt,
+            Cond::Le => Condition::Le,
+            Cond::Illegal => Condition::Nv,
+        }
+    }
+    /// Returns the condition under which the instruction is executed. Instructions without a condition code return
+    /// [`Condition::Al`], except for those in the unconditional instruction space which return [`Condition::Nv`].
+    pub fn condition(&self) -> Condition {
+        match self.op {
+            Opcode::B => self.modifier_cond_condition(),
+            _ => Condition::Al,
+        }
+    }
+    /// Returns a description of why the encoding is UNPREDICTABLE, or `None` if it's not. Only the constraints in the
+    /// ISA specification are checked, so this is not exhaustive.
+    pub const fn unpredictable_reason(&self) -> Option<&'static str> {
+        match self.op {
+            Opcode::Ldm => {
+                if (self.code & 0x000000ff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::Ldmia => {
+                if (self.code & 0x000000ff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::Pop => {
+                if (self.code & 0x000001ff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::Push => {
+                if (self.code & 0x000001ff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            Opcode::Stm => {
+                if (self.code & 0x000000ff) == 0 {
+                    return Some("Empty register list");
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+    /// Returns true if the encoding is UNPREDICTABLE, see [`Self::unpredictable_reason`].
+    pub const fn is_unpredictable(&self) -> bool {
+        self.unpredictable_reason().is_some()
+    }
+}
+/// cond: Condition code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Cond {
+    Illegal,
+    /// eq: Equal
+    Eq,
+    /// ne: Not equal
+    Ne,
+    /// hs: Unsigned higher or same
+    Hs,
+    /// lo: Unsigned lower
+    Lo,
+    /// mi: Minus/negative
+    Mi,
+    /// pl: Plus/positive or zero
+    Pl,
+    /// vs: Overflow
+    Vs,
+    /// vc: No overflow
+    Vc,
+    /// hi: Unsigned higher
+    Hi,
+    /// ls: Unsigned lower or same
+    Ls,
+    /// ge: Signed greater than or equal
+    Ge,
+    /// lt: Signed less than
+    Lt,
+    /// gt: Signed greater than
+    Gt,
+    /// le: Signed less than or equal
+    Le,
+}
+#[inline]
+fn parse_adc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
+    if flags.ual {
+        *out = ParsedIns {
+            mnemonic: "adcs",
+            args: [
                 Argument::Reg(ins.field_rd_0()),
                 Argument::Reg(ins.field_rd_0_ual()),
                 Argument::Reg(ins.field_rm_3()),
@@ -1287,9 +9577,12 @@ fn parse_adc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_3(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "adds",
@@ -1314,9 +9607,12 @@ fn parse_add_3(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_8(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "adds",
@@ -1341,9 +9637,12 @@ fn parse_add_8(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "adds",
@@ -1368,9 +9667,12 @@ fn parse_add_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_hr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "add",
@@ -1395,9 +9697,12 @@ fn parse_add_hr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "add",
         args: [
@@ -1409,8 +9714,11 @@ fn parse_add_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_sp7(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "add",
@@ -1435,9 +9743,12 @@ fn parse_add_sp7(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_reg_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "add",
         args: [
@@ -1449,8 +9760,11 @@ fn parse_add_reg_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_sp_reg(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "add",
@@ -1475,9 +9789,12 @@ fn parse_add_sp_reg(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_add_pc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "add",
         args: [
@@ -1489,8 +9806,11 @@ fn parse_add_pc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_adr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "adr",
         args: [
@@ -1502,8 +9822,11 @@ fn parse_adr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_and(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "ands",
@@ -1528,9 +9851,12 @@ fn parse_and(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_asr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "asrs",
@@ -1555,9 +9881,12 @@ fn parse_asr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_asr_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "asrs",
@@ -1582,9 +9911,12 @@ fn parse_asr_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = match ins.modifier_cond() {
         Cond::Eq => {
             ParsedIns {
@@ -1768,19 +10100,6 @@ fn parse_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 ],
             }
         }
-        Cond::Al => {
-            ParsedIns {
-                mnemonic: "b",
-                args: [
-                    Argument::BranchDest(ins.field_branch_offset_8()),
-                    Argument::None,
-                    Argument::None,
-                    Argument::None,
-                    Argument::None,
-                    Argument::None,
-                ],
-            }
-        }
         _ => {
             ParsedIns {
                 mnemonic: "<illegal>",
@@ -1795,12 +10114,15 @@ fn parse_b(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             }
         }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_b_long(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "b",
         args: [
-            Argument::SImm(ins.field_branch_offset_11()),
+            Argument::BranchDest(ins.field_branch_offset_11()),
             Argument::None,
             Argument::None,
             Argument::None,
@@ -1808,8 +10130,11 @@ fn parse_b_long(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_bic(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "bics",
@@ -1834,9 +10159,12 @@ fn parse_bic(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_bkpt(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "bkpt",
         args: [
@@ -1848,8 +10176,11 @@ fn parse_bkpt(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_bl_h(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "bl",
         args: [
@@ -1861,8 +10192,11 @@ fn parse_bl_h(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_bl(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "bl",
         args: [
@@ -1874,8 +10208,11 @@ fn parse_bl(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_blx_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "blx",
         args: [
@@ -1887,8 +10224,11 @@ fn parse_blx_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_blx_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "blx",
         args: [
@@ -1900,8 +10240,11 @@ fn parse_blx_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_bx_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "bx",
         args: [
@@ -1913,8 +10256,11 @@ fn parse_bx_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cmn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "cmn",
         args: [
@@ -1926,8 +10272,11 @@ fn parse_cmn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cmp_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "cmp",
         args: [
@@ -1939,8 +10288,11 @@ fn parse_cmp_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cmp_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "cmp",
         args: [
@@ -1952,8 +10304,11 @@ fn parse_cmp_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_cmp_hr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "cmp",
         args: [
@@ -1965,8 +10320,11 @@ fn parse_cmp_hr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_eor(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "eors",
@@ -1991,9 +10349,12 @@ fn parse_eor(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldm",
         args: [
@@ -2005,8 +10366,11 @@ fn parse_ldm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldmia(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldmia",
         args: [
@@ -2018,8 +10382,11 @@ fn parse_ldmia(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldr",
         args: [
@@ -2031,8 +10398,11 @@ fn parse_ldr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldr",
         args: [
@@ -2044,8 +10414,11 @@ fn parse_ldr_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_pc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldr",
         args: [
@@ -2057,8 +10430,11 @@ fn parse_ldr_pc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldr_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldr",
         args: [
@@ -2070,8 +10446,11 @@ fn parse_ldr_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldrb_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldrb",
         args: [
@@ -2083,8 +10462,11 @@ fn parse_ldrb_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldrb_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldrb",
         args: [
@@ -2096,8 +10478,11 @@ fn parse_ldrb_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldrh_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldrh",
         args: [
@@ -2109,8 +10494,11 @@ fn parse_ldrh_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldrh_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldrh",
         args: [
@@ -2122,8 +10510,11 @@ fn parse_ldrh_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldrsb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldrsb",
         args: [
@@ -2135,8 +10526,11 @@ fn parse_ldrsb(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ldrsh(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "ldrsh",
         args: [
@@ -2148,8 +10542,11 @@ fn parse_ldrsh(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_lsl_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "lsls",
@@ -2174,9 +10571,12 @@ fn parse_lsl_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_lsl_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "lsls",
@@ -2201,9 +10601,12 @@ fn parse_lsl_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_lsr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "lsrs",
@@ -2228,9 +10631,12 @@ fn parse_lsr_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_lsr_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "lsrs",
@@ -2255,9 +10661,12 @@ fn parse_lsr_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_mov_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "movs",
@@ -2282,9 +10691,12 @@ fn parse_mov_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_mov_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "mov",
         args: [
@@ -2296,8 +10708,11 @@ fn parse_mov_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_movs_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "movs",
         args: [
@@ -2309,8 +10724,11 @@ fn parse_movs_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mov_hr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "mov",
         args: [
@@ -2322,8 +10740,11 @@ fn parse_mov_hr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_mul(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "muls",
@@ -2348,9 +10769,12 @@ fn parse_mul(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_mvn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "mvns",
@@ -2375,9 +10799,12 @@ fn parse_mvn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_neg(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "neg",
         args: [
@@ -2389,8 +10816,11 @@ fn parse_neg(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_rsbs(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "rsbs",
         args: [
@@ -2402,8 +10832,11 @@ fn parse_rsbs(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_orr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "orrs",
@@ -2428,9 +10861,12 @@ fn parse_orr(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_pop(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "pop",
         args: [
@@ -2442,8 +10878,11 @@ fn parse_pop(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_push(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "push",
         args: [
@@ -2455,8 +10894,11 @@ fn parse_push(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_ror(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "rors",
@@ -2481,9 +10923,12 @@ fn parse_ror(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_sbc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "sbcs",
@@ -2508,9 +10953,12 @@ fn parse_sbc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_stm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "stm",
@@ -2535,9 +10983,12 @@ fn parse_stm(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "str",
         args: [
@@ -2549,8 +11000,11 @@ fn parse_str_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "str",
         args: [
@@ -2562,8 +11016,11 @@ fn parse_str_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_str_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "str",
         args: [
@@ -2575,8 +11032,11 @@ fn parse_str_sp(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_strb_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "strb",
         args: [
@@ -2588,8 +11048,11 @@ fn parse_strb_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_strb_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "strb",
         args: [
@@ -2601,8 +11064,11 @@ fn parse_strb_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_strh_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "strh",
         args: [
@@ -2614,8 +11080,11 @@ fn parse_strh_i(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_strh_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "strh",
         args: [
@@ -2627,8 +11096,11 @@ fn parse_strh_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_subs_3(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "subs",
         args: [
@@ -2640,8 +11112,11 @@ fn parse_subs_3(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_sub_8(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "subs",
@@ -2666,9 +11141,12 @@ fn parse_sub_8(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_sub_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     if flags.ual {
         *out = ParsedIns {
             mnemonic: "subs",
@@ -2693,22 +11171,42 @@ fn parse_sub_r(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
                 Argument::None,
             ],
         }
-    }
+    };
+    guard.disarm();
 }
+#[inline]
 fn parse_sub_sp7(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
-    *out = ParsedIns {
-        mnemonic: "sub",
-        args: [
-            Argument::Reg(ins.field_sp()),
-            Argument::Reg(ins.field_sp()),
-            Argument::UImm(ins.field_rel_immed_7()),
-            Argument::None,
-            Argument::None,
-            Argument::None,
-        ],
+    let guard = crate::no_panic::NoPanicGuard;
+    if flags.ual {
+        *out = ParsedIns {
+            mnemonic: "sub",
+            args: [
+                Argument::Reg(ins.field_sp()),
+                Argument::Reg(ins.field_sp_ual()),
+                Argument::UImm(ins.field_rel_immed_7()),
+                Argument::None,
+                Argument::None,
+                Argument::None,
+            ],
+        }
+    } else {
+        *out = ParsedIns {
+            mnemonic: "sub",
+            args: [
+                Argument::Reg(ins.field_sp()),
+                Argument::UImm(ins.field_rel_immed_7()),
+                Argument::None,
+                Argument::None,
+                Argument::None,
+                Argument::None,
+            ],
+        }
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_svc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "svc",
         args: [
@@ -2720,8 +11218,11 @@ fn parse_svc(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_swi(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "swi",
         args: [
@@ -2733,8 +11234,11 @@ fn parse_swi(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
 }
+#[inline]
 fn parse_tst(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
     *out = ParsedIns {
         mnemonic: "tst",
         args: [
@@ -2746,9 +11250,38 @@ fn parse_tst(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
             Argument::None,
         ],
     };
+    guard.disarm();
+}
+#[inline]
+fn parse_udf(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    let guard = crate::no_panic::NoPanicGuard;
+    *out = ParsedIns {
+        mnemonic: "udf",
+        args: [
+            Argument::UImm(ins.field_immed_8()),
+            Argument::None,
+            Argument::None,
+            Argument::None,
+            Argument::None,
+            Argument::None,
+        ],
+    };
+    guard.disarm();
+}
+/// Parses the instruction with a table of parse functions.
+#[cfg(not(feature = "fast"))]
+#[inline]
+pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    parse_table(out, ins, flags)
+}
+/// Parses the instruction with a table of parse functions.
+#[cfg(feature = "fast")]
+#[inline]
+pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    parse_table(out, ins, flags)
 }
 type MnemonicParser = fn(&mut ParsedIns, Ins, &ParseFlags);
-static MNEMONIC_PARSERS: [MnemonicParser; 72] = [
+static MNEMONIC_PARSERS: [MnemonicParser; 73] = [
     parse_adc,
     parse_add_3,
     parse_add_8,
@@ -2821,22 +11354,2932 @@ static MNEMONIC_PARSERS: [MnemonicParser; 72] = [
     parse_svc,
     parse_swi,
     parse_tst,
+    parse_udf,
 ];
+/// Implementation of [`parse`] as a call through a table of parse functions, indexed by the opcode. Public
+/// to test that it's equivalent to [`parse_match`], whichever one the profile uses.
+#[doc(hidden)]
 #[inline]
-pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
-    if ins.op != Opcode::Illegal {
-        MNEMONIC_PARSERS[ins.op as usize](out, ins, flags);
-    } else {
-        *out = ParsedIns {
-            mnemonic: "<illegal>",
-            args: [
-                Argument::None,
-                Argument::None,
-                Argument::None,
-                Argument::None,
-                Argument::None,
-                Argument::None,
+pub fn parse_table(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    match MNEMONIC_PARSERS.get(ins.op as usize) {
+        Some(parser) => parser(out, ins, flags),
+        None => {
+            *out = ParsedIns {
+                mnemonic: "<illegal>",
+                args: [
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                ],
+            };
+        }
+    }
+}
+/// Implementation of [`parse`] as a match on the opcode, which inlines the parse function of each opcode.
+/// Public to test that it's equivalent to [`parse_table`], whichever one the profile uses.
+#[doc(hidden)]
+pub fn parse_match(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+    match ins.op {
+        Opcode::Adc => parse_adc(out, ins, flags),
+        Opcode::Add3 => parse_add_3(out, ins, flags),
+        Opcode::Add8 => parse_add_8(out, ins, flags),
+        Opcode::AddR => parse_add_r(out, ins, flags),
+        Opcode::AddHr => parse_add_hr(out, ins, flags),
+        Opcode::AddSp => parse_add_sp(out, ins, flags),
+        Opcode::AddSp7 => parse_add_sp7(out, ins, flags),
+        Opcode::AddRegSp => parse_add_reg_sp(out, ins, flags),
+        Opcode::AddSpReg => parse_add_sp_reg(out, ins, flags),
+        Opcode::AddPc => parse_add_pc(out, ins, flags),
+        Opcode::Adr => parse_adr(out, ins, flags),
+        Opcode::And => parse_and(out, ins, flags),
+        Opcode::AsrI => parse_asr_i(out, ins, flags),
+        Opcode::AsrR => parse_asr_r(out, ins, flags),
+        Opcode::B => parse_b(out, ins, flags),
+        Opcode::BLong => parse_b_long(out, ins, flags),
+        Opcode::Bic => parse_bic(out, ins, flags),
+        Opcode::Bkpt => parse_bkpt(out, ins, flags),
+        Opcode::BlH => parse_bl_h(out, ins, flags),
+        Opcode::Bl => parse_bl(out, ins, flags),
+        Opcode::BlxI => parse_blx_i(out, ins, flags),
+        Opcode::BlxR => parse_blx_r(out, ins, flags),
+        Opcode::BxR => parse_bx_r(out, ins, flags),
+        Opcode::Cmn => parse_cmn(out, ins, flags),
+        Opcode::CmpI => parse_cmp_i(out, ins, flags),
+        Opcode::CmpR => parse_cmp_r(out, ins, flags),
+        Opcode::CmpHr => parse_cmp_hr(out, ins, flags),
+        Opcode::Eor => parse_eor(out, ins, flags),
+        Opcode::Ldm => parse_ldm(out, ins, flags),
+        Opcode::Ldmia => parse_ldmia(out, ins, flags),
+        Opcode::LdrI => parse_ldr_i(out, ins, flags),
+        Opcode::LdrR => parse_ldr_r(out, ins, flags),
+        Opcode::LdrPc => parse_ldr_pc(out, ins, flags),
+        Opcode::LdrSp => parse_ldr_sp(out, ins, flags),
+        Opcode::LdrbI => parse_ldrb_i(out, ins, flags),
+        Opcode::LdrbR => parse_ldrb_r(out, ins, flags),
+        Opcode::LdrhI => parse_ldrh_i(out, ins, flags),
+        Opcode::LdrhR => parse_ldrh_r(out, ins, flags),
+        Opcode::Ldrsb => parse_ldrsb(out, ins, flags),
+        Opcode::Ldrsh => parse_ldrsh(out, ins, flags),
+        Opcode::LslI => parse_lsl_i(out, ins, flags),
+        Opcode::LslR => parse_lsl_r(out, ins, flags),
+        Opcode::LsrI => parse_lsr_i(out, ins, flags),
+        Opcode::LsrR => parse_lsr_r(out, ins, flags),
+        Opcode::MovI => parse_mov_i(out, ins, flags),
+        Opcode::MovR => parse_mov_r(out, ins, flags),
+        Opcode::MovsR => parse_movs_r(out, ins, flags),
+        Opcode::MovHr => parse_mov_hr(out, ins, flags),
+        Opcode::Mul => parse_mul(out, ins, flags),
+        Opcode::Mvn => parse_mvn(out, ins, flags),
+        Opcode::Neg => parse_neg(out, ins, flags),
+        Opcode::Rsbs => parse_rsbs(out, ins, flags),
+        Opcode::Orr => parse_orr(out, ins, flags),
+        Opcode::Pop => parse_pop(out, ins, flags),
+        Opcode::Push => parse_push(out, ins, flags),
+        Opcode::Ror => parse_ror(out, ins, flags),
+        Opcode::Sbc => parse_sbc(out, ins, flags),
+        Opcode::Stm => parse_stm(out, ins, flags),
+        Opcode::StrI => parse_str_i(out, ins, flags),
+        Opcode::StrR => parse_str_r(out, ins, flags),
+        Opcode::StrSp => parse_str_sp(out, ins, flags),
+        Opcode::StrbI => parse_strb_i(out, ins, flags),
+        Opcode::StrbR => parse_strb_r(out, ins, flags),
+        Opcode::StrhI => parse_strh_i(out, ins, flags),
+        Opcode::StrhR => parse_strh_r(out, ins, flags),
+        Opcode::Subs3 => parse_subs_3(out, ins, flags),
+        Opcode::Sub8 => parse_sub_8(out, ins, flags),
+        Opcode::SubR => parse_sub_r(out, ins, flags),
+        Opcode::SubSp7 => parse_sub_sp7(out, ins, flags),
+        Opcode::Svc => parse_svc(out, ins, flags),
+        Opcode::Swi => parse_swi(out, ins, flags),
+        Opcode::Tst => parse_tst(out, ins, flags),
+        Opcode::Udf => parse_udf(out, ins, flags),
+        _ => {
+            *out = ParsedIns {
+                mnemonic: "<illegal>",
+                args: [
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                    Argument::None,
+                ],
+            };
+        }
+    }
+}
+/// Rd_0: Destination register
+fn encode_rd_0(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 0, 3).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rd_0_ual: Destination register
+fn encode_rd_0_ual(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 0, 3).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rd_8: Destination register
+fn encode_rd_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 8, 11).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rd_H1: Destination register
+fn encode_rd_h1(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (encode::bits(value.reg as u32, 0, 4).map(|reg| (reg & 7) | (reg & 8) << 4))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// Rd_H1_ual: Destination register
+fn encode_rd_h1_ual(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (encode::bits(value.reg as u32, 0, 4).map(|reg| (reg & 7) | (reg & 8) << 4))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// Rn_0: First source operand register
+fn encode_rn_0(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 0, 3).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_3: First source operand register
+fn encode_rn_3(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 3, 6).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_3_deref: Base register
+fn encode_rn_3_deref(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 3, 6).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_8: First source operand register
+fn encode_rn_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 8, 11).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_8_wb: First source operand register
+fn encode_rn_8_wb(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 8, 11).ok_or(FieldError::OutOfRange)?;
+    if !value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rn_8_ldm: First source operand register
+fn encode_rn_8_ldm(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 8, 11).ok_or(FieldError::OutOfRange)?;
+    code |= (Some(0)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// Rn_H1: First source operand register
+fn encode_rn_h1(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (encode::bits(value.reg as u32, 0, 4).map(|reg| (reg & 7) | (reg & 8) << 4))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// Rm_3: Second source operand register
+fn encode_rm_3(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 3, 6).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rm_6: Second source operand register
+fn encode_rm_6(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 6, 9).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rm_6_offset: Offset register
+fn encode_rm_6_offset(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetReg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.add {
+        return Err(FieldError::Invalid);
+    }
+    if value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 6, 9).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// Rm_H2: Second source operand register
+fn encode_rm_h2(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 3, 7).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// Rs: Register containing shift offset
+fn encode_rs(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    code |= encode::bits(value.reg as u32, 3, 6).ok_or(FieldError::OutOfRange)?;
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// pc: Program counter
+fn encode_pc(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.reg as u32 != 15 {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// pc_deref: Program counter as base register
+fn encode_pc_deref(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.reg as u32 != 15 {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// sp: Stack pointer
+fn encode_sp(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.reg as u32 != 13 {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// sp_ual: Stack pointer
+fn encode_sp_ual(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.reg as u32 != 13 {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// sp_deref: Stack pointer as base register
+fn encode_sp_deref(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::Reg(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if !value.deref {
+        return Err(FieldError::Invalid);
+    }
+    if value.reg as u32 != 13 {
+        return Err(FieldError::Invalid);
+    }
+    if value.writeback {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// registers: List of general-purpose registers
+fn encode_registers(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::RegList(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value.regs, 0, 8).ok_or(FieldError::OutOfRange)?;
+    if value.user_mode {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// registers_pc: List of general-purpose registers, including PC
+fn encode_registers_pc(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::RegList(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.user_mode {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= ((value.regs & !0x80ff == 0)
+            .then_some((value.regs & 0xff) | (value.regs >> 15) << 8))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// registers_lr: List of general-purpose registers, including LR
+fn encode_registers_lr(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::RegList(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.user_mode {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= ((value.regs & !0x40ff == 0)
+            .then_some((value.regs & 0xff) | (value.regs >> 14) << 8))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// zero: Zero
+fn encode_zero(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value != 0 {
+        return Err(FieldError::Invalid);
+    }
+    Ok(code)
+}
+/// immed_3: 3-bit immediate
+fn encode_immed_3(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 6, 9).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// immed_8: 8-bit immediate
+fn encode_immed_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 0, 8).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// rel_immed_7: Address-relative 7-bit immediate
+fn encode_rel_immed_7(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::bits(value, 0, 9).filter(|imm| imm & 3 == 0).map(|imm| imm >> 2))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// rel_immed_8: Address-relative 8-bit immediate
+fn encode_rel_immed_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::bits(value, 0, 10).filter(|imm| imm & 3 == 0).map(|imm| imm >> 2))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// left_shift_imm: 5-bit left shift offset
+fn encode_left_shift_imm(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= encode::bits(value, 6, 11).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// right_shift_imm: 5-bit right shift offset
+fn encode_right_shift_imm(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::arm_shift(1, value).map(|imm| imm << 6))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// branch_offset_8: 9-bit signed B target offset
+fn encode_branch_offset_8(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::BranchDest(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code |= (encode::signed_bits(value as i64 - 4, 1, 8)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// branch_offset_11: 12-bit signed B target offset
+fn encode_branch_offset_11(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::BranchDest(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::signed_bits(value as i64 - 4, 1, 11)).ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// high_branch_offset_11: 23-bit signed BL/BLX target offset (high part)
+fn encode_high_branch_offset_11(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::SImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (u32::try_from(value as i64 - 4)
+            .ok()
+            .filter(|offset| offset & 0xfff == 0)
+            .and_then(|offset| encode::bits(offset >> 12, 0, 11)))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// low_branch_offset_11: 23-bit signed BL target offset (low part)
+fn encode_low_branch_offset_11(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::bits(value, 0, 12)
+            .filter(|offset| offset & 1 == 0)
+            .map(|offset| offset >> 1))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// low_blx_offset_11: 23-bit signed BLX target offset (low part)
+fn encode_low_blx_offset_11(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::UImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    code
+        |= (encode::bits(value, 0, 12)
+            .filter(|offset| offset & 3 == 0)
+            .map(|offset| offset >> 1))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// offset_5: 7-bit immediate offset
+fn encode_offset_5(arg: Argument) -> Result<u32, FieldError> {
+    let Argument::OffsetImm(value) = arg else {
+        return Err(FieldError::Invalid);
+    };
+    let mut code = 0;
+    if value.post_indexed {
+        return Err(FieldError::Invalid);
+    }
+    code
+        |= (u32::try_from(value.value)
+            .ok()
+            .and_then(|offset| encode::bits(offset, 0, 7))
+            .filter(|offset| offset & 3 == 0)
+            .map(|offset| offset << 4))
+            .ok_or(FieldError::OutOfRange)?;
+    Ok(code)
+}
+/// cond: Condition code
+static ENCODE_CASES_COND: [EncodeCase; 14] = [
+    EncodeCase {
+        suffix: "eq",
+        bitmask: 0x00000f00,
+        pattern: 0x00000000,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ne",
+        bitmask: 0x00000f00,
+        pattern: 0x00000100,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "hs",
+        bitmask: 0x00000f00,
+        pattern: 0x00000200,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "lo",
+        bitmask: 0x00000f00,
+        pattern: 0x00000300,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "mi",
+        bitmask: 0x00000f00,
+        pattern: 0x00000400,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "pl",
+        bitmask: 0x00000f00,
+        pattern: 0x00000500,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "vs",
+        bitmask: 0x00000f00,
+        pattern: 0x00000600,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "vc",
+        bitmask: 0x00000f00,
+        pattern: 0x00000700,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "hi",
+        bitmask: 0x00000f00,
+        pattern: 0x00000800,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ls",
+        bitmask: 0x00000f00,
+        pattern: 0x00000900,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "ge",
+        bitmask: 0x00000f00,
+        pattern: 0x00000a00,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "lt",
+        bitmask: 0x00000f00,
+        pattern: 0x00000b00,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "gt",
+        bitmask: 0x00000f00,
+        pattern: 0x00000c00,
+        fields: &[],
+    },
+    EncodeCase {
+        suffix: "le",
+        bitmask: 0x00000f00,
+        pattern: 0x00000d00,
+        fields: &[],
+    },
+];
+static ENCODE_FORMS: [&[EncodeForm]; 73] = [
+    &[
+        EncodeForm {
+            name: "adc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004140,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
             ],
-        };
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "adc",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004140,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000fe00,
+            pattern: 0x00001c00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_immed_3,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000fe00,
+            pattern: 0x00001c00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_immed_3,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x00003000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x00003000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000fe00,
+            pattern: 0x00001800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000fe00,
+            pattern: 0x00001800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ff00,
+            pattern: 0x00004400,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_h1,
+                    bitmask: 0x00000087,
+                },
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ff00,
+            pattern: 0x00004400,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_h1,
+                    bitmask: 0x00000087,
+                },
+                EncodeField {
+                    encode: encode_rd_h1_ual,
+                    bitmask: 0x00000087,
+                },
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x0000a800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ff80,
+            pattern: 0x0000b000,
+            fields: &[
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_7,
+                    bitmask: 0x0000007f,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ff80,
+            pattern: 0x0000b000,
+            fields: &[
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_sp_ual,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_7,
+                    bitmask: 0x0000007f,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff78,
+            pattern: 0x00004468,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_h1,
+                    bitmask: 0x00000087,
+                },
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rd_h1,
+                    bitmask: 0x00000087,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ff87,
+            pattern: 0x00004485,
+            fields: &[
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ff87,
+            pattern: 0x00004485,
+            fields: &[
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_sp_ual,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "add",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x0000a000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_pc,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "adr",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x0000a000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "and",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "and",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "asr",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x00001000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_right_shift_imm,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "asr",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x00001000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_right_shift_imm,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "asr",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004100,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "asr",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004100,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "b",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f000,
+            pattern: 0x0000d000,
+            fields: &[
+                EncodeField {
+                    encode: encode_branch_offset_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[&ENCODE_CASES_COND],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "b",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x0000e000,
+            fields: &[
+                EncodeField {
+                    encode: encode_branch_offset_11,
+                    bitmask: 0x000007ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bic",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004380,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "bic",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004380,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bkpt",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff00,
+            pattern: 0x0000be00,
+            fields: &[
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bl",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x0000f000,
+            fields: &[
+                EncodeField {
+                    encode: encode_high_branch_offset_11,
+                    bitmask: 0x000007ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bl",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x0000f800,
+            fields: &[
+                EncodeField {
+                    encode: encode_low_branch_offset_11,
+                    bitmask: 0x000007ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "blx",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x0000e800,
+            fields: &[
+                EncodeField {
+                    encode: encode_low_blx_offset_11,
+                    bitmask: 0x000007ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "blx",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff87,
+            pattern: 0x00004780,
+            fields: &[
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "bx",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff87,
+            pattern: 0x00004700,
+            fields: &[
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cmn",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ffc0,
+            pattern: 0x000042c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cmp",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00002800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cmp",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004280,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "cmp",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff00,
+            pattern: 0x00004500,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_h1,
+                    bitmask: 0x00000087,
+                },
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "eor",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004040,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "eor",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004040,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x0000c800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_8_ldm,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldmia",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x0000c800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_8_wb,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00006800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_offset_5,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00004800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_pc_deref,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldr",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00009800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_sp_deref,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldrb",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00007800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_offset_5,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldrb",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005c00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldrh",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00008800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_offset_5,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldrh",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005a00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldrsb",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005600,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ldrsh",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005e00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "lsl",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x00000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_left_shift_imm,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "lsl",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x00000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_left_shift_imm,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "lsl",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004080,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "lsl",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004080,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "lsr",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x00000800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_right_shift_imm,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "lsr",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x00000800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_right_shift_imm,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "lsr",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x000040c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "lsr",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x000040c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x00002000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x00002000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00001c00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "movs",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00000000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mov",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff00,
+            pattern: 0x00004600,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_h1,
+                    bitmask: 0x00000087,
+                },
+                EncodeField {
+                    encode: encode_rm_h2,
+                    bitmask: 0x00000078,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mul",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004340,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "mul",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004340,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "mvn",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x000043c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "mvn",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x000043c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "neg",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004240,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "rsbs",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004240,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_zero,
+                    bitmask: 0x00000000,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "orr",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004300,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "orr",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004300,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "pop",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x0000bc00,
+            fields: &[
+                EncodeField {
+                    encode: encode_registers_pc,
+                    bitmask: 0x000001ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "push",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x0000b400,
+            fields: &[
+                EncodeField {
+                    encode: encode_registers_lr,
+                    bitmask: 0x000001ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "ror",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x000041c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "ror",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x000041c0,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rs,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "sbc",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004180,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "sbc",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004180,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rd_0_ual,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "ia",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x0000c000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_8_wb,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "stm",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x0000c000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_8_wb,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_registers,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00006000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_offset_5,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "str",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00009000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_sp_deref,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "strb",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00007000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_offset_5,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "strb",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005400,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "strh",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000f800,
+            pattern: 0x00008000,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_offset_5,
+                    bitmask: 0x000007c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "strh",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00005200,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3_deref,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6_offset,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "subs",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000fe00,
+            pattern: 0x00001e00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_immed_3,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000f800,
+            pattern: 0x00003800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000f800,
+            pattern: 0x00003800,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_8,
+                    bitmask: 0x00000700,
+                },
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000fe00,
+            pattern: 0x00001a00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "s",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000fe00,
+            pattern: 0x00001a00,
+            fields: &[
+                EncodeField {
+                    encode: encode_rd_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rn_3,
+                    bitmask: 0x00000038,
+                },
+                EncodeField {
+                    encode: encode_rm_6,
+                    bitmask: 0x000001c0,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ff80,
+            pattern: 0x0000b080,
+            fields: &[
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_7,
+                    bitmask: 0x0000007f,
+                },
+            ],
+            modifiers: &[],
+        },
+        EncodeForm {
+            name: "sub",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ff80,
+            pattern: 0x0000b080,
+            fields: &[
+                EncodeField {
+                    encode: encode_sp,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_sp_ual,
+                    bitmask: 0x00000000,
+                },
+                EncodeField {
+                    encode: encode_rel_immed_7,
+                    bitmask: 0x0000007f,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "svc",
+            opcode_suffix: "",
+            ual: true,
+            flag: Some(true),
+            bitmask: 0x0000ff00,
+            pattern: 0x0000df00,
+            fields: &[
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "swi",
+            opcode_suffix: "",
+            ual: false,
+            flag: Some(false),
+            bitmask: 0x0000ff00,
+            pattern: 0x0000df00,
+            fields: &[
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "tst",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ffc0,
+            pattern: 0x00004200,
+            fields: &[
+                EncodeField {
+                    encode: encode_rn_0,
+                    bitmask: 0x00000007,
+                },
+                EncodeField {
+                    encode: encode_rm_3,
+                    bitmask: 0x00000038,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+    &[
+        EncodeForm {
+            name: "udf",
+            opcode_suffix: "",
+            ual: true,
+            flag: None,
+            bitmask: 0x0000ff00,
+            pattern: 0x0000de00,
+            fields: &[
+                EncodeField {
+                    encode: encode_immed_8,
+                    bitmask: 0x000000ff,
+                },
+            ],
+            modifiers: &[],
+        },
+    ],
+];
+/// Encodes a parsed instruction with any opcode. The result decodes to the same [`ParsedIns`] with the given
+/// flags.
+pub fn encode(ins: &ParsedIns, flags: &ParseFlags) -> Result<u32, EncodeError> {
+    let mut error = EncodeError::UnknownMnemonic;
+    for forms in ENCODE_FORMS {
+        let result = encode::encode_forms(
+            forms,
+            ins,
+            flags.ual,
+            |code| Ins::new(code, flags).parse(flags) == *ins,
+        );
+        match result {
+            Ok(code) => return Ok(code),
+            Err(e) => error = error.most_relevant(e),
+        }
     }
+    Err(error)
 }
@@ -0,0 +1,192 @@
+use crate::{
+    args::{Argument, Reg, Register},
+    v5te::{arm, thumb},
+    Endian, Op, ParseFlags, ParseMode, ParsedIns,
+};
+
+/// An ARM or Thumb instruction, see [`UnifiedIter`]
+#[derive(Clone, Copy)]
+pub enum Ins {
+    Arm(arm::Ins),
+    Thumb(thumb::Ins),
+}
+
+impl Ins {
+    pub fn mode(&self) -> ParseMode {
+        match self {
+            Self::Arm(_) => ParseMode::Arm,
+            Self::Thumb(_) => ParseMode::Thumb,
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Arm(ins) => ins.code,
+            Self::Thumb(ins) => ins.code,
+        }
+    }
+
+    pub fn op(&self) -> Op {
+        match self {
+            Self::Arm(ins) => Op::ArmV5Te(ins.op),
+            Self::Thumb(ins) => Op::ThumbV5Te(ins.op),
+        }
+    }
+
+    /// Returns the number of bytes this instruction takes, see `arm::Ins::size` and `thumb::Ins::size`
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Arm(ins) => ins.size(),
+            Self::Thumb(ins) => ins.size(),
+        }
+    }
+
+    /// Parses the instruction. The first half of a Thumb BL pair is parsed on its own, like `thumb::Ins::parse`.
+    pub fn parse(self, flags: &ParseFlags) -> ParsedIns {
+        match self {
+            Self::Arm(ins) => ins.parse(flags),
+            Self::Thumb(ins) => ins.parse(flags),
+        }
+    }
+}
+
+/// Iterator over ARM and Thumb instructions in a code buffer, which parses each instruction like the `InsIter` of its
+/// mode. The mode can be switched between instructions with [`Self::set_mode`], and optionally by following mode
+/// switches which can be seen in the code itself, see [`Self::follow_mode_switches`]. Stops when there are too few bytes
+/// left for the next instruction, or in [`ParseMode::Data`], see [`Parser`](crate::Parser) to also emit data lines.
+#[derive(Clone)]
+pub struct UnifiedIter<'a> {
+    pub address: u32,
+    pub endian: Endian,
+    pub flags: ParseFlags,
+    mode: ParseMode,
+    data: &'a [u8],
+    follow_mode_switches: bool,
+    /// Mode switches at addresses ahead of the iterator, sorted by address
+    switches: Vec<(u32, ParseMode)>,
+    /// Register which the previous instruction set to a known address, and that address
+    known: Option<(Register, u32)>,
+}
+
+impl<'a> UnifiedIter<'a> {
+    pub fn new(data: &'a [u8], address: u32, endian: Endian, flags: ParseFlags, mode: ParseMode) -> Self {
+        Self {
+            address,
+            endian,
+            flags,
+            mode,
+            data,
+            follow_mode_switches: false,
+            switches: vec![],
+            known: None,
+        }
+    }
+
+    /// Switches the mode at addresses where the code itself shows a mode switch:
+    /// - The destination of `blx` with an immediate target switches to the other mode.
+    /// - `bx rN` right after `add rN, pc, #imm` or `adr rN, #imm` switches to Thumb at the address in `rN` if its low bit
+    ///   is set, otherwise to ARM.
+    /// - Thumb `bx pc` switches to ARM at the next word-aligned address, like the usual Thumb-to-ARM veneer.
+    ///
+    /// Only destinations ahead of the iterator are followed. This is a heuristic which can be wrong, e.g. if the code
+    /// between a branch and its destination ends in the middle of an instruction.
+    pub fn follow_mode_switches(mut self) -> Self {
+        self.follow_mode_switches = true;
+        self
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Switches the mode of the instructions after the current address. Mode switches found by
+    /// [`Self::follow_mode_switches`] further ahead still apply.
+    pub fn set_mode(&mut self, mode: ParseMode) {
+        self.mode = mode;
+    }
+
+    /// Applies the mode switches at the current address, and drops those which were skipped over
+    fn apply_switches(&mut self) {
+        let passed = self.switches.partition_point(|&(address, _)| address <= self.address);
+        if let Some(&(address, mode)) = passed.checked_sub(1).map(|index| &self.switches[index]) {
+            if address == self.address {
+                self.mode = mode;
+            }
+        }
+        self.switches.drain(..passed);
+    }
+
+    /// Switches to `mode` at `address`, unless the iterator is already past it
+    fn switch_at(&mut self, address: u32, mode: ParseMode) {
+        if address < self.address {
+            return;
+        }
+        let index = self.switches.partition_point(|&(start, _)| start < address);
+        match self.switches.get_mut(index) {
+            Some(switch) if switch.0 == address => switch.1 = mode,
+            _ => self.switches.insert(index, (address, mode)),
+        }
+    }
+
+    /// Looks for a mode switch in the instruction at `address`
+    fn track(&mut self, address: u32, mode: ParseMode, parsed: &ParsedIns) {
+        let known = self.known.take();
+        let other = match mode {
+            ParseMode::Arm => ParseMode::Thumb,
+            _ => ParseMode::Arm,
+        };
+        match (parsed.mnemonic_base(), parsed.args[0]) {
+            ("blx", Argument::BranchDest(_)) => {
+                if let Some(dest) = parsed.branch_destination(address, mode) {
+                    self.switch_at(dest, other);
+                }
+            }
+            ("bx", Argument::Reg(Reg { reg: Register::Pc, .. })) if mode == ParseMode::Thumb => {
+                self.switch_at(address.wrapping_add(4) & !3, ParseMode::Arm);
+            }
+            ("bx", Argument::Reg(Reg { reg, .. })) => {
+                if let Some((_, dest)) = known.filter(|(known, _)| *known == reg) {
+                    let mode = if dest & 1 != 0 { ParseMode::Thumb } else { ParseMode::Arm };
+                    self.switch_at(dest & !1, mode);
+                }
+            }
+            ("add" | "adr", Argument::Reg(Reg { reg, .. })) => {
+                self.known = parsed.pc_relative_address(address, mode).map(|dest| (reg, dest));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Iterator for UnifiedIter<'_> {
+    type Item = (u32, Ins, ParsedIns);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.apply_switches();
+        let address = self.address;
+        let (ins, parsed) = match self.mode {
+            ParseMode::Arm => {
+                let ins = arm::Ins::from_bytes(self.data, self.endian, &self.flags)?;
+                (Ins::Arm(ins), ins.parse(&self.flags))
+            }
+            ParseMode::Thumb => {
+                let ins = thumb::Ins::from_bytes(self.data, self.endian, &self.flags)?;
+                let parsed = if ins.is_half_bl() {
+                    let second = thumb::Ins::from_bytes(&self.data[2..], self.endian, &self.flags)?;
+                    ins.parse(&self.flags).combine_thumb_bl(&second.parse(&self.flags))
+                } else {
+                    ins.parse(&self.flags)
+                };
+                (Ins::Thumb(ins), parsed)
+            }
+            ParseMode::Data => return None,
+        };
+        let size = ins.size();
+        self.data = &self.data[size..];
+        self.address = address.wrapping_add(size as u32);
+        if self.follow_mode_switches {
+            self.track(address, ins.mode(), &parsed);
+        }
+        Some((address, ins, parsed))
+    }
+}
@@ -0,0 +1,659 @@
+//! Typed views of the arguments of common instruction families, converted from a [`ParsedIns`] with `TryFrom`. ARM and
+//! Thumb instructions, and both divided and unified syntax, convert into the same views.
+
+use std::fmt::Display;
+
+use crate::{
+    args::{Argument, CoReg, OffsetImm, OffsetReg, Reg, Register, Shift, ShiftImm, ShiftReg},
+    Condition, ParsedIns,
+};
+
+/// Error of converting a [`ParsedIns`] into a view
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ViewError {
+    /// The instruction belongs to another family, e.g. `bl` converted into a [`DataProcessingView`]
+    OtherFamily(&'static str),
+    /// The argument at this index doesn't fit the arguments of the family
+    UnexpectedArgument(usize, Argument),
+    /// The instruction has fewer arguments than the family needs
+    MissingArgument(usize),
+}
+
+impl Display for ViewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OtherFamily(mnemonic) => write!(f, "`{mnemonic}` belongs to another instruction family"),
+            Self::UnexpectedArgument(index, arg) => write!(f, "unexpected {:?} argument at index {index}", arg.kind()),
+            Self::MissingArgument(index) => write!(f, "missing argument at index {index}"),
+        }
+    }
+}
+
+impl std::error::Error for ViewError {}
+
+/// Reads the arguments of an instruction in order
+struct Args<'a> {
+    args: &'a [Argument],
+    index: usize,
+}
+
+impl<'a> Args<'a> {
+    fn new(ins: &'a ParsedIns) -> Self {
+        Self {
+            args: &ins.args,
+            index: 0,
+        }
+    }
+
+    fn peek(&self) -> Argument {
+        self.args.get(self.index).copied().unwrap_or_default()
+    }
+
+    fn next(&mut self) -> Result<Argument, ViewError> {
+        match self.peek() {
+            Argument::None => Err(ViewError::MissingArgument(self.index)),
+            arg => {
+                self.index += 1;
+                Ok(arg)
+            }
+        }
+    }
+
+    /// Returns the number of remaining arguments
+    fn remaining(&self) -> usize {
+        self.args[self.index..]
+            .iter()
+            .take_while(|arg| **arg != Argument::None)
+            .count()
+    }
+
+    fn unexpected(&self) -> ViewError {
+        ViewError::UnexpectedArgument(self.index.saturating_sub(1), self.args[self.index.saturating_sub(1)])
+    }
+
+    /// Reads a register which is not dereferenced
+    fn reg(&mut self) -> Result<Register, ViewError> {
+        match self.next()? {
+            Argument::Reg(Reg {
+                reg,
+                deref: false,
+                writeback: false,
+            }) => Ok(reg),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Reads an optional shift of the previous register
+    fn shift_imm(&mut self) -> Result<Option<ShiftImm>, ViewError> {
+        match self.peek() {
+            Argument::ShiftImm(shift) => {
+                self.index += 1;
+                Ok(Some(shift))
+            }
+            Argument::Shift(Shift::Rrx) => {
+                self.index += 1;
+                Ok(Some(ShiftImm { imm: 0, op: Shift::Rrx }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Reads an optional offset from a base register, and whether it is post-indexed
+    fn addr_offset(&mut self) -> Result<Option<(AddrOffset, bool)>, ViewError> {
+        let offset = match self.peek() {
+            Argument::OffsetImm(OffsetImm { post_indexed, value }) => (AddrOffset::Imm(value), post_indexed),
+            // Thumb loads relative to SP and PC
+            Argument::UImm(value) => (AddrOffset::Imm(value as i32), false),
+            Argument::OffsetReg(OffsetReg { add, post_indexed, reg }) => {
+                self.next()?;
+                let shift = self.shift_imm()?;
+                return Ok(Some((AddrOffset::Reg { add, reg, shift }, post_indexed)));
+            }
+            _ => return Ok(None),
+        };
+        self.next()?;
+        Ok(Some(offset))
+    }
+
+    /// Fails if there are any arguments left
+    fn end(&self) -> Result<(), ViewError> {
+        match self.peek() {
+            Argument::None => Ok(()),
+            arg => Err(ViewError::UnexpectedArgument(self.index, arg)),
+        }
+    }
+}
+
+/// Operation of a data-processing instruction. The shift mnemonics such as `lsl` and `rrx` are [`DataOp::Mov`] with a
+/// shifted operand, and `neg` is [`DataOp::Rsb`] from zero.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DataOp {
+    And,
+    Eor,
+    Sub,
+    Rsb,
+    Add,
+    Adc,
+    Sbc,
+    Rsc,
+    Tst,
+    Teq,
+    Cmp,
+    Cmn,
+    Orr,
+    Mov,
+    Bic,
+    Mvn,
+}
+
+impl DataOp {
+    /// Returns true if the operation only sets the flags, like `cmp`
+    pub fn is_comparison(self) -> bool {
+        matches!(self, Self::Tst | Self::Teq | Self::Cmp | Self::Cmn)
+    }
+
+    /// Returns true if the operation has no first operand `Rn`, like `mov`
+    pub fn is_move(self) -> bool {
+        matches!(self, Self::Mov | Self::Mvn)
+    }
+}
+
+/// Second operand of a data-processing instruction
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operand2 {
+    Imm(u32),
+    Reg(Register),
+    /// Register shifted by an immediate. `rrx` has an amount of 0.
+    ShiftImm(Register, ShiftImm),
+    /// Register shifted by the amount in another register
+    ShiftReg(Register, ShiftReg),
+}
+
+/// Data-processing instruction, e.g. `adds r0, r1, r2, lsl #0x2`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DataProcessingView {
+    pub op: DataOp,
+    pub cond: Condition,
+    /// True if the instruction sets the flags, which is always the case for comparisons. Thumb instructions must be
+    /// parsed in unified syntax, which shows the flag-setting ones with an `s` suffix.
+    pub s: bool,
+    /// Destination register, `None` for comparisons
+    pub rd: Option<Register>,
+    /// First operand, `None` for moves
+    pub rn: Option<Register>,
+    pub operand2: Operand2,
+}
+
+const DATA_OPS: &[(&str, DataOp)] = &[
+    ("and", DataOp::And),
+    ("eor", DataOp::Eor),
+    ("sub", DataOp::Sub),
+    ("rsb", DataOp::Rsb),
+    ("add", DataOp::Add),
+    ("adc", DataOp::Adc),
+    ("sbc", DataOp::Sbc),
+    ("rsc", DataOp::Rsc),
+    ("tst", DataOp::Tst),
+    ("teq", DataOp::Teq),
+    ("cmp", DataOp::Cmp),
+    ("cmn", DataOp::Cmn),
+    ("orr", DataOp::Orr),
+    ("mov", DataOp::Mov),
+    ("cpy", DataOp::Mov),
+    ("bic", DataOp::Bic),
+    ("mvn", DataOp::Mvn),
+];
+
+const SHIFT_OPS: &[(&str, Shift)] = &[
+    ("lsl", Shift::Lsl),
+    ("lsr", Shift::Lsr),
+    ("asr", Shift::Asr),
+    ("ror", Shift::Ror),
+    ("rrx", Shift::Rrx),
+];
+
+impl TryFrom<&ParsedIns> for DataProcessingView {
+    type Error = ViewError;
+
+    fn try_from(ins: &ParsedIns) -> Result<Self, Self::Error> {
+        let mnemonic = ins.mnemonic_base();
+        let (name, s) = match mnemonic.strip_suffix('s') {
+            Some(name) => (name, true),
+            None => (mnemonic, false),
+        };
+        let cond = ins.condition();
+        let mut args = Args::new(ins);
+
+        if let Some((_, shift)) = SHIFT_OPS.iter().find(|(shift, _)| *shift == name) {
+            let rd = args.reg()?;
+            // Two operand forms shift the destination, e.g. `lsl r0, r1` is `mov r0, r0, lsl r1`
+            let (rm, amount) = match (args.remaining(), shift) {
+                (1, Shift::Rrx) => (args.reg()?, Argument::None),
+                (1, _) => (rd, args.next()?),
+                _ => (args.reg()?, args.next()?),
+            };
+            let operand2 = match amount {
+                Argument::UImm(imm) => Operand2::ShiftImm(rm, ShiftImm { imm, op: *shift }),
+                Argument::None => Operand2::ShiftImm(rm, ShiftImm { imm: 0, op: *shift }),
+                Argument::Reg(Reg {
+                    reg,
+                    deref: false,
+                    writeback: false,
+                }) => Operand2::ShiftReg(rm, ShiftReg { op: *shift, reg }),
+                _ => return Err(args.unexpected()),
+            };
+            args.end()?;
+            return Ok(Self {
+                op: DataOp::Mov,
+                cond,
+                s,
+                rd: Some(rd),
+                rn: None,
+                operand2,
+            });
+        }
+        if mnemonic == "adr" {
+            let rd = args.reg()?;
+            let Argument::UImm(imm) = args.next()? else {
+                return Err(args.unexpected());
+            };
+            args.end()?;
+            return Ok(Self {
+                op: DataOp::Add,
+                cond,
+                s: false,
+                rd: Some(rd),
+                rn: Some(Register::Pc),
+                operand2: Operand2::Imm(imm),
+            });
+        }
+        if name == "neg" {
+            let rd = args.reg()?;
+            let rn = args.reg()?;
+            args.end()?;
+            return Ok(Self {
+                op: DataOp::Rsb,
+                cond,
+                s,
+                rd: Some(rd),
+                rn: Some(rn),
+                operand2: Operand2::Imm(0),
+            });
+        }
+
+        let Some((_, op)) = DATA_OPS.iter().find(|(op, _)| *op == name) else {
+            return Err(ViewError::OtherFamily(mnemonic));
+        };
+        let op = *op;
+        let (rd, rn) = if op.is_comparison() {
+            (None, Some(args.reg()?))
+        } else if op.is_move() {
+            (Some(args.reg()?), None)
+        } else {
+            let rd = args.reg()?;
+            // Two operand forms use the destination as the first operand, e.g. the Thumb `adc r0, r1`
+            match args.remaining() {
+                1 => (Some(rd), Some(rd)),
+                _ => (Some(rd), Some(args.reg()?)),
+            }
+        };
+        let operand2 = match args.next()? {
+            Argument::UImm(imm) => Operand2::Imm(imm),
+            Argument::Reg(Reg {
+                reg,
+                deref: false,
+                writeback: false,
+            }) => match (args.shift_imm()?, args.peek()) {
+                (Some(shift), _) => Operand2::ShiftImm(reg, shift),
+                (None, Argument::ShiftReg(shift)) => {
+                    args.next()?;
+                    Operand2::ShiftReg(reg, shift)
+                }
+                (None, _) => Operand2::Reg(reg),
+            },
+            _ => return Err(args.unexpected()),
+        };
+        args.end()?;
+        Ok(Self {
+            op,
+            cond,
+            s: s || op.is_comparison(),
+            rd,
+            rn,
+            operand2,
+        })
+    }
+}
+
+/// Size of the data transferred by a load or store
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Width {
+    Byte,
+    Halfword,
+    Word,
+    /// Two words transferred to or from two consecutive registers, see [`LoadStoreView::rt2`]
+    Doubleword,
+}
+
+impl Width {
+    /// Returns the number of bytes transferred
+    pub fn size(self) -> u32 {
+        match self {
+            Self::Byte => 1,
+            Self::Halfword => 2,
+            Self::Word => 4,
+            Self::Doubleword => 8,
+        }
+    }
+}
+
+/// Offset from the base register of a load or store
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AddrOffset {
+    Imm(i32),
+    /// Register offset which is added or subtracted, optionally shifted
+    Reg {
+        add: bool,
+        reg: Register,
+        shift: Option<ShiftImm>,
+    },
+}
+
+/// Memory operand of any instruction which dereferences a base register, e.g. `[r1, -r3, rrx]!` or `[r1], #0xfff`. See
+/// [`ParsedIns::mem_operand`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MemOperand {
+    pub base: Register,
+    /// Offset from the base register, `AddrOffset::Imm(0)` if there is none like in `[r0]` or `[r0], {0x4}`
+    pub offset: AddrOffset,
+    /// True if the offset is applied to the base register before the access, false if it is post-indexed
+    pub pre_indexed: bool,
+    /// True if the base register is updated, which includes all post-indexed accesses
+    pub writeback: bool,
+}
+
+impl ParsedIns {
+    /// Returns the memory operand of this instruction, read from the dereferenced base register and the offset after it,
+    /// which are the arguments that [`ParsedIns::visit`] puts in brackets along with any post-indexed offset. Returns
+    /// `None` if no register is dereferenced, like the base register of `ldm`.
+    pub fn mem_operand(&self) -> Option<MemOperand> {
+        let index = self
+            .args_iter()
+            .position(|arg| matches!(arg, Argument::Reg(Reg { deref: true, .. })))?;
+        let Argument::Reg(Reg {
+            reg: base, writeback, ..
+        }) = self.args[index]
+        else {
+            return None;
+        };
+        let mut args = Args {
+            args: &self.args,
+            index: index + 1,
+        };
+        let (offset, post_indexed) = args.addr_offset().ok().flatten().unwrap_or((AddrOffset::Imm(0), false));
+        Some(MemOperand {
+            base,
+            offset,
+            pre_indexed: !post_indexed,
+            writeback: writeback || post_indexed,
+        })
+    }
+}
+
+/// Load or store of a single register, or the register pair of `ldrd` and `strd`, e.g. `ldrb r0, [r1, #0x4]!`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LoadStoreView {
+    pub load: bool,
+    pub cond: Condition,
+    pub width: Width,
+    /// True for loads which sign-extend the data, like `ldrsh`
+    pub sign: bool,
+    /// True for `ldrt` and the other accesses which are performed as if in user mode
+    pub translated: bool,
+    /// Transferred register
+    pub rt: Register,
+    /// Second transferred register of `ldrd` and `strd`. Divided syntax doesn't show it, as it always follows `rt`.
+    pub rt2: Option<Register>,
+    pub base: Register,
+    pub offset: AddrOffset,
+    /// True if the offset is applied to the base register after the access
+    pub post_indexed: bool,
+    /// True if the base register is updated, which includes all post-indexed accesses
+    pub writeback: bool,
+}
+
+impl TryFrom<&ParsedIns> for LoadStoreView {
+    type Error = ViewError;
+
+    fn try_from(ins: &ParsedIns) -> Result<Self, Self::Error> {
+        let mnemonic = ins.mnemonic_base();
+        let (load, rest) = match (mnemonic.strip_prefix("ldr"), mnemonic.strip_prefix("str")) {
+            (Some(rest), _) => (true, rest),
+            (_, Some(rest)) => (false, rest),
+            _ => return Err(ViewError::OtherFamily(mnemonic)),
+        };
+        let (width, sign, translated) = match (load, rest) {
+            (_, "") => (Width::Word, false, false),
+            (_, "t") => (Width::Word, false, true),
+            (_, "b") => (Width::Byte, false, false),
+            (_, "bt") => (Width::Byte, false, true),
+            (_, "h") => (Width::Halfword, false, false),
+            (_, "d") => (Width::Doubleword, false, false),
+            (true, "sb") => (Width::Byte, true, false),
+            (true, "sh") => (Width::Halfword, true, false),
+            _ => return Err(ViewError::OtherFamily(mnemonic)),
+        };
+
+        let mut args = Args::new(ins);
+        let rt = args.reg()?;
+        let rt2 = match (width, args.peek()) {
+            (Width::Doubleword, Argument::Reg(Reg { deref: false, .. })) => Some(args.reg()?),
+            (Width::Doubleword, _) => Register::try_parse(rt as u32 + 1),
+            _ => None,
+        };
+        let Argument::Reg(Reg {
+            deref: true,
+            reg: base,
+            writeback,
+        }) = args.next()?
+        else {
+            return Err(args.unexpected());
+        };
+        let (offset, post_indexed) = match args.peek() {
+            Argument::None => (AddrOffset::Imm(0), false),
+            arg => args.addr_offset()?.ok_or(ViewError::UnexpectedArgument(args.index, arg))?,
+        };
+        args.end()?;
+        Ok(Self {
+            load,
+            cond: ins.condition(),
+            width,
+            sign,
+            translated,
+            rt,
+            rt2,
+            base,
+            offset,
+            post_indexed,
+            writeback: writeback || post_indexed,
+        })
+    }
+}
+
+/// Addressing mode of a coprocessor load or store, selected by the P, U and W bits
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CoAddrMode {
+    /// `[Rn, #offset]`, the base register is not updated
+    Offset,
+    /// `[Rn, #offset]!`, the offset is applied to the base register before the access
+    PreIndexed,
+    /// `[Rn], #offset`, the offset is applied to the base register after the access
+    PostIndexed,
+    /// `[Rn], {option}`, the base register is not updated and the 8-bit option is passed to the coprocessor
+    Unindexed(u32),
+}
+
+/// Coprocessor load or store, e.g. `ldcl p1, c2, [r3, #-0x1a4]!`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CoLoadStoreView {
+    pub load: bool,
+    pub cond: Condition,
+    /// True for `ldcl` and `stcl`, the long transfers selected by the N bit
+    pub long: bool,
+    /// True for the unconditional `ldc2` and `stc2`
+    pub extended: bool,
+    pub coproc: u32,
+    pub crd: CoReg,
+    pub base: Register,
+    pub mode: CoAddrMode,
+    /// Offset in bytes, which is the 8-bit immediate scaled by 4. Always 0 for [`CoAddrMode::Unindexed`].
+    pub offset: i32,
+    /// True if the base register is updated, which is the case for [`CoAddrMode::PreIndexed`] and
+    /// [`CoAddrMode::PostIndexed`]
+    pub writeback: bool,
+}
+
+impl TryFrom<&ParsedIns> for CoLoadStoreView {
+    type Error = ViewError;
+
+    fn try_from(ins: &ParsedIns) -> Result<Self, Self::Error> {
+        let mnemonic = ins.mnemonic_base();
+        let (load, rest) = match (mnemonic.strip_prefix("ldc"), mnemonic.strip_prefix("stc")) {
+            (Some(rest), _) => (true, rest),
+            (_, Some(rest)) => (false, rest),
+            _ => return Err(ViewError::OtherFamily(mnemonic)),
+        };
+        let (extended, long) = match rest {
+            "" => (false, false),
+            "l" => (false, true),
+            "2" => (true, false),
+            "2l" => (true, true),
+            _ => return Err(ViewError::OtherFamily(mnemonic)),
+        };
+
+        let mut args = Args::new(ins);
+        let Argument::CoprocNum(coproc) = args.next()? else {
+            return Err(args.unexpected());
+        };
+        let Argument::CoReg(crd) = args.next()? else {
+            return Err(args.unexpected());
+        };
+        let Argument::Reg(Reg {
+            deref: true,
+            reg: base,
+            writeback,
+        }) = args.next()?
+        else {
+            return Err(args.unexpected());
+        };
+        let (mode, offset) = match (args.next()?, writeback) {
+            (
+                Argument::OffsetImm(OffsetImm {
+                    post_indexed: false,
+                    value,
+                }),
+                false,
+            ) => (CoAddrMode::Offset, value),
+            (
+                Argument::OffsetImm(OffsetImm {
+                    post_indexed: false,
+                    value,
+                }),
+                true,
+            ) => (CoAddrMode::PreIndexed, value),
+            (
+                Argument::OffsetImm(OffsetImm {
+                    post_indexed: true,
+                    value,
+                }),
+                false,
+            ) => (CoAddrMode::PostIndexed, value),
+            (Argument::CoOption(option), false) => (CoAddrMode::Unindexed(option), 0),
+            _ => return Err(args.unexpected()),
+        };
+        args.end()?;
+        Ok(Self {
+            load,
+            cond: ins.condition(),
+            long,
+            extended,
+            coproc,
+            crd,
+            base,
+            mode,
+            offset,
+            writeback: matches!(mode, CoAddrMode::PreIndexed | CoAddrMode::PostIndexed),
+        })
+    }
+}
+
+/// Kind of branch instruction
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BranchKind {
+    B,
+    Bl,
+    Blx,
+    Bx,
+    Bxj,
+}
+
+impl BranchKind {
+    /// Returns true if the branch writes the return address to LR
+    pub fn is_call(self) -> bool {
+        matches!(self, Self::Bl | Self::Blx)
+    }
+}
+
+/// Destination of a branch
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BranchTarget {
+    /// Destination relative to the address of the instruction, see [`ParsedIns::branch_destination`]
+    Offset(i32),
+    Reg(Register),
+    /// High part of the offset in the first half of a Thumb `bl` pair, see [`ParsedIns::combine_thumb_bl`]
+    HighHalf(i32),
+    /// Low part of the offset in the second half of a Thumb `bl` or `blx` pair
+    LowHalf(u32),
+}
+
+/// Branch instruction, e.g. `blne #0x100` or `bx lr`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BranchView {
+    pub kind: BranchKind,
+    pub cond: Condition,
+    pub target: BranchTarget,
+}
+
+impl TryFrom<&ParsedIns> for BranchView {
+    type Error = ViewError;
+
+    fn try_from(ins: &ParsedIns) -> Result<Self, Self::Error> {
+        let mnemonic = ins.mnemonic_base();
+        let kind = match mnemonic {
+            "b" => BranchKind::B,
+            "bl" => BranchKind::Bl,
+            "blx" => BranchKind::Blx,
+            "bx" => BranchKind::Bx,
+            "bxj" => BranchKind::Bxj,
+            _ => return Err(ViewError::OtherFamily(mnemonic)),
+        };
+        let mut args = Args::new(ins);
+        let target = match args.next()? {
+            Argument::BranchDest(offset) => BranchTarget::Offset(offset),
+            Argument::Reg(Reg {
+                reg,
+                deref: false,
+                writeback: false,
+            }) => BranchTarget::Reg(reg),
+            Argument::SImm(high) => BranchTarget::HighHalf(high),
+            Argument::UImm(low) => BranchTarget::LowHalf(low),
+            _ => return Err(args.unexpected()),
+        };
+        args.end()?;
+        Ok(Self {
+            kind,
+            cond: ins.condition(),
+            target,
+        })
+    }
+}
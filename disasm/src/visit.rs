@@ -0,0 +1,185 @@
+use crate::{
+    args::{Argument, OffsetImm, OffsetReg, Reg, RegList, Register, Shift, ShiftImm, ShiftReg},
+    ParsedIns,
+};
+
+/// Where a general-purpose register appears in an instruction, see [`ArgumentVisitor::visit_register`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RegisterRole {
+    /// A plain register argument. `writeback` is set for the base register of load/store multiple.
+    Operand { writeback: bool },
+    /// Base register of a memory operand, e.g. `r1` in `ldr r0, [r1, #4]!`
+    Base { writeback: bool },
+    /// Register offset of a memory operand, which is added to the base register if `add` is true and subtracted
+    /// otherwise
+    Offset { add: bool },
+    /// Register holding a shift amount, e.g. `r2` in `lsl r2`
+    ShiftAmount,
+    /// Member of a register list
+    List,
+}
+
+/// What an immediate value means, see [`ArgumentVisitor::visit_immediate`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImmKind {
+    /// [`Argument::UImm`]
+    Unsigned,
+    /// [`Argument::SImm`]
+    Signed,
+    /// [`Argument::SatImm`]
+    Saturation,
+    /// Offset of a memory operand, see [`Argument::OffsetImm`], or of `adr` from PC, see
+    /// [`DisplayOptions::adr`](crate::DisplayOptions::adr)
+    Offset,
+    /// Amount of a [`ShiftImm`]
+    ShiftAmount,
+    /// Relative branch destination, see [`Argument::BranchDest`]
+    BranchOffset,
+    /// Absolute branch destination, only passed to [`FormatVisitor::immediate`](crate::FormatVisitor::immediate) when
+    /// displaying with [`ParsedIns::display_with_pc`]
+    BranchAddress,
+    /// Absolute address of `adr`, only passed to [`FormatVisitor::immediate`](crate::FormatVisitor::immediate) when
+    /// displaying with [`ParsedIns::display_with_pc`] and [`DisplayOptions::adr`](crate::DisplayOptions::adr)
+    PcRelativeAddress,
+    /// [`Argument::CoOption`]
+    CoOption,
+    /// [`Argument::CoOpcode`]
+    CoOpcode,
+    /// [`Argument::CoprocNum`]
+    CoprocNum,
+    /// Mode bits of [`Argument::CpsrMode`]
+    CpsrMode,
+    /// Code of an illegal instruction displayed as data, which is written without a `#` so that the assembler accepts it.
+    /// Only passed to [`FormatVisitor::immediate`](crate::FormatVisitor::immediate) with
+    /// [`IllegalStyle::RawWord`](crate::IllegalStyle::RawWord).
+    RawWord,
+}
+
+/// Walks the arguments of a [`ParsedIns`], see [`ParsedIns::visit`]. Every method does nothing by default, so visitors
+/// only implement the parts they care about and keep compiling when new argument variants are added.
+///
+/// The `index` passed to each method is the index of the argument in [`ParsedIns::args`]. Compound arguments are split
+/// into several visits with the same index, e.g. [`ShiftImm`] is visited as a shift followed by an immediate.
+pub trait ArgumentVisitor {
+    /// Called for every argument. The default implementation splits the argument with [`walk_argument`], override this
+    /// to handle whole arguments.
+    fn visit_argument(&mut self, index: usize, arg: &Argument) {
+        walk_argument(self, index, arg);
+    }
+
+    fn visit_register(&mut self, index: usize, reg: Register, role: RegisterRole) {
+        let _ = (index, reg, role);
+    }
+
+    /// Called for immediates of every [`ImmKind`]. Unsigned values are zero-extended, signed values are sign-extended.
+    fn visit_immediate(&mut self, index: usize, value: i64, kind: ImmKind) {
+        let _ = (index, value, kind);
+    }
+
+    fn visit_shift(&mut self, index: usize, shift: Shift) {
+        let _ = (index, shift);
+    }
+
+    /// Called once for a register list. The default implementation visits each register with [`RegisterRole::List`] in
+    /// ascending order, override this to handle the whole list instead.
+    fn visit_reg_list(&mut self, index: usize, list: RegList) {
+        for reg in (0..16).filter(|i| list.regs & (1 << i) != 0) {
+            self.visit_register(index, Register::parse(reg), RegisterRole::List);
+        }
+    }
+
+    /// Called before the base register of a memory operand, i.e. at its opening bracket
+    fn visit_memory_begin(&mut self, index: usize) {
+        let _ = index;
+    }
+
+    /// Called at the closing bracket of a memory operand, which comes before post-indexed offsets. `index` is the index of
+    /// the base register.
+    fn visit_memory_end(&mut self, index: usize, writeback: bool) {
+        let _ = (index, writeback);
+    }
+
+    /// Called for arguments which are neither registers, immediates nor shifts, e.g. [`Argument::StatusReg`]
+    fn visit_other(&mut self, index: usize, arg: &Argument) {
+        let _ = (index, arg);
+    }
+}
+
+/// Splits an argument into the visits described by [`ArgumentVisitor`]
+pub fn walk_argument<V: ArgumentVisitor + ?Sized>(visitor: &mut V, index: usize, arg: &Argument) {
+    match *arg {
+        Argument::None => {}
+        Argument::Reg(Reg { deref, reg, writeback }) => {
+            let role = if deref {
+                RegisterRole::Base { writeback }
+            } else {
+                RegisterRole::Operand { writeback }
+            };
+            visitor.visit_register(index, reg, role);
+        }
+        Argument::RegList(list) => visitor.visit_reg_list(index, list),
+        Argument::Shift(shift) => visitor.visit_shift(index, shift),
+        Argument::ShiftImm(ShiftImm { imm, op }) => {
+            visitor.visit_shift(index, op);
+            visitor.visit_immediate(index, imm.into(), ImmKind::ShiftAmount);
+        }
+        Argument::ShiftReg(ShiftReg { op, reg }) => {
+            visitor.visit_shift(index, op);
+            visitor.visit_register(index, reg, RegisterRole::ShiftAmount);
+        }
+        Argument::UImm(x) => visitor.visit_immediate(index, x.into(), ImmKind::Unsigned),
+        Argument::SatImm(x) => visitor.visit_immediate(index, x.into(), ImmKind::Saturation),
+        Argument::SImm(x) => visitor.visit_immediate(index, x.into(), ImmKind::Signed),
+        Argument::OffsetImm(OffsetImm { value, .. }) => visitor.visit_immediate(index, value.into(), ImmKind::Offset),
+        Argument::OffsetReg(OffsetReg { add, reg, .. }) => visitor.visit_register(index, reg, RegisterRole::Offset { add }),
+        Argument::BranchDest(x) => visitor.visit_immediate(index, x.into(), ImmKind::BranchOffset),
+        Argument::CoOption(x) => visitor.visit_immediate(index, x.into(), ImmKind::CoOption),
+        Argument::CoOpcode(x) => visitor.visit_immediate(index, x.into(), ImmKind::CoOpcode),
+        Argument::CoprocNum(x) => visitor.visit_immediate(index, x.into(), ImmKind::CoprocNum),
+        Argument::CpsrMode(x) => visitor.visit_immediate(index, x.mode.into(), ImmKind::CpsrMode),
+        Argument::CoReg(_)
+        | Argument::StatusReg(_)
+        | Argument::StatusMask(_)
+        | Argument::CpsrFlags(_)
+        | Argument::Endian(_)
+        | Argument::SReg(_)
+        | Argument::DReg(_)
+        | Argument::DRegIndex(_)
+        | Argument::SRegList(_)
+        | Argument::DRegList(_)
+        | Argument::VfpSysReg(_) => visitor.visit_other(index, arg),
+    }
+}
+
+impl ParsedIns {
+    /// Walks the arguments in display order with `visitor`. A memory operand begins at its base register and ends after
+    /// the last argument, or before the first post-indexed offset or coprocessor option.
+    pub fn visit<V: ArgumentVisitor + ?Sized>(&self, visitor: &mut V) {
+        let mut memory: Option<(usize, bool)> = None;
+        for (index, arg) in self.args_iter().enumerate() {
+            if let Some((base, writeback)) = memory {
+                let closes = matches!(
+                    arg,
+                    Argument::OffsetImm(OffsetImm { post_indexed: true, .. })
+                        | Argument::OffsetReg(OffsetReg { post_indexed: true, .. })
+                        | Argument::CoOption(_)
+                );
+                if closes {
+                    visitor.visit_memory_end(base, writeback);
+                    memory = None;
+                }
+            }
+            if let Argument::Reg(Reg {
+                deref: true, writeback, ..
+            }) = *arg
+            {
+                visitor.visit_memory_begin(index);
+                memory = Some((index, writeback));
+            }
+            visitor.visit_argument(index, arg);
+        }
+        if let Some((base, writeback)) = memory {
+            visitor.visit_memory_end(base, writeback);
+        }
+    }
+}
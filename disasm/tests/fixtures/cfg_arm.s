@@ -0,0 +1,44 @@
+@ Fixture for the control flow analysis tests, assembled with:
+@   llvm-mc -triple=armv5te -filetype=obj cfg_arm.s -o cfg_arm.o
+@   llvm-objcopy -O binary cfg_arm.o cfg_arm.bin
+
+    .arm
+    .text
+
+@ int sum_abs(int *p, int n) {
+@     if (p == NULL) return 0;
+@     int sum = 0;
+@     for (int i = 0; i < n; i++) {
+@         if (p[i] < 0) sum -= p[i];
+@         else sum += p[i];
+@     }
+@     return sum;
+@ }
+sum_abs:
+    push {r4, lr}
+    cmp r0, #0
+    popeq {r4, pc}
+    mov r2, #0
+    mov r3, #0
+.Lloop:
+    cmp r3, r1
+    bge .Ldone
+    ldr r4, [r0, r3, lsl #2]
+    cmp r4, #0
+    blt .Lneg
+    add r2, r2, r4
+    b .Lnext
+.Lneg:
+    sub r2, r2, r4
+.Lnext:
+    add r3, r3, #1
+    b .Lloop
+.Ldone:
+    mov r0, r2
+    pop {r4, pc}
+
+@ Calls sum_abs and jumps to the address it returns
+dispatch:
+    push {lr}
+    .inst 0xebffffec  @ bl sum_abs, encoded by hand as llvm-mc leaves ARM calls to the linker
+    mov pc, r0
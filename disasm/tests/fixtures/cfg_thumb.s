@@ -0,0 +1,40 @@
+@ Fixture for the control flow analysis tests, assembled with:
+@   llvm-mc -triple=thumbv5te -filetype=obj cfg_thumb.s -o cfg_thumb.o
+@   llvm-objcopy -O binary cfg_thumb.o cfg_thumb.bin
+
+    .thumb
+    .text
+
+@ Same as sum_abs in cfg_arm.s
+sum_abs:
+    push {r4, lr}
+    cmp r0, #0
+    bne .Lbody
+    movs r0, #0
+    pop {r4, pc}
+.Lbody:
+    movs r2, #0
+    movs r3, #0
+.Lloop:
+    cmp r3, r1
+    bge .Ldone
+    lsls r4, r3, #2
+    ldr r4, [r0, r4]
+    cmp r4, #0
+    blt .Lneg
+    adds r2, r2, r4
+    b .Lnext
+.Lneg:
+    subs r2, r2, r4
+.Lnext:
+    adds r3, #1
+    b .Lloop
+.Ldone:
+    movs r0, r2
+    pop {r4, pc}
+
+@ Calls sum_abs and jumps to the address it returns
+dispatch:
+    push {lr}
+    bl sum_abs
+    bx r0
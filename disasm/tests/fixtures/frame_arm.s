@@ -0,0 +1,47 @@
+@ Fixture for the stack frame analysis tests, assembled with:
+@   llvm-mc -triple=armv5te -filetype=obj frame_arm.s -o frame_arm.o
+@   llvm-objcopy -O binary frame_arm.o frame_arm.bin
+
+    .arm
+    .text
+
+@ Saves registers and allocates locals, with an early return through the common epilogue
+normal:
+    push {r4, r5, r6, lr}
+    sub sp, sp, #0x10
+    mov r4, r0
+    cmp r1, #0
+    beq .Lnormal_done
+    str r4, [sp, #0x4]
+    add r0, sp, #0x4
+.Lnormal_done:
+    add sp, sp, #0x10
+    pop {r4, r5, r6, pc}
+
+@ Leaf function without a frame
+leaf:
+    add r0, r0, r1
+    bx lr
+
+@ Restores SP from a frame pointer
+framed:
+    push {r4, r7, lr}
+    add r7, sp, #0x4
+    sub sp, sp, #0x18
+    str r0, [sp]
+    sub sp, r7, #0x4
+    pop {r4, r7, pc}
+
+@ The prologue of `normal` patched by hand with an early return which forgets r5 and the locals, and a path which
+@ allocates more stack space than the epilogue frees
+patched:
+    push {r4, r5, lr}
+    sub sp, sp, #0x8
+    cmp r0, #0
+    popeq {r4, pc}
+    cmp r1, #0
+    bne .Lpatched_skip
+    sub sp, sp, #0x4
+.Lpatched_skip:
+    add sp, sp, #0x8
+    pop {r4, r5, pc}
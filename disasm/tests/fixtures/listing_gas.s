@@ -0,0 +1,172 @@
+    .syntax unified
+    .arch armv5te
+    .arm
+    .global _start
+_start:
+    mov r0, #0x4000000               @ 02000000
+    str r0, [r0, #0x208]             @ 02000004
+    mov r0, #0x12                    @ 02000008
+    msr cpsr_c, r0                   @ 0200000c
+    ldr sp, [pc, #0x114]             @ 02000010
+    mov r0, #0x13                    @ 02000014
+    msr cpsr_c, r0                   @ 02000018
+    ldr sp, [pc, #0x10c]             @ 0200001c
+    mov r0, #0x1f                    @ 02000020
+    msr cpsr_c, r0                   @ 02000024
+    ldr sp, [pc, #0x104]             @ 02000028
+    mrc p15, #0, r0, c1, c0, #0      @ 0200002c
+    bic r0, r0, #0x1000              @ 02000030
+    bic r0, r0, #0x5                 @ 02000034
+    mcr p15, #0, r0, c1, c0, #0      @ 02000038
+    mov r0, #0x0                     @ 0200003c
+    mcr p15, #0, r0, c7, c5, #0      @ 02000040
+    mcr p15, #0, r0, c7, c6, #0      @ 02000044
+    mcr p15, #0, r0, c7, c10, #4     @ 02000048
+    ldr r1, [pc, #0xe4]              @ 0200004c
+    ldr r2, [pc, #0xe4]              @ 02000050
+    ldr r3, [pc, #0xe4]              @ 02000054
+func_02000058:
+    bl func_02000058                 @ 02000058
+    ldr r0, [pc, #0xe0]              @ 0200005c
+    ldr r1, [pc, #0xe0]              @ 02000060
+    mov r2, #0x0                     @ 02000064
+lbl_02000068:
+    cmp r0, r1                       @ 02000068
+    strlt r2, [r0], #0x4             @ 0200006c
+    blt lbl_02000068                 @ 02000070
+    ldr r3, [pc, #0xd0]              @ 02000074
+    mov lr, pc                       @ 02000078
+    bx r3                            @ 0200007c
+lbl_02000080:
+    b lbl_02000080                   @ 02000080
+lbl_02000084:
+    cmp r2, r3                       @ 02000084
+    ldrlo r0, [r1], #0x4             @ 02000088
+    strlo r0, [r2], #0x4             @ 0200008c
+    blo lbl_02000084                 @ 02000090
+    bx lr                            @ 02000094
+    push {r4, r5, r6, lr}            @ 02000098
+    mov r3, r1                       @ 0200009c
+    mov r4, r1                       @ 020000a0
+    mov r5, r1                       @ 020000a4
+    mov r6, r1                       @ 020000a8
+lbl_020000ac:
+    subs r2, r2, #0x10               @ 020000ac
+    stmhs r0!, {r3, r4, r5, r6}      @ 020000b0
+    bhi lbl_020000ac                 @ 020000b4
+    adds r2, r2, #0x10               @ 020000b8
+    popeq {r4, r5, r6, pc}           @ 020000bc
+lbl_020000c0:
+    str r1, [r0], #0x4               @ 020000c0
+    subs r2, r2, #0x4                @ 020000c4
+    bhi lbl_020000c0                 @ 020000c8
+    pop {r4, r5, r6, pc}             @ 020000cc
+    push {r0, r1, r2, r3, r12, lr}   @ 020000d0
+    mov r12, #0x4000000              @ 020000d4
+    ldr r1, [r12, #0x210]            @ 020000d8
+    ldr r2, [r12, #0x214]            @ 020000dc
+    and r1, r1, r2                   @ 020000e0
+    str r1, [r12, #0x214]            @ 020000e4
+    ldr r0, [pc, #0x60]              @ 020000e8
+    ldr r2, [r0, #0x0]               @ 020000ec
+    orr r2, r2, r1                   @ 020000f0
+    str r2, [r0, #0x0]               @ 020000f4
+    ldr r3, [pc, #0x54]              @ 020000f8
+    mov r0, #0x0                     @ 020000fc
+lbl_02000100:
+    tst r1, #0x1                     @ 02000100
+    bne lbl_02000118                 @ 02000104
+    add r0, r0, #0x1                 @ 02000108
+    lsrs r1, r1, #0x1                @ 0200010c
+    bne lbl_02000100                 @ 02000110
+    pop {r0, r1, r2, r3, r12, pc}    @ 02000114
+lbl_02000118:
+    ldr r3, [r3, r0, lsl #0x2]       @ 02000118
+    cmp r3, #0x0                     @ 0200011c
+    movne lr, pc                     @ 02000120
+    bxne r3                          @ 02000124
+    pop {r0, r1, r2, r3, r12, pc}    @ 02000128
+    .word 0x27fff80                  @ 0200012c
+    .word 0x27fffc0                  @ 02000130
+    .word 0x27fff00                  @ 02000134
+    .word 0x2100000                  @ 02000138
+    .word 0x27c0000                  @ 0200013c
+    .word 0x27c1000                  @ 02000140
+    .word 0x27c1000                  @ 02000144
+    .word 0x27c8000                  @ 02000148
+    .word 0x2004000                  @ 0200014c
+    .word 0x27ffff8                  @ 02000150
+    .word 0x27ffc00                  @ 02000154
+    .thumb
+    .global vec_add
+    .thumb_func
+vec_add:
+    ldr r2, [r0, #0x0]               @ 02000158
+    ldr r3, [r1, #0x0]               @ 0200015a
+    adds r2, r2, r3                  @ 0200015c
+    str r2, [r0, #0x0]               @ 0200015e
+    ldr r2, [r0, #0x4]               @ 02000160
+    ldr r3, [r1, #0x4]               @ 02000162
+    adds r2, r2, r3                  @ 02000164
+    str r2, [r0, #0x4]               @ 02000166
+    ldr r2, [r0, #0x8]               @ 02000168
+    ldr r3, [r1, #0x8]               @ 0200016a
+    adds r2, r2, r3                  @ 0200016c
+    str r2, [r0, #0x8]               @ 0200016e
+    bx lr                            @ 02000170
+    push {r4, r5, lr}                @ 02000172
+    sub sp, sp, #0x4                 @ 02000174
+    movs r4, r0                      @ 02000176
+    movs r5, #0x0                    @ 02000178
+lbl_0200017a:
+    ldrh r0, [r4, #0x10]             @ 0200017a
+    cmp r0, r1                       @ 0200017c
+    beq lbl_0200018e                 @ 0200017e
+    adds r4, #0x20                   @ 02000180
+    adds r5, r5, #0x1                @ 02000182
+    cmp r5, #0x40                    @ 02000184
+    blt lbl_0200017a                 @ 02000186
+    movs r0, #0x0                    @ 02000188
+    add sp, sp, #0x4                 @ 0200018a
+    pop {r4, r5, pc}                 @ 0200018c
+lbl_0200018e:
+    movs r0, r4                      @ 0200018e
+    ldr r1, [r4, #0x18]              @ 02000190
+    cmp r1, #0x0                     @ 02000192
+    beq lbl_0200019a                 @ 02000194
+    bl vec_add                       @ 02000196
+lbl_0200019a:
+    movs r0, r4                      @ 0200019a
+    add sp, sp, #0x4                 @ 0200019c
+    pop {r4, r5, pc}                 @ 0200019e
+    push {r4, lr}                    @ 020001a0
+    ldr r4, [pc, #0x2c]              @ 020001a2
+    ldr r0, [r4, #0x0]               @ 020001a4
+    subs r0, r0, #0x1                @ 020001a6
+    str r0, [r4, #0x0]               @ 020001a8
+    bne lbl_020001ba                 @ 020001aa
+    ldrb r0, [r4, #0x4]              @ 020001ac
+    lsls r0, r0, #0x2                @ 020001ae
+    ldr r1, [pc, #0x20]              @ 020001b0
+    ldr r1, [r1, r0]                 @ 020001b2
+    movs r0, r4                      @ 020001b4
+    bl func_020001bc                 @ 020001b6
+lbl_020001ba:
+    pop {r4, pc}                     @ 020001ba
+func_020001bc:
+    bx r1                            @ 020001bc
+    cmp r2, #0x0                     @ 020001be
+    beq lbl_020001ce                 @ 020001c0
+lbl_020001c2:
+    ldrh r3, [r1, #0x0]              @ 020001c2
+    strh r3, [r0, #0x0]              @ 020001c4
+    adds r0, #0x2                    @ 020001c6
+    adds r1, #0x2                    @ 020001c8
+    subs r2, r2, #0x1                @ 020001ca
+    bne lbl_020001c2                 @ 020001cc
+lbl_020001ce:
+    bx lr                            @ 020001ce
+    .word 0x27e0040                  @ 020001d0
+    .word 0x20a1230                  @ 020001d4
+    .hword 0x3412                    @ 020001d8
+    .byte 0x56                       @ 020001da
@@ -0,0 +1,113 @@
+@ Fixture for the endianness tests, written in the style of NDS ARM9 startup code and assembled with:
+@   llvm-mc -triple=armv5te -filetype=obj nds_arm9.s -o nds_arm9.o
+@   llvm-objcopy -O binary nds_arm9.o nds_arm9.bin
+
+    .arm
+    .text
+
+_start:
+    mov r0, #0x04000000
+    str r0, [r0, #0x208]        @ REG_IME = 0
+    mov r0, #0x12
+    msr cpsr_c, r0              @ IRQ mode
+    ldr sp, =0x027fff80
+    mov r0, #0x13
+    msr cpsr_c, r0              @ SVC mode
+    ldr sp, =0x027fffc0
+    mov r0, #0x1f
+    msr cpsr_c, r0              @ System mode
+    ldr sp, =0x027fff00
+
+    @ Disable caches and the protection unit
+    mrc p15, 0, r0, c1, c0, 0
+    bic r0, r0, #0x1000
+    bic r0, r0, #0x5
+    mcr p15, 0, r0, c1, c0, 0
+    mov r0, #0
+    mcr p15, 0, r0, c7, c5, 0   @ Flush ICache
+    mcr p15, 0, r0, c7, c6, 0   @ Flush DCache
+    mcr p15, 0, r0, c7, c10, 4  @ Drain write buffer
+
+    @ Copy .data from ROM to RAM
+    ldr r1, =__data_lma
+    ldr r2, =__data_start
+    ldr r3, =__data_end
+    bl copy_words
+
+    @ Clear .bss
+    ldr r0, =__bss_start
+    ldr r1, =__bss_end
+    mov r2, #0
+.Lclear:
+    cmp r0, r1
+    strlt r2, [r0], #4
+    blt .Lclear
+
+    ldr r3, =main
+    mov lr, pc
+    bx r3
+.Lhang:
+    b .Lhang
+
+copy_words:
+    cmp r2, r3
+    ldrlo r0, [r1], #4
+    strlo r0, [r2], #4
+    blo copy_words
+    bx lr
+
+memset32:
+    push {r4-r6, lr}
+    mov r3, r1
+    mov r4, r1
+    mov r5, r1
+    mov r6, r1
+.Lset16:
+    subs r2, r2, #16
+    stmiahs r0!, {r3-r6}
+    bhi .Lset16
+    adds r2, r2, #16
+    popeq {r4-r6, pc}
+.Lset4:
+    str r1, [r0], #4
+    subs r2, r2, #4
+    bhi .Lset4
+    pop {r4-r6, pc}
+
+irq_handler:
+    push {r0-r3, r12, lr}
+    mov r12, #0x04000000
+    ldr r1, [r12, #0x210]       @ REG_IE
+    ldr r2, [r12, #0x214]       @ REG_IF
+    and r1, r1, r2
+    str r1, [r12, #0x214]
+    ldr r0, =irq_flags
+    ldr r2, [r0]
+    orr r2, r2, r1
+    str r2, [r0]
+    ldr r3, =irq_table
+    mov r0, #0
+.Lfind:
+    tst r1, #1
+    bne .Lfound
+    add r0, r0, #1
+    movs r1, r1, lsr #1
+    bne .Lfind
+    pop {r0-r3, r12, pc}
+.Lfound:
+    ldr r3, [r3, r0, lsl #2]
+    cmp r3, #0
+    movne lr, pc
+    bxne r3
+    pop {r0-r3, r12, pc}
+
+    .ltorg
+
+    .equ __data_lma, 0x02100000
+    .equ __data_start, 0x027c0000
+    .equ __data_end, 0x027c1000
+    .equ __bss_start, 0x027c1000
+    .equ __bss_end, 0x027c8000
+    .equ main, 0x02004000
+    .equ irq_flags, 0x027ffff8
+    .equ irq_table, 0x027ffc00
@@ -0,0 +1,86 @@
+@ Fixture for the endianness tests, written in the style of compiled NDS Thumb code and assembled with:
+@   llvm-mc -triple=thumbv5te -filetype=obj nds_thumb.s -o nds_thumb.o
+@   llvm-objcopy -O binary nds_thumb.o nds_thumb.bin
+
+    .thumb
+    .text
+
+vec_add:
+    ldr r2, [r0]
+    ldr r3, [r1]
+    adds r2, r2, r3
+    str r2, [r0]
+    ldr r2, [r0, #4]
+    ldr r3, [r1, #4]
+    adds r2, r2, r3
+    str r2, [r0, #4]
+    ldr r2, [r0, #8]
+    ldr r3, [r1, #8]
+    adds r2, r2, r3
+    str r2, [r0, #8]
+    bx lr
+
+find_entity:
+    push {r4, r5, lr}
+    sub sp, #4
+    movs r4, r0
+    movs r5, #0
+.Lloop:
+    ldrh r0, [r4, #0x10]
+    cmp r0, r1
+    beq .Lfound
+    adds r4, #0x20
+    adds r5, r5, #1
+    cmp r5, #0x40
+    blt .Lloop
+    movs r0, #0
+    add sp, #4
+    pop {r4, r5, pc}
+.Lfound:
+    movs r0, r4
+    ldr r1, [r4, #0x18]
+    cmp r1, #0
+    beq .Lskip
+    bl vec_add
+.Lskip:
+    movs r0, r4
+    add sp, #4
+    pop {r4, r5, pc}
+
+update_timer:
+    push {r4, lr}
+    ldr r4, .Ltimer
+    ldr r0, [r4]
+    subs r0, r0, #1
+    str r0, [r4]
+    bne .Ldone
+    ldrb r0, [r4, #4]
+    lsls r0, r0, #2
+    ldr r1, .Ltable
+    ldr r1, [r1, r0]
+    movs r0, r4
+    bl call_r1
+.Ldone:
+    pop {r4, pc}
+
+call_r1:
+    bx r1
+
+copy_halfwords:
+    cmp r2, #0
+    beq .Lret
+.Lcopy:
+    ldrh r3, [r1]
+    strh r3, [r0]
+    adds r0, #2
+    adds r1, #2
+    subs r2, r2, #1
+    bne .Lcopy
+.Lret:
+    bx lr
+
+    .align 2
+.Ltimer:
+    .word 0x027e0040
+.Ltable:
+    .word 0x020a1230
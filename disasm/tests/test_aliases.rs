@@ -0,0 +1,81 @@
+use unarm::{
+    args::{Argument, RegList, Register},
+    ParseFlags,
+};
+
+const ALIASES: ParseFlags = ParseFlags {
+    aliases: true,
+    ..ParseFlags::DEFAULT
+};
+
+fn disasm_arm(code: u32, flags: &ParseFlags) -> String {
+    let ins = unarm::v5te::arm::Ins::new(code, flags);
+    let text = ins.parse(flags).display(Default::default()).to_string();
+    let v6k = unarm::v6k::arm::Ins::new(code, flags);
+    assert_eq!(v6k.parse(flags).display(Default::default()).to_string(), text, "{code:#010x}");
+    text
+}
+
+#[test]
+fn test_single_transfer() {
+    assert_eq!(disasm_arm(0xe8900008, &ALIASES), "ldr r3, [r0, #0x0]");
+    assert_eq!(disasm_arm(0x18900008, &ALIASES), "ldrne r3, [r0, #0x0]");
+    assert_eq!(disasm_arm(0xe88d4000, &ALIASES), "str lr, [sp, #0x0]");
+    assert_eq!(disasm_arm(0xb8810001, &ALIASES), "strlt r0, [r1, #0x0]");
+    let divided = ParseFlags { ual: false, ..ALIASES };
+    assert_eq!(disasm_arm(0x18900008, &divided), "ldrne r3, [r0, #0x0]");
+
+    // Thumb has no writeback if the base register is loaded
+    let ins = unarm::v5te::thumb::Ins::new(0xc801, &ALIASES);
+    assert_eq!(
+        ins.parse(&ALIASES).display(Default::default()).to_string(),
+        "ldr r0, [r0, #0x0]"
+    );
+    let ins = unarm::v5te::arm::Ins::new(0xe8900008, &ALIASES);
+    assert_eq!(
+        ins.parse_unaliased(&ALIASES).display(Default::default()).to_string(),
+        "ldm r0, {r3}"
+    );
+}
+
+#[test]
+fn test_not_aliased() {
+    for (code, text) in [
+        // Writeback
+        (0xe8b00008, "ldm r0!, {r3}"),
+        (0xe8ad4000, "stm sp!, {lr}"),
+        // Other addressing modes
+        (0xe9900008, "ldmib r0, {r3}"),
+        (0xe8100008, "ldmda r0, {r3}"),
+        (0xe9000008, "stmdb r0, {r3}"),
+        // More than one register
+        (0xe890000c, "ldm r0, {r2, r3}"),
+        // User-mode registers
+        (0xe8d00008, "ldm r0, {r3}^"),
+        // Empty list
+        (0xe8900000, "ldm r0, {}"),
+    ] {
+        assert_eq!(disasm_arm(code, &ALIASES), text, "{code:#010x}");
+    }
+    // Thumb `ldm` writes back unless the base register is loaded, and `stm` always writes back
+    for (code, text) in [(0xc108, "stm r1!, {r3}"), (0xc802, "ldm r0!, {r1}")] {
+        let ins = unarm::v5te::thumb::Ins::new(code, &ALIASES);
+        assert_eq!(ins.parse(&ALIASES).display(Default::default()).to_string(), text);
+    }
+    // Off by default
+    assert_eq!(disasm_arm(0xe8900008, &Default::default()), "ldm r0, {r3}");
+}
+
+#[test]
+fn test_reg_list() {
+    let list = |regs| RegList { regs, user_mode: false };
+    assert_eq!((list(0).len(), list(0).is_empty(), list(0).single()), (0, true, None));
+    assert_eq!((list(0x8).len(), list(0x8).single()), (1, Some(Register::R3)));
+    assert_eq!((list(0xc00c).len(), list(0xc00c).single()), (4, None));
+    let ins = unarm::v5te::arm::Ins::new(0xe92d4ff0, &Default::default()).parse(&Default::default());
+    let Argument::RegList(regs) = ins.args[0] else {
+        panic!("{:?}", ins.args[0]);
+    };
+    assert_eq!(regs.registers().iter().last(), Some(Register::Lr));
+    assert_eq!(regs.len(), 9);
+}
@@ -0,0 +1,510 @@
+use unarm::{
+    analysis::{
+        frame_summary, guess_endianness, pair_flags, segment, Cfg, FlagPairing, Flow, FrameMismatch, FrameSummary,
+        RegisterSet, StatusFlags,
+    },
+    args::Register::{self, *},
+    testing::fixture,
+    ArmVersion, Endian, Op, ParseFlags, ParseMode, ParsedIns, Parser,
+};
+
+fn assert_blocks(cfg: &Cfg, expected: &[(u32, u32, &[u32])]) {
+    let blocks: Vec<_> = cfg
+        .blocks
+        .iter()
+        .map(|block| (block.start, block.end, block.successors.as_slice()))
+        .collect();
+    assert_eq!(blocks, expected);
+}
+
+#[test]
+fn test_segment_arm() {
+    let code = fixture("cfg_arm").unwrap().code;
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    );
+    let cfg = segment(code, 0, &parser, &[0x0, 0x44]);
+
+    assert_blocks(
+        &cfg,
+        &[
+            // Early return
+            (0x00, 0x0c, &[0x0c]),
+            (0x0c, 0x14, &[0x14]),
+            // Loop header
+            (0x14, 0x1c, &[0x3c, 0x1c]),
+            // If/else
+            (0x1c, 0x28, &[0x30, 0x28]),
+            (0x28, 0x30, &[0x34]),
+            (0x30, 0x34, &[0x34]),
+            // Back edge
+            (0x34, 0x3c, &[0x14]),
+            (0x3c, 0x44, &[]),
+            (0x44, 0x50, &[]),
+        ],
+    );
+    assert_eq!(cfg.blocks[0].flow, Flow::Return { conditional: true });
+    assert_eq!(cfg.blocks[7].flow, Flow::Return { conditional: false });
+    assert_eq!(cfg.blocks[8].flow, Flow::Indirect { conditional: false });
+    assert_eq!(cfg.blocks[8].instructions.len(), 3);
+    assert_eq!(cfg.unresolved, [0x4c]);
+
+    assert_eq!(cfg.functions.len(), 2);
+    assert_eq!(cfg.functions[0].entry, 0x0);
+    assert_eq!(cfg.functions[0].blocks, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(cfg.functions[1].entry, 0x44);
+    assert_eq!(cfg.functions[1].blocks, [8]);
+
+    assert_eq!(cfg.block_containing(0x20).map(|block| block.start), Some(0x1c));
+    assert_eq!(cfg.block_containing(0x50).map(|block| block.start), None);
+}
+
+#[test]
+fn test_segment_thumb() {
+    let code = fixture("cfg_thumb").unwrap().code;
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Thumb,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    );
+    let base = 0x02000000;
+    let cfg = segment(code, base, &parser, &[base, base + 0x28]);
+
+    let expected: &[(u32, u32, &[u32])] = &[
+        // Early return
+        (0x00, 0x06, &[0x0a, 0x06]),
+        (0x06, 0x0a, &[]),
+        (0x0a, 0x0e, &[0x0e]),
+        // Loop header
+        (0x0e, 0x12, &[0x24, 0x12]),
+        // If/else
+        (0x12, 0x1a, &[0x1e, 0x1a]),
+        (0x1a, 0x1e, &[0x20]),
+        (0x1e, 0x20, &[0x20]),
+        // Back edge
+        (0x20, 0x24, &[0x0e]),
+        (0x24, 0x28, &[]),
+        (0x28, 0x30, &[]),
+    ];
+    let blocks: Vec<_> = cfg
+        .blocks
+        .iter()
+        .map(|block| {
+            let successors: Vec<_> = block.successors.iter().map(|s| s - base).collect();
+            (block.start - base, block.end - base, successors)
+        })
+        .collect();
+    let expected: Vec<_> = expected
+        .iter()
+        .map(|&(start, end, successors)| (start, end, successors.to_vec()))
+        .collect();
+    assert_eq!(blocks, expected);
+
+    // The BL pair is combined into one instruction
+    let dispatch = &cfg.blocks[9];
+    assert_eq!(dispatch.instructions.len(), 3);
+    assert_eq!(dispatch.instructions[1].0, base + 0x2a);
+    assert_eq!(dispatch.instructions[1].2.mnemonic, "bl");
+    assert_eq!(dispatch.flow, Flow::Indirect { conditional: false });
+    assert_eq!(cfg.unresolved, [base + 0x2e]);
+
+    assert_eq!(cfg.functions[0].blocks, [0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(cfg.functions[1].blocks, [9]);
+}
+
+#[test]
+fn test_segment_out_of_range() {
+    // b #0x1000, outside of the code
+    let code = 0xea0003feu32.to_le_bytes();
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    );
+    let cfg = segment(&code, 0, &parser, &[0x0, 0x2, 0x100]);
+
+    assert_blocks(&cfg, &[(0x0, 0x4, &[])]);
+    assert_eq!(
+        cfg.blocks[0].flow,
+        Flow::Branch {
+            target: 0x1000,
+            conditional: false
+        }
+    );
+    assert_eq!(cfg.dangling, [0x0]);
+    assert_eq!(cfg.functions.len(), 3);
+    assert!(cfg.functions[1].blocks.is_empty());
+    assert!(cfg.functions[2].blocks.is_empty());
+}
+
+#[test]
+fn test_classify_calls() {
+    // bl, blx r3, beq
+    let code = [0xebffffecu32, 0xe12fff33, 0x0a000000];
+    let bytes: Vec<u8> = code.iter().flat_map(|c| c.to_le_bytes()).collect();
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0x100,
+        Endian::Little,
+        ParseFlags::default(),
+        &bytes,
+    );
+    let flows: Vec<_> = parser
+        .zip(code)
+        .map(|((address, op, ins), code)| Flow::classify(address, code, op, &ins))
+        .collect();
+    assert_eq!(
+        flows,
+        [
+            Flow::Call { target: Some(0xb8) },
+            Flow::Call { target: None },
+            Flow::Branch {
+                target: 0x110,
+                conditional: true
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_frame_summary() {
+    let code = fixture("frame_arm").unwrap().code;
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    );
+    let cfg = segment(code, 0, &parser, &[0x0, 0x24, 0x2c, 0x44]);
+    let summaries: Vec<_> = cfg.functions.iter().map(|function| frame_summary(&cfg, function)).collect();
+
+    assert_eq!(
+        summaries[0],
+        FrameSummary {
+            saved: regs(&[R4, R5, R6]),
+            restored: regs(&[R4, R5, R6]),
+            saves_lr: true,
+            frame_size: 0x20,
+            mismatches: vec![],
+        }
+    );
+    assert_eq!(summaries[1], FrameSummary::default());
+    // SP is restored from the frame pointer in r7
+    assert_eq!(
+        summaries[2],
+        FrameSummary {
+            saved: regs(&[R4, R7]),
+            restored: regs(&[R4, R7]),
+            saves_lr: true,
+            frame_size: 0x24,
+            mismatches: vec![],
+        }
+    );
+
+    let patched = &summaries[3];
+    assert_eq!(patched.saved, regs(&[R4, R5]));
+    assert_eq!(patched.restored, regs(&[R4]));
+    assert_eq!(patched.frame_size, 0x18);
+    assert_eq!(
+        patched.mismatches,
+        [
+            FrameMismatch::NotRestored {
+                exit: 0x50,
+                regs: regs(&[R5])
+            },
+            FrameMismatch::UnbalancedStack {
+                exit: 0x50,
+                offset: Some(-0xc)
+            },
+            FrameMismatch::StackConflict { block: 0x60 },
+            FrameMismatch::UnbalancedStack {
+                exit: 0x64,
+                offset: None
+            },
+        ]
+    );
+
+    // Divided syntax shows pushes and pops as `stmdb` and `ldmia`
+    let flags = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
+    let parser = Parser::new(ArmVersion::V5Te, ParseMode::Arm, 0, Endian::Little, flags, &[]);
+    let cfg = segment(code, 0, &parser, &[0x0, 0x24, 0x2c, 0x44]);
+    assert_eq!(cfg.blocks[0].instructions[0].2.mnemonic, "stmdb");
+    let divided: Vec<_> = cfg.functions.iter().map(|function| frame_summary(&cfg, function)).collect();
+    assert_eq!(divided, summaries);
+}
+
+#[test]
+fn test_frame_summary_thumb() {
+    let code = fixture("cfg_thumb").unwrap().code;
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Thumb,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    );
+    let cfg = segment(code, 0, &parser, &[0x0]);
+    let summary = frame_summary(&cfg, &cfg.functions[0]);
+    assert_eq!(
+        summary,
+        FrameSummary {
+            saved: regs(&[R4]),
+            restored: regs(&[R4]),
+            saves_lr: true,
+            frame_size: 8,
+            mismatches: vec![],
+        }
+    );
+}
+
+fn byte_swap(bytes: &[u8], size: usize) -> Vec<u8> {
+    bytes.chunks(size).flat_map(|chunk| chunk.iter().rev().copied()).collect()
+}
+
+#[test]
+fn test_guess_endianness_arm() {
+    let code = fixture("nds_arm9").unwrap().code;
+
+    let (endian, confidence) = guess_endianness(code, ArmVersion::V5Te, ParseMode::Arm);
+    assert_eq!(endian, Endian::Little);
+    assert!(confidence > 0.9, "confidence {confidence}");
+
+    let (endian, confidence) = guess_endianness(&byte_swap(code, 4), ArmVersion::V5Te, ParseMode::Arm);
+    assert_eq!(endian, Endian::Big);
+    assert!(confidence > 0.9, "confidence {confidence}");
+}
+
+#[test]
+fn test_guess_endianness_thumb() {
+    let code = fixture("nds_thumb").unwrap().code;
+
+    let (endian, confidence) = guess_endianness(code, ArmVersion::V5Te, ParseMode::Thumb);
+    assert_eq!(endian, Endian::Little);
+    assert!(confidence > 0.9, "confidence {confidence}");
+
+    let (endian, confidence) = guess_endianness(&byte_swap(code, 2), ArmVersion::V5Te, ParseMode::Thumb);
+    assert_eq!(endian, Endian::Big);
+    assert!(confidence > 0.9, "confidence {confidence}");
+}
+
+#[test]
+fn test_guess_endianness_data() {
+    // xorshift32
+    let mut state = 0x12345678u32;
+    let random: Vec<u8> = (0..0x1000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect();
+    let zeros = [0; 0x100];
+
+    for data in [random.as_slice(), &zeros, &[]] {
+        for mode in [ParseMode::Arm, ParseMode::Thumb] {
+            let (_, confidence) = guess_endianness(data, ArmVersion::V5Te, mode);
+            assert!(confidence < 0.5, "confidence {confidence}");
+        }
+    }
+}
+
+#[test]
+fn test_guess_endianness_skips_header() {
+    // A header of zeroes followed by code, which should still be detected
+    let code = fixture("nds_arm9").unwrap().code;
+    let mut bytes = vec![0; 0x80];
+    bytes.extend_from_slice(&byte_swap(code, 4));
+
+    let (endian, confidence) = guess_endianness(&bytes, ArmVersion::V5Te, ParseMode::Arm);
+    assert_eq!(endian, Endian::Big);
+    assert!(confidence > 0.5, "confidence {confidence}");
+}
+
+fn regs(regs: &[Register]) -> RegisterSet {
+    regs.iter().copied().collect()
+}
+
+fn arm_defs_uses(code: u32) -> (RegisterSet, RegisterSet) {
+    use unarm::v5te::arm::Ins;
+    let flags = ParseFlags::default();
+    let ins = Ins::new(code, &flags);
+    let parsed = ins.parse(&flags);
+    (parsed.defs(Op::ArmV5Te(ins.op)), parsed.uses(Op::ArmV5Te(ins.op)))
+}
+
+#[test]
+fn test_defs_uses_arm() {
+    // ldr r2, [r1, r3]!
+    assert_eq!(arm_defs_uses(0xe7b12003), (regs(&[R1, R2]), regs(&[R1, R3])));
+    // ldr r0, [r1], #0x4
+    assert_eq!(arm_defs_uses(0xe4910004), (regs(&[R0, R1]), regs(&[R1])));
+    // str r0, [r1, #0x4]
+    assert_eq!(arm_defs_uses(0xe5810004), (regs(&[]), regs(&[R0, R1])));
+    // stmda r5, {r0, r1}^
+    assert_eq!(arm_defs_uses(0xe8450003), (regs(&[]), regs(&[R0, R1, R5])));
+    // ldm r0!, {r0, r1, r2, r3, lr}
+    assert_eq!(arm_defs_uses(0xe8b0400f), (regs(&[R0, R1, R2, R3, Lr]), regs(&[R0])));
+    // mla r1, r4, r3, r2
+    assert_eq!(arm_defs_uses(0xe0212394), (regs(&[R1]), regs(&[R2, R3, R4])));
+    // umlal r0, r1, r2, r3
+    assert_eq!(arm_defs_uses(0xe0a10392), (regs(&[R0, R1]), regs(&[R0, R1, R2, R3])));
+    // add r0, r1, r2, lsl r3
+    assert_eq!(arm_defs_uses(0xe0810312), (regs(&[R0]), regs(&[R1, R2, R3])));
+    // cmp r0, r1
+    assert_eq!(arm_defs_uses(0xe1500001), (regs(&[]), regs(&[R0, R1])));
+    // mov pc, lr
+    assert_eq!(arm_defs_uses(0xe1a0f00e), (regs(&[Pc]), regs(&[Lr])));
+    // pop {r4, pc}
+    assert_eq!(arm_defs_uses(0xe8bd8010), (regs(&[R4, Sp, Pc]), regs(&[Sp])));
+    // bl #-0x48
+    assert_eq!(arm_defs_uses(0xebffffec), (regs(&[Lr, Pc]), regs(&[])));
+    // bx lr
+    assert_eq!(arm_defs_uses(0xe12fff1e), (regs(&[Pc]), regs(&[Lr])));
+}
+
+#[test]
+fn test_defs_uses_thumb() {
+    use unarm::v5te::thumb::Ins;
+    let flags = ParseFlags::default();
+    let defs_uses = |code| {
+        let ins = Ins::new(code, &flags);
+        let parsed = ins.parse(&flags);
+        (parsed.defs(Op::ThumbV5Te(ins.op)), parsed.uses(Op::ThumbV5Te(ins.op)))
+    };
+
+    // adc r7, r2
+    assert_eq!(defs_uses(0x4157), (regs(&[R7]), regs(&[R2, R7])));
+    // push {r4, lr}
+    assert_eq!(defs_uses(0xb510), (regs(&[Sp]), regs(&[R4, Sp, Lr])));
+    // ldr r0, [r1, #0x4]
+    assert_eq!(defs_uses(0x6848), (regs(&[R0]), regs(&[R1])));
+    // mov r8, r9
+    assert_eq!(defs_uses(0x46c8), (regs(&[R8]), regs(&[R9])));
+}
+
+#[test]
+fn test_thumb_moves() {
+    use unarm::v6k::thumb::Ins;
+    let flags = ParseFlags::default();
+    let divided = ParseFlags { ual: false, ..flags };
+    let parse = |code, flags| Ins::new(code, &flags).parse(&flags);
+
+    // Each encoding of a move from r1 to r0 writes the same registers, but only the high-register one keeps the flags
+    for (code, asm, written) in [
+        (0x4608, "mov r0, r1", StatusFlags::default()),
+        (0x0008, "movs r0, r1", StatusFlags::NZC),
+        (0x1c08, "adds r0, r1, #0x0", StatusFlags::NZCV),
+    ] {
+        let ins = parse(code, flags);
+        assert_eq!(ins.display(Default::default()).to_string(), asm);
+        assert_eq!(ins.flags_written(), written, "{asm}");
+        let op = Op::ThumbV6K(Ins::new(code, &flags).op);
+        assert_eq!((ins.defs(op), ins.uses(op)), (regs(&[R0]), regs(&[R1])), "{asm}");
+    }
+
+    // `cpy` is the divided syntax of the high-register move, and writes no flags either
+    let ins = parse(0x4608, divided);
+    assert_eq!(ins.display(Default::default()).to_string(), "cpy r0, r1");
+    assert_eq!(ins.flags_written(), StatusFlags::default());
+    let op = Op::ThumbV6K(Ins::new(0x4608, &divided).op);
+    assert_eq!((ins.defs(op), ins.uses(op)), (regs(&[R0]), regs(&[R1])));
+}
+
+#[test]
+fn test_register_set() {
+    let mut set = regs(&[R0, R4, Pc]);
+    assert_eq!(set.0, 0x8011);
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(R4) && !set.contains(R5));
+    set.insert(Register::Illegal);
+    set.remove(R4);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [R0, Pc]);
+    assert!(RegisterSet::default().is_empty());
+    assert_eq!(set.intersection(regs(&[R0, R1])), regs(&[R0]));
+    assert_eq!(set.difference(regs(&[R0, R1])), regs(&[Pc]));
+}
+
+/// Parses one instruction per line, starting at 0x8000
+fn block(text: &str) -> Vec<(u32, ParsedIns)> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| (0x8000 + index as u32 * 4, line.trim().parse().unwrap()))
+        .collect()
+}
+
+fn pairs(pairings: &[FlagPairing]) -> Vec<(u32, u32)> {
+    pairings.iter().map(|pairing| (pairing.setter, pairing.user)).collect()
+}
+
+#[test]
+fn test_pair_flags() {
+    // An intervening instruction which doesn't set the flags
+    let pairings = pair_flags(&block(
+        "cmp r0, #0x0
+         mov r1, #0x1
+         addeq r2, r2, #0x1
+         movne r2, #0x0",
+    ));
+    assert_eq!(pairs(&pairings), [(0x8000, 0x8008), (0x8000, 0x800c)]);
+    assert_eq!(pairings[0].flags, StatusFlags::Z);
+    assert_eq!(pairings[0].user_comment(), "; uses flags from 0x8000");
+    assert_eq!(pairings[0].setter_comment(), "; flags used at 0x8008");
+
+    // A second `cmp` supersedes the first
+    let pairings = pair_flags(&block(
+        "cmp r0, #0x0
+         cmp r1, #0x1
+         bgt #0x20",
+    ));
+    assert_eq!(pairs(&pairings), [(0x8004, 0x8008)]);
+    assert!(pairings[0].flags.contains(StatusFlags::Z) && pairings[0].flags.contains(StatusFlags::V));
+
+    // A conditional `cmp` may leave the flags of the first one
+    let pairings = pair_flags(&block(
+        "cmp r0, #0x0
+         cmpne r1, #0x0
+         beq #0x20",
+    ));
+    assert_eq!(pairs(&pairings), [(0x8000, 0x8004), (0x8000, 0x8008), (0x8004, 0x8008)]);
+
+    // Flags come from different instructions, and `muls` doesn't write C
+    let pairings = pair_flags(&block(
+        "adds r0, r0, r1
+         muls r2, r3, r2
+         adc r4, r4, #0x0
+         movpl r0, #0x0",
+    ));
+    assert_eq!(pairs(&pairings), [(0x8000, 0x8008), (0x8004, 0x800c)]);
+    assert_eq!(pairings[0].flags, StatusFlags::C);
+
+    // Calls and `msr` clobber the flags, and flags are unknown at the start of the block
+    let pairings = pair_flags(&block(
+        "moveq r0, #0x0
+         cmp r0, #0x0
+         bl #0x100
+         bne #0x20
+         tst r0, #0x1
+         msr cpsr_f, r1
+         bne #0x20",
+    ));
+    assert!(pairings.is_empty());
+}
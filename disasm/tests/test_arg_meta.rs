@@ -0,0 +1,139 @@
+use unarm::{args::ArgumentKind, ArgMeta, ParseFlags, ParsedIns};
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+fn kinds(meta: &[ArgMeta]) -> Vec<(ArgumentKind, bool)> {
+    meta.iter().map(|m| (m.kind, m.optional)).collect()
+}
+
+#[test]
+fn test_sxtb() {
+    use unarm::v6k::arm::{Ins, Opcode};
+
+    assert_eq!(Opcode::Sxtb.min_args(&UNIFIED), 2);
+    assert_eq!(Opcode::Sxtb.max_args(&UNIFIED), 3);
+    assert_eq!(
+        kinds(Opcode::Sxtb.arg_meta(&UNIFIED)),
+        [
+            (ArgumentKind::Reg, false),
+            (ArgumentKind::Reg, false),
+            (ArgumentKind::ShiftImm, true)
+        ]
+    );
+
+    // sxtb r2, r1 / sxtb r2, r1, ror #0x8
+    assert_eq!(Ins::new(0xe6af2071, &UNIFIED).parse(&UNIFIED).args_iter().count(), 2);
+    assert_eq!(Ins::new(0xe6af2471, &UNIFIED).parse(&UNIFIED).args_iter().count(), 3);
+}
+
+#[test]
+fn test_ldm() {
+    use unarm::v5te::arm::Opcode;
+
+    // The user mode marker `^` is part of the register list, so it doesn't change the number of arguments
+    for op in [Opcode::Ldm, Opcode::LdmW, Opcode::LdmP, Opcode::LdmPc, Opcode::LdmPcW] {
+        for flags in [UNIFIED, DIVIDED] {
+            assert_eq!(op.min_args(&flags), 2, "{op:?}");
+            assert_eq!(op.max_args(&flags), 2, "{op:?}");
+            assert_eq!(
+                kinds(op.arg_meta(&flags)),
+                [(ArgumentKind::Reg, false), (ArgumentKind::RegList, false)]
+            );
+        }
+    }
+    assert_eq!(Opcode::Ldm.arg_meta(&UNIFIED)[0].name, "Rn");
+    assert_eq!(Opcode::LdmW.arg_meta(&UNIFIED)[0].name, "Rn_wb");
+}
+
+#[test]
+fn test_data_processing() {
+    use unarm::v5te::arm::Opcode;
+
+    // add r0, r1, #0x1 / add r0, r1, r2 / add r0, r1, r2, lsl r3
+    assert_eq!(Opcode::Add.min_args(&UNIFIED), 3);
+    assert_eq!(Opcode::Add.max_args(&UNIFIED), 4);
+    let meta = Opcode::Add.arg_meta(&UNIFIED);
+    assert!(!meta[0].optional && !meta[1].optional);
+    assert!(meta[2..].iter().all(|m| m.optional));
+}
+
+#[test]
+fn test_illegal() {
+    use unarm::v5te::arm::Opcode;
+
+    assert_eq!(Opcode::Illegal.min_args(&UNIFIED), 0);
+    assert_eq!(Opcode::Illegal.max_args(&UNIFIED), 0);
+    assert!(Opcode::Illegal.arg_meta(&UNIFIED).is_empty());
+}
+
+/// Checks that the parsed arguments are within the advertised range, and that they're a subsequence of the argument
+/// metadata which includes every non-optional argument
+fn check_arity(parsed: &ParsedIns, min_args: usize, max_args: usize, meta: &[ArgMeta], desc: &str) {
+    if parsed.mnemonic == "<illegal>" {
+        return;
+    }
+    let count = parsed.args_iter().count();
+    assert!(
+        min_args <= count && count <= max_args,
+        "{desc}: {count} args not in {min_args}..={max_args}"
+    );
+
+    let mut meta = meta.iter();
+    for arg in parsed.args_iter() {
+        loop {
+            let Some(m) = meta.next() else {
+                panic!("{desc}: argument {arg:?} is not in the metadata");
+            };
+            if m.kind == arg.kind() {
+                break;
+            }
+            assert!(m.optional, "{desc}: missing non-optional argument {}", m.name);
+        }
+    }
+    assert!(meta.all(|m| m.optional), "{desc}: missing non-optional argument");
+}
+
+macro_rules! check_corpus {
+    ($module:ident, $mode:ident, $codes:expr) => {{
+        use unarm::$module::$mode::Ins;
+        for code in $codes {
+            for flags in [UNIFIED, DIVIDED] {
+                let ins = Ins::new(code, &flags);
+                let parsed = ins.parse(&flags);
+                let desc = format!("{} {:08x} {:?} ual={}", stringify!($module), code, ins.op, flags.ual);
+                check_arity(
+                    &parsed,
+                    ins.op.min_args(&flags),
+                    ins.op.max_args(&flags),
+                    ins.op.arg_meta(&flags),
+                    &desc,
+                );
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_corpus_arity() {
+    // xorshift32
+    let mut state = 0x9e3779b9u32;
+    let arm_codes: Vec<u32> = (0..0x10000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect();
+
+    check_corpus!(v4t, arm, arm_codes.iter().copied());
+    check_corpus!(v5te, arm, arm_codes.iter().copied());
+    check_corpus!(v6k, arm, arm_codes.iter().copied());
+    check_corpus!(v4t, thumb, 0..=0xffff);
+    check_corpus!(v5te, thumb, 0..=0xffff);
+    check_corpus!(v6k, thumb, 0..=0xffff);
+}
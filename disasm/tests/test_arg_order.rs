@@ -0,0 +1,116 @@
+use unarm::{
+    args::{Argument, ArgumentKind, Register},
+    ParseFlags,
+};
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+/// Returns the position of the ARM register fields which are covered by the positional contract
+fn arm_field_shift(name: &str) -> Option<u32> {
+    match name {
+        "Rd" | "RdLo" | "Rn_12" => Some(12),
+        "Rn" | "Rn_wb" | "Rn_deref" | "RdHi" => Some(16),
+        "Rs" => Some(8),
+        "Rm" => Some(0),
+        _ => None,
+    }
+}
+
+fn names(meta: &[unarm::ArgMeta]) -> Vec<&'static str> {
+    meta.iter().map(|m| m.name).collect()
+}
+
+#[test]
+fn test_families() {
+    use unarm::v6k::arm::Opcode;
+
+    for flags in [UNIFIED, DIVIDED] {
+        // Base first for load/store multiple
+        assert_eq!(names(Opcode::Ldm.arg_meta(&flags)), ["Rn", "registers"]);
+        assert_eq!(names(Opcode::LdmW.arg_meta(&flags)), ["Rn_wb", "registers"]);
+        assert_eq!(names(Opcode::StmP.arg_meta(&flags)), ["Rn", "registers_c"]);
+        // Destination first for data processing
+        assert_eq!(names(&Opcode::Add.arg_meta(&flags)[..2]), ["Rd", "Rn"]);
+        // Result first for strex
+        assert_eq!(names(Opcode::Strex.arg_meta(&flags)), ["Rd", "Rm", "Rn_deref"]);
+        // Destination first for multiplies, low half first for long multiplies
+        assert_eq!(names(Opcode::Mul.arg_meta(&flags)), ["RdHi", "Rm", "Rs"]);
+        assert_eq!(names(Opcode::Mla.arg_meta(&flags)), ["RdHi", "Rm", "Rs", "Rn_12"]);
+        assert_eq!(names(Opcode::Smla.arg_meta(&flags)), ["RdHi", "Rm", "Rs", "Rn_12"]);
+        assert_eq!(names(Opcode::Umull.arg_meta(&flags)), ["RdLo", "RdHi", "Rm", "Rs"]);
+        assert_eq!(names(Opcode::Umaal.arg_meta(&flags)), ["RdLo", "RdHi", "Rm", "Rs"]);
+    }
+}
+
+macro_rules! check_arm_corpus {
+    ($module:ident, $codes:expr) => {{
+        use unarm::$module::arm::Ins;
+        for code in $codes {
+            for flags in [UNIFIED, DIVIDED] {
+                let ins = Ins::new(code, &flags);
+                let parsed = ins.parse(&flags);
+                if parsed.mnemonic == "<illegal>" {
+                    continue;
+                }
+                let args: Vec<_> = parsed.args_iter().collect();
+                let desc = format!("{} {:08x} {:?} ual={}", stringify!($module), code, ins.op, flags.ual);
+
+                // Required arguments come first, so their positions are stable
+                let meta = ins.op.arg_meta(&flags);
+                for (i, m) in meta.iter().take_while(|m| !m.optional).enumerate() {
+                    let Some(shift) = arm_field_shift(m.name) else {
+                        continue;
+                    };
+                    let Argument::Reg(reg) = args[i] else {
+                        panic!("{desc}: expected {} at {i}, got {:?}", m.name, args[i]);
+                    };
+                    assert_eq!(reg.reg, Register::parse((code >> shift) & 0xf), "{desc}: {} at {i}", m.name);
+                }
+                if let Some(last) = meta.last().filter(|m| m.kind == ArgumentKind::RegList) {
+                    assert!(matches!(args.last(), Some(Argument::RegList(_))), "{desc}: {}", last.name);
+                    assert!(args.len() <= 2, "{desc}");
+                }
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_corpus_positions() {
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    let codes: Vec<u32> = (0..0x20000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect();
+
+    check_arm_corpus!(v4t, codes.iter().copied());
+    check_arm_corpus!(v5te, codes.iter().copied());
+    check_arm_corpus!(v6k, codes.iter().copied());
+}
+
+#[test]
+fn test_thumb_load_store_multiple() {
+    use unarm::v5te::thumb::Ins;
+
+    for flags in [UNIFIED, DIVIDED] {
+        for code in 0xc000..0xd000 {
+            let ins = Ins::new(code, &flags);
+            let parsed = ins.parse(&flags);
+            let args: Vec<_> = parsed.args_iter().collect();
+            let Argument::Reg(base) = args[0] else {
+                panic!("{code:04x}: expected base register, got {:?}", args[0]);
+            };
+            assert_eq!(base.reg, Register::parse((code >> 8) & 7), "{code:04x}");
+            assert!(matches!(args[1], Argument::RegList(_)), "{code:04x}");
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use unarm::{
+    args::{Argument, CoReg, Endian, OffsetImm, Reg, Register, Shift, StatusReg},
+    ParsedIns, RegNames,
+};
+
+#[test]
+fn test_register() {
+    assert_eq!(Register::try_parse(0), Some(Register::R0));
+    assert_eq!(Register::try_parse(15), Some(Register::Pc));
+    assert_eq!(Register::try_parse(16), None);
+    assert_eq!(Register::try_parse(u32::MAX), None);
+    assert_eq!(Register::parse(15), Register::Pc);
+    assert_eq!(Register::parse(16), Register::Illegal);
+}
+
+#[test]
+fn test_co_reg() {
+    assert_eq!(CoReg::try_parse(0), Some(CoReg::C0));
+    assert_eq!(CoReg::try_parse(15), Some(CoReg::C15));
+    assert_eq!(CoReg::try_parse(16), None);
+    assert_eq!(CoReg::parse(15), CoReg::C15);
+    assert_eq!(CoReg::parse(16), CoReg::Illegal);
+}
+
+#[test]
+fn test_shift() {
+    assert_eq!(Shift::try_parse(0), Some(Shift::Lsl));
+    assert_eq!(Shift::try_parse(4), Some(Shift::Rrx));
+    assert_eq!(Shift::try_parse(5), None);
+    assert_eq!(Shift::parse(4), Shift::Rrx);
+    assert_eq!(Shift::parse(5), Shift::Illegal);
+}
+
+#[test]
+fn test_status_reg() {
+    assert_eq!(StatusReg::try_parse(0), Some(StatusReg::Cpsr));
+    assert_eq!(StatusReg::try_parse(1), Some(StatusReg::Spsr));
+    assert_eq!(StatusReg::try_parse(2), Some(StatusReg::ApsrNzcv));
+    assert_eq!(StatusReg::try_parse(3), None);
+    assert_eq!(StatusReg::parse(1), StatusReg::Spsr);
+    assert_eq!(StatusReg::parse(3), StatusReg::Illegal);
+}
+
+#[test]
+fn test_endian() {
+    assert_eq!(Endian::try_parse(0), Some(Endian::Le));
+    assert_eq!(Endian::try_parse(1), Some(Endian::Be));
+    assert_eq!(Endian::try_parse(2), None);
+    assert_eq!(Endian::parse(1), Endian::Be);
+    assert_eq!(Endian::parse(2), Endian::Illegal);
+}
+
+#[test]
+fn test_display_illegal() {
+    assert_eq!(Register::Illegal.display(RegNames::default()).to_string(), "<illegal>");
+    assert_eq!(CoReg::Illegal.to_string(), "<illegal>");
+    assert_eq!(Shift::Illegal.to_string(), "<illegal>");
+    assert_eq!(StatusReg::Illegal.to_string(), "<illegal>");
+    assert_eq!(Endian::Illegal.to_string(), "<illegal>");
+}
+
+#[test]
+fn test_arg_count() {
+    let parse = |code| {
+        let flags = Default::default();
+        unarm::v6k::arm::Ins::new(code, &flags).parse(&flags)
+    };
+    let reg = |reg, deref| {
+        Argument::Reg(Reg {
+            deref,
+            reg,
+            writeback: false,
+        })
+    };
+
+    // clrex
+    let clrex = parse(0xf57ff01f);
+    assert_eq!(clrex.arg_count(), 0);
+    assert_eq!(clrex.args(), []);
+    assert_eq!(clrex.args_iter().count(), 0);
+    assert_eq!(ParsedIns::default().arg_count(), 0);
+
+    // add r2, r1, r7
+    let add = parse(0xe0812007);
+    assert_eq!(add.arg_count(), 3);
+    assert_eq!(
+        add.args(),
+        [reg(Register::R2, false), reg(Register::R1, false), reg(Register::R7, false)]
+    );
+
+    // ldr r2, [r1, #0xfff], where the memory operand takes two slots
+    let ldr = parse(0xe5912fff);
+    assert_eq!(ldr.arg_count(), 3);
+    assert_eq!(
+        ldr.args(),
+        [
+            reg(Register::R2, false),
+            reg(Register::R1, true),
+            Argument::OffsetImm(OffsetImm {
+                post_indexed: false,
+                value: 0xfff
+            })
+        ]
+    );
+    assert!(ldr.args_iter().eq(ldr.args()));
+}
@@ -0,0 +1,83 @@
+use unarm::{Endian, ParseFlags, ParseMode, ParsedIns};
+
+fn arm(code: u32) -> ParsedIns {
+    use unarm::v5te::arm::Ins;
+    let flags = ParseFlags::default();
+    Ins::new(code, &flags).parse(&flags)
+}
+
+fn thumb(codes: &[u16]) -> ParsedIns {
+    use unarm::v5te::thumb::InsIter;
+    let bytes: Vec<u8> = codes.iter().flat_map(|code| code.to_le_bytes()).collect();
+    let (_, _, parsed) = InsIter::new(&bytes, 0, Endian::Little, ParseFlags::default()).next().unwrap();
+    parsed
+}
+
+#[test]
+fn test_arm() {
+    let address = 0x02000000;
+    let dest = |code| arm(code).branch_destination(address, ParseMode::Arm);
+    assert_eq!(dest(0xea000000), Some(0x02000008)); // b
+    assert_eq!(dest(0x0afffffe), Some(0x02000000)); // beq
+    assert_eq!(dest(0xeb000000), Some(0x02000008)); // bl
+    assert_eq!(dest(0xebfffffc), Some(0x01fffff8)); // bl
+    assert_eq!(dest(0xfa000000), Some(0x02000008)); // blx
+                                                    // The H bit selects the halfword of the Thumb destination
+    assert_eq!(dest(0xfb000000), Some(0x0200000a)); // blx
+    assert_eq!(dest(0xe12fff1e), None); // bx lr
+    assert_eq!(dest(0xe3a00000), None); // mov r0, #0x0
+}
+
+#[test]
+fn test_arm_long_offsets() {
+    // The whole 24-bit offset is used
+    assert_eq!(arm(0xea800000).display(Default::default()).to_string(), "b #-0x1fffff8");
+    assert_eq!(arm(0xea7fffff).display(Default::default()).to_string(), "b #0x2000004");
+    assert_eq!(
+        arm(0xea800000).branch_destination(0x02000000, ParseMode::Arm),
+        Some(0x00000008)
+    );
+}
+
+#[test]
+fn test_thumb() {
+    let address = 0x02000002;
+    let dest = |codes: &[u16]| thumb(codes).branch_destination(address, ParseMode::Thumb);
+    assert_eq!(dest(&[0xe000]), Some(0x02000006)); // b
+    assert_eq!(dest(&[0xd0fe]), Some(0x02000002)); // beq
+    assert_eq!(dest(&[0xf099, 0xf866]), Some(0x020990d2)); // bl
+                                                           // Switches to ARM, so the destination is word-aligned
+    assert_eq!(dest(&[0xf099, 0xe866]), Some(0x020990d0)); // blx
+    assert_eq!(dest(&[0x4770]), None); // bx lr
+}
+
+#[test]
+fn test_wrap_around() {
+    assert_eq!(arm(0xeafffffd).branch_destination(0, ParseMode::Arm), Some(0xfffffffc));
+    assert_eq!(arm(0xea000000).branch_destination(0xfffffff8, ParseMode::Arm), Some(0));
+    assert_eq!(thumb(&[0xe7fc]).branch_destination(0x2, ParseMode::Thumb), Some(0xfffffffe));
+}
+
+#[test]
+fn test_display_with_pc() {
+    let options = Default::default();
+    assert_eq!(
+        arm(0x0a012345)
+            .display_with_pc(options, 0x02000000, ParseMode::Arm)
+            .to_string(),
+        "beq #0x2048d1c"
+    );
+    assert_eq!(
+        thumb(&[0xf099, 0xe866])
+            .display_with_pc(options, 0x02000002, ParseMode::Thumb)
+            .to_string(),
+        "blx #0x20990d0"
+    );
+    // Other arguments are unchanged
+    assert_eq!(
+        arm(0xe5b02268)
+            .display_with_pc(options, 0x02000000, ParseMode::Arm)
+            .to_string(),
+        "ldr r2, [r0, #0x268]!"
+    );
+}
@@ -0,0 +1,47 @@
+use unarm::{ArmVersion, Endian, ParseFlags, ParseMode, ParseStats, ParsedIns, Parser};
+
+fn parser(mode: ParseMode) -> Parser<'static> {
+    Parser::new(ArmVersion::V5Te, mode, 0, Endian::Little, ParseFlags::default(), &[])
+}
+
+#[test]
+fn test_parse_catching() {
+    use unarm::v5te::{arm, thumb};
+
+    let flags = ParseFlags::default();
+    let stats = ParseStats::new();
+    let arm_parser = parser(ParseMode::Arm).with_stats(&stats);
+    for code in [0xe5902268, 0xe12fff1e, 0xe7f000f0] {
+        assert_eq!(arm_parser.parse_catching(code), arm::Ins::new(code, &flags).parse(&flags));
+    }
+    let thumb_parser = parser(ParseMode::Thumb).with_stats(&stats);
+    for code in [0x4157, 0xb510, 0xf000] {
+        assert_eq!(thumb_parser.parse_catching(code), thumb::Ins::new(code, &flags).parse(&flags));
+    }
+    assert_eq!(parser(ParseMode::Data).parse_catching(0x1234).mnemonic, ".word");
+    assert_eq!(stats.caught_panics(), 0);
+}
+
+#[test]
+fn test_panic_is_contained() {
+    let stats = ParseStats::new();
+    let parser = parser(ParseMode::Arm).with_stats(&stats);
+
+    let parsed = parser.catch_parse(0xe5902268, |_, code| panic!("mock parser panicked on {code:#x}"));
+    assert_eq!(parsed, "<illegal>".parse::<ParsedIns>().unwrap());
+    assert_eq!(stats.caught_panics(), 1);
+
+    // A panic after starting to build the result doesn't leak the partial result
+    let parsed = parser.catch_parse(0xe5902268, |parser, code| {
+        let mut partial = parser.parse_catching(code);
+        partial.mnemonic = "partial";
+        panic!("mock parser panicked after writing {partial:?}");
+    });
+    assert_eq!(parsed.mnemonic, "<illegal>");
+    assert_eq!(stats.caught_panics(), 2);
+
+    // Parsers without stats still contain panics
+    let parsed = self::parser(ParseMode::Arm).catch_parse(0, |_, _| panic!("mock parser panicked"));
+    assert_eq!(parsed.mnemonic, "<illegal>");
+    assert_eq!(stats.caught_panics(), 2);
+}
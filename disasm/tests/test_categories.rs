@@ -0,0 +1,135 @@
+use unarm::ParseFlags;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Categories {
+    branch: bool,
+    conditional_branch: bool,
+    load: bool,
+    store: bool,
+    coprocessor: bool,
+    data_processing: bool,
+}
+
+fn categories(names: &[&str]) -> Categories {
+    Categories {
+        branch: names.contains(&"branch"),
+        conditional_branch: names.contains(&"conditional_branch"),
+        load: names.contains(&"load"),
+        store: names.contains(&"store"),
+        coprocessor: names.contains(&"coprocessor"),
+        data_processing: names.contains(&"data_processing"),
+    }
+}
+
+macro_rules! assert_categories {
+    ($module:ident, $mode:ident, $code:expr, $expected:expr) => {{
+        use unarm::$module::$mode::Ins;
+        let op = Ins::new($code, &ParseFlags::default()).op;
+        let actual = Categories {
+            branch: op.is_branch(),
+            conditional_branch: op.is_conditional_branch_capable(),
+            load: op.is_load(),
+            store: op.is_store(),
+            coprocessor: op.is_coprocessor(),
+            data_processing: op.is_data_processing(),
+        };
+        assert_eq!(actual, categories(&$expected), "{} {:?}", stringify!($module), op);
+    }};
+}
+
+macro_rules! test_arm {
+    ($module:ident) => {{
+        // b #0x8
+        assert_categories!($module, arm, 0xea000000, ["branch", "conditional_branch"]);
+        // bx lr
+        assert_categories!($module, arm, 0xe12fff1e, ["branch", "conditional_branch"]);
+        // ldr r2, [r0, #0x268]
+        assert_categories!($module, arm, 0xe5902268, ["load"]);
+        // pop {r4, pc}
+        assert_categories!($module, arm, 0xe8bd8010, ["load"]);
+        // str r2, [r0, #0x268]
+        assert_categories!($module, arm, 0xe5802268, ["store"]);
+        // swp r2, r2, [r1]
+        assert_categories!($module, arm, 0xe1012092, ["load", "store"]);
+        // cdp p0, #0, c0, c0, c0, #0
+        assert_categories!($module, arm, 0xee000000, ["coprocessor"]);
+        // ldc p1, c2, [r3, #-0x1a4]!
+        assert_categories!($module, arm, 0xed332169, ["load", "coprocessor"]);
+        // add r0, r1, r2
+        assert_categories!($module, arm, 0xe0810002, ["data_processing"]);
+        // lsl r0, r1, #0x2 / mov r0, r1, lsl #0x2
+        assert_categories!($module, arm, 0xe1a00101, ["data_processing"]);
+        // mul r0, r1, r0
+        assert_categories!($module, arm, 0xe0000091, []);
+        // swi #0x0
+        assert_categories!($module, arm, 0xef000000, []);
+    }};
+}
+
+macro_rules! test_thumb {
+    ($module:ident) => {{
+        // beq #0x4
+        assert_categories!($module, thumb, 0xd000, ["branch", "conditional_branch"]);
+        // b #0x4
+        assert_categories!($module, thumb, 0xe000, ["branch"]);
+        // bx lr
+        assert_categories!($module, thumb, 0x4770, ["branch"]);
+        // ldr r0, [r1, #0x4]
+        assert_categories!($module, thumb, 0x6848, ["load"]);
+        // ldr r0, [pc, #0x0]
+        assert_categories!($module, thumb, 0x4800, ["load"]);
+        // str r0, [r1, #0x4]
+        assert_categories!($module, thumb, 0x6048, ["store"]);
+        // push {r4, lr}
+        assert_categories!($module, thumb, 0xb510, ["store"]);
+        // adds r0, r1, r2
+        assert_categories!($module, thumb, 0x1888, ["data_processing"]);
+        // cmp r0, #0x1
+        assert_categories!($module, thumb, 0x2801, ["data_processing"]);
+        // muls r0, r1, r0
+        assert_categories!($module, thumb, 0x4348, []);
+    }};
+}
+
+#[test]
+fn test_v4t() {
+    test_arm!(v4t);
+    test_thumb!(v4t);
+}
+
+#[test]
+fn test_v5te() {
+    test_arm!(v5te);
+    test_thumb!(v5te);
+
+    use unarm::v5te::arm::Opcode;
+    // blx #0x8 is unconditional
+    assert!(Opcode::BlxI.is_branch() && !Opcode::BlxI.is_conditional_branch_capable());
+    assert!(Opcode::LdrD.is_load() && Opcode::StrD.is_store());
+    assert!(Opcode::Mcr2.is_coprocessor() && !Opcode::Mcr2.is_load());
+}
+
+#[test]
+fn test_v6k() {
+    test_arm!(v6k);
+    test_thumb!(v6k);
+
+    use unarm::v6k::arm::Opcode;
+    assert!(Opcode::Ldrex.is_load() && Opcode::Strex.is_store());
+    assert!(Opcode::Rfe.is_load() && Opcode::Srs.is_store());
+    assert!(!Opcode::Sxtb.is_data_processing());
+    assert!(!Opcode::Illegal.is_branch());
+    assert!(Opcode::Nop.is_hint() && Opcode::Wfi.is_hint() && Opcode::Dbg.is_hint());
+    assert!(!Opcode::MsrI.is_hint());
+
+    // Unallocated hints execute as NOP
+    let flags = ParseFlags::default();
+    let op = unarm::v6k::arm::Ins::new(0xe320f005, &flags).op;
+    assert_eq!(op, Opcode::Hint);
+    assert!(op.is_hint());
+    assert_eq!(op.describe(), "Unallocated hint, executes as NOP");
+    assert_eq!(unarm::v6k::arm::Ins::new(0xe320f0f0, &flags).op, Opcode::Dbg);
+
+    // The classification methods are usable in constants
+    const { assert!(Opcode::B.is_branch()) };
+}
@@ -0,0 +1,44 @@
+use unarm::ParseFlags;
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+#[test]
+fn test_arm_swi_comment() {
+    use unarm::v5te::arm::Ins;
+
+    for flags in [UNIFIED, DIVIDED] {
+        let parse = |code| Ins::new(code, &flags).parse(&flags);
+        // swi #0x123456
+        assert_eq!(parse(0xef123456).swi_comment(), Some(0x123456));
+        // swine #0xffffff, all 24 bits
+        assert_eq!(parse(0x1fffffff).swi_comment(), Some(0xffffff));
+        assert_eq!(parse(0xef123456).bkpt_immediate(), None);
+        // bkpt #0xffff, all 16 bits
+        assert_eq!(parse(0xe12fff7f).bkpt_immediate(), Some(0xffff));
+        assert_eq!(parse(0xe12fff7f).swi_comment(), None);
+        // mov r0, #0x12
+        assert_eq!(parse(0xe3a00012).swi_comment(), None);
+        assert_eq!(parse(0xe3a00012).bkpt_immediate(), None);
+    }
+}
+
+#[test]
+fn test_thumb_swi_comment() {
+    use unarm::v5te::thumb::Ins;
+
+    for flags in [UNIFIED, DIVIDED] {
+        let parse = |code| Ins::new(code, &flags).parse(&flags);
+        // swi #0xff, all 8 bits
+        assert_eq!(parse(0xdfff).swi_comment(), Some(0xff));
+        assert_eq!(parse(0xdf0b).swi_comment(), Some(0x0b));
+        // bkpt #0xff, all 8 bits
+        assert_eq!(parse(0xbeff).bkpt_immediate(), Some(0xff));
+        assert_eq!(parse(0xbeff).swi_comment(), None);
+        // movs r0, #0x12
+        assert_eq!(parse(0x2012).swi_comment(), None);
+    }
+}
@@ -0,0 +1,128 @@
+use unarm::{args::ArgumentKind, Condition, ParseFlags, ParsedIns};
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+#[test]
+fn test_arm_condition() {
+    use unarm::v5te::arm::Ins;
+
+    for flags in [UNIFIED, DIVIDED] {
+        // adcne r9, r11, r10, lsl #0xc
+        let ins = Ins::new(0x10ab960a, &flags);
+        assert_eq!(ins.condition(), Condition::Ne);
+        assert_eq!(ins.parse(&flags).mnemonic_base(), "adc");
+
+        // add r0, r1, r2
+        let ins = Ins::new(0xe0810002, &flags);
+        assert_eq!(ins.condition(), Condition::Al);
+        assert_eq!(ins.parse(&flags).mnemonic_base(), "add");
+
+        // blx #0x8
+        assert_eq!(Ins::new(0xfa000000, &flags).condition(), Condition::Nv);
+        // bkpt #0x0, which has no condition field
+        assert_eq!(Ins::new(0xe1200070, &flags).condition(), Condition::Al);
+    }
+
+    // ldrbne r0, [r1] / ldrneb r0, [r1]
+    let ins = Ins::new(0x15d10000, &UNIFIED);
+    assert_eq!(ins.parse(&UNIFIED).mnemonic, "ldrbne");
+    assert_eq!(ins.parse(&UNIFIED).mnemonic_base(), "ldrb");
+    assert_eq!(ins.parse(&DIVIDED).mnemonic, "ldrneb");
+    assert_eq!(ins.parse(&DIVIDED).mnemonic_base(), "ldrb");
+    assert_eq!(ins.parse(&UNIFIED).condition(), Condition::Ne);
+    assert_eq!(ins.parse(&DIVIDED).condition(), Condition::Ne);
+
+    // addsgt r0, r0, r1 / addgts r0, r0, r1
+    let ins = Ins::new(0xc0900001, &UNIFIED);
+    assert_eq!(ins.condition(), Condition::Gt);
+    assert_eq!(ins.parse(&UNIFIED).mnemonic_base(), "adds");
+    assert_eq!(ins.parse(&DIVIDED).mnemonic_base(), "adds");
+
+    // teq r0, r1 / teqeq r0, r1, where only the second one has a condition suffix
+    assert_eq!(Ins::new(0xe1300001, &UNIFIED).parse(&UNIFIED).mnemonic_base(), "teq");
+    assert_eq!(Ins::new(0x01300001, &UNIFIED).parse(&UNIFIED).mnemonic_base(), "teq");
+}
+
+#[test]
+fn test_thumb_condition() {
+    use unarm::v5te::thumb::Ins;
+
+    let flags = ParseFlags::default();
+    // bls #0x4
+    let ins = Ins::new(0xd900, &flags);
+    assert_eq!(ins.condition(), Condition::Ls);
+    assert_eq!(ins.parse(&flags).mnemonic_base(), "b");
+    // b #0x4
+    assert_eq!(Ins::new(0xe000, &flags).condition(), Condition::Al);
+    // adds r0, r1, r2
+    let ins = Ins::new(0x1888, &flags);
+    assert_eq!(ins.condition(), Condition::Al);
+    assert_eq!(ins.parse(&flags).mnemonic_base(), "adds");
+}
+
+#[test]
+fn test_suffix() {
+    for value in 0x0..0xe {
+        let cond = Condition::parse(value);
+        assert_eq!(cond as u32, value);
+        assert_eq!(Condition::from_suffix(cond.suffix()), Some(cond));
+    }
+    assert_eq!(Condition::parse(0xe).suffix(), "");
+    assert_eq!(Condition::parse(0xf), Condition::Nv);
+    assert_eq!(Condition::from_suffix(""), None);
+}
+
+#[test]
+fn test_corpus_mnemonic_base() {
+    use unarm::v6k::arm::Ins;
+
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    for _ in 0..0x10000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        for flags in [UNIFIED, DIVIDED] {
+            let ins = Ins::new(state, &flags);
+            let parsed = ins.parse(&flags);
+            match ins.condition() {
+                Condition::Al | Condition::Nv => assert_eq!(parsed.mnemonic_base(), parsed.mnemonic),
+                cond => {
+                    // The same instruction without a condition, unless it's a different instruction such as udf
+                    let always = Ins::new((state & 0x0fffffff) | 0xe0000000, &flags);
+                    if always.op != ins.op {
+                        continue;
+                    }
+                    assert_eq!(parsed.mnemonic_base(), always.parse(&flags).mnemonic, "{state:08x} {cond:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_signature() {
+    let parse = |text: &str| text.parse::<ParsedIns>().unwrap();
+
+    let addne = parse("addne r0, r1, r2");
+    assert!(addne.eq_ignoring_condition(&parse("add r0, r1, r2")));
+    assert!(addne.eq_ignoring_condition(&parse("addeq r0, r1, r2")));
+    assert!(!addne.eq_ignoring_condition(&parse("addeq r3, r4, r5")));
+    assert!(!addne.eq_ignoring_condition(&parse("addsne r0, r1, r2")));
+
+    let signature = addne.signature();
+    assert_eq!(signature, parse("addeq r3, r4, r5").signature());
+    assert_eq!(signature.mnemonic, "add");
+    assert_eq!(
+        signature.kinds[..4],
+        [ArgumentKind::Reg, ArgumentKind::Reg, ArgumentKind::Reg, ArgumentKind::None]
+    );
+    assert_ne!(signature, parse("addne r0, r1, #0x4").signature());
+    assert_ne!(signature, parse("subne r0, r1, r2").signature());
+    // Divided syntax puts the condition before the size suffix
+    assert_eq!(parse("ldrneb r0, [r1]").signature(), parse("ldrb r2, [r3]").signature());
+}
@@ -0,0 +1,87 @@
+use unarm::{
+    args::CoReg,
+    coproc::{find_unimplemented_accesses, Arm1176jzfs, Arm946es, CoprocAccess},
+    v5te::arm::Ins,
+    ArmVersion, Endian, ParseFlags, ParseMode, ParsedIns, Parser,
+};
+
+fn parse(code: u32) -> ParsedIns {
+    let flags = ParseFlags::default();
+    Ins::new(code, &flags).parse(&flags)
+}
+
+#[test]
+fn test_coproc_access() {
+    // mrc p15, #0, r0, c15, c0, #0
+    assert_eq!(
+        parse(0xee1f0f10).coproc_access(),
+        Some(CoprocAccess {
+            coproc: 15,
+            opc1: 0,
+            crn: CoReg::C15,
+            crm: CoReg::C0,
+            opc2: 0,
+            read: true,
+        })
+    );
+    // mcr p15, #0, r0, c7, c10, #4
+    let access = parse(0xee070f9a).coproc_access().unwrap();
+    assert_eq!(
+        (access.crn, access.crm, access.opc2, access.read),
+        (CoReg::C7, CoReg::C10, 4, false)
+    );
+    // mov r0, r1
+    assert_eq!(parse(0xe1a00001).coproc_access(), None);
+}
+
+#[test]
+fn test_arm946es() {
+    let model = Arm946es;
+    // The ARM946E-S has no c15 test registers
+    assert!(parse(0xee1f0f10).unimplemented_coproc_access(&model).is_some());
+    // Nor an MMU, so no TLB operations
+    assert!(parse(0xee080f17).unimplemented_coproc_access(&model).is_some());
+    for code in [
+        // mrc p15, #0, r0, c0, c0, #0
+        0xee100f10, // mcr p15, #0, r0, c7, c5, #0
+        0xee070f15, // mcr p15, #0, r0, c7, c14, #1
+        0xee070f3e, // mcr p15, #0, r0, c7, c10, #4
+        0xee070f9a, // mcr p15, #0, r0, c6, c7, #0
+        0xee060f17, // mcr p15, #0, r0, c9, c1, #0
+        0xee090f11, // mcr p14, #0, r0, c1, c0, #0
+        0xee010e10,
+    ] {
+        assert_eq!(parse(code).unimplemented_coproc_access(&model), None, "{code:#x}");
+    }
+}
+
+#[test]
+fn test_arm1176jzfs() {
+    let model = Arm1176jzfs;
+    // mcr p15, #0, r0, c8, c7, #0
+    assert_eq!(parse(0xee080f17).unimplemented_coproc_access(&model), None);
+    // mcr p15, #0, r0, c7, c10, #5
+    assert_eq!(parse(0xee070fba).unimplemented_coproc_access(&model), None);
+    // mcr p15, #0, r0, c6, c7, #0 is a protection region of the ARM946E-S
+    assert!(parse(0xee060f17).unimplemented_coproc_access(&model).is_some());
+}
+
+#[test]
+fn test_find_unimplemented_accesses() {
+    let code: Vec<u8> = [0xee070f15u32, 0xe1a00001, 0xee1f0f10, 0xee070f9a]
+        .iter()
+        .flat_map(|code| code.to_le_bytes())
+        .collect();
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0x2000000,
+        Endian::Little,
+        ParseFlags::default(),
+        &code,
+    );
+    let found = find_unimplemented_accesses(parser, &Arm946es);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0, 0x2000008);
+    assert_eq!(found[0].1.crn, CoReg::C15);
+}
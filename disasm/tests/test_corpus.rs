@@ -0,0 +1,55 @@
+use unarm::{
+    corpus::{FrequencyModel, MODEL, SEED},
+    v5te::arm::{Ins, Opcode},
+    Endian, ParseFlags,
+};
+
+#[test]
+fn test_builtin_model() {
+    let model = FrequencyModel::parse(MODEL).unwrap();
+    assert!(model.len() > 100);
+    assert!(model.count(Opcode::Ldr) > model.count(Opcode::Mcr));
+    // Writing and parsing a model keeps it the same
+    let text = model.to_string();
+    assert_eq!(FrequencyModel::parse(&text).unwrap().to_string(), text);
+}
+
+#[test]
+fn test_generate() {
+    let model = FrequencyModel::builtin();
+    let code = model.generate(SEED, 0x1000);
+    assert_eq!(code.len(), 0x4000);
+    assert_eq!(model.generate(SEED, 0x1000), code);
+    assert_ne!(model.generate(SEED + 1, 0x1000), code);
+
+    // Every instruction is one of the model's opcodes, with about the same frequency
+    let generated = FrequencyModel::observe(&code, Endian::Little);
+    assert_eq!(generated.len(), 0x1000);
+    let flags = ParseFlags::default();
+    for chunk in code.chunks_exact(4) {
+        let ins = Ins::from_bytes(chunk, Endian::Little, &flags).unwrap();
+        assert_ne!(model.count(ins.op), 0, "{:#010x}", ins.code);
+    }
+    let share = |model: &FrequencyModel, op| model.count(op) as f64 / model.len() as f64;
+    assert!((share(&generated, Opcode::Ldr) - share(&model, Opcode::Ldr)).abs() < 0.05);
+}
+
+#[test]
+fn test_observe() {
+    // ldr r0, [r1, #0x4] / ldr r2, [r1, #0x4] / bx lr
+    let code = [0xe5910004u32, 0xe5912004, 0xe12fff1e];
+    let bytes: Vec<u8> = code.iter().flat_map(|code| code.to_le_bytes()).collect();
+    let model = FrequencyModel::observe(&bytes, Endian::Little);
+    assert_eq!((model.len(), model.count(Opcode::Ldr), model.count(Opcode::Bx)), (3, 2, 1));
+    let text = model.to_string();
+    assert!(text.starts_with("op Ldr 2\nop Bx 1\n"), "{text}");
+    assert!(text.contains("field Rn_deref 0x1 2\n"), "{text}");
+
+    let regenerated = FrequencyModel::observe(&model.generate(1, 0x100), Endian::Little);
+    assert_eq!(regenerated.count(Opcode::Ldr) + regenerated.count(Opcode::Bx), 0x100);
+
+    assert_eq!(
+        FrequencyModel::parse("op Nope 1"),
+        Err("line 1: unknown opcode: 'op Nope 1'".to_string())
+    );
+}
@@ -0,0 +1,56 @@
+use serde_json::Value;
+use unarm::ParseFlags;
+
+/// Decodes `code` in the module at `module` (e.g. `v5te/arm`) and returns the name of its opcode
+fn decode(module: &str, code: u32, ual: bool) -> String {
+    let flags = ParseFlags {
+        ual,
+        ..Default::default()
+    };
+    match module {
+        "v4t/arm" => format!("{:?}", unarm::v4t::arm::Ins::new(code, &flags).op),
+        "v4t/thumb" => format!("{:?}", unarm::v4t::thumb::Ins::new(code, &flags).op),
+        "v5te/arm" => format!("{:?}", unarm::v5te::arm::Ins::new(code, &flags).op),
+        "v5te/thumb" => format!("{:?}", unarm::v5te::thumb::Ins::new(code, &flags).op),
+        "v6k/arm" => format!("{:?}", unarm::v6k::arm::Ins::new(code, &flags).op),
+        "v6k/thumb" => format!("{:?}", unarm::v6k::thumb::Ins::new(code, &flags).op),
+        _ => panic!("unknown module {module}"),
+    }
+}
+
+/// Reference instructions which no opcode implements yet, as `module: name`. Keep this in sync with
+/// `specs/COVERAGE.md` when implementing an instruction.
+const KNOWN_MISSING: &[&str] = &[];
+
+#[test]
+fn test_covered_instructions_decode() {
+    // The checklist is generated together with the decoders, and the generator refuses to drop a covered instruction.
+    // This checks that the decoders still agree with it: some opcode of each instruction decodes from its fixed bits.
+    // Not every opcode does, e.g. `msr` with an immediate and no fields to write is `nop`.
+    let coverage: Value = serde_json::from_str(include_str!("../../specs/coverage.json")).unwrap();
+    let modules = coverage.as_object().unwrap();
+    assert_eq!(modules.len(), 6);
+    let mut missing = vec![];
+    for (module, isa) in modules {
+        let instructions = isa["instructions"].as_array().unwrap();
+        assert!(!instructions.is_empty(), "{module}");
+        for instruction in instructions {
+            let name = instruction["name"].as_str().unwrap();
+            if instruction["status"] == "missing" {
+                missing.push(format!("{module}: {name}"));
+                continue;
+            }
+
+            let opcodes = instruction["opcodes"].as_array().unwrap();
+            let names: Vec<_> = opcodes.iter().map(|op| op["name"].as_str().unwrap()).collect();
+            let decodes = opcodes.iter().any(|op| {
+                let pattern = op["pattern"].as_u64().unwrap() as u32;
+                [false, true]
+                    .into_iter()
+                    .any(|ual| names.contains(&decode(module, pattern, ual).as_str()))
+            });
+            assert!(decodes, "{module}: {name} is not decoded by any of {names:?}");
+        }
+    }
+    assert_eq!(missing, KNOWN_MISSING);
+}
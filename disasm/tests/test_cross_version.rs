@@ -0,0 +1,170 @@
+//! Decodes a shared corpus in every version and reports instructions which are legal in several versions but are
+//! displayed differently, except for the intentional differences in `cross-version-allowlist.txt`. Run
+//! `cargo xtask cross-version` to print every difference, including allowed ones.
+
+use std::collections::BTreeMap;
+
+use unarm::{enumerate::FieldFilter, ParseFlags};
+
+const VERSIONS: [&str; 3] = ["v4t", "v5te", "v6k"];
+
+/// Decodes `code` in one version and returns its opcode variant name and text, or `None` if it's illegal
+type Decoder = fn(u32, &ParseFlags) -> Option<(&'static str, String)>;
+
+macro_rules! decoder {
+    ($version:ident, $mode:ident) => {
+        |code, flags| {
+            use unarm::$version::$mode::{Ins, Opcode};
+            let ins = Ins::new(code, flags);
+            let text = ins.parse(flags).display(Default::default()).to_string();
+            (ins.op != Opcode::Illegal && text != "<illegal>").then(|| (ins.op.variant_name(), text))
+        }
+    };
+}
+
+macro_rules! patterns {
+    ($version:ident, $mode:ident, $path:literal) => {{
+        use unarm::$version::$mode::Opcode;
+        variant_names(include_str!($path))
+            .filter_map(|name| Opcode::from_variant_name(name))
+            .flat_map(|op| {
+                // Every field zero and every field ones
+                let ones = op
+                    .field_descs()
+                    .iter()
+                    .fold(FieldFilter::new(), |filter, field| filter.pin(field.name, u32::MAX));
+                [false, true].into_iter().flat_map(move |ual| {
+                    let flags = ParseFlags {
+                        ual,
+                        ..Default::default()
+                    };
+                    [FieldFilter::new(), ones.clone()]
+                        .into_iter()
+                        .filter_map(move |filter| op.enumerate(&filter, &flags).next())
+                })
+            })
+            .collect::<Vec<_>>()
+    }};
+}
+
+struct Mode {
+    name: &'static str,
+    decoders: [Decoder; 3],
+    corpus: Vec<u32>,
+}
+
+fn variant_names(snapshot: &str) -> impl Iterator<Item = &str> {
+    snapshot.lines().filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Returns the code of every `assert_asm!` in a test file
+fn test_codes(source: &str) -> impl Iterator<Item = u32> + '_ {
+    source.lines().filter_map(|line| {
+        let (_module, args) = line.trim().strip_prefix("assert_asm!(")?.split_once(", ")?;
+        let (code, _) = args.split_once(", ")?;
+        u32::from_str_radix(code.strip_prefix("0x")?, 16).ok()
+    })
+}
+
+fn modes() -> [Mode; 2] {
+    let corpus = |sources: [&str; 3], patterns: [Vec<u32>; 3]| {
+        let mut corpus: Vec<u32> = sources
+            .into_iter()
+            .flat_map(test_codes)
+            .chain(patterns.into_iter().flatten())
+            .collect();
+        corpus.sort_unstable();
+        corpus.dedup();
+        corpus
+    };
+    [
+        Mode {
+            name: "arm",
+            decoders: [decoder!(v4t, arm), decoder!(v5te, arm), decoder!(v6k, arm)],
+            corpus: corpus(
+                [
+                    include_str!("test_arm_v4t.rs"),
+                    include_str!("test_arm_v5te.rs"),
+                    include_str!("test_arm_v6k.rs"),
+                ],
+                [
+                    patterns!(v4t, arm, "../../specs/v4t/arm.variants.txt"),
+                    patterns!(v5te, arm, "../../specs/v5te/arm.variants.txt"),
+                    patterns!(v6k, arm, "../../specs/v6k/arm.variants.txt"),
+                ],
+            ),
+        },
+        Mode {
+            name: "thumb",
+            decoders: [decoder!(v4t, thumb), decoder!(v5te, thumb), decoder!(v6k, thumb)],
+            corpus: corpus(
+                [
+                    include_str!("test_thumb_v4t.rs"),
+                    include_str!("test_thumb_v5te.rs"),
+                    include_str!("test_thumb_v6k.rs"),
+                ],
+                [
+                    patterns!(v4t, thumb, "../../specs/v4t/thumb.variants.txt"),
+                    patterns!(v5te, thumb, "../../specs/v5te/thumb.variants.txt"),
+                    patterns!(v6k, thumb, "../../specs/v6k/thumb.variants.txt"),
+                ],
+            ),
+        },
+    ]
+}
+
+#[test]
+fn test_cross_version() {
+    let report = std::env::var_os("UNARM_CROSS_VERSION_REPORT").is_some();
+    let allowlist: Vec<&str> = include_str!("cross-version-allowlist.txt")
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    // Examples of each difference, by mode, versions and opcode variant of the older version
+    let mut diffs: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for mode in modes() {
+        for ual in [false, true] {
+            let flags = ParseFlags {
+                ual,
+                ..Default::default()
+            };
+            for &code in mode.corpus.iter() {
+                let decoded = mode.decoders.map(|decode| decode(code, &flags));
+                for old in 0..VERSIONS.len() {
+                    for new in old + 1..VERSIONS.len() {
+                        let (Some((op, old_text)), Some((_, new_text))) = (&decoded[old], &decoded[new]) else {
+                            continue;
+                        };
+                        if old_text != new_text {
+                            let key = format!("{} {} {} {op}", mode.name, VERSIONS[old], VERSIONS[new]);
+                            diffs
+                                .entry(key)
+                                .or_default()
+                                .push(format!("{code:#010x}: '{old_text}' != '{new_text}'"));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let unexpected: Vec<_> = diffs.keys().filter(|key| !allowlist.contains(&key.as_str())).collect();
+    for (key, examples) in diffs.iter() {
+        if !report && allowlist.contains(&key.as_str()) {
+            continue;
+        }
+        let allowed = if allowlist.contains(&key.as_str()) { ", allowed" } else { "" };
+        println!("{key} ({} codes{allowed})", examples.len());
+        for example in examples.iter().take(if report { usize::MAX } else { 3 }) {
+            println!("    {example}");
+        }
+    }
+    let stale: Vec<_> = allowlist.iter().filter(|entry| !diffs.contains_key(**entry)).collect();
+    assert!(
+        unexpected.is_empty(),
+        "Versions display the same codes differently: {unexpected:?}"
+    );
+    assert!(stale.is_empty(), "Allowlist entries without a difference: {stale:?}");
+}
@@ -1,13 +1,11 @@
-use unarm::{v6k::arm::Ins, DisplayOptions, R9Use, RegNames};
+use std::borrow::Cow;
 
-macro_rules! assert_asm {
-    ($code:literal, $options:expr, $disasm:literal) => {{
-        let flags = Default::default();
-        let ins = Ins::new($code, &flags);
-        let parsed = ins.parse(&flags);
-        assert_eq!(parsed.display($options).to_string(), $disasm)
-    }};
-}
+use unarm::{
+    args::{Argument, Reg, Register},
+    testing::assert_asm,
+    v6k::arm::Ins,
+    DisplayOptions, IllegalStyle, ImmediateStyle, OperandSeparator, ParseMode, ParsedIns, R9Use, RegNames, TokenKind,
+};
 
 #[test]
 pub fn test_av() {
@@ -16,13 +14,14 @@ pub fn test_av() {
             av_registers: true,
             ..Default::default()
         },
+        ..Default::default()
     };
-    assert_asm!(0xe0812007, options, "add a3, a2, v4");
-    assert_asm!(0xe1d52153, options, "bics a3, v2, a4, asr a2");
-    assert_asm!(0x4d332169, options, "ldcmi p1, c2, [a4, #-0x1a4]!");
-    assert_asm!(0xe8b25555, options, "ldm a3!, {a1, a3, v1, v3, v5, v7, r12, lr}");
-    assert_asm!(0xe7312063, options, "ldr a3, [a2, -a4, rrx]!");
-    assert_asm!(0xe00120d3, options, "ldrd a3, a4, [a2], -a4");
+    assert_asm!(v6k::arm, 0xe0812007, "add a3, a2, v4", options: options);
+    assert_asm!(v6k::arm, 0xe1d52153, "bics a3, v2, a4, asr a2", options: options);
+    assert_asm!(v6k::arm, 0x4d332169, "ldcmi p1, c2, [a4, #-0x1a4]!", options: options);
+    assert_asm!(v6k::arm, 0xe8b25555, "ldm a3!, {a1, a3, v1, v3, v5, v7, r12, lr}", options: options);
+    assert_asm!(v6k::arm, 0xe7312063, "ldr a3, [a2, -a4, rrx]!", options: options);
+    assert_asm!(v6k::arm, 0xe00120d3, "ldrd a3, a4, [a2], -a4", options: options);
 }
 
 #[test]
@@ -32,30 +31,33 @@ pub fn test_r9() {
             r9_use: R9Use::Pid,
             ..Default::default()
         },
+        ..Default::default()
     };
     let tls = DisplayOptions {
         reg_names: RegNames {
             r9_use: R9Use::Tls,
             ..Default::default()
         },
+        ..Default::default()
     };
     let v6 = DisplayOptions {
         reg_names: RegNames {
             av_registers: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     let r9 = Default::default();
 
-    assert_asm!(0x102b960a, pid, "eorne sb, r11, r10, lsl #0xc");
-    assert_asm!(0x102b960a, tls, "eorne tr, r11, r10, lsl #0xc");
-    assert_asm!(0x102b960a, v6, "eorne v6, v8, v7, lsl #0xc");
-    assert_asm!(0x102b960a, r9, "eorne r9, r11, r10, lsl #0xc");
+    assert_asm!(v6k::arm, 0x102b960a, "eorne sb, r11, r10, lsl #0xc", options: pid);
+    assert_asm!(v6k::arm, 0x102b960a, "eorne tr, r11, r10, lsl #0xc", options: tls);
+    assert_asm!(v6k::arm, 0x102b960a, "eorne v6, v8, v7, lsl #0xc", options: v6);
+    assert_asm!(v6k::arm, 0x102b960a, "eorne r9, r11, r10, lsl #0xc", options: r9);
 
-    assert_asm!(0xe831aaaa, pid, "ldmda r1!, {r1, r3, r5, r7, sb, r11, sp, pc}");
-    assert_asm!(0xe831aaaa, tls, "ldmda r1!, {r1, r3, r5, r7, tr, r11, sp, pc}");
-    assert_asm!(0xe831aaaa, v6, "ldmda a2!, {a2, a4, v2, v4, v6, v8, sp, pc}");
-    assert_asm!(0xe831aaaa, r9, "ldmda r1!, {r1, r3, r5, r7, r9, r11, sp, pc}");
+    assert_asm!(v6k::arm, 0xe831aaaa, "ldmda r1!, {r1, r3, r5, r7, sb, r11, sp, pc}", options: pid);
+    assert_asm!(v6k::arm, 0xe831aaaa, "ldmda r1!, {r1, r3, r5, r7, tr, r11, sp, pc}", options: tls);
+    assert_asm!(v6k::arm, 0xe831aaaa, "ldmda a2!, {a2, a4, v2, v4, v6, v8, sp, pc}", options: v6);
+    assert_asm!(v6k::arm, 0xe831aaaa, "ldmda r1!, {r1, r3, r5, r7, r9, r11, sp, pc}", options: r9);
 }
 
 #[test]
@@ -65,22 +67,24 @@ pub fn test_r10() {
             explicit_stack_limit: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     let v7 = DisplayOptions {
         reg_names: RegNames {
             av_registers: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     let r10 = Default::default();
 
-    assert_asm!(0x10ab960a, sl, "adcne r9, r11, sl, lsl #0xc");
-    assert_asm!(0x10ab960a, v7, "adcne v6, v8, v7, lsl #0xc");
-    assert_asm!(0x10ab960a, r10, "adcne r9, r11, r10, lsl #0xc");
+    assert_asm!(v6k::arm, 0x10ab960a, "adcne r9, r11, sl, lsl #0xc", options: sl);
+    assert_asm!(v6k::arm, 0x10ab960a, "adcne v6, v8, v7, lsl #0xc", options: v7);
+    assert_asm!(v6k::arm, 0x10ab960a, "adcne r9, r11, r10, lsl #0xc", options: r10);
 
-    assert_asm!(0xb8b25555, sl, "ldmlt r2!, {r0, r2, r4, r6, r8, sl, r12, lr}");
-    assert_asm!(0xb8b25555, v7, "ldmlt a3!, {a1, a3, v1, v3, v5, v7, r12, lr}");
-    assert_asm!(0xb8b25555, r10, "ldmlt r2!, {r0, r2, r4, r6, r8, r10, r12, lr}");
+    assert_asm!(v6k::arm, 0xb8b25555, "ldmlt r2!, {r0, r2, r4, r6, r8, sl, r12, lr}", options: sl);
+    assert_asm!(v6k::arm, 0xb8b25555, "ldmlt a3!, {a1, a3, v1, v3, v5, v7, r12, lr}", options: v7);
+    assert_asm!(v6k::arm, 0xb8b25555, "ldmlt r2!, {r0, r2, r4, r6, r8, r10, r12, lr}", options: r10);
 }
 
 #[test]
@@ -90,22 +94,24 @@ pub fn test_r11() {
             frame_pointer: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     let v8 = DisplayOptions {
         reg_names: RegNames {
             av_registers: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     let r11 = Default::default();
 
-    assert_asm!(0x106b960a, fp, "rsbne r9, fp, r10, lsl #0xc");
-    assert_asm!(0x106b960a, v8, "rsbne v6, v8, v7, lsl #0xc");
-    assert_asm!(0x106b960a, r11, "rsbne r9, r11, r10, lsl #0xc");
+    assert_asm!(v6k::arm, 0x106b960a, "rsbne r9, fp, r10, lsl #0xc", options: fp);
+    assert_asm!(v6k::arm, 0x106b960a, "rsbne v6, v8, v7, lsl #0xc", options: v8);
+    assert_asm!(v6k::arm, 0x106b960a, "rsbne r9, r11, r10, lsl #0xc", options: r11);
 
-    assert_asm!(0xd903cccc, fp, "stmdble r3, {r2, r3, r6, r7, r10, fp, lr, pc}");
-    assert_asm!(0xd903cccc, v8, "stmdble a4, {a3, a4, v3, v4, v7, v8, lr, pc}");
-    assert_asm!(0xd903cccc, r11, "stmdble r3, {r2, r3, r6, r7, r10, r11, lr, pc}");
+    assert_asm!(v6k::arm, 0xd903cccc, "stmdble r3, {r2, r3, r6, r7, r10, fp, lr, pc}", options: fp);
+    assert_asm!(v6k::arm, 0xd903cccc, "stmdble a4, {a3, a4, v3, v4, v7, v8, lr, pc}", options: v8);
+    assert_asm!(v6k::arm, 0xd903cccc, "stmdble r3, {r2, r3, r6, r7, r10, r11, lr, pc}", options: r11);
 }
 
 #[test]
@@ -115,12 +121,457 @@ pub fn test_r12() {
             ip: true,
             ..Default::default()
         },
+        ..Default::default()
     };
     let r12 = Default::default();
 
-    assert_asm!(0x708c046e, ip, "addvc r0, ip, lr, ror #0x8");
-    assert_asm!(0x708c046e, r12, "addvc r0, r12, lr, ror #0x8");
+    assert_asm!(v6k::arm, 0x708c046e, "addvc r0, ip, lr, ror #0x8", options: ip);
+    assert_asm!(v6k::arm, 0x708c046e, "addvc r0, r12, lr, ror #0x8", options: r12);
+
+    assert_asm!(v6k::arm, 0xb8a25555, "stmlt r2!, {r0, r2, r4, r6, r8, r10, ip, lr}", options: ip);
+    assert_asm!(v6k::arm, 0xb8a25555, "stmlt r2!, {r0, r2, r4, r6, r8, r10, r12, lr}", options: r12);
+}
+
+/// Renames `bl` to `call` and `bx lr` to `ret`, keeping the condition suffix
+/// Parses a register list such as `{r1, r3-r7, r9}^` back to its bitfield
+fn parse_reg_list(text: &str) -> u32 {
+    let reg = |name: &str| match name {
+        "sp" => 13,
+        "lr" => 14,
+        "pc" => 15,
+        _ => name.strip_prefix('r').unwrap().parse::<u32>().unwrap(),
+    };
+    let inner = text
+        .trim_end_matches('^')
+        .strip_prefix('{')
+        .unwrap()
+        .strip_suffix('}')
+        .unwrap();
+    inner
+        .split(", ")
+        .map(|item| match item.split_once('-') {
+            Some((first, last)) => (reg(first)..=reg(last)).fold(0, |acc, r| acc | 1 << r),
+            None => 1 << reg(item),
+        })
+        .fold(0, |acc, bits| acc | bits)
+}
+
+#[test]
+pub fn test_reg_list_ranges() {
+    let ranges = DisplayOptions {
+        reg_list_ranges: true,
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xe83102fa, "ldmda r1!, {r1, r3-r7, r9}", options: ranges);
+    assert_asm!(v6k::arm, 0xe92d40f0, "push {r4-r7, lr}", options: ranges);
+    assert_asm!(v6k::arm, 0xe8bd8ff0, "pop {r4-r11, pc}", options: ranges);
+    // Two consecutive registers are not a range
+    assert_asm!(v6k::arm, 0xe92d4030, "push {r4, r5, lr}", options: ranges);
+    // sp, lr and pc have special names
+    assert_asm!(v6k::arm, 0xe8d0e000, "ldm r0, {sp, lr, pc}^", options: ranges);
+    assert_asm!(v6k::arm, 0xe8dfffff, "ldm pc, {r0-r12, sp, lr, pc}^", options: ranges);
+    // Disabled by default
+    assert_asm!(v6k::arm, 0xe92d40f0, "push {r4, r5, r6, r7, lr}");
+
+    // Special register names break ranges
+    let fp = DisplayOptions {
+        reg_names: RegNames {
+            frame_pointer: true,
+            r9_use: R9Use::Pid,
+            ..Default::default()
+        },
+        reg_list_ranges: true,
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xe8bd8ff0, "pop {r4-r8, sb, r10, fp, pc}", options: fp);
+    let av = DisplayOptions {
+        reg_names: RegNames {
+            av_registers: true,
+            ..Default::default()
+        },
+        reg_list_ranges: true,
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xe8bd8fff, "pop {a1-a4, v1-v8, pc}", options: av);
+
+    // Round trip
+    let flags = Default::default();
+    for code in [
+        0xe83102fa, 0xe92d40f0, 0xe8bd8ff0, 0xe8d0e000, 0xe8dfffff, 0xe8b25555, 0xe8907fbe,
+    ] {
+        let parsed = Ins::new(code, &flags).parse(&flags);
+        let arg = parsed.args_iter().find(|arg| matches!(arg, Argument::RegList(_))).unwrap();
+        let Argument::RegList(list) = arg else { unreachable!() };
+        let text = arg.display(ranges).to_string();
+        assert_eq!(parse_reg_list(&text), list.regs, "{code:08x}: {text}");
+        assert_eq!(text.ends_with('^'), list.user_mode, "{code:08x}: {text}");
+    }
+}
+
+fn house_style(ins: &ParsedIns) -> Option<Cow<'static, str>> {
+    let suffix = ins.condition().suffix();
+    let lr = Argument::Reg(Reg {
+        deref: false,
+        reg: Register::Lr,
+        writeback: false,
+    });
+    match ins.mnemonic_base() {
+        "bl" => Some(format!("call{suffix}").into()),
+        "bx" if ins.args[0] == lr => Some(format!("ret{suffix}").into()),
+        _ => None,
+    }
+}
+
+#[test]
+pub fn test_mnemonic_hook() {
+    let house = DisplayOptions {
+        mnemonic_hook: Some(&house_style),
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xebffffec, "call #-0x48", options: house);
+    assert_asm!(v6k::arm, 0x0bffffec, "calleq #-0x48", options: house);
+    assert_asm!(v6k::arm, 0xe12fff1e, "ret lr", options: house);
+    assert_asm!(v6k::arm, 0x112fff1e, "retne lr", options: house);
+    // Falls back to the default mnemonic
+    assert_asm!(v6k::arm, 0xe12fff10, "bx r0", options: house);
+    assert_asm!(v6k::arm, 0x10ab960a, "adcne r9, r11, r10, lsl #0xc", options: house);
+
+    // Drops the condition suffix
+    let unconditional = DisplayOptions {
+        mnemonic_hook: Some(&|ins: &ParsedIns| (ins.mnemonic_base() == "bl").then_some("call".into())),
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0x0bffffec, "call #-0x48", options: unconditional);
+
+    let upper = DisplayOptions {
+        mnemonic_hook: Some(&|ins: &ParsedIns| Some(ins.mnemonic.to_uppercase().into())),
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xd903cccc, "STMDBLE r3, {r2, r3, r6, r7, r10, r11, lr, pc}", options: upper);
+}
+
+#[test]
+pub fn test_mnemonic_hook_tokens() {
+    let house = DisplayOptions {
+        mnemonic_hook: Some(&house_style),
+        ..Default::default()
+    };
+    let flags = Default::default();
+    let parsed = Ins::new(0x0bffffec, &flags).parse(&flags);
+    let tokens = parsed.display(house).tokens();
+    assert_eq!(tokens.text, "calleq #-0x48");
+    assert_eq!(tokens.tokens[0].kind, TokenKind::Mnemonic);
+    assert_eq!(tokens.text(&tokens.tokens[0]), "calleq");
+    assert_eq!(tokens.arg_at(tokens.text.find('#').unwrap()), Some(0));
+}
+
+#[test]
+pub fn test_immediate_style() {
+    let decimal = DisplayOptions {
+        immediate_style: ImmediateStyle::Decimal,
+        ..Default::default()
+    };
+    let threshold = DisplayOptions {
+        immediate_style: ImmediateStyle::HexAboveThreshold(9),
+        ..Default::default()
+    };
+    let flags = Default::default();
+    for (code, hex, dec, thr) in [
+        // UImm
+        (0xe2810c01, "add r0, r1, #0x100", "add r0, r1, #256", "add r0, r1, #0x100"),
+        (0xe2810009, "add r0, r1, #0x9", "add r0, r1, #9", "add r0, r1, #9"),
+        // OffsetImm
+        (
+            0xe5110010,
+            "ldr r0, [r1, #-0x10]",
+            "ldr r0, [r1, #-16]",
+            "ldr r0, [r1, #-0x10]",
+        ),
+        (0xe4910004, "ldr r0, [r1], #0x4", "ldr r0, [r1], #4", "ldr r0, [r1], #4"),
+        // ShiftImm
+        (0xe1a00281, "lsl r0, r1, #0x5", "lsl r0, r1, #5", "lsl r0, r1, #5"),
+        (
+            0xe0810a62,
+            "add r0, r1, r2, ror #0x14",
+            "add r0, r1, r2, ror #20",
+            "add r0, r1, r2, ror #0x14",
+        ),
+        (
+            0xe6af0c71,
+            "sxtb r0, r1, ror #0x18",
+            "sxtb r0, r1, ror #24",
+            "sxtb r0, r1, ror #0x18",
+        ),
+        (
+            0xe6a12c73,
+            "sxtab r2, r1, r3, ror #0x18",
+            "sxtab r2, r1, r3, ror #24",
+            "sxtab r2, r1, r3, ror #0x18",
+        ),
+        (
+            0xe6812893,
+            "pkhbt r2, r1, r3, lsl #0x11",
+            "pkhbt r2, r1, r3, lsl #17",
+            "pkhbt r2, r1, r3, lsl #0x11",
+        ),
+        (
+            0xe6812053,
+            "pkhtb r2, r1, r3, asr #0x20",
+            "pkhtb r2, r1, r3, asr #32",
+            "pkhtb r2, r1, r3, asr #0x20",
+        ),
+        (
+            0xe6b94a53,
+            "ssat r4, #0x1a, r3, asr #0x14",
+            "ssat r4, #26, r3, asr #20",
+            "ssat r4, #0x1a, r3, asr #0x14",
+        ),
+        // SatImm
+        (0xe6ef0011, "usat r0, #0xf, r1", "usat r0, #15, r1", "usat r0, #0xf, r1"),
+        // BranchDest
+        (0xeafffffe, "b #0x0", "b #0", "b #0"),
+        (0xea000010, "b #0x48", "b #72", "b #0x48"),
+        (0xebfffff0, "bl #-0x38", "bl #-56", "bl #-0x38"),
+    ] {
+        let parsed = Ins::new(code, &flags).parse(&flags);
+        assert_eq!(parsed.display(Default::default()).to_string(), hex, "{code:08x}");
+        assert_eq!(parsed.display(decimal).to_string(), dec, "{code:08x}");
+        assert_eq!(parsed.display(threshold).to_string(), thr, "{code:08x}");
+    }
+
+    // Absolute branch destinations are always hexadecimal
+    let parsed = Ins::new(0xea000010, &flags).parse(&flags);
+    assert_eq!(
+        parsed.display_with_pc(decimal, 0x100, unarm::ParseMode::Arm).to_string(),
+        "b #0x148"
+    );
+}
+
+#[test]
+pub fn test_cp15_names() {
+    let names = DisplayOptions {
+        cp15_names: true,
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xee110f10, "mrc p15, #0, r0, c1, c0, #0  ; SCTLR", options: names);
+    assert_asm!(v6k::arm, 0xee070f15, "mcr p15, #0, r0, c7, c5, #0  ; ICIALLU", options: names);
+    assert_asm!(v6k::arm, 0xee070f3e, "mcr p15, #0, r0, c7, c14, #1  ; DCCIMVAC", options: names);
+    assert_asm!(v6k::arm, 0xee070f9a, "mcr p15, #0, r0, c7, c10, #4  ; CP15DSB", options: names);
+    assert_asm!(v6k::arm, 0xee080f17, "mcr p15, #0, r0, c8, c7, #0  ; TLBIALL", options: names);
+    assert_asm!(v6k::arm, 0x1e091f11, "mcrne p15, #0, r1, c9, c1, #0  ; DTCMRR", options: names);
+    // Unknown registers and other coprocessors are unchanged
+    assert_asm!(v6k::arm, 0xee0f0f10, "mcr p15, #0, r0, c15, c0, #0", options: names);
+    assert_asm!(v6k::arm, 0xee010e10, "mcr p14, #0, r0, c1, c0, #0", options: names);
+    assert_asm!(v6k::arm, 0xee110f10, "mrc p15, #0, r0, c1, c0, #0");
+
+    let flags = Default::default();
+    let parsed = Ins::new(0xee070f9a, &flags).parse(&flags);
+    let tokens = parsed.display(names).tokens();
+    let last = tokens.tokens.last().unwrap();
+    assert_eq!((last.kind, tokens.text(last)), (TokenKind::Comment, "; CP15DSB"));
+}
+
+#[test]
+pub fn test_uppercase() {
+    let upper = DisplayOptions {
+        uppercase: true,
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xe2a45e23, "ADC R5, R4, #0x230", options: upper);
+    assert_asm!(v6k::arm, 0x10ab960a, "ADCNE R9, R11, R10, LSL #0xc", options: upper);
+    assert_asm!(v6k::arm, 0x40a5f238, "ADCMI PC, R5, R8, LSR R2", options: upper);
+    assert_asm!(v6k::arm, 0xb0887060, "ADDLT R7, R8, R0, RRX", options: upper);
+    assert_asm!(v6k::arm, 0x0a012345, "BEQ #0x48d1c", options: upper);
+    assert_asm!(v6k::arm, 0x3afffffd, "BLO #-0x4", options: upper);
+    assert_asm!(v6k::arm, 0xe8b25555, "LDM R2!, {R0, R2, R4, R6, R8, R10, R12, LR}", options: upper);
+    assert_asm!(v6k::arm, 0xe00120d3, "LDRD R2, R3, [R1], -R3", options: upper);
+    assert_asm!(v6k::arm, 0xe5912fff, "LDR R2, [R1, #0xfff]", options: upper);
+    assert_asm!(v6k::arm, 0xee110f10, "MRC P15, #0, R0, C1, C0, #0", options: upper);
+
+    // Other options still apply, and hexadecimal comments keep their case
+    let options = DisplayOptions {
+        reg_names: RegNames {
+            frame_pointer: true,
+            ..Default::default()
+        },
+        reg_list_ranges: true,
+        immediate_style: ImmediateStyle::Decimal,
+        cp15_names: true,
+        ..upper
+    };
+    assert_asm!(v6k::arm, 0xe92d4ff0, "PUSH {R4-R10, FP, LR}", options: options);
+    assert_asm!(v6k::arm, 0xe2810c01, "ADD R0, R1, #256", options: options);
+    assert_asm!(v6k::arm, 0xee070f9a, "MCR P15, #0, R0, C7, C10, #4  ; CP15DSB", options: options);
+    let flags = Default::default();
+    let parsed = Ins::new(0xe59f001c, &flags).parse(&flags);
+    let options = DisplayOptions {
+        pc_relative_comment: true,
+        ..upper
+    };
+    let text = parsed.display_with_pc(options, 0x200480, unarm::ParseMode::Arm).to_string();
+    assert_eq!(text, "LDR R0, [PC, #0x1c]  ; 0x2004a4");
+
+    // Tokens and arguments displayed on their own are converted too
+    let tokens = parsed.display(upper).tokens();
+    assert_eq!(tokens.text, "LDR R0, [PC, #0x1c]");
+    assert_eq!(tokens.text(&tokens.tokens[0]), "LDR");
+    assert_eq!(parsed.args[1].display(upper).to_string(), "PC");
+}
+
+#[test]
+pub fn test_mnemonic_width() {
+    let listing = |options: DisplayOptions| {
+        let flags = Default::default();
+        let mut text = String::new();
+        for code in [
+            0xe92d4010, 0xe5910004, 0x10810002, 0xe320f000, 0xeb000010, 0xee110f10, 0xe8bd8010,
+        ] {
+            let parsed = Ins::new(code, &flags).parse(&flags);
+            text.push_str(&parsed.display(options).to_string());
+            text.push('\n');
+        }
+        text
+    };
+    let padded = DisplayOptions {
+        mnemonic_width: Some(8),
+        ..Default::default()
+    };
+    assert_eq!(
+        listing(padded),
+        "push    {r4, lr}\n\
+         ldr     r0, [r1, #0x4]\n\
+         addne   r0, r1, r2\n\
+         nop\n\
+         bl      #0x48\n\
+         mrc     p15, #0, r0, c1, c0, #0\n\
+         pop     {r4, pc}\n"
+    );
+    let tabs = DisplayOptions {
+        operand_separator: OperandSeparator::Tab,
+        ..Default::default()
+    };
+    assert_eq!(
+        listing(tabs),
+        "push\t{r4, lr}\nldr\tr0, [r1, #0x4]\naddne\tr0, r1, r2\nnop\nbl\t#0x48\nmrc\tp15, #0, r0, c1, c0, #0\npop\t{r4, pc}\n"
+    );
+    // Mnemonics longer than the width still have a separator
+    let narrow = DisplayOptions {
+        mnemonic_width: Some(4),
+        operand_separator: OperandSeparator::Tab,
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0x10810002, "addne\tr0, r1, r2", options: narrow);
+    assert_asm!(v6k::arm, 0xe5910004, "ldr\tr0, [r1, #0x4]", options: narrow);
+
+    // The padding is one separator token, and renamed mnemonics are padded by their own length
+    let house = DisplayOptions {
+        mnemonic_hook: Some(&house_style),
+        ..padded
+    };
+    assert_asm!(v6k::arm, 0x0bffffec, "calleq  #-0x48", options: house);
+    assert_asm!(v6k::arm, 0xe12fff1e, "ret     lr", options: house);
+    let flags = Default::default();
+    let parsed = Ins::new(0xe5910004, &flags).parse(&flags);
+    let tokens = parsed.display(padded).tokens();
+    assert_eq!(
+        (tokens.tokens[1].kind, tokens.text(&tokens.tokens[1])),
+        (TokenKind::Separator, "     ")
+    );
+    assert_eq!(tokens.arg_at(8), Some(0));
+}
+
+#[test]
+pub fn test_comment_field_style() {
+    let hex = DisplayOptions::default();
+    let decimal = DisplayOptions {
+        comment_field_style: Some(ImmediateStyle::Decimal),
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xef000012, "svc #0x12", options: hex);
+    assert_asm!(v6k::arm, 0xef000012, "svc #18", options: decimal);
+    assert_asm!(v6k::arm, 0x1f123456, "svcne #1193046", options: decimal);
+    assert_asm!(v6k::arm, 0xe1212374, "bkpt #0x1234", options: hex);
+    assert_asm!(v6k::arm, 0xe1212374, "bkpt #4660", options: decimal);
+    // Other immediates keep the immediate style
+    assert_asm!(v6k::arm, 0xe3a00012, "mov r0, #0x12", options: decimal);
+
+    let thumb = |code, options| {
+        let flags = Default::default();
+        let ins = unarm::v6k::thumb::Ins::new(code, &flags);
+        ins.parse(&flags).display(options).to_string()
+    };
+    assert_eq!(thumb(0xdf12, hex), "svc #0x12");
+    assert_eq!(thumb(0xdf12, decimal), "svc #18");
+    assert_eq!(thumb(0xbeff, decimal), "bkpt #255");
+}
+
+#[test]
+pub fn test_comment_field_hook() {
+    fn swi_name(ins: &ParsedIns) -> Option<Cow<'static, str>> {
+        match ins.swi_comment()? {
+            0x0b0000 | 0x0b => Some("swi_Div".into()),
+            _ => None,
+        }
+    }
+    let options = DisplayOptions {
+        mnemonic_hook: Some(&swi_name),
+        comment_field_style: Some(ImmediateStyle::Decimal),
+        ..Default::default()
+    };
+    assert_asm!(v6k::arm, 0xef0b0000, "swi_Div #720896", options: options);
+    assert_asm!(v6k::arm, 0xef0c0000, "svc #786432", options: options);
+}
+
+#[test]
+pub fn test_illegal_style() {
+    let style = |illegal_style| DisplayOptions {
+        illegal_style,
+        ..Default::default()
+    };
+    let flags = Default::default();
+
+    // Unallocated in every version
+    let arm = Ins::new(0xe1000070, &flags);
+    let parsed = arm.parse(&flags);
+    assert!(parsed.is_illegal());
+    let display = |options| parsed.display(options).with_code(arm.code, arm.size()).to_string();
+    assert_eq!(display(style(IllegalStyle::Placeholder)), "<illegal>");
+    assert_eq!(display(style(IllegalStyle::RawWord)), ".word 0xe1000070");
+    assert_eq!(display(style(IllegalStyle::Custom("udf"))), "udf");
+    assert_eq!(
+        display(DisplayOptions {
+            illegal_style: IllegalStyle::RawWord,
+            uppercase: true,
+            ..Default::default()
+        }),
+        ".WORD 0xe1000070"
+    );
+    // The code is needed to display the raw word
+    assert_eq!(parsed.display(style(IllegalStyle::RawWord)).to_string(), "<illegal>");
+
+    // Undefined in ARMv5TE, the `cbz` of ARMv6T2
+    let thumb = unarm::v5te::thumb::Ins::new(0xb100, &flags);
+    let parsed = thumb.parse(&flags);
+    assert!(parsed.is_illegal());
+    let display = |options| {
+        parsed
+            .display_with_pc(options, 0x2000000, ParseMode::Thumb)
+            .with_code(thumb.code, thumb.size())
+            .to_string()
+    };
+    assert_eq!(display(style(IllegalStyle::Placeholder)), "<illegal>");
+    assert_eq!(display(style(IllegalStyle::RawWord)), ".hword 0xb100");
+    assert_eq!(display(style(IllegalStyle::Custom("???"))), "???");
 
-    assert_asm!(0xb8a25555, ip, "stmlt r2!, {r0, r2, r4, r6, r8, r10, ip, lr}");
-    assert_asm!(0xb8a25555, r12, "stmlt r2!, {r0, r2, r4, r6, r8, r10, r12, lr}");
+    // Legal instructions are not affected
+    let legal = Ins::new(0xe0812007, &flags);
+    assert!(!legal.parse(&flags).is_illegal());
+    assert_eq!(
+        legal
+            .parse(&flags)
+            .display(style(IllegalStyle::RawWord))
+            .with_code(legal.code, legal.size())
+            .to_string(),
+        "add r2, r1, r7"
+    );
 }
@@ -0,0 +1,198 @@
+use unarm::{
+    args::*,
+    encode::EncodeError,
+    testing::assert_roundtrip,
+    v5te::arm::{encode, Ins, Opcode},
+    ParseFlags, ParsedIns,
+};
+
+const MODULES: [&str; 6] = ["v4t/arm", "v4t/thumb", "v5te/arm", "v5te/thumb", "v6k/arm", "v6k/thumb"];
+
+/// Decodes `code` in the module at `module`, then encodes the result and decodes it again. Returns `None` if `code` is
+/// illegal.
+fn round_trip(module: &str, code: u32, flags: &ParseFlags) -> Option<(ParsedIns, Result<ParsedIns, EncodeError>)> {
+    macro_rules! round_trip {
+        ($module:ident::$mode:ident, $code:expr) => {{
+            let parsed = unarm::$module::$mode::Ins::new($code, flags).parse(flags);
+            let encoded = unarm::$module::$mode::encode(&parsed, flags);
+            let decoded = encoded.map(|code| unarm::$module::$mode::Ins::new(code, flags).parse(flags));
+            (parsed, decoded)
+        }};
+    }
+    let (parsed, decoded) = match module {
+        "v4t/arm" => round_trip!(v4t::arm, code),
+        "v4t/thumb" => round_trip!(v4t::thumb, code & 0xffff),
+        "v5te/arm" => round_trip!(v5te::arm, code),
+        "v5te/thumb" => round_trip!(v5te::thumb, code & 0xffff),
+        "v6k/arm" => round_trip!(v6k::arm, code),
+        "v6k/thumb" => round_trip!(v6k::thumb, code & 0xffff),
+        _ => panic!("unknown module {module}"),
+    };
+    (parsed.mnemonic != "<illegal>").then_some((parsed, decoded))
+}
+
+#[test]
+fn test_round_trip() {
+    for module in MODULES {
+        for ual in [false, true] {
+            let flags = ParseFlags {
+                ual,
+                ..Default::default()
+            };
+            // xorshift32
+            let mut state = 0x2545f491u32;
+            let mut count = 0;
+            for _ in 0..0x1000 {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                let Some((parsed, decoded)) = round_trip(module, state, &flags) else {
+                    continue;
+                };
+                let text = parsed.display(Default::default()).to_string();
+                assert_eq!(decoded, Ok(parsed), "{module} {text} ({state:#x}, ual: {ual})");
+                count += 1;
+            }
+            assert!(count > 0xc00, "{module}");
+        }
+    }
+}
+
+#[test]
+fn test_text_round_trip() {
+    assert_roundtrip!(v5te::arm, 0xe0812003);
+    assert_roundtrip!(v5te::arm, 0xe5902268);
+    assert_roundtrip!(v5te::arm, 0xe92d4010);
+    assert_roundtrip!(v5te::arm, 0xeb000010);
+    assert_roundtrip!(v5te::arm, 0x01b0c0a2);
+    assert_roundtrip!(v5te::arm, 0xe328f4ff);
+    assert_roundtrip!(
+        v5te::arm,
+        0xef000001,
+        flags: ParseFlags {
+            ual: false,
+            ..Default::default()
+        }
+    );
+}
+
+fn parse(code: u32) -> ParsedIns {
+    Ins::new(code, &Default::default()).parse(&Default::default())
+}
+
+fn assemble(ins: &ParsedIns) -> Result<String, EncodeError> {
+    encode(ins, &Default::default()).map(|code| parse(code).display(Default::default()).to_string())
+}
+
+#[test]
+fn test_encode_data_processing() {
+    // add r0, r1, #0x1
+    let mut ins = parse(0xe2810001);
+    assert_eq!(encode(&ins, &Default::default()), Ok(0xe2810001));
+    ins.args[2] = Argument::UImm(0xff000000);
+    assert_eq!(assemble(&ins), Ok("add r0, r1, #0xff000000".to_string()));
+    // Not an 8-bit value rotated by an even amount
+    ins.args[2] = Argument::UImm(0x101);
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::OutOfRange { index: 2 }));
+    ins.args[2] = Argument::Reg(Reg {
+        reg: Register::R2,
+        deref: false,
+        writeback: false,
+    });
+    assert_eq!(assemble(&ins), Ok("add r0, r1, r2".to_string()));
+    ins.args[3] = Argument::ShiftImm(ShiftImm { op: Shift::Lsr, imm: 32 });
+    assert_eq!(assemble(&ins), Ok("add r0, r1, r2, lsr #0x20".to_string()));
+    ins.args[3] = Argument::ShiftImm(ShiftImm { op: Shift::Lsl, imm: 32 });
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::OutOfRange { index: 3 }));
+
+    ins.mnemonic = "addeqs";
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::UnknownMnemonic));
+    ins.mnemonic = "addseq";
+    ins.args[3] = Argument::None;
+    assert_eq!(assemble(&ins), Ok("addseq r0, r1, r2".to_string()));
+}
+
+#[test]
+fn test_encode_load_store() {
+    // ldr r2, [r0, #0x268]
+    let mut ins = parse(0xe5902268);
+    ins.args[2] = Argument::OffsetImm(OffsetImm {
+        value: -0xfff,
+        post_indexed: true,
+    });
+    assert_eq!(assemble(&ins), Ok("ldr r2, [r0], #-0xfff".to_string()));
+    ins.args[2] = Argument::OffsetImm(OffsetImm {
+        value: 0x1000,
+        post_indexed: false,
+    });
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::OutOfRange { index: 2 }));
+
+    // ldrd r0, r1, [r2, #0x8]
+    let mut ins = parse(0xe1c200d8);
+    assert_eq!(assemble(&ins), Ok("ldrd r0, r1, [r2, #0x8]".to_string()));
+    // The second register must follow the first one
+    ins.args[1] = Argument::Reg(Reg {
+        reg: Register::R3,
+        deref: false,
+        writeback: false,
+    });
+    assert_eq!(
+        encode(&ins, &Default::default()),
+        Err(EncodeError::InvalidArgument { index: 1 })
+    );
+
+    // An extra argument
+    let mut ins = parse(0xe5902268);
+    ins.args[3] = Argument::UImm(0);
+    assert_eq!(
+        encode(&ins, &Default::default()),
+        Err(EncodeError::InvalidArgument { index: 3 })
+    );
+}
+
+#[test]
+fn test_encode_branch() {
+    // b #0x48
+    let mut ins = parse(0xea000010);
+    ins.args[0] = Argument::BranchDest(-0x2000000 + 8);
+    assert_eq!(encode(&ins, &Default::default()), Ok(0xea800000));
+    ins.args[0] = Argument::BranchDest(-0x2000000 + 4);
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::OutOfRange { index: 0 }));
+    ins.args[0] = Argument::BranchDest(0x4a);
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::OutOfRange { index: 0 }));
+
+    // blx #0x4a
+    let mut ins = parse(0xfb000010);
+    assert_eq!(encode(&ins, &Default::default()), Ok(0xfb000010));
+    ins.args[0] = Argument::BranchDest(0x48);
+    assert_eq!(encode(&ins, &Default::default()), Ok(0xfa000010));
+}
+
+#[test]
+fn test_encode_opcode() {
+    // sub r0, r1, #0x1
+    let ins = parse(0xe2410001);
+    assert_eq!(Opcode::Sub.encode(&ins, &Default::default()), Ok(0xe2410001));
+    assert_eq!(
+        Opcode::Add.encode(&ins, &Default::default()),
+        Err(EncodeError::UnknownMnemonic)
+    );
+    assert_eq!(
+        Opcode::Illegal.encode(&ins, &Default::default()),
+        Err(EncodeError::UnknownMnemonic)
+    );
+}
+
+#[test]
+fn test_encode_shadowed() {
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
+    // stmdb sp!, {r4, lr} is push {r4, lr} in unified syntax
+    let ins = Ins::new(0xe92d4010, &divided).parse(&divided);
+    assert_eq!(ins.display(Default::default()).to_string(), "stmdb sp!, {r4, lr}");
+    assert_eq!(encode(&ins, &divided), Ok(0xe92d4010));
+    assert_eq!(encode(&ins, &Default::default()), Err(EncodeError::Shadowed));
+    assert_eq!(assemble(&parse(0xe92d4010)), Ok("push {r4, lr}".to_string()));
+}
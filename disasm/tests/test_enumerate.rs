@@ -0,0 +1,154 @@
+use unarm::{enumerate::FieldFilter, ParseFlags};
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+#[test]
+fn test_clz_exhaustive() {
+    use unarm::v5te::arm::{Ins, Opcode};
+
+    let filter = FieldFilter::new().sweep("cond").sweep("Rd").sweep("Rm");
+    let codes = Opcode::Clz.enumerate(&filter, &UNIFIED).collect::<Vec<_>>();
+    for code in codes.iter() {
+        assert_eq!(Ins::new(*code, &UNIFIED).op, Opcode::Clz, "{code:#010x}");
+    }
+    // Same as checking every encoding of the pattern
+    let expected = (0..0x1000)
+        .map(|bits| 0x016f0f10 | (bits & 0xf00) << 20 | (bits & 0xf0) << 8 | (bits & 0xf))
+        .filter(|code| Ins::new(*code, &UNIFIED).op == Opcode::Clz)
+        .collect::<Vec<_>>();
+    assert_eq!(codes, expected);
+    assert_eq!(codes.len(), 16 * 16 * 16);
+    assert!(codes.contains(&0xe16f2f13));
+
+    let display = |code| {
+        Ins::new(code, &UNIFIED)
+            .parse(&UNIFIED)
+            .display(Default::default())
+            .to_string()
+    };
+    let filter = FieldFilter::new().pin("cond", 0xe).pin("Rm", 3).sweep("Rd");
+    let codes = Opcode::Clz.enumerate(&filter, &UNIFIED).collect::<Vec<_>>();
+    assert_eq!(codes.len(), 16);
+    assert_eq!(display(codes[0]), "clz r0, r3");
+    assert_eq!(display(codes[15]), "clz pc, r3");
+}
+
+#[test]
+fn test_ldr_base() {
+    use unarm::v5te::arm::{Ins, Opcode};
+
+    // All immediate offsets of `ldr r0, [r1, #imm]`, where bit 9 of the addressing mode is the P bit
+    let filter = FieldFilter::new()
+        .pin("cond", 0xe)
+        .pin("Rn_deref", 1)
+        .pin("addr_ldr_str", 0x200)
+        .sweep("offset_12");
+    let mut count = 0;
+    for code in Opcode::Ldr.enumerate(&filter, &UNIFIED) {
+        let ins = Ins::new(code, &UNIFIED);
+        assert_eq!(ins.op, Opcode::Ldr);
+        let text = ins.parse(&UNIFIED).display(Default::default()).to_string();
+        assert!(text.starts_with("ldr r0, [r1, #"), "{text}");
+        count += 1;
+    }
+    assert_eq!(count, 0x2000);
+
+    // Fields which the opcode doesn't have are ignored
+    let codes = Opcode::Ldr.enumerate(&filter.clone().pin("Rm", 2), &UNIFIED);
+    assert_eq!(codes.count(), 0x2000);
+}
+
+#[test]
+fn test_shadowed() {
+    use unarm::v5te::arm::{Ins, Opcode};
+
+    // `stmdb sp!, {r4, lr}` is `push {r4, lr}` in unified syntax
+    let filter = FieldFilter::new()
+        .pin("cond", 0xe)
+        .sweep("Rn_wb")
+        .sweep("addr_ldm_stm")
+        .pin("registers", 0x4010);
+    let divided = Opcode::StmW.enumerate(&filter, &DIVIDED).collect::<Vec<_>>();
+    let unified = Opcode::StmW.enumerate(&filter, &UNIFIED).collect::<Vec<_>>();
+    assert_eq!(divided.len(), 64);
+    assert_eq!(unified.len(), 63);
+    assert!(divided.contains(&0xe92d4010));
+    assert!(!unified.contains(&0xe92d4010));
+    assert_eq!(Ins::new(0xe92d4010, &UNIFIED).op, Opcode::PushM);
+
+    assert_eq!(Opcode::Illegal.enumerate(&filter, &UNIFIED).count(), 0);
+}
+
+#[test]
+fn test_thumb() {
+    use unarm::v5te::thumb::{Ins, Opcode};
+
+    for op in [Opcode::Add3, Opcode::LslI, Opcode::BxR] {
+        let filter = op
+            .field_descs()
+            .iter()
+            .fold(FieldFilter::new(), |filter, field| filter.sweep(field.name));
+        let mut count = 0;
+        for code in op.enumerate(&filter, &UNIFIED) {
+            assert!(code <= 0xffff);
+            assert_eq!(Ins::new(code, &UNIFIED).op, op, "{code:#06x}");
+            count += 1;
+        }
+        assert!(count > 0, "{op:?}");
+    }
+}
+
+/// Checks that the pattern of every opcode decodes to that opcode or a more specific one, with the flags of either
+/// syntax
+macro_rules! check_patterns {
+    ($module:ident::$mode:ident) => {{
+        use unarm::$module::$mode::Opcode;
+
+        let vfp = ParseFlags { vfp: true, ..UNIFIED };
+        let divided_vfp = ParseFlags { vfp: true, ..DIVIDED };
+        let mut count = 0;
+        for op in Opcode::iter() {
+            count += 1;
+            assert_ne!(op, Opcode::Illegal);
+            assert_eq!(op.pattern() & !op.bitmask(), 0, "{op:?}");
+            let matches = [UNIFIED, DIVIDED, vfp, divided_vfp].iter().any(|flags| {
+                let found = Opcode::find(op.pattern(), flags);
+                found == op
+                    || (found.bitmask() & op.bitmask() == op.bitmask()
+                        && found.bitmask() != op.bitmask()
+                        && op.pattern() & found.bitmask() == found.pattern())
+            });
+            assert!(matches, "{op:?} {:#x}", op.pattern());
+        }
+        assert!(count <= Opcode::count());
+        assert_eq!(Opcode::Illegal.bitmask(), 0);
+        assert_eq!(Opcode::Illegal.pattern(), 0);
+    }};
+}
+
+#[test]
+fn test_patterns() {
+    check_patterns!(v4t::arm);
+    check_patterns!(v4t::thumb);
+    check_patterns!(v5te::arm);
+    check_patterns!(v5te::thumb);
+    check_patterns!(v6k::arm);
+    check_patterns!(v6k::thumb);
+}
+
+#[test]
+fn test_iter() {
+    use unarm::v5te::arm::Opcode;
+
+    let ops = Opcode::iter().collect::<Vec<_>>();
+    assert!(ops.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(ops.contains(&Opcode::Clz));
+    assert_eq!(Opcode::Clz.bitmask(), 0x0fff0ff0);
+    assert_eq!(Opcode::Clz.pattern(), 0x016f0f10);
+    #[cfg(feature = "ext-vfp")]
+    assert_eq!(ops.len(), Opcode::count());
+}
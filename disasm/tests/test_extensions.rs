@@ -0,0 +1,52 @@
+use unarm::{v5te, v6k, ParseFlags};
+
+macro_rules! assert_arm {
+    ($module:ident, $code:literal, $disasm:literal) => {{
+        let flags = ParseFlags::default();
+        let ins = $module::arm::Ins::new($code, &flags);
+        let parsed = ins.parse(&flags);
+        assert_eq!(parsed.display(Default::default()).to_string(), $disasm)
+    }};
+}
+
+#[test]
+fn test_dsp() {
+    #[cfg(feature = "ext-dsp")]
+    {
+        assert_arm!(v5te, 0xe1012053, "qadd r2, r3, r1");
+        assert_arm!(v5te, 0xe1c120d0, "ldrd r2, r3, [r1, #0x0]");
+        assert_arm!(v6k, 0xe1012053, "qadd r2, r3, r1");
+        assert_eq!(v6k::arm::Ins::new(0xe1012053, &Default::default()).op, v6k::arm::Opcode::Qadd);
+    }
+    #[cfg(not(feature = "ext-dsp"))]
+    {
+        assert_eq!(
+            v5te::arm::Ins::new(0xe1012053, &Default::default()).op,
+            v5te::arm::Opcode::Illegal
+        );
+        assert_arm!(v5te, 0xe1012053, "<illegal>");
+        assert_arm!(v6k, 0xe1012053, "<illegal>");
+    }
+
+    // Instructions outside the extension are unaffected
+    assert_arm!(v5te, 0xe0812003, "add r2, r1, r3");
+    assert_arm!(v5te, 0xe12fff31, "blx r1");
+}
+
+#[test]
+fn test_media() {
+    #[cfg(feature = "ext-media")]
+    {
+        assert_arm!(v6k, 0xe6512f93, "uadd8 r2, r1, r3");
+        assert_arm!(v6k, 0xe6af2071, "sxtb r2, r1");
+    }
+    #[cfg(not(feature = "ext-media"))]
+    {
+        assert_arm!(v6k, 0xe6512f93, "<illegal>");
+        assert_arm!(v6k, 0xe6af2071, "<illegal>");
+    }
+
+    // Instructions outside the extension are unaffected
+    assert_arm!(v6k, 0xe6bf2f31, "rev r2, r1");
+    assert_arm!(v6k, 0xe0412393, "umaal r2, r1, r3, r3");
+}
@@ -0,0 +1,58 @@
+use unarm::ParseFlags;
+
+const FLAGS: ParseFlags = ParseFlags::DEFAULT;
+
+#[test]
+fn test_ldr_immediate_fields() {
+    use unarm::v5te::arm::{Ins, Opcode};
+
+    // ldr r2, [r1, #0xfff]
+    let ins = Ins::new(0xe5912fff, &FLAGS);
+    assert_eq!(ins.op, Opcode::Ldr);
+    let fields = ins.fields().collect::<Vec<_>>();
+    assert!(fields.contains(&("Rd", 2)), "{fields:?}");
+    assert!(fields.contains(&("Rn_deref", 1)), "{fields:?}");
+    // The U bit is packed above the 12-bit immediate
+    assert!(fields.contains(&("offset_12", 0x1000 | 0xfff)), "{fields:?}");
+    // Fields of other addressing modes are left out
+    assert!(!fields.iter().any(|(name, _)| *name == "reg_offset"), "{fields:?}");
+
+    let offset = ins.field_descs().iter().find(|field| field.name == "offset_12").unwrap();
+    assert_eq!(offset.bitmask, 0x00800fff);
+    assert_eq!(offset.value(0xe5112fff), 0xfff);
+}
+
+#[test]
+fn test_ldr_register_fields() {
+    use unarm::v5te::arm::Ins;
+
+    // ldr r2, [r1, r3]
+    let fields = Ins::new(0xe7912003, &FLAGS).fields().collect::<Vec<_>>();
+    assert!(fields.contains(&("Rd", 2)), "{fields:?}");
+    assert!(fields.contains(&("Rn_deref", 1)), "{fields:?}");
+    assert!(fields.iter().any(|(name, _)| *name == "reg_offset"), "{fields:?}");
+    assert!(!fields.iter().any(|(name, _)| *name == "offset_12"), "{fields:?}");
+}
+
+#[test]
+fn test_thumb_fields() {
+    use unarm::v5te::thumb::Ins;
+
+    // add r1, r2, r3
+    let fields = Ins::new(0x18d1, &FLAGS).fields().collect::<Vec<_>>();
+    assert!(fields.contains(&("Rd_0", 1)), "{fields:?}");
+    assert!(fields.contains(&("Rn_3", 2)), "{fields:?}");
+    assert!(fields.contains(&("Rm_6", 3)), "{fields:?}");
+}
+
+#[test]
+fn test_rotated_immediate_field() {
+    use unarm::v5te::arm::{Ins, Opcode};
+
+    // msr cpsr_f, #0xff000000
+    let ins = Ins::new(0xe328f4ff, &FLAGS);
+    assert_eq!(ins.op, Opcode::MsrI);
+    assert_eq!(ins.field_rotated_immed_8(), 0xff000000);
+    // add r5, r4, #0x230
+    assert_eq!(Ins::new(0xe2845e23, &FLAGS).field_rotated_immed_8(), 0x230);
+}
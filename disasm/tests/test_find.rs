@@ -0,0 +1,92 @@
+use unarm::ParseFlags;
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+macro_rules! check_equivalent {
+    ($version:ident, $mode:ident, $codes:expr) => {{
+        use unarm::$version::$mode::Opcode;
+        for code in $codes {
+            for flags in [UNIFIED, DIVIDED] {
+                let tree = Opcode::find_tree(code, &flags);
+                assert_eq!(Opcode::find_table(code, &flags), tree, "{code:#010x}");
+                assert_eq!(Opcode::find(code, &flags), tree, "{code:#010x}");
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_table_equals_tree() {
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    let arm_codes: Vec<u32> = (0..0x400000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect();
+
+    check_equivalent!(v4t, arm, arm_codes.iter().copied());
+    check_equivalent!(v5te, arm, arm_codes.iter().copied());
+    check_equivalent!(v6k, arm, arm_codes.iter().copied());
+    check_equivalent!(v4t, thumb, 0..=0xffff);
+    check_equivalent!(v5te, thumb, 0..=0xffff);
+    check_equivalent!(v6k, thumb, 0..=0xffff);
+}
+
+#[test]
+fn test_table_keys() {
+    // Every key of the ARM table, with the other bits cleared and set
+    let codes = (0..0x1000u32).flat_map(|key| {
+        let bits = (key & 0xff0) << 16 | (key & 0xf) << 4;
+        [bits, bits | 0xf00fff0f, bits | 0x000f0000, bits | 0xe0000000]
+    });
+    check_equivalent!(v4t, arm, codes.clone());
+    check_equivalent!(v5te, arm, codes.clone());
+    check_equivalent!(v6k, arm, codes);
+}
+
+mod const_arm {
+    use unarm::{
+        v5te::arm::{Ins, Opcode},
+        ParseFlags,
+    };
+
+    const INS: Ins = Ins::const_new(0xe0a12003, &ParseFlags::DEFAULT);
+    const _: () = assert!(matches!(INS.op, Opcode::Adc));
+
+    const UNPREDICTABLE: ParseFlags = ParseFlags {
+        unpredictable_as_illegal: true,
+        ..ParseFlags::DEFAULT
+    };
+    const _: () = assert!(matches!(Ins::const_new(0xe1c030d0, &UNPREDICTABLE).op, Opcode::Illegal));
+
+    #[test]
+    fn test_const_new() {
+        let mut state = 0x2545f491u32;
+        for _ in 0..100_000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            for flags in [ParseFlags::DEFAULT, UNPREDICTABLE] {
+                assert_eq!(Ins::const_new(state, &flags).op, Ins::new(state, &flags).op, "{state:#010x}");
+            }
+        }
+    }
+}
+
+mod const_thumb {
+    use unarm::{
+        v5te::thumb::{Ins, Opcode},
+        ParseFlags,
+    };
+
+    const INS: Ins = Ins::const_new(0x4148, &ParseFlags::DEFAULT);
+    const _: () = assert!(matches!(INS.op, Opcode::Adc));
+}
@@ -0,0 +1,47 @@
+use unarm::ParseFlags;
+
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+#[test]
+fn test_single_match() {
+    use unarm::v5te::arm::Opcode;
+
+    // ldr r2, [r0, #0x268]!
+    let all: Vec<_> = Opcode::find_all(0xe5b02268, &UNIFIED).collect();
+    assert_eq!(all, [Opcode::Ldr]);
+    assert_eq!(all[0], Opcode::find(0xe5b02268, &UNIFIED));
+}
+
+#[test]
+fn test_general_and_specific() {
+    use unarm::v5te::thumb::Opcode;
+
+    // add r0, sp, r0 is matched by the general high register form too
+    let all: Vec<_> = Opcode::find_all(0x4468, &UNIFIED).collect();
+    assert_eq!(all, [Opcode::AddRegSp, Opcode::AddHr]);
+    assert_eq!(all[0], Opcode::find(0x4468, &UNIFIED));
+}
+
+#[test]
+fn test_aliases() {
+    use unarm::v5te::arm::Opcode;
+
+    // push {r4, lr} is only an alias in unified syntax
+    let all: Vec<_> = Opcode::find_all(0xe92d4010, &UNIFIED).collect();
+    assert_eq!(all, [Opcode::PushM, Opcode::StmW]);
+    let all: Vec<_> = Opcode::find_all(0xe92d4010, &DIVIDED).collect();
+    assert_eq!(all, [Opcode::StmW]);
+}
+
+#[test]
+fn test_no_match() {
+    use unarm::v5te::thumb::Opcode;
+
+    // cbz doesn't exist until ARMv6T2
+    assert_eq!(Opcode::find(0xb100, &UNIFIED), Opcode::Illegal);
+    assert_eq!(Opcode::find_all(0xb100, &UNIFIED).count(), 0);
+}
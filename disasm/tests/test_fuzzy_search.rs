@@ -0,0 +1,65 @@
+use unarm::fuzzy_score;
+
+#[test]
+fn test_fuzzy_score() {
+    assert_eq!(fuzzy_score("ldr", "ldr"), Some(1000));
+    assert_eq!(fuzzy_score("ld", "ldrb"), Some(898));
+    assert_eq!(fuzzy_score("ldb", "ldrb"), Some(599));
+    assert_eq!(fuzzy_score("stmr", "stm"), Some(300));
+    assert_eq!(fuzzy_score("stmr", "ldr"), None);
+    assert_eq!(fuzzy_score("", "ldr"), None);
+}
+
+#[test]
+fn test_arm() {
+    use unarm::v5te::arm::Opcode;
+
+    let top = |query: &str, limit: usize| -> Vec<Opcode> {
+        Opcode::fuzzy_search(query, limit).into_iter().map(|(op, _)| op).collect()
+    };
+
+    // Stack aliases match the load/store multiple opcodes
+    assert_eq!(
+        Opcode::fuzzy_search("ldmfd", 8),
+        [
+            (Opcode::LdmW, 1000),
+            (Opcode::Ldm, 1000),
+            (Opcode::LdmP, 1000),
+            (Opcode::LdmPcW, 1000),
+            (Opcode::LdmPc, 1000)
+        ]
+    );
+    assert_eq!(top("stmfd", 1), [Opcode::Stm]);
+
+    // Typos
+    assert_eq!(top("stmr", 8), [Opcode::Stm, Opcode::StmW, Opcode::StmP, Opcode::Str]);
+    assert_eq!(top("pushh", 8), [Opcode::PushM, Opcode::PushR]);
+
+    // Prefixes rank above subsequences
+    assert_eq!(top("ad", 3), [Opcode::Adc, Opcode::Add, Opcode::And]);
+    assert_eq!(top("LDR", 2), [Opcode::Ldr, Opcode::LdrB]);
+    assert_eq!(top("smlabb", 2), [Opcode::Smla, Opcode::SmlalXy]);
+
+    assert!(Opcode::fuzzy_search("xyz", 8).is_empty());
+    assert!(Opcode::fuzzy_search("", 8).is_empty());
+    assert_eq!(Opcode::fuzzy_search("ldr", 0), []);
+}
+
+#[test]
+fn test_thumb() {
+    use unarm::v5te::thumb::Opcode;
+
+    let hits = Opcode::fuzzy_search("blxx", 8);
+    assert!(hits.iter().any(|&(op, _)| op == Opcode::BlxR), "{hits:?}");
+    assert_eq!(Opcode::fuzzy_search("push", 1), [(Opcode::Push, 1000)]);
+}
+
+#[test]
+fn test_describe() {
+    use unarm::v5te::arm::Opcode;
+
+    assert_eq!(Opcode::Adc.describe(), "Add with Carry");
+    assert_eq!(Opcode::Ldm.describe(), "Load Multiple");
+    assert_eq!(Opcode::Illegal.describe(), "Illegal or unknown");
+    assert_eq!(unarm::v6k::thumb::Opcode::Udf.describe(), "Permanently Undefined");
+}
@@ -0,0 +1,139 @@
+use unarm::{
+    args::{Argument, CoReg, Endian, OffsetReg, Reg, Register, Shift, ShiftImm, StatusMask, StatusReg},
+    ParseFlags, ParsedIns,
+};
+
+const STRICT: ParseFlags = ParseFlags {
+    illegal_operand_as_illegal: true,
+    ..ParseFlags::DEFAULT
+};
+
+fn ins(mnemonic: &'static str, args: &[Argument]) -> ParsedIns {
+    let mut parsed = ParsedIns {
+        mnemonic,
+        ..Default::default()
+    };
+    parsed.args[..args.len()].copy_from_slice(args);
+    parsed
+}
+
+fn reg(reg: Register) -> Argument {
+    Argument::Reg(Reg {
+        deref: false,
+        reg,
+        writeback: false,
+    })
+}
+
+#[test]
+fn test_detection() {
+    let legal = ins("mov", &[reg(Register::R0), reg(Register::R1)]);
+    assert!(!legal.has_illegal_operand());
+
+    let cases = [
+        ins("mov", &[reg(Register::R0), reg(Register::Illegal)]),
+        ins(
+            "mov",
+            &[
+                reg(Register::R0),
+                reg(Register::R1),
+                Argument::ShiftImm(ShiftImm {
+                    imm: 1,
+                    op: Shift::Illegal,
+                }),
+            ],
+        ),
+        ins(
+            "mcr",
+            &[
+                Argument::CoprocNum(15),
+                Argument::CoOpcode(0),
+                reg(Register::R0),
+                Argument::CoReg(CoReg::Illegal),
+            ],
+        ),
+        ins("mrs", &[reg(Register::R0), Argument::StatusReg(StatusReg::Illegal)]),
+        ins(
+            "msr",
+            &[Argument::StatusMask(StatusMask {
+                control: true,
+                extension: false,
+                flags: false,
+                reg: StatusReg::Illegal,
+                status: false,
+            })],
+        ),
+        ins(
+            "ldr",
+            &[
+                reg(Register::R0),
+                Argument::Reg(Reg {
+                    deref: true,
+                    reg: Register::R1,
+                    writeback: false,
+                }),
+                Argument::OffsetReg(OffsetReg {
+                    add: true,
+                    post_indexed: false,
+                    reg: Register::Illegal,
+                }),
+            ],
+        ),
+        ins("setend", &[Argument::Endian(Endian::Illegal)]),
+    ];
+    for parsed in cases {
+        assert!(parsed.has_illegal_operand(), "{parsed:?}");
+        // The rest of the instruction is still displayed
+        let text = parsed.display(Default::default()).to_string();
+        assert!(text.starts_with(parsed.mnemonic), "{text}");
+        assert!(text.contains("<illegal>"), "{text}");
+    }
+}
+
+#[test]
+fn test_parse_range() {
+    // Out-of-range field values can only be produced by expressions, which the generator checks with a
+    // debug_assert, so every value which fits the field width parses to a legal variant
+    assert_eq!(Register::parse(16), Register::Illegal);
+    assert_eq!(CoReg::parse(16), CoReg::Illegal);
+    assert_eq!(Shift::parse(5), Shift::Illegal);
+    assert!(Register::parse(16).is_illegal());
+    assert!(!Register::parse(15).is_illegal());
+}
+
+macro_rules! check_corpus {
+    ($module:ident, $mode:ident, $codes:expr) => {{
+        use unarm::$module::$mode::{Ins, Opcode};
+        let flags = ParseFlags::default();
+        for code in $codes {
+            let ins = Ins::new(code, &flags);
+            let strict = Ins::new(code, &STRICT);
+            if ins.parse(&flags).has_illegal_operand() {
+                assert_eq!(strict.op, Opcode::Illegal, "{code:08x}");
+            } else {
+                assert_eq!(strict.op, ins.op, "{code:08x}");
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_strict_corpus() {
+    // xorshift32
+    let mut state = 0x1b873593u32;
+    let arm_codes: Vec<u32> = (0..0x10000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect();
+
+    check_corpus!(v4t, arm, arm_codes.iter().copied());
+    check_corpus!(v5te, arm, arm_codes.iter().copied());
+    check_corpus!(v6k, arm, arm_codes.iter().copied());
+    check_corpus!(v4t, thumb, 0..=0xffff);
+    check_corpus!(v5te, thumb, 0..=0xffff);
+    check_corpus!(v6k, thumb, 0..=0xffff);
+}
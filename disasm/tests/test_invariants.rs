@@ -0,0 +1,213 @@
+//! Parsing decides what an instruction is, displaying only decides how it looks:
+//!
+//! - `ParseFlags` select the mnemonic, its suffixes, aliases and the arguments of a `ParsedIns`.
+//! - `DisplayOptions` select register names, the radix of immediates, case, padding and comments.
+//!
+//! These tests check both directions on a random corpus. Rendering one `ParsedIns` under every combination of display
+//! options must keep the mnemonic and the structure of its arguments, and the text of registers must only depend on
+//! `RegNames`, no matter which flags parsed them.
+
+use std::thread;
+
+use unarm::{
+    args::Argument, testing::random_code, ArmVersion, DisplayOptions, Endian, ImmediateStyle, OperandSeparator, ParseFlags,
+    ParseMode, ParsedIns, Parser, R9Use, RegNames, TokenKind, Tokens,
+};
+
+const ADDRESS: u32 = 0x02000000;
+
+fn parse(version: ArmVersion, mode: ParseMode, flags: ParseFlags, code: &[u8]) -> Vec<(u32, ParsedIns)> {
+    Parser::new(version, mode, ADDRESS, Endian::Little, flags, code)
+        .map(|(address, _, ins)| (address, ins))
+        .collect()
+}
+
+fn parse_flags() -> Vec<ParseFlags> {
+    let mut flags = vec![];
+    for ual in [false, true] {
+        for unpredictable_as_illegal in [false, true] {
+            for illegal_operand_as_illegal in [false, true] {
+                for vfp in [false, true] {
+                    for aliases in [false, true] {
+                        for unallocated_hint_as_illegal in [false, true] {
+                            flags.push(ParseFlags {
+                                ual,
+                                unpredictable_as_illegal,
+                                illegal_operand_as_illegal,
+                                vfp,
+                                aliases,
+                                unallocated_hint_as_illegal,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    flags
+}
+
+fn reg_names() -> [RegNames; 3] {
+    [
+        RegNames::default(),
+        RegNames {
+            av_registers: true,
+            r9_use: R9Use::Pid,
+            explicit_stack_limit: true,
+            frame_pointer: true,
+            ip: true,
+        },
+        RegNames {
+            av_registers: false,
+            r9_use: R9Use::Tls,
+            explicit_stack_limit: false,
+            frame_pointer: true,
+            ip: false,
+        },
+    ]
+}
+
+/// Every combination of display options, apart from `mnemonic_hook` which renames mnemonics on purpose
+fn display_options() -> Vec<DisplayOptions<'static>> {
+    let mut options = vec![DisplayOptions::default()];
+    macro_rules! vary {
+        ($field:ident, [$($value:expr),+]) => {
+            options = options
+                .iter()
+                .flat_map(|&base| [$(DisplayOptions { $field: $value, ..base }),+])
+                .collect();
+        };
+    }
+    vary!(reg_names, [reg_names()[0], reg_names()[1], reg_names()[2]]);
+    vary!(reg_list_ranges, [false, true]);
+    vary!(
+        immediate_style,
+        [
+            ImmediateStyle::Hex,
+            ImmediateStyle::Decimal,
+            ImmediateStyle::HexAboveThreshold(9)
+        ]
+    );
+    vary!(comment_field_style, [None, Some(ImmediateStyle::Decimal)]);
+    vary!(pc_relative_comment, [false, true]);
+    vary!(cp15_names, [false, true]);
+    vary!(uppercase, [false, true]);
+    vary!(mnemonic_width, [None, Some(8)]);
+    vary!(operand_separator, [OperandSeparator::Space, OperandSeparator::Tab]);
+    options
+}
+
+/// The parts of rendered tokens which display options must not change: the mnemonic, the brackets and commas, and
+/// which argument each run of argument text belongs to. Comments and whitespace are left out.
+fn structure(tokens: &Tokens) -> Vec<String> {
+    let mut structure: Vec<String> = vec![];
+    for token in &tokens.tokens {
+        let text = tokens.text(token);
+        let part = match token.kind {
+            TokenKind::Mnemonic => text.to_lowercase(),
+            TokenKind::Separator if text.trim().is_empty() => continue,
+            TokenKind::Separator => text.trim().to_string(),
+            TokenKind::Argument => format!("arg{}", token.arg_index.unwrap()),
+            TokenKind::Comment => continue,
+        };
+        if token.kind != TokenKind::Argument || structure.last() != Some(&part) {
+            structure.push(part);
+        }
+    }
+    structure
+}
+
+const CONFIGS: [(ArmVersion, ParseMode); 6] = [
+    (ArmVersion::V4T, ParseMode::Arm),
+    (ArmVersion::V4T, ParseMode::Thumb),
+    (ArmVersion::V5Te, ParseMode::Arm),
+    (ArmVersion::V5Te, ParseMode::Thumb),
+    (ArmVersion::V6K, ParseMode::Arm),
+    (ArmVersion::V6K, ParseMode::Thumb),
+];
+
+fn check_structure(version: ArmVersion, mode: ParseMode, flags: ParseFlags, code: &[u8], options: &[DisplayOptions]) {
+    for (address, ins) in parse(version, mode, flags, code) {
+        let parsed = ins.clone();
+        let reference = structure(&ins.display(Default::default()).tokens());
+        assert_eq!(reference[0], ins.mnemonic, "{version:?} {mode:?} {address:#x}");
+        for &options in options {
+            let tokens = ins.display_with_pc(options, address, mode).tokens();
+            assert_eq!(
+                structure(&tokens),
+                reference,
+                "{version:?} {mode:?} {address:#x} `{}` under {options:?}",
+                tokens.text
+            );
+        }
+        assert_eq!(ins, parsed, "displaying changed the instruction at {address:#x}");
+    }
+}
+
+#[test]
+fn test_display_options_keep_structure() {
+    let code = random_code(0x2468ace1, 0x100);
+    let options = display_options();
+    assert_eq!(options.len(), 3 * 2 * 3 * 2 * 2 * 2 * 2 * 2 * 2);
+    // Each version, mode and syntax renders in its own thread, as there are thousands of combinations
+    thread::scope(|scope| {
+        for (version, mode) in CONFIGS {
+            for ual in [false, true] {
+                let (code, options) = (&code, &options);
+                scope.spawn(move || {
+                    check_structure(
+                        version,
+                        mode,
+                        ParseFlags {
+                            ual,
+                            ..Default::default()
+                        },
+                        code,
+                        options,
+                    )
+                });
+            }
+        }
+    });
+}
+
+#[test]
+fn test_parse_flags_keep_register_names() {
+    let code = random_code(0x13579bdf, 0x200);
+    for (version, mode) in CONFIGS {
+        let mut rendered: Vec<(ParsedIns, String)> = vec![];
+        for flags in parse_flags() {
+            for (address, ins) in parse(version, mode, flags, &code) {
+                for reg_names in reg_names() {
+                    let options = DisplayOptions {
+                        reg_names,
+                        ..Default::default()
+                    };
+                    let tokens = ins.display(options).tokens();
+                    // The text of a register argument only depends on the register and the names
+                    for token in &tokens.tokens {
+                        let Some(index) = token.arg_index else { continue };
+                        let text = tokens.text(token);
+                        let expected = match ins.args[index] {
+                            Argument::Reg(reg) if text != "!" => {
+                                let writeback = if reg.writeback && !reg.deref { "!" } else { "" };
+                                format!("{}{writeback}", reg.reg.display(reg_names))
+                            }
+                            Argument::RegList(_) | Argument::ShiftReg(_) | Argument::OffsetReg(_) => {
+                                ins.args[index].display(options).to_string()
+                            }
+                            _ => continue,
+                        };
+                        assert_eq!(text, expected, "{version:?} {mode:?} {address:#x} {flags:?}");
+                    }
+                }
+                // Flags may only change the text by changing the parsed instruction
+                let text = ins.display(Default::default()).to_string();
+                match rendered.iter().find(|(other, _)| *other == ins) {
+                    Some((_, other)) => assert_eq!(&text, other, "{version:?} {mode:?} {address:#x} {flags:?}"),
+                    None => rendered.push((ins, text)),
+                }
+            }
+        }
+    }
+}
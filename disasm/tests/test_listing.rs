@@ -0,0 +1,146 @@
+//! Writes a listing of the NDS fixtures as GNU assembler source and compares it with `fixtures/listing_gas.s`. If
+//! `arm-none-eabi-as` is installed, the source is also assembled and linked, and the result must be the original image.
+//! Run with `UNARM_LISTING_GOLDEN_UPDATE=1` to rewrite the golden file after an intended change to the listing.
+
+use std::process::Command;
+
+use unarm::{testing::fixture, ArmVersion, Endian, Listing, OutputDialect, ParseFlags, ParseMode, Parser};
+
+const GOLDEN_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/listing_gas.s");
+const BASE: u32 = 0x2000000;
+/// Literal pool at the end of `nds_arm9`
+const ARM_POOL: u32 = BASE + 0x12c;
+/// Start of `nds_thumb` in the image
+const THUMB: u32 = BASE + 0x158;
+/// Literal pool at the end of `nds_thumb`
+const THUMB_POOL: u32 = THUMB + 0x78;
+
+/// Returns `nds_arm9` followed by `nds_thumb` and three bytes of data, with the mode of each region
+fn image() -> (Vec<u8>, Vec<(u32, ParseMode)>) {
+    let mut bytes = fixture("nds_arm9").unwrap().code.to_vec();
+    bytes.extend(fixture("nds_thumb").unwrap().code);
+    bytes.extend([0x12, 0x34, 0x56]);
+    let modes = vec![
+        (ARM_POOL, ParseMode::Data),
+        (THUMB, ParseMode::Thumb),
+        (THUMB_POOL, ParseMode::Data),
+    ];
+    (bytes, modes)
+}
+
+fn listing<'a>(bytes: &'a [u8], modes: &'a [(u32, ParseMode)], parser: &Parser<'a>) -> Listing<'a> {
+    Listing::new(bytes, BASE, parser)
+        .with_modes(modes)
+        .with_symbol(BASE, "_start")
+        .with_symbol(THUMB, "vec_add")
+}
+
+fn parser() -> Parser<'static> {
+    Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    )
+}
+
+#[test]
+fn test_gas_source_golden() {
+    let (bytes, modes) = image();
+    let parser = parser();
+    let actual = listing(&bytes, &modes, &parser)
+        .with_dialect(OutputDialect::GasSource)
+        .to_string();
+    if std::env::var_os("UNARM_LISTING_GOLDEN_UPDATE").is_some() {
+        std::fs::write(GOLDEN_PATH, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(GOLDEN_PATH).unwrap();
+    assert_eq!(actual, expected, "run with UNARM_LISTING_GOLDEN_UPDATE=1 if this is intended");
+}
+
+/// Runs `program` with `args`, returns `None` if it's not installed and panics if it fails
+fn run(program: &str, args: &[&str]) -> Option<()> {
+    let output = Command::new(program).args(args).output().ok()?;
+    assert!(
+        output.status.success(),
+        "{program}: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Some(())
+}
+
+#[test]
+fn test_gas_source_assembles() {
+    let (bytes, modes) = image();
+    let parser = parser();
+    let source = listing(&bytes, &modes, &parser)
+        .with_dialect(OutputDialect::GasSource)
+        .to_string();
+
+    let dir = std::env::temp_dir().join(format!("unarm-listing-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+    std::fs::write(path("listing.s"), source).unwrap();
+
+    let as_ = std::env::var("ARM_AS").unwrap_or_else(|_| "arm-none-eabi-as".to_string());
+    let tools = as_.strip_suffix("as").unwrap_or("arm-none-eabi-");
+    let text = format!("-Ttext={BASE:#x}");
+    let assembled = run(&as_, &["-o", &path("listing.o"), &path("listing.s")])
+        .and_then(|_| {
+            run(
+                &format!("{tools}ld"),
+                &[&text, "-e", "_start", "-o", &path("listing.elf"), &path("listing.o")],
+            )
+        })
+        .and_then(|_| {
+            run(
+                &format!("{tools}objcopy"),
+                &["-O", "binary", &path("listing.elf"), &path("listing.bin")],
+            )
+        });
+    if assembled.is_none() {
+        println!("{as_} not found, only the golden file is checked");
+        std::fs::remove_dir_all(&dir).unwrap();
+        return;
+    }
+    let reassembled = std::fs::read(path("listing.bin")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(reassembled, bytes);
+}
+
+#[test]
+fn test_plain() {
+    let (bytes, modes) = image();
+    let parser = parser();
+    let plain = listing(&bytes, &modes, &parser).to_string();
+    let lines: Vec<&str> = plain.lines().collect();
+    assert_eq!(lines[0], "_start:");
+    assert_eq!(lines[1], "02000000: e3a00301   mov r0, #0x4000000");
+    assert!(lines.contains(&"vec_add:"));
+    assert!(lines.contains(&"02000120: 11a0e00f   movne lr, pc"), "{plain}");
+}
+
+#[test]
+fn test_gas_source_directives() {
+    let (bytes, modes) = image();
+    let parser = parser();
+    let gas = listing(&bytes, &modes, &parser)
+        .with_dialect(OutputDialect::GasSource)
+        .to_string();
+    let lines: Vec<&str> = gas.lines().map(str::trim_end).collect();
+    assert_eq!(
+        lines[..4],
+        ["    .syntax unified", "    .arch armv5te", "    .arm", "    .global _start"]
+    );
+    let thumb = lines.iter().position(|line| *line == "    .thumb").unwrap();
+    assert_eq!(
+        lines[thumb + 1..thumb + 4],
+        ["    .global vec_add", "    .thumb_func", "vec_add:"]
+    );
+    assert!(lines.contains(&"    .word 0x27e0040                  @ 020001d0"), "{gas}");
+    assert!(lines.contains(&"    .hword 0x3412                    @ 020001d8"), "{gas}");
+    assert!(lines.contains(&"    .byte 0x56                       @ 020001da"), "{gas}");
+}
@@ -0,0 +1,124 @@
+use unarm::{DisplayOptions, IllegalStyle, ImmediateStyle, OperandSeparator, ParseFlags, R9Use, RegNames};
+
+const OPTIONS: [DisplayOptions; 2] = [
+    DisplayOptions {
+        reg_names: RegNames {
+            av_registers: false,
+            r9_use: R9Use::GeneralPurpose,
+            explicit_stack_limit: false,
+            frame_pointer: false,
+            ip: false,
+        },
+        mnemonic_hook: None,
+        reg_list_ranges: false,
+        immediate_style: ImmediateStyle::Hex,
+        comment_field_style: None,
+        pc_relative_comment: false,
+        adr: false,
+        cp15_names: false,
+        uppercase: false,
+        mnemonic_width: None,
+        operand_separator: OperandSeparator::Space,
+        illegal_style: IllegalStyle::Placeholder,
+    },
+    DisplayOptions {
+        reg_names: RegNames {
+            av_registers: true,
+            r9_use: R9Use::Tls,
+            explicit_stack_limit: true,
+            frame_pointer: true,
+            ip: true,
+        },
+        mnemonic_hook: None,
+        reg_list_ranges: true,
+        immediate_style: ImmediateStyle::Decimal,
+        comment_field_style: Some(ImmediateStyle::Hex),
+        pc_relative_comment: true,
+        adr: true,
+        cp15_names: false,
+        uppercase: true,
+        mnemonic_width: None,
+        operand_separator: OperandSeparator::Tab,
+        illegal_style: IllegalStyle::Placeholder,
+    },
+];
+
+fn arm_codes() -> Vec<u32> {
+    // xorshift32
+    let mut state = 0x0badf00du32;
+    let mut codes = vec![];
+    for _ in 0..0x8000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        // Maximize register lists, immediates and offsets, and their negated forms
+        codes.extend([
+            state,
+            state | 0x0000ffff,
+            state | 0x00ffffff,
+            (state | 0x00ffffff) & !0x00800000,
+        ]);
+    }
+    codes
+}
+
+macro_rules! check_max_len {
+    ($module:ident, $mode:ident, $codes:expr) => {{
+        use unarm::$module::$mode::{Ins, MAX_MNEMONIC_LEN, MAX_RENDERED_LEN};
+        for code in $codes {
+            for ual in [false, true] {
+                let flags = ParseFlags {
+                    ual,
+                    ..Default::default()
+                };
+                let parsed = Ins::new(code, &flags).parse(&flags);
+                assert!(
+                    parsed.mnemonic.len() <= MAX_MNEMONIC_LEN,
+                    "{} {code:08x}: '{}' is longer than {MAX_MNEMONIC_LEN}",
+                    stringify!($module),
+                    parsed.mnemonic
+                );
+                for options in OPTIONS {
+                    let text = parsed.display(options).to_string();
+                    assert!(
+                        text.len() <= MAX_RENDERED_LEN,
+                        "{} {code:08x}: '{text}' is longer than {MAX_RENDERED_LEN}",
+                        stringify!($module)
+                    );
+                }
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_max_len_arm() {
+    let codes = arm_codes();
+    check_max_len!(v4t, arm, codes.iter().copied());
+    check_max_len!(v5te, arm, codes.iter().copied());
+    check_max_len!(v6k, arm, codes.iter().copied());
+}
+
+#[test]
+fn test_max_len_thumb() {
+    check_max_len!(v4t, thumb, 0..=0xffff);
+    check_max_len!(v5te, thumb, 0..=0xffff);
+    check_max_len!(v6k, thumb, 0..=0xffff);
+}
+
+#[test]
+fn test_max_len_ldm() {
+    use unarm::v5te::arm::{Ins, MAX_RENDERED_LEN};
+
+    // The longest instruction: ldmdbne r10!, {r0-pc}^
+    let flags = ParseFlags::default();
+    let text = Ins::new(0x197affff, &flags)
+        .parse(&flags)
+        .display(Default::default())
+        .to_string();
+    assert_eq!(
+        text,
+        "ldmdbne r10!, {r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11, r12, sp, lr, pc}^"
+    );
+    assert_eq!(text.len(), MAX_RENDERED_LEN);
+}
@@ -0,0 +1,163 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use unarm::{
+    analysis::{check_literals, segment_mapped, Flow, LiteralIssue, LiteralIssueKind},
+    ArmVersion, Endian, MemoryMap, ParseFlags, ParseMode, Parser, SkippedStyle, StreamOptions,
+};
+
+const MAIN: u32 = 0x02000000;
+const OVERLAY: u32 = 0x02100000;
+
+/// A file with a header followed by two segments: main code at 0x02000000 and an overlay at 0x02100000
+fn image() -> (Vec<u8>, MemoryMap) {
+    let words = [
+        // Header
+        0xdeadbeef,
+        // 0x02000000: bl #0x02100000
+        0xeb03fffe,
+        // 0x02000004: bx lr
+        0xe12fff1e,
+        // 0x02100000: mov r0, #0x0
+        0xe3a00000,
+        // 0x02100004: b #0x03000000, outside of any segment
+        0xea3bfffd,
+        // 0x02100008: pointer to the main segment
+        MAIN + 0x4,
+    ];
+    let file = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    let mut map = MemoryMap::new();
+    map.push(0x4..0xc, MAIN);
+    map.push(0xc..0x18, OVERLAY);
+    (file, map)
+}
+
+fn parser() -> Parser<'static> {
+    Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[],
+    )
+}
+
+#[test]
+fn test_addresses() {
+    let (file, map) = image();
+    assert_eq!(map.file_offset(MAIN), Some(0x4));
+    assert_eq!(map.file_offset(OVERLAY + 0x8), Some(0x14));
+    assert_eq!(map.file_offset(MAIN + 0x8), None);
+    assert!(!map.is_mapped(0x03000000));
+    assert_eq!(map.bytes_from(&file, OVERLAY + 0x8), Some(&file[0x14..]));
+    assert_eq!(
+        map.segment(OVERLAY + 0x4).map(|segment| segment.addresses()),
+        Some(OVERLAY..OVERLAY + 0xc)
+    );
+}
+
+#[test]
+fn test_stream() {
+    let (file, map) = image();
+    let options = StreamOptions {
+        skip_ranges: vec![OVERLAY + 0x8..OVERLAY + 0xc],
+        render_skipped: SkippedStyle::WordTable,
+    };
+    let lines: Vec<_> = map
+        .stream(&file, &parser(), &options)
+        .map(|(address, _, ins)| {
+            let text = ins.display_with_pc(Default::default(), address, ParseMode::Arm).to_string();
+            (address, text)
+        })
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            (MAIN, "bl #0x2100000".to_string()),
+            (MAIN + 0x4, "bx lr".to_string()),
+            (OVERLAY, "mov r0, #0x0".to_string()),
+            (OVERLAY + 0x4, "b #0x3000000".to_string()),
+            (OVERLAY + 0x8, ".word #0x2000004".to_string()),
+        ]
+    );
+
+    // The pointer into the main segment is resolved, even though it's outside of the overlay
+    let overlay = map.bytes_from(&file, OVERLAY).unwrap();
+    let stream = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        OVERLAY,
+        Endian::Little,
+        ParseFlags::default(),
+        overlay,
+    )
+    .with_options(options.clone());
+    let (address, _, ins) = stream.clone().nth(2).unwrap();
+    assert_eq!(stream.pointer_target(address, &ins), None);
+    assert_eq!(stream.with_map(&map).pointer_target(address, &ins), Some(MAIN + 0x4));
+}
+
+#[test]
+fn test_segment_mapped() {
+    let (file, map) = image();
+    let cfg = segment_mapped(&file, &map, &parser(), &[MAIN, OVERLAY, 0x03000000]);
+
+    let blocks: Vec<_> = cfg.blocks.iter().map(|block| (block.start, block.end)).collect();
+    assert_eq!(blocks, [(MAIN, MAIN + 0x8), (OVERLAY, OVERLAY + 0x8)]);
+    assert_eq!(cfg.blocks[0].instructions[0].2.mnemonic, "bl");
+    assert_eq!(
+        cfg.blocks[1].flow,
+        Flow::Branch {
+            target: 0x03000000,
+            conditional: false
+        }
+    );
+    // The cross-segment call is resolved, but the branch out of the overlay is not
+    assert_eq!(cfg.dangling, [OVERLAY + 0x4]);
+    assert!(cfg.functions[2].blocks.is_empty());
+}
+
+#[test]
+fn test_check_literals() {
+    let words: [u32; 6] = [
+        // 0x02000000: ldr r0, [pc, #0x8]
+        0xe59f0008, // 0x02000004: ldr r1, [pc, #0x100], past the end of the segment
+        0xe59f1100, // 0x02000008: ldrh r2, [pc, #0x1], misaligned
+        0xe1df20b1, // 0x0200000c: bx lr
+        0xe12fff1e, // 0x02000010: literal pool
+        0x12345678, // 0x02000014: ldr r3, [pc, #-0x14], loads the ldrh above
+        0xe51f3014,
+    ];
+    let file: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    let mut map = MemoryMap::new();
+    map.push(0..0x18, MAIN);
+
+    let issue = |address, target, size, kind| LiteralIssue {
+        address: MAIN + address,
+        target: MAIN + target,
+        size,
+        kind,
+    };
+    assert_eq!(
+        check_literals(&file, &map, &parser(), &[]),
+        [
+            issue(0x4, 0x10c, 4, LiteralIssueKind::Unmapped),
+            issue(0x8, 0x11, 2, LiteralIssueKind::Misaligned),
+        ]
+    );
+
+    // With mapping symbols, the literal pool is data and the rest is code
+    let modes = [
+        (MAIN, ParseMode::Arm),
+        (MAIN + 0x10, ParseMode::Data),
+        (MAIN + 0x14, ParseMode::Arm),
+    ];
+    assert_eq!(
+        check_literals(&file, &map, &parser(), &modes),
+        [
+            issue(0x4, 0x10c, 4, LiteralIssueKind::Unmapped),
+            issue(0x8, 0x11, 2, LiteralIssueKind::Misaligned),
+            issue(0x14, 0x8, 4, LiteralIssueKind::InCode),
+        ]
+    );
+}
@@ -0,0 +1,129 @@
+use unarm::{
+    analysis::RegisterSet,
+    args::{Argument, Reg, Register},
+    ArmVersion, Op, ParseFlags, ParsedIns,
+};
+
+const STRICT: ParseFlags = ParseFlags {
+    unpredictable_as_illegal: true,
+    ..ParseFlags::DEFAULT
+};
+
+fn reg(reg: Register) -> Argument {
+    Argument::Reg(Reg {
+        deref: false,
+        reg,
+        writeback: false,
+    })
+}
+
+fn regs(regs: &[Register]) -> RegisterSet {
+    regs.iter().copied().collect()
+}
+
+fn parse_v6k(code: u32) -> (unarm::v6k::arm::Ins, ParsedIns) {
+    let flags = ParseFlags::default();
+    let ins = unarm::v6k::arm::Ins::new(code, &flags);
+    (ins, ins.parse(&flags))
+}
+
+#[test]
+fn test_umaal_structure() {
+    use unarm::v6k::arm::Opcode;
+
+    // umaal r2, r1, r4, r3
+    let (ins, parsed) = parse_v6k(0xe0412394);
+    assert_eq!(ins.op, Opcode::Umaal);
+    assert_eq!(parsed.display(Default::default()).to_string(), "umaal r2, r1, r4, r3");
+    assert_eq!(
+        parsed.args_iter().copied().collect::<Vec<_>>(),
+        [reg(Register::R2), reg(Register::R1), reg(Register::R4), reg(Register::R3)]
+    );
+    let names: Vec<_> = ins.op.arg_meta(&Default::default()).iter().map(|m| m.name).collect();
+    assert_eq!(names, ["RdLo", "RdHi", "Rm", "Rs"]);
+    assert_eq!(ins.op.describe(), "Unsigned Multiply Accumulate Accumulate Long");
+
+    // Not a branch, load or store, and conditional like the other long multiplies
+    assert!(!ins.op.is_branch() && !ins.op.is_load() && !ins.op.is_store());
+    assert_eq!(
+        parse_v6k(0x30412394).1.display(Default::default()).to_string(),
+        "umaallo r2, r1, r4, r3"
+    );
+}
+
+#[test]
+fn test_umaal_defs_uses() {
+    use Register::*;
+
+    let (ins, parsed) = parse_v6k(0xe0412394);
+    let op = Op::ArmV6K(ins.op);
+    // Both halves are accumulators, so they are read as well as written
+    assert_eq!(parsed.defs(op), regs(&[R1, R2]));
+    assert_eq!(parsed.uses(op), regs(&[R1, R2, R3, R4]));
+
+    // umull only reads its sources
+    let (ins, parsed) = parse_v6k(0xe0812394);
+    assert_eq!(parsed.display(Default::default()).to_string(), "umull r2, r1, r4, r3");
+    assert_eq!(parsed.defs(Op::ArmV6K(ins.op)), regs(&[R1, R2]));
+    assert_eq!(parsed.uses(Op::ArmV6K(ins.op)), regs(&[R3, R4]));
+}
+
+#[test]
+fn test_umaal_constraints() {
+    use unarm::v6k::arm::{Ins, Opcode};
+
+    let cases: [(u32, Option<&str>); 5] = [
+        // umaal r2, r1, r4, r3
+        (0xe0412394, None),
+        // umaal r1, r1, r4, r3
+        (0xe0411394, Some("Same destination register for both halves")),
+        // umaal pc, r1, r4, r3
+        (0xe041f394, Some("PC as destination register")),
+        // umaal r2, pc, r4, r3
+        (0xe04f2394, Some("PC as destination register")),
+        // umaal r2, r1, r2, r1, the sources may overlap the destinations
+        (0xe0412192, None),
+    ];
+    for (code, reason) in cases {
+        let ins = Ins::new(code, &Default::default());
+        assert_eq!(ins.op, Opcode::Umaal, "{code:08x}");
+        assert_eq!(ins.unpredictable_reason(), reason, "{code:08x}");
+        let strict = Ins::new(code, &STRICT);
+        let expected = if reason.is_some() { Opcode::Illegal } else { Opcode::Umaal };
+        assert_eq!(strict.op, expected, "{code:08x}");
+    }
+}
+
+#[test]
+fn test_umaal_versions() {
+    // UMAAL was introduced in ARMv6, the encoding is not a multiply in earlier versions
+    let flags = ParseFlags::default();
+    let v5te = unarm::v5te::arm::Ins::new(0xe0412394, &flags);
+    assert_eq!(v5te.parse(&flags).display(Default::default()).to_string(), "<illegal>");
+    let v4t = unarm::v4t::arm::Ins::new(0xe0412394, &flags);
+    assert_eq!(v4t.parse(&flags).display(Default::default()).to_string(), "<illegal>");
+
+    // Only v6k has an opcode named umaal
+    assert!(unarm::v4t::arm::Opcode::fuzzy_search("umaal", 8)
+        .iter()
+        .all(|&(_, score)| score < 1000));
+    assert!(unarm::v5te::arm::Opcode::fuzzy_search("umaal", 8)
+        .iter()
+        .all(|&(_, score)| score < 1000));
+    assert_eq!(
+        unarm::v6k::arm::Opcode::fuzzy_search("umaal", 1),
+        [(unarm::v6k::arm::Opcode::Umaal, 1000)]
+    );
+
+    // The Parser agrees with the modules
+    let bytes = 0xe0412394u32.to_le_bytes();
+    for (version, expected) in [
+        (ArmVersion::V4T, "<illegal>"),
+        (ArmVersion::V5Te, "<illegal>"),
+        (ArmVersion::V6K, "umaal r2, r1, r4, r3"),
+    ] {
+        let mut parser = unarm::Parser::new(version, unarm::ParseMode::Arm, 0, unarm::Endian::Little, flags, &bytes);
+        let (_, _, parsed) = parser.next().unwrap();
+        assert_eq!(parsed.display(Default::default()).to_string(), expected, "{version:?}");
+    }
+}
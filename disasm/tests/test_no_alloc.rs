@@ -0,0 +1,109 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Write,
+};
+
+use unarm::{v5te, DisplayOptions, ImmediateStyle, ParseFlags, ParseMode, ParsedIns, RegNames};
+
+/// Counts the allocations of the current thread, as the tests of this file run in parallel
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn corpus(flags: &ParseFlags) -> Vec<(ParsedIns, ParsedIns)> {
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    (0..0x4000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let arm = v5te::arm::Ins::new(state, flags).parse(flags);
+            let thumb = v5te::thumb::Ins::new(state & 0xffff, flags).parse(flags);
+            (arm, thumb)
+        })
+        .collect()
+}
+
+#[test]
+fn test_write_str() {
+    let options = [
+        DisplayOptions::default(),
+        DisplayOptions {
+            reg_names: RegNames {
+                av_registers: true,
+                frame_pointer: true,
+                ..Default::default()
+            },
+            reg_list_ranges: true,
+            immediate_style: ImmediateStyle::HexAboveThreshold(9),
+            pc_relative_comment: true,
+            adr: true,
+            cp15_names: true,
+            uppercase: true,
+            mnemonic_width: Some(8),
+            ..Default::default()
+        },
+    ];
+    for flags in [
+        ParseFlags::default(),
+        ParseFlags {
+            ual: false,
+            ..Default::default()
+        },
+    ] {
+        let corpus = corpus(&flags);
+        for options in options {
+            let mut text = String::with_capacity(256);
+            let before = allocations();
+            for (arm, thumb) in corpus.iter() {
+                text.clear();
+                arm.write_str(&mut text, options).unwrap();
+                text.clear();
+                write!(text, "{}", thumb.display_with_pc(options, 0x2000000, ParseMode::Thumb)).unwrap();
+                for arg in arm.args_iter() {
+                    text.clear();
+                    arg.write_str(&mut text, options).unwrap();
+                }
+            }
+            assert_eq!(allocations(), before, "{options:?}");
+        }
+    }
+}
+
+#[test]
+fn test_same_text() {
+    let flags = ParseFlags::default();
+    let mut text = String::new();
+    for (arm, _) in corpus(&flags) {
+        text.clear();
+        arm.write_str(&mut text, Default::default()).unwrap();
+        assert_eq!(text, arm.display(Default::default()).to_string());
+        if let Some(arg) = arm.args_iter().next() {
+            text.clear();
+            arg.write_str(&mut text, Default::default()).unwrap();
+            assert_eq!(text, arg.display(Default::default()).to_string());
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Links `Opcode::find` and the parse functions of every decoder, so that `cargo xtask no-panic` fails to link this test
+//! if any of them may panic. See `disasm/src/no_panic.rs`.
+
+use unarm::ParseFlags;
+
+macro_rules! decode {
+    ($version:ident, $isa:ident, $code:expr, $flags:expr) => {{
+        use unarm::$version::$isa::{Ins, Opcode};
+        let ins = Ins {
+            code: $code,
+            op: Opcode::find($code, $flags),
+        };
+        ins.parse($flags)
+    }};
+}
+
+#[test]
+fn test_decode_every_version() {
+    let flags = [
+        ParseFlags::default(),
+        ParseFlags {
+            ual: false,
+            vfp: true,
+            ..Default::default()
+        },
+    ];
+    // xorshift32
+    let mut code = 0x2468ace1u32;
+    for _ in 0..0x1000 {
+        code ^= code << 13;
+        code ^= code >> 17;
+        code ^= code << 5;
+        for flags in &flags {
+            for parsed in [
+                decode!(v4t, arm, code, flags),
+                decode!(v4t, thumb, code & 0xffff, flags),
+                decode!(v5te, arm, code, flags),
+                decode!(v5te, thumb, code & 0xffff, flags),
+                decode!(v6k, arm, code, flags),
+                decode!(v6k, thumb, code & 0xffff, flags),
+            ] {
+                assert!(!parsed.mnemonic.is_empty());
+            }
+        }
+    }
+}
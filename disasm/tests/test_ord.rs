@@ -0,0 +1,128 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use unarm::{
+    args::{Argument, Reg, Register},
+    v5te::arm::{Ins, Opcode},
+    ArmVersion, Condition, Endian, ParseFlags, ParseMode, ParsedIns, Parser,
+};
+
+const MODULES: [(ArmVersion, ParseMode); 6] = [
+    (ArmVersion::V4T, ParseMode::Arm),
+    (ArmVersion::V4T, ParseMode::Thumb),
+    (ArmVersion::V5Te, ParseMode::Arm),
+    (ArmVersion::V5Te, ParseMode::Thumb),
+    (ArmVersion::V6K, ParseMode::Arm),
+    (ArmVersion::V6K, ParseMode::Thumb),
+];
+
+fn corpus(version: ArmVersion, mode: ParseMode) -> Vec<ParsedIns> {
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    let bytes: Vec<u8> = (0..0x4000)
+        .flat_map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            // Narrow the random space so that some instructions repeat
+            (state & 0xfff0_0fff).to_le_bytes()
+        })
+        .collect();
+
+    let parser = Parser::new(version, mode, 0, Endian::Little, ParseFlags::default(), &bytes);
+    parser.map(|(_, _, parsed)| parsed).collect()
+}
+
+#[test]
+fn test_sort_is_deterministic() {
+    let corpus: Vec<_> = MODULES
+        .into_iter()
+        .flat_map(|(version, mode)| corpus(version, mode))
+        .collect();
+    let mut sorted = corpus.clone();
+    sorted.sort();
+
+    // Fisher-Yates with a fixed seed
+    let mut shuffled = corpus;
+    let mut state = 0x9e3779b9u32;
+    for i in (1..shuffled.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        shuffled.swap(i, state as usize % (i + 1));
+    }
+    shuffled.sort_unstable();
+    assert_eq!(shuffled, sorted);
+    assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_hash_dedup_matches_strings() {
+    // Only within one instruction set, as the same text can have different arguments in another. For example, `blx`
+    // has a branch destination in ARM but an unsigned immediate in the second half of a Thumb `bl`/`blx` pair.
+    for (version, mode) in MODULES {
+        let corpus = corpus(version, mode);
+        let unique: HashSet<&ParsedIns> = corpus.iter().collect();
+        let strings: HashSet<String> = corpus.iter().map(|ins| ins.display(Default::default()).to_string()).collect();
+        assert!(unique.len() < corpus.len(), "{version:?} {mode:?}");
+        assert_eq!(unique.len(), strings.len(), "{version:?} {mode:?}");
+
+        let mut deduped = corpus.clone();
+        deduped.sort();
+        deduped.dedup();
+        assert_eq!(deduped.len(), unique.len(), "{version:?} {mode:?}");
+    }
+}
+
+#[test]
+fn test_btree_set() {
+    let flags = ParseFlags::default();
+    let set: BTreeSet<ParsedIns> = [
+        0xe5912004, // ldr r2, [r1, #0x4]
+        0xe0812007, // add r2, r1, r7
+        0x10812007, // addne r2, r1, r7
+        0xe0812003, // add r2, r1, r3
+        0xe0812007, // add r2, r1, r7
+    ]
+    .into_iter()
+    .map(|code| Ins::new(code, &flags).parse(&flags))
+    .collect();
+    let text: Vec<_> = set.iter().map(|ins| ins.display(Default::default()).to_string()).collect();
+    assert_eq!(
+        text,
+        ["add r2, r1, r3", "add r2, r1, r7", "addne r2, r1, r7", "ldr r2, [r1, #0x4]"]
+    );
+
+    // Ordered like the tuple of mnemonic and arguments
+    let tuples: Vec<_> = set.iter().map(|ins| (ins.mnemonic, ins.args)).collect();
+    assert!(tuples.is_sorted());
+}
+
+#[test]
+fn test_map_keys() {
+    let flags = ParseFlags::default();
+    let codes = [0xe0812007, 0x10812007, 0xe0812003, 0xe5912004];
+
+    let mut opcodes: BTreeMap<Opcode, usize> = BTreeMap::new();
+    let mut conditions: HashMap<Condition, usize> = HashMap::new();
+    let mut args: HashMap<Argument, usize> = HashMap::new();
+    for code in codes {
+        let ins = Ins::new(code, &flags);
+        *opcodes.entry(ins.op).or_default() += 1;
+        *conditions.entry(ins.parse(&flags).condition()).or_default() += 1;
+        for arg in ins.parse(&flags).args_iter() {
+            *args.entry(*arg).or_default() += 1;
+        }
+    }
+    assert_eq!(opcodes.into_iter().collect::<Vec<_>>(), [(Opcode::Add, 3), (Opcode::Ldr, 1)]);
+    assert_eq!(conditions[&Condition::Al], 3);
+    assert_eq!(conditions[&Condition::Ne], 1);
+    let r2 = Argument::Reg(Reg {
+        deref: false,
+        reg: Register::R2,
+        writeback: false,
+    });
+    assert_eq!(args[&r2], 4);
+
+    let unique: BTreeSet<Ins> = codes.iter().chain(&codes).map(|&code| Ins::new(code, &flags)).collect();
+    assert_eq!(unique.len(), codes.len());
+}
@@ -0,0 +1,48 @@
+use unarm::{ParseFlags, ParsedIns};
+
+const UNIFIED: ParseFlags = ParseFlags {
+    vfp: true,
+    ..ParseFlags::DEFAULT
+};
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+
+/// Parses every code with both implementations of `parse` and checks that they agree
+macro_rules! check_equivalent {
+    ($version:ident, $mode:ident, $codes:expr) => {{
+        use unarm::$version::$mode::{parse_match, parse_table, Ins};
+        let mut table = ParsedIns::default();
+        let mut matched = ParsedIns::default();
+        for code in $codes {
+            for flags in [UNIFIED, DIVIDED] {
+                let ins = Ins::new(code, &flags);
+                parse_table(&mut table, ins, &flags);
+                parse_match(&mut matched, ins, &flags);
+                assert_eq!(matched, table, "{code:#010x}");
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_match_equals_table() {
+    // xorshift32
+    let mut state = 0x2545f491u32;
+    let arm_codes: Vec<u32> = (0..0x100000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .collect();
+
+    check_equivalent!(v4t, arm, arm_codes.iter().copied());
+    check_equivalent!(v5te, arm, arm_codes.iter().copied());
+    check_equivalent!(v6k, arm, arm_codes.iter().copied());
+    check_equivalent!(v4t, thumb, 0..=0xffff);
+    check_equivalent!(v5te, thumb, 0..=0xffff);
+    check_equivalent!(v6k, thumb, 0..=0xffff);
+}
@@ -0,0 +1,91 @@
+use unarm::{
+    patch::{branch_to, relocate_instruction, RelocError},
+    ParseFlags, ParseMode, ParsedIns,
+};
+
+fn parse(code: u32) -> ParsedIns {
+    let flags = ParseFlags::default();
+    unarm::v5te::arm::Ins::new(code, &flags).parse(&flags)
+}
+
+/// Returns the address an instruction refers to, either as a branch or as a PC-relative operand
+fn target(code: u32, address: u32) -> Option<u32> {
+    let parsed = parse(code);
+    parsed
+        .branch_destination(address, ParseMode::Arm)
+        .or_else(|| parsed.pc_relative_address(address, ParseMode::Arm))
+}
+
+#[test]
+fn test_relocate() {
+    let old = 0x02001000;
+    let cases: [(u32, &str); 9] = [
+        (0xea000010, "b #0x48"),
+        (0x1b000010, "blne #0x48"),
+        (0xfa000010, "blx #0x48"),
+        (0xfb000010, "blx #0x4a"),
+        (0xe59f001c, "ldr r0, [pc, #0x1c]"),
+        (0xe55f1004, "ldrb r1, [pc, #-0x4]"),
+        (0xe28f0010, "add r0, pc, #0x10"),
+        (0xe24f4004, "sub r4, pc, #0x4"),
+        (0xe28f0b01, "add r0, pc, #0x400"),
+    ];
+    for new in [0x02001000, 0x02001100, 0x02000f00, 0x02000f80] {
+        for (code, text) in cases {
+            assert_eq!(parse(code).display(Default::default()).to_string(), text);
+            let relocated = relocate_instruction(code, old, new).unwrap_or_else(|e| panic!("{text} to {new:#x}: {e}"));
+            // Same operation on the same registers, referring to the same address
+            let (before, after) = (parse(code), parse(relocated));
+            assert_eq!(target(relocated, new), target(code, old), "{text} to {new:#x}");
+            if before.branch_destination(old, ParseMode::Arm).is_none() {
+                assert_eq!(before.args[0], after.args[0], "{text} to {new:#x}");
+            }
+            if !matches!(before.mnemonic, "add" | "sub") {
+                assert_eq!(before.mnemonic, after.mnemonic, "{text} to {new:#x}");
+            }
+        }
+    }
+    // adr turns into the other direction when moved past its target
+    let relocated = relocate_instruction(0xe28f0010, 0x1000, 0x1100).unwrap();
+    assert_eq!(parse(relocated).display(Default::default()).to_string(), "sub r0, pc, #0xf0");
+}
+
+#[test]
+fn test_relocate_unchanged() {
+    for code in [0xe0810002, 0xe5910004, 0xe12fff1e, 0xe1a0f000, 0xe8bd8010] {
+        assert_eq!(relocate_instruction(code, 0x1000, 0x80000), Ok(code));
+    }
+}
+
+#[test]
+fn test_relocate_errors() {
+    // ldr r0, [pc, #0x1c] can only reach 4 KB
+    assert_eq!(relocate_instruction(0xe59f001c, 0x1000, 0x3000), Err(RelocError::OutOfRange));
+    // b #0x48 can only reach 32 MB
+    assert_eq!(relocate_instruction(0xea000010, 0, 0x04000000), Err(RelocError::OutOfRange));
+    // add r0, pc, #0x10 to an offset which isn't a rotated 8-bit immediate
+    assert_eq!(relocate_instruction(0xe28f0010, 0x1000, 0xc0c), Err(RelocError::OutOfRange));
+    // add r0, pc, r1
+    assert_eq!(relocate_instruction(0xe08f0001, 0x1000, 0x2000), Err(RelocError::Unsupported));
+    // ldrh r0, [pc, #0x4]
+    assert_eq!(relocate_instruction(0xe1df00b4, 0x1000, 0x2000), Err(RelocError::Unsupported));
+    // mov r0, pc
+    assert_eq!(relocate_instruction(0xe1a0000f, 0x1000, 0x2000), Err(RelocError::Unsupported));
+}
+
+#[test]
+fn test_branch_to() {
+    let code = branch_to(0x02000000, 0x02000100).unwrap();
+    assert_eq!(parse(code).display(Default::default()).to_string(), "b #0x100");
+    assert_eq!(target(code, 0x02000000), Some(0x02000100));
+
+    let code = branch_to(0x02000100, 0x02000000).unwrap();
+    assert_eq!(target(code, 0x02000100), Some(0x02000000));
+
+    assert_eq!(branch_to(0x02000000, 0x02000102), None);
+    // The offset from PC is at most 32 MB - 4 forward and 32 MB backward
+    assert!(branch_to(0, 0x01fffffc + 8).is_some());
+    assert_eq!(branch_to(0, 0x02000000 + 8), None);
+    assert!(branch_to(0x02000000, 8).is_some());
+    assert_eq!(branch_to(0x02000004, 8), None);
+}
@@ -0,0 +1,137 @@
+use unarm::{
+    args::{RegList, Register},
+    ins_match, ins_matches,
+    v5te::{arm, thumb},
+    ParseFlags, ParsedIns,
+};
+
+fn arm(code: u32) -> ParsedIns {
+    let flags = ParseFlags::default();
+    arm::Ins::new(code, &flags).parse(&flags)
+}
+
+fn thumb(code: u32) -> ParsedIns {
+    let flags = ParseFlags::default();
+    thumb::Ins::new(code, &flags).parse(&flags)
+}
+
+#[test]
+fn test_prologue() {
+    // push {r4, lr}
+    for ins in [arm(0xe92d4010), thumb(0xb510)] {
+        assert!(ins_matches!(ins, push { r4, lr }));
+        assert!(!ins_matches!(ins, push { r4, r5, lr }));
+        assert!(!ins_matches!(ins, pop { r4, lr }));
+        let (list,) = ins_match!(ins, push { regs }).unwrap();
+        assert_eq!(list.regs, 1 << 4 | 1 << 14);
+    }
+
+    // push {lr}
+    assert!(ins_matches!(arm(0xe92d4000), push { lr }));
+    assert!(!ins_matches!(arm(0xe92d4000), push { pc }));
+
+    // sub sp, sp, #0x10
+    for ins in [arm(0xe24dd010), thumb(0xb084)] {
+        assert_eq!(ins_match!(ins, sub sp, sp, #size), Some((0x10,)));
+        assert!(ins_matches!(ins, sub sp, sp, #0x10));
+        assert!(!ins_matches!(ins, sub sp, sp, #0x20));
+        assert!(!ins_matches!(ins, sub sp, sp));
+        assert!(!ins_matches!(ins, sub sp, sp, #size, _));
+    }
+}
+
+#[test]
+fn test_epilogue() {
+    // add sp, sp, #0x10
+    for ins in [arm(0xe28dd010), thumb(0xb004)] {
+        assert_eq!(ins_match!(ins, add sp, sp, #size), Some((0x10,)));
+        assert!(!ins_matches!(ins, sub sp, sp, #size));
+    }
+
+    // pop {r4, pc}
+    for ins in [arm(0xe8bd8010), thumb(0xbd10)] {
+        assert!(ins_matches!(ins, pop { r4, pc }));
+        assert!(ins_matches!(ins, pop { _ }));
+        assert!(!ins_matches!(ins, pop { r4, lr }));
+    }
+
+    // bx lr
+    for ins in [arm(0xe12fff1e), thumb(0x4770)] {
+        assert!(ins_matches!(ins, bx lr));
+        assert!(ins_matches!(ins, bx.al lr));
+        assert!(!ins_matches!(ins, bx.eq lr));
+        assert_eq!(ins_match!(ins, bx rm), Some((Register::Lr,)));
+        assert_eq!(ins_match!(ins, _ r14), Some(()));
+    }
+
+    // moveq pc, lr
+    let ins = arm(0x01a0f00e);
+    assert!(ins_matches!(ins, mov pc, lr));
+    assert!(ins_matches!(ins, mov.eq pc, lr));
+    assert!(!ins_matches!(ins, mov.ne pc, lr));
+    assert!(!ins_matches!(ins, mov pc, lr, _));
+}
+
+#[test]
+fn test_jump_table() {
+    // cmp r0, #0x3
+    for ins in [arm(0xe3500003), thumb(0x2803)] {
+        assert_eq!(ins_match!(ins, cmp index, #count), Some((Register::R0, 3)));
+    }
+
+    // addls pc, pc, r0, lsl #0x2
+    let ins = arm(0x908ff100);
+    assert_eq!(ins_match!(ins, add.ls pc, pc, index, lsl #2), Some((Register::R0,)));
+    assert_eq!(ins_match!(ins, add pc, pc, index, lsl #amount), Some((Register::R0, 2)));
+    assert!(ins_matches!(ins, add.ls pc, pc, r0, _));
+    assert!(!ins_matches!(ins, add.hi pc, pc, _, _));
+    assert!(!ins_matches!(ins, add pc, pc, r0, lsr #2));
+    assert!(!ins_matches!(ins, add pc, pc, r0));
+
+    // ldrls pc, [pc, r0, lsl #0x2]
+    let ins = arm(0x979ff100);
+    assert_eq!(ins_match!(ins, ldr.ls pc, [pc, index, lsl #2]), Some((Register::R0,)));
+    assert!(!ins_matches!(ins, ldr pc, [pc, -index, lsl #2]));
+    assert!(!ins_matches!(ins, ldr pc, [pc, index, lsl #2]!));
+    assert!(!ins_matches!(ins, ldr pc, [pc]));
+}
+
+#[test]
+fn test_memory() {
+    // ldr r0, [r1, #0x4]!
+    let ins = arm(0xe5b10004);
+    assert_eq!(ins_match!(ins, ldr rt, [rn, #offset]!), Some((Register::R0, Register::R1, 4)));
+    assert!(ins_matches!(ins, ldr r0, [r1, #4]!));
+    assert!(!ins_matches!(ins, ldr r0, [r1, #4]));
+    assert!(!ins_matches!(ins, ldr r0, [r1], #4));
+
+    // ldr r0, [r1], #-0x4
+    let ins = arm(0xe4110004);
+    assert_eq!(ins_match!(ins, ldr r0, [r1], #offset), Some((-4,)));
+    assert!(ins_matches!(ins, ldr r0, [r1], #-4));
+
+    // ldr r0, [r1, -r2]
+    let ins = arm(0xe7110002);
+    assert_eq!(ins_match!(ins, ldr _, [rn, -rm]), Some((Register::R1, Register::R2)));
+    assert!(!ins_matches!(ins, ldr _, [rn, rm]));
+
+    // ldr r0, [sp, #0x4]
+    let ins = thumb(0x9801);
+    assert_eq!(ins_match!(ins, ldr rt, [sp, #offset]), Some((Register::R0, 4)));
+}
+
+#[test]
+fn test_bindings() {
+    // stmdb sp!, {r4, lr} with a user-mode register list doesn't bind
+    let ins = arm(0xe96d4010);
+    assert_eq!(ins_match!(ins, _ sp!, { _ }), None);
+
+    // stmdb r0!, {r4, lr}
+    let ins = arm(0xe9204010);
+    let expected = RegList {
+        regs: 1 << 4 | 1 << 14,
+        user_mode: false,
+    };
+    assert_eq!(ins_match!(ins, stmdb base!, { regs }), Some((Register::R0, expected)));
+    assert!(!ins_matches!(ins, stmdb base, { regs }));
+}
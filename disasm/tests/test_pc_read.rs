@@ -0,0 +1,72 @@
+use unarm::ParseFlags;
+
+#[test]
+fn test_arm() {
+    use unarm::v5te::arm::Ins;
+
+    let cases: [(u32, &str, Option<u32>); 10] = [
+        // PC as Rn or Rm with no shift or an immediate shift reads 8 bytes ahead
+        (0xe08f0001, "add r0, pc, r1", Some(0x1008)),
+        (0xe081000f, "add r0, r1, pc", Some(0x1008)),
+        (0xe08f0101, "add r0, pc, r1, lsl #0x2", Some(0x1008)),
+        (0xe081010f, "add r0, r1, pc, lsl #0x2", Some(0x1008)),
+        (0xe1a0000f, "mov r0, pc", Some(0x1008)),
+        // A register-specified shift reads PC one cycle later, 12 bytes ahead
+        (0xe08f0211, "add r0, pc, r1, lsl r2", Some(0x100c)),
+        (0xe081021f, "add r0, r1, pc, lsl r2", Some(0x100c)),
+        (0xe1a0011f, "lsl r0, pc, r1", Some(0x100c)),
+        // Loads are not data-processing instructions
+        (0xe59f0004, "ldr r0, [pc, #0x4]", Some(0x1008)),
+        (0xe0810002, "add r0, r1, r2", None),
+    ];
+    let flags = ParseFlags::default();
+    for (code, text, expected) in cases {
+        let ins = Ins::new(code, &flags);
+        assert_eq!(ins.parse(&flags).display(Default::default()).to_string(), text);
+        assert_eq!(ins.pc_read_value(0x1000), expected, "{text}");
+    }
+    // Writing to PC is not reading it
+    assert_eq!(Ins::new(0xe1a0f000, &flags).pc_read_value(0x1000), None);
+}
+
+#[test]
+fn test_thumb() {
+    use unarm::v5te::thumb::Ins;
+
+    let cases: [(u32, &str, Option<u32>, Option<u32>); 6] = [
+        // High register operations read 4 bytes ahead
+        (0x4478, "add r0, r0, pc", Some(0x1004), Some(0x1006)),
+        (0x4678, "mov r0, pc", Some(0x1004), Some(0x1006)),
+        (0x4778, "bx pc", Some(0x1004), Some(0x1006)),
+        // PC-relative loads and address calculations align down to 4 bytes
+        (0x4801, "ldr r0, [pc, #0x4]", Some(0x1004), Some(0x1004)),
+        (0xa001, "adr r0, #0x4", Some(0x1004), Some(0x1004)),
+        (0x1888, "adds r0, r1, r2", None, None),
+    ];
+    let flags = ParseFlags::default();
+    for (code, text, aligned, unaligned) in cases {
+        let ins = Ins::new(code, &flags);
+        assert_eq!(ins.parse(&flags).display(Default::default()).to_string(), text);
+        assert_eq!(ins.pc_read_value(0x1000), aligned, "{text}");
+        assert_eq!(ins.pc_read_value(0x1002), unaligned, "{text}");
+    }
+}
+
+#[test]
+fn test_pc_relative_address_agrees() {
+    // The address of a PC-relative load is based on the same PC value
+    let flags = ParseFlags::default();
+    let ins = unarm::v5te::thumb::Ins::new(0x4801, &flags);
+    let parsed = ins.parse(&flags);
+    for address in [0x1000, 0x1002] {
+        assert_eq!(
+            parsed.pc_relative_address(address, unarm::ParseMode::Thumb),
+            ins.pc_read_value(address).map(|pc| pc + 4)
+        );
+    }
+    let ins = unarm::v5te::arm::Ins::new(0xe28f0010, &flags);
+    assert_eq!(
+        ins.parse(&flags).pc_relative_address(0x1000, unarm::ParseMode::Arm),
+        ins.pc_read_value(0x1000).map(|pc| pc + 0x10)
+    );
+}
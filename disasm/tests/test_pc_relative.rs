@@ -0,0 +1,161 @@
+use unarm::{DisplayOptions, ParseFlags, ParseMode, TokenKind};
+
+fn arm(code: u32) -> unarm::ParsedIns {
+    let flags = ParseFlags::default();
+    unarm::v5te::arm::Ins::new(code, &flags).parse(&flags)
+}
+
+fn thumb(code: u32, ual: bool) -> unarm::ParsedIns {
+    let flags = ParseFlags {
+        ual,
+        ..Default::default()
+    };
+    unarm::v5te::thumb::Ins::new(code, &flags).parse(&flags)
+}
+
+#[test]
+fn test_arm() {
+    let cases: [(u32, &str, Option<u32>); 10] = [
+        (0xe59f001c, "ldr r0, [pc, #0x1c]", Some(0x200024)),
+        (0xe51f0004, "ldr r0, [pc, #-0x4]", Some(0x200004)),
+        (0x159f1000, "ldrne r1, [pc, #0x0]", Some(0x200008)),
+        (0xe1df00b4, "ldrh r0, [pc, #0x4]", Some(0x20000c)),
+        (0xe1cf00d8, "ldrd r0, r1, [pc, #0x8]", Some(0x200010)),
+        (0xe28f0010, "add r0, pc, #0x10", Some(0x200018)),
+        (0xe24f0004, "sub r0, pc, #0x4", Some(0x200004)),
+        // Register offsets, post-indexing and stores are not resolved
+        (0xe79f0001, "ldr r0, [pc, r1]", None),
+        (0xe49f0004, "ldr r0, [pc], #0x4", None),
+        (0xe58f001c, "str r0, [pc, #0x1c]", None),
+    ];
+    for (code, text, target) in cases {
+        let parsed = arm(code);
+        assert_eq!(parsed.display(Default::default()).to_string(), text);
+        assert_eq!(parsed.pc_relative_address(0x200000, ParseMode::Arm), target, "{text}");
+    }
+    // Coprocessor loads and stores scale the offset by 4
+    let cases: [(u32, &str, Option<u32>); 5] = [
+        (0xed9f2104, "ldc p1, c2, [pc, #0x10]", Some(0x200018)),
+        (0xed1f2104, "ldc p1, c2, [pc, #-0x10]", Some(0x1ffff8)),
+        (0xed8f2104, "stc p1, c2, [pc, #0x10]", Some(0x200018)),
+        (0xedbf2104, "ldc p1, c2, [pc, #0x10]!", None),
+        (0xec9f2104, "ldc p1, c2, [pc], {0x4}", None),
+    ];
+    for (code, text, target) in cases {
+        let parsed = arm(code);
+        assert_eq!(parsed.display(Default::default()).to_string(), text);
+        assert_eq!(parsed.pc_relative_address(0x200000, ParseMode::Arm), target, "{text}");
+    }
+    // Not PC-relative
+    assert_eq!(arm(0xe5910004).pc_relative_address(0x200000, ParseMode::Arm), None);
+    assert_eq!(arm(0xe2810010).pc_relative_address(0x200000, ParseMode::Arm), None);
+}
+
+#[test]
+fn test_thumb_alignment() {
+    // ldr r0, [pc, #0x1c]
+    let ldr = thumb(0x4807, true);
+    // PC is the address plus 4, aligned down to 4
+    assert_eq!(ldr.pc_relative_address(0x1000, ParseMode::Thumb), Some(0x1020));
+    assert_eq!(ldr.pc_relative_address(0x1002, ParseMode::Thumb), Some(0x1020));
+    assert_eq!(ldr.pc_relative_address(0x1004, ParseMode::Thumb), Some(0x1024));
+    assert_eq!(ldr.pc_relative_address(0x1006, ParseMode::Thumb), Some(0x1024));
+
+    // adr r4, #0x4c, which is add r4, pc, #0x4c in divided syntax
+    for ual in [false, true] {
+        let adr = thumb(0xa413, ual);
+        assert_eq!(adr.pc_relative_address(0x1000, ParseMode::Thumb), Some(0x1050));
+        assert_eq!(adr.pc_relative_address(0x1002, ParseMode::Thumb), Some(0x1050));
+    }
+
+    // add r4, sp, #0x4c
+    assert_eq!(thumb(0xac13, true).pc_relative_address(0x1000, ParseMode::Thumb), None);
+    assert_eq!(ldr.pc_relative_address(0x1000, ParseMode::Data), None);
+}
+
+#[test]
+fn test_comment() {
+    let options = DisplayOptions {
+        pc_relative_comment: true,
+        ..Default::default()
+    };
+    let ldr = arm(0xe59f001c);
+    assert_eq!(
+        ldr.display_with_pc(options, 0x200480, ParseMode::Arm).to_string(),
+        "ldr r0, [pc, #0x1c]  ; 0x2004a4"
+    );
+    // Requires both the option and an address
+    assert_eq!(
+        ldr.display_with_pc(Default::default(), 0x200480, ParseMode::Arm).to_string(),
+        "ldr r0, [pc, #0x1c]"
+    );
+    assert_eq!(ldr.display(options).to_string(), "ldr r0, [pc, #0x1c]");
+    assert_eq!(
+        thumb(0xa413, true)
+            .display_with_pc(options, 0x1002, ParseMode::Thumb)
+            .to_string(),
+        "adr r4, #0x4c  ; 0x1050"
+    );
+    assert_eq!(
+        arm(0xed1f2104).display_with_pc(options, 0x200480, ParseMode::Arm).to_string(),
+        "ldc p1, c2, [pc, #-0x10]  ; 0x200478"
+    );
+    assert_eq!(
+        arm(0xe5910004).display_with_pc(options, 0x200480, ParseMode::Arm).to_string(),
+        "ldr r0, [r1, #0x4]"
+    );
+
+    let tokens = ldr.display_with_pc(options, 0x200480, ParseMode::Arm).tokens();
+    let comment = tokens.tokens.last().unwrap();
+    assert_eq!(comment.kind, TokenKind::Comment);
+    assert_eq!(tokens.text(comment), "; 0x2004a4");
+    assert_eq!(comment.arg_index, None);
+}
+
+#[test]
+fn test_adr() {
+    let options = DisplayOptions {
+        adr: true,
+        ..Default::default()
+    };
+    let cases: [(u32, &str, &str); 5] = [
+        (0xe28f0010, "adr r0, #0x10", "adr r0, #0x200018"),
+        (0xe24f0004, "adr r0, #-0x4", "adr r0, #0x200004"),
+        (0xe28f41a5, "adr r4, #0x40000029", "adr r4, #0x40200031"),
+        (0x124fcf41, "adrne r12, #-0x104", "adrne r12, #0x1fff04"),
+        // Not equivalent to adr
+        (0xe29f0010, "adds r0, pc, #0x10", "adds r0, pc, #0x10"),
+    ];
+    for (code, text, text_with_pc) in cases {
+        let parsed = arm(code);
+        assert_eq!(parsed.display(options).to_string(), text);
+        assert_eq!(
+            parsed.display_with_pc(options, 0x200000, ParseMode::Arm).to_string(),
+            text_with_pc
+        );
+    }
+    assert_eq!(arm(0xe2810010).display(options).to_string(), "add r0, r1, #0x10");
+    assert_eq!(arm(0xe08f0001).display(options).to_string(), "add r0, pc, r1");
+    // The raw form is kept without the option
+    assert_eq!(arm(0xe24f0004).display(Default::default()).to_string(), "sub r0, pc, #0x4");
+
+    // Thumb add r4, pc, #0x4c in divided syntax
+    for ual in [false, true] {
+        let adr = thumb(0xa413, ual);
+        assert_eq!(adr.display(options).to_string(), "adr r4, #0x4c");
+        assert_eq!(
+            adr.display_with_pc(options, 0x1002, ParseMode::Thumb).to_string(),
+            "adr r4, #0x1050"
+        );
+    }
+
+    // The address replaces the comment, and keeps the index of the immediate
+    let options = DisplayOptions {
+        pc_relative_comment: true,
+        ..options
+    };
+    let tokens = arm(0xe24f0004).display_with_pc(options, 0x200000, ParseMode::Arm).tokens();
+    assert_eq!(tokens.text, "adr r0, #0x200004");
+    let address = tokens.tokens.last().unwrap();
+    assert_eq!((tokens.text(address), address.arg_index), ("#0x200004", Some(2)));
+}
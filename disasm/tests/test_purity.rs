@@ -0,0 +1,114 @@
+//! Decoding and formatting must be pure: the same input always produces the same output, no matter how many threads are
+//! decoding at the same time. See the "Thread safety" section in the README.
+
+use std::thread;
+
+use unarm::{
+    testing::random_code, ArmVersion, DisplayOptions, Endian, IllegalStyle, ImmediateStyle, OperandSeparator, ParseFlags,
+    ParseMode, Parser, R9Use, RegNames,
+};
+
+const THREADS: usize = 8;
+const CORPUS_SIZE: usize = 0x4000;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+fn configs() -> Vec<(ArmVersion, ParseMode, ParseFlags, DisplayOptions<'static>)> {
+    let versions = [ArmVersion::V4T, ArmVersion::V5Te, ArmVersion::V6K];
+    let modes = [ParseMode::Arm, ParseMode::Thumb, ParseMode::Data];
+    let options = [
+        DisplayOptions::default(),
+        DisplayOptions {
+            reg_names: RegNames {
+                av_registers: true,
+                r9_use: R9Use::Tls,
+                explicit_stack_limit: true,
+                frame_pointer: true,
+                ip: true,
+            },
+            mnemonic_hook: None,
+            reg_list_ranges: true,
+            immediate_style: ImmediateStyle::HexAboveThreshold(9),
+            comment_field_style: Some(ImmediateStyle::Hex),
+            pc_relative_comment: true,
+            adr: true,
+            cp15_names: true,
+            uppercase: true,
+            mnemonic_width: Some(8),
+            operand_separator: OperandSeparator::Tab,
+            illegal_style: IllegalStyle::Placeholder,
+        },
+    ];
+    let mut configs = vec![];
+    for version in versions {
+        for mode in modes {
+            for ual in [false, true] {
+                for options in options {
+                    configs.push((
+                        version,
+                        mode,
+                        ParseFlags {
+                            ual,
+                            ..Default::default()
+                        },
+                        options,
+                    ));
+                }
+            }
+        }
+    }
+    configs
+}
+
+fn disassemble(code: &[u8], config: (ArmVersion, ParseMode, ParseFlags, DisplayOptions<'static>)) -> Vec<String> {
+    let (version, mode, flags, options) = config;
+    Parser::new(version, mode, 0x02000000, Endian::Little, flags, code)
+        .map(|(address, op, ins)| format!("{address:08x} {:04x} {}", op.id(), ins.display(options)))
+        .collect()
+}
+
+#[test]
+fn test_types_are_thread_safe() {
+    assert_send_sync::<Parser<'static>>();
+    assert_send_sync::<unarm::ParsedIns>();
+    assert_send_sync::<unarm::v4t::arm::Ins>();
+    assert_send_sync::<unarm::v5te::thumb::Ins>();
+    assert_send_sync::<unarm::v6k::arm::Ins>();
+}
+
+#[test]
+fn test_concurrent_parse_matches_reference() {
+    let code = random_code(0x2468ace1, CORPUS_SIZE);
+    let configs = configs();
+    let reference: Vec<_> = configs.iter().map(|&config| disassemble(&code, config)).collect();
+
+    thread::scope(|scope| {
+        for thread in 0..THREADS {
+            let code = &code;
+            let configs = &configs;
+            let reference = &reference;
+            scope.spawn(move || {
+                // Start at a different config in each thread so that all configs are decoded concurrently
+                for i in 0..configs.len() {
+                    let index = (i + thread) % configs.len();
+                    let output = disassemble(code, configs[index]);
+                    assert!(
+                        output == reference[index],
+                        "output differs from reference for {:?}",
+                        configs[index]
+                    );
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_repeated_parse_is_deterministic() {
+    let code = random_code(0x13579bdf, CORPUS_SIZE);
+    for config in configs() {
+        let first = disassemble(&code, config);
+        let second = disassemble(&code, config);
+        assert!(first == second, "output differs between runs for {config:?}");
+    }
+}
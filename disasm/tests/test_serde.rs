@@ -0,0 +1,139 @@
+#![cfg(feature = "serde")]
+
+use unarm::{
+    args::{Argument, Reg, Register},
+    window, window_with_modes, ArmVersion, Endian, Line, Op, ParseFlags, ParseMode, ParsedIns, Parser,
+};
+
+#[test]
+fn test_format() {
+    let flags = ParseFlags::default();
+    let ins = unarm::v5te::arm::Ins::new(0xe0810002, &flags);
+    let parsed = ins.parse(&flags);
+    let json = serde_json::to_string(&(Op::ArmV5Te(ins.op), &parsed)).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"[{"ArmV5Te":"Add"},{"mnemonic":"add","args":["#,
+            r#"{"Reg":{"deref":false,"reg":"R0","writeback":false}},"#,
+            r#"{"Reg":{"deref":false,"reg":"R1","writeback":false}},"#,
+            r#"{"Reg":{"deref":false,"reg":"R2","writeback":false}},"None","None","None"]}]"#
+        )
+    );
+
+    let (op, parsed): (Op, ParsedIns) = serde_json::from_str(&json).unwrap();
+    assert_eq!(op, Op::ArmV5Te(unarm::v5te::arm::Opcode::Add));
+    assert_eq!(parsed.mnemonic, "add");
+    assert_eq!(
+        parsed.args[2],
+        Argument::Reg(Reg {
+            deref: false,
+            reg: Register::R2,
+            writeback: false
+        })
+    );
+}
+
+#[test]
+fn test_unknown_mnemonic() {
+    let json = r#"{"mnemonic":"frobnicate","args":["None","None","None","None","None","None"]}"#;
+    let error = serde_json::from_str::<ParsedIns>(json).unwrap_err();
+    assert!(error.to_string().contains("unknown mnemonic `frobnicate`"), "{error}");
+}
+
+#[test]
+fn test_round_trip_corpus() {
+    // xorshift32
+    let mut state = 0x3c6ef372u32;
+    let bytes: Vec<u8> = (0..0x1000)
+        .flat_map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()
+        })
+        .collect();
+
+    for version in [ArmVersion::V4T, ArmVersion::V5Te, ArmVersion::V6K] {
+        for mode in [ParseMode::Arm, ParseMode::Thumb, ParseMode::Data] {
+            for ual in [false, true] {
+                let flags = ParseFlags {
+                    ual,
+                    ..Default::default()
+                };
+                for (address, op, parsed) in Parser::new(version, mode, 0, Endian::Little, flags, &bytes) {
+                    let json = serde_json::to_string(&(op, &parsed)).unwrap();
+                    let (op2, parsed2): (Op, ParsedIns) =
+                        serde_json::from_str(&json).unwrap_or_else(|e| panic!("{address:#x} {json}: {e}"));
+                    assert_eq!(op2, op, "{address:#x}");
+                    assert_eq!(parsed2.mnemonic, parsed.mnemonic, "{address:#x}");
+                    assert_eq!(parsed2.args, parsed.args, "{address:#x}");
+                }
+            }
+        }
+    }
+}
+
+fn line_json(code: &[u8], mode: ParseMode, around: u32, modes: &[(u32, ParseMode)]) -> Vec<String> {
+    let parser = Parser::new(ArmVersion::V5Te, mode, 0, Endian::Little, ParseFlags::default(), &[]);
+    let lines = match modes {
+        [] => window(code, 0x1000, around, 0, 0, &parser),
+        modes => window_with_modes(code, 0x1000, around, 0, 0, &parser, modes),
+    };
+    lines
+        .iter()
+        .map(|line| {
+            let json = serde_json::to_string(line).unwrap();
+            assert_eq!(&serde_json::from_str::<Line>(&json).unwrap(), line);
+            json
+        })
+        .collect()
+}
+
+#[test]
+fn test_line_provenance() {
+    // bl #0x14, then the first half of a pair without its second half
+    let thumb = [0xf000u16, 0xf808, 0xf000]
+        .iter()
+        .flat_map(|h| h.to_le_bytes())
+        .collect::<Vec<_>>();
+    // bx lr, then a literal pool
+    let arm = [0xe12fff1eu32, 0x12345678]
+        .iter()
+        .flat_map(|w| w.to_le_bytes())
+        .collect::<Vec<_>>();
+    let pool = [(0x1004, ParseMode::Data)];
+
+    assert_eq!(
+        line_json(&arm, ParseMode::Arm, 0x1000, &pool),
+        [concat!(
+            r#"{"address":4096,"size":4,"op":{"ArmV5Te":"Bx"},"ins":{"mnemonic":"bx","args":["#,
+            r#"{"Reg":{"deref":false,"reg":"Lr","writeback":false}},"None","None","None","None","None"]},"#,
+            r#""provenance":"Plain"}"#
+        )]
+    );
+    assert_eq!(
+        line_json(&thumb, ParseMode::Thumb, 0x1000, &[]),
+        [concat!(
+            r#"{"address":4096,"size":4,"op":{"ThumbV5Te":"BlH"},"ins":{"mnemonic":"bl","args":["#,
+            r#"{"BranchDest":20},"None","None","None","None","None"]},"#,
+            r#""provenance":{"CombinedThumbBl":{"first":61440,"second":63496}}}"#
+        )]
+    );
+    assert_eq!(
+        line_json(&thumb, ParseMode::Thumb, 0x1004, &[]),
+        [concat!(
+            r#"{"address":4100,"size":2,"op":"Data","ins":{"mnemonic":".hword","args":["#,
+            r#"{"UImm":61440},"None","None","None","None","None"]},"#,
+            r#""provenance":{"DataFallback":{"reason":"Truncated"}}}"#
+        )]
+    );
+    assert_eq!(
+        line_json(&arm, ParseMode::Arm, 0x1004, &pool),
+        [concat!(
+            r#"{"address":4100,"size":4,"op":"Data","ins":{"mnemonic":".word","args":["#,
+            r#"{"UImm":305419896},"None","None","None","None","None"]},"#,
+            r#""provenance":{"DataFallback":{"reason":"DataMode"}}}"#
+        )]
+    );
+}
@@ -0,0 +1,271 @@
+#![allow(clippy::single_range_in_vec_init)]
+
+use unarm::{args::Argument, ArmVersion, Endian, Op, ParseFlags, ParseMode, Parser, SkippedStyle, StreamOptions};
+
+const BASE: u32 = 0x02000000;
+
+/// A vector table at 0x0 followed by ARM code at 0x20
+fn image() -> Vec<u8> {
+    let words = [
+        // Vector table: two ARM handlers, a Thumb handler, a reserved entry and a pointer outside of the image
+        BASE + 0x20,
+        BASE + 0x28,
+        BASE + 0x2d,
+        0x00000000,
+        0x08000000,
+        BASE + 0x20,
+        BASE + 0x20,
+        BASE + 0x20,
+        // mov r0, #0x0
+        0xe3a00000,
+        // bx lr
+        0xe12fff1e,
+        // add r0, r0, #0x1
+        0xe2800001,
+        // bx lr
+        0xe12fff1e,
+    ];
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+fn disassemble(code: &[u8], mode: ParseMode, options: StreamOptions) -> Vec<(u32, String)> {
+    Parser::new(ArmVersion::V5Te, mode, BASE, Endian::Little, ParseFlags::default(), code)
+        .with_options(options)
+        .map(|(address, _, ins)| (address - BASE, ins.display(Default::default()).to_string()))
+        .collect()
+}
+
+fn vector_table(render_skipped: SkippedStyle) -> StreamOptions {
+    StreamOptions {
+        skip_ranges: vec![BASE..BASE + 0x20],
+        render_skipped,
+    }
+}
+
+#[test]
+fn test_no_skip_ranges() {
+    let code = image();
+    let parser = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        BASE,
+        Endian::Little,
+        ParseFlags::default(),
+        &code,
+    );
+    let expected: Vec<_> = parser
+        .map(|(address, op, ins)| (address, op, ins.display(Default::default()).to_string()))
+        .collect();
+    let actual: Vec<_> = parser
+        .with_options(StreamOptions::default())
+        .map(|(address, op, ins)| (address, op, ins.display(Default::default()).to_string()))
+        .collect();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn test_skip_data() {
+    let lines = disassemble(&image(), ParseMode::Arm, vector_table(SkippedStyle::Data));
+    assert_eq!(
+        lines,
+        [
+            (0x00, ".word #0x2000020".to_string()),
+            (0x04, ".word #0x2000028".to_string()),
+            (0x08, ".word #0x200002d".to_string()),
+            (0x0c, ".word #0x0".to_string()),
+            (0x10, ".word #0x8000000".to_string()),
+            (0x14, ".word #0x2000020".to_string()),
+            (0x18, ".word #0x2000020".to_string()),
+            (0x1c, ".word #0x2000020".to_string()),
+            (0x20, "mov r0, #0x0".to_string()),
+            (0x24, "bx lr".to_string()),
+            (0x28, "add r0, r0, #0x1".to_string()),
+            (0x2c, "bx lr".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_skip_elide() {
+    let lines = disassemble(&image(), ParseMode::Arm, vector_table(SkippedStyle::Elide));
+    assert_eq!(
+        lines,
+        [
+            (0x20, "mov r0, #0x0".to_string()),
+            (0x24, "bx lr".to_string()),
+            (0x28, "add r0, r0, #0x1".to_string()),
+            (0x2c, "bx lr".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_skip_word_table() {
+    let code = image();
+    let stream = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Arm,
+        BASE,
+        Endian::Little,
+        ParseFlags::default(),
+        &code,
+    )
+    .with_options(vector_table(SkippedStyle::WordTable));
+    let items: Vec<_> = stream.clone().take(9).collect();
+    let table: Vec<_> = items[..8]
+        .iter()
+        .map(|(address, op, ins)| {
+            assert_eq!(*op, Op::Data);
+            assert_eq!(ins.mnemonic, ".word");
+            let target = stream.pointer_target(*address, ins).map(|target| target - BASE);
+            (address - BASE, target)
+        })
+        .collect();
+    assert_eq!(
+        table,
+        [
+            (0x00, Some(0x20)),
+            (0x04, Some(0x28)),
+            // The Thumb bit is ignored
+            (0x08, Some(0x2c)),
+            (0x0c, None),
+            // Outside of the image
+            (0x10, None),
+            (0x14, Some(0x20)),
+            (0x18, Some(0x20)),
+            (0x1c, Some(0x20)),
+        ]
+    );
+    // Words keep their absolute value
+    assert_eq!(items[2].2.args[0], Argument::UImm(BASE + 0x2d));
+    assert_eq!(items[3].2.args[0], Argument::UImm(0));
+    assert_eq!(items[4].2.args[0], Argument::UImm(0x08000000));
+    // Instructions after the table are not pointers, even if they look like one
+    let (address, _, ins) = &items[8];
+    assert_eq!(ins.mnemonic, "mov");
+    assert_eq!(stream.pointer_target(*address, ins), None);
+}
+
+#[test]
+fn test_skip_unaligned_edges() {
+    // A literal pool which starts and ends in the middle of a word
+    let options = StreamOptions {
+        skip_ranges: vec![BASE + 0x22..BASE + 0x26],
+        render_skipped: SkippedStyle::Data,
+    };
+    let lines = disassemble(&image(), ParseMode::Arm, options);
+    assert_eq!(
+        &lines[8..],
+        [
+            (0x20, ".hword #0x0".to_string()),
+            (0x22, ".hword #0xe3a0".to_string()),
+            (0x24, ".hword #0xff1e".to_string()),
+            (0x26, ".hword #0xe12f".to_string()),
+            (0x28, "add r0, r0, #0x1".to_string()),
+            (0x2c, "bx lr".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_skip_thumb_bl_edge() {
+    // bl #0x0, split by a skipped range
+    let code: Vec<u8> = [0xf000u16, 0xf800, 0x4770].iter().flat_map(|h| h.to_le_bytes()).collect();
+    let options = StreamOptions {
+        skip_ranges: vec![BASE + 0x2..BASE + 0x4],
+        render_skipped: SkippedStyle::Elide,
+    };
+    let lines = disassemble(&code, ParseMode::Thumb, options);
+    assert_eq!(lines, [(0x0, ".hword #0xf000".to_string()), (0x4, "bx lr".to_string())]);
+
+    let lines = disassemble(&code, ParseMode::Thumb, StreamOptions::default());
+    assert_eq!(lines, [(0x0, "bl #0x4".to_string()), (0x4, "bx lr".to_string())]);
+}
+
+/// Parses `code` at `BASE`, switching to data mode over `pool` and back to `mode` after it
+fn disassemble_with_pool(code: &[u8], mode: ParseMode, pool: std::ops::Range<u32>) -> Vec<(u32, String)> {
+    let mut parser = Parser::new(ArmVersion::V5Te, mode, BASE, Endian::Little, ParseFlags::default(), code);
+    let options = unarm::DisplayOptions {
+        uppercase: true,
+        ..Default::default()
+    };
+    let mut lines = vec![];
+    loop {
+        let address = parser.address - BASE;
+        parser.set_mode(if pool.contains(&address) { ParseMode::Data } else { mode });
+        let Some((_, _, ins)) = parser.next() else {
+            break;
+        };
+        lines.push((address, ins.display(options).to_string()));
+    }
+    lines
+}
+
+#[test]
+fn test_set_mode_arm_literal_pool() {
+    let words: [u32; 5] = [
+        // ldr r0, [pc, #0x0]
+        0xe59f0000, // bx lr
+        0xe12fff1e, // Literal pool entry
+        0x12345678, // mov r1, #0x1
+        0xe3a01001, // bx lr
+        0xe12fff1e,
+    ];
+    let code: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    assert_eq!(
+        disassemble_with_pool(&code, ParseMode::Arm, 0x8..0xc),
+        [
+            (0x0, "LDR R0, [PC, #0x0]".to_string()),
+            (0x4, "BX LR".to_string()),
+            (0x8, ".WORD #0x12345678".to_string()),
+            (0xc, "MOV R1, #0x1".to_string()),
+            (0x10, "BX LR".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_set_mode_thumb_literal_pool() {
+    let halfwords: [u16; 6] = [
+        // ldr r0, [pc, #0x0]
+        0x4800, // bx lr
+        0x4770, // Literal pool entry
+        0x5678, 0x1234, // movs r1, #0x1
+        0x2101, // bx lr
+        0x4770,
+    ];
+    let code: Vec<u8> = halfwords.iter().flat_map(|halfword| halfword.to_le_bytes()).collect();
+    assert_eq!(
+        disassemble_with_pool(&code, ParseMode::Thumb, 0x4..0x8),
+        [
+            (0x0, "LDR R0, [PC, #0x0]".to_string()),
+            (0x2, "BX LR".to_string()),
+            (0x4, ".WORD #0x12345678".to_string()),
+            (0x8, "MOVS R1, #0x1".to_string()),
+            (0xa, "BX LR".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_data_mode_unaligned() {
+    let code = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+    let lines: Vec<_> = Parser::new(
+        ArmVersion::V5Te,
+        ParseMode::Data,
+        BASE + 1,
+        Endian::Little,
+        ParseFlags::default(),
+        &code,
+    )
+    .map(|(address, _, ins)| (address - BASE, ins.display(Default::default()).to_string()))
+    .collect();
+    assert_eq!(
+        lines,
+        [
+            (0x1, ".byte #0x11".to_string()),
+            (0x2, ".hword #0x3322".to_string()),
+            (0x4, ".word #0x77665544".to_string()),
+        ]
+    );
+}
@@ -0,0 +1,142 @@
+use unarm::{
+    args::{Argument, OffsetImm, OffsetReg, Reg, RegList, Register},
+    DisplayOptions, ParseFlags, ParseMode, ParsedIns, R9Use, RegNames, TextError,
+};
+
+/// Returns the code and text of every `assert_asm!` in a test file
+fn expectations(source: &str) -> Vec<(u32, &str)> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let (_module, args) = line.trim().strip_prefix("assert_asm!(")?.split_once(", ")?;
+            let (code, text) = args.strip_suffix(");")?.split_once(", ")?;
+            let code = u32::from_str_radix(code.strip_prefix("0x")?, 16).ok()?;
+            Some((code, text.strip_prefix('"')?.strip_suffix('"')?))
+        })
+        .collect()
+}
+
+macro_rules! round_trip {
+    ($name:ident, $version:ident, $mode:ident, $parse_mode:ident, $file:literal) => {
+        #[test]
+        fn $name() {
+            use unarm::$version::$mode::Ins;
+
+            let expectations = expectations(include_str!($file));
+            assert!(expectations.len() > 50);
+            let flags = ParseFlags::default();
+            for (code, text) in expectations {
+                let parsed = ParsedIns::from_text(text, ParseMode::$parse_mode).unwrap_or_else(|e| panic!("{text}: {e}"));
+                assert_eq!(parsed, Ins::new(code, &flags).parse(&flags), "{text}");
+                assert_eq!(parsed.display(Default::default()).to_string(), text);
+            }
+        }
+    };
+}
+
+round_trip!(test_arm_v4t, v4t, arm, Arm, "test_arm_v4t.rs");
+round_trip!(test_arm_v5te, v5te, arm, Arm, "test_arm_v5te.rs");
+round_trip!(test_arm_v6k, v6k, arm, Arm, "test_arm_v6k.rs");
+round_trip!(test_thumb_v4t, v4t, thumb, Thumb, "test_thumb_v4t.rs");
+round_trip!(test_thumb_v5te, v5te, thumb, Thumb, "test_thumb_v5te.rs");
+round_trip!(test_thumb_v6k, v6k, thumb, Thumb, "test_thumb_v6k.rs");
+
+fn parse(text: &str) -> ParsedIns {
+    text.parse().unwrap_or_else(|e| panic!("{text}: {e}"))
+}
+
+#[test]
+fn test_memory_operands() {
+    let base = |reg, writeback| {
+        Argument::Reg(Reg {
+            deref: true,
+            reg,
+            writeback,
+        })
+    };
+    assert_eq!(
+        parse("ldr r0, [r1, #0x4]!").args[1..3],
+        [
+            base(Register::R1, true),
+            Argument::OffsetImm(OffsetImm {
+                post_indexed: false,
+                value: 4
+            })
+        ]
+    );
+    assert_eq!(
+        parse("ldr r0, [r1], #-4").args[1..3],
+        [
+            base(Register::R1, false),
+            Argument::OffsetImm(OffsetImm {
+                post_indexed: true,
+                value: -4
+            })
+        ]
+    );
+    assert_eq!(
+        parse("strb r0, [r1, -r2, lsl #2]").args[2],
+        Argument::OffsetReg(OffsetReg {
+            add: false,
+            post_indexed: false,
+            reg: Register::R2
+        })
+    );
+}
+
+#[test]
+fn test_alternative_syntax() {
+    // Register aliases, decimal immediates, ranges and uppercase
+    let names = RegNames {
+        av_registers: false,
+        r9_use: R9Use::Pid,
+        explicit_stack_limit: true,
+        frame_pointer: true,
+        ip: true,
+    };
+    let options = DisplayOptions {
+        reg_names: names,
+        ..Default::default()
+    };
+    let ins = parse("ADD fp, ip, #16");
+    assert_eq!(ins, parse("add r11, r12, #0x10"));
+    assert_eq!(ins.display(options).to_string(), "add fp, ip, #0x10");
+    assert_eq!(parse("mov sb, sl"), parse("mov r9, r10"));
+    assert_eq!(parse("mov a1, v1"), parse("mov r0, r4"));
+
+    let ins = parse("push {r4-r7, lr}");
+    assert_eq!(
+        ins.args[0],
+        Argument::RegList(RegList {
+            regs: 0x40f0,
+            user_mode: false
+        })
+    );
+    assert_eq!(ins, parse("push {r4, r5, r6, r7, lr}"));
+    assert_eq!(
+        parse("ldmia r0!, {r1-r3}^").args[1],
+        Argument::RegList(RegList {
+            regs: 0xe,
+            user_mode: true
+        })
+    );
+
+    // Conditions and the S flag are part of the mnemonic
+    let ins = parse("addnes r0, r1, r2");
+    assert_eq!(ins.mnemonic, "addnes");
+    assert_eq!(ins.mnemonic_base(), "adds");
+}
+
+#[test]
+fn test_errors() {
+    let error = |text: &str| text.parse::<ParsedIns>().unwrap_err();
+    assert_eq!(error("foo r0, r1"), TextError::UnknownMnemonic("foo".to_string()));
+    assert_eq!(error("add r0, r16, r1"), TextError::InvalidToken("r16".to_string()));
+    assert_eq!(error("add r0, r1, #0xg"), TextError::InvalidToken("0xg".to_string()));
+    assert_eq!(error("ldr r0, [r1, #4"), TextError::InvalidToken(String::new()));
+    assert_eq!(error("push {r7-r4}"), TextError::InvalidToken("r7-r4".to_string()));
+    assert_eq!(error("add r0, r1, {r2}"), TextError::UnexpectedOperand("{r2}".to_string()));
+    assert_eq!(error("bx lr, r0"), TextError::UnexpectedOperand("r0".to_string()));
+    assert_eq!(error("clz r0"), TextError::MissingOperand);
+    assert_eq!(error("clz r0").to_string(), "missing operand");
+}
@@ -0,0 +1,84 @@
+//! Decodes every 16-bit Thumb code in every version and syntax, and compares hashes of the disassembly with
+//! `fixtures/thumb_exhaustive.txt`. Run `cargo xtask thumb-golden` to rewrite the file after changing the ISA, and
+//! review the blocks which changed in its diff.
+
+use std::fmt::Write;
+
+use unarm::ParseFlags;
+
+const GOLDEN_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/thumb_exhaustive.txt");
+const HEADER: &str = "\
+# Hashes of the disassembly of every 16-bit Thumb code, see test_thumb_exhaustive.rs. Regenerate with
+# `cargo xtask thumb-golden`. Each line is a version, a syntax, the first code of a block of 256 codes, and the 64-bit
+# FNV-1a hash of the lines `<code> <disassembly>` of the block.
+";
+const BLOCK_SIZE: u32 = 0x100;
+
+/// Disassembles `code` with the default display options
+type Decoder = fn(u32, &ParseFlags, &mut String);
+
+macro_rules! decoder {
+    ($version:ident) => {
+        |code, flags, out| {
+            use unarm::$version::thumb::Ins;
+            let ins = Ins::new(code, flags).parse(flags);
+            writeln!(out, "{:04x} {}", code, ins.display(Default::default())).unwrap();
+        }
+    };
+}
+
+const DECODERS: [(&str, Decoder); 3] = [("v4t", decoder!(v4t)), ("v5te", decoder!(v5te)), ("v6k", decoder!(v6k))];
+
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Returns the lines of the golden file for the current decoders
+fn golden() -> String {
+    let mut out = HEADER.to_string();
+    let mut text = String::new();
+    for (version, decoder) in DECODERS {
+        for (syntax, ual) in [("unified", true), ("divided", false)] {
+            let flags = ParseFlags {
+                ual,
+                ..Default::default()
+            };
+            for start in (0..0x10000).step_by(BLOCK_SIZE as usize) {
+                text.clear();
+                for code in start..start + BLOCK_SIZE {
+                    decoder(code, &flags, &mut text);
+                }
+                writeln!(out, "{version} {syntax} {start:#06x} {:016x}", fnv1a(&text)).unwrap();
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test_thumb_exhaustive() {
+    let actual = golden();
+    if std::env::var_os("UNARM_THUMB_GOLDEN_UPDATE").is_some() {
+        std::fs::write(GOLDEN_PATH, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(GOLDEN_PATH).unwrap();
+    let changed = expected
+        .lines()
+        .zip(actual.lines())
+        .filter(|(expected, actual)| expected != actual)
+        .map(|(_, actual)| actual.rsplit_once(' ').unwrap().0)
+        .collect::<Vec<_>>();
+    assert!(
+        changed.is_empty() && expected.lines().count() == actual.lines().count(),
+        "Thumb disassembly changed in the blocks {changed:?}, run `cargo xtask thumb-golden` if this is intended"
+    );
+}
+
+#[test]
+fn test_fnv1a() {
+    assert_eq!(fnv1a(""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a("a"), 0xaf63dc4c8601ec8c);
+}
@@ -1,13 +1,8 @@
-use unarm::v5te::thumb::Ins;
-
-macro_rules! assert_asm {
-    ($code:literal, $disasm:literal) => {{
-        let flags = Default::default();
-        let ins = Ins::new($code, &flags);
-        let parsed = ins.parse(&flags);
-        assert_eq!(parsed.display(Default::default()).to_string(), $disasm)
-    }};
-}
+use unarm::testing::assert_asm;
+use unarm::{
+    v5te::thumb::{parse_all, Ins, InsIter},
+    Endian, ParseFlags,
+};
 
 macro_rules! assert_bl {
     ($code:literal, $disasm:literal) => {{
@@ -24,52 +19,52 @@ macro_rules! assert_bl {
 
 #[test]
 fn test_adc() {
-    assert_asm!(0x4157, "adcs r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4157, "adcs r7, r7, r2");
 }
 
 #[test]
 fn test_add() {
-    assert_asm!(0x1cca, "adds r2, r1, #0x3");
-    assert_asm!(0x3642, "adds r6, #0x42");
-    assert_asm!(0x1853, "adds r3, r2, r1");
-    assert_asm!(0x44de, "add lr, lr, r11");
-    assert_asm!(0xacff, "add r4, sp, #0x3fc");
-    assert_asm!(0xb03a, "add sp, sp, #0xe8");
-    assert_asm!(0x1c17, "adds r7, r2, #0x0");
+    assert_asm!(v5te::thumb, 0x1cca, "adds r2, r1, #0x3");
+    assert_asm!(v5te::thumb, 0x3642, "adds r6, #0x42");
+    assert_asm!(v5te::thumb, 0x1853, "adds r3, r2, r1");
+    assert_asm!(v5te::thumb, 0x44de, "add lr, lr, r11");
+    assert_asm!(v5te::thumb, 0xacff, "add r4, sp, #0x3fc");
+    assert_asm!(v5te::thumb, 0xb03a, "add sp, sp, #0xe8");
+    assert_asm!(v5te::thumb, 0x1c17, "adds r7, r2, #0x0");
 }
 
 #[test]
 fn test_adr() {
-    assert_asm!(0xa413, "adr r4, #0x4c");
+    assert_asm!(v5te::thumb, 0xa413, "adr r4, #0x4c");
 }
 
 #[test]
 fn test_and() {
-    assert_asm!(0x4017, "ands r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4017, "ands r7, r7, r2");
 }
 
 #[test]
 fn test_asr() {
-    assert_asm!(0x1023, "asrs r3, r4, #0x20");
-    assert_asm!(0x1163, "asrs r3, r4, #0x5");
-    assert_asm!(0x4117, "asrs r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x1023, "asrs r3, r4, #0x20");
+    assert_asm!(v5te::thumb, 0x1163, "asrs r3, r4, #0x5");
+    assert_asm!(v5te::thumb, 0x4117, "asrs r7, r7, r2");
 }
 
 #[test]
 fn test_b() {
-    assert_asm!(0xd042, "beq #0x88");
-    assert_asm!(0xd942, "bls #0x88");
-    assert_asm!(0xdc42, "bgt #0x88");
+    assert_asm!(v5te::thumb, 0xd042, "beq #0x88");
+    assert_asm!(v5te::thumb, 0xd942, "bls #0x88");
+    assert_asm!(v5te::thumb, 0xdc42, "bgt #0x88");
 }
 
 #[test]
 fn test_bic() {
-    assert_asm!(0x4397, "bics r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4397, "bics r7, r7, r2");
 }
 
 #[test]
 fn test_bkpt() {
-    assert_asm!(0xde42, "bkpt #0x42");
+    assert_asm!(v5te::thumb, 0xbe42, "bkpt #0x42");
 }
 
 #[test]
@@ -82,163 +77,278 @@ fn test_bl() {
 fn test_blx() {
     assert_bl!(0xf099e866, "blx #0x990d0");
     assert_bl!(0xf799e866, "blx #-0x66f30");
-    assert_asm!(0x47d0, "blx r10");
+    assert_asm!(v5te::thumb, 0x47d0, "blx r10");
 }
 
 #[test]
 fn test_bx() {
-    assert_asm!(0x4750, "bx r10");
+    assert_asm!(v5te::thumb, 0x4750, "bx r10");
 }
 
 #[test]
 fn test_cmn() {
-    assert_asm!(0x42d7, "cmn r7, r2");
+    assert_asm!(v5te::thumb, 0x42d7, "cmn r7, r2");
 }
 
 #[test]
 fn test_cmp() {
-    assert_asm!(0x2942, "cmp r1, #0x42");
-    assert_asm!(0x4297, "cmp r7, r2");
-    assert_asm!(0x45de, "cmp lr, r11");
+    assert_asm!(v5te::thumb, 0x2942, "cmp r1, #0x42");
+    assert_asm!(v5te::thumb, 0x4297, "cmp r7, r2");
+    assert_asm!(v5te::thumb, 0x45de, "cmp lr, r11");
 }
 
 #[test]
 fn test_eor() {
-    assert_asm!(0x4057, "eors r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4057, "eors r7, r7, r2");
 }
 
 #[test]
 fn test_ldm() {
-    assert_asm!(0xc955, "ldm r1!, {r0, r2, r4, r6}");
+    assert_asm!(v5te::thumb, 0xc955, "ldm r1!, {r0, r2, r4, r6}");
 }
 
 #[test]
 fn test_ldr() {
-    assert_asm!(0x6c22, "ldr r2, [r4, #0x40]");
-    assert_asm!(0x5822, "ldr r2, [r4, r0]");
-    assert_asm!(0x4f42, "ldr r7, [pc, #0x108]");
-    assert_asm!(0x9f42, "ldr r7, [sp, #0x108]");
+    assert_asm!(v5te::thumb, 0x6c22, "ldr r2, [r4, #0x40]");
+    assert_asm!(v5te::thumb, 0x5822, "ldr r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x4f42, "ldr r7, [pc, #0x108]");
+    assert_asm!(v5te::thumb, 0x9f42, "ldr r7, [sp, #0x108]");
 }
 
 #[test]
 fn test_ldrb() {
-    assert_asm!(0x7c22, "ldrb r2, [r4, #0x40]");
-    assert_asm!(0x5c22, "ldrb r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x7c22, "ldrb r2, [r4, #0x40]");
+    assert_asm!(v5te::thumb, 0x5c22, "ldrb r2, [r4, r0]");
 }
 
 #[test]
 fn test_ldrh() {
-    assert_asm!(0x8c22, "ldrh r2, [r4, #0x40]");
-    assert_asm!(0x5a22, "ldrh r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x8c22, "ldrh r2, [r4, #0x40]");
+    assert_asm!(v5te::thumb, 0x5a22, "ldrh r2, [r4, r0]");
 }
 
 #[test]
 fn test_ldrsb() {
-    assert_asm!(0x5622, "ldrsb r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x5622, "ldrsb r2, [r4, r0]");
 }
 
 #[test]
 fn test_ldrsh() {
-    assert_asm!(0x5e22, "ldrsh r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x5e22, "ldrsh r2, [r4, r0]");
 }
 
 #[test]
 fn test_lsl() {
-    assert_asm!(0x0163, "lsls r3, r4, #0x5");
-    assert_asm!(0x4097, "lsls r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x0163, "lsls r3, r4, #0x5");
+    assert_asm!(v5te::thumb, 0x4097, "lsls r7, r7, r2");
 }
 
 #[test]
 fn test_lsr() {
-    assert_asm!(0x0823, "lsrs r3, r4, #0x20");
-    assert_asm!(0x0963, "lsrs r3, r4, #0x5");
-    assert_asm!(0x40d7, "lsrs r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x0823, "lsrs r3, r4, #0x20");
+    assert_asm!(v5te::thumb, 0x0963, "lsrs r3, r4, #0x5");
+    assert_asm!(v5te::thumb, 0x40d7, "lsrs r7, r7, r2");
 }
 
 #[test]
 fn test_mov() {
-    assert_asm!(0x2163, "movs r1, #0x63");
-    assert_asm!(0x0017, "movs r7, r2");
+    assert_asm!(v5te::thumb, 0x2163, "movs r1, #0x63");
+    assert_asm!(v5te::thumb, 0x0017, "movs r7, r2");
 }
 
 #[test]
 fn test_mul() {
-    assert_asm!(0x4357, "muls r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4357, "muls r7, r7, r2");
 }
 
 #[test]
 fn test_mvn() {
-    assert_asm!(0x43d7, "mvns r7, r2");
+    assert_asm!(v5te::thumb, 0x43d7, "mvns r7, r2");
 }
 
 #[test]
 fn test_orr() {
-    assert_asm!(0x4317, "orrs r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4317, "orrs r7, r7, r2");
 }
 
 #[test]
 fn test_pop() {
-    assert_asm!(0xbdff, "pop {r0, r1, r2, r3, r4, r5, r6, r7, pc}");
+    assert_asm!(v5te::thumb, 0xbdff, "pop {r0, r1, r2, r3, r4, r5, r6, r7, pc}");
 }
 
 #[test]
 fn test_push() {
-    assert_asm!(0xb5ff, "push {r0, r1, r2, r3, r4, r5, r6, r7, lr}");
+    assert_asm!(v5te::thumb, 0xb5ff, "push {r0, r1, r2, r3, r4, r5, r6, r7, lr}");
 }
 
 #[test]
 fn test_ror() {
-    assert_asm!(0x41d7, "rors r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x41d7, "rors r7, r7, r2");
 }
 
 #[test]
 fn test_rsb() {
-    assert_asm!(0x4257, "rsbs r7, r2, #0x0");
+    assert_asm!(v5te::thumb, 0x4257, "rsbs r7, r2, #0x0");
 }
 
 #[test]
 fn test_sbc() {
-    assert_asm!(0x4197, "sbcs r7, r7, r2");
+    assert_asm!(v5te::thumb, 0x4197, "sbcs r7, r7, r2");
 }
 
 #[test]
 fn test_stm() {
-    assert_asm!(0xc155, "stm r1!, {r0, r2, r4, r6}");
+    assert_asm!(v5te::thumb, 0xc155, "stm r1!, {r0, r2, r4, r6}");
 }
 
 #[test]
 fn test_str() {
-    assert_asm!(0x6422, "str r2, [r4, #0x40]");
-    assert_asm!(0x5022, "str r2, [r4, r0]");
-    assert_asm!(0x9742, "str r7, [sp, #0x108]");
+    assert_asm!(v5te::thumb, 0x6422, "str r2, [r4, #0x40]");
+    assert_asm!(v5te::thumb, 0x5022, "str r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x9742, "str r7, [sp, #0x108]");
 }
 
 #[test]
 fn test_strb() {
-    assert_asm!(0x7422, "strb r2, [r4, #0x40]");
-    assert_asm!(0x5422, "strb r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x7422, "strb r2, [r4, #0x40]");
+    assert_asm!(v5te::thumb, 0x5422, "strb r2, [r4, r0]");
 }
 
 #[test]
 fn test_strh() {
-    assert_asm!(0x8422, "strh r2, [r4, #0x40]");
-    assert_asm!(0x5222, "strh r2, [r4, r0]");
+    assert_asm!(v5te::thumb, 0x8422, "strh r2, [r4, #0x40]");
+    assert_asm!(v5te::thumb, 0x5222, "strh r2, [r4, r0]");
 }
 
 #[test]
 fn test_sub() {
-    assert_asm!(0x1eca, "subs r2, r1, #0x3");
-    assert_asm!(0x3e42, "subs r6, #0x42");
-    assert_asm!(0x1a53, "subs r3, r2, r1");
-    assert_asm!(0xb0ff, "sub sp, sp, #0x1fc");
+    assert_asm!(v5te::thumb, 0x1eca, "subs r2, r1, #0x3");
+    assert_asm!(v5te::thumb, 0x3e42, "subs r6, #0x42");
+    assert_asm!(v5te::thumb, 0x1a53, "subs r3, r2, r1");
+    assert_asm!(v5te::thumb, 0xb0ff, "sub sp, sp, #0x1fc");
 }
 
 #[test]
 fn test_svc() {
-    assert_asm!(0xdf42, "svc #0x42");
+    assert_asm!(v5te::thumb, 0xdf42, "svc #0x42");
 }
 
 #[test]
 fn test_tst() {
-    assert_asm!(0x4217, "tst r7, r2");
+    assert_asm!(v5te::thumb, 0x4217, "tst r7, r2");
+}
+
+#[test]
+fn test_udf() {
+    assert_asm!(v5te::thumb, 0xdefe, "udf #0xfe");
+}
+
+#[test]
+fn test_add_sub_sp_exhaustive() {
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
+    for code in 0xb000..=0xb0ff {
+        let op = if code & 0x80 == 0 { "add" } else { "sub" };
+        let offset = (code & 0x7f) << 2;
+        let ins = Ins::new(code, &unified);
+        let expected = format!("{op} sp, sp, #0x{offset:x}");
+        assert_eq!(ins.parse(&unified).display(Default::default()).to_string(), expected);
+        let ins = Ins::new(code, &divided);
+        let expected = format!("{op} sp, #0x{offset:x}");
+        assert_eq!(ins.parse(&divided).display(Default::default()).to_string(), expected);
+    }
+
+    // Neighbors
+    assert_asm!(v5te::thumb, 0xafff, "add r7, sp, #0x3fc");
+    assert_asm!(v5te::thumb, 0xb100, "<illegal>");
+}
+
+#[test]
+fn test_conditional_branch_space() {
+    assert_asm!(v5te::thumb, 0xd000, "beq #0x4");
+    assert_asm!(v5te::thumb, 0xdd7f, "ble #0x102");
+    assert_asm!(v5te::thumb, 0xdd80, "ble #-0xfc");
+    assert_asm!(v5te::thumb, 0xde00, "udf #0x0");
+    assert_asm!(v5te::thumb, 0xdeff, "udf #0xff");
+    assert_asm!(v5te::thumb, 0xdf00, "svc #0x0");
+    assert_asm!(v5te::thumb, 0xdfff, "svc #0xff");
+    assert_asm!(v5te::thumb, 0xe000, "b #0x4");
+    assert_asm!(v5te::thumb, 0xe3ff, "b #0x802");
+    assert_asm!(v5te::thumb, 0xe7ff, "b #0x2");
+    assert_asm!(v5te::thumb, 0xbe00, "bkpt #0x0");
+    assert_asm!(v5te::thumb, 0xbeff, "bkpt #0xff");
+}
+
+#[test]
+fn test_from_bytes() {
+    let flags = Default::default();
+    // adds r2, r1, #0x3
+    let le = Ins::from_bytes(&[0xca, 0x1c], Endian::Little, &flags).unwrap();
+    let be = Ins::from_bytes(&[0x1c, 0xca], Endian::Big, &flags).unwrap();
+    assert_eq!(le.code, 0x1cca);
+    assert_eq!(be.code, 0x1cca);
+    assert_eq!(le.op, be.op);
+    assert_eq!(le.parse(&flags).display(Default::default()).to_string(), "adds r2, r1, #0x3");
+    assert_eq!(le.size(), 2);
+
+    // bl #0x990d0
+    let bytes = [0x99, 0xf0, 0x66, 0xf8];
+    let first = Ins::from_bytes(&bytes, Endian::Little, &flags).unwrap();
+    assert!(first.is_half_bl());
+    assert_eq!(first.size(), 4);
+    let second = Ins::from_bytes(&bytes[2..], Endian::Little, &flags).unwrap();
+    assert_eq!(second.size(), 2);
+    let parsed = first.parse(&flags).combine_thumb_bl(&second.parse(&flags));
+    assert_eq!(parsed.display(Default::default()).to_string(), "bl #0x990d0");
+
+    assert!(Ins::from_bytes(&[0xca], Endian::Little, &flags).is_none());
+    assert!(Ins::from_bytes(&bytes[4..], Endian::Little, &flags).is_none());
+}
+
+#[test]
+fn test_ins_iter() {
+    let code: Vec<u8> = [0x1ccau16, 0xf099, 0xf866, 0x4770]
+        .iter()
+        .flat_map(|code| code.to_be_bytes())
+        // Trailing partial halfword
+        .chain([0x47])
+        .collect();
+    let lines: Vec<_> = InsIter::new(&code, 0x02000000, Endian::Big, Default::default())
+        .map(|(address, ins, parsed)| (address, ins.code, parsed.display(Default::default()).to_string()))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            (0x02000000, 0x1cca, "adds r2, r1, #0x3".to_string()),
+            (0x02000002, 0xf099, "bl #0x990d0".to_string()),
+            (0x02000006, 0x4770, "bx lr".to_string()),
+        ]
+    );
+
+    // The second half of a BL is missing
+    let code = [0xf0, 0x99, 0xf8];
+    assert_eq!(InsIter::new(&code, 0, Endian::Big, Default::default()).count(), 0);
+}
+
+#[test]
+fn test_parse_all() {
+    let flags = Default::default();
+    let code: Vec<u8> = [0x1ccau16, 0xf099, 0xf866, 0x4770]
+        .iter()
+        .flat_map(|code| code.to_be_bytes())
+        .collect();
+    let expected: Vec<_> = InsIter::new(&code, 0x02000000, Endian::Big, flags)
+        .map(|(address, _, parsed)| (address, parsed))
+        .collect();
+    assert_eq!(expected.len(), 3);
+
+    let mut out = vec![];
+    parse_all(&code, 0x02000000, Endian::Big, &flags, &mut out);
+    assert_eq!(out, expected);
+    // The second half of the BL is missing
+    parse_all(&code[..5], 0x02000000, Endian::Big, &flags, &mut out);
+    assert_eq!(out, expected[..1]);
 }
@@ -0,0 +1,127 @@
+use unarm::{v5te::arm::Ins, ParseFlags, TokenKind, Tokens};
+
+fn tokenize(code: u32) -> (Ins, Tokens) {
+    let flags = ParseFlags::default();
+    let ins = Ins::new(code, &flags);
+    let tokens = ins.parse(&flags).display(Default::default()).tokens();
+    (ins, tokens)
+}
+
+fn tokens_of(tokens: &Tokens) -> Vec<(&str, Option<usize>)> {
+    tokens
+        .tokens
+        .iter()
+        .map(|token| (tokens.text(token), token.arg_index))
+        .collect()
+}
+
+#[test]
+fn test_pre_indexed() {
+    let (ins, tokens) = tokenize(0xe5b02268);
+    assert_eq!(tokens.text, "ldr r2, [r0, #0x268]!");
+    assert_eq!(
+        tokens_of(&tokens),
+        [
+            ("ldr", None),
+            (" ", None),
+            ("r2", Some(0)),
+            (", ", None),
+            ("[", None),
+            ("r0", Some(1)),
+            (", ", None),
+            ("#0x268", Some(2)),
+            ("]", None),
+            ("!", Some(1)),
+        ]
+    );
+    assert_eq!(tokens.tokens[0].kind, TokenKind::Mnemonic);
+    assert_eq!(tokens.tokens[4].kind, TokenKind::Separator);
+    assert_eq!(tokens.tokens[9].kind, TokenKind::Argument);
+
+    // Clicking the offset maps to the 12-bit immediate, plus the U and I bits
+    assert_eq!(tokens.arg_at(tokens.text.find('#').unwrap()), Some(2));
+    assert_eq!(ins.arg_bitmask(2, &Default::default()), 0x02800fff);
+    // The base register includes its writeback bit
+    assert_eq!(tokens.arg_at(tokens.text.len() - 1), Some(1));
+    assert_eq!(ins.arg_bitmask(1, &Default::default()), 0x002f0000);
+    assert_eq!(ins.arg_bitmask(0, &Default::default()), 0x0000f000);
+    assert_eq!(tokens.arg_at(0), None);
+    assert_eq!(tokens.arg_at(tokens.text.len()), None);
+}
+
+#[test]
+fn test_post_indexed() {
+    let (ins, tokens) = tokenize(0xe4902268);
+    assert_eq!(tokens.text, "ldr r2, [r0], #0x268");
+    assert_eq!(
+        tokens_of(&tokens),
+        [
+            ("ldr", None),
+            (" ", None),
+            ("r2", Some(0)),
+            (", ", None),
+            ("[", None),
+            ("r0", Some(1)),
+            ("]", None),
+            (", ", None),
+            ("#0x268", Some(2)),
+        ]
+    );
+    assert_eq!(ins.arg_bitmask(1, &Default::default()), 0x000f0000);
+    assert_eq!(ins.arg_bitmask(2, &Default::default()), 0x03800fff);
+    assert_eq!(ins.arg_bitmask(3, &Default::default()), 0);
+}
+
+#[test]
+fn test_reg_list() {
+    let (ins, tokens) = tokenize(0xe8b0400f);
+    assert_eq!(tokens.text, "ldm r0!, {r0, r1, r2, r3, lr}");
+    assert_eq!(
+        tokens_of(&tokens),
+        [
+            ("ldm", None),
+            (" ", None),
+            ("r0!", Some(0)),
+            (", ", None),
+            ("{r0, r1, r2, r3, lr}", Some(1)),
+        ]
+    );
+    // The comma inside the register list belongs to the list
+    assert_eq!(tokens.arg_at(tokens.text.find("r1").unwrap() - 2), Some(1));
+    assert_eq!(ins.arg_bitmask(1, &Default::default()), 0x0000ffff);
+}
+
+#[test]
+fn test_shifted_operand() {
+    let (ins, tokens) = tokenize(0xe0810182);
+    assert_eq!(tokens.text, "add r0, r1, r2, lsl #0x3");
+    assert_eq!(
+        tokens_of(&tokens),
+        [
+            ("add", None),
+            (" ", None),
+            ("r0", Some(0)),
+            (", ", None),
+            ("r1", Some(1)),
+            (", ", None),
+            ("r2", Some(2)),
+            (", ", None),
+            ("lsl #0x3", Some(3)),
+        ]
+    );
+    assert_eq!(ins.arg_bitmask(2, &Default::default()), 0x0200000f);
+    assert_eq!(ins.arg_bitmask(3, &Default::default()), 0x02000fe0);
+}
+
+#[test]
+fn test_tokens_match_display() {
+    let flags = ParseFlags::default();
+    for code in [0xe5b02268, 0xe7910102, 0xe92d4010, 0xed9f0a01, 0xf57ff01f, 0xe12fff1e] {
+        let parsed = Ins::new(code, &flags).parse(&flags);
+        let display = parsed.display(Default::default());
+        let tokens = display.tokens();
+        assert_eq!(tokens.text, display.to_string());
+        let joined: String = tokens.tokens.iter().map(|token| tokens.text(token)).collect();
+        assert_eq!(joined, tokens.text);
+    }
+}
@@ -0,0 +1,17 @@
+use unarm::{
+    tuning::{recommended_batch_size, ARM_BATCH_SIZE, L1_DATA_CACHE_SIZE, PARSED_ENTRY_SIZE, THUMB_BATCH_SIZE},
+    ArmVersion, Endian, ParseFlags, ParseMode, Parser,
+};
+
+#[test]
+fn test_recommended_batch_size() {
+    for size in [ARM_BATCH_SIZE, THUMB_BATCH_SIZE] {
+        assert!(size.is_power_of_two());
+        assert!(size * PARSED_ENTRY_SIZE <= L1_DATA_CACHE_SIZE / 2);
+    }
+    assert_eq!(recommended_batch_size(L1_DATA_CACHE_SIZE), 1);
+
+    let parser = |mode| Parser::new(ArmVersion::V5Te, mode, 0, Endian::Little, ParseFlags::default(), &[]);
+    assert_eq!(parser(ParseMode::Arm).recommended_batch_size(), ARM_BATCH_SIZE);
+    assert_eq!(parser(ParseMode::Thumb).recommended_batch_size(), THUMB_BATCH_SIZE);
+}
@@ -1,6 +1,8 @@
 use unarm::{
+    analysis::RegisterSet,
+    args::Register,
     v6k::{arm, thumb},
-    ParseFlags,
+    Op, ParseFlags,
 };
 
 macro_rules! assert_arm {
@@ -21,8 +23,11 @@ macro_rules! assert_thumb {
 
 #[test]
 fn test_data() {
-    let unified = ParseFlags { ual: true };
-    let divided = ParseFlags { ual: false };
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
 
     assert_arm!(0x00912003, &unified, "addseq r2, r1, r3");
     assert_arm!(0x00912003, &divided, "addeqs r2, r1, r3");
@@ -48,8 +53,11 @@ fn test_data() {
 
 #[test]
 fn test_shift() {
-    let unified = ParseFlags { ual: true };
-    let divided = ParseFlags { ual: false };
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
 
     assert_arm!(0xe1a011c2, &unified, "asr r1, r2, #0x3");
     assert_arm!(0xe1a011c2, &divided, "mov r1, r2, asr #0x3");
@@ -65,8 +73,11 @@ fn test_shift() {
 
 #[test]
 fn test_ldm_stm() {
-    let unified = ParseFlags { ual: true };
-    let divided = ParseFlags { ual: false };
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
 
     assert_arm!(0xe8900011, &unified, "ldm r0, {r0, r4}");
     assert_arm!(0xe8900011, &divided, "ldmia r0, {r0, r4}");
@@ -90,8 +101,11 @@ fn test_ldm_stm() {
 
 #[test]
 fn test_ldr_str() {
-    let unified = ParseFlags { ual: true };
-    let divided = ParseFlags { ual: false };
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
 
     assert_arm!(0x01d120b4, &unified, "ldrheq r2, [r1, #0x4]");
     assert_arm!(0x01d120b4, &divided, "ldreqh r2, [r1, #0x4]");
@@ -110,8 +124,11 @@ fn test_ldr_str() {
 
 #[test]
 fn test_push_pop() {
-    let unified = ParseFlags { ual: true };
-    let divided = ParseFlags { ual: false };
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
 
     assert_arm!(0xe92d0011, &unified, "push {r0, r4}");
     assert_arm!(0xe92d0011, &divided, "stmdb sp!, {r0, r4}");
@@ -126,9 +143,185 @@ fn test_push_pop() {
 
 #[test]
 fn test_svc_swi() {
-    let unified = ParseFlags { ual: true };
-    let divided = ParseFlags { ual: false };
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
 
     assert_arm!(0xef000123, &unified, "svc #0x123");
     assert_arm!(0xef000123, &divided, "swi #0x123");
 }
+
+#[test]
+fn test_flags_reach_every_decoder() {
+    macro_rules! assert_svc_swi {
+        ($version:ident, $isa:ident, $code:literal, $svc:literal, $swi:literal) => {{
+            use unarm::$version::$isa::{Ins, Opcode};
+            let unified = ParseFlags::default();
+            let divided = ParseFlags {
+                ual: false,
+                ..Default::default()
+            };
+            assert_eq!(Ins::new($code, &unified).op, Opcode::Svc);
+            assert_eq!(Ins::new($code, &divided).op, Opcode::Swi);
+            assert_eq!(
+                Ins::new($code, &unified)
+                    .parse(&unified)
+                    .display(Default::default())
+                    .to_string(),
+                $svc
+            );
+            assert_eq!(
+                Ins::new($code, &divided)
+                    .parse(&divided)
+                    .display(Default::default())
+                    .to_string(),
+                $swi
+            );
+        }};
+    }
+
+    assert_svc_swi!(v4t, arm, 0xef000123, "svc #0x123", "swi #0x123");
+    assert_svc_swi!(v4t, thumb, 0xdf12, "svc #0x12", "swi #0x12");
+    assert_svc_swi!(v5te, arm, 0xef000123, "svc #0x123", "swi #0x123");
+    assert_svc_swi!(v5te, thumb, 0xdf12, "svc #0x12", "swi #0x12");
+    assert_svc_swi!(v6k, arm, 0xef000123, "svc #0x123", "swi #0x123");
+    assert_svc_swi!(v6k, thumb, 0xdf12, "svc #0x12", "swi #0x12");
+}
+
+#[test]
+fn test_add_sub_exchange() {
+    let unified = ParseFlags::default();
+    let divided = ParseFlags {
+        ual: false,
+        ..Default::default()
+    };
+
+    assert_arm!(0xe6212f33, &unified, "qasx r2, r1, r3");
+    assert_arm!(0xe6212f33, &divided, "qaddsubx r2, r1, r3");
+    assert_arm!(0xe6212f53, &unified, "qsax r2, r1, r3");
+    assert_arm!(0xe6212f53, &divided, "qsubaddx r2, r1, r3");
+    assert_arm!(0xe6112f33, &unified, "sasx r2, r1, r3");
+    assert_arm!(0xe6112f33, &divided, "saddsubx r2, r1, r3");
+    assert_arm!(0xe6112f53, &unified, "ssax r2, r1, r3");
+    assert_arm!(0xe6112f53, &divided, "ssubaddx r2, r1, r3");
+    assert_arm!(0xe6312f33, &unified, "shasx r2, r1, r3");
+    assert_arm!(0xe6312f33, &divided, "shaddsubx r2, r1, r3");
+    assert_arm!(0xe6312f53, &unified, "shsax r2, r1, r3");
+    assert_arm!(0xe6312f53, &divided, "shsubaddx r2, r1, r3");
+    assert_arm!(0xe6512f33, &unified, "uasx r2, r1, r3");
+    assert_arm!(0xe6512f33, &divided, "uaddsubx r2, r1, r3");
+    assert_arm!(0xe6512f53, &unified, "usax r2, r1, r3");
+    assert_arm!(0xe6512f53, &divided, "usubaddx r2, r1, r3");
+    assert_arm!(0xe6612f33, &unified, "uqasx r2, r1, r3");
+    assert_arm!(0xe6612f33, &divided, "uqaddsubx r2, r1, r3");
+    assert_arm!(0xe6612f53, &unified, "uqsax r2, r1, r3");
+    assert_arm!(0xe6612f53, &divided, "uqsubaddx r2, r1, r3");
+    assert_arm!(0xe6712f33, &unified, "uhasx r2, r1, r3");
+    assert_arm!(0xe6712f33, &divided, "uhaddsubx r2, r1, r3");
+    assert_arm!(0xe6712f53, &unified, "uhsax r2, r1, r3");
+    assert_arm!(0xe6712f53, &divided, "uhsubaddx r2, r1, r3");
+
+    // The condition comes after the whole mnemonic in both syntaxes
+    assert_arm!(0xb6512f33, &unified, "uasxlt r2, r1, r3");
+    assert_arm!(0xb6512f33, &divided, "uaddsubxlt r2, r1, r3");
+}
+
+macro_rules! assert_alias {
+    ($module:ident, $variant:ident, $code:literal, $op:ident, $alias:literal, $underlying_op:ident, $underlying:literal) => {{
+        let flags = ParseFlags::default();
+        let ins = $module::Ins::new($code, &flags);
+        assert_eq!(ins.op, $module::Opcode::$op);
+        assert_eq!(ins.aliased_from(&flags), Some($module::Opcode::$underlying_op));
+        let parsed = ins.parse(&flags);
+        assert_eq!(parsed.display(Default::default()).to_string(), $alias);
+        assert_eq!(
+            ins.parse_unaliased(&flags).display(Default::default()).to_string(),
+            $underlying
+        );
+        let underlying = parsed.aliased_from(Op::$variant(ins.op)).unwrap();
+        assert_eq!(underlying.display(Default::default()).to_string(), $underlying);
+    }};
+}
+
+#[test]
+fn test_aliases() {
+    assert_alias!(thumb, ThumbV6K, 0xa1ff, Adr, "adr r1, #0x3fc", AddPc, "add r1, pc, #0x3fc");
+
+    assert_alias!(arm, ArmV6K, 0xe92d0011, PushM, "push {r0, r4}", StmW, "stmdb sp!, {r0, r4}");
+    assert_alias!(arm, ArmV6K, 0xe52d3004, PushR, "push {r3}", Str, "str r3, [sp, #-0x4]!");
+    assert_alias!(arm, ArmV6K, 0xe8bd0011, PopM, "pop {r0, r4}", LdmW, "ldm sp!, {r0, r4}");
+    assert_alias!(arm, ArmV6K, 0xe49d3004, PopR, "pop {r3}", Ldr, "ldr r3, [sp], #0x4");
+
+    assert_alias!(arm, ArmV6K, 0xe1a011c2, Asr, "asr r1, r2, #0x3", Mov, "mov r1, r2, asr #0x3");
+    assert_alias!(arm, ArmV6K, 0xe1a01182, Lsl, "lsl r1, r2, #0x3", Mov, "mov r1, r2, lsl #0x3");
+    assert_alias!(arm, ArmV6K, 0xe1a01062, Rrx, "rrx r1, r2", Mov, "mov r1, r2, rrx");
+    assert_alias!(arm, ArmV6K, 0x01a01312, Lsl, "lsleq r1, r2, r3", Mov, "moveq r1, r2, lsl r3");
+    assert_alias!(
+        arm,
+        ArmV6K,
+        0xe1b01182,
+        Lsl,
+        "lsls r1, r2, #0x3",
+        Mov,
+        "movs r1, r2, lsl #0x3"
+    );
+
+    assert_alias!(thumb, ThumbV6K, 0x0011, MovsR, "movs r1, r2", LslI, "lsls r1, r2, #0x0");
+    assert_alias!(thumb, ThumbV6K, 0x4251, Rsbs, "rsbs r1, r2, #0x0", Neg, "neg r1, r2");
+}
+
+#[test]
+fn test_alias_defs_uses() {
+    let flags = ParseFlags::default();
+    let regs = |regs: &[Register]| regs.iter().copied().collect::<RegisterSet>();
+
+    // `adr` reads PC, like the underlying `add r1, pc, #0x3fc`
+    let ins = thumb::Ins::new(0xa1ff, &flags);
+    let parsed = ins.parse(&flags);
+    assert_eq!(parsed.defs(Op::ThumbV6K(ins.op)), regs(&[Register::R1]));
+    assert_eq!(parsed.uses(Op::ThumbV6K(ins.op)), regs(&[Register::Pc]));
+
+    let ins = arm::Ins::new(0xe52d3004, &flags);
+    let parsed = ins.parse(&flags);
+    assert_eq!(parsed.defs(Op::ArmV6K(ins.op)), regs(&[Register::Sp]));
+    assert_eq!(parsed.uses(Op::ArmV6K(ins.op)), regs(&[Register::R3, Register::Sp]));
+
+    let ins = arm::Ins::new(0xe8bd0011, &flags);
+    let parsed = ins.parse(&flags);
+    assert_eq!(
+        parsed.defs(Op::ArmV6K(ins.op)),
+        regs(&[Register::R0, Register::R4, Register::Sp])
+    );
+    assert_eq!(parsed.uses(Op::ArmV6K(ins.op)), regs(&[Register::Sp]));
+
+    let ins = arm::Ins::new(0xe1a01312, &flags);
+    let parsed = ins.parse(&flags);
+    assert_eq!(parsed.defs(Op::ArmV6K(ins.op)), regs(&[Register::R1]));
+    assert_eq!(parsed.uses(Op::ArmV6K(ins.op)), regs(&[Register::R2, Register::R3]));
+}
+
+#[test]
+fn test_not_aliases() {
+    let flags = ParseFlags::default();
+
+    // NOP has its own hint encoding in ARMv6K, so it is not an alias of MOV
+    let ins = arm::Ins::new(0xe320f000, &flags);
+    assert_eq!(ins.op, arm::Opcode::Nop);
+    assert_eq!(ins.aliased_from(&flags), None);
+    assert_eq!(ins.parse(&flags).aliased_from(Op::ArmV6K(ins.op)), None);
+    assert_eq!(ins.parse_unaliased(&flags).display(Default::default()).to_string(), "nop");
+
+    // SVC only renames SWI, so the parsed instruction has no underlying form with other operands
+    let ins = arm::Ins::new(0xef000123, &flags);
+    assert_eq!(ins.aliased_from(&flags), Some(arm::Opcode::Swi));
+    assert_eq!(ins.parse(&flags).aliased_from(Op::ArmV6K(ins.op)), None);
+
+    let ins = arm::Ins::new(0xe0812003, &flags);
+    assert_eq!(ins.aliased_from(&flags), None);
+    assert_eq!(
+        ins.parse_unaliased(&flags).display(Default::default()).to_string(),
+        "add r2, r1, r3"
+    );
+}
@@ -0,0 +1,186 @@
+use unarm::{
+    v5te::{arm, thumb, Ins, UnifiedIter},
+    Endian, ParseFlags, ParseMode, ParsedIns,
+};
+
+const BASE: u32 = 0x02000000;
+
+/// ARM code which switches to Thumb with `bx r0`, and back to ARM with `bx pc`
+fn veneers() -> Vec<u8> {
+    let mut code = vec![];
+    // add r0, pc, #0x1
+    code.extend(0xe28f0001u32.to_le_bytes());
+    // bx r0
+    code.extend(0xe12fff10u32.to_le_bytes());
+    // movs r0, #0x1; adds r0, r0, #0x1; bl #0x8; bx pc; nop
+    for halfword in [0x2001u16, 0x1c40, 0xf000, 0xf802, 0x4778, 0x46c0] {
+        code.extend(halfword.to_le_bytes());
+    }
+    // mov r0, #0x0
+    code.extend(0xe3a00000u32.to_le_bytes());
+    // bx lr
+    code.extend(0xe12fff1eu32.to_le_bytes());
+    code
+}
+
+/// ARM `blx` to a Thumb function after it
+fn blx_call() -> Vec<u8> {
+    let mut code = vec![];
+    // blx #0x8
+    code.extend(0xfa000000u32.to_le_bytes());
+    // bx lr
+    code.extend(0xe12fff1eu32.to_le_bytes());
+    // movs r0, #0x1; bx lr
+    for halfword in [0x2001u16, 0x4770] {
+        code.extend(halfword.to_le_bytes());
+    }
+    code
+}
+
+fn unified(iter: UnifiedIter) -> Vec<(u32, ParseMode, ParsedIns)> {
+    iter.map(|(address, ins, parsed)| (address - BASE, ins.mode(), parsed))
+        .collect()
+}
+
+/// Parses `code` with the per-mode iterators, with the mode of each range given by `modes`
+fn per_mode(code: &[u8], modes: &[(u32, u32, ParseMode)]) -> Vec<(u32, ParseMode, ParsedIns)> {
+    let flags = ParseFlags::default();
+    let mut lines = vec![];
+    for &(start, end, mode) in modes {
+        let bytes = &code[start as usize..end as usize];
+        match mode {
+            ParseMode::Arm => lines.extend(
+                arm::InsIter::new(bytes, BASE + start, Endian::Little, flags)
+                    .map(|(address, _, parsed)| (address - BASE, mode, parsed)),
+            ),
+            ParseMode::Thumb => lines.extend(
+                thumb::InsIter::new(bytes, BASE + start, Endian::Little, flags)
+                    .map(|(address, _, parsed)| (address - BASE, mode, parsed)),
+            ),
+            ParseMode::Data => unreachable!(),
+        }
+    }
+    lines
+}
+
+fn display(lines: &[(u32, ParseMode, ParsedIns)]) -> Vec<(u32, String)> {
+    lines
+        .iter()
+        .map(|(address, _, parsed)| (*address, parsed.display(Default::default()).to_string()))
+        .collect()
+}
+
+#[test]
+fn test_follow_bx() {
+    let code = veneers();
+    let iter = UnifiedIter::new(&code, BASE, Endian::Little, Default::default(), ParseMode::Arm).follow_mode_switches();
+    let lines = unified(iter);
+    assert_eq!(
+        lines,
+        per_mode(
+            &code,
+            &[
+                (0x0, 0x8, ParseMode::Arm),
+                (0x8, 0x14, ParseMode::Thumb),
+                (0x14, 0x1c, ParseMode::Arm)
+            ]
+        )
+    );
+    assert_eq!(
+        display(&lines),
+        [
+            (0x0, "add r0, pc, #0x1".to_string()),
+            (0x4, "bx r0".to_string()),
+            (0x8, "movs r0, #0x1".to_string()),
+            (0xa, "adds r0, r0, #0x1".to_string()),
+            (0xc, "bl #0x8".to_string()),
+            (0x10, "bx pc".to_string()),
+            (0x12, "mov r8, r8".to_string()),
+            (0x14, "mov r0, #0x0".to_string()),
+            (0x18, "bx lr".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_follow_blx() {
+    let code = blx_call();
+    let iter = UnifiedIter::new(&code, BASE, Endian::Little, Default::default(), ParseMode::Arm).follow_mode_switches();
+    let lines = unified(iter);
+    assert_eq!(
+        lines,
+        per_mode(&code, &[(0x0, 0x8, ParseMode::Arm), (0x8, 0xc, ParseMode::Thumb)])
+    );
+    assert_eq!(
+        display(&lines)[2..],
+        [(0x8, "movs r0, #0x1".to_string()), (0xa, "bx lr".to_string())]
+    );
+}
+
+#[test]
+fn test_no_heuristics() {
+    let code = veneers();
+    let lines = unified(UnifiedIter::new(
+        &code,
+        BASE,
+        Endian::Little,
+        Default::default(),
+        ParseMode::Arm,
+    ));
+    assert_eq!(lines, per_mode(&code, &[(0x0, 0x1c, ParseMode::Arm)]));
+}
+
+#[test]
+fn test_set_mode() {
+    let code = veneers();
+    let mut iter = UnifiedIter::new(&code, BASE, Endian::Little, Default::default(), ParseMode::Arm);
+    let mut lines = vec![];
+    loop {
+        let mode = match iter.address - BASE {
+            0x8..0x14 => ParseMode::Thumb,
+            _ => ParseMode::Arm,
+        };
+        iter.set_mode(mode);
+        let Some((address, ins, parsed)) = iter.next() else {
+            break;
+        };
+        assert_eq!(ins.mode(), iter.mode());
+        lines.push((address - BASE, ins.mode(), parsed));
+    }
+    assert_eq!(
+        lines,
+        per_mode(
+            &code,
+            &[
+                (0x0, 0x8, ParseMode::Arm),
+                (0x8, 0x14, ParseMode::Thumb),
+                (0x14, 0x1c, ParseMode::Arm)
+            ]
+        )
+    );
+}
+
+#[test]
+fn test_ins() {
+    let flags = ParseFlags::default();
+    let code = veneers();
+    let iter = UnifiedIter::new(&code, BASE, Endian::Little, flags, ParseMode::Arm).follow_mode_switches();
+    for (_, ins, parsed) in iter {
+        match ins {
+            Ins::Arm(arm) => {
+                assert_eq!(ins.code(), arm.code);
+                assert_eq!(ins.size(), 4);
+                assert_eq!(ins.parse(&flags), parsed);
+            }
+            Ins::Thumb(thumb) => {
+                assert_eq!(ins.code(), thumb.code);
+                if thumb.is_half_bl() {
+                    assert_eq!(ins.size(), 4);
+                } else {
+                    assert_eq!(ins.size(), 2);
+                    assert_eq!(ins.parse(&flags), parsed);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use unarm::ParseFlags;
+
+const STRICT: ParseFlags = ParseFlags {
+    unpredictable_as_illegal: true,
+    ..ParseFlags::DEFAULT
+};
+
+macro_rules! check {
+    ($module:ident, $code:expr, $reason:expr) => {{
+        use unarm::$module::arm::{Ins, Opcode};
+        let code: u32 = $code;
+        let reason: Option<&str> = $reason;
+        let ins = Ins::new(code, &Default::default());
+        assert_ne!(ins.op, Opcode::Illegal, "{code:08x}");
+        assert_eq!(ins.unpredictable_reason(), reason, "{code:08x}");
+        assert_eq!(ins.is_unpredictable(), reason.is_some(), "{code:08x}");
+
+        let strict = Ins::new(code, &STRICT);
+        if reason.is_some() {
+            assert_eq!(strict.op, Opcode::Illegal, "{code:08x}");
+            assert_eq!(strict.parse(&STRICT).display(Default::default()).to_string(), "<illegal>");
+        } else {
+            assert_eq!(strict.op, ins.op, "{code:08x}");
+        }
+    }};
+}
+
+#[test]
+fn test_ldrd_strd() {
+    // ldrd r3, r4, [r0] / ldrd r2, r3, [r0]
+    check!(v5te, 0xe1c030d0, Some("Odd first transfer register"));
+    check!(v5te, 0xe1c020d0, None);
+    // strd r3, r4, [r0] / strd r2, r3, [r0]
+    check!(v6k, 0xe1c030f0, Some("Odd first transfer register"));
+    check!(v6k, 0xe1c020f0, None);
+    // ldrd lr, pc, [r0]
+    check!(v5te, 0xe1c0e0d0, Some("PC as second transfer register"));
+    // ldrd r0, r1, [r1, #0x8]! / ldrd r0, r1, [r2, #0x8]!
+    check!(v5te, 0xe1e100d8, Some("Writeback to a loaded register"));
+    check!(v5te, 0xe1e200d8, None);
+}
+
+#[test]
+fn test_exclusive_doubleword() {
+    // ldrexd r3, r4, [r0] / ldrexd r2, r3, [r0]
+    check!(v6k, 0xe1b03f9f, Some("Odd first transfer register"));
+    check!(v6k, 0xe1b02f9f, None);
+    // ldrexd lr, pc, [r0]
+    check!(v6k, 0xe1b0ef9f, Some("PC as second transfer register"));
+    // strexd r2, r5, r6, [r1] / strexd r0, r2, r3, [r1]
+    check!(v6k, 0xe1a12f95, Some("Odd first stored register"));
+    check!(v6k, 0xe1a10f92, None);
+    // strexd r3, r2, r3, [r1] / strexd r1, r2, r3, [r1]
+    check!(v6k, 0xe1a13f92, Some("Status register is the base or a stored register"));
+    check!(v6k, 0xe1a11f92, Some("Status register is the base or a stored register"));
+}
+
+#[test]
+fn test_mul() {
+    // mul pc, r1, r2 / mul r1, r3, r2
+    check!(v4t, 0xe00f0291, Some("PC as destination register"));
+    check!(v4t, 0xe0010293, None);
+    // mul r1, r1, r2
+    check!(v5te, 0xe0010291, Some("Destination register same as first operand"));
+    check!(v6k, 0xe0010291, None);
+    // mla pc, r4, r3, r2
+    check!(v6k, 0xe02f2394, Some("PC as destination register"));
+    // umull pc, r1, r2, r3 / umull r0, r1, r2, r3
+    check!(v5te, 0xe081f392, Some("PC as destination register"));
+    check!(v5te, 0xe0810392, None);
+    // umull r1, r1, r2, r3
+    check!(v5te, 0xe0811392, Some("Same destination register for both halves"));
+}
+
+#[test]
+fn test_writeback() {
+    // ldr r2, [r2, #0x4]! / ldr r2, [r1, #0x4]!
+    check!(v5te, 0xe5b22004, Some("Writeback to the loaded register"));
+    check!(v5te, 0xe5b12004, None);
+    // ldr r2, [r2], #0x4 / ldr r2, [r2, #0x4]
+    check!(v4t, 0xe4922004, Some("Writeback to the loaded register"));
+    check!(v4t, 0xe5922004, None);
+    // ldm r0!, {r0, r1, r2, r3, lr} / ldm r0!, {r1, r2, lr} / ldm r0, {r0, r1, r2, r3, lr}
+    check!(v5te, 0xe8b0400f, Some("Writeback to a loaded register"));
+    check!(v5te, 0xe8b04006, None);
+    check!(v5te, 0xe890400f, None);
+}
+
+macro_rules! check_misc_load_store {
+    ($module:ident) => {{
+        use unarm::$module::arm::Ins;
+        // The immediate 0xfc is split into bits 8..12 and 0..4, so reading the immediate/register select bit (22) from
+        // the wrong position displays r12 instead of #0xfc, or #0x3 instead of r3
+        for (mnemonic, bits) in [("ldrh", 0x1000b0), ("strh", 0xb0), ("ldrsb", 0x1000d0), ("ldrsh", 0x1000f0)] {
+            for p in [0, 1] {
+                for i in [0, 1] {
+                    for w in [0, 1] {
+                        let offset = if i == 1 { 0xf0c } else { 0x3 };
+                        let code = 0xe0812000 | bits | p << 24 | i << 22 | w << 21 | offset;
+                        let reason = (p == 0 && w == 1).then_some("Post-indexed with writeback");
+                        check!($module, code, reason);
+
+                        let offset = if i == 1 { "#0xfc" } else { "r3" };
+                        let expected = match (p, w) {
+                            (0, 0) => format!("{mnemonic} r2, [r1], {offset}"),
+                            (0, _) => "<illegal>".to_string(),
+                            (_, 0) => format!("{mnemonic} r2, [r1, {offset}]"),
+                            _ => format!("{mnemonic} r2, [r1, {offset}]!"),
+                        };
+                        let flags = Default::default();
+                        let text = Ins::new(code, &flags).parse(&flags).display(Default::default()).to_string();
+                        assert_eq!(text, expected, "{code:08x}");
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[test]
+fn test_misc_load_store() {
+    // Every (P, I, W) combination of the halfword and signed byte loads and stores
+    check_misc_load_store!(v4t);
+    check_misc_load_store!(v5te);
+    check_misc_load_store!(v6k);
+}
+
+#[test]
+fn test_empty_register_list() {
+    // ldm r0, {} / stmdb r0!, {} / ldmib r0, {}^ / ldm r0, {r0}
+    check!(v4t, 0xe8900000, Some("Empty register list"));
+    check!(v5te, 0xe9200000, Some("Empty register list"));
+    check!(v6k, 0xe9d00000, Some("Empty register list"));
+    check!(v5te, 0xe8900001, None);
+
+    // ldmia r0!, {} / push {} / pop {pc}
+    use unarm::v5te::thumb::Ins;
+    assert_eq!(
+        Ins::new(0xc800, &Default::default()).unpredictable_reason(),
+        Some("Empty register list")
+    );
+    assert_eq!(
+        Ins::new(0xb400, &Default::default()).unpredictable_reason(),
+        Some("Empty register list")
+    );
+    assert_eq!(Ins::new(0xbd00, &Default::default()).unpredictable_reason(), None);
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+/// Checks the generated variant names against a snapshot written by the generator, e.g. `specs/v5te/arm.variants.txt`
+macro_rules! check_snapshot {
+    ($module:ident::$mode:ident, $path:literal) => {{
+        use unarm::$module::$mode::Opcode;
+        let names: Vec<&str> = include_str!($path)
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect();
+        assert_eq!(names.len(), Opcode::count(), $path);
+        for name in names {
+            let op = Opcode::from_variant_name(name).unwrap_or_else(|| panic!("{name} in {}", $path));
+            assert_eq!(op.variant_name(), name);
+            assert_eq!(format!("{op:?}"), name);
+        }
+    }};
+}
+
+#[test]
+fn test_snapshots() {
+    check_snapshot!(v4t::arm, "../../specs/v4t/arm.variants.txt");
+    check_snapshot!(v4t::thumb, "../../specs/v4t/thumb.variants.txt");
+    check_snapshot!(v5te::arm, "../../specs/v5te/arm.variants.txt");
+    check_snapshot!(v5te::thumb, "../../specs/v5te/thumb.variants.txt");
+    check_snapshot!(v6k::arm, "../../specs/v6k/arm.variants.txt");
+    check_snapshot!(v6k::thumb, "../../specs/v6k/thumb.variants.txt");
+}
+
+/// Checks that the ids of every opcode are unique and can be looked up again
+macro_rules! check_ids {
+    ($module:ident::$mode:ident) => {{
+        use unarm::$module::$mode::Opcode;
+        let mut ids = HashSet::new();
+        for op in Opcode::iter().chain([Opcode::Illegal]) {
+            assert!(ids.insert(op.id()), "{} of {op:?} in {}", op.id(), stringify!($module::$mode));
+            assert_eq!(Opcode::from_id(op.id()), Some(op));
+        }
+        assert_eq!(ids.len(), Opcode::count() + 1);
+    }};
+}
+
+#[test]
+fn test_ids() {
+    check_ids!(v4t::arm);
+    check_ids!(v4t::thumb);
+    check_ids!(v5te::arm);
+    check_ids!(v5te::thumb);
+    check_ids!(v6k::arm);
+    check_ids!(v6k::thumb);
+
+    use unarm::v5te::arm::Opcode;
+    assert_eq!(Opcode::LdrB.id(), "ldr_b");
+    assert_eq!(Opcode::LdmPcW.id(), "ldm_pc_w");
+    assert_eq!(Opcode::from_id("mov_imm"), Some(Opcode::MovImm));
+    assert_eq!(Opcode::from_id("illegal"), Some(Opcode::Illegal));
+    assert_eq!(Opcode::from_id("MovImm"), None);
+}
+
+#[test]
+fn test_duplicated_mnemonics() {
+    use unarm::v5te::arm::{Ins, Opcode};
+
+    let flags = Default::default();
+    // mov r0, #0x1 and mov r0, r1 are different opcodes with the same mnemonic
+    let mov_imm = Ins::new(0xe3a00001, &flags).op;
+    let mov_reg = Ins::new(0xe1a00001, &flags).op;
+    assert_eq!(mov_imm.mnemonic(), mov_reg.mnemonic());
+    assert_ne!(mov_imm.variant_name(), mov_reg.variant_name());
+
+    assert_eq!(Opcode::Illegal.variant_name(), "Illegal");
+    assert_eq!(Opcode::from_variant_name("Illegal"), Some(Opcode::Illegal));
+    assert_eq!(Opcode::from_variant_name("ldr"), None);
+}
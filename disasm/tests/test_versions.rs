@@ -0,0 +1,72 @@
+use unarm::{v4t, v5te, ArmVersion, Endian, Op, ParseFlags, ParseMode, Parser};
+
+/// Parses `code` as little-endian words or halfwords, and returns the mnemonic of each instruction
+fn mnemonics(version: ArmVersion, mode: ParseMode, code: &[u32]) -> Vec<&'static str> {
+    let bytes: Vec<u8> = match mode {
+        ParseMode::Thumb => code.iter().flat_map(|code| (*code as u16).to_le_bytes()).collect(),
+        _ => code.iter().flat_map(|code| code.to_le_bytes()).collect(),
+    };
+    Parser::new(version, mode, 0, Endian::Little, ParseFlags::default(), &bytes)
+        .map(|(_, _, ins)| ins.mnemonic)
+        .collect()
+}
+
+#[test]
+fn test_v5t() {
+    let dsp = [
+        0xe1012053, // qadd r2, r3, r1
+        0xe1600281, // smulbb r0, r1, r2
+        0xe1c100d0, // ldrd r0, r1, [r1]
+        0xf5d0f000, // pld [r0]
+    ];
+    assert_eq!(
+        mnemonics(ArmVersion::V5Te, ParseMode::Arm, &dsp),
+        ["qadd", "smulbb", "ldrd", "pld"]
+    );
+    assert_eq!(mnemonics(ArmVersion::V5T, ParseMode::Arm, &dsp), ["<illegal>"; 4]);
+
+    let base = [
+        0xe12fff30, // blx r0
+        0xe16f1f12, // clz r1, r2
+        0xe0810002, // add r0, r1, r2
+    ];
+    assert_eq!(mnemonics(ArmVersion::V5T, ParseMode::Arm, &base), ["blx", "clz", "add"]);
+    assert_eq!(
+        mnemonics(ArmVersion::V5T, ParseMode::Thumb, &[0x4780, 0xf000, 0xe800]),
+        ["blx", "blx"]
+    );
+
+    let qadd = Op::ArmV5Te(v5te::arm::Opcode::Qadd);
+    assert!(ArmVersion::V5Te.supports(qadd));
+    assert!(!ArmVersion::V5T.supports(qadd));
+    let (_, op, _) = Parser::new(
+        ArmVersion::V5T,
+        ParseMode::Arm,
+        0,
+        Endian::Little,
+        ParseFlags::default(),
+        &[0x53, 0x20, 0x01, 0xe1],
+    )
+    .next()
+    .unwrap();
+    assert_eq!(op, Op::ArmV5Te(v5te::arm::Opcode::Illegal));
+}
+
+#[test]
+fn test_v4() {
+    let code = [
+        0xe12fff1e, // bx lr
+        0xe0810002, // add r0, r1, r2
+        0xe1d100b2, // ldrh r0, [r1, #0x2]
+    ];
+    assert_eq!(mnemonics(ArmVersion::V4T, ParseMode::Arm, &code), ["bx", "add", "ldrh"]);
+    assert_eq!(mnemonics(ArmVersion::V4, ParseMode::Arm, &code), ["<illegal>", "add", "ldrh"]);
+    assert!(!ArmVersion::V4.supports(Op::ArmV4T(v4t::arm::Opcode::Bx)));
+
+    // No Thumb instructions, and a BL pair is two illegal halfwords
+    assert_eq!(
+        mnemonics(ArmVersion::V4, ParseMode::Thumb, &[0x1c48, 0xf000, 0xf800]),
+        ["<illegal>"; 3]
+    );
+    assert_eq!(mnemonics(ArmVersion::V4, ParseMode::Data, &[0x1234]), [".word"]);
+}
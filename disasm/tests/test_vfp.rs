@@ -0,0 +1,188 @@
+use unarm::{
+    analysis::{RegisterSet, StatusFlags},
+    args::{Argument, DReg, DRegList, Register},
+    v5te, v6k, Op, ParseFlags, ParsedIns,
+};
+
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    vfp: true,
+    ..ParseFlags::DEFAULT
+};
+const UNIFIED: ParseFlags = ParseFlags {
+    vfp: true,
+    ..ParseFlags::DEFAULT
+};
+
+fn disasm(code: u32, flags: &ParseFlags) -> String {
+    let v5te = v5te::arm::Ins::new(code, flags)
+        .parse(flags)
+        .display(Default::default())
+        .to_string();
+    let v6k = v6k::arm::Ins::new(code, flags)
+        .parse(flags)
+        .display(Default::default())
+        .to_string();
+    assert_eq!(v5te, v6k, "{code:#010x}");
+    v5te
+}
+
+macro_rules! assert_vfp {
+    ($code:literal, $divided:literal, $unified:literal) => {{
+        assert_eq!(disasm($code, &DIVIDED), $divided);
+        assert_eq!(disasm($code, &UNIFIED), $unified);
+    }};
+}
+
+#[test]
+fn test_data_processing() {
+    assert_vfp!(0xee300a01, "fadds s0, s0, s2", "vadd.f32 s0, s0, s2");
+    assert_vfp!(0xee310b02, "faddd d0, d1, d2", "vadd.f64 d0, d1, d2");
+    assert_vfp!(0x0e201a81, "fmulseq s2, s1, s2", "vmuleq.f32 s2, s1, s2");
+    assert_vfp!(0xee000a40, "fnmacs s0, s0, s0", "vmls.f32 s0, s0, s0");
+    assert_vfp!(0xee800b01, "fdivd d0, d0, d1", "vdiv.f64 d0, d0, d1");
+    assert_vfp!(0xeef00a60, "fcpys s1, s1", "vmov.f32 s1, s1");
+    assert_vfp!(0xeeb10bc1, "fsqrtd d0, d1", "vsqrt.f64 d0, d1");
+    assert_vfp!(0xeeb40ae0, "fcmpes s0, s1", "vcmpe.f32 s0, s1");
+    assert_vfp!(0xeeb50a40, "fcmpzs s0", "vcmp.f32 s0, #0x0");
+    assert_vfp!(0xeeb50bc0, "fcmpezd d0", "vcmpe.f64 d0, #0x0");
+}
+
+#[test]
+fn test_conversions() {
+    assert_vfp!(0xeeb70ac0, "fcvtds d0, s0", "vcvt.f64.f32 d0, s0");
+    assert_vfp!(0xeeb70bc0, "fcvtsd s0, d0", "vcvt.f32.f64 s0, d0");
+    assert_vfp!(0xeeb80a60, "fuitos s0, s1", "vcvt.f32.u32 s0, s1");
+    assert_vfp!(0xeeb80bc0, "fsitod d0, s0", "vcvt.f64.s32 d0, s0");
+    assert_vfp!(0xeebc0a40, "ftouis s0, s0", "vcvtr.u32.f32 s0, s0");
+    assert_vfp!(0xeebd0ac0, "ftosizs s0, s0", "vcvt.s32.f32 s0, s0");
+    assert_vfp!(0xeefd0bc1, "ftosizd s1, d1", "vcvt.s32.f64 s1, d1");
+}
+
+#[test]
+fn test_load_store() {
+    assert_vfp!(0xed9f0a02, "flds s0, [pc, #0x8]", "vldr s0, [pc, #0x8]");
+    assert_vfp!(0xed118b01, "fldd d8, [r1, #-0x4]", "vldr d8, [r1, #-0x4]");
+    assert_vfp!(0x1dc40a00, "fstsne s1, [r4, #0x0]", "vstrne s1, [r4, #0x0]");
+    assert_vfp!(0xec900a04, "fldmias r0, {s0-s3}", "vldmia r0, {s0-s3}");
+    assert_vfp!(0xeca01b02, "fstmiad r0!, {d1}", "vstmia r0!, {d1}");
+    assert_vfp!(0xed300b04, "fldmdbd r0!, {d0-d1}", "vldmdb r0!, {d0-d1}");
+    assert_vfp!(0xed2d8b08, "fstmdbd sp!, {d8-d11}", "vpush {d8-d11}");
+    assert_vfp!(0xed2d8b09, "fstmdbx sp!, {d8-d11}", "vpush {d8-d11}");
+    assert_vfp!(0xecbd8b08, "fldmiad sp!, {d8-d11}", "vpop {d8-d11}");
+    assert_vfp!(0xecfd0a01, "fldmias sp!, {s1}", "vpop {s1}");
+}
+
+#[test]
+fn test_transfers() {
+    assert_vfp!(0xee000a10, "fmsr s0, r0", "vmov s0, r0");
+    assert_vfp!(0xee105a90, "fmrs r5, s1", "vmov r5, s1");
+    assert_vfp!(0xee021b10, "fmdlr d2, r1", "vmov.32 d2[0], r1");
+    assert_vfp!(0xee201b10, "fmdhr d0, r1", "vmov.32 d0[1], r1");
+    assert_vfp!(0xee330b10, "fmrdh r0, d3", "vmov.32 r0, d3[1]");
+    assert_vfp!(0xec410b12, "fmdrr d2, r0, r1", "vmov d2, r0, r1");
+    assert_vfp!(0xec510b10, "fmrrd r0, r1, d0", "vmov r0, r1, d0");
+    assert_vfp!(0xeee10a10, "fmxr fpscr, r0", "vmsr fpscr, r0");
+    assert_vfp!(0xeee83a10, "fmxr fpexc, r3", "vmsr fpexc, r3");
+    assert_vfp!(0xeef00a10, "fmrx r0, fpsid", "vmrs r0, fpsid");
+    assert_vfp!(0xeef1fa10, "fmstat", "vmrs apsr_nzcv, fpscr");
+    // Only four system registers exist
+    assert_vfp!(0xeee20a10, "mcr p10, #7, r0, c2, c0, #0", "mcr p10, #7, r0, c2, c0, #0");
+}
+
+#[test]
+fn test_register_lists() {
+    let ins = v5te::arm::Ins::new(0xed2d8b08, &UNIFIED);
+    let parsed = ins.parse(&UNIFIED);
+    assert_eq!(
+        parsed.args[0],
+        Argument::DRegList(DRegList {
+            first: DReg::D8,
+            count: 4
+        })
+    );
+    assert_eq!(ins.aliased_from(&UNIFIED), Some(v5te::arm::Opcode::Fstmdb));
+    assert_eq!(
+        ins.parse_unaliased(&UNIFIED).display(Default::default()).to_string(),
+        "fstmdbd sp!, {d8-d11}"
+    );
+    assert!(!ins.is_unpredictable());
+    assert!(v5te::arm::Ins::new(0xed2d8b00, &UNIFIED).is_unpredictable());
+    assert!(v5te::arm::Ins::new(0xed2dfb04, &UNIFIED).is_unpredictable());
+}
+
+#[test]
+fn test_defs_uses() {
+    let defs_uses = |code| {
+        let ins = v5te::arm::Ins::new(code, &UNIFIED);
+        let parsed = ins.parse(&UNIFIED);
+        let op = Op::ArmV5Te(ins.op);
+        (parsed.defs(op), parsed.uses(op))
+    };
+    let set = |regs: &[Register]| regs.iter().copied().collect::<RegisterSet>();
+    // vmov r0, r1, d0 / vmov d2, r0, r1
+    assert_eq!(defs_uses(0xec510b10), (set(&[Register::R0, Register::R1]), set(&[])));
+    assert_eq!(defs_uses(0xec410b12), (set(&[]), set(&[Register::R0, Register::R1])));
+    // vmsr fpscr, r0 / vmrs r0, fpsid
+    assert_eq!(defs_uses(0xeee10a10), (set(&[]), set(&[Register::R0])));
+    assert_eq!(defs_uses(0xeef00a10), (set(&[Register::R0]), set(&[])));
+    // vldmia r0, {s0-s3} / vstmia r0!, {d1} / vpush {d8-d11}
+    assert_eq!(defs_uses(0xec900a04), (set(&[]), set(&[Register::R0])));
+    assert_eq!(defs_uses(0xeca01b02), (set(&[Register::R0]), set(&[Register::R0])));
+    assert_eq!(defs_uses(0xed2d8b08), (set(&[Register::Sp]), set(&[Register::Sp])));
+
+    for flags in [DIVIDED, UNIFIED] {
+        let fmstat = v5te::arm::Ins::new(0xeef1fa10, &flags).parse(&flags);
+        assert_eq!(fmstat.flags_written(), StatusFlags::NZCV);
+    }
+}
+
+#[test]
+fn test_without_vfp() {
+    // Without the flag, NDS code keeps decoding coprocessor 10 and 11 as generic coprocessor instructions
+    let flags = ParseFlags::default();
+    assert_eq!(disasm(0xed2d8b08, &flags), "stc p11, c8, [sp, #-0x20]!");
+    assert_eq!(disasm(0xee300a01, &flags), "cdp p10, #3, c0, c0, c1, #0");
+    assert_eq!(disasm(0xee000a10, &flags), "mcr p10, #0, r0, c0, c0, #0");
+}
+
+#[test]
+fn test_text_round_trip() {
+    for code in [0xee310b02, 0xed2d8b08, 0xee201b10, 0xeef1fa10, 0xeeb50a40, 0xed118b01] {
+        for flags in [DIVIDED, UNIFIED] {
+            let parsed = v6k::arm::Ins::new(code, &flags).parse(&flags);
+            let text = parsed.display(Default::default()).to_string();
+            assert_eq!(text.parse::<ParsedIns>(), Ok(parsed), "{text}");
+        }
+    }
+}
+
+#[test]
+fn test_encode_round_trip() {
+    for flags in [DIVIDED, UNIFIED] {
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let mut count = 0;
+        for _ in 0..0x4000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            // Coprocessor 10 or 11 in the coprocessor load/store, data-processing and register transfer spaces
+            let code = (state & !0x0f000e00) | (0xc + state % 3) << 24 | 0xa00;
+            let ins = v6k::arm::Ins::new(code, &flags);
+            if ins.op.extension() != Some("vfp") {
+                continue;
+            }
+            let parsed = ins.parse(&flags);
+            if parsed.mnemonic == "<illegal>" {
+                continue;
+            }
+            let text = parsed.display(Default::default()).to_string();
+            let encoded = v6k::arm::encode(&parsed, &flags);
+            let decoded = encoded.map(|code| v6k::arm::Ins::new(code, &flags).parse(&flags));
+            assert_eq!(decoded, Ok(parsed), "{text} ({code:#x}, ual: {})", flags.ual);
+            count += 1;
+        }
+        assert!(count > 0x1000, "{count}");
+    }
+}
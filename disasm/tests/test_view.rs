@@ -0,0 +1,466 @@
+use unarm::{
+    args::CoReg,
+    args::{Argument, Register, Shift, ShiftImm, ShiftReg},
+    view::{
+        AddrOffset, BranchKind, BranchTarget, BranchView, CoAddrMode, CoLoadStoreView, DataOp, DataProcessingView,
+        LoadStoreView, MemOperand, Operand2, Width,
+    },
+    Condition, ParseFlags, ParsedIns,
+};
+
+const DIVIDED: ParseFlags = ParseFlags {
+    ual: false,
+    ..ParseFlags::DEFAULT
+};
+const UNIFIED: ParseFlags = ParseFlags::DEFAULT;
+
+/// Family of an opcode, from its categories
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Family {
+    DataProcessing,
+    LoadStore,
+    CoLoadStore,
+    Branch,
+    Other,
+}
+
+macro_rules! decode {
+    ($module:ident::$mode:ident, $code:expr, $flags:expr) => {{
+        use unarm::$module::$mode::Ins;
+        let ins = Ins::new($code, $flags);
+        let parsed = ins.parse($flags);
+        let op = ins.op;
+        let family = if op.is_data_processing() {
+            Family::DataProcessing
+        } else if op.is_branch() {
+            Family::Branch
+        } else if (op.is_load() || op.is_store()) && !op.is_coprocessor() && is_single_transfer(&parsed) {
+            Family::LoadStore
+        } else if (op.is_load() || op.is_store()) && op.is_coprocessor() {
+            Family::CoLoadStore
+        } else {
+            Family::Other
+        };
+        (family, parsed)
+    }};
+}
+
+/// Returns false for the loads and stores which have no view: multiple transfers, swaps, exclusives, `srs` and `rfe`
+fn is_single_transfer(ins: &ParsedIns) -> bool {
+    let mnemonic = ins.mnemonic_base();
+    !ins.args_iter().any(|arg| matches!(arg, Argument::RegList(_)))
+        && !matches!(mnemonic, "swp" | "swpb" | "srs" | "rfe")
+        && !mnemonic.contains("ex")
+}
+
+fn reg_name(reg: Register) -> String {
+    reg.display(Default::default()).to_string()
+}
+
+/// Returns the registers in the text of an instruction
+fn text_regs(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .skip(1)
+        .filter(|token| {
+            matches!(*token, "sp" | "lr" | "pc")
+                || token.strip_prefix('r').is_some_and(|n| n.parse::<u8>().is_ok_and(|n| n < 16))
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Converts an instruction of `family` into its view, and checks that the registers and immediates of the view are the
+/// ones in the text
+fn check(family: Family, ins: &ParsedIns) {
+    if ins.mnemonic == "<illegal>" {
+        return;
+    }
+    let text = ins.display(Default::default()).to_string();
+    let regs = text_regs(&text);
+    assert_eq!(ins.mem_operand().is_some(), text.contains('['), "{text}");
+    let assert_reg = |reg: Register| assert!(regs.contains(&reg_name(reg)), "{reg:?} not in {text}");
+    let assert_imm = |value: i64| {
+        let hex = if value < 0 {
+            format!("#-{:#x}", -value)
+        } else {
+            format!("#{value:#x}")
+        };
+        assert!(text.contains(&hex), "{hex} not in {text}");
+    };
+    match family {
+        Family::DataProcessing => {
+            let view = DataProcessingView::try_from(ins).unwrap_or_else(|e| panic!("{text}: {e}"));
+            assert_eq!(view.cond, ins.condition(), "{text}");
+            // `adr` is an addition to PC
+            let rn = view.rn.filter(|_| ins.mnemonic_base() != "adr");
+            view.rd.into_iter().chain(rn).for_each(assert_reg);
+            match view.operand2 {
+                Operand2::Imm(0) => {}
+                Operand2::Imm(value) => assert_imm(value as i64),
+                Operand2::Reg(reg) => assert_reg(reg),
+                Operand2::ShiftImm(reg, shift) => {
+                    assert_reg(reg);
+                    if shift.imm != 0 {
+                        assert_imm(shift.imm as i64);
+                    }
+                }
+                Operand2::ShiftReg(reg, shift) => {
+                    assert_reg(reg);
+                    assert_reg(shift.reg);
+                }
+            }
+        }
+        Family::LoadStore => {
+            let view = LoadStoreView::try_from(ins).unwrap_or_else(|e| panic!("{text}: {e}"));
+            assert_eq!(view.cond, ins.condition(), "{text}");
+            assert_eq!(regs[0], reg_name(view.rt), "{text}");
+            assert!(text.contains(&format!("[{}", reg_name(view.base))), "{text}");
+            assert_eq!(view.writeback, view.post_indexed || text.ends_with('!'), "{text}");
+            assert_eq!(
+                ins.mem_operand(),
+                Some(MemOperand {
+                    base: view.base,
+                    offset: view.offset,
+                    pre_indexed: !view.post_indexed,
+                    writeback: view.writeback,
+                }),
+                "{text}"
+            );
+            match view.offset {
+                AddrOffset::Imm(value) => assert_imm(value as i64),
+                AddrOffset::Reg { reg, .. } => assert_reg(reg),
+            }
+        }
+        Family::CoLoadStore => {
+            let view = CoLoadStoreView::try_from(ins).unwrap_or_else(|e| panic!("{text}: {e}"));
+            assert_eq!(view.cond, ins.condition(), "{text}");
+            assert!(
+                text.contains(&format!("p{}, c{}, [{}", view.coproc, view.crd as u8, reg_name(view.base))),
+                "{text}"
+            );
+            assert_eq!(view.writeback, text.ends_with('!') || text.contains("], #"), "{text}");
+            match view.mode {
+                CoAddrMode::Unindexed(option) => assert!(text.ends_with(&format!("{{{option:#x}}}")), "{text}"),
+                _ => assert_imm(view.offset as i64),
+            }
+        }
+        Family::Branch => {
+            let view = BranchView::try_from(ins).unwrap_or_else(|e| panic!("{text}: {e}"));
+            assert_eq!(view.cond, ins.condition(), "{text}");
+            match view.target {
+                BranchTarget::Offset(offset) => assert_imm(offset as i64),
+                BranchTarget::Reg(reg) => assert_reg(reg),
+                BranchTarget::HighHalf(_) | BranchTarget::LowHalf(_) => {}
+            }
+        }
+        Family::Other => {}
+    }
+}
+
+#[test]
+fn test_corpus() {
+    for flags in [DIVIDED, UNIFIED] {
+        for code in 0..=0xffff {
+            for (family, ins) in [
+                decode!(v4t::thumb, code, &flags),
+                decode!(v5te::thumb, code, &flags),
+                decode!(v6k::thumb, code, &flags),
+            ] {
+                check(family, &ins);
+            }
+        }
+        // xorshift32
+        let mut code = 0x2545f491u32;
+        for _ in 0..0x8000 {
+            code ^= code << 13;
+            code ^= code >> 17;
+            code ^= code << 5;
+            for (family, ins) in [
+                decode!(v4t::arm, code, &flags),
+                decode!(v5te::arm, code, &flags),
+                decode!(v6k::arm, code, &flags),
+            ] {
+                check(family, &ins);
+            }
+        }
+    }
+}
+
+fn parse(text: &str) -> ParsedIns {
+    text.parse().unwrap_or_else(|e| panic!("{text}: {e}"))
+}
+
+#[test]
+fn test_data_processing() {
+    assert_eq!(
+        DataProcessingView::try_from(&parse("addnes r0, r1, r2, lsl #2")),
+        Ok(DataProcessingView {
+            op: DataOp::Add,
+            cond: Condition::Ne,
+            s: true,
+            rd: Some(Register::R0),
+            rn: Some(Register::R1),
+            operand2: Operand2::ShiftImm(Register::R2, ShiftImm { imm: 2, op: Shift::Lsl }),
+        })
+    );
+    let cmp = DataProcessingView::try_from(&parse("cmp r3, #0x10")).unwrap();
+    assert_eq!((cmp.op, cmp.s, cmp.rd, cmp.rn), (DataOp::Cmp, true, None, Some(Register::R3)));
+    assert_eq!(cmp.operand2, Operand2::Imm(0x10));
+
+    // Thumb shifts, two operand forms and `neg` map to the ARM operations
+    let thumb = |code: u32| {
+        let ins = unarm::v5te::thumb::Ins::new(code, &UNIFIED).parse(&UNIFIED);
+        DataProcessingView::try_from(&ins).unwrap()
+    };
+    let lsl = thumb(0x4088);
+    assert_eq!((lsl.op, lsl.rd, lsl.rn), (DataOp::Mov, Some(Register::R0), None));
+    assert_eq!(
+        lsl.operand2,
+        Operand2::ShiftReg(
+            Register::R0,
+            ShiftReg {
+                op: Shift::Lsl,
+                reg: Register::R1
+            }
+        )
+    );
+    let adc = thumb(0x4148);
+    assert_eq!(
+        (adc.op, adc.rd, adc.rn, adc.operand2),
+        (
+            DataOp::Adc,
+            Some(Register::R0),
+            Some(Register::R0),
+            Operand2::Reg(Register::R1)
+        )
+    );
+    let neg = unarm::v5te::thumb::Ins::new(0x4248, &DIVIDED).parse(&DIVIDED);
+    let neg = DataProcessingView::try_from(&neg).unwrap();
+    assert_eq!(
+        (neg.op, neg.rn, neg.operand2),
+        (DataOp::Rsb, Some(Register::R1), Operand2::Imm(0))
+    );
+    assert_eq!(thumb(0x4248), DataProcessingView { s: true, ..neg });
+}
+
+#[test]
+fn test_load_store() {
+    assert_eq!(
+        LoadStoreView::try_from(&parse("ldr r2, [r1, #0xfff]")),
+        Ok(LoadStoreView {
+            load: true,
+            cond: Condition::Al,
+            width: Width::Word,
+            sign: false,
+            translated: false,
+            rt: Register::R2,
+            rt2: None,
+            base: Register::R1,
+            offset: AddrOffset::Imm(0xfff),
+            post_indexed: false,
+            writeback: false,
+        })
+    );
+    let ldrsh = LoadStoreView::try_from(&parse("ldrsh r2, [r1], #-0x4")).unwrap();
+    assert_eq!(
+        (ldrsh.width, ldrsh.sign, ldrsh.offset),
+        (Width::Halfword, true, AddrOffset::Imm(-4))
+    );
+    assert!(ldrsh.post_indexed && ldrsh.writeback);
+    let strb = LoadStoreView::try_from(&parse("strneb r0, [r1, -r2, lsl #2]!")).unwrap();
+    assert_eq!((strb.load, strb.cond, strb.width), (false, Condition::Ne, Width::Byte));
+    assert_eq!(
+        strb.offset,
+        AddrOffset::Reg {
+            add: false,
+            reg: Register::R2,
+            shift: Some(ShiftImm { imm: 2, op: Shift::Lsl })
+        }
+    );
+    assert!(strb.writeback && !strb.post_indexed);
+
+    // The second register of `ldrd` is implied in divided syntax
+    for flags in [DIVIDED, UNIFIED] {
+        let ins = unarm::v5te::arm::Ins::new(0xe1c120d4, &flags).parse(&flags);
+        let ldrd = LoadStoreView::try_from(&ins).unwrap();
+        assert_eq!(
+            (ldrd.width, ldrd.rt, ldrd.rt2),
+            (Width::Doubleword, Register::R2, Some(Register::R3))
+        );
+    }
+}
+
+#[test]
+fn test_mem_operand() {
+    let mem = |code: u32| unarm::v5te::arm::Ins::new(code, &UNIFIED).parse(&UNIFIED).mem_operand();
+    let operand = |offset: AddrOffset, pre_indexed: bool, writeback: bool| {
+        Some(MemOperand {
+            base: Register::R1,
+            offset,
+            pre_indexed,
+            writeback,
+        })
+    };
+    let reg = |add: bool, shift: Option<ShiftImm>| AddrOffset::Reg {
+        add,
+        reg: Register::R3,
+        shift,
+    };
+
+    // ldr r2, [r1, #0xfff]
+    assert_eq!(mem(0xe5912fff), operand(AddrOffset::Imm(0xfff), true, false));
+    // ldr r2, [r1, -r3]
+    assert_eq!(mem(0xe7112003), operand(reg(false, None), true, false));
+    // ldr r2, [r1, r3, ror #0x1]
+    assert_eq!(
+        mem(0xe79120e3),
+        operand(reg(true, Some(ShiftImm { imm: 1, op: Shift::Ror })), true, false)
+    );
+    // ldr r2, [r1, #-0xfff]!
+    assert_eq!(mem(0xe5312fff), operand(AddrOffset::Imm(-0xfff), true, true));
+    // ldr r2, [r1, r3]!
+    assert_eq!(mem(0xe7b12003), operand(reg(true, None), true, true));
+    // ldr r2, [r1, -r3, rrx]!
+    assert_eq!(
+        mem(0xe7312063),
+        operand(reg(false, Some(ShiftImm { imm: 0, op: Shift::Rrx })), true, true)
+    );
+    // ldr r2, [r1], #0xfff
+    assert_eq!(mem(0xe4912fff), operand(AddrOffset::Imm(0xfff), false, true));
+    // ldr r2, [r1], -r3
+    assert_eq!(mem(0xe6112003), operand(reg(false, None), false, true));
+    // ldr r2, [r1], r3, lsr #0x20
+    assert_eq!(
+        mem(0xe6912023),
+        operand(
+            reg(
+                true,
+                Some(ShiftImm {
+                    imm: 0x20,
+                    op: Shift::Lsr
+                })
+            ),
+            false,
+            true
+        )
+    );
+
+    // Instructions outside of the load/store view
+    assert_eq!(
+        parse("swp r0, r2, [r1]").mem_operand(),
+        operand(AddrOffset::Imm(0), true, false)
+    );
+    assert_eq!(
+        parse("pld [r1, #-0x10]").mem_operand(),
+        operand(AddrOffset::Imm(-0x10), true, false)
+    );
+    assert_eq!(
+        parse("ldc p1, c2, [r1], {0x4}").mem_operand(),
+        operand(AddrOffset::Imm(0), true, false)
+    );
+    let ldr_pc = unarm::v5te::thumb::Ins::new(0x4f42, &UNIFIED).parse(&UNIFIED);
+    assert_eq!(
+        ldr_pc.mem_operand(),
+        Some(MemOperand {
+            base: Register::Pc,
+            offset: AddrOffset::Imm(0x108),
+            pre_indexed: true,
+            writeback: false,
+        })
+    );
+    assert_eq!(parse("ldmia r1!, {r0, r2}").mem_operand(), None);
+    assert_eq!(parse("add r0, r1, #0x4").mem_operand(), None);
+}
+
+#[test]
+fn test_co_load_store() {
+    let cases = [
+        (0xed932169, "ldc p1, c2, [r3, #0x1a4]", CoAddrMode::Offset, 0x1a4),
+        (0xed132169, "ldc p1, c2, [r3, #-0x1a4]", CoAddrMode::Offset, -0x1a4),
+        (0xedb32169, "ldc p1, c2, [r3, #0x1a4]!", CoAddrMode::PreIndexed, 0x1a4),
+        (0xed332169, "ldc p1, c2, [r3, #-0x1a4]!", CoAddrMode::PreIndexed, -0x1a4),
+        (0xecb32169, "ldc p1, c2, [r3], #0x1a4", CoAddrMode::PostIndexed, 0x1a4),
+        (0xec332169, "ldc p1, c2, [r3], #-0x1a4", CoAddrMode::PostIndexed, -0x1a4),
+        (0xec932169, "ldc p1, c2, [r3], {0x69}", CoAddrMode::Unindexed(0x69), 0),
+        // The U bit of unindexed accesses must be set
+        (0xec132169, "<illegal>", CoAddrMode::Offset, 0),
+    ];
+    for (code, text, mode, offset) in cases {
+        let ins = unarm::v5te::arm::Ins::new(code, &DIVIDED).parse(&DIVIDED);
+        assert_eq!(ins.display(Default::default()).to_string(), text);
+        if text == "<illegal>" {
+            continue;
+        }
+        let view = CoLoadStoreView::try_from(&ins).unwrap();
+        assert_eq!((view.mode, view.offset), (mode, offset), "{text}");
+        assert_eq!(
+            view.writeback,
+            matches!(mode, CoAddrMode::PreIndexed | CoAddrMode::PostIndexed),
+            "{text}"
+        );
+        assert_eq!((view.coproc, view.crd, view.base), (1, CoReg::C2, Register::R3));
+    }
+
+    assert_eq!(
+        CoLoadStoreView::try_from(&parse("stcnel p14, c5, [sp, #-0x8]!")),
+        Ok(CoLoadStoreView {
+            load: false,
+            cond: Condition::Ne,
+            long: true,
+            extended: false,
+            coproc: 14,
+            crd: CoReg::C5,
+            base: Register::Sp,
+            mode: CoAddrMode::PreIndexed,
+            offset: -8,
+            writeback: true,
+        })
+    );
+    let ldc2 = unarm::v5te::arm::Ins::new(0xfcf32169, &DIVIDED).parse(&DIVIDED);
+    let ldc2 = CoLoadStoreView::try_from(&ldc2).unwrap();
+    assert_eq!((ldc2.load, ldc2.long, ldc2.extended), (true, true, true));
+    assert_eq!((ldc2.mode, ldc2.offset), (CoAddrMode::PostIndexed, 0x1a4));
+    assert_eq!(
+        CoLoadStoreView::try_from(&parse("ldr r0, [r1]")),
+        Err(unarm::view::ViewError::OtherFamily("ldr"))
+    );
+}
+
+#[test]
+fn test_branch() {
+    assert_eq!(
+        BranchView::try_from(&parse("blne #0x100")),
+        Ok(BranchView {
+            kind: BranchKind::Bl,
+            cond: Condition::Ne,
+            target: BranchTarget::Offset(0x100)
+        })
+    );
+    let bx = BranchView::try_from(&parse("bx lr")).unwrap();
+    assert_eq!((bx.kind, bx.target), (BranchKind::Bx, BranchTarget::Reg(Register::Lr)));
+    assert!(!bx.kind.is_call());
+}
+
+#[test]
+fn test_errors() {
+    use unarm::view::ViewError;
+    assert_eq!(
+        DataProcessingView::try_from(&parse("bl #0x8")),
+        Err(ViewError::OtherFamily("bl"))
+    );
+    assert_eq!(
+        BranchView::try_from(&parse("ldr r0, [r1]")),
+        Err(ViewError::OtherFamily("ldr"))
+    );
+    assert_eq!(
+        LoadStoreView::try_from(&parse("ldrex r0, [r1]")),
+        Err(ViewError::OtherFamily("ldrex"))
+    );
+    let mut ins = parse("add r0, r1, r2");
+    ins.args[2] = Argument::CoprocNum(15);
+    let error = DataProcessingView::try_from(&ins).unwrap_err();
+    assert_eq!(error, ViewError::UnexpectedArgument(2, Argument::CoprocNum(15)));
+    assert_eq!(error.to_string(), "unexpected CoprocNum argument at index 2");
+    ins.args[1] = Argument::None;
+    assert_eq!(DataProcessingView::try_from(&ins), Err(ViewError::MissingArgument(1)));
+}
@@ -0,0 +1,399 @@
+use std::fmt;
+
+use unarm::{
+    args::{Argument, RegList, Register, Shift},
+    ArgumentVisitor, ArmVersion, Comment, DisplayOptions, Endian, FormatVisitor, ImmKind, Immediate, ParseFlags, ParseMode,
+    Parser, RegisterRole,
+};
+
+/// Records every visit as a string
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl ArgumentVisitor for Recorder {
+    fn visit_register(&mut self, index: usize, reg: Register, role: RegisterRole) {
+        self.0.push(format!("{index}: {reg:?} {role:?}"));
+    }
+
+    fn visit_immediate(&mut self, index: usize, value: i64, kind: ImmKind) {
+        self.0.push(format!("{index}: {value} {kind:?}"));
+    }
+
+    fn visit_shift(&mut self, index: usize, shift: Shift) {
+        self.0.push(format!("{index}: {shift:?}"));
+    }
+
+    fn visit_memory_begin(&mut self, index: usize) {
+        self.0.push(format!("{index}: ["));
+    }
+
+    fn visit_memory_end(&mut self, index: usize, writeback: bool) {
+        self.0.push(format!("{index}: ] {writeback}"));
+    }
+
+    fn visit_other(&mut self, index: usize, arg: &Argument) {
+        self.0.push(format!("{index}: {arg:?}"));
+    }
+}
+
+fn record(code: u32) -> Vec<String> {
+    let flags = ParseFlags::default();
+    let parsed = unarm::v6k::arm::Ins::new(code, &flags).parse(&flags);
+    let mut recorder = Recorder::default();
+    parsed.visit(&mut recorder);
+    recorder.0
+}
+
+#[test]
+fn test_walk() {
+    // ldr r2, [r0], -r3, lsl #0x2
+    assert_eq!(
+        record(0xe6102103),
+        [
+            "0: R2 Operand { writeback: false }",
+            "1: [",
+            "1: R0 Base { writeback: false }",
+            "1: ] false",
+            "2: R3 Offset { add: false }",
+            "3: Lsl",
+            "3: 2 ShiftAmount",
+        ]
+    );
+    // ldr r2, [r0, #-0x8]!
+    assert_eq!(
+        record(0xe5302008),
+        [
+            "0: R2 Operand { writeback: false }",
+            "1: [",
+            "1: R0 Base { writeback: true }",
+            "2: -8 Offset",
+            "1: ] true",
+        ]
+    );
+    // ldmia r1!, {r2, r3}
+    assert_eq!(
+        record(0xe8b1000c),
+        ["0: R1 Operand { writeback: true }", "1: R2 List", "1: R3 List"]
+    );
+    // mrs r0, cpsr
+    assert_eq!(
+        record(0xe10f0000),
+        ["0: R0 Operand { writeback: false }", "1: StatusReg(Cpsr)"]
+    );
+}
+
+#[test]
+fn test_whole_reg_list() {
+    struct Lists(Vec<RegList>, usize);
+
+    impl ArgumentVisitor for Lists {
+        fn visit_register(&mut self, _index: usize, _reg: Register, _role: RegisterRole) {
+            self.1 += 1;
+        }
+
+        fn visit_reg_list(&mut self, _index: usize, list: RegList) {
+            self.0.push(list);
+        }
+    }
+
+    let flags = ParseFlags::default();
+    // push {r4, r5, r6, r7, lr}
+    let parsed = unarm::v6k::arm::Ins::new(0xe92d40f0, &flags).parse(&flags);
+    let mut lists = Lists(vec![], 0);
+    parsed.visit(&mut lists);
+    assert_eq!(
+        lists.0,
+        [RegList {
+            regs: 0x40f0,
+            user_mode: false
+        }]
+    );
+    assert_eq!(lists.1, 0);
+}
+
+/// Collects every immediate, as a downstream visitor would
+#[derive(Default)]
+struct Immediates(Vec<(usize, i64, ImmKind)>);
+
+impl ArgumentVisitor for Immediates {
+    fn visit_immediate(&mut self, index: usize, value: i64, kind: ImmKind) {
+        self.0.push((index, value, kind));
+    }
+}
+
+/// Returns the immediate of an argument by matching every variant, which is what the visitor replaces
+fn expected_immediate(arg: &Argument) -> Option<(i64, ImmKind)> {
+    match *arg {
+        Argument::UImm(x) => Some((x.into(), ImmKind::Unsigned)),
+        Argument::SatImm(x) => Some((x.into(), ImmKind::Saturation)),
+        Argument::SImm(x) => Some((x.into(), ImmKind::Signed)),
+        Argument::OffsetImm(x) => Some((x.value.into(), ImmKind::Offset)),
+        Argument::ShiftImm(x) => Some((x.imm.into(), ImmKind::ShiftAmount)),
+        Argument::BranchDest(x) => Some((x.into(), ImmKind::BranchOffset)),
+        Argument::CoOption(x) => Some((x.into(), ImmKind::CoOption)),
+        Argument::CoOpcode(x) => Some((x.into(), ImmKind::CoOpcode)),
+        Argument::CoprocNum(x) => Some((x.into(), ImmKind::CoprocNum)),
+        Argument::CpsrMode(x) => Some((x.mode.into(), ImmKind::CpsrMode)),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_immediates_corpus() {
+    // xorshift32
+    let mut state = 0x68e31da4u32;
+    let bytes: Vec<u8> = (0..0x10000)
+        .flat_map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()
+        })
+        .collect();
+
+    let mut kinds = vec![];
+    for version in [ArmVersion::V4T, ArmVersion::V5Te, ArmVersion::V6K] {
+        for mode in [ParseMode::Arm, ParseMode::Thumb, ParseMode::Data] {
+            let parser = Parser::new(version, mode, 0, Endian::Little, ParseFlags::default(), &bytes);
+            for (address, _, parsed) in parser {
+                let mut immediates = Immediates::default();
+                parsed.visit(&mut immediates);
+                let expected: Vec<_> = parsed
+                    .args_iter()
+                    .enumerate()
+                    .filter_map(|(index, arg)| expected_immediate(arg).map(|(value, kind)| (index, value, kind)))
+                    .collect();
+                assert_eq!(immediates.0, expected, "{version:?} {mode:?} {address:#x}");
+
+                for &(_, value, kind) in &immediates.0 {
+                    match kind {
+                        ImmKind::ShiftAmount | ImmKind::Saturation => assert!((0..=32).contains(&value), "{address:#x}"),
+                        ImmKind::CoprocNum => assert!((0..16).contains(&value), "{address:#x}"),
+                        _ => {}
+                    }
+                    if !kinds.contains(&kind) {
+                        kinds.push(kind);
+                    }
+                }
+            }
+        }
+    }
+    assert!(kinds.len() >= 8, "{kinds:?}");
+}
+
+/// Records every formatting event as a string
+#[derive(Default)]
+struct FormatRecorder(Vec<String>);
+
+impl FormatVisitor for FormatRecorder {
+    fn mnemonic(&mut self, mnemonic: &str) -> fmt::Result {
+        self.0.push(format!("mnemonic {mnemonic}"));
+        Ok(())
+    }
+
+    fn separator(&mut self, index: Option<usize>, text: &str) -> fmt::Result {
+        self.0.push(format!("separator {index:?} {text:?}"));
+        Ok(())
+    }
+
+    fn padding(&mut self, width: usize) -> fmt::Result {
+        self.0.push(format!("padding {width}"));
+        Ok(())
+    }
+
+    fn register(&mut self, index: usize, reg: Register, role: RegisterRole) -> fmt::Result {
+        self.0.push(format!("{index}: {reg:?} {role:?}"));
+        Ok(())
+    }
+
+    fn register_range(&mut self, index: usize, first: Register, last: Register) -> fmt::Result {
+        self.0.push(format!("{index}: {first:?}-{last:?}"));
+        Ok(())
+    }
+
+    fn immediate(&mut self, index: usize, imm: Immediate, kind: ImmKind) -> fmt::Result {
+        self.0.push(format!("{index}: {imm} {kind:?}"));
+        Ok(())
+    }
+
+    fn shift(&mut self, index: usize, shift: Shift) -> fmt::Result {
+        self.0.push(format!("{index}: {shift:?}"));
+        Ok(())
+    }
+
+    fn open_deref(&mut self, index: usize) -> fmt::Result {
+        self.0.push(format!("{index}: ["));
+        Ok(())
+    }
+
+    fn close_deref(&mut self, index: usize, writeback: bool) -> fmt::Result {
+        self.0.push(format!("{index}: ] {writeback}"));
+        Ok(())
+    }
+
+    fn open_reg_list(&mut self, index: usize) -> fmt::Result {
+        self.0.push(format!("{index}: {{"));
+        Ok(())
+    }
+
+    fn close_reg_list(&mut self, index: usize, user_mode: bool) -> fmt::Result {
+        self.0.push(format!("{index}: }} {user_mode}"));
+        Ok(())
+    }
+
+    fn other(&mut self, index: usize, arg: &Argument) -> fmt::Result {
+        self.0.push(format!("{index}: {arg:?}"));
+        Ok(())
+    }
+
+    fn comment(&mut self, comment: Comment) -> fmt::Result {
+        self.0.push(format!("comment {comment:?}"));
+        Ok(())
+    }
+}
+
+fn record_format(code: u32, options: DisplayOptions) -> Vec<String> {
+    let flags = ParseFlags::default();
+    let parsed = unarm::v6k::arm::Ins::new(code, &flags).parse(&flags);
+    let mut recorder = FormatRecorder::default();
+    parsed.visit_format(&mut recorder, options).unwrap();
+    recorder.0
+}
+
+#[test]
+fn test_format_events() {
+    // ldr r2, [r0, #-0x8]!
+    assert_eq!(
+        record_format(0xe5302008, Default::default()),
+        [
+            "mnemonic ldr",
+            "separator None \" \"",
+            "0: R2 Operand { writeback: false }",
+            "separator None \", \"",
+            "1: [",
+            "1: R0 Base { writeback: true }",
+            "separator None \", \"",
+            "2: #-0x8 Offset",
+            "1: ] true",
+        ]
+    );
+    // ldmia r1!, {r2-r4}
+    let options = DisplayOptions {
+        reg_list_ranges: true,
+        mnemonic_width: Some(8),
+        ..Default::default()
+    };
+    assert_eq!(
+        record_format(0xe8b1001c, options),
+        [
+            "mnemonic ldm",
+            "padding 4",
+            "separator None \" \"",
+            "0: R1 Operand { writeback: true }",
+            "separator None \", \"",
+            "1: {",
+            "1: R2-R4",
+            "1: } false",
+        ]
+    );
+    // add r0, r1, r2, lsl #0x3
+    assert_eq!(
+        record_format(0xe0810182, Default::default()),
+        [
+            "mnemonic add",
+            "separator None \" \"",
+            "0: R0 Operand { writeback: false }",
+            "separator None \", \"",
+            "1: R1 Operand { writeback: false }",
+            "separator None \", \"",
+            "2: R2 Operand { writeback: false }",
+            "separator None \", \"",
+            "3: Lsl",
+            "separator Some(3) \" \"",
+            "3: #0x3 ShiftAmount",
+        ]
+    );
+}
+
+#[test]
+fn test_format_matches_display() {
+    /// Writes the text of the events which don't depend on display options, as a downstream formatter would
+    #[derive(Default)]
+    struct Text(String);
+
+    impl FormatVisitor for Text {
+        fn mnemonic(&mut self, mnemonic: &str) -> fmt::Result {
+            self.0 += mnemonic;
+            Ok(())
+        }
+
+        fn separator(&mut self, _index: Option<usize>, text: &str) -> fmt::Result {
+            self.0 += text;
+            Ok(())
+        }
+
+        fn register(&mut self, _index: usize, reg: Register, role: RegisterRole) -> fmt::Result {
+            if role == (RegisterRole::Offset { add: false }) {
+                self.0 += "-";
+            }
+            self.0 += &reg.display(Default::default()).to_string();
+            if role == (RegisterRole::Operand { writeback: true }) {
+                self.0 += "!";
+            }
+            Ok(())
+        }
+
+        fn immediate(&mut self, _index: usize, imm: Immediate, _kind: ImmKind) -> fmt::Result {
+            self.0 += &imm.to_string();
+            Ok(())
+        }
+
+        fn shift(&mut self, _index: usize, shift: Shift) -> fmt::Result {
+            self.0 += &shift.to_string();
+            Ok(())
+        }
+
+        fn open_deref(&mut self, _index: usize) -> fmt::Result {
+            self.0 += "[";
+            Ok(())
+        }
+
+        fn close_deref(&mut self, _index: usize, writeback: bool) -> fmt::Result {
+            self.0 += if writeback { "]!" } else { "]" };
+            Ok(())
+        }
+
+        fn open_reg_list(&mut self, _index: usize) -> fmt::Result {
+            self.0 += "{";
+            Ok(())
+        }
+
+        fn close_reg_list(&mut self, _index: usize, user_mode: bool) -> fmt::Result {
+            self.0 += if user_mode { "}^" } else { "}" };
+            Ok(())
+        }
+
+        fn other(&mut self, _index: usize, arg: &Argument) -> fmt::Result {
+            self.0 += &arg.display(Default::default()).to_string();
+            Ok(())
+        }
+    }
+
+    let bytes: Vec<u8> = (0..0x4000u32)
+        .flat_map(|i| i.wrapping_mul(0x9e3779b9).to_le_bytes())
+        .collect();
+    for version in [ArmVersion::V4T, ArmVersion::V5Te, ArmVersion::V6K] {
+        for mode in [ParseMode::Arm, ParseMode::Thumb] {
+            let parser = Parser::new(version, mode, 0, Endian::Little, ParseFlags::default(), &bytes);
+            for (address, _, parsed) in parser {
+                let mut text = Text::default();
+                parsed.visit_format(&mut text, Default::default()).unwrap();
+                assert_eq!(
+                    text.0,
+                    parsed.display(Default::default()).to_string(),
+                    "{version:?} {mode:?} {address:#x}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use unarm::{window, window_with_modes, ArmVersion, DataReason, Endian, Line, Op, ParseFlags, ParseMode, Parser, Provenance};
+
+const BASE: u32 = 0x02000000;
+
+fn parser(mode: ParseMode) -> Parser<'static> {
+    Parser::new(ArmVersion::V5Te, mode, 0, Endian::Little, ParseFlags::default(), &[])
+}
+
+fn words(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+fn halfwords(halfwords: &[u16]) -> Vec<u8> {
+    halfwords.iter().flat_map(|halfword| halfword.to_le_bytes()).collect()
+}
+
+fn lines(window: &[Line]) -> Vec<(u32, String)> {
+    window
+        .iter()
+        .map(|line| (line.address - BASE, line.ins.display(Default::default()).to_string()))
+        .collect()
+}
+
+fn addresses(window: &[Line]) -> Vec<u32> {
+    window.iter().map(|line| line.address - BASE).collect()
+}
+
+#[test]
+fn test_arm() {
+    // add r0, r0, #0x0 to add r0, r0, #0xf
+    let code = words(&(0..16).map(|i| 0xe2800000 | i).collect::<Vec<_>>());
+    let parser = parser(ParseMode::Arm);
+
+    let lines = lines(&window(&code, BASE, BASE + 0x10, 2, 2, &parser));
+    assert_eq!(
+        lines,
+        [
+            (0x8, "add r0, r0, #0x2".to_string()),
+            (0xc, "add r0, r0, #0x3".to_string()),
+            (0x10, "add r0, r0, #0x4".to_string()),
+            (0x14, "add r0, r0, #0x5".to_string()),
+            (0x18, "add r0, r0, #0x6".to_string()),
+        ]
+    );
+    // Unaligned addresses are centered on their instruction
+    assert_eq!(
+        addresses(&window(&code, BASE, BASE + 0x12, 2, 2, &parser)),
+        [0x8, 0xc, 0x10, 0x14, 0x18]
+    );
+    // Far from the start, so decoding doesn't start at the base
+    assert_eq!(addresses(&window(&code, BASE, BASE + 0x3c, 1, 0, &parser)), [0x38, 0x3c]);
+
+    // Clamped to the edges of the code
+    assert_eq!(addresses(&window(&code, BASE, BASE + 0x4, 3, 1, &parser)), [0x0, 0x4, 0x8]);
+    assert_eq!(
+        addresses(&window(&code, BASE, BASE + 0x38, 1, 3, &parser)),
+        [0x34, 0x38, 0x3c]
+    );
+    assert!(window(&code, BASE, BASE + 0x40, 1, 1, &parser).is_empty());
+    assert!(window(&code, BASE, BASE - 4, 1, 1, &parser).is_empty());
+}
+
+#[test]
+fn test_thumb_mid_pair() {
+    // movs r0, #0x1, then BL pairs which start 2 bytes into a word
+    let mut code = vec![0x2001];
+    for _ in 0..64 {
+        code.extend([0xf000, 0xf800]);
+    }
+    let code = halfwords(&code);
+    let parser = parser(ParseMode::Thumb);
+
+    // The first and second half of a pair give the same window
+    let pair = window(&code, BASE, BASE + 0xc2, 3, 3, &parser);
+    assert_eq!(addresses(&pair), [0xb6, 0xba, 0xbe, 0xc2, 0xc6, 0xca, 0xce]);
+    assert!(pair.iter().all(|line| line.size == 4 && line.ins.mnemonic == "bl"));
+    assert_eq!(addresses(&window(&code, BASE, BASE + 0xc4, 3, 3, &parser)), addresses(&pair));
+
+    // The movs is found from the start of the code
+    let start = window(&code, BASE, BASE + 0x4, 3, 1, &parser);
+    assert_eq!(addresses(&start), [0x0, 0x2, 0x6]);
+    assert_eq!(start[0].ins.mnemonic, "movs");
+    assert_eq!(start[0].size, 2);
+}
+
+#[test]
+fn test_mode_boundary() {
+    // add r0, r0, #0x0 to add r0, r0, #0x3
+    let mut code = words(&[0xe2800000, 0xe2800001, 0xe2800002, 0xe2800003]);
+    // movs r0, #0x0 to movs r0, #0x3, then a BL pair and bx lr
+    code.extend(halfwords(&[0x2000, 0x2001, 0x2002, 0x2003, 0xf000, 0xf808, 0x4770]));
+    let modes = [(BASE + 0x10, ParseMode::Thumb)];
+    let parser = parser(ParseMode::Arm);
+
+    let window = window_with_modes(&code, BASE, BASE + 0x12, 3, 4, &parser, &modes);
+    assert_eq!(
+        lines(&window),
+        [
+            (0x8, "add r0, r0, #0x2".to_string()),
+            (0xc, "add r0, r0, #0x3".to_string()),
+            (0x10, "movs r0, #0x0".to_string()),
+            (0x12, "movs r0, #0x1".to_string()),
+            (0x14, "movs r0, #0x2".to_string()),
+            (0x16, "movs r0, #0x3".to_string()),
+            (0x18, "bl #0x14".to_string()),
+            (0x1c, "bx lr".to_string()),
+        ]
+    );
+    assert!(matches!(window[1].op, Op::ArmV5Te(_)));
+    assert!(matches!(window[2].op, Op::ThumbV5Te(_)));
+
+    // Centered on the last ARM instruction, the Thumb code follows
+    let window = window_with_modes(&code, BASE, BASE + 0xc, 1, 2, &parser, &modes);
+    assert_eq!(addresses(&window), [0x8, 0xc, 0x10, 0x12]);
+}
+
+/// Checks that the lines of a window cover its bytes without gaps or overlaps, and that the provenance of each line
+/// accounts for its size
+fn assert_tiled(window: &[Line], code: &[u8]) {
+    for pair in window.windows(2) {
+        assert_eq!(pair[0].address + pair[0].size, pair[1].address, "{pair:?}");
+    }
+    for line in window {
+        let offset = (line.address - BASE) as usize;
+        let bytes = &code[offset..offset + line.size as usize];
+        match line.provenance {
+            Provenance::Plain => assert!(line.op != Op::Data, "{line:?}"),
+            Provenance::CombinedThumbBl { first, second } => {
+                assert_eq!(line.size, 4);
+                assert_eq!(bytes, halfwords(&[first, second]));
+            }
+            Provenance::DataFallback { .. } => {
+                assert_eq!(line.op, Op::Data);
+                let size = match line.ins.mnemonic {
+                    ".word" => 4,
+                    ".hword" => 2,
+                    _ => 1,
+                };
+                assert_eq!(line.size, size, "{line:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_provenance() {
+    // add r0, r0, #0x0 and add r0, r0, #0x1, then two bytes which are too few for an ARM instruction
+    let mut code = words(&[0xe2800000, 0xe2800001]);
+    code.extend([0xaa, 0xbb]);
+    let arm = window(&code, BASE, BASE, 0, 4, &parser(ParseMode::Arm));
+    assert_tiled(&arm, &code);
+    assert_eq!(
+        arm.iter().map(|line| line.provenance).collect::<Vec<_>>(),
+        [
+            Provenance::Plain,
+            Provenance::Plain,
+            Provenance::DataFallback {
+                reason: DataReason::Truncated
+            },
+        ]
+    );
+
+    // movs r0, #0x1, a BL pair, then the first half of a pair without its second half
+    let code = halfwords(&[0x2001, 0xf000, 0xf808, 0xf000]);
+    let thumb = window(&code, BASE, BASE, 0, 4, &parser(ParseMode::Thumb));
+    assert_tiled(&thumb, &code);
+    assert_eq!(
+        thumb.iter().map(|line| line.provenance).collect::<Vec<_>>(),
+        [
+            Provenance::Plain,
+            Provenance::CombinedThumbBl {
+                first: 0xf000,
+                second: 0xf808
+            },
+            Provenance::DataFallback {
+                reason: DataReason::Truncated
+            },
+        ]
+    );
+
+    // A literal pool after ARM code
+    let code = words(&[0xe2800000, 0x12345678, 0x9abcdef0]);
+    let modes = [(BASE + 0x4, ParseMode::Data)];
+    let mixed = window_with_modes(&code, BASE, BASE, 0, 4, &parser(ParseMode::Arm), &modes);
+    assert_tiled(&mixed, &code);
+    assert_eq!(
+        mixed.iter().map(|line| line.provenance).collect::<Vec<_>>(),
+        [
+            Provenance::Plain,
+            Provenance::DataFallback {
+                reason: DataReason::DataMode
+            },
+            Provenance::DataFallback {
+                reason: DataReason::DataMode
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_windows_are_tiled() {
+    // Pseudorandom code has BL pairs and data at the mode changes
+    let mut state = 0x9e3779b9u32;
+    let code: Vec<u8> = (0..0x100)
+        .flat_map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()
+        })
+        .take(0x3fe)
+        .collect();
+    let modes = [
+        (BASE + 0x102, ParseMode::Thumb),
+        (BASE + 0x201, ParseMode::Data),
+        (BASE + 0x207, ParseMode::Arm),
+    ];
+    for mode in [ParseMode::Arm, ParseMode::Thumb] {
+        for around in (BASE..BASE + 0x3fe).step_by(0x3d) {
+            assert_tiled(&window_with_modes(&code, BASE, around, 8, 8, &parser(mode), &modes), &code);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Sequences of codes for the fuzzer threads to parse
+
+use std::ops::RangeInclusive;
+
+/// Every code of a range in order, or as many pseudorandom codes of the range with `--seed`
+pub enum Codes {
+    Ordered(RangeInclusive<u32>),
+    Random {
+        rng: SplitMix64,
+        start: u32,
+        len: u64,
+        remaining: u64,
+    },
+}
+
+impl Codes {
+    pub fn new(range: RangeInclusive<u32>, rng: Option<SplitMix64>) -> Self {
+        match rng {
+            None => Self::Ordered(range),
+            Some(rng) => {
+                let len = *range.end() as u64 - *range.start() as u64 + 1;
+                Self::Random {
+                    rng,
+                    start: *range.start(),
+                    len,
+                    remaining: len,
+                }
+            }
+        }
+    }
+
+    /// Returns the generator of a random sequence, to continue it in the next iteration
+    pub fn into_rng(self) -> Option<SplitMix64> {
+        match self {
+            Self::Ordered(_) => None,
+            Self::Random { rng, .. } => Some(rng),
+        }
+    }
+}
+
+impl Iterator for Codes {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Ordered(range) => range.next(),
+            Self::Random {
+                rng,
+                start,
+                len,
+                remaining,
+            } => {
+                if *remaining == 0 {
+                    return None;
+                }
+                *remaining -= 1;
+                Some(*start + (rng.next() % *len) as u32)
+            }
+        }
+    }
+}
+
+/// Pseudorandom number generator which accepts any seed
+#[derive(Clone)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Returns the generator of thread `index`, so that each thread draws a different but reproducible sequence
+    pub fn for_thread(seed: u64, index: usize) -> Self {
+        let mut rng = Self(seed ^ (index as u64).wrapping_mul(0x9e3779b97f4a7c15));
+        rng.next();
+        rng
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,296 @@
+//! Differential comparison with Capstone, enabled with `--compare-capstone`. Requires the `capstone` feature.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+};
+
+#[cfg(feature = "capstone")]
+use capstone::{arch::arm::ArchMode, prelude::*};
+
+/// Number of example mismatches to keep per opcode
+const MAX_EXAMPLES: usize = 3;
+
+/// Known divergences from Capstone which are not reported. See `capstone-allowlist.txt` for the format.
+#[derive(Default)]
+pub struct Allowlist {
+    /// Entries for ARM and Thumb
+    modes: [AllowlistEntries; 2],
+}
+
+#[derive(Default)]
+struct AllowlistEntries {
+    /// Opcode variant names whose mismatches are ignored
+    opcodes: HashSet<String>,
+    /// Our mnemonics mapped to Capstone's
+    mnemonics: Vec<(String, String)>,
+    /// Whether to ignore codes which we decode as `<illegal>`
+    illegal: bool,
+    /// Whether to ignore codes which Capstone decodes as `<illegal>`
+    unsupported: bool,
+}
+
+impl Allowlist {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut allowlist = Self::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let mut words: Vec<_> = line.split_whitespace().collect();
+            let modes = match words.first() {
+                Some(&"arm") => vec![false],
+                Some(&"thumb") => vec![true],
+                _ => vec![false, true],
+            };
+            if modes.len() == 1 {
+                words.remove(0);
+            }
+            for thumb in modes {
+                let entries = &mut allowlist.modes[thumb as usize];
+                match words.as_slice() {
+                    [] => {}
+                    ["opcode", name] => {
+                        entries.opcodes.insert(name.to_string());
+                    }
+                    ["mnemonic", ours, theirs] => entries.mnemonics.push((ours.to_string(), theirs.to_string())),
+                    ["illegal"] => entries.illegal = true,
+                    ["unsupported"] => entries.unsupported = true,
+                    _ => return Err(format!("Invalid allowlist entry on line {}: '{}'", index + 1, line.trim())),
+                }
+            }
+        }
+        Ok(allowlist)
+    }
+
+    /// Returns the entries for ARM or Thumb
+    fn entries(&self, thumb: bool) -> &AllowlistEntries {
+        &self.modes[thumb as usize]
+    }
+
+    /// The allowlist in `fuzz/capstone-allowlist.txt`
+    pub fn builtin() -> Self {
+        Self::parse(include_str!("../capstone-allowlist.txt")).unwrap()
+    }
+}
+
+/// Mismatches of one opcode
+#[derive(Default)]
+pub struct OpcodeMismatches {
+    pub count: usize,
+    /// Code, our output and Capstone's output of the first few mismatches
+    pub examples: Vec<(u32, String, String)>,
+}
+
+/// Mismatches per opcode variant name
+#[derive(Default)]
+pub struct Mismatches(BTreeMap<&'static str, OpcodeMismatches>);
+
+impl Mismatches {
+    fn insert(&mut self, opcode: &'static str, code: u32, ours: String, theirs: String) {
+        let mismatches = self.0.entry(opcode).or_default();
+        mismatches.count += 1;
+        if mismatches.examples.len() < MAX_EXAMPLES {
+            mismatches.examples.push((code, ours, theirs));
+        }
+    }
+
+    pub fn merge(&mut self, other: Self) {
+        for (opcode, other) in other.0 {
+            let mismatches = self.0.entry(opcode).or_default();
+            mismatches.count += other.count;
+            let room = MAX_EXAMPLES - mismatches.examples.len();
+            mismatches.examples.extend(other.examples.into_iter().take(room));
+        }
+    }
+
+    /// Returns the opcode, code, our output and Capstone's output of every example
+    pub fn examples(&self) -> impl Iterator<Item = (&'static str, u32, &str, &str)> {
+        self.0.iter().flat_map(|(opcode, mismatches)| {
+            mismatches
+                .examples
+                .iter()
+                .map(|(code, ours, theirs)| (*opcode, *code, ours.as_str(), theirs.as_str()))
+        })
+    }
+
+    /// Returns the number of mismatches of each opcode which has any
+    pub fn counts(&self) -> BTreeMap<&'static str, usize> {
+        self.0
+            .iter()
+            .map(|(opcode, mismatches)| (*opcode, mismatches.count))
+            .collect()
+    }
+
+    /// Prints the examples and the number of mismatches of each opcode, from most to least mismatches
+    pub fn print_summary(&self) {
+        let mut opcodes: Vec<_> = self.0.iter().collect();
+        opcodes.sort_by_key(|(opcode, mismatches)| (usize::MAX - mismatches.count, **opcode));
+        for (opcode, mismatches) in opcodes.iter() {
+            for (code, ours, theirs) in mismatches.examples.iter() {
+                println!("{opcode}: {code:#010x}: '{ours}' != '{theirs}'");
+            }
+        }
+        println!("Mismatches per opcode:");
+        for (opcode, mismatches) in opcodes.iter() {
+            println!("{:>12} {opcode}", mismatches.count);
+        }
+        println!(
+            "{:>12} total",
+            opcodes.iter().map(|(_, mismatches)| mismatches.count).sum::<usize>()
+        );
+    }
+}
+
+/// Compares our output with Capstone's for one thread, as Capstone handles can't be shared
+pub struct Comparer {
+    #[cfg(feature = "capstone")]
+    capstone: Capstone,
+    thumb: bool,
+    allowlist: Arc<Allowlist>,
+    mismatches: Mismatches,
+}
+
+impl Comparer {
+    #[cfg(feature = "capstone")]
+    pub fn new(thumb: bool, allowlist: Arc<Allowlist>) -> Self {
+        let mode = if thumb { ArchMode::Thumb } else { ArchMode::Arm };
+        let capstone = Capstone::new()
+            .arm()
+            .mode(mode)
+            .build()
+            .expect("Failed to create Capstone handle");
+        Self {
+            capstone,
+            thumb,
+            allowlist,
+            mismatches: Mismatches::default(),
+        }
+    }
+
+    #[cfg(not(feature = "capstone"))]
+    pub fn new(_thumb: bool, _allowlist: Arc<Allowlist>) -> Self {
+        panic!("unarm-fuzz was built without the capstone feature");
+    }
+
+    #[cfg(feature = "capstone")]
+    fn capstone_text(&self, code: u32) -> String {
+        let bytes = code.to_le_bytes();
+        let bytes = if self.thumb { &bytes[..2] } else { &bytes[..] };
+        match self.capstone.disasm_count(bytes, 0, 1) {
+            Ok(insns) => match insns.iter().next() {
+                Some(insn) => format!(
+                    "{} {}",
+                    insn.mnemonic().unwrap_or_default(),
+                    insn.op_str().unwrap_or_default()
+                ),
+                None => "<illegal>".to_string(),
+            },
+            Err(_) => "<illegal>".to_string(),
+        }
+    }
+
+    #[cfg(not(feature = "capstone"))]
+    fn capstone_text(&self, _code: u32) -> String {
+        unreachable!()
+    }
+
+    /// Compares our output `ours` for `code`, which was decoded as `opcode`
+    pub fn compare(&mut self, code: u32, opcode: &'static str, ours: &str) {
+        let allowlist = self.allowlist.entries(self.thumb);
+        if allowlist.opcodes.contains(opcode) || (allowlist.illegal && ours == "<illegal>") {
+            return;
+        }
+        let theirs = self.capstone_text(code);
+        if allowlist.unsupported && theirs == "<illegal>" {
+            return;
+        }
+        let (mnemonic, operands) = normalize(ours);
+        let mnemonic = match allowlist.mnemonics.iter().find(|(ours, _)| *ours == mnemonic) {
+            Some((_, theirs)) => theirs.clone(),
+            None => mnemonic,
+        };
+        if (mnemonic, operands) != normalize(&theirs) {
+            self.mismatches.insert(opcode, code, ours.to_string(), theirs);
+        }
+    }
+
+    pub fn into_mismatches(self) -> Mismatches {
+        self.mismatches
+    }
+}
+
+/// Splits an instruction into its mnemonic and operands, ignoring whitespace, case, register aliases and the radix of
+/// immediates
+fn normalize(text: &str) -> (String, Vec<String>) {
+    let text = text.trim().to_lowercase();
+    let (mnemonic, operands) = text.split_once(char::is_whitespace).unwrap_or((&text, ""));
+    let mut tokens = vec![];
+    let mut token = String::new();
+    for c in operands.chars() {
+        if c.is_whitespace() || ",[]{}!^".contains(c) {
+            tokens.push(normalize_token(&token));
+            if !c.is_whitespace() {
+                tokens.push(c.to_string());
+            }
+            token.clear();
+        } else {
+            token.push(c);
+        }
+    }
+    tokens.push(normalize_token(&token));
+    tokens.retain(|token| !token.is_empty());
+    // Zero offsets, e.g. `[r0, #0x0]` is `[r0]`
+    while let Some(index) = tokens.windows(3).position(|window| window == [",", "#0", "]"]) {
+        tokens.drain(index..index + 2);
+    }
+    // Immediates with an explicit rotation, e.g. `#232, #2` is `#58`
+    if let [.., value, comma, rotation] = tokens.as_slice() {
+        let immediate = |token: &str| token.strip_prefix('#')?.parse::<u32>().ok();
+        if let (",", Some(value), Some(rotation)) = (comma.as_str(), immediate(value), immediate(rotation)) {
+            if value <= 0xff && rotation < 32 && rotation % 2 == 0 {
+                tokens.truncate(tokens.len() - 3);
+                tokens.push(format!("#{}", value.rotate_right(rotation)));
+            }
+        }
+    }
+    // Two operand forms, e.g. `adds r0, r0, r1` is `adds r0, r1`
+    if tokens.len() >= 5 && tokens[1] == "," && tokens[3] == "," && tokens[0] == tokens[2] {
+        tokens.drain(0..2);
+    }
+    (mnemonic.to_string(), tokens)
+}
+
+/// Normalizes register aliases and numbers, which may be immediates (`#0x10`), bare numbers (`{0x9}`) or negative
+/// (`#-4`, `-r2`)
+fn normalize_token(token: &str) -> String {
+    let (prefix, rest) = match token.strip_prefix('#') {
+        Some(rest) => ("#", rest),
+        None => ("", token),
+    };
+    let (negative, rest) = match rest.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, rest),
+    };
+    let sign = if negative { "-" } else { "" };
+    let reg = match rest {
+        "sb" => Some("r9"),
+        "sl" => Some("r10"),
+        "fp" => Some("r11"),
+        "ip" => Some("r12"),
+        // Capstone's name for `pc` as the destination of `mrc`
+        "apsr_nzcv" => Some("pc"),
+        _ => None,
+    };
+    if let Some(reg) = reg {
+        return format!("{prefix}{sign}{reg}");
+    }
+    let value = match rest.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16),
+        None => rest.parse(),
+    };
+    // Capstone prints negative branch offsets as addresses, which wrap around at address 0
+    match value {
+        Ok(value) if negative => format!("{prefix}{}", (-value) as u32),
+        Ok(value) => format!("{prefix}{}", value as u32),
+        Err(_) => token.to_string(),
+    }
+}
@@ -0,0 +1,169 @@
+//! Failure files written to `failures/` when a code panics or mismatches, and read back with `--replay`
+
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fs,
+    panic::{catch_unwind, UnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use unarm::{parse::ArmVersion, ParseFlags};
+
+use crate::compare::Mismatches;
+
+/// Directory of the failure files, relative to the working directory
+pub const FAILURES_DIR: &str = "failures";
+
+/// Version, mode and flags which a code was parsed with
+#[derive(Clone, Copy)]
+pub struct Target {
+    pub version: ArmVersion,
+    pub thumb: bool,
+    pub flags: ParseFlags,
+}
+
+impl Target {
+    pub fn version_name(&self) -> &'static str {
+        match self.version {
+            ArmVersion::V4T => "v4t",
+            ArmVersion::V5Te => "v5te",
+            ArmVersion::V6K => "v6k",
+            ArmVersion::V4 => "v4",
+            ArmVersion::V5T => "v5t",
+        }
+    }
+
+    pub fn mode_name(&self) -> &'static str {
+        if self.thumb {
+            "thumb"
+        } else {
+            "arm"
+        }
+    }
+
+    /// Writes `code` and the reason it failed to a new file in [`FAILURES_DIR`]
+    pub fn write_failure(&self, code: u32, reason: &str) -> std::io::Result<PathBuf> {
+        fs::create_dir_all(FAILURES_DIR)?;
+        let path = Path::new(FAILURES_DIR).join(format!("{}-{}-{code:08x}.txt", self.version_name(), self.mode_name()));
+        let mut text = String::new();
+        for line in reason.lines() {
+            text += &format!("# {line}\n");
+        }
+        text += &format!("version {}\n", self.version_name());
+        text += &format!("mode {}\n", self.mode_name());
+        text += "flags";
+        for (name, set) in [
+            ("ual", self.flags.ual),
+            ("unpredictable_as_illegal", self.flags.unpredictable_as_illegal),
+            ("illegal_operand_as_illegal", self.flags.illegal_operand_as_illegal),
+            ("vfp", self.flags.vfp),
+            ("aliases", self.flags.aliases),
+            ("unallocated_hint_as_illegal", self.flags.unallocated_hint_as_illegal),
+        ] {
+            if set {
+                text += " ";
+                text += name;
+            }
+        }
+        text += &format!("\ncode {code:#010x}\n");
+        fs::write(&path, text)?;
+        Ok(path)
+    }
+
+    /// Reads the target and every code of a failure file
+    pub fn read_failures(text: &str) -> Result<(Self, Vec<u32>), String> {
+        let mut version = None;
+        let mut thumb = None;
+        let mut flags = ParseFlags {
+            ual: false,
+            ..Default::default()
+        };
+        let mut codes = vec![];
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let words: Vec<_> = line.split_whitespace().collect();
+            let error = || format!("Invalid failure file line {}: '{}'", index + 1, line.trim());
+            match words.as_slice() {
+                [] => {}
+                ["version", "v4t"] => version = Some(ArmVersion::V4T),
+                ["version", "v5te"] => version = Some(ArmVersion::V5Te),
+                ["version", "v6k"] => version = Some(ArmVersion::V6K),
+                ["mode", "arm"] => thumb = Some(false),
+                ["mode", "thumb"] => thumb = Some(true),
+                ["flags", names @ ..] => {
+                    for name in names {
+                        match *name {
+                            "ual" => flags.ual = true,
+                            "unpredictable_as_illegal" => flags.unpredictable_as_illegal = true,
+                            "illegal_operand_as_illegal" => flags.illegal_operand_as_illegal = true,
+                            "vfp" => flags.vfp = true,
+                            "aliases" => flags.aliases = true,
+                            "unallocated_hint_as_illegal" => flags.unallocated_hint_as_illegal = true,
+                            _ => return Err(error()),
+                        }
+                    }
+                }
+                ["code", code] => {
+                    let code = code.strip_prefix("0x").ok_or_else(error)?;
+                    codes.push(u32::from_str_radix(code, 16).map_err(|_| error())?);
+                }
+                _ => return Err(error()),
+            }
+        }
+        let version = version.ok_or("Failure file has no version")?;
+        let thumb = thumb.ok_or("Failure file has no mode")?;
+        Ok((Self { version, thumb, flags }, codes))
+    }
+}
+
+/// Results of one fuzzer thread
+#[derive(Default)]
+pub struct Report {
+    pub mismatches: Mismatches,
+    /// Codes which panicked and the panic messages
+    pub panics: Vec<(u32, String)>,
+    /// Number of parsed codes per opcode variant name, including `Illegal`
+    pub opcodes: BTreeMap<&'static str, u64>,
+    /// Throughput of each thread
+    pub threads: Vec<ThreadStats>,
+}
+
+impl Report {
+    pub fn merge(&mut self, other: Self) {
+        self.mismatches.merge(other.mismatches);
+        self.panics.extend(other.panics);
+        for (opcode, count) in other.opcodes {
+            *self.opcodes.entry(opcode).or_default() += count;
+        }
+        self.threads.extend(other.threads);
+    }
+}
+
+/// Number of codes one thread parsed and how long it took
+#[derive(Clone, Copy)]
+pub struct ThreadStats {
+    pub codes: u64,
+    pub seconds: f64,
+}
+
+impl ThreadStats {
+    pub fn codes_per_second(&self) -> f64 {
+        self.codes as f64 / self.seconds
+    }
+}
+
+/// Runs `f` and returns the panic message if it panics
+pub fn catch_panic<T>(f: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    catch_unwind(f).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "<unknown panic>".to_string()
+    }
+}
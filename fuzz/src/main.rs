@@ -1,19 +1,33 @@
+mod codes;
+mod compare;
+mod failure;
+mod summary;
 mod v4t;
 mod v5te;
 mod v6k;
 
-use std::time::Instant;
+use std::{collections::BTreeMap, panic::AssertUnwindSafe, sync::Arc, time::Instant};
 
-use unarm::{parse::ArmVersion, ParseFlags};
+use compare::{Allowlist, Comparer};
+use failure::{catch_panic, Report, Target, ThreadStats};
+use summary::Summary;
+
+use unarm::{parse::ArmVersion, ParseFlags, ParsedIns};
 
 fn main() {
-    let (threads, iterations, arm, thumb, version, ual) = {
+    let (threads, iterations, arm, thumb, version, ual, vfp, seed, compare, replay, corpus, json) = {
         let mut threads = num_cpus::get();
         let mut iterations = 1;
         let mut arm = false;
         let mut thumb = false;
         let mut version = None;
         let mut ual = false;
+        let mut vfp = false;
+        let mut seed = None;
+        let mut compare = None;
+        let mut replay = None;
+        let mut corpus = None;
+        let mut json = None;
         let mut args = std::env::args();
         args.next(); // skip program name
         while let Some(arg) = args.next() {
@@ -26,11 +40,37 @@ fn main() {
                 "v5te" => version = Some(ArmVersion::V5Te),
                 "v6k" => version = Some(ArmVersion::V6K),
                 "ual" => ual = true,
+                "vfp" => vfp = true,
+                "--seed" => {
+                    seed = Some(
+                        args.next()
+                            .and_then(|a| a.parse().ok())
+                            .expect("Expected number after --seed"),
+                    )
+                }
+                "--compare-capstone" => compare = Some(Allowlist::builtin()),
+                "--allowlist" => {
+                    let path = args.next().expect("Expected path after --allowlist");
+                    let text = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read '{path}': {e}"));
+                    compare = Some(Allowlist::parse(&text).unwrap_or_else(|e| panic!("{e}")));
+                }
+                "--replay" => replay = Some(args.next().expect("Expected path after --replay")),
+                "--corpus" => corpus = Some(args.next().expect("Expected path after --corpus")),
+                "--json" => json = Some(args.next().expect("Expected path after --json")),
                 _ => panic!("Unknown argument '{}'", arg),
             }
         }
-        (threads, iterations, arm, thumb, version, ual)
+        (
+            threads, iterations, arm, thumb, version, ual, vfp, seed, compare, replay, corpus, json,
+        )
     };
+    if compare.is_some() && !cfg!(feature = "capstone") {
+        panic!("Comparing with Capstone requires the capstone feature");
+    }
+    if let Some(path) = replay {
+        replay_failures(&path, compare.map(Arc::new));
+        return;
+    }
     if threads == 0 {
         panic!("Number of threads must be positive");
     }
@@ -41,37 +81,173 @@ fn main() {
         panic!("Expected one of: arm, thumb");
     }
     let Some(version) = version else {
-        panic!("Expected one of: v5te");
+        panic!("Expected one of: v4t, v5te, v6k");
+    };
+    if compare.is_some() && !ual {
+        println!("Capstone prints unified syntax, pass `ual` to avoid mismatching most mnemonics");
+    }
+    let compare = compare.map(Arc::new);
+    let flags = ParseFlags {
+        ual,
+        vfp,
+        ..Default::default()
     };
-    let flags = ParseFlags { ual };
+
+    if let Some(path) = corpus {
+        let target = Target { version, thumb, flags };
+        let start = Instant::now();
+        let report = parse_corpus(&path, &target, compare.clone());
+        if let Some(json) = json {
+            Summary::new(&target, &report, start.elapsed().as_secs_f64(), compare.is_some()).write(&json);
+        }
+        write_failures(&target, &report);
+        return;
+    }
 
     println!("Starting {} threads running {} iterations", threads, iterations);
+    if let Some(seed) = seed {
+        println!("Parsing pseudorandom codes with seed {seed}, reproducible with the same seed and number of threads");
+    }
     let start = Instant::now();
-    match version {
-        ArmVersion::V4T => {
-            if arm {
-                v4t::arm::fuzz(threads, iterations, flags);
-            }
-            if thumb {
-                v4t::thumb::fuzz(threads, iterations, flags);
-            }
-        }
-        ArmVersion::V5Te => {
-            if arm {
-                v5te::arm::fuzz(threads, iterations, flags);
+    let report = match (version, thumb) {
+        (ArmVersion::V4T, false) => v4t::arm::fuzz(threads, iterations, flags, seed, compare.clone()),
+        (ArmVersion::V4T, true) => v4t::thumb::fuzz(threads, iterations, flags, seed, compare.clone()),
+        (ArmVersion::V5Te, false) => v5te::arm::fuzz(threads, iterations, flags, seed, compare.clone()),
+        (ArmVersion::V5Te, true) => v5te::thumb::fuzz(threads, iterations, flags, seed, compare.clone()),
+        (ArmVersion::V6K, false) => v6k::arm::fuzz(threads, iterations, flags, seed, compare.clone()),
+        (ArmVersion::V6K, true) => v6k::thumb::fuzz(threads, iterations, flags, seed, compare.clone()),
+        (ArmVersion::V4 | ArmVersion::V5T, _) => unreachable!("Only versions with their own decoder are fuzzed"),
+    };
+    let seconds = start.elapsed().as_secs_f64();
+    println!("Finished in {seconds:.2}s");
+    if compare.is_some() {
+        report.mismatches.print_summary();
+    }
+    let target = Target { version, thumb, flags };
+    if let Some(json) = json {
+        Summary::new(&target, &report, seconds, compare.is_some()).write(&json);
+    }
+    write_failures(&target, &report);
+}
+
+/// Writes a failure file for every panic and every example mismatch
+fn write_failures(target: &Target, report: &Report) {
+    let panics = report
+        .panics
+        .iter()
+        .map(|(code, message)| (*code, format!("panic: {message}")));
+    let mismatches = report
+        .mismatches
+        .examples()
+        .map(|(opcode, code, ours, theirs)| (code, format!("{opcode} mismatch: '{ours}' != '{theirs}'")));
+    let mut count = 0;
+    for (code, reason) in panics.chain(mismatches) {
+        target
+            .write_failure(code, &reason)
+            .unwrap_or_else(|e| panic!("Failed to write failure of {code:#010x}: {e}"));
+        count += 1;
+    }
+    if count > 0 {
+        println!(
+            "Wrote {count} failures to {}/, replay them with --replay <file>",
+            failure::FAILURES_DIR
+        );
+    }
+    if !report.panics.is_empty() {
+        println!("{} codes panicked", report.panics.len());
+        std::process::exit(1);
+    }
+}
+
+/// Parses every code of a failure file again and prints the results
+fn replay_failures(path: &str, compare: Option<Arc<Allowlist>>) {
+    let text = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("Failed to read '{path}': {e}"));
+    let (target, codes) = Target::read_failures(&text).unwrap_or_else(|e| panic!("{e}"));
+    let mut comparer = compare.map(|allowlist| Comparer::new(target.thumb, allowlist));
+    let mut panics = 0;
+    for code in codes {
+        let mut parsed = ParsedIns::default();
+        let result = catch_panic(AssertUnwindSafe(|| parse_one(&target, code, &mut parsed)));
+        match result {
+            Ok(opcode) => {
+                let text = parsed.display(Default::default()).to_string();
+                println!("{code:#010x}: {text}");
+                if let Some(comparer) = &mut comparer {
+                    comparer.compare(code, opcode, &text);
+                }
             }
-            if thumb {
-                v5te::thumb::fuzz(threads, iterations, flags);
+            Err(message) => {
+                println!("{code:#010x}: panicked: {message}");
+                panics += 1;
             }
         }
-        ArmVersion::V6K => {
-            if arm {
-                v6k::arm::fuzz(threads, iterations, flags);
-            }
-            if thumb {
-                v6k::thumb::fuzz(threads, iterations, flags);
+    }
+    if let Some(comparer) = comparer {
+        comparer.into_mismatches().print_summary();
+    }
+    if panics > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Parses `code` for `target` into `parsed` and returns the variant name of its opcode
+fn parse_one(target: &Target, code: u32, parsed: &mut ParsedIns) -> &'static str {
+    let flags = &target.flags;
+    match (target.version, target.thumb) {
+        (ArmVersion::V4T, false) => v4t::arm::parse_one(code, flags, parsed).op.variant_name(),
+        (ArmVersion::V4T, true) => v4t::thumb::parse_one(code, flags, parsed).op.variant_name(),
+        (ArmVersion::V5Te, false) => v5te::arm::parse_one(code, flags, parsed).op.variant_name(),
+        (ArmVersion::V5Te, true) => v5te::thumb::parse_one(code, flags, parsed).op.variant_name(),
+        (ArmVersion::V6K, false) => v6k::arm::parse_one(code, flags, parsed).op.variant_name(),
+        (ArmVersion::V6K, true) => v6k::thumb::parse_one(code, flags, parsed).op.variant_name(),
+        (ArmVersion::V4 | ArmVersion::V5T, _) => unreachable!("Only versions with their own decoder are fuzzed"),
+    }
+}
+
+/// Parses and displays every instruction of a little-endian code file, e.g. one written by `cargo xtask corpus`
+fn parse_corpus(path: &str, target: &Target, compare: Option<Arc<Allowlist>>) -> Report {
+    let data = std::fs::read(path).unwrap_or_else(|e| panic!("Failed to read '{path}': {e}"));
+    let codes: Vec<u32> = if target.thumb {
+        data.chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]) as u32)
+            .collect()
+    } else {
+        data.chunks_exact(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    };
+    println!("Parsing {} instructions of {path}", codes.len());
+    let start = Instant::now();
+    let mut comparer = compare.map(|allowlist| Comparer::new(target.thumb, allowlist));
+    let mut parsed = ParsedIns::default();
+    let mut panics = vec![];
+    let mut opcodes = BTreeMap::new();
+    let count = codes.len() as u64;
+    for code in codes {
+        let result = catch_panic(AssertUnwindSafe(|| {
+            let opcode = parse_one(target, code, &mut parsed);
+            (opcode, parsed.display(Default::default()).to_string())
+        }));
+        match result {
+            Ok((opcode, text)) => {
+                *opcodes.entry(opcode).or_default() += 1;
+                if let Some(comparer) = &mut comparer {
+                    comparer.compare(code, opcode, &text);
+                }
             }
+            Err(message) => panics.push((code, message)),
         }
     }
-    println!("Finished in {:.2}s", start.elapsed().as_secs_f32());
+    let seconds = start.elapsed().as_secs_f64();
+    println!("Finished in {seconds:.2}s");
+    let mismatches = comparer.map(Comparer::into_mismatches);
+    if let Some(mismatches) = &mismatches {
+        mismatches.print_summary();
+    }
+    Report {
+        mismatches: mismatches.unwrap_or_default(),
+        panics,
+        opcodes,
+        threads: vec![ThreadStats { codes: count, seconds }],
+    }
 }
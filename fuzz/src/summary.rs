@@ -0,0 +1,47 @@
+//! Summary of a run written with `--json <path>`, for tracking the throughput and output of the decoder over time
+
+use std::{collections::BTreeMap, fs};
+
+use serde::Serialize;
+
+use crate::failure::{Report, Target};
+
+#[derive(Serialize)]
+pub struct Summary {
+    pub version: &'static str,
+    pub mode: &'static str,
+    pub seconds: f64,
+    /// Number of parsed codes in total
+    pub codes: u64,
+    /// Codes parsed per second by each thread
+    pub codes_per_second: Vec<f64>,
+    /// Number of codes parsed as illegal
+    pub illegal: u64,
+    /// Number of codes per opcode variant name, including `Illegal`
+    pub opcodes: BTreeMap<&'static str, u64>,
+    pub panics: usize,
+    /// Number of mismatches per opcode variant name, if compared with Capstone
+    pub mismatches: Option<BTreeMap<&'static str, usize>>,
+}
+
+impl Summary {
+    pub fn new(target: &Target, report: &Report, seconds: f64, compared: bool) -> Self {
+        Self {
+            version: target.version_name(),
+            mode: target.mode_name(),
+            seconds,
+            codes: report.threads.iter().map(|thread| thread.codes).sum(),
+            codes_per_second: report.threads.iter().map(|thread| thread.codes_per_second()).collect(),
+            illegal: report.opcodes.get("Illegal").copied().unwrap_or_default(),
+            opcodes: report.opcodes.clone(),
+            panics: report.panics.len(),
+            mismatches: compared.then(|| report.mismatches.counts()),
+        }
+    }
+
+    pub fn write(&self, path: &str) {
+        let json = serde_json::to_string_pretty(self).expect("Failed to serialize summary");
+        fs::write(path, json + "\n").unwrap_or_else(|e| panic!("Failed to write '{path}': {e}"));
+        println!("Wrote summary to {path}");
+    }
+}
@@ -1,48 +1,115 @@
-use std::{hint::black_box, ops::RangeInclusive};
+use std::{hint::black_box, ops::RangeInclusive, panic::AssertUnwindSafe, sync::Arc, time::Instant};
 
-use unarm::{v5te::arm, ParseFlags, ParsedIns};
+use unarm::{v4t::arm, ParseFlags, ParsedIns};
 
-pub fn fuzz(num_threads: usize, iterations: usize, flags: ParseFlags) {
+use crate::{
+    codes::{Codes, SplitMix64},
+    compare::{Allowlist, Comparer},
+    failure::{catch_panic, Report, ThreadStats},
+};
+
+pub fn fuzz(
+    num_threads: usize,
+    iterations: usize,
+    flags: ParseFlags,
+    seed: Option<u64>,
+    compare: Option<Arc<Allowlist>>,
+) -> Report {
     let fuzzers: Vec<_> = (0..num_threads)
         .map(|i| {
             let start = ((0x100000000 * i) / num_threads).try_into().unwrap();
             let end = ((0x100000000 * (i + 1)) / num_threads - 1).try_into().unwrap();
-            Fuzzer::new(start..=end, iterations, flags)
+            let rng = seed.map(|seed| SplitMix64::for_thread(seed, i));
+            Fuzzer::new(start..=end, iterations, flags, rng, compare.clone())
         })
         .collect();
 
     let handles: Vec<_> = fuzzers.iter().map(|f| f.run()).collect();
+    let mut report = Report::default();
     for handle in handles {
-        handle.join().unwrap();
+        report.merge(handle.join().unwrap());
     }
+    report
+}
+
+/// Parses `code` into `parsed` the same way as the fuzzer threads
+pub fn parse_one(code: u32, flags: &ParseFlags, parsed: &mut ParsedIns) -> arm::Ins {
+    let ins = arm::Ins::new(code, flags);
+    assert!(ins.size() == 4, "{code:#x} has size {}", ins.size());
+    #[allow(clippy::unit_arg)]
+    black_box(arm::parse(parsed, ins, flags));
+    ins
 }
 
 struct Fuzzer {
     range: RangeInclusive<u32>,
     iterations: usize,
     flags: ParseFlags,
+    rng: Option<SplitMix64>,
+    compare: Option<Arc<Allowlist>>,
 }
 
 impl Fuzzer {
-    fn new(range: RangeInclusive<u32>, iterations: usize, flags: ParseFlags) -> Self {
+    fn new(
+        range: RangeInclusive<u32>,
+        iterations: usize,
+        flags: ParseFlags,
+        rng: Option<SplitMix64>,
+        compare: Option<Arc<Allowlist>>,
+    ) -> Self {
         Self {
             range,
             iterations,
             flags,
+            rng,
+            compare,
         }
     }
 
-    fn run(&self) -> std::thread::JoinHandle<()> {
+    fn run(&self) -> std::thread::JoinHandle<Report> {
         let range = self.range.clone();
         let iterations = self.iterations;
         let flags = self.flags;
+        let mut rng = self.rng.clone();
+        let compare = self.compare.clone();
         std::thread::spawn(move || {
+            let start = Instant::now();
             let mut parsed = ParsedIns::default();
+            // Indexed by `op as usize`
+            let mut opcodes = [0u64; 256];
+            let mut count = 0;
+            let mut comparer = compare.map(|allowlist| Comparer::new(false, allowlist));
+            let mut panics = vec![];
             for _ in 0..iterations {
-                for code in range.clone() {
-                    #[allow(clippy::unit_arg)]
-                    black_box(arm::parse(&mut parsed, arm::Ins::new(code, &flags), &flags));
+                // Keep drawing from the same generator, so that every iteration parses different codes
+                let mut codes = Codes::new(range.clone(), rng.take());
+                for code in codes.by_ref() {
+                    count += 1;
+                    let ins = match catch_panic(AssertUnwindSafe(|| parse_one(code, &flags, &mut parsed))) {
+                        Ok(ins) => ins,
+                        Err(message) => {
+                            panics.push((code, message));
+                            continue;
+                        }
+                    };
+                    opcodes[ins.op as usize] += 1;
+                    if let Some(comparer) = &mut comparer {
+                        let text = parsed.display(Default::default()).to_string();
+                        comparer.compare(code, ins.op.variant_name(), &text);
+                    }
                 }
+                rng = codes.into_rng();
+            }
+            let seconds = start.elapsed().as_secs_f64();
+            Report {
+                mismatches: comparer.map(Comparer::into_mismatches).unwrap_or_default(),
+                panics,
+                opcodes: arm::Opcode::iter()
+                    .chain([arm::Opcode::Illegal])
+                    .map(|op| (op.variant_name(), opcodes[op as usize]))
+                    .filter(|(_, count)| *count > 0)
+                    .collect(),
+                threads: vec![ThreadStats { codes: count, seconds }],
             }
         })
     }
@@ -70,6 +70,8 @@ pub struct Arg {
     pub name: String,
     pub desc: String,
     pub r#type: ArgType,
+    /// Length of the longest rendering of this argument, with any display options
+    pub max_len: usize,
 }
 
 impl Arg {
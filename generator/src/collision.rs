@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+
+use crate::isa::{Isa, ModifierCase, Opcode};
+
+/// Which opcode and modifier cases a mnemonic was rendered from. Modifier cases without a suffix are left out, as they
+/// can't be told apart by the mnemonic anyway. Opcodes which only differ in their arguments (such as `mov` with an
+/// immediate or a register) have the same rendering.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct Rendering {
+    opcode: String,
+    cases: Vec<(String, u32)>,
+}
+
+impl Rendering {
+    fn new(opcode: &Opcode, cases: &[ModifierCase], ual: bool) -> Self {
+        Self {
+            opcode: opcode.name(ual),
+            cases: cases
+                .iter()
+                .filter(|case| !case.suffix(ual).is_empty())
+                .map(|case| (case.name.clone(), case.pattern))
+                .collect(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        let mut desc = self.opcode.clone();
+        for (name, _) in &self.cases {
+            desc += " + ";
+            desc += name;
+        }
+        desc
+    }
+}
+
+/// A mnemonic which is rendered from more than one opcode or set of modifier cases
+pub struct Collision {
+    pub mnemonic: String,
+    pub ual: bool,
+    renderings: Vec<Rendering>,
+    pub allowed: bool,
+}
+
+impl Collision {
+    pub fn describe(&self) -> String {
+        let renderings: Vec<_> = self.renderings.iter().map(|r| r.describe()).collect();
+        let syntax = if self.ual { "unified" } else { "divided" };
+        format!("'{}' ({syntax}): {}", self.mnemonic, renderings.join(", "))
+    }
+}
+
+pub struct MnemonicReport {
+    /// Number of distinct mnemonics in both syntaxes
+    pub mnemonics: usize,
+    pub collisions: Vec<Collision>,
+}
+
+impl MnemonicReport {
+    pub fn allowed_collisions(&self) -> usize {
+        self.collisions.iter().filter(|c| c.allowed).count()
+    }
+}
+
+/// Renders every combination of opcode and modifier cases in both syntaxes, and finds mnemonics which can't be mapped
+/// back to a single combination. Returns an error if a collision is not in the ISA's allowlist, or if the allowlist has
+/// mnemonics which don't collide.
+pub fn check_mnemonics(isa: &Isa) -> Result<MnemonicReport> {
+    let mut mnemonics = 0;
+    let mut collisions = vec![];
+    for ual in [false, true] {
+        let mut renderings: BTreeMap<String, Vec<Rendering>> = BTreeMap::new();
+        for opcode in isa.opcodes.iter() {
+            if opcode.ual_flag().is_some_and(|flag| flag != ual) {
+                continue;
+            }
+            let syntax = opcode.parsed_syntax(isa, ual)?;
+            for cases in opcode.get_case_combinations(isa, syntax)? {
+                let rendering = Rendering::new(opcode, &cases, syntax);
+                let entry = renderings.entry(opcode.mnemonic(&cases, syntax)).or_default();
+                if !entry.contains(&rendering) {
+                    entry.push(rendering);
+                }
+            }
+        }
+        mnemonics += renderings.len();
+
+        for (mnemonic, mut renderings) in renderings {
+            if renderings.len() > 1 {
+                renderings.sort();
+                let allowed = isa.allow_collisions.contains(&mnemonic);
+                collisions.push(Collision {
+                    mnemonic,
+                    ual,
+                    renderings,
+                    allowed,
+                });
+            }
+        }
+    }
+
+    let denied: Vec<_> = collisions.iter().filter(|c| !c.allowed).map(|c| c.describe()).collect();
+    if !denied.is_empty() {
+        bail!(
+            "Ambiguous mnemonics, add them to `allow_collisions` if this is intentional:\n{}",
+            denied.join("\n")
+        )
+    }
+    for allowed in isa.allow_collisions.iter() {
+        if !collisions.iter().any(|c| &c.mnemonic == allowed) {
+            bail!("Mnemonic '{allowed}' is in `allow_collisions` but doesn't collide")
+        }
+    }
+
+    Ok(MnemonicReport { mnemonics, collisions })
+}
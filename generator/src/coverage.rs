@@ -0,0 +1,191 @@
+use std::{collections::BTreeMap, fmt::Write, fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    generate::disasm::unconditional_mnemonics,
+    isa::{Isa, Opcode},
+};
+
+/// An instruction from a reference list, see the header of any `*.reference.txt` file in `specs/`
+pub struct Reference {
+    name: String,
+    /// Opcode names from the ISA file, or `None` to match opcodes by mnemonic
+    opcodes: Option<Vec<String>>,
+}
+
+impl Reference {
+    pub fn load(path: &Path) -> Result<Vec<Self>> {
+        let text = fs::read_to_string(path).with_context(|| format!("Failed to open reference list '{}'", path.display()))?;
+        let mut references: Vec<Self> = vec![];
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (name, opcodes) = match line.split_once(':') {
+                Some((name, opcodes)) => (name.trim(), Some(opcodes.split_whitespace().map(str::to_string).collect())),
+                None => (line, None),
+            };
+            if references.iter().any(|r| r.name == name) {
+                bail!("Duplicate instruction '{name}' on line {} of '{}'", index + 1, path.display());
+            }
+            references.push(Self {
+                name: name.to_string(),
+                opcodes,
+            });
+        }
+        Ok(references)
+    }
+
+    fn is_implemented_by(&self, isa: &Isa, opcode: &Opcode) -> Result<bool> {
+        Ok(match &self.opcodes {
+            Some(names) => names.iter().any(|name| name.replace('$', "_") == opcode.ident_name()),
+            None => unconditional_mnemonics(isa, opcode)?.contains(&self.name.to_lowercase()),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Covered,
+    Missing,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Instruction {
+    pub name: String,
+    pub status: Status,
+    /// Opcodes which implement this instruction, named like their `Opcode` variant
+    pub opcodes: Vec<CoveringOpcode>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CoveringOpcode {
+    pub name: String,
+    /// The fixed bits of the opcode, as an example encoding with every other bit cleared
+    pub pattern: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct IsaCoverage {
+    pub instructions: Vec<Instruction>,
+    /// Opcodes which don't implement any instruction of the reference list
+    pub unreferenced: Vec<String>,
+}
+
+impl IsaCoverage {
+    pub fn new(isa: &Isa, references: &[Reference]) -> Result<Self> {
+        let mut referenced = vec![false; isa.opcodes.len()];
+        let mut instructions = vec![];
+        for reference in references {
+            let mut opcodes = vec![];
+            for (index, opcode) in isa.opcodes.iter().enumerate() {
+                if reference.is_implemented_by(isa, opcode)? {
+                    referenced[index] = true;
+                    opcodes.push(CoveringOpcode {
+                        name: opcode.enum_name(),
+                        pattern: opcode.pattern,
+                    });
+                }
+            }
+            let status = if opcodes.is_empty() {
+                Status::Missing
+            } else {
+                Status::Covered
+            };
+            instructions.push(Instruction {
+                name: reference.name.clone(),
+                status,
+                opcodes,
+            });
+        }
+        let unreferenced = isa
+            .opcodes
+            .iter()
+            .zip(referenced)
+            .filter(|(_, referenced)| !referenced)
+            .map(|(opcode, _)| opcode.enum_name())
+            .collect();
+        Ok(Self {
+            instructions,
+            unreferenced,
+        })
+    }
+
+    pub fn missing(&self) -> usize {
+        self.instructions.iter().filter(|i| i.status == Status::Missing).count()
+    }
+}
+
+/// Coverage of each ISA, keyed by its module path such as `v5te/arm`
+pub type Coverage = BTreeMap<String, IsaCoverage>;
+
+/// Returns an error if an instruction which is covered in `old` is missing in `new`, so that removing or renaming an
+/// opcode can't silently drop an instruction from the checklist.
+pub fn check_regressions(old: &Coverage, new: &Coverage) -> Result<()> {
+    let mut regressions = vec![];
+    for (module, old) in old {
+        let Some(new) = new.get(module) else {
+            bail!("Coverage of {module} is no longer generated");
+        };
+        for instruction in old.instructions.iter().filter(|i| i.status == Status::Covered) {
+            if !new
+                .instructions
+                .iter()
+                .any(|i| i.name == instruction.name && i.status == Status::Covered)
+            {
+                regressions.push(format!("{module}: {}", instruction.name));
+            }
+        }
+    }
+    if !regressions.is_empty() {
+        bail!(
+            "Previously covered instructions are no longer implemented by any opcode:\n    {}",
+            regressions.join("\n    ")
+        );
+    }
+    Ok(())
+}
+
+pub fn to_markdown(coverage: &Coverage) -> Result<String> {
+    let mut md = String::new();
+    writeln!(md, "# Instruction coverage")?;
+    writeln!(md)?;
+    writeln!(
+        md,
+        "Generated by the generator from the `*.reference.txt` instruction lists in this directory. Do not edit."
+    )?;
+    for (module, isa) in coverage {
+        writeln!(md)?;
+        writeln!(md, "## {module}")?;
+        writeln!(md)?;
+        writeln!(
+            md,
+            "{} of {} instructions covered.",
+            isa.instructions.len() - isa.missing(),
+            isa.instructions.len()
+        )?;
+        writeln!(md)?;
+        writeln!(md, "| Instruction | Opcodes |")?;
+        writeln!(md, "| --- | --- |")?;
+        for instruction in &isa.instructions {
+            let opcodes = match instruction.status {
+                Status::Covered => {
+                    let names: Vec<_> = instruction.opcodes.iter().map(|op| format!("`{}`", op.name)).collect();
+                    names.join(", ")
+                }
+                Status::Missing => "missing".to_string(),
+            };
+            writeln!(md, "| {} | {opcodes} |", instruction.name.replace('<', "\\<"))?;
+        }
+        if !isa.unreferenced.is_empty() {
+            writeln!(md)?;
+            let names: Vec<_> = isa.unreferenced.iter().map(|name| format!("`{name}`")).collect();
+            writeln!(md, "Not in the reference list: {}", names.join(", "))?;
+        }
+    }
+    Ok(md)
+}
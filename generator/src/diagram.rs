@@ -0,0 +1,166 @@
+use anyhow::Result;
+
+use crate::isa::{Isa, Opcode};
+
+/// Bits of an encoding which belong to the same part of the instruction
+struct Segment {
+    high: u32,
+    low: u32,
+    label: String,
+}
+
+/// Labels each bit of an opcode's encoding, from highest to lowest. Fixed bits are labeled by their value, the other
+/// bits by the modifier or argument which reads them. Bits read by more than one are labeled by the first one.
+fn label_bits(isa: &Isa, opcode: &Opcode) -> Result<Vec<String>> {
+    let mut labels = vec![None; isa.ins_size as usize];
+    let mut label = |bitmask: u32, name: &dyn Fn(u32) -> String| {
+        for (bit, label) in labels.iter_mut().enumerate() {
+            if bitmask & (1 << bit) != 0 && label.is_none() {
+                *label = Some(name(bit as u32));
+            }
+        }
+    };
+
+    label(opcode.bitmask, &|bit| ((opcode.pattern >> bit) & 1).to_string());
+    for modifier in opcode.get_modifiers(isa, false)? {
+        label(modifier.get_full_bitmask(isa)?, &|_| modifier.name.clone());
+    }
+    for arg in opcode.args.iter() {
+        let field = isa.get_field(arg)?;
+        label(field.get_bitmask()?, &|_| field.name.clone());
+    }
+
+    Ok(labels
+        .into_iter()
+        .rev()
+        .map(|label| label.unwrap_or_else(|| "-".to_string()))
+        .collect())
+}
+
+fn segments(isa: &Isa, opcode: &Opcode) -> Result<Vec<Segment>> {
+    let mut segments: Vec<Segment> = vec![];
+    let mut bit = isa.ins_size;
+    for label in label_bits(isa, opcode)? {
+        bit -= 1;
+        let fixed = label == "0" || label == "1";
+        if let Some(last) = segments.last_mut() {
+            let last_fixed = last.label.bytes().all(|c| c == b'0' || c == b'1');
+            if fixed && last_fixed {
+                last.low = bit;
+                last.label += &label;
+                continue;
+            }
+            if !fixed && last.label == label {
+                last.low = bit;
+                continue;
+            }
+        }
+        segments.push(Segment {
+            high: bit,
+            low: bit,
+            label,
+        });
+    }
+    Ok(segments)
+}
+
+/// Returns the lines of a table of the opcode's encoding, with the bit ranges on top and what they contain below, e.g.
+/// ```text
+/// 31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:0
+/// cond  | 0000101 | S  | Rn    | Rd    | op2
+/// ```
+pub fn encoding_diagram(isa: &Isa, opcode: &Opcode) -> Result<[String; 2]> {
+    let mut ranges = vec![];
+    let mut labels = vec![];
+    for segment in segments(isa, opcode)? {
+        let range = if segment.high == segment.low {
+            segment.high.to_string()
+        } else {
+            format!("{}:{}", segment.high, segment.low)
+        };
+        let width = range.len().max(segment.label.len());
+        ranges.push(format!("{range:width$}"));
+        labels.push(format!("{:width$}", segment.label));
+    }
+    Ok([
+        ranges.join(" | ").trim_end().to_string(),
+        labels.join(" | ").trim_end().to_string(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn isa(yaml: &str) -> Isa {
+        serde_yml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_arm() {
+        let isa = isa(r"
+ins_size: 32
+fields:
+  - name: Rn
+    arg: reg
+    desc: First source operand register
+    value: !Bits 16..20
+  - name: Rd
+    arg: reg
+    desc: Destination register
+    value: !Bits 12..16
+  - name: imm
+    arg: uimm
+    desc: Immediate
+    value: !Bits 0..12
+modifiers:
+  - name: cond
+    desc: Condition
+    bitmask: 0xf0000000
+  - name: S
+    desc: Update condition flags
+    bitmask: 0x00100000
+opcodes:
+  - name: add
+    desc: Add
+    bitmask: 0x0fe00000
+    pattern: 0x02800000
+    modifiers: [S, cond]
+    args: [Rd, Rn, imm]
+");
+        assert_eq!(
+            encoding_diagram(&isa, &isa.opcodes[0]).unwrap(),
+            [
+                "31:28 | 27:21   | 20 | 19:16 | 15:12 | 11:0",
+                "cond  | 0010100 | S  | Rn    | Rd    | imm",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_thumb() {
+        let isa = isa(r"
+ins_size: 16
+fields:
+  - name: Rd
+    arg: reg
+    desc: Destination register
+    value: !Bits 0..3
+  - name: Rm
+    arg: reg
+    desc: Source register
+    value: !Bits 3..6
+modifiers: []
+opcodes:
+  - name: mvn
+    desc: Bitwise NOT
+    bitmask: 0xffc0
+    pattern: 0x43c0
+    args: [Rd, Rm]
+");
+        assert_eq!(
+            encoding_diagram(&isa, &isa.opcodes[0]).unwrap(),
+            ["15:6       | 5:3 | 2:0", "0100001111 | Rm  | Rd"]
+        );
+    }
+}
@@ -29,9 +29,10 @@ fn generate_types(isa_args: &IsaArgs) -> Result<Vec<TokenStream>, anyhow::Error>
         .iter()
         .map(|ty| {
             let ident = Ident::new(&ty.pascal_case_name(), Span::call_site());
+            let doc = format!(" {}", ty.desc);
             let tokens = match &ty.r#type {
-                TypeKind::Struct(members) => generate_struct(members, isa_args, ident)?,
-                TypeKind::Enum(values) => generate_enum(values, ident),
+                TypeKind::Struct(members) => generate_struct(members, isa_args, ident, &doc)?,
+                TypeKind::Enum(values) => generate_enum(values, ident, &doc),
             };
             Ok(tokens)
         })
@@ -45,10 +46,11 @@ fn generate_arg_types(isa_args: &IsaArgs) -> Result<Vec<TokenStream>, anyhow::Er
         .iter()
         .map(|arg| {
             let ident = Ident::new(&arg.pascal_case_name(), Span::call_site());
+            let doc = format!(" {}", arg.desc);
 
             let tokens = match &arg.r#type {
-                ArgType::Struct(members) => generate_struct(members, isa_args, ident)?,
-                ArgType::Enum(values) => generate_enum(values, ident),
+                ArgType::Struct(members) => generate_struct(members, isa_args, ident, &doc)?,
+                ArgType::Enum(values) => generate_enum(values, ident, &doc),
                 ArgType::U32 => quote! {},
                 ArgType::I32 => quote! {},
                 ArgType::Bool => quote! {},
@@ -87,20 +89,77 @@ fn generate_args_enum(isa_args: &IsaArgs, max_args: usize) -> Result<TokenStream
             })
         })
         .collect::<Result<Vec<_>>>()?;
+    let kind_variants = isa_args
+        .args
+        .iter()
+        .map(|arg| {
+            let doc = format!(" {}", arg.desc);
+            let variant = Ident::new(&arg.pascal_case_name(), Span::call_site());
+            quote! {
+                #[doc = #doc]
+                #variant
+            }
+        })
+        .collect::<Vec<_>>();
+    let kind_arms = isa_args
+        .args
+        .iter()
+        .map(|arg| {
+            let variant = Ident::new(&arg.pascal_case_name(), Span::call_site());
+            quote! { Argument::#variant(_) => ArgumentKind::#variant }
+        })
+        .collect::<Vec<_>>();
+    let illegal_arms = isa_args
+        .args
+        .iter()
+        .filter(|arg| !matches!(arg.r#type, ArgType::U32 | ArgType::I32 | ArgType::Bool))
+        .map(|arg| {
+            let variant = Ident::new(&arg.pascal_case_name(), Span::call_site());
+            quote! { Argument::#variant(x) => x.is_illegal() }
+        })
+        .collect::<Vec<_>>();
     let max_args = Literal::usize_suffixed(max_args);
     let args_enum = quote! {
-        pub type Arguments = [Argument; #max_args];
-        #[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+        #[doc = " Maximum number of arguments of an instruction"]
+        pub const MAX_ARGS: usize = #max_args;
+        pub type Arguments = [Argument; MAX_ARGS];
+        #[doc = " Arguments are ordered by variant, then by value. Like all `Ord` impls in this crate, the order follows the"]
+        #[doc = " representation and not any meaning of the values, but it is deterministic and consistent with `Eq`."]
+        #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Argument {
             #[default]
             None,
             #(#args_variants),*
         }
+        #[doc = " The variant of an `Argument`, without its value"]
+        #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum ArgumentKind {
+            #[default]
+            None,
+            #(#kind_variants),*
+        }
+        impl Argument {
+            pub fn kind(&self) -> ArgumentKind {
+                match self {
+                    Argument::None => ArgumentKind::None,
+                    #(#kind_arms),*
+                }
+            }
+            #[doc = " Returns true if the argument contains an `Illegal` value, such as `Register::Illegal`."]
+            pub fn is_illegal(&self) -> bool {
+                match self {
+                    #(#illegal_arms,)*
+                    _ => false,
+                }
+            }
+        }
     };
     Ok(args_enum)
 }
 
-fn generate_enum(values: &[EnumValue], ident: Ident) -> TokenStream {
+fn generate_enum(values: &[EnumValue], ident: Ident, doc: &str) -> TokenStream {
     let values_tokens = values
         .iter()
         .map(|value| {
@@ -119,7 +178,7 @@ fn generate_enum(values: &[EnumValue], ident: Ident) -> TokenStream {
         })
         .collect::<Vec<_>>();
 
-    let parse_body = if is_continuous(values) {
+    let try_parse_body = if is_continuous(values) {
         let min = values.iter().map(|v| v.value).min().unwrap_or(0);
         let max = values.iter().map(|v| v.value).max().unwrap_or(0);
 
@@ -133,35 +192,46 @@ fn generate_enum(values: &[EnumValue], ident: Ident) -> TokenStream {
 
         quote! {
             if #cond {
-                unsafe { std::mem::transmute::<u8, Self>(value as u8) }
+                Some(unsafe { std::mem::transmute::<u8, Self>(value as u8) })
             } else {
-                Self::Illegal
+                None
             }
         }
     } else {
         let arms = values.iter().map(|value| {
             let value_lit = Literal::u32_unsuffixed(value.value);
             let variant = Ident::new(&value.pascal_case_name(), Span::call_site());
-            quote! { #value_lit => Self::#variant }
+            quote! { #value_lit => Some(Self::#variant) }
         });
         quote! {
             match value {
-                #(#arms),*
-                _ => Self::Illegal,
+                #(#arms,)*
+                _ => None,
             }
         }
     };
 
     quote! {
-        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[doc = #doc]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #[repr(u8)]
         pub enum #ident {
             Illegal = u8::MAX,
             #(#values_tokens),*
         }
         impl #ident {
+            #[doc = " Returns `None` if `value` has no variant."]
+            pub fn try_parse(value: u32) -> Option<Self> {
+                #try_parse_body
+            }
+            #[doc = " Returns `Illegal` if `value` has no variant, see [`Self::try_parse`]."]
             pub fn parse(value: u32) -> Self {
-                #parse_body
+                Self::try_parse(value).unwrap_or(Self::Illegal)
+            }
+            #[doc = " Returns true if this is the `Illegal` variant."]
+            pub fn is_illegal(self) -> bool {
+                self == Self::Illegal
             }
         }
     }
@@ -171,7 +241,21 @@ fn generate_struct(
     members: &BTreeMap<String, StructMember>,
     isa_args: &IsaArgs,
     ident: Ident,
+    doc: &str,
 ) -> Result<TokenStream, anyhow::Error> {
+    let illegal_members = members
+        .iter()
+        .filter(|(_, member)| !matches!(member.r#type, ArgType::U32 | ArgType::I32 | ArgType::Bool))
+        .map(|(name, _)| {
+            let member = Ident::new(name, Span::call_site());
+            quote! { self.#member.is_illegal() }
+        })
+        .collect::<Vec<_>>();
+    let is_illegal = if illegal_members.is_empty() {
+        quote! { false }
+    } else {
+        quote! { #(#illegal_members)||* }
+    };
     let members = members
         .iter()
         .map(|(name, member_type)| {
@@ -197,9 +281,17 @@ fn generate_struct(
         })
         .collect::<Result<Vec<_>>>()?;
     Ok(quote! {
-        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[doc = #doc]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct #ident {
             #(#members),*
         }
+        impl #ident {
+            #[doc = " Returns true if any member is `Illegal`."]
+            pub fn is_illegal(self) -> bool {
+                #is_illegal
+            }
+        }
     })
 }
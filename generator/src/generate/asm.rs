@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::{Expr, Ident};
+
+use crate::{
+    args::{ArgType, IsaArgs, TypeKind},
+    isa::{Field, FieldValue, Isa, Modifier, Opcode},
+    token::HexLiteral,
+};
+
+/// Generates the encoder, which is the inverse of the parse functions. Returns the `Opcode::encode` method and the
+/// module-level items.
+pub fn generate_asm(isa: &Isa, isa_args: &IsaArgs, num_opcodes_token: &Literal) -> Result<(TokenStream, TokenStream)> {
+    let mut items = TokenStream::new();
+    for field in isa.fields.iter() {
+        items.extend(generate_field_encoder(field, isa_args).with_context(|| format!("In field '{}'", field.name))?);
+    }
+
+    let mut case_tables = BTreeMap::new();
+    let mut forms = vec![];
+    for opcode in isa.opcodes.iter() {
+        let opcode_forms = generate_forms(isa, isa_args, opcode, &mut case_tables)
+            .with_context(|| format!("While generating encoder for opcode '{}'", opcode.enum_name()))?;
+        forms.push(match opcode.feature() {
+            Some(feature) => quote! {
+                #[cfg(feature = #feature)] &[#(#opcode_forms),*],
+                #[cfg(not(feature = #feature))] &[]
+            },
+            None => quote! { &[#(#opcode_forms),*] },
+        });
+    }
+    for tokens in case_tables.into_values() {
+        items.extend(tokens);
+    }
+
+    items.extend(quote! {
+        static ENCODE_FORMS: [&[EncodeForm]; #num_opcodes_token] = [#(#forms),*];
+
+        #[doc = " Encodes a parsed instruction with any opcode. The result decodes to the same [`ParsedIns`] with the given"]
+        #[doc = " flags."]
+        pub fn encode(ins: &ParsedIns, flags: &ParseFlags) -> Result<u32, EncodeError> {
+            let mut error = EncodeError::UnknownMnemonic;
+            for forms in ENCODE_FORMS {
+                let result = encode::encode_forms(forms, ins, flags.ual, |code| Ins::new(code, flags).parse(flags) == *ins);
+                match result {
+                    Ok(code) => return Ok(code),
+                    Err(e) => error = error.most_relevant(e),
+                }
+            }
+            Err(error)
+        }
+    });
+
+    let method = quote! {
+        #[doc = " Encodes a parsed instruction as this opcode. The result decodes to this opcode and the same [`ParsedIns`]"]
+        #[doc = " with the given flags."]
+        pub fn encode(self, ins: &ParsedIns, flags: &ParseFlags) -> Result<u32, EncodeError> {
+            if self == Opcode::Illegal {
+                return Err(EncodeError::UnknownMnemonic);
+            }
+            encode::encode_forms(ENCODE_FORMS[self as usize], ins, flags.ual, |code| {
+                let decoded = Ins::new(code, flags);
+                decoded.op == self && decoded.parse(flags) == *ins
+            })
+        }
+    };
+    Ok((method, items))
+}
+
+fn encoder_ident(field: &Field) -> Ident {
+    Ident::new(&format!("encode_{}", field.name.to_lowercase()), Span::call_site())
+}
+
+/// Generates a function which encodes an argument into the bits of the field, the inverse of its accessor
+fn generate_field_encoder(field: &Field, isa_args: &IsaArgs) -> Result<TokenStream> {
+    let arg = isa_args.get_arg(&field.arg)?;
+    let variant = Ident::new(&arg.pascal_case_name(), Span::call_site());
+
+    let mut statements = vec![];
+    match (&arg.r#type, &field.value) {
+        (ArgType::Struct(members), FieldValue::Struct(values)) => {
+            for (name, member) in members {
+                let value = values
+                    .get(name)
+                    .with_context(|| format!("Member '{name}' missing from struct value"))?;
+                let ident = Ident::new(name, Span::call_site());
+                let member_value = quote! { value.#ident };
+                let as_u32 = value_as_u32(&member.r#type, member_value.clone(), isa_args)?;
+                statements.extend(generate_value_encoder(value, member_value, as_u32));
+            }
+        }
+        (ArgType::Struct(_), _) => bail!("Value must be a struct"),
+        (arg_type, value) => {
+            let as_u32 = value_as_u32(arg_type, quote! { value }, isa_args)?;
+            statements.extend(generate_value_encoder(value, quote! { value }, as_u32));
+        }
+    }
+    if let Some(encode) = &field.encode {
+        let encode: Expr = syn::parse_str(encode)?;
+        statements.push(quote! {
+            code |= (#encode).ok_or(FieldError::OutOfRange)?;
+        });
+    }
+
+    let encoder = encoder_ident(field);
+    let doc = field.doc();
+    Ok(quote! {
+        #[doc = #doc]
+        fn #encoder(arg: Argument) -> Result<u32, FieldError> {
+            let Argument::#variant(value) = arg else {
+                return Err(FieldError::Invalid);
+            };
+            let mut code = 0;
+            #(#statements)*
+            Ok(code)
+        }
+    })
+}
+
+fn value_as_u32(arg_type: &ArgType, value: TokenStream, isa_args: &IsaArgs) -> Result<TokenStream> {
+    Ok(match arg_type {
+        ArgType::U32 => value,
+        ArgType::I32 | ArgType::Bool | ArgType::Enum(_) => quote! { #value as u32 },
+        ArgType::Custom(name) => match isa_args.get_type(name)?.r#type {
+            TypeKind::Enum(_) => quote! { #value as u32 },
+            TypeKind::Struct(_) => bail!("Nested structs are not supported"),
+        },
+        ArgType::Struct(_) => bail!("Nested structs are not supported"),
+    })
+}
+
+/// Generates statements which check a constant value or encode a bit range. Expressions are encoded by the `encode`
+/// expression of the field instead.
+fn generate_value_encoder(value: &FieldValue, arg_value: TokenStream, as_u32: TokenStream) -> Option<TokenStream> {
+    match value {
+        FieldValue::Bits(range) => {
+            let start = Literal::u8_unsuffixed(range.0.start);
+            let end = Literal::u8_unsuffixed(range.0.end);
+            Some(quote! {
+                code |= encode::bits(#as_u32, #start, #end).ok_or(FieldError::OutOfRange)?;
+            })
+        }
+        FieldValue::Bool(value) => {
+            let condition = if *value {
+                quote! { !#arg_value }
+            } else {
+                quote! { #arg_value }
+            };
+            Some(quote! {
+                if #condition {
+                    return Err(FieldError::Invalid);
+                }
+            })
+        }
+        FieldValue::U32(value) => {
+            let value = Literal::u32_unsuffixed(*value);
+            Some(quote! {
+                if #as_u32 != #value {
+                    return Err(FieldError::Invalid);
+                }
+            })
+        }
+        FieldValue::Struct(_) | FieldValue::Expr(_) => None,
+    }
+}
+
+/// Generates one `EncodeForm` for each syntax the opcode is parsed in
+fn generate_forms(
+    isa: &Isa,
+    isa_args: &IsaArgs,
+    opcode: &Opcode,
+    case_tables: &mut BTreeMap<String, TokenStream>,
+) -> Result<Vec<TokenStream>> {
+    let mut syntaxes: Vec<(bool, Vec<bool>)> = vec![];
+    for flag in [false, true] {
+        if opcode.ual_flag() == Some(!flag) {
+            continue;
+        }
+        let ual = opcode.parsed_syntax(isa, flag)?;
+        match syntaxes.iter_mut().find(|(syntax, _)| *syntax == ual) {
+            Some((_, flags)) => flags.push(flag),
+            None => syntaxes.push((ual, vec![flag])),
+        }
+    }
+
+    let mut forms = vec![];
+    for (ual, flags) in syntaxes {
+        let flag = match flags.as_slice() {
+            [flag] => quote! { Some(#flag) },
+            _ => quote! { None },
+        };
+        let name = opcode.base_name();
+        let opcode_suffix = opcode.suffix.as_ref().map_or("", |s| s.suffix(ual));
+        let bitmask = HexLiteral(opcode.bitmask);
+        let pattern = HexLiteral(opcode.pattern);
+        let fields = generate_field_list(&opcode.get_args(isa, ual)?, isa_args)?;
+        let modifiers = opcode
+            .get_modifiers(isa, ual)?
+            .iter()
+            .map(|modifier| generate_case_table(isa, isa_args, modifier, ual, case_tables))
+            .collect::<Result<Vec<_>>>()?;
+        forms.push(quote! {
+            EncodeForm {
+                name: #name,
+                opcode_suffix: #opcode_suffix,
+                ual: #ual,
+                flag: #flag,
+                bitmask: #bitmask,
+                pattern: #pattern,
+                fields: #fields,
+                modifiers: &[#(&#modifiers),*],
+            }
+        });
+    }
+    Ok(forms)
+}
+
+/// Returns the bits which the encoder of a field writes. Boolean expressions such as the writeback of Thumb `ldm` only
+/// read bits which belong to other fields, so they are left out.
+fn encoded_bitmask(field: &Field, isa_args: &IsaArgs) -> Result<u32> {
+    let (ArgType::Struct(members), FieldValue::Struct(values)) = (&isa_args.get_arg(&field.arg)?.r#type, &field.value) else {
+        return field.get_bitmask();
+    };
+    let mut bitmask = 0;
+    for (name, value) in values {
+        let is_bool = members.get(name).is_some_and(|member| matches!(member.r#type, ArgType::Bool));
+        if !(is_bool && matches!(value, FieldValue::Expr(_))) {
+            bitmask |= value.get_bitmask()?;
+        }
+    }
+    Ok(bitmask)
+}
+
+fn generate_field_list(fields: &[&Field], isa_args: &IsaArgs) -> Result<TokenStream> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let encoder = encoder_ident(field);
+            let bitmask = HexLiteral(encoded_bitmask(field, isa_args)?);
+            Ok(quote! {
+                EncodeField { encode: #encoder, bitmask: #bitmask }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(quote! { &[#(#fields),*] })
+}
+
+/// Generates a static table of the cases of a modifier, and returns its name. Modifiers whose suffixes differ between
+/// syntaxes get one table per syntax.
+fn generate_case_table(
+    isa: &Isa,
+    isa_args: &IsaArgs,
+    modifier: &Modifier,
+    ual: bool,
+    case_tables: &mut BTreeMap<String, TokenStream>,
+) -> Result<Ident> {
+    let cases = modifier.get_cases()?;
+    let ual_changes = cases.iter().any(|case| case.suffix(true) != case.suffix(false));
+    let name = if ual && ual_changes {
+        format!("ENCODE_CASES_{}_UAL", modifier.name.to_uppercase())
+    } else {
+        format!("ENCODE_CASES_{}", modifier.name.to_uppercase())
+    };
+    let ident = Ident::new(&name, Span::call_site());
+    if case_tables.contains_key(&name) {
+        return Ok(ident);
+    }
+
+    let num_cases = Literal::usize_unsuffixed(cases.len());
+    let cases = cases
+        .iter()
+        .map(|case| {
+            let suffix = case.suffix(ual);
+            let bitmask = HexLiteral(case.bitmask.unwrap_or(0));
+            let pattern = HexLiteral(case.pattern);
+            let fields = case.args.iter().map(|arg| isa.get_field(arg)).collect::<Result<Vec<_>>>()?;
+            let fields = generate_field_list(&fields, isa_args)?;
+            Ok(quote! {
+                EncodeCase {
+                    suffix: #suffix,
+                    bitmask: #bitmask,
+                    pattern: #pattern,
+                    fields: #fields,
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let doc = modifier.doc();
+    case_tables.insert(
+        name,
+        quote! {
+            #[doc = #doc]
+            static #ident: [EncodeCase; #num_cases] = [#(#cases),*];
+        },
+    );
+    Ok(ident)
+}
@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use anyhow::{bail, Context, Result};
 use proc_macro2::{Literal, Span, TokenStream};
 use quote::quote;
@@ -7,30 +9,99 @@ use syn::{
     Expr, ExprLit, Ident, Lit,
 };
 
+use super::asm::generate_asm;
 use crate::{
-    args::{ArgType, IsaArgs},
-    isa::{Field, FieldValue, Flag, Isa, Opcode},
+    args::{ArgType, EnumValue, IsaArgs, TypeKind},
+    diagram::encoding_diagram,
+    isa::{Category, Field, FieldValue, Flag, Isa, Modifier, ModifierCase, Opcode},
     iter::cartesian,
+    profile::{ParseImpl, Profile},
     search::SearchTree,
+    table::{FindImpl, FindTable},
     token::HexLiteral,
+    util::{describe_bitmask, snake_case},
 };
 
 pub fn generate_disasm(isa: &Isa, isa_args: &IsaArgs, max_args: usize) -> Result<TokenStream> {
     // Generate opcode enum and mnemonics array
-    let (opcode_enum_tokens, opcode_mnemonics_tokens, num_opcodes_token) = generate_opcode_tokens(&isa.opcodes);
+    let (opcode_enum_tokens, opcode_mnemonics_tokens, num_opcodes_token) = generate_opcode_tokens(isa)?;
+    // The largest value is reserved for `Opcode::Illegal`
+    let opcode_repr = if isa.opcodes.len() < u8::MAX as usize {
+        Ident::new("u8", Span::call_site())
+    } else {
+        Ident::new("u16", Span::call_site())
+    };
+
+    // Generate alias check
+    let opcode_is_alias_tokens = generate_opcode_is_alias(&isa.opcodes);
+
+    // Generate variant names
+    let (opcode_variant_names_tokens, opcode_variant_names_static) = generate_opcode_variant_names(isa, &num_opcodes_token);
+
+    // Generate classification methods
+    let opcode_categories_tokens = generate_opcode_categories(&isa.opcodes);
 
     // Generate opcode search function
     let mut opcodes = isa.opcodes.to_vec();
     let tree = SearchTree::optimize(&opcodes, u32::MAX).unwrap();
     let body = generate_search_node(Some(Box::new(tree)), &mut opcodes);
+    let find_table = FindTable::new(isa)?;
+    let (opcode_find_table_tokens, opcode_find_table_static) = generate_find_table(&find_table);
+    let find_fns = Profile::ALL.iter().map(|profile| {
+        let cfg = profile.cfg();
+        let (doc, find_impl) = match profile.find() {
+            FindImpl::Tree => (
+                " Decodes the opcode with a tree of bit tests.",
+                quote! { Self::find_tree(code, flags) },
+            ),
+            FindImpl::Table => (
+                " Decodes the opcode with a lookup table.",
+                quote! { Self::find_table(code, flags) },
+            ),
+        };
+        quote! {
+            #[doc = #doc]
+            #cfg
+            #[inline]
+            pub const fn find(code: u32, flags: &ParseFlags) -> Self {
+                let guard = crate::no_panic::NoPanicGuard;
+                let op = #find_impl;
+                guard.disarm();
+                op
+            }
+        }
+    });
     let opcode_find_tokens = quote! {
+        #(#find_fns)*
+        #[doc = " Implementation of [`Self::find`] as a tree of bit tests. Public to test that it's equivalent to"]
+        #[doc = " [`Self::find_table`], whichever one the profile uses."]
+        #[doc(hidden)]
         #[inline]
-        pub fn find(code: u32, flags: &ParseFlags) -> Self {
+        pub const fn find_tree(code: u32, flags: &ParseFlags) -> Self {
             #body
             Opcode::Illegal
         }
+        #opcode_find_table_tokens
     };
 
+    // Generate diagnostic search function
+    let opcode_find_all_tokens = generate_opcode_find_all(&isa.opcodes);
+
+    // Generate rendered length bounds
+    let max_lengths_tokens = generate_max_lengths(isa, isa_args)?;
+
+    // Generate mnemonic list for deserialization
+    let mnemonics_tokens = generate_mnemonics(isa)?;
+
+    // Generate UNPREDICTABLE checks
+    let unpredictable_tokens = generate_unpredictable(isa)?;
+
+    // Generate condition accessor and unconditional mnemonics
+    let (condition_tokens, unconditional_mnemonics_tokens) = generate_condition(isa)?;
+
+    // Generate descriptions and fuzzy search
+    let (opcode_search_tokens, opcode_search_statics) = generate_opcode_search(isa, &num_opcodes_token)?;
+
     // Generate field accessors
     let field_accessors_tokens = generate_field_accessors(isa, isa_args)?;
 
@@ -40,48 +111,90 @@ pub fn generate_disasm(isa: &Isa, isa_args: &IsaArgs, max_args: usize) -> Result
     // Generate modifier accessors
     let modifier_accessors_tokens = generate_modifier_accessors(isa)?;
 
+    // Generate argument metadata
+    let (opcode_arg_meta_tokens, opcode_arg_meta_static) = generate_opcode_arg_meta(isa, isa_args, &num_opcodes_token)?;
+
+    // Generate field descriptions for enumeration
+    let (opcode_field_descs_tokens, opcode_field_descs_static) = generate_opcode_field_descs(isa, &num_opcodes_token)?;
+    let ins_field_descs_tokens = generate_ins_field_descs(isa)?;
+
     // Generate parse functions
     let parse_functions = generate_parse_functions(isa, isa_args, max_args, &isa.opcodes, &num_opcodes_token)?;
 
+    // Generate encoder
+    let (opcode_encode_tokens, encode_functions) = generate_asm(isa, isa_args, &num_opcodes_token)?;
+
     Ok(quote! {
         #![cfg_attr(rustfmt, rustfmt_skip)]
         #![allow(unused)]
-        #![allow(clippy::double_parens, clippy::unnecessary_cast)]
+        #![allow(clippy::double_parens, clippy::unnecessary_cast, clippy::match_like_matches_macro, clippy::collapsible_match)]
         #[comment = " Generated by unarm-generator. Do not edit!"]
 
-        use crate::{ParseFlags, args::*, parse::ParsedIns};
+        use crate::{ParseFlags, args::*, encode::{self, EncodeCase, EncodeError, EncodeField, EncodeForm, FieldError}, enumerate::{self, FieldDesc, FieldFilter}, parse::{ArgMeta, Condition, ParsedIns}};
         use super::Ins;
 
         #[doc = " These are the mnemonics of each opcode. Some mnemonics are duplicated due to them having multiple formats."]
         static OPCODE_MNEMONICS: [&str; #num_opcodes_token] = [#opcode_mnemonics_tokens];
 
-        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-        #[repr(u8)]
+        #opcode_variant_names_static
+
+        #opcode_arg_meta_static
+
+        #opcode_field_descs_static
+
+        #opcode_find_table_static
+
+        #max_lengths_tokens
+
+        #unconditional_mnemonics_tokens
+
+        #mnemonics_tokens
+
+        #opcode_search_statics
+
+        #[doc = " Opcode of an instruction. The discriminants, such as `op as u8`, change whenever opcodes are added or"]
+        #[doc = " reordered, so use [`Opcode::id`] or [`Opcode::variant_name`] to store an opcode."]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(#opcode_repr)]
         #[non_exhaustive]
         pub enum Opcode {
             #[doc = " Illegal or unknown"]
             #[default]
-            Illegal = u8::MAX,
+            Illegal = #opcode_repr::MAX,
             #opcode_enum_tokens
         }
         impl Opcode {
             #opcode_find_tokens
+            #opcode_find_all_tokens
             pub fn mnemonic(self) -> &'static str {
                 OPCODE_MNEMONICS[self as usize]
             }
             pub fn count() -> usize {
                 #num_opcodes_token
             }
+            #opcode_is_alias_tokens
+            #opcode_variant_names_tokens
+            #opcode_categories_tokens
+            #opcode_arg_meta_tokens
+            #opcode_field_descs_tokens
+            #opcode_search_tokens
+            #opcode_encode_tokens
         }
 
         impl Ins {
             #field_accessors_tokens
             #modifier_accessors_tokens
+            #ins_field_descs_tokens
+            #condition_tokens
+            #unpredictable_tokens
         }
 
         #case_enums_tokens
 
         #parse_functions
+
+        #encode_functions
     })
 }
 
@@ -91,23 +204,36 @@ fn generate_search_node(node: Option<Box<SearchTree>>, opcodes: &mut Vec<Opcode>
         let pattern_token = HexLiteral(node.left_pattern);
 
         let (mut left, mut right) = node.filter(opcodes);
+        let right_is_leaf = node.right.is_none();
         let left_node = generate_search_node(node.left, &mut left);
         let right_node = generate_search_node(node.right, &mut right);
 
-        let body = quote! {
-            if (code & #bitmask_token) == #pattern_token {
-                #left_node
-            } else #right_node
+        let body = if right_is_leaf && right.iter().any(|op| op.extension.is_some()) {
+            // Checks for extension opcodes are separate statements, so they can't be chained with `else`
+            quote! {
+                if (code & #bitmask_token) == #pattern_token {
+                    #left_node
+                } else {
+                    #right_node
+                }
+            }
+        } else {
+            quote! {
+                if (code & #bitmask_token) == #pattern_token {
+                    #left_node
+                } else #right_node
+            }
         };
         body
     } else {
         // When bitmask A is a subset of B, then B must be first, otherwise we might never choose B
-        opcodes.sort_unstable_by_key(|op| 32 - op.bitmask.count_ones());
+        opcodes.sort_by_key(|op| 32 - op.bitmask.count_ones());
         let opcode_checks = opcodes.iter().map(|op| {
             let bitmask_token = HexLiteral(op.bitmask);
             let pattern_token = HexLiteral(op.pattern);
             let variant_token = Ident::new(&op.enum_name(), Span::call_site());
 
+            let cfg = opcode_cfg(op);
             let code_mask = if op.bitmask != 0xffffffff {
                 quote! { (code & #bitmask_token) }
             } else {
@@ -116,6 +242,7 @@ fn generate_search_node(node: Option<Box<SearchTree>>, opcodes: &mut Vec<Opcode>
 
             if op.flags.is_empty() {
                 quote! {
+                    #cfg
                     if #code_mask == #pattern_token {
                         return Opcode::#variant_token;
                     }
@@ -124,21 +251,411 @@ fn generate_search_node(node: Option<Box<SearchTree>>, opcodes: &mut Vec<Opcode>
                 let flags_checks = op.flags.iter().map(|f| match f {
                     Flag::Ual(true) => quote! { flags.ual },
                     Flag::Ual(false) => quote! { !flags.ual },
+                    Flag::Vfp(true) => quote! { flags.vfp },
+                    Flag::Vfp(false) => quote! { !flags.vfp },
                 });
                 let flags_expr = quote! {
                     #(#flags_checks)&&*
                 };
                 quote! {
+                    #cfg
                     if #flags_expr && #code_mask == #pattern_token {
                         return Opcode::#variant_token;
                     }
                 }
             }
         });
+        if opcodes.iter().any(|op| op.extension.is_some()) {
+            // Every check returns, so they can be separate statements which may be removed by `#[cfg]`
+            quote! {
+                #(#opcode_checks)*
+            }
+        } else {
+            quote! {
+                #(#opcode_checks)else*
+            }
+        }
+    }
+}
+
+/// Generates `Opcode::find_table` and its lookup table
+fn generate_find_table(table: &FindTable) -> (TokenStream, TokenStream) {
+    let num_entries = Literal::usize_unsuffixed(table.entries.len());
+    let entries = table.entries.iter().map(|(fallback, group)| {
+        let variant = match fallback {
+            Some(opcode) => Ident::new(&opcode.enum_name(), Span::call_site()),
+            None => Ident::new("Illegal", Span::call_site()),
+        };
+        let group = Literal::u8_unsuffixed(*group as u8);
+        quote! { (Opcode::#variant, #group) }
+    });
+    let doc = format!(
+        " Maps the bits {:#010x} of an instruction to its opcode, unless the residual group checks another",
+        table.key_mask
+    );
+    let static_tokens = quote! {
+        #[doc = #doc]
+        #[doc = " opcode first. See [`Opcode::find_table`]."]
+        static FIND_TABLE: [(Opcode, u8); #num_entries] = [#(#entries),*];
+    };
+
+    let key = table.key_runs().into_iter().map(|(shift, mask)| {
+        let mask = HexLiteral(mask);
+        if shift == 0 {
+            quote! { (code & #mask) }
+        } else {
+            let shift = Literal::u32_unsuffixed(shift);
+            quote! { ((code >> #shift) & #mask) }
+        }
+    });
+    let arms = table.groups.iter().enumerate().skip(1).map(|(index, group)| {
+        let index = Literal::u8_unsuffixed(index as u8);
+        let checks = group.iter().map(|op| {
+            let bitmask = op.bitmask & !table.key_mask;
+            let bitmask_token = HexLiteral(bitmask);
+            let pattern_token = HexLiteral(op.pattern & bitmask);
+            let variant_token = Ident::new(&op.enum_name(), Span::call_site());
+            let cfg = opcode_cfg(op);
+            let mut conditions = op
+                .flags
+                .iter()
+                .map(|f| match f {
+                    Flag::Ual(true) => quote! { flags.ual },
+                    Flag::Ual(false) => quote! { !flags.ual },
+                    Flag::Vfp(true) => quote! { flags.vfp },
+                    Flag::Vfp(false) => quote! { !flags.vfp },
+                })
+                .collect::<Vec<_>>();
+            if bitmask != 0 {
+                conditions.push(quote! { (code & #bitmask_token) == #pattern_token });
+            }
+            if conditions.is_empty() {
+                // Otherwise it would be the fallback opcode, so it belongs to an extension
+                return quote! {
+                    #cfg
+                    return Opcode::#variant_token;
+                };
+            }
+            quote! {
+                #cfg
+                if #(#conditions)&&* {
+                    return Opcode::#variant_token;
+                }
+            }
+        });
         quote! {
-            #(#opcode_checks)else*
+            #index => {
+                #(#checks)*
+            }
+        }
+    });
+    let method_tokens = quote! {
+        #[doc = " Implementation of [`Self::find`] as a lookup table. Public to test that it's equivalent to"]
+        #[doc = " [`Self::find_tree`], whichever one the profile uses."]
+        #[doc(hidden)]
+        #[inline]
+        pub const fn find_table(code: u32, flags: &ParseFlags) -> Self {
+            let key = #(#key)|*;
+            let (opcode, group) = FIND_TABLE[key as usize];
+            match group {
+                #(#arms)*
+                _ => {}
+            }
+            opcode
+        }
+    };
+    (method_tokens, static_tokens)
+}
+
+fn generate_max_lengths(isa: &Isa, isa_args: &IsaArgs) -> Result<TokenStream> {
+    let illegal_len = "<illegal>".len();
+    let mut max_mnemonic_len = illegal_len;
+    let mut max_rendered_len = illegal_len;
+    for opcode in isa.opcodes.iter() {
+        for ual in [false, true] {
+            let ual = opcode.parsed_syntax(isa, ual)?;
+            let opcode_args = opcode.get_args(isa, ual)?;
+            for cases in opcode.get_case_combinations(isa, ual)? {
+                let mnemonic_len = opcode.mnemonic(&cases, ual).len();
+                max_mnemonic_len = max_mnemonic_len.max(mnemonic_len);
+
+                let mut fields = opcode_args.clone();
+                for arg in cases.iter().flat_map(|case| case.args.iter()) {
+                    fields.push(isa.get_field(arg)?);
+                }
+                let mut rendered_len = mnemonic_len;
+                if !fields.is_empty() {
+                    // Space after the mnemonic and commas between arguments
+                    rendered_len += 1 + 2 * (fields.len() - 1);
+                }
+                for field in fields.iter() {
+                    rendered_len += isa_args.get_arg(&field.arg)?.max_len;
+                }
+                if fields.iter().any(|field| field.can_deref()) {
+                    rendered_len += "[]".len();
+                }
+                max_rendered_len = max_rendered_len.max(rendered_len);
+            }
         }
     }
+
+    let max_mnemonic_len = Literal::usize_unsuffixed(max_mnemonic_len);
+    let max_rendered_len = Literal::usize_unsuffixed(max_rendered_len);
+    Ok(quote! {
+        #[doc = " Length of the longest mnemonic, including `<illegal>`."]
+        pub const MAX_MNEMONIC_LEN: usize = #max_mnemonic_len;
+        #[doc = " Upper bound of the length of a rendered instruction. This holds for any [`crate::DisplayOptions`] without a"]
+        #[doc = " mnemonic hook, as no register name is longer than the default ones."]
+        pub const MAX_RENDERED_LEN: usize = #max_rendered_len;
+    })
+}
+
+fn generate_mnemonics(isa: &Isa) -> Result<TokenStream> {
+    let mut mnemonics = BTreeSet::from(["<illegal>".to_string()]);
+    for opcode in isa.opcodes.iter() {
+        for ual in [false, true] {
+            let ual = opcode.parsed_syntax(isa, ual)?;
+            for cases in opcode.get_case_combinations(isa, ual)? {
+                mnemonics.insert(opcode.mnemonic(&cases, ual));
+            }
+        }
+    }
+    let num_mnemonics = Literal::usize_unsuffixed(mnemonics.len());
+    let mnemonics = mnemonics.iter();
+    Ok(quote! {
+        #[doc = " Every mnemonic in both syntaxes, sorted. Used to parse and deserialize [`ParsedIns::mnemonic`]."]
+        pub(crate) static MNEMONICS: [&str; #num_mnemonics] = [#(#mnemonics),*];
+    })
+}
+
+fn generate_opcode_find_all(opcodes: &[Opcode]) -> TokenStream {
+    let mut opcodes = opcodes.to_vec();
+    opcodes.sort_by_key(|op| 32 - op.bitmask.count_ones());
+    let opcode_checks = opcodes.iter().map(|op| {
+        let bitmask_token = HexLiteral(op.bitmask);
+        let pattern_token = HexLiteral(op.pattern);
+        let variant_token = Ident::new(&op.enum_name(), Span::call_site());
+        let cfg = opcode_cfg(op);
+        let code_mask = if op.bitmask != 0xffffffff {
+            quote! { (code & #bitmask_token) }
+        } else {
+            quote! { code }
+        };
+        let flags_checks = op.flags.iter().map(|f| match f {
+            Flag::Ual(true) => quote! { flags.ual && },
+            Flag::Ual(false) => quote! { !flags.ual && },
+            Flag::Vfp(true) => quote! { flags.vfp && },
+            Flag::Vfp(false) => quote! { !flags.vfp && },
+        });
+        quote! {
+            #cfg
+            if #(#flags_checks)* #code_mask == #pattern_token && found != Opcode::#variant_token {
+                all.push(Opcode::#variant_token);
+            }
+        }
+    });
+    quote! {
+        #[doc = " Returns every opcode whose bitmask and pattern match the code, starting with the result of [`Self::find`]."]
+        #[doc = " The rest are ordered from most to least specific bitmask. This is meant for debugging the ISA"]
+        #[doc = " definitions, as it checks every opcode one by one."]
+        pub fn find_all(code: u32, flags: &ParseFlags) -> impl Iterator<Item = Self> {
+            let found = Self::find(code, flags);
+            let mut all = vec![];
+            if found != Opcode::Illegal {
+                all.push(found);
+            }
+            #(#opcode_checks)*
+            all.into_iter()
+        }
+    }
+}
+
+/// Name of the modifier which holds the condition code
+const COND_MODIFIER: &str = "cond";
+
+/// Generates `Ins::condition` and a sorted list of every mnemonic without a condition suffix, which is used to strip
+/// condition suffixes from mnemonics
+fn generate_condition(isa: &Isa) -> Result<(TokenStream, TokenStream)> {
+    let mut arms = TokenStream::new();
+    let mut mnemonics = BTreeSet::new();
+    for opcode in isa.opcodes.iter() {
+        mnemonics.extend(unconditional_mnemonics(isa, opcode)?);
+
+        let variant_token = Ident::new(&opcode.enum_name(), Span::call_site());
+        let cfg = opcode_cfg(opcode);
+        if opcode.has_modifier(COND_MODIFIER) {
+            arms.extend(quote! {
+                #cfg
+                Opcode::#variant_token => self.modifier_cond_condition(),
+            });
+        } else if opcode.bitmask & 0xf0000000 == 0xf0000000 && opcode.pattern & 0xf0000000 == 0xf0000000 {
+            // Unconditional instruction space of the ARM instruction set
+            arms.extend(quote! {
+                #cfg
+                Opcode::#variant_token => Condition::Nv,
+            });
+        }
+    }
+
+    let cond_accessor = match isa.modifiers.iter().find(|m| m.name == COND_MODIFIER) {
+        Some(modifier) => {
+            let enum_ident = Ident::new(&modifier.enum_name(), Span::call_site());
+            let cases = modifier.get_cases()?;
+            let case_arms = cases.iter().map(|case| {
+                let variant_ident = Ident::new(&case.variant_name(), Span::call_site());
+                quote! { #enum_ident::#variant_ident => Condition::#variant_ident, }
+            });
+            quote! {
+                #[inline(always)]
+                fn modifier_cond_condition(&self) -> Condition {
+                    match self.modifier_cond() {
+                        #(#case_arms)*
+                        #enum_ident::Illegal => Condition::Nv,
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let condition_tokens = quote! {
+        #cond_accessor
+        #[doc = " Returns the condition under which the instruction is executed. Instructions without a condition code return"]
+        #[doc = " [`Condition::Al`], except for those in the unconditional instruction space which return [`Condition::Nv`]."]
+        pub fn condition(&self) -> Condition {
+            match self.op {
+                #arms
+                _ => Condition::Al,
+            }
+        }
+    };
+
+    let num_mnemonics = Literal::usize_unsuffixed(mnemonics.len());
+    let mnemonics = mnemonics.iter();
+    let unconditional_mnemonics_tokens = quote! {
+        #[doc = " Every mnemonic without a condition suffix, sorted."]
+        pub(crate) static UNCONDITIONAL_MNEMONICS: [&str; #num_mnemonics] = [#(#mnemonics),*];
+    };
+    Ok((condition_tokens, unconditional_mnemonics_tokens))
+}
+
+/// Returns every mnemonic of `opcode` without a condition suffix, in both syntaxes
+pub fn unconditional_mnemonics(isa: &Isa, opcode: &Opcode) -> Result<BTreeSet<String>> {
+    let mut mnemonics = BTreeSet::new();
+    for ual in [false, true] {
+        let ual = opcode.parsed_syntax(isa, ual)?;
+        let cond_index = opcode.get_modifiers(isa, ual)?.iter().position(|m| m.name == COND_MODIFIER);
+        for cases in opcode.get_case_combinations(isa, ual)? {
+            if cond_index.is_none_or(|index| cases[index].name == "al") {
+                mnemonics.insert(opcode.mnemonic(&cases, ual));
+            }
+        }
+    }
+    Ok(mnemonics)
+}
+
+fn generate_opcode_search(isa: &Isa, num_opcodes_token: &Literal) -> Result<(TokenStream, TokenStream)> {
+    let descriptions = isa.opcodes.iter().map(|opcode| &opcode.desc);
+    let spellings = isa
+        .opcodes
+        .iter()
+        .map(|opcode| {
+            let variant_token = Ident::new(&opcode.enum_name(), Span::call_site());
+            let cfg = opcode_cfg(opcode);
+            let mnemonics = unconditional_mnemonics(isa, opcode)?;
+            let mnemonics = mnemonics.iter();
+            Ok(quote! { #cfg (Opcode::#variant_token, &[#(#mnemonics),*]) })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let statics = quote! {
+        #[doc = " One-line description of each opcode."]
+        static OPCODE_DESCRIPTIONS: [&str; #num_opcodes_token] = [#(#descriptions),*];
+        #[doc = " Every mnemonic of each opcode without a condition suffix, in both syntaxes."]
+        static OPCODE_SPELLINGS: &[(Opcode, &[&str])] = &[#(#spellings),*];
+    };
+    let methods = quote! {
+        #[doc = " Returns a one-line description of this opcode, e.g. \"Add with Carry\"."]
+        pub fn describe(self) -> &'static str {
+            if self == Opcode::Illegal {
+                return "Illegal or unknown";
+            }
+            OPCODE_DESCRIPTIONS[self as usize]
+        }
+        #[doc = " Finds the opcodes whose mnemonics best match `query`, with the best match first. Each mnemonic is matched with"]
+        #[doc = " all of its suffixes except conditions, e.g. `ldmdb` and its stack alias `ldmea`. See [`crate::fuzzy_score`] for"]
+        #[doc = " how matches are scored."]
+        pub fn fuzzy_search(query: &str, limit: usize) -> Vec<(Self, u32)> {
+            crate::search::fuzzy_search(query, limit, OPCODE_SPELLINGS.iter().copied())
+        }
+        #[doc = " Returns the opcodes which have `mnemonic` as one of their mnemonics without a condition suffix, in either"]
+        #[doc = " syntax."]
+        pub fn from_mnemonic(mnemonic: &str) -> impl Iterator<Item = Self> + '_ {
+            OPCODE_SPELLINGS
+                .iter()
+                .filter(move |(_, spellings)| spellings.contains(&mnemonic))
+                .map(|(opcode, _)| *opcode)
+        }
+    };
+    Ok((methods, statics))
+}
+
+fn generate_unpredictable(isa: &Isa) -> Result<TokenStream> {
+    let mut arms = TokenStream::new();
+    for opcode in isa.opcodes.iter().filter(|op| !op.unpredictable.is_empty()) {
+        let checks = opcode
+            .unpredictable
+            .iter()
+            .map(|constraint| {
+                let mut expr = syn::parse_str(&constraint.expr)?;
+                FoldFieldExpr.visit_expr_mut(&mut expr);
+                let desc = &constraint.desc;
+                Ok(quote! {
+                    if #expr {
+                        return Some(#desc);
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let variant_token = Ident::new(&opcode.enum_name(), Span::call_site());
+        let cfg = opcode_cfg(opcode);
+        arms.extend(quote! {
+            #cfg
+            Opcode::#variant_token => {
+                #(#checks)*
+            }
+        });
+    }
+    let body = if arms.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            match self.op {
+                #arms
+                _ => {}
+            }
+        }
+    };
+    Ok(quote! {
+        #[doc = " Returns a description of why the encoding is UNPREDICTABLE, or `None` if it's not. Only the constraints in the"]
+        #[doc = " ISA specification are checked, so this is not exhaustive."]
+        pub const fn unpredictable_reason(&self) -> Option<&'static str> {
+            #body
+            None
+        }
+        #[doc = " Returns true if the encoding is UNPREDICTABLE, see [`Self::unpredictable_reason`]."]
+        pub const fn is_unpredictable(&self) -> bool {
+            self.unpredictable_reason().is_some()
+        }
+    })
+}
+
+/// Returns a `#[cfg]` attribute which removes the opcode if its extension is disabled
+pub fn opcode_cfg(opcode: &Opcode) -> TokenStream {
+    match opcode.feature() {
+        Some(feature) => quote! { #[cfg(feature = #feature)] },
+        None => quote! {},
+    }
 }
 
 fn illegal_ins(max_args: usize) -> TokenStream {
@@ -179,27 +696,88 @@ fn generate_parse_functions(
         };
 
         let parse_fn = Ident::new(&opcode.parser_name(), Span::call_site());
+        let cfg = opcode_cfg(opcode);
         parse_functions.extend(quote! {
+            #cfg
+            #[inline]
             fn #parse_fn(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
-                #parse_body
+                let guard = crate::no_panic::NoPanicGuard;
+                #parse_body;
+                guard.disarm();
             }
         })
     }
-    let parser_fns = sorted_opcodes
-        .iter()
-        .map(|op| Ident::new(&op.parser_name(), Span::call_site()));
     let illegal_ins = illegal_ins(max_args);
+    let parser_fns = sorted_opcodes.iter().map(|op| {
+        let parse_fn = Ident::new(&op.parser_name(), Span::call_site());
+        if let Some(feature) = op.feature() {
+            quote! {
+                #[cfg(feature = #feature)] #parse_fn,
+                #[cfg(not(feature = #feature))] parse_illegal
+            }
+        } else {
+            quote! { #parse_fn }
+        }
+    });
+    if sorted_opcodes.iter().any(|op| op.extension.is_some()) {
+        parse_functions.extend(quote! {
+            fn parse_illegal(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+                *out = #illegal_ins;
+            }
+        });
+    }
+    let match_arms = isa.opcodes.iter().map(|op| {
+        let parse_fn = Ident::new(&op.parser_name(), Span::call_site());
+        let variant = Ident::new(&op.enum_name(), Span::call_site());
+        let cfg = opcode_cfg(op);
+        quote! { #cfg Opcode::#variant => #parse_fn(out, ins, flags), }
+    });
+    let parse_fns = Profile::ALL.iter().map(|profile| {
+        let cfg = profile.cfg();
+        let (doc, parse_impl) = match profile.parse() {
+            ParseImpl::Table => (
+                " Parses the instruction with a table of parse functions.",
+                quote! { parse_table(out, ins, flags) },
+            ),
+            ParseImpl::Match => (
+                " Parses the instruction with a match on the opcode.",
+                quote! { parse_match(out, ins, flags) },
+            ),
+        };
+        quote! {
+            #[doc = #doc]
+            #cfg
+            #[inline]
+            pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+                #parse_impl
+            }
+        }
+    });
     parse_functions.extend(quote! {
+        #(#parse_fns)*
         type MnemonicParser = fn(&mut ParsedIns, Ins, &ParseFlags);
         static MNEMONIC_PARSERS: [MnemonicParser; #num_opcodes_token] = [
             #(#parser_fns),*
         ];
+        #[doc = " Implementation of [`parse`] as a call through a table of parse functions, indexed by the opcode. Public"]
+        #[doc = " to test that it's equivalent to [`parse_match`], whichever one the profile uses."]
+        #[doc(hidden)]
         #[inline]
-        pub fn parse(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
-            if ins.op != Opcode::Illegal {
-                MNEMONIC_PARSERS[ins.op as usize](out, ins, flags);
-            } else {
-                *out = #illegal_ins;
+        pub fn parse_table(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+            // `Opcode::Illegal` is the maximum value, so it's the only opcode without a parser
+            match MNEMONIC_PARSERS.get(ins.op as usize) {
+                Some(parser) => parser(out, ins, flags),
+                None => *out = #illegal_ins,
+            }
+        }
+        #[doc = " Implementation of [`parse`] as a match on the opcode, which inlines the parse function of each opcode."]
+        #[doc = " Public to test that it's equivalent to [`parse_table`], whichever one the profile uses."]
+        #[doc(hidden)]
+        pub fn parse_match(out: &mut ParsedIns, ins: Ins, flags: &ParseFlags) {
+            match ins.op {
+                #(#match_arms)*
+                // `Opcode::Illegal` and the opcodes of disabled extensions
+                _ => *out = #illegal_ins,
             }
         }
     });
@@ -225,20 +803,7 @@ fn generate_instruction_parse_body(
         .collect();
     let modifier_values = modifier_values?;
 
-    let opcode_args = opcode
-        .args
-        .iter()
-        .filter_map(|arg| {
-            let field = isa.get_field(arg);
-            let Ok(field) = field else { return Some(field) };
-            match (field.ual_flag(), ual) {
-                (None, _) => Some(Ok(field)),
-                // Remove fields that only belong in unified/divided syntax but not both
-                (Some(a), b) if a == b => Some(Ok(field)),
-                _ => None,
-            }
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let opcode_args = opcode.get_args(isa, ual)?;
     let modifier_cases = opcode.get_modifier_cases(isa, ual)?;
 
     let body = {
@@ -267,13 +832,7 @@ fn generate_instruction_parse_body(
                         quote! { #enum_name::#variant_name }
                     }
                 });
-                let suffix = cases.iter().map(|case| case.suffix(ual)).collect::<String>();
-                let opcode_suffix = opcode.suffix.as_ref().map_or("", |s| s.suffix(ual));
-                let mnemonic = if ual {
-                    opcode.base_name().to_string() + opcode_suffix + &suffix
-                } else {
-                    opcode.base_name().to_string() + &suffix + opcode_suffix
-                };
+                let mnemonic = opcode.mnemonic(&cases, ual);
                 let case_args = {
                     let mut case_args = opcode_args.clone();
                     for case in cases.iter() {
@@ -430,10 +989,13 @@ fn generate_modifier_accessors(isa: &Isa) -> Result<TokenStream> {
         };
 
         let doc = modifier.doc();
+        let bits_doc = bits_doc(modifier_bitmask(modifier)?);
         let fn_name = Ident::new(&modifier.accessor_name(), Span::call_site());
 
         modifier_accessors_tokens.extend(quote! {
             #[doc = #doc]
+            #[doc = ""]
+            #[doc = #bits_doc]
             #[inline(always)]
             pub const fn #fn_name(&self) -> #ret_type {
                 #inner
@@ -443,6 +1005,22 @@ fn generate_modifier_accessors(isa: &Isa) -> Result<TokenStream> {
     Ok(modifier_accessors_tokens)
 }
 
+/// Returns the bits which select the case of a modifier, excluding the fields of the cases
+fn modifier_bitmask(modifier: &Modifier) -> Result<u32> {
+    Ok(modifier
+        .get_cases()?
+        .iter()
+        .fold(0, |acc, case| acc | case.bitmask.unwrap_or(0)))
+}
+
+/// Returns the doc line of an accessor which names the bits it reads
+fn bits_doc(bitmask: u32) -> String {
+    match bitmask {
+        0 => " Reads no bits of the instruction.".to_string(),
+        bitmask => format!(" Reads {} of the instruction.", describe_bitmask(bitmask)),
+    }
+}
+
 fn generate_modifier_case_enums(isa: &Isa) -> TokenStream {
     let mut case_enums_tokens = TokenStream::new();
     for modifier in isa.modifiers.iter() {
@@ -462,7 +1040,7 @@ fn generate_modifier_case_enums(isa: &Isa) -> TokenStream {
             let doc = modifier.doc();
             case_enums_tokens.extend(quote! {
                 #[doc = #doc]
-                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
                 pub enum #enum_ident {
                     Illegal,
                     #variants_tokens
@@ -513,8 +1091,11 @@ fn generate_field_accessors(isa: &Isa, isa_args: &IsaArgs) -> Result<TokenStream
                                 }
                                 ArgType::Custom(custom_name) => {
                                     let custom_type = isa_args.get_type(custom_name)?;
+                                    let TypeKind::Enum(enum_values) = &custom_type.r#type else {
+                                        bail!("Nested structs (in argument '{}') are not supported", arg.name);
+                                    };
                                     let custom_ident = Ident::new(&custom_type.pascal_case_name(), Span::call_site());
-                                    quote! { #custom_ident::parse(#expr) }
+                                    generate_enum_parse(&custom_ident, enum_values, value, field, expr)?
                                 }
                             };
 
@@ -531,10 +1112,10 @@ fn generate_field_accessors(isa: &Isa, isa_args: &IsaArgs) -> Result<TokenStream
                         }
                     }
                 }
-                ArgType::Enum(_) => {
+                ArgType::Enum(enum_values) => {
                     let enum_ident = Ident::new(&arg.pascal_case_name(), Span::call_site());
                     let expr = generate_argument_expr(&field.value, field)?;
-                    quote! { #enum_ident::parse(#expr) }
+                    generate_enum_parse(&enum_ident, enum_values, &field.value, field, expr)?
                 }
                 ArgType::U32 => generate_argument_expr(&field.value, field)?,
                 ArgType::I32 => {
@@ -544,9 +1125,12 @@ fn generate_field_accessors(isa: &Isa, isa_args: &IsaArgs) -> Result<TokenStream
                 ArgType::Bool => generate_argument_expr(&field.value, field)?,
                 ArgType::Custom(custom_name) => {
                     let custom_type = isa_args.get_type(custom_name)?;
+                    let TypeKind::Enum(enum_values) = &custom_type.r#type else {
+                        bail!("Value of field '{}' can't be a struct type", field.name);
+                    };
                     let custom_ident = Ident::new(&custom_type.pascal_case_name(), Span::call_site());
                     let expr = generate_argument_expr(&field.value, field)?;
-                    quote! { #custom_ident::parse(#expr) }
+                    generate_enum_parse(&custom_ident, enum_values, &field.value, field, expr)?
                 }
             };
 
@@ -561,9 +1145,12 @@ fn generate_field_accessors(isa: &Isa, isa_args: &IsaArgs) -> Result<TokenStream
             };
 
             let doc = field.doc();
+            let bits_doc = bits_doc(field.get_bitmask()?);
             let accessor_ident = Ident::new(&field.accessor_name(), Span::call_site());
             Ok(quote! {
                 #[doc = #doc]
+                #[doc = ""]
+                #[doc = #bits_doc]
                 #[inline(always)]
                 pub fn #accessor_ident(&self) -> #return_type {
                     #body
@@ -577,6 +1164,44 @@ fn generate_field_accessors(isa: &Isa, isa_args: &IsaArgs) -> Result<TokenStream
     })
 }
 
+/// Generates a call to `parse` of an enum type. Bit ranges must be narrow enough that every value has a variant and
+/// constants must have a variant, which is checked here. Values of other expressions are checked by a debug assertion
+/// instead.
+fn generate_enum_parse(
+    enum_ident: &Ident,
+    enum_values: &[EnumValue],
+    value: &FieldValue,
+    field: &Field,
+    expr: TokenStream,
+) -> Result<TokenStream> {
+    let has_variant = |v: u32| enum_values.iter().any(|e| e.value == v);
+    match value {
+        FieldValue::Bits(range) => {
+            let width = range.0.end - range.0.start;
+            if let Some(v) = (0..1u32 << width).find(|&v| !has_variant(v)) {
+                bail!(
+                    "Field '{}' can have the value {v}, which is not a valid {enum_ident}",
+                    field.name
+                );
+            }
+            Ok(quote! { #enum_ident::parse(#expr) })
+        }
+        FieldValue::U32(v) => {
+            if !has_variant(*v) {
+                bail!("Field '{}' has the value {v}, which is not a valid {enum_ident}", field.name);
+            }
+            Ok(quote! { #enum_ident::parse(#expr) })
+        }
+        _ => Ok(quote! {
+            {
+                let value = #expr;
+                debug_assert!(#enum_ident::try_parse(value).is_some());
+                #enum_ident::parse(value)
+            }
+        }),
+    }
+}
+
 struct FoldFieldExpr;
 
 impl VisitMut for FoldFieldExpr {
@@ -696,21 +1321,587 @@ fn generate_argument_expr(value: &FieldValue, field: &Field) -> Result<TokenStre
     Ok(expr)
 }
 
-fn generate_opcode_tokens(sorted_opcodes: &[Opcode]) -> (TokenStream, TokenStream, Literal) {
+fn generate_opcode_tokens(isa: &Isa) -> Result<(TokenStream, TokenStream, Literal)> {
     let mut opcode_enum_tokens = TokenStream::new();
     let mut opcode_mnemonics_tokens = TokenStream::new();
-    let num_opcodes_token = Literal::usize_unsuffixed(sorted_opcodes.len());
-    for (i, opcode) in sorted_opcodes.iter().enumerate() {
+    let num_opcodes_token = Literal::usize_unsuffixed(isa.opcodes.len());
+    for (i, opcode) in isa.opcodes.iter().enumerate() {
         let name = &opcode.name(true);
         opcode_mnemonics_tokens.extend(quote! { #name, });
 
         let enum_name = Ident::new(&opcode.enum_name(), Span::call_site());
-        let enum_value = Literal::u8_unsuffixed(i.try_into().unwrap());
+        let enum_value = Literal::usize_unsuffixed(i);
         let doc = opcode.doc(true);
+        let [ranges, labels] = encoding_diagram(isa, opcode)
+            .with_context(|| format!("While generating encoding diagram of opcode '{}'", opcode.raw_name()))?;
+        let diagram = [
+            String::new(),
+            " ```text".to_string(),
+            format!(" {ranges}"),
+            format!(" {labels}"),
+            " ```".to_string(),
+        ];
+        let cfg = opcode_cfg(opcode);
+        let old_names = isa.old_names(opcode);
         opcode_enum_tokens.extend(quote! {
             #[doc = #doc]
+            #(#[doc = #diagram])*
+            #(#[doc(alias = #old_names)])*
+            #cfg
             #enum_name = #enum_value,
         });
     }
-    (opcode_enum_tokens, opcode_mnemonics_tokens, num_opcodes_token)
+    Ok((opcode_enum_tokens, opcode_mnemonics_tokens, num_opcodes_token))
+}
+
+fn generate_opcode_variant_names(isa: &Isa, num_opcodes_token: &Literal) -> (TokenStream, TokenStream) {
+    let names = isa.opcodes.iter().map(|op| op.enum_name()).collect::<Vec<_>>();
+    let arms = isa.opcodes.iter().map(|op| {
+        let variant = Ident::new(&op.enum_name(), Span::call_site());
+        let names = std::iter::once(op.enum_name()).chain(isa.old_names(op));
+        let cfg = opcode_cfg(op);
+        quote! {
+            #cfg
+            #(#names)|* => Some(Opcode::#variant),
+        }
+    });
+    let methods = quote! {
+        #[doc = " Returns the name of this variant, e.g. `LdrB`. Unlike the mnemonic, it tells apart opcodes with the same"]
+        #[doc = " mnemonic. Variant names are kept stable, see `renames` in the ISA files."]
+        pub fn variant_name(self) -> &'static str {
+            if self == Opcode::Illegal {
+                "Illegal"
+            } else {
+                OPCODE_VARIANT_NAMES[self as usize]
+            }
+        }
+        #[doc = " Returns the opcode with the given variant name. Old names of renamed variants are also accepted, so that"]
+        #[doc = " names logged by earlier versions can be read back."]
+        pub fn from_variant_name(name: &str) -> Option<Self> {
+            match name {
+                "Illegal" => Some(Opcode::Illegal),
+                #(#arms)*
+                _ => None,
+            }
+        }
+    };
+    let ids = isa.opcodes.iter().map(|op| op.ident_name()).collect::<Vec<_>>();
+    // Sorted by id for `from_id`, including the ids of old variant names
+    let mut sorted_ids = isa
+        .opcodes
+        .iter()
+        .flat_map(|op| {
+            let variant = Ident::new(&op.enum_name(), Span::call_site());
+            let cfg = opcode_cfg(op);
+            let old_ids = isa.old_names(op).into_iter().map(|name| snake_case(&name));
+            std::iter::once(op.ident_name())
+                .chain(old_ids)
+                .map(move |id| (id.clone(), quote! { #cfg (#id, Opcode::#variant) }))
+        })
+        .chain(std::iter::once((
+            "illegal".to_string(),
+            quote! { ("illegal", Opcode::Illegal) },
+        )))
+        .collect::<Vec<_>>();
+    sorted_ids.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let sorted_ids = sorted_ids.into_iter().map(|(_, tokens)| tokens);
+    let methods = quote! {
+        #methods
+        #[doc = " Returns the stable id of this opcode, e.g. `ldr_b`, which is the name of its entry in the ISA file. Unlike"]
+        #[doc = " the discriminant, which changes when opcodes are added or reordered, ids are kept stable like variant"]
+        #[doc = " names, so they can key on-disk caches."]
+        pub fn id(self) -> &'static str {
+            if self == Opcode::Illegal {
+                "illegal"
+            } else {
+                OPCODE_IDS[self as usize]
+            }
+        }
+        #[doc = " Returns the opcode with the given [`id`](Self::id). Ids of renamed variants are also accepted."]
+        pub fn from_id(id: &str) -> Option<Self> {
+            OPCODES_BY_ID
+                .binary_search_by_key(&id, |&(id, _)| id)
+                .ok()
+                .map(|index| OPCODES_BY_ID[index].1)
+        }
+    };
+    let names_static = quote! {
+        #[doc = " These are the names of each opcode variant, see [`Opcode::variant_name`]."]
+        static OPCODE_VARIANT_NAMES: [&str; #num_opcodes_token] = [#(#names),*];
+        #[doc = " These are the ids of each opcode, see [`Opcode::id`]."]
+        static OPCODE_IDS: [&str; #num_opcodes_token] = [#(#ids),*];
+        #[doc = " Opcodes sorted by id, see [`Opcode::from_id`]."]
+        static OPCODES_BY_ID: &[(&str, Opcode)] = &[#(#sorted_ids),*];
+    };
+    (methods, names_static)
+}
+
+fn generate_opcode_is_alias(opcodes: &[Opcode]) -> TokenStream {
+    generate_opcode_predicate(
+        opcodes,
+        "is_alias",
+        &[
+            "Returns whether this opcode is a unified syntax (UAL) alias of another opcode, such as PUSH for STMDB.",
+            "See `Ins::aliased_from` to find the underlying opcode.",
+        ],
+        Opcode::is_alias,
+    )
+}
+
+/// Generates a `const fn` which returns true for the opcodes matching `filter`
+fn generate_opcode_predicate(opcodes: &[Opcode], name: &str, doc: &[&str], filter: impl Fn(&Opcode) -> bool) -> TokenStream {
+    let mut variants = vec![];
+    let mut cfg_arms = TokenStream::new();
+    for opcode in opcodes.iter().filter(|op| filter(op)) {
+        let variant_token = Ident::new(&opcode.enum_name(), Span::call_site());
+        if opcode.feature().is_some() {
+            let cfg = opcode_cfg(opcode);
+            cfg_arms.extend(quote! {
+                #cfg
+                Opcode::#variant_token => true,
+            });
+        } else {
+            variants.push(quote! { Opcode::#variant_token });
+        }
+    }
+    let body = if variants.is_empty() && cfg_arms.is_empty() {
+        quote! { false }
+    } else {
+        let arm = (!variants.is_empty()).then(|| quote! { #(#variants)|* => true, });
+        quote! {
+            match self {
+                #arm
+                #cfg_arms
+                _ => false,
+            }
+        }
+    };
+    let fn_ident = Ident::new(name, Span::call_site());
+    let doc = doc.iter().map(|line| format!(" {line}"));
+    quote! {
+        #(#[doc = #doc])*
+        pub const fn #fn_ident(self) -> bool {
+            #body
+        }
+    }
+}
+
+fn generate_opcode_categories(opcodes: &[Opcode]) -> TokenStream {
+    let in_category = |category| move |op: &Opcode| op.category.contains(&category);
+    let predicates: [(&str, &[&str], _); 6] = [
+        (
+            "is_branch",
+            &[
+                "Returns true for branch instructions. Other instructions which can write to PC, such as `pop {pc}`, are",
+                "not included.",
+            ],
+            in_category(Category::Branch),
+        ),
+        (
+            "is_load",
+            &["Returns true for instructions which load from memory."],
+            in_category(Category::Load),
+        ),
+        (
+            "is_store",
+            &["Returns true for instructions which store to memory."],
+            in_category(Category::Store),
+        ),
+        (
+            "is_coprocessor",
+            &["Returns true for coprocessor instructions."],
+            in_category(Category::Coprocessor),
+        ),
+        (
+            "is_data_processing",
+            &[
+                "Returns true for arithmetic, logical, move and compare instructions, including shifts. Multiplies and",
+                "media instructions are not included.",
+            ],
+            in_category(Category::DataProcessing),
+        ),
+        (
+            "is_hint",
+            &["Returns true for hint instructions such as `nop` and `wfi`, which execute as NOP if not implemented."],
+            in_category(Category::Hint),
+        ),
+    ];
+    let mut tokens: TokenStream = predicates
+        .into_iter()
+        .map(|(name, doc, filter)| generate_opcode_predicate(opcodes, name, doc, filter))
+        .collect();
+    tokens.extend(generate_opcode_predicate(
+        opcodes,
+        "is_conditional_branch_capable",
+        &["Returns true for branch instructions which have a condition code."],
+        |op| op.category.contains(&Category::Branch) && op.has_modifier(COND_MODIFIER),
+    ));
+    tokens.extend(generate_opcode_extension(opcodes));
+    tokens
+}
+
+fn generate_opcode_extension(opcodes: &[Opcode]) -> TokenStream {
+    let arms = opcodes
+        .iter()
+        .filter_map(|op| {
+            let extension = op.extension.as_ref()?;
+            let variant_token = Ident::new(&op.enum_name(), Span::call_site());
+            let cfg = opcode_cfg(op);
+            Some(quote! {
+                #cfg
+                Opcode::#variant_token => Some(#extension),
+            })
+        })
+        .collect::<Vec<_>>();
+    let body = if arms.is_empty() {
+        quote! { None }
+    } else {
+        quote! {
+            match self {
+                #(#arms)*
+                _ => None,
+            }
+        }
+    };
+    quote! {
+        #[doc = " Returns the architecture extension of the opcode, e.g. `dsp`, or `None` if it's in the base instruction set."]
+        #[doc = " Each extension has an `ext-<name>` cargo feature."]
+        pub const fn extension(self) -> Option<&'static str> {
+            #body
+        }
+    }
+}
+
+/// Merges the arguments of a modifier's cases in order of appearance, such that the argument kinds of every case are a
+/// subsequence of the result. Where appending a case's new arguments would break this, they're inserted before the
+/// arguments which follow them instead, e.g. `Dd` of `[Dd, Rn_deref]` goes before `Rn_deref` of `[Sd, Rn_deref]`.
+fn merge_case_args<'a>(isa: &Isa, cases: &'a [ModifierCase]) -> Result<Vec<&'a String>> {
+    let kind = |arg: &String| isa.get_field(arg).map(|field| &field.arg);
+    let is_kind_subsequence = |args: &[String], merged: &[&String]| -> Result<bool> {
+        let mut merged = merged.iter();
+        for arg in args {
+            let arg_kind = kind(arg)?;
+            loop {
+                match merged.next() {
+                    Some(other) if kind(other)? == arg_kind => break,
+                    Some(_) => {}
+                    None => return Ok(false),
+                }
+            }
+        }
+        Ok(true)
+    };
+
+    let mut merged: Vec<&String> = vec![];
+    for case in cases {
+        let start_len = merged.len();
+        for arg in case.args.iter() {
+            if !merged.contains(&arg) {
+                merged.push(arg);
+            }
+        }
+        if is_kind_subsequence(&case.args, &merged)? {
+            continue;
+        }
+        merged.truncate(start_len);
+        let mut pos = 0;
+        for arg in case.args.iter() {
+            match merged[pos..].iter().position(|other| *other == arg) {
+                Some(index) => pos += index + 1,
+                None => {
+                    merged.insert(pos, arg);
+                    pos += 1;
+                }
+            }
+        }
+    }
+    Ok(merged)
+}
+
+fn generate_opcode_arg_meta(isa: &Isa, isa_args: &IsaArgs, num_opcodes_token: &Literal) -> Result<(TokenStream, TokenStream)> {
+    let mut meta_consts = BTreeMap::new();
+    let mut entries = vec![];
+    for opcode in isa.opcodes.iter() {
+        let mut syntaxes = vec![];
+        for ual in [false, true] {
+            let ual = opcode.parsed_syntax(isa, ual)?;
+            let mut meta = vec![];
+            let mut min_args = 0;
+            let mut max_args = 0;
+            for field in opcode.get_args(isa, ual)? {
+                meta.push((field, false));
+                min_args += 1;
+                max_args += 1;
+            }
+            for cases in opcode.get_modifier_cases(isa, ual)? {
+                let case_args = merge_case_args(isa, &cases)?;
+                for arg in case_args {
+                    // Arguments which don't appear in every case of a modifier are optional
+                    let optional = !cases.iter().all(|case| case.args.contains(arg));
+                    meta.push((isa.get_field(arg)?, optional));
+                }
+                min_args += cases.iter().map(|case| case.args.len()).min().unwrap_or(0);
+                max_args += cases.iter().map(|case| case.args.len()).max().unwrap_or(0);
+            }
+            let meta = meta
+                .into_iter()
+                .map(|(field, optional)| {
+                    let prefix = if optional { "OPT" } else { "ARG" };
+                    let ident = format!("{prefix}_{}", field.name.to_uppercase());
+                    if !meta_consts.contains_key(&ident) {
+                        let name = &field.name;
+                        let arg = isa_args.get_arg(&field.arg)?;
+                        let kind = Ident::new(&arg.pascal_case_name(), Span::call_site());
+                        meta_consts.insert(
+                            ident.clone(),
+                            quote! { ArgMeta { name: #name, optional: #optional, kind: ArgumentKind::#kind } },
+                        );
+                    }
+                    let ident = Ident::new(&ident, Span::call_site());
+                    Ok(quote! { #ident })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let min_args = Literal::u8_unsuffixed(min_args.try_into()?);
+            let max_args = Literal::u8_unsuffixed(max_args.try_into()?);
+            syntaxes.push(quote! { (#min_args, #max_args, &[#(#meta),*]) });
+        }
+        entries.push(quote! { [#(#syntaxes),*] });
+    }
+
+    let meta_consts = meta_consts.into_iter().map(|(ident, meta)| {
+        let ident = Ident::new(&ident, Span::call_site());
+        quote! { const #ident: ArgMeta = #meta; }
+    });
+    let statics = quote! {
+        #(#meta_consts)*
+        #[doc = " Minimum number of arguments, maximum number of arguments and argument metadata of each opcode,"]
+        #[doc = " in divided (pre-UAL) and unified (UAL) syntax."]
+        static OPCODE_ARGS: [[(u8, u8, &[ArgMeta]); 2]; #num_opcodes_token] = [#(#entries),*];
+    };
+    let methods = quote! {
+        #[doc = " Returns the minimum number of arguments this opcode produces when parsed."]
+        pub fn min_args(self, flags: &ParseFlags) -> usize {
+            if self == Opcode::Illegal {
+                return 0;
+            }
+            OPCODE_ARGS[self as usize][flags.ual as usize].0 as usize
+        }
+        #[doc = " Returns the maximum number of arguments this opcode produces when parsed."]
+        pub fn max_args(self, flags: &ParseFlags) -> usize {
+            if self == Opcode::Illegal {
+                return 0;
+            }
+            OPCODE_ARGS[self as usize][flags.ual as usize].1 as usize
+        }
+        #[doc = " Returns every argument this opcode can produce, in order. Optional arguments only appear for some"]
+        #[doc = " modifier cases, so the parsed arguments are always a subsequence of this slice. See [`ArgMeta`] for"]
+        #[doc = " the positional contract of each opcode family."]
+        pub fn arg_meta(self, flags: &ParseFlags) -> &'static [ArgMeta] {
+            if self == Opcode::Illegal {
+                return &[];
+            }
+            OPCODE_ARGS[self as usize][flags.ual as usize].2
+        }
+    };
+    Ok((methods, statics))
+}
+
+/// Generates `Ins::field_descs`, which lists the fields of the modifier cases an instruction was decoded with
+fn generate_ins_field_descs(isa: &Isa) -> Result<TokenStream> {
+    let mut arms = TokenStream::new();
+    for opcode in isa.opcodes.iter() {
+        let mut base: Vec<(String, u32)> = vec![];
+        let add = |descs: &mut Vec<(String, u32)>, name: &str, bitmask: u32| {
+            let bitmask = bitmask & !opcode.bitmask;
+            if bitmask != 0 && !descs.iter().any(|(n, _)| n == name) {
+                descs.push((name.to_string(), bitmask));
+            }
+        };
+        for ual in [false, true] {
+            for field in opcode.get_args(isa, ual)? {
+                add(&mut base, &field.name, field.get_bitmask()?);
+            }
+        }
+        // The modifiers are the same in both syntaxes, only their order differs
+        let modifiers = opcode.get_modifiers(isa, false)?;
+        for modifier in modifiers.iter() {
+            add(&mut base, &modifier.name, modifier_bitmask(modifier)?);
+        }
+        let to_tokens = |descs: &[(String, u32)]| {
+            let descs = descs.iter().map(|(name, bitmask)| {
+                let bitmask = HexLiteral(*bitmask);
+                quote! { FieldDesc { name: #name, bitmask: #bitmask } }
+            });
+            quote! { &[#(#descs),*] }
+        };
+
+        // Only modifiers with arguments in some case change the fields
+        let arg_modifiers = modifiers
+            .iter()
+            .filter(|modifier| {
+                modifier
+                    .cases
+                    .as_ref()
+                    .is_some_and(|cases| cases.iter().any(|case| !case.args.is_empty()))
+            })
+            .collect::<Vec<_>>();
+        let body = if arg_modifiers.is_empty() {
+            to_tokens(&base)
+        } else {
+            let modifier_cases = arg_modifiers
+                .iter()
+                .map(|modifier| modifier.get_cases())
+                .collect::<Result<Vec<_>>>()?;
+            let multiple = arg_modifiers.len() > 1;
+            let mut case_arms = vec![];
+            for cases in cartesian(&modifier_cases) {
+                let mut descs = base.clone();
+                for arg in cases.iter().flat_map(|case| case.args.iter()) {
+                    add(&mut descs, arg, isa.get_field(arg)?.get_bitmask()?);
+                }
+                let values = cases.iter().zip(arg_modifiers.iter()).map(|(case, modifier)| {
+                    if modifier.pattern.is_some() {
+                        let value = case.pattern != 0;
+                        quote! { #value }
+                    } else {
+                        let enum_name = Ident::new(&modifier.enum_name(), Span::call_site());
+                        let variant_name = Ident::new(&case.variant_name(), Span::call_site());
+                        quote! { #enum_name::#variant_name }
+                    }
+                });
+                let descs = to_tokens(&descs);
+                case_arms.push(if multiple {
+                    quote! { (#(#values),*) => #descs }
+                } else {
+                    quote! { #(#values)* => #descs }
+                });
+            }
+            let accessors = arg_modifiers.iter().map(|modifier| {
+                let accessor = Ident::new(&modifier.accessor_name(), Span::call_site());
+                quote! { self.#accessor() }
+            });
+            let accessors = if multiple {
+                quote! { (#(#accessors),*) }
+            } else {
+                quote! { #(#accessors)* }
+            };
+            quote! {
+                match #accessors {
+                    #(#case_arms,)*
+                    _ => &[],
+                }
+            }
+        };
+        let variant = Ident::new(&opcode.enum_name(), Span::call_site());
+        let cfg = opcode_cfg(opcode);
+        arms.extend(quote! {
+            #cfg
+            Opcode::#variant => #body,
+        });
+    }
+    Ok(quote! {
+        #[doc = " Returns the fields and modifiers of this instruction which are not fixed by its opcode, in both syntaxes. Unlike"]
+        #[doc = " [`Opcode::field_descs`], fields which only belong to other modifier cases are left out, e.g. the offset"]
+        #[doc = " register of an `ldr` with an immediate offset."]
+        pub fn field_descs(&self) -> &'static [FieldDesc] {
+            match self.op {
+                #arms
+                _ => &[],
+            }
+        }
+        #[doc = " Returns the name and raw value of each field of [`Self::field_descs`], see [`FieldDesc::value`]."]
+        pub fn fields(&self) -> impl Iterator<Item = (&'static str, u32)> {
+            let code = self.code;
+            self.field_descs().iter().map(move |field| (field.name, field.value(code)))
+        }
+    })
+}
+
+/// Generates the fields and modifiers of each opcode, which `Opcode::enumerate` sweeps
+fn generate_opcode_field_descs(isa: &Isa, num_opcodes_token: &Literal) -> Result<(TokenStream, TokenStream)> {
+    let mut entries = vec![];
+    for opcode in isa.opcodes.iter() {
+        let mut descs: Vec<(String, u32)> = vec![];
+        let mut add = |name: &str, bitmask: u32| {
+            let bitmask = bitmask & !opcode.bitmask;
+            if bitmask != 0 && !descs.iter().any(|(n, _)| n == name) {
+                descs.push((name.to_string(), bitmask));
+            }
+        };
+        for ual in [false, true] {
+            for field in opcode.get_args(isa, ual)? {
+                add(&field.name, field.get_bitmask()?);
+            }
+            for modifier in opcode.get_modifiers(isa, ual)? {
+                let cases = modifier.get_cases()?;
+                add(&modifier.name, modifier_bitmask(&modifier)?);
+                for arg in cases.iter().flat_map(|case| case.args.iter()) {
+                    add(arg, isa.get_field(arg)?.get_bitmask()?);
+                }
+            }
+        }
+        let descs = descs.into_iter().map(|(name, bitmask)| {
+            let bitmask = HexLiteral(bitmask);
+            quote! { FieldDesc { name: #name, bitmask: #bitmask } }
+        });
+        let bitmask = HexLiteral(opcode.bitmask);
+        let pattern = HexLiteral(opcode.pattern);
+        entries.push(quote! { (#bitmask, #pattern, &[#(#descs),*]) });
+    }
+
+    let variants = isa.opcodes.iter().map(|opcode| {
+        let variant = Ident::new(&opcode.enum_name(), Span::call_site());
+        let cfg = opcode_cfg(opcode);
+        quote! { #cfg Opcode::#variant }
+    });
+
+    let statics = quote! {
+        #[doc = " Bitmask, pattern and fields of each opcode."]
+        static OPCODE_FIELDS: [(u32, u32, &[FieldDesc]); #num_opcodes_token] = [#(#entries),*];
+        #[doc = " Every opcode enabled by the cargo features, in order of discriminant."]
+        static OPCODES: &[Opcode] = &[#(#variants),*];
+    };
+    let methods = quote! {
+        #[doc = " Returns every opcode enabled by the cargo features except [`Opcode::Illegal`], in order of discriminant."]
+        pub fn iter() -> impl Iterator<Item = Self> {
+            OPCODES.iter().copied()
+        }
+        #[doc = " Returns the bits which are fixed by this opcode. A code with these bits equal to [`Self::pattern`] may"]
+        #[doc = " decode to this opcode, unless a more specific opcode also matches it. Returns 0 for [`Opcode::Illegal`]."]
+        pub fn bitmask(self) -> u32 {
+            if self == Opcode::Illegal {
+                return 0;
+            }
+            OPCODE_FIELDS[self as usize].0
+        }
+        #[doc = " Returns the values of the bits in [`Self::bitmask`], which is also the lowest code of this opcode. Returns"]
+        #[doc = " 0 for [`Opcode::Illegal`]."]
+        pub fn pattern(self) -> u32 {
+            if self == Opcode::Illegal {
+                return 0;
+            }
+            OPCODE_FIELDS[self as usize].1
+        }
+        #[doc = " Returns the fields and modifiers of this opcode which are not fixed by its pattern, in both syntaxes."]
+        pub fn field_descs(self) -> &'static [FieldDesc] {
+            if self == Opcode::Illegal {
+                return &[];
+            }
+            OPCODE_FIELDS[self as usize].2
+        }
+        #[doc = " Returns the encodings of this opcode selected by `filter`, in increasing order. Encodings which decode to"]
+        #[doc = " another opcode with the given flags, e.g. a more specific opcode with an overlapping pattern, are skipped."]
+        pub fn enumerate(self, filter: &FieldFilter, flags: &ParseFlags) -> impl Iterator<Item = u32> {
+            let (bitmask, pattern, fields) = match self {
+                Opcode::Illegal => (0, 0, None),
+                _ => {
+                    let (bitmask, pattern, fields) = OPCODE_FIELDS[self as usize];
+                    (bitmask, pattern, Some(fields))
+                }
+            };
+            let flags = *flags;
+            enumerate::enumerate(bitmask, pattern, fields, filter, move |code| Ins::new(code, &flags).op == self)
+        }
+    };
+    Ok((methods, statics))
 }
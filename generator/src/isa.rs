@@ -1,4 +1,8 @@
-use std::{collections::HashMap, fs::File, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    path::Path,
+};
 
 use anyhow::{bail, Context, Result};
 
@@ -19,6 +23,13 @@ pub struct Isa {
     pub fields: Box<[Field]>,
     pub modifiers: Box<[Modifier]>,
     pub opcodes: Box<[Opcode]>,
+    /// Mnemonics which are intentionally rendered from more than one opcode or set of modifier cases
+    #[serde(default)]
+    pub allow_collisions: Box<[String]>,
+    /// Old opcode variant names mapped to their new names. The old names are kept as doc aliases and are accepted by
+    /// `Opcode::from_variant_name`.
+    #[serde(default)]
+    pub renames: BTreeMap<String, String>,
 }
 
 impl Isa {
@@ -39,9 +50,27 @@ impl Isa {
         for opcode in self.opcodes.iter() {
             opcode.validate(self)?;
         }
+        for (old, new) in self.renames.iter() {
+            if !self.opcodes.iter().any(|op| op.enum_name() == *new) {
+                bail!("Opcode '{old}' is renamed to '{new}', which doesn't exist")
+            }
+            if self.opcodes.iter().any(|op| op.enum_name() == *old) {
+                bail!("Opcode '{old}' is renamed to '{new}', but an opcode is still named '{old}'")
+            }
+        }
         Ok(())
     }
 
+    /// Returns the old variant names of an opcode, see [`Self::renames`]
+    pub fn old_names(&self, opcode: &Opcode) -> Vec<String> {
+        let name = opcode.enum_name();
+        self.renames
+            .iter()
+            .filter(|(_, new)| **new == name)
+            .map(|(old, _)| old.clone())
+            .collect()
+    }
+
     pub fn get_modifier(&self, name: &str) -> Result<&Modifier> {
         self.modifiers
             .iter()
@@ -79,6 +108,8 @@ pub struct Field {
     #[serde(default)]
     pub no_bitmask: bool,
     pub value: FieldValue,
+    /// Rust expression which encodes the `!Expr` parts of `value`, see [`FieldValue::has_expr`]
+    pub encode: Option<String>,
     #[serde(default)]
     pub flags: Box<[Flag]>,
 }
@@ -97,9 +128,28 @@ impl Field {
         }
         let arg = isa_args.get_arg(&self.arg)?;
         self.value.validate(self, arg)?;
+        match (&self.encode, self.value.has_expr()) {
+            (Some(encode), true) => {
+                syn::parse_str::<Expr>(encode)
+                    .with_context(|| format!("Invalid encode expression in field '{}'", self.name))?;
+            }
+            (None, true) => bail!("Field {} has an expression value but no encode expression", self.name),
+            (Some(_), false) => bail!("Field {} has an encode expression but no expression value", self.name),
+            (None, false) => {}
+        }
         Ok(())
     }
 
+    /// Returns whether this field can be rendered as a dereferenced base register, i.e. in brackets
+    pub fn can_deref(&self) -> bool {
+        match &self.value {
+            FieldValue::Struct(members) => members
+                .get("deref")
+                .is_some_and(|deref| !matches!(deref, FieldValue::Bool(false))),
+            _ => false,
+        }
+    }
+
     pub fn accessor_name(&self) -> String {
         format!("field_{}", self.name.to_lowercase())
     }
@@ -113,13 +163,10 @@ impl Field {
     /// - `Some(false)` if this field only exists in divided syntax (pre-UAL)
     /// - `None` if this field exists in both syntaxes
     pub fn ual_flag(&self) -> Option<bool> {
-        self.flags
-            .iter()
-            .map(|f| {
-                let Flag::Ual(ual) = f;
-                *ual
-            })
-            .next()
+        self.flags.iter().find_map(|f| match f {
+            Flag::Ual(ual) => Some(*ual),
+            _ => None,
+        })
     }
 }
 
@@ -167,6 +214,17 @@ impl FieldValue {
         }
     }
 
+    /// Returns whether this value or any of its members is an `!Expr`. Such fields must have an `encode` expression,
+    /// which is given the whole argument as `value` and returns `Option<u32>` with the encoded bits of the expressions,
+    /// or `None` if the argument is out of range.
+    pub fn has_expr(&self) -> bool {
+        match self {
+            FieldValue::Expr(_) => true,
+            FieldValue::Struct(members) => members.values().any(|value| value.has_expr()),
+            FieldValue::Bits(_) | FieldValue::Bool(_) | FieldValue::U32(_) => false,
+        }
+    }
+
     pub fn validate(&self, field: &Field, arg: &Arg) -> Result<()> {
         match (self, &arg.r#type) {
             (FieldValue::Struct(values), ArgType::Struct(members)) => {
@@ -306,9 +364,7 @@ impl Modifier {
         if has_suffix || has_nsuffix {
             true
         } else {
-            self.cases
-                .as_ref()
-                .map_or(false, |cases| cases.iter().any(|c| c.has_suffix()))
+            self.cases.as_ref().is_some_and(|cases| cases.iter().any(|c| c.has_suffix()))
         }
     }
 
@@ -366,7 +422,8 @@ impl ModifierCase {
         if self.get_bitmask(isa, parent)? == 0 {
             bail!("Modifier case '{}' has no bitmask", self.name)
         }
-        Ok(())
+        validate_defs_uses(&self.args, &self.defs, &self.uses)
+            .with_context(|| format!("While validating modifier case '{}'", self.name))
     }
 
     pub fn from_modifier(modifier: &Modifier, negative: bool) -> Result<Self> {
@@ -462,14 +519,37 @@ pub struct Opcode {
     pub suffix: Option<OpcodeSuffix>,
     pub bitmask: u32,
     pub pattern: u32,
+    /// Optional architecture extension, can be disabled with the `ext-<name>` cargo feature
+    pub extension: Option<String>,
     #[serde(default)]
     pub flags: Box<[Flag]>,
+    /// Kinds of instruction this opcode belongs to, for the generated classification methods
+    #[serde(default)]
+    pub category: Box<[Category]>,
     #[serde(default)]
     modifiers: Box<[String]>,
     #[serde(default)]
     pub args: Box<[String]>,
     pub defs: Option<Box<[String]>>,
     pub uses: Option<Box<[String]>>,
+    /// Conditions under which the encoding is UNPREDICTABLE
+    #[serde(default)]
+    pub unpredictable: Box<[Constraint]>,
+    /// Names of opcodes which are intentionally matched by the same instructions as this opcode, see `check_overlaps`.
+    /// The decoder prefers the opcode with the most bits in its bitmask, or the first one in the ISA file if they have
+    /// the same number of bits.
+    #[serde(default)]
+    pub allow_overlap_with: Box<[String]>,
+}
+
+/// Checks that the registers written and read by an opcode or modifier case are among its arguments
+fn validate_defs_uses(args: &[String], defs: &Option<Box<[String]>>, uses: &Option<Box<[String]>>) -> Result<()> {
+    for name in defs.iter().chain(uses.iter()).flat_map(|names| names.iter()) {
+        if !args.contains(name) {
+            bail!("'{name}' is listed in `defs` or `uses` but is not an argument")
+        }
+    }
+    Ok(())
 }
 
 impl Opcode {
@@ -509,13 +589,65 @@ impl Opcode {
             }
             bitmask_acc |= bitmask;
         }
-        let complete_bitmask = ((1u64 << isa.ins_size) - 1).try_into().unwrap();
+        let complete_bitmask: u32 = ((1u64 << isa.ins_size) - 1).try_into().unwrap();
         if bitmask_acc != complete_bitmask {
             bail!("Opcode '{}' has an incomplete bitmask 0x{:08x}", self.name, bitmask_acc)
         }
+        self.validate_arg_order(isa)
+            .with_context(|| format!("While validating opcode '{}'", self.name))?;
+        validate_defs_uses(&self.args, &self.defs, &self.uses)
+            .with_context(|| format!("While validating opcode '{}'", self.name))?;
+        for constraint in self.unpredictable.iter() {
+            syn::parse_str::<syn::Expr>(&constraint.expr).with_context(|| {
+                format!(
+                    "Invalid expression in constraint '{}' of opcode '{}'",
+                    constraint.desc, self.name
+                )
+            })?;
+        }
         Ok(())
     }
 
+    /// Checks the positional contract of the arguments, which users may rely on when indexing parsed arguments:
+    /// - Register lists come last, after the base register of load/store multiple opcodes
+    /// - Long multiplies start with `RdLo, RdHi`, other multiplies start with their destination `RdHi`
+    /// - The destination or transfer register `Rd` comes first, except for coprocessor transfers
+    fn validate_arg_order(&self, isa: &Isa) -> Result<()> {
+        let args = self.args.iter().map(|arg| isa.get_field(arg)).collect::<Result<Vec<_>>>()?;
+        if let Some(index) = args.iter().position(|field| field.arg == "reg_list") {
+            if index != args.len() - 1 || index > 1 {
+                bail!(
+                    "Register list '{}' must be the last argument, after the base register",
+                    args[index].name
+                )
+            }
+        }
+        let position = |name: &str| self.args.iter().position(|arg| arg == name);
+        match (position("RdLo"), position("RdHi")) {
+            (Some(lo), Some(hi)) if (lo, hi) != (0, 1) => bail!("Expected 'RdLo, RdHi' as the first arguments"),
+            (Some(_), None) => bail!("Expected 'RdHi' after 'RdLo'"),
+            (None, Some(hi)) if hi != 0 => bail!("Expected 'RdHi' as the first argument"),
+            _ => {}
+        }
+        let coprocessor = self.category.contains(&Category::Coprocessor);
+        if let Some(index) = self.args.iter().position(|arg| arg == "Rd" || arg.starts_with("Rd_")) {
+            if index != 0 && !coprocessor {
+                bail!("Expected '{}' as the first argument", self.args[index])
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the cargo feature which enables this opcode, if it belongs to an extension
+    pub fn feature(&self) -> Option<String> {
+        self.extension.as_ref().map(|ext| format!("ext-{ext}"))
+    }
+
+    /// Returns the name of this opcode as written in the ISA file, e.g. `ldr$sb`
+    pub fn raw_name(&self) -> &str {
+        &self.name
+    }
+
     pub fn base_name(&self) -> &str {
         if let Some((name, _)) = self.name.split_once('$') {
             name
@@ -528,13 +660,26 @@ impl Opcode {
         self.base_name().to_owned() + self.suffix.as_ref().map_or("", |s| s.suffix(ual))
     }
 
+    /// Returns the mnemonic of this opcode with the suffixes of the given modifier cases applied
+    pub fn mnemonic(&self, cases: &[ModifierCase], ual: bool) -> String {
+        let suffix = cases.iter().map(|case| case.suffix(ual)).collect::<String>();
+        let opcode_suffix = self.suffix.as_ref().map_or("", |s| s.suffix(ual));
+        if ual {
+            self.base_name().to_string() + opcode_suffix + &suffix
+        } else {
+            self.base_name().to_string() + &suffix + opcode_suffix
+        }
+    }
+
     pub fn doc(&self, ual: bool) -> String {
         format!(" {}: {}", self.name(ual).to_uppercase(), self.desc)
     }
 
+    /// Returns the name of the `Opcode` variant. The name is split at each `$`, and each part is capitalized by making
+    /// its first letter uppercase and the rest lowercase. The parts are then joined, e.g. `smlal$xy` becomes `SmlalXy` and
+    /// `ldr$SB` becomes `LdrSb`. Renaming an opcode in the ISA file renames its variant, so the old name should be added
+    /// to `renames`.
     pub fn enum_name(&self) -> String {
-        // Split by $ delimiter, capitalize all the words, then join them
-        // e.g. smlal$xy => SmlalXy
         capitalize_with_delimiter(self.name.clone(), '$')
     }
 
@@ -560,12 +705,54 @@ impl Opcode {
         Ok(modifiers)
     }
 
+    pub fn has_modifier(&self, name: &str) -> bool {
+        self.modifiers.iter().any(|m| m == name)
+    }
+
     pub fn get_modifier_cases(&self, isa: &Isa, ual: bool) -> Result<Vec<Box<[ModifierCase]>>> {
         let modifiers = self.get_modifiers(isa, ual)?;
         let modifiers = modifiers.iter().map(|m| m.get_cases()).collect::<Result<Vec<_>>>()?;
         Ok(modifiers)
     }
 
+    /// Returns every combination of modifier cases, one case per modifier. An opcode without modifiers has one empty
+    /// combination.
+    pub fn get_case_combinations(&self, isa: &Isa, ual: bool) -> Result<Vec<Box<[ModifierCase]>>> {
+        let modifier_cases = self.get_modifier_cases(isa, ual)?;
+        if modifier_cases.is_empty() {
+            Ok(vec![Box::new([])])
+        } else {
+            Ok(cartesian(&modifier_cases).collect())
+        }
+    }
+
+    /// Returns the fields of the opcode's own arguments in the given syntax, excluding arguments added by modifiers
+    pub fn get_args<'a>(&self, isa: &'a Isa, ual: bool) -> Result<Vec<&'a Field>> {
+        self.args
+            .iter()
+            .filter_map(|arg| {
+                let field = isa.get_field(arg);
+                let Ok(field) = field else { return Some(field) };
+                match (field.ual_flag(), ual) {
+                    (None, _) => Some(Ok(field)),
+                    // Remove fields that only belong in unified/divided syntax but not both
+                    (Some(a), b) if a == b => Some(Ok(field)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns which syntax the opcode is parsed as when the `ual` flag is set to the given value. This is only different
+    /// from `ual` if the opcode exists in one syntax or has no differences between the syntaxes.
+    pub fn parsed_syntax(&self, isa: &Isa, ual: bool) -> Result<bool> {
+        Ok(match (self.has_ual_changes(isa)?, self.ual_flag()) {
+            (true, None) => ual,
+            (_, Some(false)) => false,
+            (_, Some(true)) | (false, None) => true,
+        })
+    }
+
     fn get_max_args(&self, isa: &Isa, ual: bool) -> Result<usize> {
         let base_args = self.args.len();
         let modifiers = self.get_modifier_cases(isa, ual)?;
@@ -613,13 +800,16 @@ impl Opcode {
     /// - `Some(false)` if this opcode only exists in divided syntax (pre-UAL)
     /// - `None` if this opcode exists in both syntaxes
     pub fn ual_flag(&self) -> Option<bool> {
-        self.flags
-            .iter()
-            .map(|f| {
-                let Flag::Ual(ual) = f;
-                *ual
-            })
-            .next()
+        self.flags.iter().find_map(|f| match f {
+            Flag::Ual(ual) => Some(*ual),
+            _ => None,
+        })
+    }
+
+    /// Returns whether this opcode is an alias which only exists in unified syntax (UAL). In divided syntax (pre-UAL), the
+    /// same encoding is decoded as the underlying opcode instead.
+    pub fn is_alias(&self) -> bool {
+        self.ual_flag() == Some(true)
     }
 }
 
@@ -648,4 +838,25 @@ impl OpcodeSuffix {
 #[derive(Deserialize, Clone, PartialEq, Eq)]
 pub enum Flag {
     Ual(bool),
+    /// Only decoded if `ParseFlags::vfp` is equal to this value
+    Vfp(bool),
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    Branch,
+    Load,
+    Store,
+    Coprocessor,
+    DataProcessing,
+    Hint,
+}
+
+/// A condition on the encoding of an opcode, as a Rust expression on `self.code`
+#[derive(Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Constraint {
+    pub desc: String,
+    pub expr: String,
 }
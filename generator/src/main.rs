@@ -1,17 +1,28 @@
 mod args;
+mod collision;
+mod coverage;
+mod diagram;
 mod generate;
 mod isa;
 mod iter;
+mod overlap;
+mod profile;
 mod search;
+mod table;
 mod token;
 mod util;
+mod variants;
 
 use std::{fs, path::Path};
 
 use anyhow::{Context, Result};
 use args::IsaArgs;
+use collision::check_mnemonics;
+use coverage::{check_regressions, to_markdown, Coverage, IsaCoverage, Reference};
 use generate::{args::generate_args, disasm::generate_disasm};
 use isa::Isa;
+use overlap::check_overlaps;
+use variants::update_snapshot;
 
 fn main() -> Result<()> {
     let args = IsaArgs::load(Path::new("specs/args.yaml"))?;
@@ -40,6 +51,7 @@ fn main() -> Result<()> {
             let isa = Isa::load(&path)?;
             isa.validate(&args)
                 .with_context(|| format!("While validating {}", path.display()))?;
+            check_overlaps(&isa).with_context(|| format!("While checking opcode patterns of {}", path.display()))?;
             isas.push((path, isa));
         }
     }
@@ -58,6 +70,7 @@ fn main() -> Result<()> {
     let formatted = prettyplease::unparse(&file);
     fs::write("disasm/src/args.rs", formatted)?;
 
+    let mut coverage = Coverage::new();
     for (path, isa) in &isas {
         let tokens = generate_disasm(isa, &args, max_args)
             .with_context(|| format!("While generating disassembler for {}", path.display()))?;
@@ -76,10 +89,48 @@ fn main() -> Result<()> {
             .strip_prefix(specs_path)?
             .join(module_name);
 
+        let report = check_mnemonics(isa).with_context(|| format!("While checking mnemonics of {}", path.display()))?;
+
         let out_path = format!("disasm/src/{}/generated.rs", module_path.display());
-        println!("{}", out_path);
+        println!(
+            "{}: {} mnemonics, {} allowed collisions",
+            out_path,
+            report.mnemonics,
+            report.allowed_collisions()
+        );
+        for collision in &report.collisions {
+            println!("    {}", collision.describe());
+        }
         fs::write(out_path, formatted)?;
+
+        // Keep a snapshot of the variant names next to the ISA file, e.g. `arm.variants.txt`
+        update_snapshot(isa, &path.with_extension("variants.txt"))
+            .with_context(|| format!("While checking variant names of {}", path.display()))?;
+
+        // Map the instructions of the reference list next to the ISA file, e.g. `arm.reference.txt`
+        let reference_path = path.with_extension("reference.txt");
+        if reference_path.exists() {
+            let references = Reference::load(&reference_path)?;
+            let isa_coverage = IsaCoverage::new(isa, &references)
+                .with_context(|| format!("While checking coverage of {}", reference_path.display()))?;
+            println!(
+                "{}: {} of {} instructions covered",
+                reference_path.display(),
+                isa_coverage.instructions.len() - isa_coverage.missing(),
+                isa_coverage.instructions.len()
+            );
+            coverage.insert(module_path.display().to_string(), isa_coverage);
+        }
+    }
+
+    let coverage_path = specs_path.join("coverage.json");
+    if coverage_path.exists() {
+        let old: Coverage = serde_json::from_str(&fs::read_to_string(&coverage_path)?)
+            .with_context(|| format!("While parsing {}", coverage_path.display()))?;
+        check_regressions(&old, &coverage)?;
     }
+    fs::write(&coverage_path, serde_json::to_string_pretty(&coverage)? + "\n")?;
+    fs::write(specs_path.join("COVERAGE.md"), to_markdown(&coverage)?)?;
 
     Ok(())
 }
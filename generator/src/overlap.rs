@@ -0,0 +1,204 @@
+use anyhow::{bail, Result};
+
+use crate::isa::{Flag, Isa, Opcode};
+
+/// Two opcodes whose patterns both match the same instruction, where neither is more specific than the other
+pub struct Overlap<'a> {
+    pub a: &'a Opcode,
+    pub b: &'a Opcode,
+    /// An instruction which both opcodes match
+    pub example: u32,
+}
+
+impl Overlap<'_> {
+    pub fn describe(&self) -> String {
+        format!(
+            "'{}' (0x{:08x}/0x{:08x}) and '{}' (0x{:08x}/0x{:08x}) both match 0x{:08x}",
+            self.a.raw_name(),
+            self.a.pattern,
+            self.a.bitmask,
+            self.b.raw_name(),
+            self.b.pattern,
+            self.b.bitmask,
+            self.example
+        )
+    }
+
+    fn allowed(&self) -> bool {
+        self.a.allow_overlap_with.contains(&self.b.raw_name().to_string())
+            || self.b.allow_overlap_with.contains(&self.a.raw_name().to_string())
+    }
+}
+
+/// Returns whether two opcodes can never be decoded with the same `ParseFlags`
+fn exclusive_flags(a: &Opcode, b: &Opcode) -> bool {
+    a.flags.iter().any(|fa| {
+        b.flags.iter().any(|fb| match (fa, fb) {
+            (Flag::Ual(a), Flag::Ual(b)) | (Flag::Vfp(a), Flag::Vfp(b)) => a != b,
+            _ => false,
+        })
+    })
+}
+
+/// Returns the overlap of two opcodes, if any. Opcodes are allowed to overlap if the bitmask of one is a strict
+/// superset of the other, as the decoder checks the one with the most bits first.
+fn find_overlap<'a>(a: &'a Opcode, b: &'a Opcode) -> Option<Overlap<'a>> {
+    let common = a.bitmask & b.bitmask;
+    if (a.pattern ^ b.pattern) & common != 0 || exclusive_flags(a, b) {
+        return None;
+    }
+    let nested = common != a.bitmask || common != b.bitmask;
+    let strict_superset = nested && (common == a.bitmask || common == b.bitmask);
+    if strict_superset {
+        return None;
+    }
+    Some(Overlap {
+        a,
+        b,
+        example: a.pattern | b.pattern,
+    })
+}
+
+/// Finds every pair of opcodes which can match the same instruction without one being nested in the other. Without
+/// this check, the decoder would silently prefer one of them. Returns an error if an overlap is not allowed by
+/// `allow_overlap_with`, or if `allow_overlap_with` names an opcode which doesn't overlap.
+pub fn check_overlaps(isa: &Isa) -> Result<usize> {
+    let mut overlaps = vec![];
+    for (i, a) in isa.opcodes.iter().enumerate() {
+        for b in isa.opcodes[i + 1..].iter() {
+            if let Some(overlap) = find_overlap(a, b) {
+                overlaps.push(overlap);
+            }
+        }
+    }
+
+    let denied: Vec<_> = overlaps.iter().filter(|o| !o.allowed()).map(|o| o.describe()).collect();
+    if !denied.is_empty() {
+        bail!(
+            "Overlapping opcode patterns, add `allow_overlap_with` to one of the opcodes if this is intentional:\n{}",
+            denied.join("\n")
+        )
+    }
+    for opcode in isa.opcodes.iter() {
+        for other in opcode.allow_overlap_with.iter() {
+            let overlaps_other = overlaps.iter().any(|o| {
+                (o.a.raw_name() == opcode.raw_name() && o.b.raw_name() == other)
+                    || (o.b.raw_name() == opcode.raw_name() && o.a.raw_name() == other)
+            });
+            if !overlaps_other {
+                bail!(
+                    "Opcode '{}' has '{other}' in `allow_overlap_with` but doesn't overlap with it",
+                    opcode.raw_name()
+                )
+            }
+        }
+    }
+
+    Ok(overlaps.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn isa(opcodes: &str) -> Isa {
+        let yaml = format!("ins_size: 32\nfields: []\nmodifiers: []\nopcodes:\n{opcodes}");
+        serde_yml::from_str(&yaml).unwrap()
+    }
+
+    #[test]
+    fn test_conflicting() {
+        let isa = isa(r"
+  - name: foo
+    desc: Foo
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+  - name: bar
+    desc: Bar
+    bitmask: 0x00f00000
+    pattern: 0x00200000
+");
+        let error = check_overlaps(&isa).unwrap_err().to_string();
+        assert!(error.contains("'foo' (0x01000000/0x0f000000) and 'bar' (0x00200000/0x00f00000) both match 0x01200000"));
+    }
+
+    #[test]
+    fn test_nested() {
+        let isa = isa(r"
+  - name: foo
+    desc: Foo
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+  - name: bar
+    desc: Bar
+    bitmask: 0x0ff00000
+    pattern: 0x01200000
+");
+        assert_eq!(check_overlaps(&isa).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_same_bitmask() {
+        let isa = isa(r"
+  - name: foo
+    desc: Foo
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+  - name: bar
+    desc: Bar
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+");
+        assert!(check_overlaps(&isa).is_err());
+    }
+
+    #[test]
+    fn test_exclusive_flags() {
+        let isa = isa(r"
+  - name: foo
+    desc: Foo
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+    flags: [!Ual false]
+  - name: bar
+    desc: Bar
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+    flags: [!Ual true]
+");
+        assert_eq!(check_overlaps(&isa).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_allowed() {
+        let isa = isa(r"
+  - name: foo
+    desc: Foo
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+    allow_overlap_with: [bar]
+  - name: bar
+    desc: Bar
+    bitmask: 0x00f00000
+    pattern: 0x00200000
+");
+        assert_eq!(check_overlaps(&isa).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_allowed_without_overlap() {
+        let isa = isa(r"
+  - name: foo
+    desc: Foo
+    bitmask: 0x0f000000
+    pattern: 0x01000000
+    allow_overlap_with: [bar]
+  - name: bar
+    desc: Bar
+    bitmask: 0x0ff00000
+    pattern: 0x01200000
+");
+        let error = check_overlaps(&isa).unwrap_err().to_string();
+        assert!(error.contains("doesn't overlap"));
+    }
+}
@@ -0,0 +1,54 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::table::FindImpl;
+
+/// Implementation of the generated `parse` function, chosen by the `Profile`. Both are generated either way, to test
+/// that they're equivalent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ParseImpl {
+    /// Static array of the parse function of each opcode, indexed by the opcode
+    Table,
+    /// Match on the opcode, with the parse function of each opcode inlined into its arm. Not used by any profile, see
+    /// `Profile::parse`.
+    #[allow(dead_code)]
+    Match,
+}
+
+/// Code generation choices selected by a cargo feature of unarm. Both profiles are generated into the same code, only
+/// the `fast` feature decides which one is compiled in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Smallest decoder, used unless the `fast` feature is enabled
+    Compact,
+    /// Fastest decoder, the default
+    Fast,
+}
+
+impl Profile {
+    pub const ALL: [Self; 2] = [Self::Compact, Self::Fast];
+
+    pub fn find(self) -> FindImpl {
+        match self {
+            Self::Compact => FindImpl::Tree,
+            Self::Fast => FindImpl::Table,
+        }
+    }
+
+    pub fn parse(self) -> ParseImpl {
+        // The match measured the same as the table or up to 10% slower in the `versions` benchmark, so it's only
+        // generated to be compared
+        match self {
+            Self::Compact => ParseImpl::Table,
+            Self::Fast => ParseImpl::Table,
+        }
+    }
+
+    /// Attribute which compiles an item only in this profile
+    pub fn cfg(self) -> TokenStream {
+        match self {
+            Self::Compact => quote! { #[cfg(not(feature = "fast"))] },
+            Self::Fast => quote! { #[cfg(feature = "fast")] },
+        }
+    }
+}
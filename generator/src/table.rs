@@ -0,0 +1,104 @@
+use std::ptr;
+
+use anyhow::{bail, Result};
+
+use crate::isa::{Isa, Opcode};
+
+/// Implementation of `Opcode::find`, chosen by the `Profile`. Both are generated either way, to test that they're
+/// equivalent.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FindImpl {
+    /// Tree of single bit tests, see `SearchTree`
+    Tree,
+    /// Lookup table, see `FindTable`
+    Table,
+}
+
+/// Decode table which maps a few key bits of an instruction to the opcodes which can match it. Most entries resolve
+/// to a single opcode, the rest are checked by a small group of residual checks.
+pub struct FindTable<'a> {
+    /// Bits of the instruction which form the key, e.g. bits 27-20 and 7-4 for ARM
+    pub key_mask: u32,
+    /// Fallback opcode and residual group of each key. `None` is an illegal instruction.
+    pub entries: Vec<(Option<&'a Opcode>, usize)>,
+    /// Opcodes to check in order before the fallback opcode. The first group is empty.
+    pub groups: Vec<Vec<&'a Opcode>>,
+}
+
+impl<'a> FindTable<'a> {
+    pub fn new(isa: &'a Isa) -> Result<Self> {
+        let key_mask: u32 = match isa.ins_size {
+            32 => 0x0ff000f0,
+            16 => 0x0000fff0,
+            size => bail!("No decode table key for instruction size {size}"),
+        };
+
+        // Same order as the leaves of the search tree, so both decoders prefer the same opcodes
+        let mut opcodes = isa.opcodes.iter().collect::<Vec<_>>();
+        opcodes.sort_by_key(|op| 32 - op.bitmask.count_ones());
+
+        let mut entries = vec![];
+        let mut groups: Vec<Vec<&Opcode>> = vec![vec![]];
+        for key in 0..1u32 << key_mask.count_ones() {
+            let bits = deposit(key, key_mask);
+            let mut checks = vec![];
+            let mut fallback = None;
+            for opcode in opcodes.iter().filter(|op| (op.pattern ^ bits) & op.bitmask & key_mask == 0) {
+                if opcode.flags.is_empty() && opcode.extension.is_none() && opcode.bitmask & !key_mask == 0 {
+                    fallback = Some(*opcode);
+                    break;
+                }
+                checks.push(*opcode);
+            }
+            let same =
+                |group: &&Vec<&Opcode>| group.len() == checks.len() && group.iter().zip(&checks).all(|(a, b)| ptr::eq(*a, *b));
+            let group = match groups.iter().position(|group| same(&group)) {
+                Some(index) => index,
+                None => {
+                    groups.push(checks);
+                    groups.len() - 1
+                }
+            };
+            entries.push((fallback, group));
+        }
+        if groups.len() > u8::MAX as usize {
+            bail!("Decode table has {} residual groups, at most 255 are supported", groups.len());
+        }
+
+        Ok(Self {
+            key_mask,
+            entries,
+            groups,
+        })
+    }
+
+    /// Returns the contiguous runs of key bits as `(shift, mask)`, such that ORing `(code >> shift) & mask` of every
+    /// run produces the key
+    pub fn key_runs(&self) -> Vec<(u32, u32)> {
+        let mut runs = vec![];
+        let mut key_bit = 0;
+        let mut mask = self.key_mask;
+        while mask != 0 {
+            let start = mask.trailing_zeros();
+            let len = (mask >> start).trailing_ones();
+            runs.push((start - key_bit, ((1 << len) - 1) << key_bit));
+            key_bit += len;
+            mask &= !(((1u64 << (start + len)) - 1) as u32);
+        }
+        runs
+    }
+}
+
+/// Places the lowest bits of `value` in the set bits of `mask`, from lowest to highest
+fn deposit(mut value: u32, mut mask: u32) -> u32 {
+    let mut result = 0;
+    while mask != 0 {
+        let bit = mask & mask.wrapping_neg();
+        if value & 1 != 0 {
+            result |= bit;
+        }
+        value >>= 1;
+        mask &= !bit;
+    }
+    result
+}
@@ -33,6 +33,40 @@ impl<'de> Deserialize<'de> for BitRange {
     }
 }
 
+/// Describes the bits of `mask` in the notation of the ARM ARM, from highest to lowest, e.g. `bits[23], bits[11:0]`
+pub fn describe_bitmask(mask: u32) -> String {
+    let mut ranges = vec![];
+    let mut bit = 32;
+    while bit > 0 {
+        bit -= 1;
+        if mask & (1 << bit) == 0 {
+            continue;
+        }
+        let high = bit;
+        while bit > 0 && mask & (1 << (bit - 1)) != 0 {
+            bit -= 1;
+        }
+        if high == bit {
+            ranges.push(format!("bits[{high}]"));
+        } else {
+            ranges.push(format!("bits[{high}:{bit}]"));
+        }
+    }
+    ranges.join(", ")
+}
+
+/// Inverse of [`capitalize_with_delimiter`] with `_`, e.g. `LdrB` to `ldr_b`
+pub fn snake_case(s: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        c.to_lowercase().for_each(|c| snake.push(c));
+    }
+    snake
+}
+
 pub fn capitalize_with_delimiter(s: String, delim: char) -> String {
     s.split(delim)
         .map(|s| {
@@ -0,0 +1,31 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+
+use crate::isa::Isa;
+
+const HEADER: &str = "# Opcode variant names, generated by unarm-generator. Do not edit!
+# A name which disappears from this list must be added to `renames` in the ISA file.
+";
+
+/// Checks the variant names against the snapshot at `path`, then updates it. Every name in the old snapshot must still
+/// exist or be renamed, so that renaming an opcode in the ISA file can't silently break downstream users.
+pub fn update_snapshot(isa: &Isa, path: &Path) -> Result<()> {
+    let names: Vec<_> = isa.opcodes.iter().map(|op| op.enum_name()).collect();
+    if path.exists() {
+        let old = fs::read_to_string(path).with_context(|| format!("Failed to read '{}'", path.display()))?;
+        let removed: Vec<_> = old
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter(|name| !names.iter().any(|n| n == name) && !isa.renames.contains_key(*name))
+            .collect();
+        if !removed.is_empty() {
+            bail!(
+                "Opcode variants were renamed or removed, please add them to `renames`:\n    {}",
+                removed.join("\n    ")
+            );
+        }
+    }
+    fs::write(path, HEADER.to_string() + &names.join("\n") + "\n")?;
+    Ok(())
+}
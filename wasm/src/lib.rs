@@ -0,0 +1,88 @@
+//! WebAssembly bindings for unarm, for decoding instructions in a web page. Build with
+//! `wasm-pack build wasm --target web` and call `disassemble` or `disassembleBatch` from JavaScript.
+
+use serde::Serialize;
+use unarm::{ArmVersion, Endian, Op, ParseFlags, ParseMode, Parser};
+use wasm_bindgen::prelude::*;
+
+/// Returns the version named `version`, which is one of `v4`, `v4t`, `v5t`, `v5te` and `v6k`
+fn parse_version(version: &str) -> Result<ArmVersion, JsError> {
+    match version {
+        "v4" => Ok(ArmVersion::V4),
+        "v4t" => Ok(ArmVersion::V4T),
+        "v5t" => Ok(ArmVersion::V5T),
+        "v5te" => Ok(ArmVersion::V5Te),
+        "v6k" => Ok(ArmVersion::V6K),
+        _ => Err(JsError::new(&format!(
+            "unknown version '{version}', expected v4, v4t, v5t, v5te or v6k"
+        ))),
+    }
+}
+
+fn parser<'a>(version: &str, thumb: bool, ual: bool, address: u32, code: &'a [u8]) -> Result<Parser<'a>, JsError> {
+    let mode = if thumb { ParseMode::Thumb } else { ParseMode::Arm };
+    let flags = ParseFlags {
+        ual,
+        ..Default::default()
+    };
+    Ok(Parser::new(
+        parse_version(version)?,
+        mode,
+        address,
+        Endian::Little,
+        flags,
+        code,
+    ))
+}
+
+/// Returns the name of the `Opcode` variant of `op`, such as `"Ldr"`
+fn opcode_name(op: Op) -> String {
+    match op {
+        Op::ArmV4T(op) => format!("{op:?}"),
+        Op::ThumbV4T(op) => format!("{op:?}"),
+        Op::ArmV5Te(op) => format!("{op:?}"),
+        Op::ThumbV5Te(op) => format!("{op:?}"),
+        Op::ArmV6K(op) => format!("{op:?}"),
+        Op::ThumbV6K(op) => format!("{op:?}"),
+        Op::Data => "Data".to_string(),
+    }
+}
+
+/// Disassembles the instruction `code`, which is an ARM instruction or a Thumb instruction in the low 16 bits. Throws
+/// if `version` is not one of `v4`, `v4t`, `v5t`, `v5te` and `v6k`.
+#[wasm_bindgen]
+pub fn disassemble(code: u32, version: &str, thumb: bool, ual: bool) -> Result<String, JsError> {
+    let bytes = code.to_le_bytes();
+    let size = if thumb { 2 } else { 4 };
+    let parser = parser(version, thumb, ual, 0, &bytes[..size])?;
+    let mode = parser.mode;
+    Ok(parser
+        .map(|(address, _, ins)| ins.display_with_pc(Default::default(), address, mode).to_string())
+        .next()
+        .unwrap_or_default())
+}
+
+/// One line of [`disassemble_batch`]
+#[derive(Serialize)]
+struct Line {
+    address: u32,
+    opcode: String,
+    text: String,
+}
+
+/// Disassembles little-endian `code` starting at `address`, and returns a JSON array of `{ address, opcode, text }`
+/// objects. Thumb BL/BLX pairs are combined into one line with the opcode of the first half, `BlH`, and trailing bytes
+/// which don't fill an instruction are left out. Throws like [`disassemble`].
+#[wasm_bindgen(js_name = disassembleBatch)]
+pub fn disassemble_batch(code: &[u8], address: u32, version: &str, thumb: bool, ual: bool) -> Result<String, JsError> {
+    let parser = parser(version, thumb, ual, address, code)?;
+    let mode = parser.mode;
+    let lines: Vec<Line> = parser
+        .map(|(address, op, ins)| Line {
+            address,
+            opcode: opcode_name(op),
+            text: ins.display_with_pc(Default::default(), address, mode).to_string(),
+        })
+        .collect();
+    Ok(serde_json::to_string(&lines)?)
+}
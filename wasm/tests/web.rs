@@ -0,0 +1,36 @@
+//! Runs natively with `cargo test`, or in a JavaScript engine with `wasm-pack test --node wasm`
+
+use unarm::{ParseMode, ParsedIns};
+use unarm_wasm::{disassemble, disassemble_batch};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_disassemble() {
+    assert_eq!(disassemble(0xe5902268, "v5te", false, true).unwrap(), "ldr r2, [r0, #0x268]");
+    assert_eq!(disassemble(0xef000001, "v5te", false, true).unwrap(), "svc #0x1");
+    assert_eq!(disassemble(0xef000001, "v5te", false, false).unwrap(), "swi #0x1");
+    assert_eq!(disassemble(0x1c48, "v4t", true, true).unwrap(), "adds r0, r1, #0x1");
+    assert_eq!(disassemble(0x4770, "v6k", true, true).unwrap(), "bx lr");
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_round_trip() {
+    for (code, thumb) in [(0xe0812003, false), (0xe92d4010, false), (0x1c48, true), (0xb510, true)] {
+        let text = disassemble(code, "v5te", thumb, true).unwrap();
+        let mode = if thumb { ParseMode::Thumb } else { ParseMode::Arm };
+        let parsed = ParsedIns::from_text(&text, mode).unwrap();
+        assert_eq!(parsed.display(Default::default()).to_string(), text);
+    }
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn test_disassemble_batch() {
+    let code = [0x10, 0xb5, 0x00, 0xf0, 0x02, 0xf8, 0x10, 0xbd, 0xff];
+    let json = disassemble_batch(&code, 0x02000000, "v5te", true, true).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"address":33554432,"opcode":"Push","text":"push {r4, lr}"},"#.to_string()
+            + r#"{"address":33554434,"opcode":"BlH","text":"bl #0x200000a"},"#
+            + r#"{"address":33554438,"opcode":"Pop","text":"pop {r4, pc}"}]"#
+    );
+}
@@ -0,0 +1,60 @@
+/* Part of the unarm benchmark corpus, MIT licensed like the rest of unarm. Table-driven and bitwise checksums. */
+
+typedef unsigned int u32;
+typedef unsigned short u16;
+typedef unsigned char u8;
+
+static u32 crc_table[256];
+
+void crc32_init(void) {
+    for (u32 i = 0; i < 256; i++) {
+        u32 crc = i;
+        for (int bit = 0; bit < 8; bit++) {
+            crc = (crc & 1) ? (crc >> 1) ^ 0xedb88320 : crc >> 1;
+        }
+        crc_table[i] = crc;
+    }
+}
+
+u32 crc32(const u8 *data, u32 len) {
+    u32 crc = 0xffffffff;
+    while (len--) {
+        crc = crc_table[(crc ^ *data++) & 0xff] ^ (crc >> 8);
+    }
+    return ~crc;
+}
+
+u16 crc16_ccitt(const u8 *data, u32 len) {
+    u16 crc = 0xffff;
+    for (u32 i = 0; i < len; i++) {
+        crc ^= (u16)data[i] << 8;
+        for (int bit = 0; bit < 8; bit++) {
+            crc = (crc & 0x8000) ? (u16)((crc << 1) ^ 0x1021) : (u16)(crc << 1);
+        }
+    }
+    return crc;
+}
+
+u32 adler32(const u8 *data, u32 len) {
+    u32 a = 1, b = 0;
+    while (len > 0) {
+        u32 block = len < 5552 ? len : 5552;
+        len -= block;
+        while (block--) {
+            a += *data++;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    return (b << 16) | a;
+}
+
+u32 fnv1a(const char *str) {
+    u32 hash = 0x811c9dc5;
+    while (*str) {
+        hash ^= (u8)*str++;
+        hash *= 0x01000193;
+    }
+    return hash;
+}
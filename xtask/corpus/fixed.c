@@ -0,0 +1,74 @@
+/* Part of the unarm benchmark corpus, MIT licensed like the rest of unarm. Fixed-point math and memory routines. */
+
+typedef int fx32;
+typedef long long s64;
+typedef unsigned int u32;
+
+fx32 fx_mul(fx32 a, fx32 b) {
+    return (fx32)(((s64)a * b) >> 12);
+}
+
+fx32 fx_div(fx32 a, fx32 b) {
+    return b ? (fx32)(((s64)a << 12) / b) : 0;
+}
+
+u32 isqrt(u32 value) {
+    u32 result = 0, bit = 1u << 30;
+    while (bit > value) bit >>= 2;
+    while (bit) {
+        if (value >= result + bit) {
+            value -= result + bit;
+            result = (result >> 1) + bit;
+        } else {
+            result >>= 1;
+        }
+        bit >>= 2;
+    }
+    return result;
+}
+
+void mtx_mul33(const fx32 *a, const fx32 *b, fx32 *out) {
+    for (int row = 0; row < 3; row++) {
+        for (int col = 0; col < 3; col++) {
+            s64 sum = 0;
+            for (int k = 0; k < 3; k++) sum += (s64)a[row * 3 + k] * b[k * 3 + col];
+            out[row * 3 + col] = (fx32)(sum >> 12);
+        }
+    }
+}
+
+void vec_lerp(const fx32 *from, const fx32 *to, fx32 t, fx32 *out) {
+    for (int i = 0; i < 3; i++) out[i] = from[i] + fx_mul(to[i] - from[i], t);
+}
+
+void *mem_copy(void *dst, const void *src, u32 len) {
+    unsigned char *d = dst;
+    const unsigned char *s = src;
+    if ((((u32)d | (u32)s) & 3) == 0) {
+        while (len >= 4) {
+            *(u32 *)d = *(const u32 *)s;
+            d += 4;
+            s += 4;
+            len -= 4;
+        }
+    }
+    while (len--) *d++ = *s++;
+    return dst;
+}
+
+void mem_fill16(unsigned short *dst, unsigned short value, u32 count) {
+    while (count--) *dst++ = value;
+}
+
+int str_compare(const char *a, const char *b) {
+    while (*a && *a == *b) {
+        a++;
+        b++;
+    }
+    return (unsigned char)*a - (unsigned char)*b;
+}
+
+u32 rng_next(u32 *state) {
+    *state = *state * 1664525 + 1013904223;
+    return *state >> 16;
+}
@@ -0,0 +1,85 @@
+/* Part of the unarm benchmark corpus, MIT licensed like the rest of unarm. Sorting and searching of game-like data. */
+
+typedef struct {
+    int x, y;
+    short hp, max_hp;
+    unsigned char flags, team;
+} Entity;
+
+static void swap(Entity *a, Entity *b) {
+    Entity tmp = *a;
+    *a = *b;
+    *b = tmp;
+}
+
+void sort_by_y(Entity *entities, int count) {
+    for (int i = 1; i < count; i++) {
+        Entity key = entities[i];
+        int j = i - 1;
+        while (j >= 0 && entities[j].y > key.y) {
+            entities[j + 1] = entities[j];
+            j--;
+        }
+        entities[j + 1] = key;
+    }
+}
+
+void quicksort(int *values, int low, int high) {
+    while (low < high) {
+        int pivot = values[(low + high) / 2];
+        int i = low, j = high;
+        while (i <= j) {
+            while (values[i] < pivot) i++;
+            while (values[j] > pivot) j--;
+            if (i <= j) {
+                int tmp = values[i];
+                values[i++] = values[j];
+                values[j--] = tmp;
+            }
+        }
+        if (j - low < high - i) {
+            quicksort(values, low, j);
+            low = i;
+        } else {
+            quicksort(values, i, high);
+            high = j;
+        }
+    }
+}
+
+int binary_search(const int *values, int count, int key) {
+    int low = 0, high = count - 1;
+    while (low <= high) {
+        int mid = (low + high) >> 1;
+        if (values[mid] == key) return mid;
+        if (values[mid] < key) low = mid + 1;
+        else high = mid - 1;
+    }
+    return -1;
+}
+
+int remove_dead(Entity *entities, int count) {
+    int alive = 0;
+    for (int i = 0; i < count; i++) {
+        if (entities[i].hp > 0 && !(entities[i].flags & 0x80)) {
+            if (alive != i) swap(&entities[alive], &entities[i]);
+            alive++;
+        }
+    }
+    return alive;
+}
+
+int nearest_enemy(const Entity *entities, int count, const Entity *self) {
+    int best = -1;
+    unsigned int best_dist = 0xffffffff;
+    for (int i = 0; i < count; i++) {
+        if (entities[i].team == self->team) continue;
+        int dx = entities[i].x - self->x, dy = entities[i].y - self->y;
+        unsigned int dist = (unsigned int)(dx * dx + dy * dy);
+        if (dist < best_dist) {
+            best_dist = dist;
+            best = i;
+        }
+    }
+    return best;
+}
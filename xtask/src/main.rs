@@ -0,0 +1,305 @@
+//! Development tasks, run with `cargo xtask <task>`.
+//!
+//! - `profiles [--check]`: tests the `compact` and `fast` decoder profiles, then builds and benchmarks both and prints
+//!   a table of their binary size and speed. `--check` only runs the tests.
+//! - `cross-version`: decodes the test corpora and opcode patterns in every version and prints each instruction which
+//!   is displayed differently by two versions, including the intentional differences in
+//!   `disasm/tests/cross-version-allowlist.txt`.
+//! - `thumb-golden`: rewrites `disasm/tests/fixtures/thumb_exhaustive.txt` with the disassembly hashes of every 16-bit
+//!   Thumb code, after an intended change to the Thumb ISA.
+//! - `no-panic`: links `disasm/tests/test_no_panic.rs` in release mode with overflow checks and the `no-panic` feature,
+//!   which fails if `Opcode::find` or a generated parse function may panic.
+//! - `corpus [--generate] [--count <n>] [--seed <n>] [--out <path>]`: writes a reproducible ARMv5TE corpus for the
+//!   benchmarks and the fuzzer, `target/corpus/arm.bin` by default. Compiles the C files in `xtask/corpus/` if the
+//!   pinned `arm-none-eabi-gcc` is installed, otherwise or with `--generate` samples `count` instructions from
+//!   `disasm/corpus/model.txt`.
+//! - `corpus model <file>...`: prints the frequency model of little-endian ARM code files, see
+//!   `disasm/corpus/README.md`.
+
+use std::{
+    path::{Path, PathBuf},
+    process::{exit, Command},
+};
+
+use unarm::{
+    corpus::{FrequencyModel, SEED},
+    Endian,
+};
+
+/// Features of unarm besides the profile
+const FEATURES: &str = "thumb,arm,v4t,v5te,v6k,ext-dsp,ext-media";
+const PROFILES: [&str; 2] = ["compact", "fast"];
+
+/// Version of `arm-none-eabi-gcc` which compiles the C corpus, so that it's the same on every machine
+const PINNED_GCC: &str = "13.2.1";
+/// Default number of instructions in a generated corpus, 16 MB
+const CORPUS_COUNT: usize = 0x400000;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["profiles"] => profiles(false),
+        ["profiles", "--check"] => profiles(true),
+        ["cross-version"] => cross_version(),
+        ["thumb-golden"] => thumb_golden(),
+        ["no-panic"] => no_panic(),
+        ["corpus", "model", files @ ..] if !files.is_empty() => corpus_model(files),
+        ["corpus", options @ ..] => corpus(options),
+        _ => {
+            eprintln!("Usage: cargo xtask profiles [--check]");
+            eprintln!("       cargo xtask cross-version");
+            eprintln!("       cargo xtask thumb-golden");
+            eprintln!("       cargo xtask no-panic");
+            eprintln!("       cargo xtask corpus [--generate] [--count <n>] [--seed <n>] [--out <path>]");
+            eprintln!("       cargo xtask corpus model <file>...");
+            exit(1);
+        }
+    }
+}
+
+/// Runs `cargo` with `args`, exits if it fails and returns its standard output
+fn cargo(args: &[&str], envs: &[(&str, &str)]) -> String {
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(args)
+        .envs(envs.iter().copied())
+        .stderr(std::process::Stdio::inherit())
+        .output()
+        .expect("Failed to run cargo");
+    if !output.status.success() {
+        eprintln!("cargo {} failed", args.join(" "));
+        exit(1);
+    }
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+/// Returns the ns/ins of the benchmark `id` in the output of a criterion benchmark, from the middle estimate of its
+/// throughput, e.g. `thrpt:  [12.105 Melem/s 12.211 Melem/s 12.306 Melem/s]`
+fn bench_result(output: &str, id: &str) -> String {
+    let mut lines = output.lines().skip_while(|line| line.split_whitespace().next() != Some(id));
+    lines
+        .find_map(|line| line.trim().strip_prefix("thrpt:"))
+        .and_then(|throughput| {
+            let estimates: Vec<&str> = throughput.trim().trim_matches(['[', ']']).split_whitespace().collect();
+            let scale = match *estimates.get(3)? {
+                "elem/s" => 1.0,
+                "Kelem/s" => 1e3,
+                "Melem/s" => 1e6,
+                "Gelem/s" => 1e9,
+                _ => return None,
+            };
+            let throughput = estimates.get(2)?.parse::<f64>().ok()? * scale;
+            Some(format!("{:.2} ns/ins", 1e9 / throughput))
+        })
+        .unwrap_or_else(|| "?".to_string())
+}
+
+fn profiles(check: bool) {
+    // Every test decodes the same corpora in both profiles, so passing them means that the profiles agree
+    for profile in PROFILES {
+        let features = format!("{FEATURES},{profile}");
+        println!("Testing the {profile} profile");
+        cargo(
+            &[
+                "test",
+                "-q",
+                "-p",
+                "unarm",
+                "--release",
+                "--no-default-features",
+                "--features",
+                &features,
+            ],
+            &[],
+        );
+    }
+    if check {
+        return;
+    }
+
+    let mut rows = vec![];
+    for profile in PROFILES {
+        let features = format!("{FEATURES},{profile}");
+        println!("Building and benchmarking the {profile} profile");
+        let flags = ["-p", "unarm", "--no-default-features", "--features", &features];
+        cargo(
+            &[&["build", "-q", "--release", "--example", "dump"][..], &flags].concat(),
+            &[("CARGO_PROFILE_RELEASE_STRIP", "symbols")],
+        );
+        let dump = workspace_root().join("target/release/examples/dump");
+        let size = std::fs::metadata(&dump)
+            .unwrap_or_else(|e| panic!("{}: {e}", dump.display()))
+            .len();
+        let output = cargo(
+            &[&["bench", "-q", "--bench", "parse"][..], &flags, &["--", "/pseudorandom"]].concat(),
+            &[],
+        );
+        rows.push(format!(
+            "| `{profile}` | {} KiB | {} | {} |",
+            size / 1024,
+            bench_result(&output, "parse/InsIter/pseudorandom"),
+            bench_result(&output, "parse/parse_all/pseudorandom")
+        ));
+    }
+
+    println!();
+    println!("| Profile | `dump` example size | `InsIter` | `parse_all` |");
+    println!("| ------- | ------------------- | --------- | ----------- |");
+    for row in rows {
+        println!("{row}");
+    }
+}
+
+fn cross_version() {
+    // The test prints every difference instead of only the unexpected ones
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args([
+            "test",
+            "-q",
+            "-p",
+            "unarm",
+            "--test",
+            "test_cross_version",
+            "--",
+            "--nocapture",
+        ])
+        .env("UNARM_CROSS_VERSION_REPORT", "1")
+        .status()
+        .expect("Failed to run cargo");
+    exit(status.code().unwrap_or(1));
+}
+
+fn thumb_golden() {
+    cargo(
+        &["test", "-q", "-p", "unarm", "--test", "test_thumb_exhaustive"],
+        &[("UNARM_THUMB_GOLDEN_UPDATE", "1")],
+    );
+    println!("Wrote disasm/tests/fixtures/thumb_exhaustive.txt");
+}
+
+fn no_panic() {
+    // Overflow checks are off in release mode by default, but arithmetic which would panic in a debug build must fail
+    // the check as well
+    cargo(
+        &[
+            "test",
+            "-q",
+            "-p",
+            "unarm",
+            "--release",
+            "--features",
+            "no-panic",
+            "--test",
+            "test_no_panic",
+        ],
+        &[("CARGO_PROFILE_RELEASE_OVERFLOW_CHECKS", "true")],
+    );
+    println!("Opcode::find and the parse functions can't panic");
+}
+
+fn corpus(options: &[&str]) {
+    let mut generate = false;
+    let mut count = CORPUS_COUNT;
+    let mut seed = SEED;
+    let mut out = workspace_root().join("target/corpus/arm.bin");
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let mut value = || {
+            options.next().copied().unwrap_or_else(|| {
+                eprintln!("Expected a value after {option}");
+                exit(1)
+            })
+        };
+        match *option {
+            "--generate" => generate = true,
+            "--count" => count = value().parse().expect("Invalid count"),
+            "--seed" => seed = value().parse().expect("Invalid seed"),
+            "--out" => out = PathBuf::from(value()),
+            _ => {
+                eprintln!("Unknown option {option}");
+                exit(1);
+            }
+        }
+    }
+
+    let code = match (generate, pinned_gcc()) {
+        (false, Some(gcc)) => {
+            println!("Compiling xtask/corpus/*.c with {gcc}");
+            compile_corpus(&gcc)
+        }
+        _ => {
+            if !generate {
+                println!("arm-none-eabi-gcc {PINNED_GCC} not found, sampling disasm/corpus/model.txt instead");
+            }
+            FrequencyModel::builtin().generate(seed, count)
+        }
+    };
+    std::fs::create_dir_all(out.parent().unwrap()).unwrap_or_else(|e| panic!("{}: {e}", out.display()));
+    std::fs::write(&out, &code).unwrap_or_else(|e| panic!("{}: {e}", out.display()));
+    println!("Wrote {} instructions to {}", code.len() / 4, out.display());
+    println!(
+        "Benchmark it with UNARM_BENCH_CORPUS={} and the corpus feature, or fuzz it with --corpus",
+        out.display()
+    );
+}
+
+/// Returns the name of `arm-none-eabi-gcc` if it's installed in the pinned version
+fn pinned_gcc() -> Option<String> {
+    let gcc = std::env::var("ARM_GCC").unwrap_or_else(|_| "arm-none-eabi-gcc".to_string());
+    let output = Command::new(&gcc).arg("-dumpversion").output().ok()?;
+    (String::from_utf8_lossy(&output.stdout).trim() == PINNED_GCC).then_some(gcc)
+}
+
+/// Compiles every C file of `xtask/corpus/` to ARMv5TE code and returns their `.text` sections
+fn compile_corpus(gcc: &str) -> Vec<u8> {
+    let objcopy = gcc.replace("gcc", "objcopy");
+    let build = workspace_root().join("target/corpus/build");
+    std::fs::create_dir_all(&build).unwrap_or_else(|e| panic!("{}: {e}", build.display()));
+    let mut sources: Vec<_> = std::fs::read_dir(workspace_root().join("xtask/corpus"))
+        .expect("Failed to read xtask/corpus")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "c"))
+        .collect();
+    sources.sort();
+    let mut code = vec![];
+    for source in sources {
+        let object = build.join(source.file_stem().unwrap()).with_extension("o");
+        let text = object.with_extension("bin");
+        run(Command::new(gcc)
+            .args(["-march=armv5te", "-marm", "-mfloat-abi=soft", "-O2", "-ffreestanding", "-c"])
+            .arg(&source)
+            .arg("-o")
+            .arg(&object));
+        run(Command::new(&objcopy)
+            .args(["-O", "binary", "-j", ".text"])
+            .arg(&object)
+            .arg(&text));
+        code.extend(std::fs::read(&text).unwrap_or_else(|e| panic!("{}: {e}", text.display())));
+    }
+    code
+}
+
+/// Runs a command and exits if it fails
+fn run(command: &mut Command) {
+    let status = command.status().unwrap_or_else(|e| panic!("{command:?}: {e}"));
+    if !status.success() {
+        eprintln!("{command:?} failed");
+        exit(1);
+    }
+}
+
+fn corpus_model(files: &[&str]) {
+    let mut model = FrequencyModel::default();
+    for file in files {
+        let code = std::fs::read(file).unwrap_or_else(|e| panic!("{file}: {e}"));
+        model.add(&code, Endian::Little);
+    }
+    println!("# Frequency model of unarm::corpus, written by `cargo xtask corpus model`");
+    println!("# Sources: {}", files.join(", "));
+    println!("# Instructions: {}", model.len());
+    print!("{model}");
+}